@@ -0,0 +1,270 @@
+//! Example integrator program calling onreapp through CPI
+//!
+//! Depends on the `cpi` feature of the onreapp crate and uses its generated
+//! `onreapp::cpi` functions and `onreapp::cpi::accounts` structs. It also acts as
+//! a mock multisig whose vault PDA signs onreapp instructions with `invoke_signed`,
+//! like a Squads vault holding the boss authority, and as a mock screening program
+//! implementing onreapp's `check(user, amount)` interface. It is deployed only in
+//! tests.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use onreapp::program::Onreapp;
+
+/// Seed of the mock multisig vault PDA
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Largest amount the mock screening program accepts
+pub const MOCK_SCREENING_MAX_AMOUNT: u64 = 1_000_000_000;
+
+declare_id!("6ofWz43VrX9UxWarGGrHBYdMHYcDRcE175UBPbG9QW8r");
+
+#[program]
+pub mod onre_cpi_caller {
+    use super::*;
+
+    /// Takes an onreapp offer on behalf of the signing user.
+    ///
+    /// Forwards the accounts to `onreapp::cpi::take_offer` without an approval
+    /// message, so only offers that do not require approval can be taken.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeOfferViaCpi`.
+    /// - `token_in_amount`: Amount of token_in the user pays, including fees.
+    pub fn take_offer(ctx: Context<TakeOfferViaCpi>, token_in_amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let cpi_accounts = onreapp::cpi::accounts::TakeOffer {
+            offer: accounts.offer.to_account_info(),
+            state: accounts.state.to_account_info(),
+            config: accounts.config.to_account_info(),
+            boss: accounts.boss.to_account_info(),
+            vault_authority: accounts.vault_authority.to_account_info(),
+            vault_token_in_account: accounts.vault_token_in_account.to_account_info(),
+            vault_token_out_account: accounts.vault_token_out_account.to_account_info(),
+            token_in_mint: accounts.token_in_mint.to_account_info(),
+            token_in_program: accounts.token_in_program.to_account_info(),
+            token_out_mint: accounts.token_out_mint.to_account_info(),
+            token_out_program: accounts.token_out_program.to_account_info(),
+            user_token_in_account: accounts.user_token_in_account.to_account_info(),
+            user_token_out_account: accounts.user_token_out_account.to_account_info(),
+            boss_token_in_account: accounts
+                .boss_token_in_account
+                .as_ref()
+                .map(|account| account.to_account_info()),
+            mint_authority: accounts.mint_authority.to_account_info(),
+            managed_mint: accounts.managed_mint.to_account_info(),
+            instructions_sysvar: accounts.instructions_sysvar.to_account_info(),
+            token_in_oracle: None,
+            nav_history: None,
+            redemption_offer: None,
+            redemption_vault_token_in_account: None,
+            token_in_hook_approval: None,
+            screening_program: None,
+            blocklist_bucket: accounts.blocklist_bucket.to_account_info(),
+            user_position: accounts.user_position.to_account_info(),
+            take_receipt: None,
+            user: accounts.user.to_account_info(),
+            associated_token_program: accounts.associated_token_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+        };
+
+        onreapp::cpi::take_offer(
+            CpiContext::new(accounts.onre_program.to_account_info(), cpi_accounts),
+            token_in_amount,
+            None,
+        )
+    }
+
+    /// Reads the current NAV of an onreapp offer.
+    ///
+    /// Calls `onreapp::cpi::get_nav` and returns its result as this program's
+    /// return data.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetNavViaCpi`.
+    ///
+    /// # Returns
+    /// - `Ok(nav)`: The current price of the offer with 9 decimals
+    pub fn get_nav(ctx: Context<GetNavViaCpi>) -> Result<u64> {
+        let accounts = &ctx.accounts;
+        let cpi_accounts = onreapp::cpi::accounts::GetNAV {
+            offer: accounts.offer.to_account_info(),
+            config: accounts.config.to_account_info(),
+            token_in_mint: accounts.token_in_mint.to_account_info(),
+            token_out_mint: accounts.token_out_mint.to_account_info(),
+        };
+
+        let nav = onreapp::cpi::get_nav(CpiContext::new(
+            accounts.onre_program.to_account_info(),
+            cpi_accounts,
+        ))?
+        .get();
+
+        msg!("NAV read via CPI: {}", nav);
+
+        Ok(nav)
+    }
+
+    /// Executes an onreapp instruction signed by the mock multisig vault.
+    ///
+    /// The onreapp instruction accounts are passed as remaining accounts in
+    /// instruction order; the vault PDA is marked as signer wherever it appears.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ExecuteAsVault`, with the onreapp accounts as remaining accounts.
+    /// - `data`: Encoded onreapp instruction data.
+    pub fn execute_as_vault<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteAsVault<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let vault = ctx.accounts.vault.key();
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == vault,
+                is_writable: account.is_writable,
+            })
+            .collect();
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.onre_program.to_account_info());
+
+        invoke_signed(
+            &Instruction {
+                program_id: onreapp::ID,
+                accounts,
+                data,
+            },
+            &account_infos,
+            &[&[VAULT_SEED, &[ctx.bumps.vault]]],
+        )?;
+
+        Ok(())
+    }
+
+    /// Screens a take as a mock screening program.
+    ///
+    /// Implements onreapp's screening interface and rejects amounts above
+    /// `MOCK_SCREENING_MAX_AMOUNT`.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `Check`.
+    /// - `user`: The user taking the offer.
+    /// - `amount`: Amount of token_in the user pays, including fees.
+    pub fn check(ctx: Context<Check>, user: Pubkey, amount: u64) -> Result<()> {
+        require_keys_eq!(ctx.accounts.user.key(), user, MockScreeningErrorCode::Rejected);
+        require!(
+            amount <= MOCK_SCREENING_MAX_AMOUNT,
+            MockScreeningErrorCode::Rejected
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts forwarded to `onreapp::take_offer`
+///
+/// Every account is validated by onreapp, so they are passed through unchecked.
+#[derive(Accounts)]
+pub struct TakeOfferViaCpi<'info> {
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub offer: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub state: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub boss: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub vault_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub vault_token_in_account: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub vault_token_out_account: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub token_in_mint: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub token_in_program: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub token_out_mint: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub token_out_program: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub user_token_in_account: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    #[account(mut)]
+    pub user_token_out_account: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp; required only when proceeds go to the boss
+    #[account(mut)]
+    pub boss_token_in_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by onreapp
+    pub mint_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub managed_mint: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub blocklist_bucket: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp, which creates it on the user's first take
+    #[account(mut)]
+    pub user_position: UncheckedAccount<'info>,
+    /// The user paying token_in and receiving token_out
+    #[account(mut)]
+    pub user: Signer<'info>,
+    /// CHECK: Validated by onreapp
+    pub associated_token_program: UncheckedAccount<'info>,
+    /// System program for creating the user's token_out account
+    pub system_program: Program<'info, System>,
+    /// The onreapp program
+    pub onre_program: Program<'info, Onreapp>,
+}
+
+/// Accounts forwarded to `onreapp::get_nav`
+#[derive(Accounts)]
+pub struct GetNavViaCpi<'info> {
+    /// CHECK: Validated by onreapp
+    pub offer: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub token_in_mint: UncheckedAccount<'info>,
+    /// CHECK: Validated by onreapp
+    pub token_out_mint: UncheckedAccount<'info>,
+    /// The onreapp program
+    pub onre_program: Program<'info, Onreapp>,
+}
+
+/// Accounts for executing an onreapp instruction as the mock multisig vault
+#[derive(Accounts)]
+pub struct ExecuteAsVault<'info> {
+    /// The vault PDA signing the onreapp instruction
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(mut, seeds = [VAULT_SEED], bump)]
+    pub vault: UncheckedAccount<'info>,
+    /// The onreapp program
+    pub onre_program: Program<'info, Onreapp>,
+}
+
+/// Accounts of the mock screening program's `check` instruction
+#[derive(Accounts)]
+pub struct Check<'info> {
+    /// CHECK: Only compared against the screened user
+    pub user: UncheckedAccount<'info>,
+}
+
+/// Error codes of the mock screening program
+#[error_code]
+pub enum MockScreeningErrorCode {
+    /// The screened take is rejected
+    #[msg("Screening check failed")]
+    Rejected,
+}
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 /// PDA seeds used throughout the program for account derivation
 pub mod seeds {
     /// Seed for the program state account
@@ -6,11 +8,20 @@ pub mod seeds {
     /// Seed for the offers account
     pub const OFFER: &[u8] = b"offer";
 
+    /// Seed for the offer version record account
+    pub const OFFER_VERSION: &[u8] = b"offer_version";
+
+    /// Seed for the registry of live offers
+    pub const OFFER_REGISTRY: &[u8] = b"offer_registry";
+
     /// Seed for the offer vault authority account
     pub const OFFER_VAULT_AUTHORITY: &[u8] = b"offer_vault_authority";
 
-    /// Seed for the permissionless intermediary authority account
-    pub const PERMISSIONLESS_AUTHORITY: &[u8] = b"permissionless-1";
+    /// Seed prefix for permissionless intermediary authority accounts
+    ///
+    /// Followed by the authority index in decimal, so the original authority
+    /// "permissionless-1" has index 1.
+    pub const PERMISSIONLESS_AUTHORITY: &[u8] = b"permissionless-";
 
     /// Seed for mint authority PDA accounts
     pub const MINT_AUTHORITY: &[u8] = b"mint_authority";
@@ -26,19 +37,181 @@ pub mod seeds {
 
     /// Seed for the user nonce account
     pub const NONCE_ACCOUNT: &[u8] = b"nonce_account";
+
+    /// Seed for share class registry entries
+    pub const SHARE_CLASS: &[u8] = b"share_class";
+
+    /// Seed for the dual offer account
+    pub const DUAL_OFFER: &[u8] = b"dual_offer";
+
+    /// Seed for the dual redemption offer account
+    pub const DUAL_REDEMPTION_OFFER: &[u8] = b"dual_redemption_offer";
+
+    /// Seed for the clawback record account
+    pub const CLAWBACK_RECORD: &[u8] = b"clawback_record";
+
+    /// Seed for the take receipt accounts of clawback-enabled offers
+    pub const TAKE_RECEIPT: &[u8] = b"take_receipt";
+
+    /// Seed for the per-offer NAV observation history
+    pub const NAV_HISTORY: &[u8] = b"nav_history";
+
+    /// Seed for boss approvals of Token-2022 transfer-hook mints
+    pub const TRANSFER_HOOK_MINT: &[u8] = b"transfer_hook_mint";
+
+    /// Seed for the keeper reward configuration account
+    pub const KEEPER_REWARD_CONFIG: &[u8] = b"keeper_reward_config";
+
+    /// Seed for per-keeper reward accounts
+    pub const KEEPER_REWARDS: &[u8] = b"keeper_rewards";
+
+    /// Seed for the keeper reward vault authority account
+    pub const KEEPER_REWARD_VAULT_AUTHORITY: &[u8] = b"keeper_reward_vault_authority";
+
+    /// Seed for the ONyc mint throttle account
+    pub const MINT_THROTTLE: &[u8] = b"mint_throttle";
+
+    /// Seed for the pending ONyc max supply proposal account
+    pub const MAX_SUPPLY_PROPOSAL: &[u8] = b"max_supply_proposal";
+
+    /// Seed for the program-wide configuration account
+    pub const CONFIG: &[u8] = b"config";
+
+    /// Seed for the pending state closure proposal account
+    pub const CLOSE_STATE_PROPOSAL: &[u8] = b"close_state_proposal";
+
+    /// Seed for mint delegate registry entries
+    pub const MINT_DELEGATE: &[u8] = b"mint_delegate";
+
+    /// Seed a delegate program derives its signing authority from, under its own program ID
+    pub const MINT_DELEGATE_AUTHORITY: &[u8] = b"onre_mint_delegate";
+
+    /// Seed for per-mint offer vault withdrawal timelock configurations
+    pub const VAULT_WITHDRAW_TIMELOCK: &[u8] = b"vault_withdraw_timelock";
+
+    /// Seed for pending per-mint offer vault withdrawal proposals
+    pub const VAULT_WITHDRAW_PROPOSAL: &[u8] = b"vault_withdraw_proposal";
+
+    /// Seed for the vault withdrawal destination allowlist account
+    pub const WITHDRAWAL_ALLOWLIST: &[u8] = b"withdrawal_allowlist";
+
+    /// Seed for per-redemption-offer in-kind redemption baskets
+    pub const REDEMPTION_BASKET: &[u8] = b"redemption_basket";
+
+    /// Seed for per-user position accounts
+    pub const USER_POSITION: &[u8] = b"user_position";
+
+    /// Seed for sanctioned-address blocklist bucket accounts
+    pub const BLOCKLIST: &[u8] = b"blocklist";
+
+    /// Seed for the registry entries of tokens managed alongside ONyc
+    pub const MANAGED_MINT: &[u8] = b"managed_mint";
 }
 
 /// Maximum number of pricing vectors allowed per offer
-pub const MAX_VECTORS: usize = 10;
+pub const MAX_VECTORS: usize = 64;
+
+/// Maximum number of live offers tracked by the offer registry
+pub const MAX_REGISTERED_OFFERS: usize = 64;
 
 /// Maximum number of admin accounts that can be stored in program state
 pub const MAX_ADMINS: usize = 20;
 
+/// Maximum number of destination owners in the vault withdrawal allowlist
+pub const MAX_WITHDRAWAL_DESTINATIONS: usize = 8;
+
 /// Number of decimals used for price representation
 pub const PRICE_DECIMALS: u8 = 9;
 
 /// Maximum possible value of basis points (100%)
 pub const MAX_BASIS_POINTS: u16 = 10000;
 
-/// Maximum allowed fee in basis points (10% = 1000 basis points)
+/// Default maximum allowed fee in basis points (10% = 1000 basis points)
+///
+/// The effective cap is `Config::max_fee_bps`, initialized to this value.
 pub const MAX_ALLOWED_FEE_BPS: u16 = 1000;
+
+/// Default number of seconds per year used to accrue offer vector APRs (365 days)
+pub const DEFAULT_SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Lowest number of seconds per year `set_config` accepts (360 days)
+pub const MIN_SECONDS_PER_YEAR: u64 = 31_104_000;
+
+/// Highest number of seconds per year `set_config` accepts (366 days)
+pub const MAX_SECONDS_PER_YEAR: u64 = 31_622_400;
+
+/// Longest approval validity window `set_config` accepts (30 days)
+pub const MAX_APPROVAL_VALIDITY_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Largest clock skew grace `set_config` accepts for approval messages (5 minutes)
+pub const MAX_CLOCK_SKEW_GRACE_SECS: u64 = 5 * 60;
+
+/// Minimum duration in seconds of a pricing step (1 minute)
+#[constant]
+pub const MIN_PRICE_FIX_DURATION: u64 = 60;
+
+/// Maximum duration in seconds of a pricing step (365 days)
+#[constant]
+pub const MAX_PRICE_FIX_DURATION: u64 = 365 * 24 * 60 * 60;
+
+/// Maximum number of pricing steps a vector may span from its base time to its end
+#[constant]
+pub const MAX_VECTOR_STEPS: u64 = 100_000;
+
+/// Maximum number of alternative settlement mints allowlisted per redemption offer
+pub const MAX_SETTLEMENT_MINTS: usize = 3;
+
+/// Maximum number of underlying mints in an in-kind redemption basket
+pub const MAX_REDEMPTION_BASKET_MINTS: usize = 4;
+
+/// Time in seconds after a take during which it may be clawed back (24 hours)
+pub const CLAWBACK_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// Time in seconds after a redemption request locked its NAV during which it may be repriced (7 days)
+pub const REPRICE_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Time in seconds after an offer's expiry before anyone can crank it closed (7 days)
+pub const EXPIRED_OFFER_GRACE_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Number of admin accounts returned per page by the `get_admins` view
+pub const ADMINS_PAGE_SIZE: usize = 10;
+
+/// Number of pricing vectors returned per page by the `get_offer_vectors` view
+pub const OFFER_VECTORS_PAGE_SIZE: usize = 16;
+
+/// Number of offers returned per page by the `get_registry` view
+pub const OFFER_REGISTRY_PAGE_SIZE: usize = 16;
+
+/// Encoding version of the `get_offer_vectors` return data, bumped on layout changes
+pub const OFFER_VECTORS_ENCODING_VERSION: u8 = 1;
+
+/// Number of NAV observations kept in an offer's NAV history ring buffer
+pub const NAV_HISTORY_SIZE: usize = 32;
+
+/// Minimum time in seconds between two recorded NAV observations of an offer
+pub const NAV_OBSERVATION_MIN_INTERVAL: u64 = 60;
+
+/// Schema version of the `State` layout written by this program
+pub const CURRENT_STATE_SCHEMA_VERSION: u16 = 3;
+
+/// Oldest `State` schema version instructions other than `migrate_state` accept
+pub const MIN_STATE_SCHEMA_VERSION: u16 = 3;
+
+/// Size in bytes of the blocked jurisdiction bitmap in program state, covering
+/// jurisdiction codes 0 to 1023 such as ISO 3166-1 numeric country codes
+pub const JURISDICTION_BITMAP_BYTES: usize = 128;
+
+/// Number of bucket PDAs the sanctioned-address blocklist is spread over
+pub const BLOCKLIST_BUCKET_COUNT: u8 = 32;
+
+/// Maximum number of addresses held by one blocklist bucket
+pub const BLOCKLIST_BUCKET_CAPACITY: usize = 64;
+
+/// Number of crank reward rate slots in the keeper reward configuration
+pub const MAX_KEEPER_CRANKS: usize = 8;
+
+/// Delay in seconds before a proposal raising or removing the ONyc max supply can be accepted
+pub const MAX_SUPPLY_TIMELOCK_SECS: i64 = 86_400;
+
+/// Delay in seconds before a proposed closure of the program state can be confirmed
+pub const CLOSE_STATE_TIMELOCK_SECS: i64 = 259_200;
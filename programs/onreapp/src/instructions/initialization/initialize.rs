@@ -1,4 +1,4 @@
-use crate::constants::{seeds, MAX_ADMINS};
+use crate::constants::{seeds, CURRENT_STATE_SCHEMA_VERSION, MAX_ADMINS};
 use crate::state::State;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::bpf_loader_upgradeable::{
@@ -10,7 +10,7 @@ use anchor_spl::token_interface::Mint;
 /// Error codes for the initialize instruction
 #[error_code]
 pub enum InitializeErrorCode {
-    /// Triggered when attempting to re-initialize a state that already has a boss set
+    /// Triggered when re-initializing a state that was initialized with a different boss or ONyc mint
     #[msg("Boss is already set, state has been initialized")]
     BossAlreadySet,
 
@@ -40,10 +40,11 @@ pub enum InitializeErrorCode {
 ///
 /// This struct defines the accounts required to set up the program's global state,
 /// establishing the initial boss, ONyc mint, and default values for all state fields.
-/// This is a one-time operation that creates the program's main state account.
+/// The state account is created on the first call; repeating the call with the same
+/// boss and ONyc mint is a no-op, so a deployment script can safely be re-run.
 ///
 /// # Preconditions
-/// - The `state` account must not exist prior to execution; it will be created by this instruction
+/// - The `state` account is created by this instruction if it doesn't exist yet
 /// - The `boss` must have sufficient SOL to pay for account creation rent
 /// - The `onyc_mint` must be a valid SPL Token mint account
 ///
@@ -65,7 +66,7 @@ pub struct Initialize<'info> {
     /// The account is created as a PDA derived from the "state" seed to ensure
     /// deterministic addressing and program ownership.
     #[account(
-        init,
+        init_if_needed,
         payer = boss,
         space = 8 + State::INIT_SPACE,
         seeds = [seeds::STATE],
@@ -137,37 +138,47 @@ pub struct Initialize<'info> {
 /// - `admins`: Array of default pubkeys (no admins initially)
 /// - `approver`: Default pubkey (must be set separately via set_approver)
 /// - `bump`: PDA bump seed for account validation
+/// - `schema_version`: The current state schema version
 /// - `reserved`: Zero-filled bytes for future use
 ///
 /// # Arguments
 /// * `ctx` - Context containing the accounts needed for state initialization
 ///
 /// # Returns
-/// * `Ok(())` - If initialization completes successfully
+/// * `Ok(())` - If initialization completes successfully or the state is already
+///   initialized with the same boss and ONyc mint
 /// * `Err(InitializeErrorCode::BossAlreadySet)` - If the state has already been initialized
+///   with a different boss or ONyc mint
 ///
 /// # Security
-/// - Only allows initialization if boss is currently unset (default pubkey)
+/// - Only writes the state if boss is currently unset (default pubkey)
+/// - An existing state is never modified, not even its schema version
 pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     let upgrade_authority = get_upgrade_authority(
         &ctx.accounts.program,
         ctx.accounts.program_data.as_ref().map(|v| v.as_ref()),
     )?;
 
-    if upgrade_authority.is_some() {
+    if let Some(upgrade_authority) = upgrade_authority {
         // Check that the boss is the upgrade authority
         require_keys_eq!(
             ctx.accounts.boss.key(),
-            upgrade_authority.unwrap(),
+            upgrade_authority,
             InitializeErrorCode::WrongOwner
         );
     }
 
     let state = &mut ctx.accounts.state;
 
-    // Ensure this is the first initialization
+    // Repeating the initialization is a no-op, initializing differently is rejected
     if state.boss != Pubkey::default() {
-        return err!(InitializeErrorCode::BossAlreadySet);
+        require!(
+            state.boss == ctx.accounts.boss.key()
+                && state.onyc_mint == ctx.accounts.onyc_mint.key(),
+            InitializeErrorCode::BossAlreadySet
+        );
+        msg!("Program state already initialized: boss={}", state.boss);
+        return Ok(());
     }
 
     // Set core state fields
@@ -194,6 +205,9 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     // Initialize redemption_admin as unset
     state.redemption_admin = Pubkey::default();
 
+    // New states start at the current layout and need no migration
+    state.schema_version = CURRENT_STATE_SCHEMA_VERSION;
+
     msg!(
         "Program state initialized: boss={}, onyc_mint={}, bump={}",
         state.boss,
@@ -1,8 +1,21 @@
 use crate::constants::seeds;
-use crate::state::{PermissionlessAuthority, State};
+use crate::state::{PermissionlessAuthority, State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
 
+/// Event emitted when a permissionless authority is initialized
+///
+/// Provides transparency for tracking the intermediary routes available to partners.
+#[event]
+pub struct PermissionlessAuthorityInitializedEvent {
+    /// The PDA address of the new permissionless authority
+    pub permissionless_authority: Pubkey,
+    /// Index the authority is seeded with
+    pub index: u8,
+    /// Name stored in the authority
+    pub name: String,
+}
+
 /// Error codes for the initialize instruction.
 #[error_code]
 pub enum InitializePermissionlessErrorCode {
@@ -19,23 +32,29 @@ pub enum InitializePermissionlessErrorCode {
 /// - Only the boss can initialize permissionless accounts
 /// - The permissionless account must not exist prior to execution
 #[derive(Accounts)]
+#[instruction(index: u8)]
 pub struct InitializePermissionlessAuthority<'info> {
     /// The permissionless account to be created.
     ///
     /// # Note
     /// - Space is allocated as `8 + PermissionlessAuthority::INIT_SPACE` bytes
-    /// - Seeded with hardcoded "permissionless-1" for PDA derivation
+    /// - Seeded with "permissionless-" followed by the index in decimal
     #[account(
         init,
         payer = boss,
         space = 8 + PermissionlessAuthority::INIT_SPACE,
-        seeds = [seeds::PERMISSIONLESS_AUTHORITY],
+        seeds = [PermissionlessAuthority::seed(index).as_slice()],
         bump
     )]
     pub permissionless_authority: Account<'info, PermissionlessAuthority>,
 
     /// The program state account, used to verify boss authorization.
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The boss account that authorizes and pays for the permissionless account creation.
@@ -46,14 +65,17 @@ pub struct InitializePermissionlessAuthority<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Initializes a new permissionless authority with the provided name.
+/// Initializes a new permissionless authority with the provided index and name.
 ///
 /// Creates a permissionless authority that can serve as an intermediary authority
 /// for token routing operations. Only the boss can create these accounts.
-/// The PDA is always derived using the hardcoded seed "permissionless-1".
+/// Each index derives its own PDA, "permissionless-<index>", so every distribution
+/// partner can be given isolated intermediary accounts. Index 1 is the original
+/// "permissionless-1" authority.
 ///
 /// # Arguments
 /// - `ctx`: Context containing the accounts for permissionless account creation
+/// - `index`: Index the authority PDA is seeded with
 /// - `name`: The name to store in the permissionless account (separate from PDA seeds)
 ///
 /// # Returns
@@ -62,8 +84,12 @@ pub struct InitializePermissionlessAuthority<'info> {
 /// # Errors
 /// - Fails if the caller is not the boss (enforced by `has_one = boss` constraint)
 /// - Fails if the permissionless account already exists
+///
+/// # Events
+/// * `PermissionlessAuthorityInitializedEvent` - Emitted with the authority, index and name
 pub fn initialize_permissionless_authority(
     ctx: Context<InitializePermissionlessAuthority>,
+    index: u8,
     name: String,
 ) -> Result<()> {
     let name_cleaned = name.trim();
@@ -73,5 +99,18 @@ pub fn initialize_permissionless_authority(
     );
     let permissionless_authority = &mut ctx.accounts.permissionless_authority;
     permissionless_authority.name = name_cleaned.to_string();
+
+    msg!(
+        "Permissionless authority {} initialized with index {}",
+        permissionless_authority.key(),
+        index
+    );
+
+    emit!(PermissionlessAuthorityInitializedEvent {
+        permissionless_authority: permissionless_authority.key(),
+        index,
+        name: permissionless_authority.name.clone(),
+    });
+
     Ok(())
 }
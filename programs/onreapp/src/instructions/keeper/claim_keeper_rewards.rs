@@ -0,0 +1,174 @@
+use crate::constants::seeds;
+use crate::instructions::keeper::{KeeperCoreError, KeeperRewardConfig, KeeperRewards};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a keeper claims its accrued rewards
+///
+/// Provides transparency for tracking keeper incentive payouts.
+#[event]
+pub struct KeeperRewardsClaimedEvent {
+    /// The keeper that claimed the rewards
+    pub keeper: Pubkey,
+    /// Amount of ONyc paid out
+    pub amount: u64,
+    /// Cumulative rewards claimed by the keeper after this claim
+    pub total_claimed: u64,
+}
+
+/// Account structure for claiming a keeper's accrued rewards
+///
+/// Rewards are paid in ONyc from the keeper reward vault to the keeper's
+/// associated token account, created if needed.
+#[derive(Accounts)]
+pub struct ClaimKeeperRewards<'info> {
+    /// Program state account providing the ONyc mint and kill switch
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = !state.is_kill_switch_active()? @ ClaimKeeperRewardsErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The keeper reward configuration tracking unclaimed rewards
+    #[account(
+        mut,
+        seeds = [seeds::KEEPER_REWARD_CONFIG],
+        bump = keeper_reward_config.bump
+    )]
+    pub keeper_reward_config: Box<Account<'info, KeeperRewardConfig>>,
+
+    /// The keeper's reward account holding the accrued rewards
+    #[account(
+        mut,
+        seeds = [seeds::KEEPER_REWARDS, keeper.key().as_ref()],
+        bump = keeper_rewards.bump,
+        has_one = keeper
+    )]
+    pub keeper_rewards: Box<Account<'info, KeeperRewards>>,
+
+    /// Program-derived authority that controls the keeper reward vault
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::KEEPER_REWARD_VAULT_AUTHORITY], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The ONyc mint rewards are paid in
+    #[account(address = state.onyc_mint)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Keeper reward vault serving as the source of the payout
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Keeper's token account receiving the payout
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        associated_token::mint = reward_mint,
+        associated_token::authority = keeper,
+        associated_token::token_program = token_program
+    )]
+    pub keeper_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The keeper claiming its rewards and paying for account creation
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Token program interface for transfer operations
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out all rewards accrued to a keeper
+///
+/// Transfers the keeper's accrued total from the keeper reward vault in a single
+/// payout, so cranks only have to update counters.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the rewards are paid out
+/// * `Err(ClaimKeeperRewardsErrorCode::NothingToClaim)` - If no rewards are accrued
+/// * `Err(ClaimKeeperRewardsErrorCode::KillSwitchActivated)` - If the kill switch is active
+///
+/// # Access Control
+/// - Only the keeper owning the reward account can claim
+///
+/// # Effects
+/// - Transfers the accrued rewards from the vault to the keeper
+/// - Resets the keeper's accrued rewards and increases its claimed total
+/// - Decreases the total unclaimed rewards in the configuration
+///
+/// # Events
+/// * `KeeperRewardsClaimedEvent` - Emitted with the payout amount
+pub fn claim_keeper_rewards(ctx: Context<ClaimKeeperRewards>) -> Result<()> {
+    let amount = ctx.accounts.keeper_rewards.accrued;
+    require!(amount > 0, ClaimKeeperRewardsErrorCode::NothingToClaim);
+
+    let vault_authority_seeds = &[
+        seeds::KEEPER_REWARD_VAULT_AUTHORITY,
+        &[ctx.bumps.vault_authority],
+    ];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.keeper_token_account,
+        &ctx.accounts.vault_authority.to_account_info(),
+        Some(signer_seeds),
+        amount,
+    )?;
+
+    let keeper_rewards = &mut ctx.accounts.keeper_rewards;
+    keeper_rewards.accrued = 0;
+    keeper_rewards.total_claimed = keeper_rewards
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(KeeperCoreError::OverflowError)?;
+
+    let config = &mut ctx.accounts.keeper_reward_config;
+    config.total_unclaimed = config.total_unclaimed.saturating_sub(amount);
+
+    msg!(
+        "Keeper rewards claimed: keeper={}, amount={}",
+        ctx.accounts.keeper.key(),
+        amount
+    );
+
+    emit!(KeeperRewardsClaimedEvent {
+        keeper: ctx.accounts.keeper.key(),
+        amount,
+        total_claimed: ctx.accounts.keeper_rewards.total_claimed,
+    });
+
+    Ok(())
+}
+
+/// Error codes for keeper reward claims
+#[error_code]
+pub enum ClaimKeeperRewardsErrorCode {
+    /// The keeper has no accrued rewards
+    #[msg("No keeper rewards to claim")]
+    NothingToClaim,
+    /// The kill switch is active
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+}
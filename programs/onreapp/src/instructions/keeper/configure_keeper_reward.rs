@@ -0,0 +1,102 @@
+use crate::constants::seeds;
+use crate::instructions::keeper::{KeeperCrank, KeeperRewardConfig};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the reward rate of a crank is configured
+///
+/// Provides transparency for tracking keeper incentive changes.
+#[event]
+pub struct KeeperRewardConfiguredEvent {
+    /// The crank whose rate was updated
+    pub crank: KeeperCrank,
+    /// Previous reward per unit of crank work in ONyc base units
+    pub old_reward_rate: u64,
+    /// New reward per unit of crank work in ONyc base units
+    pub new_reward_rate: u64,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for configuring the reward rate of a crank
+///
+/// The reward configuration account is created on first use, paid for by the boss.
+#[derive(Accounts)]
+pub struct ConfigureKeeperReward<'info> {
+    /// The keeper reward configuration holding the per-crank rates
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + KeeperRewardConfig::INIT_SPACE,
+        seeds = [seeds::KEEPER_REWARD_CONFIG],
+        bump
+    )]
+    pub keeper_reward_config: Box<Account<'info, KeeperRewardConfig>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to configure rewards and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the reward a crank accrues to its keeper per unit of work
+///
+/// Rewards accrue in the keeper's reward account and are paid out in ONyc from the
+/// keeper reward vault by `claim_keeper_rewards`, instead of inline on every crank.
+/// Changing a rate does not affect rewards already accrued.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `crank` - The crank whose rate is configured
+/// * `reward_rate` - Reward per unit of crank work in ONyc base units (0 = no reward)
+///
+/// # Returns
+/// * `Ok(())` - If the rate is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates the keeper reward configuration account if needed
+/// - Updates the crank's reward rate
+///
+/// # Events
+/// * `KeeperRewardConfiguredEvent` - Emitted with old and new rate
+pub fn configure_keeper_reward(
+    ctx: Context<ConfigureKeeperReward>,
+    crank: KeeperCrank,
+    reward_rate: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.keeper_reward_config;
+    config.bump = ctx.bumps.keeper_reward_config;
+
+    let old_reward_rate = config.reward_rate(crank);
+    config.reward_rates[crank as usize] = reward_rate;
+
+    msg!(
+        "Keeper reward configured: crank={:?}, old={}, new={}",
+        crank,
+        old_reward_rate,
+        reward_rate
+    );
+
+    emit!(KeeperRewardConfiguredEvent {
+        crank,
+        old_reward_rate,
+        new_reward_rate: reward_rate,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
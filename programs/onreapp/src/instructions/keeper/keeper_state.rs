@@ -0,0 +1,53 @@
+use crate::constants::MAX_KEEPER_CRANKS;
+use anchor_lang::prelude::*;
+
+/// Boss-configured reward rates of the permissionless cranks
+///
+/// Rewards are paid in the ONyc mint from the keeper reward vault. A rate of 0
+/// disables rewards for the crank.
+#[account]
+#[derive(InitSpace)]
+pub struct KeeperRewardConfig {
+    /// Reward in ONyc base units per unit of crank work, indexed by `KeeperCrank`
+    pub reward_rates: [u64; MAX_KEEPER_CRANKS],
+    /// Total rewards accrued by all keepers and not yet claimed
+    pub total_unclaimed: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+/// Rewards accrued by a single keeper
+#[account]
+#[derive(InitSpace)]
+pub struct KeeperRewards {
+    /// The keeper the rewards belong to
+    pub keeper: Pubkey,
+    /// Rewards accrued and not yet claimed, in ONyc base units
+    pub accrued: u64,
+    /// Cumulative rewards claimed over the account's lifetime
+    pub total_claimed: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+/// Permissionless cranks that accrue keeper rewards
+///
+/// The discriminant is the crank's index into `KeeperRewardConfig::reward_rates`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeeperCrank {
+    /// `record_nav_observation`, rewarded per recorded observation
+    RecordNavObservation,
+    /// `checkpoint_navs`, rewarded per checkpointed offer
+    CheckpointNavs,
+}
+
+impl KeeperRewardConfig {
+    /// Returns the reward rate of a crank
+    pub fn reward_rate(&self, crank: KeeperCrank) -> u64 {
+        self.reward_rates[crank as usize]
+    }
+}
@@ -0,0 +1,81 @@
+use crate::instructions::keeper::{KeeperCrank, KeeperRewardConfig, KeeperRewards};
+use crate::utils::EventEmitter;
+use anchor_lang::prelude::*;
+
+/// Common error codes for keeper reward operations
+#[error_code]
+pub enum KeeperCoreError {
+    /// Arithmetic overflow occurred during reward accounting
+    #[msg("Overflow error")]
+    OverflowError,
+}
+
+/// Event emitted when a crank accrues a reward to its keeper
+#[event]
+pub struct KeeperRewardAccruedEvent {
+    /// The keeper the reward was accrued to
+    pub keeper: Pubkey,
+    /// The crank that earned the reward
+    pub crank: KeeperCrank,
+    /// Units of crank work rewarded
+    pub units: u64,
+    /// Reward accrued in ONyc base units
+    pub reward: u64,
+    /// Keeper's unclaimed rewards after the accrual
+    pub accrued: u64,
+}
+
+/// Accrues the reward for a unit count of crank work to the keeper's reward account
+///
+/// Cranks pass the reward configuration and the keeper's reward account as
+/// optional accounts. Nothing is accrued when either is missing or the crank's
+/// rate is 0, so cranks keep working for keepers that do not collect rewards.
+///
+/// # Arguments
+/// * `events` - Emitter for the accrual event
+/// * `config` - The keeper reward configuration, if passed
+/// * `rewards` - The keeper's reward account, if passed
+/// * `crank` - The crank that performed the work
+/// * `units` - Units of work performed, e.g. the number of checkpointed offers
+///
+/// # Returns
+/// * `Ok(reward)` - The accrued reward in ONyc base units, 0 if nothing was accrued
+/// * `Err(KeeperCoreError::OverflowError)` - If a counter would overflow
+pub fn accrue_keeper_reward(
+    events: &EventEmitter,
+    config: Option<&mut KeeperRewardConfig>,
+    rewards: Option<&mut KeeperRewards>,
+    crank: KeeperCrank,
+    units: u64,
+) -> Result<u64> {
+    let (Some(config), Some(rewards)) = (config, rewards) else {
+        return Ok(0);
+    };
+
+    let reward = config
+        .reward_rate(crank)
+        .checked_mul(units)
+        .ok_or(KeeperCoreError::OverflowError)?;
+    if reward == 0 {
+        return Ok(0);
+    }
+
+    rewards.accrued = rewards
+        .accrued
+        .checked_add(reward)
+        .ok_or(KeeperCoreError::OverflowError)?;
+    config.total_unclaimed = config
+        .total_unclaimed
+        .checked_add(reward)
+        .ok_or(KeeperCoreError::OverflowError)?;
+
+    events.emit(KeeperRewardAccruedEvent {
+        keeper: rewards.keeper,
+        crank,
+        units,
+        reward,
+        accrued: rewards.accrued,
+    })?;
+
+    Ok(reward)
+}
@@ -0,0 +1,11 @@
+pub mod claim_keeper_rewards;
+pub mod configure_keeper_reward;
+pub mod keeper_state;
+pub mod keeper_utils;
+pub mod open_keeper_rewards;
+
+pub use claim_keeper_rewards::*;
+pub use configure_keeper_reward::*;
+pub use keeper_state::*;
+pub use keeper_utils::*;
+pub use open_keeper_rewards::*;
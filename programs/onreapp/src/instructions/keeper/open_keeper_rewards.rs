@@ -0,0 +1,73 @@
+use crate::constants::seeds;
+use crate::instructions::keeper::KeeperRewards;
+use anchor_lang::prelude::*;
+
+/// Event emitted when a keeper opens its reward account
+#[event]
+pub struct KeeperRewardsOpenedEvent {
+    /// The PDA address of the reward account
+    pub keeper_rewards_pda: Pubkey,
+    /// The keeper the account belongs to
+    pub keeper: Pubkey,
+}
+
+/// Account structure for opening a keeper's reward account
+#[derive(Accounts)]
+pub struct OpenKeeperRewards<'info> {
+    /// The keeper's reward account
+    ///
+    /// Derived from the keeper address ensuring one reward account per keeper.
+    #[account(
+        init,
+        payer = keeper,
+        space = 8 + KeeperRewards::INIT_SPACE,
+        seeds = [seeds::KEEPER_REWARDS, keeper.key().as_ref()],
+        bump
+    )]
+    pub keeper_rewards: Box<Account<'info, KeeperRewards>>,
+
+    /// The keeper opening the account and paying for its creation
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the reward account that cranks accrue a keeper's rewards into
+///
+/// Keepers pass the account, together with the reward configuration, to the
+/// permissionless cranks to accrue rewards for their work.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the account is created
+///
+/// # Access Control
+/// - Permissionless: any keeper may open its own reward account
+///
+/// # Effects
+/// - Creates the keeper's reward account
+///
+/// # Events
+/// * `KeeperRewardsOpenedEvent` - Emitted with the account and keeper
+pub fn open_keeper_rewards(ctx: Context<OpenKeeperRewards>) -> Result<()> {
+    let keeper_rewards = &mut ctx.accounts.keeper_rewards;
+    keeper_rewards.keeper = ctx.accounts.keeper.key();
+    keeper_rewards.bump = ctx.bumps.keeper_rewards;
+
+    msg!(
+        "Keeper rewards opened at: {} for keeper: {}",
+        ctx.accounts.keeper_rewards.key(),
+        ctx.accounts.keeper.key()
+    );
+
+    emit!(KeeperRewardsOpenedEvent {
+        keeper_rewards_pda: ctx.accounts.keeper_rewards.key(),
+        keeper: ctx.accounts.keeper.key(),
+    });
+
+    Ok(())
+}
@@ -14,9 +14,9 @@ const EXT_SCALE: u128 = 1_000_000;
 /// Used during intermediate calculations to maintain precision
 const INT_SCALE: u128 = 1_000_000_000_000_000_000;
 
-/// Number of compounding periods per year for daily compounding
+/// Default number of compounding periods per year (daily compounding)
 /// Standard financial calculation uses 365 days per year
-const N: u128 = 365;
+pub const DEFAULT_COMPOUNDING_PERIODS_PER_YEAR: u32 = 365;
 
 /// Error codes for APY calculation operations
 #[error_code]
@@ -27,6 +27,9 @@ pub enum GetAPYErrorCode {
     /// Division by zero in fixed-point arithmetic
     #[msg("Division by zero")]
     DivByZero,
+    /// Requested compounding frequency is zero
+    #[msg("Compounding periods per year must be greater than zero")]
+    InvalidCompoundingPeriods,
 }
 
 /// Event emitted when APY calculation is successfully completed
@@ -37,10 +40,12 @@ pub enum GetAPYErrorCode {
 pub struct GetAPYEvent {
     /// The PDA address of the offer for which APY was calculated
     pub offer_pda: Pubkey,
-    /// Calculated Annual Percentage Yield with scale=6 (1_000_000 = 100%)
-    pub apy: u64,
-    /// Source Annual Percentage Rate with scale=6 used for calculation
-    pub apr: u64,
+    /// Calculated signed Annual Percentage Yield with scale=6 (1_000_000 = 100%)
+    pub apy: i64,
+    /// Source signed Annual Percentage Rate with scale=6 used for calculation
+    pub apr: i64,
+    /// Number of compounding periods per year the APY was calculated with
+    pub compounding_periods_per_year: u32,
     /// Unix timestamp when the APY calculation was performed
     pub timestamp: u64,
 }
@@ -97,18 +102,21 @@ pub struct GetAPY<'info> {
 /// This is a read-only instruction that queries the current APY for an offer by:
 /// 1. Finding the currently active pricing vector based on the current timestamp
 /// 2. Extracting the APR from the active vector
-/// 3. Converting APR to APY using daily compounding mathematics
+/// 3. Converting APR to APY compounded at the requested frequency
 /// 4. Returning the calculated APY with the same scale as the input APR
 ///
-/// The calculation uses the standard financial formula: APY = (1 + APR/365)^365 - 1
-/// with daily compounding (365 periods per year). This provides a more accurate
-/// representation of the actual annual yield compared to simple APR.
+/// The calculation uses the standard financial formula: APY = (1 + APR/n)^n - 1
+/// with `n` compounding periods per year, daily compounding (365) by default. This
+/// provides a more accurate representation of the actual annual yield compared to
+/// simple APR. Frontends displaying other frequencies pass `n` explicitly, e.g. 52
+/// for weekly compounding; a large `n` such as the seconds in a year
+/// (31_536_000) approximates continuous compounding, APY = e^APR - 1.
 ///
 /// # Process Flow
 /// 1. Load the offer account and get current timestamp
 /// 2. Identify the active pricing vector for the current time
 /// 3. Extract APR from the active vector (scale=6)
-/// 4. Apply daily compounding formula to convert APR to APY
+/// 4. Apply the compounding formula to convert APR to APY
 /// 5. Return APY with scale=6 (same as APR)
 /// 6. Emit event with calculation details
 ///
@@ -119,30 +127,36 @@ pub struct GetAPY<'info> {
 ///
 /// # Arguments
 /// * `ctx` - The instruction context containing validated accounts
+/// * `compounding_periods_per_year` - Compounding periods per year, 365 if not given
 ///
 /// # Returns
-/// * `Ok(apy)` - The calculated APY with scale=6 (1_000_000 = 100%)
+/// * `Ok(apy)` - The calculated signed APY with scale=6 (1_000_000 = 100%), negative for a declining NAV
 /// * `Err(OfferCoreError::NoActiveVector)` - If no pricing vector is currently active
+/// * `Err(GetAPYErrorCode::InvalidCompoundingPeriods)` - If zero periods are requested
 /// * `Err(GetAPYErrorCode::Overflow)` - If mathematical overflow occurs during calculation
 /// * `Err(GetAPYErrorCode::DivByZero)` - If division by zero occurs during calculation
 ///
 /// # Events
-/// * `GetAPYEvent` - Emitted on successful calculation containing offer PDA, APY, source APR, and timestamp
-pub fn get_apy(ctx: Context<GetAPY>) -> Result<u64> {
+/// * `GetAPYEvent` - Emitted on successful calculation containing offer PDA, APY, source APR,
+///   compounding frequency, and timestamp
+pub fn get_apy(ctx: Context<GetAPY>, compounding_periods_per_year: Option<u32>) -> Result<i64> {
     let offer = ctx.accounts.offer.load()?;
     let current_time = Clock::get()?.unix_timestamp as u64;
+    let compounding_periods_per_year =
+        compounding_periods_per_year.unwrap_or(DEFAULT_COMPOUNDING_PERIODS_PER_YEAR);
 
     // Find the currently active pricing vector
     let active_vector = find_active_vector_at(&offer, current_time)?;
 
     // Calculate APY from the vector's APR
-    let apy = calculate_apy_from_apr(active_vector.apr)?;
+    let apy = calculate_apy_from_apr(active_vector.apr, compounding_periods_per_year)?;
 
     msg!(
-        "APY Info - Offer PDA: {}, APR: {}, APY: {}, Timestamp: {}",
+        "APY Info - Offer PDA: {}, APR: {}, APY: {}, Periods: {}, Timestamp: {}",
         ctx.accounts.offer.key(),
         active_vector.apr,
         apy,
+        compounding_periods_per_year,
         current_time
     );
 
@@ -150,21 +164,22 @@ pub fn get_apy(ctx: Context<GetAPY>) -> Result<u64> {
         offer_pda: ctx.accounts.offer.key(),
         apy,
         apr: active_vector.apr,
+        compounding_periods_per_year,
         timestamp: current_time,
     });
 
     Ok(apy)
 }
 
-/// Converts Annual Percentage Rate (APR) to Annual Percentage Yield (APY) using daily compounding
+/// Converts Annual Percentage Rate (APR) to Annual Percentage Yield (APY)
 ///
 /// This function implements the standard financial formula for converting APR to APY
-/// with daily compounding (365 periods per year). The calculation uses high-precision
+/// with `n` compounding periods per year. The calculation uses high-precision
 /// fixed-point arithmetic to maintain accuracy across the full range of input values.
 ///
 /// # Mathematical Formula
-/// ```
-/// APY = (1 + APR/365)^365 - 1
+/// ```text
+/// APY = (1 + APR/n)^n - 1
 /// ```
 ///
 /// # Implementation Details
@@ -172,12 +187,15 @@ pub fn get_apy(ctx: Context<GetAPY>) -> Result<u64> {
 /// - Applies proper rounding for the final result
 /// - Employs exponentiation by squaring for efficient power calculation
 /// - All operations are checked for overflow protection
+/// - Negative APRs compound downwards: APY = (1 - |APR|/n)^n - 1
 ///
 /// # Arguments
-/// * `apr_scaled` - Annual Percentage Rate with scale=6 (1_000_000 = 100%)
+/// * `apr_scaled` - Signed Annual Percentage Rate with scale=6 (1_000_000 = 100%)
+/// * `compounding_periods_per_year` - Number of compounding periods `n` per year
 ///
 /// # Returns
-/// * `Ok(apy)` - Annual Percentage Yield with scale=6 (same scaling as input)
+/// * `Ok(apy)` - Signed Annual Percentage Yield with scale=6 (same scaling as input)
+/// * `Err(GetAPYErrorCode::InvalidCompoundingPeriods)` - If `n` is zero
 /// * `Err(GetAPYErrorCode::Overflow)` - If mathematical overflow occurs
 /// * `Err(GetAPYErrorCode::DivByZero)` - If division by zero occurs
 ///
@@ -186,15 +204,20 @@ pub fn get_apy(ctx: Context<GetAPY>) -> Result<u64> {
 /// - 10_000 = 1%
 /// - 100_000 = 10%
 /// - 1_000_000 = 100%
-pub fn calculate_apy_from_apr(apr_scaled: u64) -> Result<u64> {
-    let apr = apr_scaled as u128;
+pub fn calculate_apy_from_apr(apr_scaled: i64, compounding_periods_per_year: u32) -> Result<i64> {
+    require!(
+        compounding_periods_per_year > 0,
+        GetAPYErrorCode::InvalidCompoundingPeriods
+    );
+    let apr = apr_scaled.unsigned_abs() as u128;
+    let n = compounding_periods_per_year as u128;
 
-    // incr = INT_SCALE * (apr / EXT_SCALE) / N
+    // incr = INT_SCALE * (apr / EXT_SCALE) / n
     let num = INT_SCALE
         .checked_mul(apr)
         .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
     let den = EXT_SCALE
-        .checked_mul(N as u128)
+        .checked_mul(n)
         .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
     let incr = num
         .checked_add(den / 2)
@@ -202,26 +225,32 @@ pub fn calculate_apy_from_apr(apr_scaled: u64) -> Result<u64> {
         .checked_div(den)
         .ok_or_else(|| error!(GetAPYErrorCode::DivByZero))?;
 
-    let base = INT_SCALE
-        .checked_add(incr)
-        .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
+    let base = if apr_scaled >= 0 {
+        INT_SCALE.checked_add(incr)
+    } else {
+        INT_SCALE.checked_sub(incr)
+    }
+    .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
 
-    // (1 + r/n)^n at 1e18 precision
-    let pow = pow_fixed(base, N as u32, INT_SCALE)?;
+    // (1 +/- r/n)^n at 1e18 precision
+    let pow = pow_fixed(base, compounding_periods_per_year, INT_SCALE)?;
 
-    // APY_int = pow - 1.0
-    let apy_int = pow
-        .checked_sub(INT_SCALE)
-        .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
+    // |APY_int| = |pow - 1.0|, negative when the price declines
+    let (apy_int, is_negative) = if pow >= INT_SCALE {
+        (pow - INT_SCALE, false)
+    } else {
+        (INT_SCALE - pow, true)
+    };
 
     // Convert back to 1e6 scale with rounding: apy_scaled = round(apy_int * EXT_SCALE / INT_SCALE)
     let apy_scaled_u128 = mul_div_round(apy_int, EXT_SCALE, INT_SCALE)?;
 
-    if apy_scaled_u128 > u64::MAX as u128 {
+    if apy_scaled_u128 > i64::MAX as u128 {
         return Err(error!(GetAPYErrorCode::Overflow));
     }
 
-    Ok(apy_scaled_u128 as u64)
+    let apy_scaled = apy_scaled_u128 as i64;
+    Ok(if is_negative { -apy_scaled } else { apy_scaled })
 }
 
 /// Performs multiplication followed by division with proper rounding
@@ -246,9 +275,8 @@ fn mul_div_round(a: u128, b: u128, denom: u128) -> Result<u128> {
     let adj = prod
         .checked_add(denom / 2)
         .ok_or_else(|| error!(GetAPYErrorCode::Overflow))?;
-    Ok(adj
-        .checked_div(denom)
-        .ok_or_else(|| error!(GetAPYErrorCode::DivByZero))?)
+    adj.checked_div(denom)
+        .ok_or_else(|| error!(GetAPYErrorCode::DivByZero))
 }
 
 /// Computes fixed-point exponentiation using the binary exponentiation algorithm
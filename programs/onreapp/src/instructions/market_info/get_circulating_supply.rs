@@ -1,7 +1,8 @@
 use crate::constants::seeds;
+use crate::instructions::market_info::get_tvl::read_own_vault_amounts;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -9,9 +10,13 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 /// Error codes for circulating supply calculation operations
 #[error_code]
 pub enum GetCirculatingSupplyErrorCode {
-    /// The vault account address doesn't match the expected ATA address
+    /// A vault account address doesn't match the expected ATA address, or a per-offer
+    /// vault is passed twice
     #[msg("Invalid token_out vault account")]
     InvalidVaultAccount,
+    /// Mathematical overflow while summing vault holdings
+    #[msg("Math overflow")]
+    Overflow,
 }
 
 /// Event emitted when circulating supply calculation is completed
@@ -23,8 +28,11 @@ pub struct GetCirculatingSupplyEvent {
     pub circulating_supply: u64,
     /// Total token supply from the mint account in base units
     pub total_supply: u64,
-    /// Vault token amount excluded from circulation in base units
+    /// ONyc held by the offer vaults, including the passed per-offer vaults, and the
+    /// redemption vault, excluded from circulation in base units
     pub vault_amount: u64,
+    /// Part of `vault_amount` held by the redemption vault in base units
+    pub redemption_vault_amount: u64,
     /// Unix timestamp when the calculation was performed
     pub timestamp: u64,
 }
@@ -32,23 +40,30 @@ pub struct GetCirculatingSupplyEvent {
 /// Account structure for querying circulating supply information
 ///
 /// This struct defines the accounts required to calculate the circulating supply
-/// of ONyc tokens by subtracting vault holdings from total supply. All accounts
-/// are validated to ensure accurate calculation.
+/// of ONyc tokens by subtracting the offer and redemption vault holdings from total
+/// supply. All accounts are validated to ensure accurate calculation. The ONyc
+/// accounts of per-offer vaults are passed as remaining accounts, two per offer: the
+/// offer and its own vault's ONyc account.
 #[derive(Accounts)]
 pub struct GetCirculatingSupply<'info> {
     /// The ONyc token mint containing total supply information
     pub onyc_mint: InterfaceAccount<'info, Mint>,
 
     /// Program state account containing the ONyc mint reference
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = onyc_mint)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = onyc_mint,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Box<Account<'info, State>>,
 
-    /// The vault authority PDA that controls vault token accounts
+    /// The shared offer vault authority PDA
     /// CHECK: PDA derivation is validated by seeds constraint
     #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
     pub vault_authority: UncheckedAccount<'info>,
 
-    /// The vault's ONyc token account to exclude from circulating supply
+    /// The shared offer vault's ONyc token account to exclude from circulating supply
     ///
     /// This account holds tokens that are not considered in circulation.
     /// The account address is validated to match the expected ATA address
@@ -64,6 +79,25 @@ pub struct GetCirculatingSupply<'info> {
     )]
     pub onyc_vault_account: UncheckedAccount<'info>,
 
+    /// The redemption vault authority PDA
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The redemption vault's ONyc account to exclude from circulating supply
+    ///
+    /// Holds ONyc locked in pending redemption requests and collected fees.
+    /// CHECK: Account address is validated by the constraint below to allow passing uninitialized vault account
+    #[account(
+        constraint = redemption_vault_onyc_account.key()
+            == get_associated_token_address_with_program_id(
+                &redemption_vault_authority.key(),
+                &state.onyc_mint.key(),
+                &token_program.key(),
+            ) @ GetCirculatingSupplyErrorCode::InvalidVaultAccount
+    )]
+    pub redemption_vault_onyc_account: UncheckedAccount<'info>,
+
     /// SPL Token program for account validation
     pub token_program: Interface<'info, TokenInterface>,
 }
@@ -72,48 +106,72 @@ pub struct GetCirculatingSupply<'info> {
 ///
 /// This read-only instruction calculates the circulating supply by subtracting
 /// vault holdings from the total token supply. The vault amount represents tokens
-/// held by the program that are not considered in active circulation.
+/// held by the program that are not considered in active circulation: offer vault
+/// inventory and ONyc locked in the redemption vault. Offers served by their own
+/// vault hold their ONyc outside the shared offer vault, so every such offer must be
+/// passed with its ONyc vault account for its inventory to be excluded.
 ///
-/// Formula: `circulating_supply = total_supply - vault_amount`
+/// Formula: `circulating_supply = total_supply - offer_vaults - redemption_vault`
 ///
-/// The vault account can be uninitialized (treated as zero balance) or contain
+/// The vault accounts can be uninitialized (treated as zero balance) or contain
 /// tokens that should be excluded from circulation calculations.
 ///
 /// # Arguments
-/// * `ctx` - The instruction context containing validated accounts
+/// * `ctx` - The instruction context containing validated accounts and the per-offer
+///   vault accounts as remaining accounts
 ///
 /// # Returns
 /// * `Ok(circulating_supply)` - The calculated circulating supply in base units
 /// * `Err(GetCirculatingSupplyErrorCode::InvalidVaultAccount)` - If vault account validation fails
+/// * `Err(GetCirculatingSupplyErrorCode::Overflow)` - If the vault holdings overflow when summed
 ///
 /// # Events
 /// * `GetCirculatingSupplyEvent` - Emitted with calculation details and timestamp
-pub fn get_circulating_supply(ctx: Context<GetCirculatingSupply>) -> Result<u64> {
+pub fn get_circulating_supply<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetCirculatingSupply<'info>>,
+) -> Result<u64> {
     let current_time = Clock::get()?.unix_timestamp as u64;
 
-    let vault_token_out_amount = read_optional_ata_amount(
+    let offer_vault_amount = read_optional_ata_amount(
         &ctx.accounts.onyc_vault_account,
         &ctx.accounts.token_program,
     )?;
+    let own_vault_amount = read_own_vault_amounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.onyc_mint.key(),
+        &ctx.accounts.token_program,
+        ctx.program_id,
+    )
+    .ok_or(GetCirculatingSupplyErrorCode::InvalidVaultAccount)?;
+    let redemption_vault_amount = read_optional_ata_amount(
+        &ctx.accounts.redemption_vault_onyc_account,
+        &ctx.accounts.token_program,
+    )?;
+    let vault_amount = offer_vault_amount
+        .checked_add(own_vault_amount)
+        .and_then(|amount| amount.checked_add(redemption_vault_amount))
+        .ok_or(GetCirculatingSupplyErrorCode::Overflow)?;
 
     // Get total supply
     let total_supply = ctx.accounts.onyc_mint.supply;
 
     // Calculate circulating supply = total supply - vault amount
-    let circulating_supply = total_supply - vault_token_out_amount;
+    let circulating_supply = total_supply.saturating_sub(vault_amount);
 
     msg!(
-        "Circulating Supply Info - Circulating Supply: {}, Total Supply: {}, Vault Amount: {}, Timestamp: {}",
+        "Circulating Supply Info - Circulating Supply: {}, Total Supply: {}, Vault Amount: {}, Redemption Vault Amount: {}, Timestamp: {}",
         circulating_supply,
         total_supply,
-        vault_token_out_amount,
+        vault_amount,
+        redemption_vault_amount,
         current_time
     );
 
     emit!(GetCirculatingSupplyEvent {
         circulating_supply,
         total_supply,
-        vault_amount: vault_token_out_amount,
+        vault_amount,
+        redemption_vault_amount,
         timestamp: current_time,
     });
 
@@ -132,7 +190,7 @@ pub fn get_circulating_supply(ctx: Context<GetCirculatingSupply>) -> Result<u64>
 ///
 /// # Returns
 /// * `Ok(amount)` - Token amount if account is initialized, 0 otherwise
-fn read_optional_ata_amount<'info>(
+fn read_optional_ata_amount(
     vault_account: &AccountInfo,
     token_program: &Interface<TokenInterface>,
 ) -> Result<u64> {
@@ -0,0 +1,205 @@
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::market_info::get_tvl::{read_optional_ata_amount, read_own_vault_amounts};
+use crate::instructions::offer::offer_utils::{
+    calculate_current_vector_price, find_active_vector_at,
+};
+use crate::instructions::Offer;
+use crate::state::{Config, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+/// Error codes for global TVL calculation operations
+#[error_code]
+pub enum GetGlobalTVLErrorCode {
+    /// No primary offer has been designated in program state
+    #[msg("Primary offer not set")]
+    PrimaryOfferNotSet,
+    /// The offer account is not the designated primary offer
+    #[msg("Offer is not the primary offer")]
+    NotPrimaryOffer,
+    /// A vault account address doesn't match the expected ATA address, or a per-offer
+    /// vault is passed twice
+    #[msg("Invalid ONyc vault account")]
+    InvalidVaultAccount,
+    /// Mathematical overflow during TVL calculations
+    #[msg("Math overflow")]
+    Overflow,
+}
+
+/// Event emitted when the global TVL calculation is completed
+///
+/// Provides transparency for tracking protocol-wide value metrics.
+#[event]
+pub struct GetGlobalTVLEvent {
+    /// The primary offer whose NAV priced the supply
+    pub primary_offer: Pubkey,
+    /// Calculated global TVL in base units (circulating_supply * nav / 10^9)
+    pub tvl: u128,
+    /// Current NAV of the primary offer with scale=9
+    pub nav: u64,
+    /// Total ONyc supply from the mint account in base units
+    pub total_supply: u64,
+    /// ONyc held by the offer vaults, including the passed per-offer vaults, and the
+    /// redemption vault, excluded from the TVL
+    pub vault_amount: u64,
+    /// Unix timestamp when the calculation was performed
+    pub timestamp: u64,
+}
+
+/// Account structure for querying the global TVL
+///
+/// The calculation is read-only. Both vault accounts may be uninitialized, in
+/// which case they are treated as zero balance. The ONyc accounts of per-offer vaults
+/// are passed as remaining accounts, two per offer: the offer and its own vault's ONyc
+/// account.
+#[derive(Accounts)]
+pub struct GetGlobalTVL<'info> {
+    /// Program state account containing the ONyc mint and primary offer references
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = onyc_mint,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The ONyc token mint containing total supply information
+    pub onyc_mint: InterfaceAccount<'info, Mint>,
+
+    /// The primary offer whose current NAV prices the supply
+    #[account(
+        constraint = state.primary_offer != Pubkey::default()
+            @ GetGlobalTVLErrorCode::PrimaryOfferNotSet,
+        constraint = offer.key() == state.primary_offer
+            @ GetGlobalTVLErrorCode::NotPrimaryOffer
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The shared offer vault authority PDA
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    pub offer_vault_authority: UncheckedAccount<'info>,
+
+    /// The shared offer vault's ONyc account to exclude from the TVL
+    /// CHECK: Account address is validated by the constraint below to allow passing uninitialized vault account
+    #[account(
+        constraint = offer_vault_onyc_account.key()
+            == get_associated_token_address_with_program_id(
+                &offer_vault_authority.key(),
+                &onyc_mint.key(),
+                &token_program.key(),
+            ) @ GetGlobalTVLErrorCode::InvalidVaultAccount
+    )]
+    pub offer_vault_onyc_account: UncheckedAccount<'info>,
+
+    /// The redemption vault authority PDA
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The redemption vault's ONyc account to exclude from the TVL
+    ///
+    /// Holds ONyc locked in pending redemption requests and collected fees.
+    /// CHECK: Account address is validated by the constraint below to allow passing uninitialized vault account
+    #[account(
+        constraint = redemption_vault_onyc_account.key()
+            == get_associated_token_address_with_program_id(
+                &redemption_vault_authority.key(),
+                &onyc_mint.key(),
+                &token_program.key(),
+            ) @ GetGlobalTVLErrorCode::InvalidVaultAccount
+    )]
+    pub redemption_vault_onyc_account: UncheckedAccount<'info>,
+
+    /// SPL Token program for vault account validation
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Calculates and returns the TVL of the whole ONyc supply
+///
+/// Unlike `get_tvl`, which values the token_out supply of a single offer, this
+/// values all ONyc outside program-held vaults at the NAV of the primary offer
+/// designated in program state. The result is returned as u128 so large supplies
+/// with high NAVs cannot overflow. Offers served by their own vault hold their ONyc
+/// outside the shared offer vault, so every such offer must be passed with its ONyc
+/// vault account for its inventory to be excluded.
+///
+/// Formula: `TVL = (total_supply - offer_vaults - redemption_vault) * nav / 10^9`
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts and the per-offer
+///   vault accounts as remaining accounts
+///
+/// # Returns
+/// * `Ok(tvl)` - The calculated global TVL in base units
+/// * `Err(GetGlobalTVLErrorCode::PrimaryOfferNotSet)` - If no primary offer is designated
+/// * `Err(GetGlobalTVLErrorCode::NotPrimaryOffer)` - If the offer is not the primary offer
+/// * `Err(GetGlobalTVLErrorCode::InvalidVaultAccount)` - If the per-offer vault accounts are malformed
+/// * `Err(OfferCoreError::NoActiveVector)` - If the primary offer has no active vector
+///
+/// # Events
+/// * `GetGlobalTVLEvent` - Emitted with TVL, NAV, supply and vault holdings
+pub fn get_global_tvl<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetGlobalTVL<'info>>,
+) -> Result<u128> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let active_vector = find_active_vector_at(&offer, current_time)?;
+    let nav = calculate_current_vector_price(&active_vector, ctx.accounts.config.seconds_per_year)?;
+
+    let offer_vault_amount = read_optional_ata_amount(
+        &ctx.accounts.offer_vault_onyc_account,
+        &ctx.accounts.token_program,
+    )?;
+    let own_vault_amount = read_own_vault_amounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.onyc_mint.key(),
+        &ctx.accounts.token_program,
+        ctx.program_id,
+    )
+    .ok_or(GetGlobalTVLErrorCode::InvalidVaultAccount)?;
+    let redemption_vault_amount = read_optional_ata_amount(
+        &ctx.accounts.redemption_vault_onyc_account,
+        &ctx.accounts.token_program,
+    )?;
+    let vault_amount = offer_vault_amount
+        .checked_add(own_vault_amount)
+        .and_then(|amount| amount.checked_add(redemption_vault_amount))
+        .ok_or(GetGlobalTVLErrorCode::Overflow)?;
+
+    let total_supply = ctx.accounts.onyc_mint.supply;
+    let circulating_supply = total_supply.saturating_sub(vault_amount);
+
+    let tvl = (circulating_supply as u128)
+        .checked_mul(nav as u128)
+        .ok_or(GetGlobalTVLErrorCode::Overflow)?
+        / 10_u128.pow(PRICE_DECIMALS as u32);
+
+    msg!(
+        "Global TVL Info - Primary Offer: {}, TVL: {}, NAV: {}, Total Supply: {}, Vault Amount: {}, Timestamp: {}",
+        ctx.accounts.offer.key(),
+        tvl,
+        nav,
+        total_supply,
+        vault_amount,
+        current_time
+    );
+
+    emit!(GetGlobalTVLEvent {
+        primary_offer: ctx.accounts.offer.key(),
+        tvl,
+        nav,
+        total_supply,
+        vault_amount,
+        timestamp: current_time,
+    });
+
+    Ok(tvl)
+}
@@ -1,8 +1,7 @@
 use crate::constants::seeds;
-use crate::instructions::offer::offer_utils::{
-    calculate_current_step_price, find_active_vector_at,
-};
-use crate::instructions::{Offer, OfferVector};
+use crate::instructions::Offer;
+use crate::pricing::{nav_at, next_nav_change_at};
+use crate::state::Config;
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
@@ -44,6 +43,10 @@ pub struct GetNAV<'info> {
     )]
     pub offer: AccountLoader<'info, Offer>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The input token mint account for offer validation
     #[account(
         constraint =
@@ -83,37 +86,8 @@ pub fn get_nav(ctx: Context<GetNAV>) -> Result<u64> {
     let offer = ctx.accounts.offer.load()?;
     let current_time = Clock::get()?.unix_timestamp as u64;
 
-    // Find the currently active pricing vector
-    let active_vector = find_active_vector_at(&offer, current_time)?;
-
-    // Calculate current price with 9 decimals
-    let current_price = calculate_current_step_price(
-        active_vector.apr,
-        active_vector.base_price,
-        active_vector.base_time,
-        active_vector.price_fix_duration,
-    )?;
-
-    // Calculate when the next NAV change will occur
-    let elapsed_since_base = current_time.saturating_sub(active_vector.base_time);
-    let current_step = elapsed_since_base / active_vector.price_fix_duration;
-    let next_interval_timestamp = active_vector
-        .base_time
-        .checked_add(
-            (current_step + 1)
-                .checked_mul(active_vector.price_fix_duration)
-                .ok_or(OfferCoreError::OverflowError)?,
-        )
-        .ok_or(OfferCoreError::OverflowError)?;
-
-    // Find the next vector that will become active
-    let next_vector = find_next_vector_after(&offer, current_time);
-
-    // Next NAV change is the minimum of next interval and next vector start time
-    let next_price_change_timestamp = match next_vector {
-        Some(vector) => next_interval_timestamp.min(vector.start_time),
-        None => next_interval_timestamp,
-    };
+    let current_price = nav_at(&offer, current_time, ctx.accounts.config.seconds_per_year)?;
+    let next_price_change_timestamp = next_nav_change_at(&offer, current_time)?;
 
     msg!(
         "NAV Info - Offer PDA: {}, Current Timestamp: {}, Current Price: {}, Next Change: {}",
@@ -132,24 +106,3 @@ pub fn get_nav(ctx: Context<GetNAV>) -> Result<u64> {
 
     Ok(current_price)
 }
-
-/// Finds the next vector that will become active after the current time
-///
-/// Searches through all vectors to find the one with the smallest start_time
-/// that is still in the future (greater than current_time).
-///
-/// # Arguments
-/// * `offer` - The offer containing pricing vectors to search
-/// * `current_time` - The current Unix timestamp
-///
-/// # Returns
-/// * `Some(OfferVector)` - The next future vector if one exists
-/// * `None` - If no vectors are scheduled in the future
-fn find_next_vector_after(offer: &Offer, current_time: u64) -> Option<OfferVector> {
-    offer
-        .vectors
-        .iter()
-        .filter(|vector| vector.start_time > current_time)
-        .min_by_key(|vector| vector.start_time)
-        .copied()
-}
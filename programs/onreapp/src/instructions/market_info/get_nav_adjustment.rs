@@ -1,6 +1,7 @@
 use crate::constants::seeds;
 use crate::instructions::offer::offer_utils::find_active_vector_at;
-use crate::instructions::{calculate_step_price_at, Offer};
+use crate::instructions::{calculate_vector_price_at, Offer};
+use crate::state::Config;
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
@@ -44,6 +45,10 @@ pub struct GetNavAdjustment<'info> {
     )]
     pub offer: AccountLoader<'info, Offer>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The input token mint account for offer validation
     #[account(
         constraint =
@@ -114,24 +119,20 @@ pub fn get_nav_adjustment(ctx: Context<GetNavAdjustment>) -> Result<i64> {
     let active_vector = find_active_vector_at(&offer, current_time)?;
 
     // Calculate price at the start of the active vector
-    let current_price = calculate_step_price_at(
-        active_vector.apr,
-        active_vector.base_price,
-        active_vector.base_time,
-        active_vector.price_fix_duration,
+    let current_price = calculate_vector_price_at(
+        &active_vector,
         active_vector.start_time,
+        ctx.accounts.config.seconds_per_year,
     )?;
 
     // Find the previous vector and calculate its price
     let (previous_price_opt, adjustment) =
         if let Some(previous_vector) = find_previous_vector(&offer, active_vector.start_time) {
             // Calculate the price of the previous vector at its end time (when current vector starts)
-            let previous_price = calculate_step_price_at(
-                previous_vector.apr,
-                previous_vector.base_price,
-                previous_vector.base_time,
-                previous_vector.price_fix_duration,
+            let previous_price = calculate_vector_price_at(
+                &previous_vector,
                 active_vector.start_time, // End time of previous vector
+                ctx.accounts.config.seconds_per_year,
             )?;
 
             // Calculate adjustment: current - previous
@@ -0,0 +1,101 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::pricing::nav_at;
+use crate::state::Config;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when the NAV of an offer at a given timestamp is queried
+///
+/// Provides transparency for tracking historical and forward pricing lookups.
+#[event]
+pub struct GetNAVAtEvent {
+    /// The PDA address of the offer for which NAV was calculated
+    pub offer_pda: Pubkey,
+    /// Price at `nav_timestamp` with 9 decimal precision (scale=9)
+    pub price: u64,
+    /// Unix timestamp the price was evaluated at
+    pub nav_timestamp: u64,
+    /// Unix timestamp when the query was performed
+    pub timestamp: u64,
+}
+
+/// Account structure for querying the NAV of an offer at a given timestamp
+///
+/// Mirrors the accounts of `GetNAV`; the calculation is read-only and validates
+/// all accounts belong to the same offer.
+#[derive(Accounts)]
+pub struct GetNAVAt<'info> {
+    /// The offer account containing pricing vectors and configuration
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Calculates and returns the NAV of an offer at an arbitrary past or future timestamp
+///
+/// Evaluates the stepped pricing of the current vector set at `unix_ts`, exactly as
+/// `get_nav` would if the clock read `unix_ts`. Vectors that were deleted or cleaned
+/// up since are not taken into account, so historical results only match the NAV a
+/// trade was priced at while the vectors covering it are still stored.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `unix_ts` - Unix timestamp to evaluate the NAV at
+///
+/// # Returns
+/// * `Ok(price)` - The price at `unix_ts` with scale=9 (1_000_000_000 = 1.0)
+/// * `Err(OfferCoreError::NoActiveVector)` - If no pricing vector covers `unix_ts`
+///
+/// # Events
+/// * `GetNAVAtEvent` - Emitted with offer PDA, price, queried and current timestamp
+pub fn get_nav_at(ctx: Context<GetNAVAt>, unix_ts: u64) -> Result<u64> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let price = nav_at(&offer, unix_ts, ctx.accounts.config.seconds_per_year)?;
+
+    msg!(
+        "NAV At Info - Offer PDA: {}, NAV Timestamp: {}, Price: {}",
+        ctx.accounts.offer.key(),
+        unix_ts,
+        price
+    );
+
+    emit!(GetNAVAtEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        price,
+        nav_timestamp: unix_ts,
+        timestamp: current_time,
+    });
+
+    Ok(price)
+}
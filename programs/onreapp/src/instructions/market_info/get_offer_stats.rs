@@ -0,0 +1,98 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::token_interface::Mint;
+
+/// Cumulative take counters of an offer returned by `get_offer_stats`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OfferStats {
+    /// Cumulative token_in fees collected by takes
+    pub total_fees_collected_token_in: u128,
+    /// Cumulative token_in paid by takes, including fees
+    pub total_volume_token_in: u128,
+}
+
+/// Event emitted when offer statistics are queried
+///
+/// Provides transparency for tracking revenue reporting queries.
+#[event]
+pub struct GetOfferStatsEvent {
+    /// The PDA address of the queried offer
+    pub offer_pda: Pubkey,
+    /// Cumulative token_in fees collected by takes
+    pub total_fees_collected_token_in: u128,
+    /// Cumulative token_in paid by takes, including fees
+    pub total_volume_token_in: u128,
+}
+
+/// Account structure for querying the cumulative take counters of an offer
+///
+/// The query is read-only and validates that the mints belong to the offer.
+#[derive(Accounts)]
+pub struct GetOfferStats<'info> {
+    /// The offer account holding the cumulative take counters
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Returns the cumulative fees and volume collected by takes of an offer
+///
+/// Counters are updated by every take path and give an on-chain view of offer
+/// revenue without indexing events.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(OfferStats)` - The cumulative fee and volume counters in token_in base units
+///
+/// # Events
+/// * `GetOfferStatsEvent` - Emitted with the offer PDA and its counters
+pub fn get_offer_stats(ctx: Context<GetOfferStats>) -> Result<OfferStats> {
+    let offer = ctx.accounts.offer.load()?;
+    let total_fees_collected_token_in = offer.total_fees_collected_token_in();
+    let total_volume_token_in = offer.total_volume_token_in();
+
+    msg!(
+        "Offer Stats - Offer PDA: {}, Fees: {}, Volume: {}",
+        ctx.accounts.offer.key(),
+        total_fees_collected_token_in,
+        total_volume_token_in
+    );
+
+    emit!(GetOfferStatsEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        total_fees_collected_token_in,
+        total_volume_token_in,
+    });
+
+    Ok(OfferStats {
+        total_fees_collected_token_in,
+        total_volume_token_in,
+    })
+}
@@ -1,11 +1,12 @@
 use crate::constants::{seeds, PRICE_DECIMALS};
 use crate::instructions::offer::offer_utils::{
-    calculate_current_step_price, find_active_vector_at,
+    calculate_current_vector_price, find_active_vector_at,
 };
 use crate::instructions::Offer;
 use crate::OfferCoreError;
 use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
+use crate::state::Config;
 use anchor_lang::prelude::*;
 use anchor_lang::Accounts;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -59,6 +60,10 @@ pub struct GetTVL<'info> {
     )]
     pub offer: AccountLoader<'info, Offer>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The input token mint account for offer validation
     #[account(
         constraint =
@@ -77,7 +82,13 @@ pub struct GetTVL<'info> {
 
     /// The vault authority PDA that controls vault token accounts
     /// CHECK: PDA derivation is validated by seeds constraint
-    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
     pub vault_authority: UncheckedAccount<'info>,
 
     /// The vault's token_out account to exclude from circulating supply
@@ -130,12 +141,8 @@ pub fn get_tvl(ctx: Context<GetTVL>) -> Result<u64> {
     let active_vector = find_active_vector_at(&offer, current_time)?;
 
     // Calculate current price (NAV) with 9 decimals
-    let current_price = calculate_current_step_price(
-        active_vector.apr,
-        active_vector.base_price,
-        active_vector.base_time,
-        active_vector.price_fix_duration,
-    )?;
+    let current_price =
+        calculate_current_vector_price(&active_vector, ctx.accounts.config.seconds_per_year)?;
 
     let vault_token_out_amount = read_optional_ata_amount(
         &ctx.accounts.vault_token_out_account,
@@ -194,7 +201,7 @@ pub fn get_tvl(ctx: Context<GetTVL>) -> Result<u64> {
 ///
 /// # Returns
 /// * `Ok(amount)` - Token amount if account is initialized, 0 otherwise
-fn read_optional_ata_amount(
+pub(crate) fn read_optional_ata_amount(
     vault_account: &AccountInfo,
     token_program: &Interface<TokenInterface>,
 ) -> Result<u64> {
@@ -216,3 +223,64 @@ fn read_optional_ata_amount(
         Err(_) => Ok(0),
     }
 }
+
+/// Number of remaining accounts describing one per-offer vault holding
+const ACCOUNTS_PER_OWN_VAULT: usize = 2;
+
+/// Sums the balances of per-offer vault token accounts passed as remaining accounts
+///
+/// The accounts come in pairs: an offer, then the token account of `mint` of its own
+/// vault, which must be the ATA of the PDA `[OFFER_VAULT_AUTHORITY, offer]`. Each
+/// offer may be passed once, so no balance is counted twice. Uninitialized vault
+/// accounts are treated as zero balance.
+///
+/// # Arguments
+/// * `remaining_accounts` - The offer and vault account pairs
+/// * `mint` - The mint of the vault token accounts
+/// * `token_program` - The SPL Token program of the mint
+/// * `program_id` - This program's ID, deriving the vault authorities
+///
+/// # Returns
+/// * `Some(amount)` - The summed balance of the per-offer vault accounts
+/// * `None` - If the accounts are malformed or duplicated, or the sum overflows
+pub(crate) fn read_own_vault_amounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    mint: &Pubkey,
+    token_program: &Interface<'info, TokenInterface>,
+    program_id: &Pubkey,
+) -> Option<u64> {
+    if !remaining_accounts
+        .len()
+        .is_multiple_of(ACCOUNTS_PER_OWN_VAULT)
+    {
+        return None;
+    }
+
+    let mut offers: Vec<Pubkey> =
+        Vec::with_capacity(remaining_accounts.len() / ACCOUNTS_PER_OWN_VAULT);
+    let mut amount: u64 = 0;
+    for accounts in remaining_accounts.chunks(ACCOUNTS_PER_OWN_VAULT) {
+        AccountLoader::<Offer>::try_from(&accounts[0]).ok()?;
+        let offer = accounts[0].key();
+        if offers.contains(&offer) {
+            return None;
+        }
+        offers.push(offer);
+
+        let (vault_authority, _) = Pubkey::find_program_address(
+            &[seeds::OFFER_VAULT_AUTHORITY, offer.as_ref()],
+            program_id,
+        );
+        if accounts[1].key()
+            != get_associated_token_address_with_program_id(
+                &vault_authority,
+                mint,
+                &token_program.key(),
+            )
+        {
+            return None;
+        }
+        amount = amount.checked_add(read_optional_ata_amount(&accounts[1], token_program).ok()?)?;
+    }
+    Some(amount)
+}
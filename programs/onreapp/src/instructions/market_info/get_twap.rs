@@ -0,0 +1,153 @@
+use crate::constants::seeds;
+use crate::instructions::{NavHistory, Offer};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a time-weighted average NAV is calculated
+///
+/// Provides transparency for tracking TWAP queries.
+#[event]
+pub struct GetTWAPEvent {
+    /// The PDA address of the offer for which the TWAP was calculated
+    pub offer_pda: Pubkey,
+    /// Time-weighted average NAV with scale=9
+    pub twap: u64,
+    /// Requested averaging window in seconds
+    pub window_secs: u64,
+    /// Seconds of the window actually covered by recorded observations
+    pub covered_secs: u64,
+    /// Unix timestamp when the calculation was performed
+    pub timestamp: u64,
+}
+
+/// Account structure for querying the time-weighted average NAV of an offer
+///
+/// The calculation is read-only and validates that the mints and NAV history
+/// belong to the offer.
+#[derive(Accounts)]
+pub struct GetTWAP<'info> {
+    /// The offer whose NAV history is averaged
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The NAV history of the offer
+    #[account(
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Box<Account<'info, NavHistory>>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Calculates the time-weighted average NAV of an offer over a trailing window
+///
+/// Each recorded observation is treated as the NAV in force until the next one,
+/// the latest observation extending to the current time. When the window reaches
+/// further back than the oldest stored observation, the average covers the
+/// available history only; `covered_secs` in the event reports the span used.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `window_secs` - Length of the trailing averaging window in seconds
+///
+/// # Returns
+/// * `Ok(twap)` - The time-weighted average NAV with scale=9
+/// * `Err(GetTWAPErrorCode::InvalidWindow)` - If the window is zero
+/// * `Err(GetTWAPErrorCode::NoObservations)` - If no NAV has been recorded yet
+///
+/// # Events
+/// * `GetTWAPEvent` - Emitted with the average and the covered span
+pub fn get_twap(ctx: Context<GetTWAP>, window_secs: u64) -> Result<u64> {
+    require!(window_secs > 0, GetTWAPErrorCode::InvalidWindow);
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let window_start = current_time.saturating_sub(window_secs);
+    let observations = ctx.accounts.nav_history.chronological();
+    let latest = observations
+        .last()
+        .copied()
+        .ok_or(GetTWAPErrorCode::NoObservations)?;
+
+    let mut weighted_sum: u128 = 0;
+    let mut covered_secs: u64 = 0;
+    for (i, observation) in observations.iter().enumerate() {
+        let segment_end = observations
+            .get(i + 1)
+            .map(|next| next.timestamp)
+            .unwrap_or(current_time);
+        let segment_start = observation.timestamp.max(window_start);
+        if segment_end <= segment_start {
+            continue;
+        }
+
+        let duration = segment_end - segment_start;
+        weighted_sum = weighted_sum
+            .checked_add(
+                (observation.nav as u128)
+                    .checked_mul(duration as u128)
+                    .ok_or(OfferCoreError::OverflowError)?,
+            )
+            .ok_or(OfferCoreError::OverflowError)?;
+        covered_secs += duration;
+    }
+
+    // Only the latest observation falls in the window and it was taken just now
+    let twap = if covered_secs == 0 {
+        latest.nav
+    } else {
+        (weighted_sum / covered_secs as u128) as u64
+    };
+
+    msg!(
+        "TWAP Info - Offer PDA: {}, Window: {}s, Covered: {}s, TWAP: {}",
+        ctx.accounts.offer.key(),
+        window_secs,
+        covered_secs,
+        twap
+    );
+
+    emit!(GetTWAPEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        twap,
+        window_secs,
+        covered_secs,
+        timestamp: current_time,
+    });
+
+    Ok(twap)
+}
+
+/// Error codes for TWAP calculation operations
+#[error_code]
+pub enum GetTWAPErrorCode {
+    /// The averaging window must be greater than zero
+    #[msg("Invalid window: must be greater than zero")]
+    InvalidWindow,
+    /// The offer's NAV history holds no observations
+    #[msg("No NAV observations recorded")]
+    NoObservations,
+}
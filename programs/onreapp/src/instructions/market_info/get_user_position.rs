@@ -0,0 +1,203 @@
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::offer::offer_utils::{calculate_offer_price, load_token_in_usd_price};
+use crate::instructions::{Offer, UserPosition};
+use crate::state::Config;
+use crate::utils::scale_amount;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::token_interface::Mint;
+
+/// Position of a user returned by `get_user_position`, valued at the current price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct UserPositionInfo {
+    /// Cumulative amount of token_out bought through takes
+    pub total_purchased: u64,
+    /// Average take price with scale=9
+    pub average_entry_price: u64,
+    /// Cumulative amount of token_out redeemed
+    pub total_redeemed: u64,
+    /// Amount bought and not redeemed since
+    pub holdings: u64,
+    /// Current price of one token_out in token_in with scale=9
+    pub current_price: u64,
+    /// Holdings valued at the average entry price, in token_in base units
+    pub cost_basis: u64,
+    /// Holdings valued at the current price, in token_in base units
+    pub current_value: u64,
+    /// Current value minus cost basis, in token_in base units
+    pub unrealized_pnl: i64,
+}
+
+/// Event emitted when a user position is queried
+///
+/// Provides transparency for wallets showing holdings and P&L without an indexer.
+#[event]
+pub struct GetUserPositionEvent {
+    /// The PDA address of the queried position
+    pub user_position_pda: Pubkey,
+    /// The user the position belongs to
+    pub user: Pubkey,
+    /// The position valued at the current price
+    pub position: UserPositionInfo,
+    /// Unix timestamp when the query was performed
+    pub timestamp: u64,
+}
+
+/// Account structure for querying a user's position in the token_out mint of an offer
+///
+/// The query is read-only; the offer provides the current price the position is
+/// valued at.
+#[derive(Accounts)]
+pub struct GetUserPosition<'info> {
+    /// The offer account pricing the position
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The input token mint account the position is valued in
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account the position is held in
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// The user whose position is queried
+    /// CHECK: Only used to derive the position PDA
+    pub user: UncheckedAccount<'info>,
+
+    /// The user's position in token_out
+    #[account(
+        seeds = [
+            seeds::USER_POSITION,
+            token_out_mint.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump = user_position.bump
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the offer has an oracle configured via `set_offer_oracle`.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+}
+
+/// Returns a user's holdings, cost basis and unrealized P&L from their position
+///
+/// Holdings only cover tokens bought through takes and not redeemed since; they
+/// are valued at the average entry price and at the offer's current price, both
+/// rounded down.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(UserPositionInfo)` - The position valued at the current price
+/// * `Err(OfferCoreError::NoActiveVector)` - If no pricing vector is currently active
+/// * `Err(OfferCoreError::OverflowError)` - If a valuation overflows
+///
+/// # Events
+/// * `GetUserPositionEvent` - Emitted with the position PDA, user and valuation
+pub fn get_user_position(ctx: Context<GetUserPosition>) -> Result<UserPositionInfo> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let user_position = &ctx.accounts.user_position;
+
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+    let current_price = calculate_offer_price(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+
+    let holdings = user_position.holdings();
+    let token_in_decimals = ctx.accounts.token_in_mint.decimals;
+    let token_out_decimals = ctx.accounts.token_out_mint.decimals;
+    let cost_basis = value_in_token_in(
+        holdings,
+        user_position.average_entry_price,
+        token_in_decimals,
+        token_out_decimals,
+    )?;
+    let current_value = value_in_token_in(
+        holdings,
+        current_price,
+        token_in_decimals,
+        token_out_decimals,
+    )?;
+    let unrealized_pnl = i64::try_from(current_value as i128 - cost_basis as i128)
+        .map_err(|_| OfferCoreError::OverflowError)?;
+
+    let position = UserPositionInfo {
+        total_purchased: user_position.total_purchased,
+        average_entry_price: user_position.average_entry_price,
+        total_redeemed: user_position.total_redeemed,
+        holdings,
+        current_price,
+        cost_basis,
+        current_value,
+        unrealized_pnl,
+    };
+
+    msg!(
+        "User Position - PDA: {}, User: {}, Holdings: {}, Cost basis: {}, Value: {}, P&L: {}",
+        user_position.key(),
+        user_position.user,
+        holdings,
+        cost_basis,
+        current_value,
+        unrealized_pnl
+    );
+
+    emit!(GetUserPositionEvent {
+        user_position_pda: user_position.key(),
+        user: user_position.user,
+        position: position.clone(),
+        timestamp: current_time,
+    });
+
+    Ok(position)
+}
+
+/// Values a token_out amount in token_in base units at a price with scale=9, rounded down
+fn value_in_token_in(
+    token_out_amount: u64,
+    price: u64,
+    token_in_decimals: u8,
+    token_out_decimals: u8,
+) -> Result<u64> {
+    let value = scale_amount(
+        token_out_amount,
+        price as u128,
+        1,
+        token_in_decimals as u32,
+        (token_out_decimals + PRICE_DECIMALS) as u32,
+    )
+    .ok_or(OfferCoreError::OverflowError)?;
+
+    u64::try_from(value).map_err(|_| OfferCoreError::OverflowError.into())
+}
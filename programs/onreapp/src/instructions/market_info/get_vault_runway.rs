@@ -0,0 +1,195 @@
+use crate::constants::seeds;
+use crate::instructions::market_info::get_tvl::read_optional_ata_amount;
+use crate::instructions::offer::offer_utils::{
+    is_low_inventory, load_token_in_usd_price, process_offer_core_exact_out,
+};
+use crate::instructions::{FulfillmentMode, Offer};
+use crate::state::Config;
+use crate::utils::program_controls_mint;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+/// Error codes for vault runway queries
+#[error_code]
+pub enum GetVaultRunwayErrorCode {
+    /// The vault account address doesn't match the expected ATA address
+    #[msg("Invalid token_out vault account")]
+    InvalidVaultAccount,
+}
+
+/// Event emitted when the vault runway of an offer is queried
+///
+/// Provides transparency for monitoring how much the offer vault can still fill.
+#[event]
+pub struct GetVaultRunwayEvent {
+    /// The PDA address of the offer for which the runway was calculated
+    pub offer_pda: Pubkey,
+    /// token_out balance of the offer vault in base units
+    pub vault_token_out_remaining: u64,
+    /// token_in, including fees, that takes can still pay before the vault is empty
+    pub token_in_capacity: u64,
+    /// Current price with scale=9 the capacity was calculated at
+    pub current_price: u64,
+    /// How takes of the offer deliver token_out
+    pub fulfillment_mode: FulfillmentMode,
+    /// Whether the vault balance is at or below the offer's low inventory threshold
+    pub low_inventory: bool,
+    /// Unix timestamp when the query was performed
+    pub timestamp: u64,
+}
+
+/// Account structure for querying the vault runway of an offer
+///
+/// The calculation is read-only and validates all accounts belong to the same offer.
+/// The vault token_out account may be uninitialized, which counts as an empty vault.
+#[derive(Accounts)]
+pub struct GetVaultRunway<'info> {
+    /// The offer account containing pricing vectors and the low inventory threshold
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// The vault authority PDA that controls vault token accounts
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The vault's token_out account takes are filled from
+    ///
+    /// The account address is validated to match the expected ATA address
+    /// and can be uninitialized (treated as zero balance).
+    /// CHECK: Account address is validated by the constraint below to allow passing uninitialized vault account
+    #[account(
+        constraint = vault_token_out_account.key()
+            == get_associated_token_address_with_program_id(
+                &vault_authority.key(),
+                &token_out_mint.key(),
+                &token_out_program.key(),
+            ) @ GetVaultRunwayErrorCode::InvalidVaultAccount
+    )]
+    pub vault_token_out_account: UncheckedAccount<'info>,
+
+    /// Program-derived mint authority, resolving the fulfillment mode of offers
+    /// created without an explicit one
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// SPL Token program for vault account validation
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the offer has an oracle configured via `set_offer_oracle`.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+}
+
+/// Calculates how much token_in the offer vault can still fill at the current NAV
+///
+/// Prices the vault's whole token_out balance as a single exact-out take, so the
+/// capacity includes the offer fee. Offers fulfilled by minting do not draw on the
+/// vault; their capacity still only reflects the vault balance and they never flag
+/// low inventory.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(token_in_capacity)` - token_in in base units takes can pay before the vault is empty
+/// * `Err(OfferCoreError::NoActiveVector)` - If no pricing vector is currently active
+/// * `Err(GetVaultRunwayErrorCode::InvalidVaultAccount)` - If vault account validation fails
+///
+/// # Events
+/// * `GetVaultRunwayEvent` - Emitted with vault balance, capacity, price and alert flag
+pub fn get_vault_runway(ctx: Context<GetVaultRunway>) -> Result<u64> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let vault_token_out_remaining = read_optional_ata_amount(
+        &ctx.accounts.vault_token_out_account,
+        &ctx.accounts.token_out_program,
+    )?;
+
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+    let result = process_offer_core_exact_out(
+        &offer,
+        vault_token_out_remaining,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    let token_in_capacity = result
+        .token_in_net_amount
+        .checked_add(result.token_in_fee_amount)
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    let fulfillment_mode = offer.fulfillment_mode().unwrap_or_else(|| {
+        if program_controls_mint(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.mint_authority.to_account_info(),
+        ) {
+            FulfillmentMode::Mint
+        } else {
+            FulfillmentMode::Transfer
+        }
+    });
+    let low_inventory = is_low_inventory(&offer, vault_token_out_remaining, fulfillment_mode);
+
+    msg!(
+        "Vault Runway Info - Offer PDA: {}, Vault token_out: {}, token_in capacity: {}, Low inventory: {}",
+        ctx.accounts.offer.key(),
+        vault_token_out_remaining,
+        token_in_capacity,
+        low_inventory
+    );
+
+    emit!(GetVaultRunwayEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        vault_token_out_remaining,
+        token_in_capacity,
+        current_price: result.current_price,
+        fulfillment_mode,
+        low_inventory,
+        timestamp: current_time,
+    });
+
+    Ok(token_in_capacity)
+}
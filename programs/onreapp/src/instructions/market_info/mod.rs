@@ -1,11 +1,27 @@
 pub mod get_apy;
 pub mod get_circulating_supply;
+pub mod get_global_tvl;
 pub mod get_nav;
 pub mod get_nav_adjustment;
+pub mod get_nav_at;
+pub mod get_offer_stats;
 pub mod get_tvl;
+pub mod get_twap;
+pub mod get_user_position;
+pub mod get_vault_runway;
+pub mod snapshot_market_info;
+pub mod view_offer;
 
 pub use get_apy::*;
 pub use get_circulating_supply::*;
+pub use get_global_tvl::*;
 pub use get_nav::*;
 pub use get_nav_adjustment::*;
+pub use get_nav_at::*;
+pub use get_offer_stats::*;
 pub use get_tvl::*;
+pub use get_twap::*;
+pub use get_user_position::*;
+pub use get_vault_runway::*;
+pub use snapshot_market_info::*;
+pub use view_offer::*;
@@ -0,0 +1,170 @@
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::market_info::get_apy::{
+    calculate_apy_from_apr, DEFAULT_COMPOUNDING_PERIODS_PER_YEAR,
+};
+use crate::instructions::market_info::get_tvl::{read_optional_ata_amount, GetTVLErrorCode};
+use crate::instructions::offer::offer_utils::{
+    calculate_current_vector_price, find_active_vector_at,
+};
+use crate::instructions::Offer;
+use crate::state::Config;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Accounts;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+/// Event emitted with a composite snapshot of an offer's market metrics
+///
+/// Unlike the `Get...` views, which are usually simulated, the snapshot is meant to
+/// be submitted so indexers can build a market history from transaction logs alone.
+#[event]
+pub struct MarketSnapshotEvent {
+    /// The PDA address of the offer the snapshot was taken for
+    pub offer_pda: Pubkey,
+    /// Current NAV with scale=9
+    pub nav: u64,
+    /// Signed Annual Percentage Yield with scale=6 (1_000_000 = 100%)
+    pub apy: i64,
+    /// Signed Annual Percentage Rate with scale=6 of the active vector
+    pub apr: i64,
+    /// TVL in base units (circulating_supply * nav / 10^9)
+    pub tvl: u64,
+    /// Circulating token_out supply (total_supply - vault_amount) in base units
+    pub circulating_supply: u64,
+    /// Unix timestamp when the snapshot was taken
+    pub timestamp: u64,
+}
+
+/// Account structure for taking a market snapshot of an offer
+///
+/// Mirrors the accounts of `GetTVL`, which already cover everything the snapshot
+/// reports.
+#[derive(Accounts)]
+pub struct SnapshotMarketInfo<'info> {
+    /// The offer account containing pricing vectors for NAV and APY calculation
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account containing total supply information
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// The vault authority PDA that controls vault token accounts
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The vault's token_out account to exclude from circulating supply
+    ///
+    /// Can be uninitialized, in which case it is treated as zero balance.
+    /// CHECK: Account address is validated by the constraint below to allow passing uninitialized vault account
+    #[account(
+        constraint = vault_token_out_account.key()
+            == get_associated_token_address_with_program_id(
+                &vault_authority.key(),
+                &token_out_mint.key(),
+                &token_out_program.key(),
+            ) @ GetTVLErrorCode::InvalidVaultAccount
+    )]
+    pub vault_token_out_account: UncheckedAccount<'info>,
+
+    /// SPL Token program for vault account validation
+    pub token_out_program: Interface<'info, TokenInterface>,
+}
+
+/// Emits a composite snapshot of the NAV, APY, TVL and circulating supply of an offer
+///
+/// Calculates the same values as `get_nav`, `get_apy`, `get_tvl` and the offer's
+/// circulating token_out supply in a single instruction and emits them together,
+/// so no state is modified and the data lives only in the transaction logs.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the snapshot is emitted
+/// * `Err(OfferCoreError::NoActiveVector)` - If no pricing vector is currently active
+/// * `Err(GetTVLErrorCode::Overflow)` - If the TVL calculation overflows
+/// * `Err(GetAPYErrorCode::Overflow)` - If the APY calculation overflows
+///
+/// # Access Control
+/// - Permissionless: anyone may emit a snapshot
+///
+/// # Events
+/// * `MarketSnapshotEvent` - Emitted with all market metrics and the timestamp
+pub fn snapshot_market_info(ctx: Context<SnapshotMarketInfo>) -> Result<()> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let active_vector = find_active_vector_at(&offer, current_time)?;
+    let nav = calculate_current_vector_price(&active_vector, ctx.accounts.config.seconds_per_year)?;
+    let apy = calculate_apy_from_apr(active_vector.apr, DEFAULT_COMPOUNDING_PERIODS_PER_YEAR)?;
+
+    let vault_amount = read_optional_ata_amount(
+        &ctx.accounts.vault_token_out_account,
+        &ctx.accounts.token_out_program,
+    )?;
+    let circulating_supply = ctx
+        .accounts
+        .token_out_mint
+        .supply
+        .saturating_sub(vault_amount);
+
+    let tvl = (circulating_supply as u128)
+        .checked_mul(nav as u128)
+        .map(|result| result / 10_u128.pow(PRICE_DECIMALS as u32))
+        .and_then(|result| u64::try_from(result).ok())
+        .ok_or(GetTVLErrorCode::Overflow)?;
+
+    msg!(
+        "Market Snapshot - Offer PDA: {}, NAV: {}, APY: {}, TVL: {}, Circulating Supply: {}, Timestamp: {}",
+        ctx.accounts.offer.key(),
+        nav,
+        apy,
+        tvl,
+        circulating_supply,
+        current_time
+    );
+
+    emit!(MarketSnapshotEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        nav,
+        apy,
+        apr: active_vector.apr,
+        tvl,
+        circulating_supply,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
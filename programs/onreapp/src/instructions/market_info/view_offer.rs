@@ -0,0 +1,177 @@
+use crate::constants::seeds;
+use crate::instructions::{
+    calculate_current_vector_price, find_active_vector_at, FulfillmentMode, MinimumDenomination,
+    Offer, OfferVectorInfo,
+};
+use crate::state::Config;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Configuration and pricing of an offer returned by `view_offer`
+///
+/// Decoupled from the zero-copy `Offer` layout so clients can decode it through the
+/// IDL. The full pricing schedule is available page by page from `get_offer_vectors`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OfferView {
+    /// Input token mint for the exchange
+    pub token_in_mint: Pubkey,
+    /// Output token mint for the exchange
+    pub token_out_mint: Pubkey,
+    /// Fee in basis points (10000 = 100%) charged when taking the offer
+    pub fee_basis_points: u16,
+    /// Whether takes require an approval message
+    pub needs_approval: bool,
+    /// Whether the offer can be taken through `take_offer_permissionless`
+    pub allow_permissionless: bool,
+    /// Whether takes may be clawed back within the dispute window
+    pub clawback_enabled: bool,
+    /// Whether take proceeds land in the offer vault instead of the boss's account
+    pub proceeds_to_vault: bool,
+    /// token_in proceeds left in the offer vault that can still be collected
+    pub uncollected_proceeds: u64,
+    /// Whether takes are priced on the token_in amount received after transfer fees
+    pub price_on_net_received: bool,
+    /// Whether takes require the user's accounts to exist already
+    pub strict_accounts: bool,
+    /// How takes deliver token_out, `None` when decided by the token_out mint authority
+    pub fulfillment_mode: Option<FulfillmentMode>,
+    /// Minimum size of a single take (0 = no minimum)
+    pub min_take_amount: u64,
+    /// Unit `min_take_amount` is expressed in
+    pub min_take_denomination: MinimumDenomination,
+    /// Maximum token_out per pricing step (0 = no cap)
+    pub max_step_volume: u64,
+    /// Vault token_out balance at or below which takes flag low inventory (0 = no alerts)
+    pub low_inventory_threshold: u64,
+    /// Unix timestamp from which the offer can be taken (0 = immediately)
+    pub valid_from: u64,
+    /// Unix timestamp from which takes are rejected (0 = never expires)
+    pub valid_until: u64,
+    /// Whether the offer was closed after its expiry by `crank_expired_offer`
+    pub closed: bool,
+    /// Oracle valuing token_in in USD (default = no oracle)
+    pub oracle: Pubkey,
+    /// Number of configured pricing vectors
+    pub total_vectors: u8,
+    /// The currently active pricing vector, if any
+    pub active_vector: Option<OfferVectorInfo>,
+    /// Current NAV with scale=9, if a vector is active
+    pub current_nav: Option<u64>,
+}
+
+/// Event emitted when an offer is viewed
+///
+/// Provides transparency for tracking offer queries.
+#[event]
+pub struct ViewOfferEvent {
+    /// The PDA address of the viewed offer
+    pub offer_pda: Pubkey,
+    /// Current NAV with scale=9, if a vector is active
+    pub current_nav: Option<u64>,
+}
+
+/// Account structure for viewing an offer
+///
+/// The query is read-only, requires no signer and validates that the mints belong
+/// to the offer.
+#[derive(Accounts)]
+pub struct ViewOffer<'info> {
+    /// The offer account to view
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Returns the configuration, active pricing vector and current NAV of an offer
+///
+/// Unlike `get_nav`, an offer without an active vector is not an error: its
+/// `active_vector` and `current_nav` are returned as `None`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(OfferView)` - The offer's configuration and pricing
+///
+/// # Events
+/// * `ViewOfferEvent` - Emitted with the offer PDA and its current NAV
+pub fn view_offer(ctx: Context<ViewOffer>) -> Result<OfferView> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let active_vector = find_active_vector_at(&offer, current_time).ok();
+    let current_nav = active_vector
+        .as_ref()
+        .map(|vector| calculate_current_vector_price(vector, ctx.accounts.config.seconds_per_year))
+        .transpose()?;
+
+    let view = OfferView {
+        token_in_mint: offer.token_in_mint,
+        token_out_mint: offer.token_out_mint,
+        fee_basis_points: offer.fee_basis_points,
+        needs_approval: offer.needs_approval(),
+        allow_permissionless: offer.allow_permissionless(),
+        clawback_enabled: offer.clawback_enabled(),
+        proceeds_to_vault: offer.proceeds_to_vault(),
+        uncollected_proceeds: offer.uncollected_proceeds,
+        price_on_net_received: offer.price_on_net_received(),
+        strict_accounts: offer.strict_accounts(),
+        fulfillment_mode: offer.fulfillment_mode(),
+        min_take_amount: offer.min_take_amount,
+        min_take_denomination: offer.min_take_denomination(),
+        max_step_volume: offer.max_step_volume,
+        low_inventory_threshold: offer.low_inventory_threshold,
+        valid_from: offer.valid_from,
+        valid_until: offer.valid_until,
+        closed: offer.is_closed(),
+        oracle: offer.oracle,
+        total_vectors: offer
+            .vectors
+            .iter()
+            .filter(|vector| vector.start_time != 0)
+            .count() as u8,
+        active_vector: active_vector.as_ref().map(OfferVectorInfo::from),
+        current_nav,
+    };
+
+    msg!(
+        "Offer View - Offer PDA: {}, Vectors: {}, NAV: {:?}",
+        ctx.accounts.offer.key(),
+        view.total_vectors,
+        current_nav
+    );
+
+    emit!(ViewOfferEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        current_nav,
+    });
+
+    Ok(view)
+}
@@ -0,0 +1,110 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::ManagedMint;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a managed token is successfully registered
+///
+/// Provides transparency for tracking the tokens operated by the program.
+#[event]
+pub struct ManagedMintAddedEvent {
+    /// The PDA address of the new registry entry
+    pub managed_mint_pda: Pubkey,
+    /// The token mint of the managed token
+    pub mint: Pubkey,
+    /// Maximum supply of the token in base units (0 = no cap)
+    pub max_supply: u64,
+}
+
+/// Account structure for registering a managed token
+///
+/// This struct defines the accounts required to add a mint to the managed mint
+/// registry. Only the boss can register managed tokens.
+#[derive(Accounts)]
+pub struct AddManagedMint<'info> {
+    /// The registry entry being created
+    ///
+    /// Derived from the mint so each mint can be registered only once.
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + ManagedMint::INIT_SPACE,
+        seeds = [seeds::MANAGED_MINT, mint.key().as_ref()],
+        bump
+    )]
+    pub managed_mint: Account<'info, ManagedMint>,
+
+    /// The token mint of the managed token
+    #[account(constraint = mint.key() != state.onyc_mint @ AddManagedMintErrorCode::OnycMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization and the ONyc mint
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to register managed tokens and pay for the account
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a yield token to be managed alongside ONyc
+///
+/// The program operates managed tokens like ONyc: offers mint them as token_out
+/// under their own supply cap once the program holds their mint authority, and
+/// the boss mints them with `mint_managed_to`. ONyc itself stays configured in
+/// program state.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_supply` - Maximum supply of the token in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the token is successfully registered
+/// * `Err(AddManagedMintErrorCode::OnycMint)` - If the mint is the ONyc mint
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Creates the managed mint registry entry for the mint
+///
+/// # Events
+/// * `ManagedMintAddedEvent` - Emitted with the managed token configuration
+pub fn add_managed_mint(ctx: Context<AddManagedMint>, max_supply: u64) -> Result<()> {
+    let managed_mint = &mut ctx.accounts.managed_mint;
+    managed_mint.mint = ctx.accounts.mint.key();
+    managed_mint.max_supply = max_supply;
+    managed_mint.bump = ctx.bumps.managed_mint;
+
+    msg!(
+        "Managed mint added: {}, max supply: {}",
+        managed_mint.mint,
+        max_supply
+    );
+
+    emit!(ManagedMintAddedEvent {
+        managed_mint_pda: ctx.accounts.managed_mint.key(),
+        mint: ctx.accounts.mint.key(),
+        max_supply,
+    });
+
+    Ok(())
+}
+
+/// Error codes for managed mint registration
+#[error_code]
+pub enum AddManagedMintErrorCode {
+    /// ONyc is configured in program state, not in the registry
+    #[msg("ONyc is managed through program state")]
+    OnycMint,
+}
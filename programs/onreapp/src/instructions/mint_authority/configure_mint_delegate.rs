@@ -0,0 +1,143 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::MintDelegate;
+use crate::state::{MintThrottle, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a mint delegate is registered or its cap is updated
+///
+/// Provides transparency for tracking which programs may mint ONyc.
+#[event]
+pub struct MintDelegateConfiguredEvent {
+    /// Program ID of the delegate program
+    pub program: Pubkey,
+    /// PDA of the delegate program that signs its mints
+    pub authority: Pubkey,
+    /// The previous lifetime mint cap (0 for a new delegate)
+    pub old_mint_cap: u64,
+    /// The new lifetime mint cap
+    pub new_mint_cap: u64,
+    /// ONyc minted by the delegate so far
+    pub minted: u64,
+}
+
+/// Account structure for registering a mint delegate or updating its cap
+///
+/// The registry entry is created on first use, paid for by the boss.
+#[derive(Accounts)]
+#[instruction(delegate_program: Pubkey)]
+pub struct ConfigureMintDelegate<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The registry entry of the delegate program
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MintDelegate::INIT_SPACE,
+        seeds = [seeds::MINT_DELEGATE, delegate_program.as_ref()],
+        bump
+    )]
+    pub mint_delegate: Box<Account<'info, MintDelegate>>,
+
+    /// The mint throttle delegate mints are charged to
+    ///
+    /// Created without a limit if it doesn't exist yet, so delegate mints always
+    /// have a throttle to consume from.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MintThrottle::INIT_SPACE,
+        seeds = [seeds::MINT_THROTTLE],
+        bump
+    )]
+    pub mint_throttle: Box<Account<'info, MintThrottle>>,
+
+    /// The boss account authorized to register delegates and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers an external program as a mint delegate or updates its mint cap
+///
+/// Lets in-house programs, such as a staking vault, mint ONyc rewards through
+/// `mint_to_via_delegate` without holding the mint authority. The delegate signs
+/// mints with the PDA it derives from `MINT_DELEGATE_AUTHORITY`. Updating the cap
+/// keeps the amount already minted, so lowering the cap below it stops further mints.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `delegate_program` - Program ID of the delegate program
+/// * `mint_cap` - Maximum ONyc in base units the delegate may mint over its lifetime
+///
+/// # Returns
+/// * `Ok(())` - If the delegate is successfully configured
+/// * `Err(ConfigureMintDelegateErrorCode::InvalidDelegateProgram)` - If the program ID is default or this program
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates the registry entry and the mint throttle if needed
+/// - Updates the delegate's mint cap
+///
+/// # Events
+/// * `MintDelegateConfiguredEvent` - Emitted with the delegate and its cap
+pub fn configure_mint_delegate(
+    ctx: Context<ConfigureMintDelegate>,
+    delegate_program: Pubkey,
+    mint_cap: u64,
+) -> Result<()> {
+    require!(
+        delegate_program != Pubkey::default() && delegate_program != crate::ID,
+        ConfigureMintDelegateErrorCode::InvalidDelegateProgram
+    );
+
+    let mint_throttle = &mut ctx.accounts.mint_throttle;
+    mint_throttle.bump = ctx.bumps.mint_throttle;
+
+    let mint_delegate = &mut ctx.accounts.mint_delegate;
+    if mint_delegate.program == Pubkey::default() {
+        mint_delegate.program = delegate_program;
+        mint_delegate.authority =
+            Pubkey::find_program_address(&[seeds::MINT_DELEGATE_AUTHORITY], &delegate_program).0;
+        mint_delegate.bump = ctx.bumps.mint_delegate;
+    }
+
+    let old_mint_cap = mint_delegate.mint_cap;
+    mint_delegate.mint_cap = mint_cap;
+
+    msg!(
+        "Mint delegate configured: program={}, cap={} (previous: {}), minted={}",
+        delegate_program,
+        mint_cap,
+        old_mint_cap,
+        mint_delegate.minted
+    );
+
+    emit!(MintDelegateConfiguredEvent {
+        program: delegate_program,
+        authority: mint_delegate.authority,
+        old_mint_cap,
+        new_mint_cap: mint_cap,
+        minted: mint_delegate.minted,
+    });
+
+    Ok(())
+}
+
+/// Error codes for mint delegate configuration
+#[error_code]
+pub enum ConfigureMintDelegateErrorCode {
+    /// The delegate program ID is the default address or this program
+    #[msg("Invalid delegate program")]
+    InvalidDelegateProgram,
+}
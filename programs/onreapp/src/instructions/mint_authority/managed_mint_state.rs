@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry for a yield token managed alongside ONyc
+///
+/// ONyc is configured in program state; every further managed token (e.g. ONmx)
+/// has one entry derived from its mint, holding its settings. The program mints
+/// all managed tokens through the shared mint authority PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct ManagedMint {
+    /// Token mint of the managed token
+    pub mint: Pubkey,
+    /// Maximum supply of the token in base units (0 = no cap)
+    pub max_supply: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl ManagedMint {
+    /// Returns the supply cap of program mints from a possibly uninitialized entry
+    ///
+    /// Mints without a registry entry keep the cap applying to them before managed
+    /// mints existed, passed as `fallback`.
+    pub fn max_supply_or(managed_mint: &AccountInfo, fallback: u64) -> Result<u64> {
+        if managed_mint.owner != &crate::ID || managed_mint.data_is_empty() {
+            return Ok(fallback);
+        }
+        let managed_mint = ManagedMint::try_deserialize(&mut &managed_mint.try_borrow_data()?[..])?;
+        Ok(managed_mint.max_supply)
+    }
+}
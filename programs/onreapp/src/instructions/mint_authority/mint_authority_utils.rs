@@ -0,0 +1,46 @@
+use crate::state::MintThrottle;
+use anchor_lang::prelude::*;
+
+/// Common error codes for mint throttle operations
+#[error_code]
+pub enum MintThrottleErrorCode {
+    /// The mint would exceed the budget of the rolling window
+    #[msg("Mint exceeds the rolling window budget")]
+    MintBudgetExceeded,
+    /// Arithmetic overflow occurred during budget accounting
+    #[msg("Overflow error")]
+    OverflowError,
+}
+
+/// Charges a mint against the rolling mint budget
+///
+/// Every boss-initiated ONyc mint path consumes from the same throttle, so the
+/// limit holds across instructions. Nothing is charged while no limit is set.
+///
+/// # Arguments
+/// * `throttle` - The mint throttle account
+/// * `amount` - Amount of ONyc about to be minted in base units
+///
+/// # Returns
+/// * `Ok(())` - If the mint fits in the remaining budget
+/// * `Err(MintThrottleErrorCode::MintBudgetExceeded)` - If the mint exceeds the budget
+pub fn consume_mint_budget(throttle: &mut MintThrottle, amount: u64) -> Result<()> {
+    if throttle.max_mint_per_window == 0 {
+        return Ok(());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let consumed = throttle
+        .consumed_at(now)
+        .checked_add(amount)
+        .ok_or(MintThrottleErrorCode::OverflowError)?;
+    require!(
+        consumed <= throttle.max_mint_per_window,
+        MintThrottleErrorCode::MintBudgetExceeded
+    );
+
+    throttle.consumed = consumed;
+    throttle.last_update = now;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry allowing an external program to mint ONyc through CPI
+///
+/// Derived from the delegate program ID, one entry per program. The delegate proves
+/// it is the caller by signing with its `authority`, a PDA of the delegate program
+/// that only that program can sign for.
+#[account]
+#[derive(InitSpace)]
+pub struct MintDelegate {
+    /// Program ID of the delegate program
+    pub program: Pubkey,
+    /// PDA of the delegate program that must sign its mints
+    pub authority: Pubkey,
+    /// Maximum ONyc in base units the delegate may mint over its lifetime
+    pub mint_cap: u64,
+    /// ONyc in base units minted by the delegate so far
+    pub minted: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
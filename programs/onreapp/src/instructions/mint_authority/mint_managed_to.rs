@@ -0,0 +1,147 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::{consume_mint_budget, ManagedMint, MintToErrorCode};
+use crate::state::{MintThrottle, State, StateSchemaErrorCode};
+use crate::utils::token_utils::mint_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when managed tokens are successfully minted to the boss account
+///
+/// Provides transparency for tracking minting of tokens managed alongside ONyc.
+#[event]
+pub struct ManagedTokensMintedEvent {
+    /// The managed mint from which tokens were minted
+    pub mint: Pubkey,
+    /// The boss account that received the newly minted tokens
+    pub boss: Pubkey,
+    /// The amount of tokens minted in base units
+    pub amount: u64,
+}
+
+/// Account structure for minting a managed token to the boss
+///
+/// The counterpart of `MintTo` for tokens registered with `add_managed_mint`.
+#[derive(Accounts)]
+pub struct MintManagedTo<'info> {
+    /// The program state account containing boss validation
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss authorized to perform minting operations and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// The registry entry providing the supply cap of the token
+    #[account(
+        seeds = [seeds::MANAGED_MINT, mint.key().as_ref()],
+        bump = managed_mint.bump,
+        has_one = mint
+    )]
+    pub managed_mint: Box<Account<'info, ManagedMint>>,
+
+    /// The managed token mint, mutable to allow supply updates during minting
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The boss's token account to receive minted tokens, created if needed
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_program
+    )]
+    pub boss_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived account that must be the mint authority of the token
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [seeds::MINT_AUTHORITY],
+        constraint = mint.mint_authority == Some(mint_authority.key()).into()
+            @ MintToErrorCode::NoMintAuthority,
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The mint throttle charged with the minted amount
+    ///
+    /// Created without a limit if it doesn't exist yet.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MintThrottle::INIT_SPACE,
+        seeds = [seeds::MINT_THROTTLE],
+        bump
+    )]
+    pub mint_throttle: Box<Account<'info, MintThrottle>>,
+
+    /// Token program owning the managed mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Mints new tokens of a managed token directly to the boss's account
+///
+/// Works like `mint_to` for ONyc, but caps the supply with the token's registry
+/// entry. Boss mints of all managed tokens share the mint throttle.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - The amount of tokens to mint in base units
+///
+/// # Returns
+/// * `Ok(())` - If minting completes successfully
+/// * `Err(MintToErrorCode::NoMintAuthority)` - If program lacks mint authority
+/// * `Err(MintThrottleErrorCode::MintBudgetExceeded)` - If the rolling mint budget is exhausted
+/// * `Err(TokenUtilsErrorCode::MaxSupplyExceeded)` - If the mint exceeds the token's supply cap
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - The mint must be registered as a managed token
+/// - Program must have mint authority for the token
+/// - Minted amounts are limited by the mint throttle
+///
+/// # Events
+/// * `ManagedTokensMintedEvent` - Emitted on successful minting with details
+pub fn mint_managed_to(ctx: Context<MintManagedTo>, amount: u64) -> Result<()> {
+    let mint_throttle = &mut ctx.accounts.mint_throttle;
+    mint_throttle.bump = ctx.bumps.mint_throttle;
+    consume_mint_budget(mint_throttle, amount)?;
+
+    let mint_authority_seeds = &[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]];
+
+    mint_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.boss_token_account.to_account_info(),
+        &ctx.accounts.mint_authority.to_account_info(),
+        &[mint_authority_seeds.as_slice()],
+        amount,
+        ctx.accounts.managed_mint.max_supply,
+    )?;
+
+    msg!(
+        "Minted {} tokens of managed mint {} to boss account",
+        amount,
+        ctx.accounts.mint.key()
+    );
+
+    emit!(ManagedTokensMintedEvent {
+        mint: ctx.accounts.mint.key(),
+        boss: ctx.accounts.boss.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::instructions::mint_authority::consume_mint_budget;
+use crate::state::{MintThrottle, State, StateSchemaErrorCode};
 use crate::utils::token_utils::mint_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -36,7 +37,13 @@ pub enum MintToErrorCode {
 #[derive(Accounts)]
 pub struct MintTo<'info> {
     /// The program state account containing boss and ONyc mint validation
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss, has_one = onyc_mint)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        has_one = onyc_mint,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The boss authorized to perform minting operations
@@ -78,6 +85,18 @@ pub struct MintTo<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
+    /// The mint throttle charged with the minted amount
+    ///
+    /// Created without a limit if it doesn't exist yet.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MintThrottle::INIT_SPACE,
+        seeds = [seeds::MINT_THROTTLE],
+        bump
+    )]
+    pub mint_throttle: Box<Account<'info, MintThrottle>>,
+
     /// SPL Token program for minting operations
     pub token_program: Interface<'info, TokenInterface>,
 
@@ -104,16 +123,22 @@ pub struct MintTo<'info> {
 /// # Returns
 /// * `Ok(())` - If minting completes successfully
 /// * `Err(MintToErrorCode::NoMintAuthority)` - If program lacks mint authority
+/// * `Err(MintThrottleErrorCode::MintBudgetExceeded)` - If the rolling mint budget is exhausted
 /// * `Err(_)` - If token minting operation fails
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
 /// - Program must have mint authority for the ONyc token
 /// - Boss account must match the one stored in program state
+/// - Minted amounts are limited by the mint throttle
 ///
 /// # Events
 /// * `OnycTokensMinted` - Emitted on successful minting with details
 pub fn mint_to(ctx: Context<MintTo>, amount: u64) -> Result<()> {
+    let mint_throttle = &mut ctx.accounts.mint_throttle;
+    mint_throttle.bump = ctx.bumps.mint_throttle;
+    consume_mint_budget(mint_throttle, amount)?;
+
     let mint_authority_seeds = &[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]];
     let mint_authority_signer_seeds = &[mint_authority_seeds.as_slice()];
 
@@ -121,7 +146,7 @@ pub fn mint_to(ctx: Context<MintTo>, amount: u64) -> Result<()> {
     mint_tokens(
         &ctx.accounts.token_program,
         &ctx.accounts.onyc_mint,
-        &ctx.accounts.boss_onyc_account,
+        &ctx.accounts.boss_onyc_account.to_account_info(),
         &ctx.accounts.mint_authority.to_account_info(),
         mint_authority_signer_seeds,
         amount,
@@ -0,0 +1,182 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::{consume_mint_budget, MintDelegate};
+use crate::state::{MintThrottle, State, StateSchemaErrorCode};
+use crate::utils::token_utils::mint_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a mint delegate mints ONyc
+///
+/// Provides transparency for tracking supply created by delegate programs.
+#[event]
+pub struct OnycMintedViaDelegateEvent {
+    /// Program ID of the delegate program that minted
+    pub program: Pubkey,
+    /// Token account that received the minted ONyc
+    pub destination: Pubkey,
+    /// The amount of ONyc minted in base units
+    pub amount: u64,
+    /// ONyc minted by the delegate over its lifetime after this mint
+    pub minted: u64,
+}
+
+/// Error codes for mint_to_via_delegate instruction operations
+#[error_code]
+pub enum MintToViaDelegateErrorCode {
+    /// The signer is not the registered authority of the delegate program
+    #[msg("Signer is not the mint delegate's authority")]
+    InvalidDelegateAuthority,
+    /// The mint would exceed the delegate's lifetime mint cap
+    #[msg("Mint exceeds the delegate's mint cap")]
+    DelegateCapExceeded,
+    /// The mint amount must be greater than zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+    /// The program doesn't have mint authority for the ONyc mint
+    #[msg("Program does not have mint authority for this token")]
+    NoMintAuthority,
+    /// The program kill switch is activated, preventing minting
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+    /// Arithmetic overflow occurred during cap accounting
+    #[msg("Math overflow")]
+    MathOverflow,
+}
+
+/// Account structure for minting ONyc on behalf of a registered delegate program
+///
+/// Called through CPI by the delegate program, which signs with its delegate
+/// authority PDA.
+#[derive(Accounts)]
+pub struct MintToViaDelegate<'info> {
+    /// The program state account containing ONyc mint and max supply
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = onyc_mint,
+        constraint = !state.is_kill_switch_active()? @ MintToViaDelegateErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The registry entry of the calling delegate program
+    #[account(
+        mut,
+        seeds = [seeds::MINT_DELEGATE, mint_delegate.program.as_ref()],
+        bump = mint_delegate.bump
+    )]
+    pub mint_delegate: Box<Account<'info, MintDelegate>>,
+
+    /// PDA of the delegate program proving the delegate is the caller
+    #[account(
+        constraint =
+            delegate_authority.key() == mint_delegate.authority
+            @ MintToViaDelegateErrorCode::InvalidDelegateAuthority
+    )]
+    pub delegate_authority: Signer<'info>,
+
+    /// The ONyc token mint account for minting new tokens
+    #[account(mut)]
+    pub onyc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// ONyc token account receiving the minted tokens
+    #[account(
+        mut,
+        token::mint = onyc_mint,
+        token::token_program = token_program
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived account that serves as the mint authority
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [seeds::MINT_AUTHORITY],
+        constraint =
+            onyc_mint.mint_authority == Some(mint_authority.key()).into()
+            @ MintToViaDelegateErrorCode::NoMintAuthority,
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The mint throttle charged with the minted amount
+    #[account(mut, seeds = [seeds::MINT_THROTTLE], bump = mint_throttle.bump)]
+    pub mint_throttle: Box<Account<'info, MintThrottle>>,
+
+    /// SPL Token program for minting operations
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Mints ONyc for a registered delegate program
+///
+/// The caller is verified against the registry through the delegate authority: a
+/// PDA derived from `MINT_DELEGATE_AUTHORITY` under the delegate program ID, which
+/// only the delegate program can sign for with `invoke_signed`. Mints count
+/// against the delegate's lifetime cap, the shared mint throttle and the ONyc max
+/// supply.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - The amount of ONyc to mint in base units
+///
+/// # Returns
+/// * `Ok(())` - If minting completes successfully
+/// * `Err(MintToViaDelegateErrorCode::InvalidDelegateAuthority)` - If the signer is not the delegate's authority
+/// * `Err(MintToViaDelegateErrorCode::DelegateCapExceeded)` - If the delegate's cap would be exceeded
+/// * `Err(MintThrottleErrorCode::MintBudgetExceeded)` - If the rolling mint budget is exhausted
+///
+/// # Access Control
+/// - Only registered delegate programs, signing with their delegate authority
+/// - Kill switch prevents minting when activated
+///
+/// # Effects
+/// - Mints ONyc to the destination account
+/// - Increases the delegate's minted total and the throttle consumption
+///
+/// # Events
+/// * `OnycMintedViaDelegateEvent` - Emitted with the delegate, destination and amount
+pub fn mint_to_via_delegate(ctx: Context<MintToViaDelegate>, amount: u64) -> Result<()> {
+    require!(amount > 0, MintToViaDelegateErrorCode::InvalidAmount);
+
+    let mint_delegate = &mut ctx.accounts.mint_delegate;
+    let minted = mint_delegate
+        .minted
+        .checked_add(amount)
+        .ok_or(MintToViaDelegateErrorCode::MathOverflow)?;
+    require!(
+        minted <= mint_delegate.mint_cap,
+        MintToViaDelegateErrorCode::DelegateCapExceeded
+    );
+    mint_delegate.minted = minted;
+
+    consume_mint_budget(&mut ctx.accounts.mint_throttle, amount)?;
+
+    let mint_authority_seeds = &[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]];
+    let mint_authority_signer_seeds = &[mint_authority_seeds.as_slice()];
+
+    mint_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.onyc_mint,
+        &ctx.accounts.destination.to_account_info(),
+        &ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_signer_seeds,
+        amount,
+        ctx.accounts.state.max_supply,
+    )?;
+
+    msg!(
+        "Minted {} ONyc via delegate {} to {}",
+        amount,
+        ctx.accounts.mint_delegate.program,
+        ctx.accounts.destination.key()
+    );
+
+    emit!(OnycMintedViaDelegateEvent {
+        program: ctx.accounts.mint_delegate.program,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        minted,
+    });
+
+    Ok(())
+}
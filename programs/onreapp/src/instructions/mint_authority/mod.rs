@@ -1,7 +1,27 @@
+pub mod add_managed_mint;
+pub mod configure_mint_delegate;
+pub mod managed_mint_state;
+pub mod mint_authority_utils;
+pub mod mint_delegate_state;
+pub mod mint_managed_to;
 pub mod mint_to;
+pub mod mint_to_via_delegate;
+pub mod remove_managed_mint;
+pub mod remove_mint_delegate;
 pub mod transfer_mint_authority_to_boss;
 pub mod transfer_mint_authority_to_program;
+pub mod update_managed_mint;
 
+pub use add_managed_mint::*;
+pub use configure_mint_delegate::*;
+pub use managed_mint_state::*;
+pub use mint_authority_utils::*;
+pub use mint_delegate_state::*;
+pub use mint_managed_to::*;
 pub use mint_to::*;
+pub use mint_to_via_delegate::*;
+pub use remove_managed_mint::*;
+pub use remove_mint_delegate::*;
 pub use transfer_mint_authority_to_boss::*;
 pub use transfer_mint_authority_to_program::*;
+pub use update_managed_mint::*;
@@ -0,0 +1,66 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::ManagedMint;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a managed token is removed from the registry
+#[event]
+pub struct ManagedMintRemovedEvent {
+    /// The token mint of the removed managed token
+    pub mint: Pubkey,
+}
+
+/// Account structure for removing a managed token
+#[derive(Accounts)]
+pub struct RemoveManagedMint<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The registry entry to close, rent returned to the boss
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::MANAGED_MINT, managed_mint.mint.as_ref()],
+        bump = managed_mint.bump
+    )]
+    pub managed_mint: Box<Account<'info, ManagedMint>>,
+
+    /// The boss account authorized to remove managed tokens
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Removes a token from the managed mint registry
+///
+/// Program mints of the token fall back to the supply cap in program state, and
+/// `mint_managed_to` no longer accepts it.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the token is removed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Closes the registry entry
+///
+/// # Events
+/// * `ManagedMintRemovedEvent` - Emitted with the removed mint
+pub fn remove_managed_mint(ctx: Context<RemoveManagedMint>) -> Result<()> {
+    let mint = ctx.accounts.managed_mint.mint;
+
+    msg!("Managed mint removed: {}", mint);
+
+    emit!(ManagedMintRemovedEvent { mint });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::MintDelegate;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a mint delegate is removed from the registry
+#[event]
+pub struct MintDelegateRemovedEvent {
+    /// Program ID of the removed delegate program
+    pub program: Pubkey,
+    /// ONyc minted by the delegate over its lifetime
+    pub minted: u64,
+}
+
+/// Account structure for removing a mint delegate
+#[derive(Accounts)]
+pub struct RemoveMintDelegate<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The registry entry to close, rent returned to the boss
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::MINT_DELEGATE, mint_delegate.program.as_ref()],
+        bump = mint_delegate.bump
+    )]
+    pub mint_delegate: Box<Account<'info, MintDelegate>>,
+
+    /// The boss account authorized to remove delegates
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Removes a program from the mint delegate registry
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the delegate is removed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Closes the registry entry, so the program can no longer mint
+///
+/// # Events
+/// * `MintDelegateRemovedEvent` - Emitted with the removed program
+pub fn remove_mint_delegate(ctx: Context<RemoveMintDelegate>) -> Result<()> {
+    let mint_delegate = &ctx.accounts.mint_delegate;
+
+    msg!(
+        "Mint delegate removed: program={}, minted={}",
+        mint_delegate.program,
+        mint_delegate.minted
+    );
+
+    emit!(MintDelegateRemovedEvent {
+        program: mint_delegate.program,
+        minted: mint_delegate.minted,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 use anchor_spl::token::spl_token::instruction::AuthorityType;
 use anchor_spl::token::{set_authority, SetAuthority};
@@ -15,7 +15,6 @@ use anchor_spl::token_interface::{Mint, TokenInterface};
 /// - Only the current boss can initiate the transfer
 /// - Program PDA must currently hold mint authority
 /// - Uses program-derived signatures for authorization
-
 /// Error codes for mint authority transfer to boss operations
 #[error_code]
 pub enum TransferMintAuthorityToBossErrorCode {
@@ -51,7 +50,12 @@ pub struct TransferMintAuthorityToBoss<'info> {
     pub boss: Signer<'info>,
 
     /// Program state account containing boss validation
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The token mint whose authority will be transferred to the boss
@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 use anchor_spl::token_interface::{set_authority, SetAuthority};
@@ -15,7 +15,6 @@ use anchor_spl::token_interface::{Mint, TokenInterface};
 /// - Only the current boss can transfer mint authority
 /// - Boss must be the current mint authority for the token
 /// - Authority can be recovered using `transfer_mint_authority_to_boss`
-
 /// Error codes for mint authority transfer to program operations
 #[error_code]
 pub enum TransferMintAuthorityToProgramErrorCode {
@@ -50,7 +49,12 @@ pub struct TransferMintAuthorityToProgram<'info> {
     pub boss: Signer<'info>,
 
     /// Program state account containing boss validation
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The token mint whose authority will be transferred to the program
@@ -0,0 +1,85 @@
+use crate::constants::seeds;
+use crate::instructions::mint_authority::ManagedMint;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a managed token's configuration is successfully updated
+///
+/// Provides transparency for tracking supply cap changes.
+#[event]
+pub struct ManagedMintUpdatedEvent {
+    /// The PDA address of the updated registry entry
+    pub managed_mint_pda: Pubkey,
+    /// The token mint of the managed token
+    pub mint: Pubkey,
+    /// Previous maximum supply of the token in base units (0 = no cap)
+    pub old_max_supply: u64,
+    /// New maximum supply of the token in base units (0 = no cap)
+    pub new_max_supply: u64,
+}
+
+/// Account structure for updating a managed token's configuration
+#[derive(Accounts)]
+pub struct UpdateManagedMint<'info> {
+    /// The registry entry to update
+    #[account(
+        mut,
+        seeds = [seeds::MANAGED_MINT, managed_mint.mint.as_ref()],
+        bump = managed_mint.bump
+    )]
+    pub managed_mint: Account<'info, ManagedMint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update managed tokens
+    pub boss: Signer<'info>,
+}
+
+/// Updates the supply cap of a managed token
+///
+/// Lowering the cap below the current supply only blocks further mints.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_supply` - New maximum supply of the token in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the configuration is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the supply cap of the registry entry
+///
+/// # Events
+/// * `ManagedMintUpdatedEvent` - Emitted with the previous and new supply cap
+pub fn update_managed_mint(ctx: Context<UpdateManagedMint>, max_supply: u64) -> Result<()> {
+    let managed_mint = &mut ctx.accounts.managed_mint;
+    let old_max_supply = managed_mint.max_supply;
+    managed_mint.max_supply = max_supply;
+
+    msg!(
+        "Managed mint updated: {}, max supply: {} -> {}",
+        managed_mint.mint,
+        old_max_supply,
+        max_supply
+    );
+
+    emit!(ManagedMintUpdatedEvent {
+        managed_mint_pda: managed_mint.key(),
+        mint: managed_mint.mint,
+        old_max_supply,
+        new_max_supply: max_supply,
+    });
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
 pub mod initialization;
+pub mod keeper;
 pub mod market_info;
 pub mod mint_authority;
 pub mod offer;
 pub mod redemption;
+pub mod share_class;
 pub mod state_operations;
 pub mod vault_operations;
 
 pub use initialization::*;
+pub use keeper::*;
 pub use market_info::*;
 pub use mint_authority::*;
 pub use offer::*;
 pub use redemption::*;
+pub use share_class::*;
 pub use state_operations::*;
 pub use vault_operations::*;
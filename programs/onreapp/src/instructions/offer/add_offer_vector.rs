@@ -1,7 +1,12 @@
-use super::offer_state::{Offer, OfferVector};
-use crate::constants::seeds;
-use crate::instructions::{find_active_vector_at, find_vector_index_by_start_time};
-use crate::state::State;
+use super::offer_state::{Offer, OfferVector, PricingMode};
+use crate::constants::{
+    seeds, MAX_BASIS_POINTS, MAX_PRICE_FIX_DURATION, MAX_VECTOR_STEPS, MIN_PRICE_FIX_DURATION,
+};
+use crate::instructions::{
+    calculate_vector_price_at, find_active_vector_at, find_vector_index_by_start_time,
+    prune_expired_vectors,
+};
+use crate::state::{Config, State, StateSchemaErrorCode};
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -14,16 +19,20 @@ use std::cmp::max;
 pub struct OfferVectorAddedEvent {
     /// The PDA address of the offer to which the vector was added
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Calculated start time when the vector becomes active (max(base_time, current_time))
     pub start_time: u64,
     /// Original base time specified for the vector
     pub base_time: u64,
     /// Base price with 9 decimal precision at the vector start
     pub base_price: u64,
-    /// Annual Percentage Rate scaled by 1,000,000 (1_000_000 = 1% APR)
-    pub apr: u64,
+    /// Signed Annual Percentage Rate scaled by 1,000,000 (1_000_000 = 1% APR)
+    pub apr: i64,
     /// Duration in seconds for each discrete pricing step
     pub price_fix_duration: u64,
+    /// Price evolution model of the vector
+    pub pricing_mode: PricingMode,
 }
 
 /// Event emitted when old pricing vectors are retired from an offer
@@ -33,6 +42,8 @@ pub struct OfferVectorEvictedEvent {
     pub offer_token_in_mint: Pubkey,
     /// The token out mint of the offer
     pub offer_token_out_mint: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Start time of the retired pricing vector
     pub vector_start_time: u64,
 }
@@ -74,10 +85,19 @@ pub struct AddOfferVector<'info> {
     )]
     pub token_out_mint: InterfaceAccount<'info, Mint>,
 
-    /// Program state account containing boss authorization
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    /// Program state account containing boss authorization and the max NAV jump guardrail
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account authorized to add pricing vectors to offers
     pub boss: Signer<'info>,
 }
@@ -86,26 +106,35 @@ pub struct AddOfferVector<'info> {
 ///
 /// This instruction creates a new pricing vector that defines price evolution over time
 /// using APR-based growth. The vector becomes active at the start time and
-/// implements discrete pricing steps based on the specified duration.
+/// implements either discrete pricing steps based on the specified duration or
+/// per-second linear pricing, depending on the pricing mode.
 ///
 /// The start time cannot be in the past. After adding the vector, old inactive vectors are
 /// automatically cleaned up to maintain storage efficiency.
 ///
+/// When `state.max_nav_jump_bps` is non-zero, the new vector's price at its start time must
+/// stay within that many basis points of the preceding vector's price at the same time.
+///
 /// # Arguments
 /// * `ctx` - The instruction context containing validated accounts
 /// * `start_time` - Optional Unix timestamp when the vector becomes active. If not provided,
-/// max(base_time, current_time) is used.
+///   max(base_time, current_time) is used.
 /// * `base_time` - Unix timestamp when the vector should become active
 /// * `base_price` - Initial price with scale=9 (1_000_000_000 = 1.0)
-/// * `apr` - Annual Percentage Rate scaled by 1,000,000 (0.01 = 1% APR = 10_000)
+/// * `apr` - Signed Annual Percentage Rate scaled by 1,000,000 (0.01 = 1% APR = 10_000),
+///   negative for a declining price
 /// * `price_fix_duration` - Duration in seconds for each discrete pricing step
+/// * `pricing_mode` - Whether the price moves in discrete steps or linearly per second
 ///
 /// # Returns
 /// * `Ok(())` - If the vector is successfully added
 /// * `Err(AddOfferVectorErrorCode::InvalidTimeRange)` - If start_time is before latest existing vector
 /// * `Err(AddOfferVectorErrorCode::ZeroValue)` - If any required value is zero
+/// * `Err(AddOfferVectorErrorCode::InvalidPriceFixDuration)` - If the step duration is out of bounds
+/// * `Err(AddOfferVectorErrorCode::TooManySteps)` - If base_time is too many steps before start_time
 /// * `Err(AddOfferVectorErrorCode::DuplicateStartTime)` - If start_time already exists
 /// * `Err(AddOfferVectorErrorCode::TooManyVectors)` - If offer has maximum vectors
+/// * `Err(AddOfferVectorErrorCode::NavJumpTooLarge)` - If the activation price deviates too much
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
@@ -118,10 +147,80 @@ pub fn add_offer_vector(
     start_time_opt: Option<u64>,
     base_time: u64,
     base_price: u64,
-    apr: u64,
+    apr: i64,
     price_fix_duration: u64,
+    pricing_mode: PricingMode,
 ) -> Result<()> {
     let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    append_offer_vector(
+        offer,
+        ctx.accounts.offer.key(),
+        OfferVectorParams {
+            start_time: start_time_opt,
+            base_time,
+            base_price,
+            apr,
+            price_fix_duration,
+            pricing_mode,
+        },
+        ctx.accounts.state.max_nav_jump_bps,
+        ctx.accounts.config.seconds_per_year,
+    )
+}
+
+/// Parameters of a pricing vector to add to an offer
+///
+/// Mirrors the arguments of `add_offer_vector` for instructions adding a vector
+/// as part of a larger operation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OfferVectorParams {
+    /// Unix timestamp when the vector becomes active, max(base_time, current_time) if not provided
+    pub start_time: Option<u64>,
+    /// Unix timestamp the price accrues from
+    pub base_time: u64,
+    /// Initial price with scale=9 (1_000_000_000 = 1.0)
+    pub base_price: u64,
+    /// Signed Annual Percentage Rate scaled by 1,000,000
+    pub apr: i64,
+    /// Duration in seconds for each discrete pricing step
+    pub price_fix_duration: u64,
+    /// Whether the price moves in discrete steps or linearly per second
+    pub pricing_mode: PricingMode,
+}
+
+/// Validates a pricing vector and adds it to the offer
+///
+/// Performs the checks and vector cleanup described on `add_offer_vector`.
+///
+/// # Arguments
+/// * `offer` - Mutable reference to the offer receiving the vector
+/// * `offer_pda` - Address of the offer, reported in the event
+/// * `params` - Parameters of the new vector
+/// * `max_nav_jump_bps` - Maximum price deviation from the preceding vector (0 = unchecked)
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(())` - If the vector is added
+/// * `Err(AddOfferVectorErrorCode::_)` - If the vector fails validation or no slot is free
+///
+/// # Events
+/// * `OfferVectorAddedEvent` - Emitted on successful vector addition with parameters
+pub(crate) fn append_offer_vector(
+    offer: &mut Offer,
+    offer_pda: Pubkey,
+    params: OfferVectorParams,
+    max_nav_jump_bps: u16,
+    seconds_per_year: u64,
+) -> Result<()> {
+    let OfferVectorParams {
+        start_time: start_time_opt,
+        base_time,
+        base_price,
+        apr,
+        price_fix_duration,
+        pricing_mode,
+    } = params;
     let current_time = Clock::get()?.unix_timestamp as u64;
     let start_time = start_time_opt.unwrap_or_else(|| max(current_time, base_time));
 
@@ -131,23 +230,26 @@ pub fn add_offer_vector(
         base_price,
         price_fix_duration,
         current_time,
-        &offer,
+        offer,
     )?;
 
     // Create the new time vector
-    let new_vector = OfferVector {
+    let new_vector = OfferVector::new(
         start_time,
         base_time,
         base_price,
         apr,
         price_fix_duration,
-    };
+        pricing_mode,
+    );
+
+    validate_nav_jump(offer, &new_vector, max_nav_jump_bps, seconds_per_year)?;
 
     // Clean up old vectors before emitting success message
     clean_old_vectors(offer, &new_vector, current_time)?;
 
     // Find an empty slot in time_vectors array
-    let empty_slot_index = find_vector_index_by_start_time(&offer, 0)
+    let empty_slot_index = find_vector_index_by_start_time(offer, 0)
         .ok_or_else(|| error!(AddOfferVectorErrorCode::TooManyVectors))?;
 
     // Add the vector to the offer
@@ -155,17 +257,19 @@ pub fn add_offer_vector(
 
     msg!(
         "Time vector added to offer: {}, vector start_time: {}",
-        ctx.accounts.offer.key(),
+        offer_pda,
         start_time
     );
 
     emit!(OfferVectorAddedEvent {
-        offer_pda: ctx.accounts.offer.key(),
+        offer_pda,
+        offer_version: offer.offer_version,
         start_time,
         base_time,
         base_price,
         apr,
         price_fix_duration,
+        pricing_mode,
     });
 
     Ok(())
@@ -173,8 +277,12 @@ pub fn add_offer_vector(
 
 /// Validates input parameters for pricing vector creation
 ///
-/// Ensures all required parameters are non-zero values to prevent
-/// invalid pricing vector configurations.
+/// Ensures all required parameters are non-zero values and that the step schedule
+/// stays within sane bounds: `price_fix_duration` must lie within
+/// `MIN_PRICE_FIX_DURATION..=MAX_PRICE_FIX_DURATION`, and the steps already elapsed
+/// at activation (from `base_time` to `start_time`) may not exceed `MAX_VECTOR_STEPS`.
+/// The life of the preceding vector is deliberately not bounded so a vector with a
+/// pathological schedule can always be superseded.
 ///
 /// # Arguments
 /// * `base_time` - Unix timestamp for vector activation
@@ -184,6 +292,8 @@ pub fn add_offer_vector(
 /// # Returns
 /// * `Ok(())` - If all parameters are valid
 /// * `Err(AddOfferVectorErrorCode::ZeroValue)` - If any parameter is zero
+/// * `Err(AddOfferVectorErrorCode::InvalidPriceFixDuration)` - If the step duration is out of bounds
+/// * `Err(AddOfferVectorErrorCode::TooManySteps)` - If base_time is too many steps before start_time
 fn validate_inputs(
     start_time: u64,
     base_time: u64,
@@ -199,6 +309,14 @@ fn validate_inputs(
     require!(base_time > 0, AddOfferVectorErrorCode::ZeroValue);
     require!(base_price > 0, AddOfferVectorErrorCode::ZeroValue);
     require!(price_fix_duration > 0, AddOfferVectorErrorCode::ZeroValue);
+    require!(
+        (MIN_PRICE_FIX_DURATION..=MAX_PRICE_FIX_DURATION).contains(&price_fix_duration),
+        AddOfferVectorErrorCode::InvalidPriceFixDuration
+    );
+    require!(
+        start_time.saturating_sub(base_time) / price_fix_duration <= MAX_VECTOR_STEPS,
+        AddOfferVectorErrorCode::TooManySteps
+    );
 
     // Validate start_time is not duplicated
     let existing_start_times: Vec<u64> = offer
@@ -224,6 +342,72 @@ fn validate_inputs(
     Ok(())
 }
 
+/// Validates that a new vector does not introduce a NAV gap at its activation time
+///
+/// Compares the price implied by the new vector at its start time with the price the
+/// preceding vector would quote at that same moment. The check is skipped when the
+/// guardrail is disabled or when the offer has no earlier vector to compare against.
+///
+/// # Arguments
+/// * `offer` - The offer containing the existing pricing vectors
+/// * `new_vector` - The vector about to be added
+/// * `max_nav_jump_bps` - Maximum allowed deviation in basis points (0 = no limit)
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(())` - If the deviation is within the configured limit
+/// * `Err(AddOfferVectorErrorCode::ZeroPreviousNav)` - If the preceding vector quotes a zero price
+/// * `Err(AddOfferVectorErrorCode::NavJumpTooLarge)` - If the deviation exceeds the limit
+fn validate_nav_jump(
+    offer: &Offer,
+    new_vector: &OfferVector,
+    max_nav_jump_bps: u16,
+    seconds_per_year: u64,
+) -> Result<()> {
+    if max_nav_jump_bps == 0 {
+        return Ok(());
+    }
+
+    let activation_time = new_vector.start_time;
+    let prev_vector = match find_active_vector_at(offer, activation_time - 1) {
+        Ok(vector) => vector,
+        Err(_) => return Ok(()), // First vector, nothing to compare against
+    };
+
+    let prev_price = calculate_vector_price_at(
+        &prev_vector,
+        max(activation_time, prev_vector.base_time),
+        seconds_per_year,
+    )?;
+    let new_price = calculate_vector_price_at(
+        new_vector,
+        max(activation_time, new_vector.base_time),
+        seconds_per_year,
+    )?;
+
+    let deviation_bps = (new_price.abs_diff(prev_price) as u128)
+        .checked_mul(MAX_BASIS_POINTS as u128)
+        .ok_or(OfferCoreError::OverflowError)?
+        .checked_div(prev_price as u128)
+        // A declining vector may have decayed to zero, leaving no base to measure against
+        .ok_or(AddOfferVectorErrorCode::ZeroPreviousNav)?;
+
+    msg!(
+        "NAV jump at {}: {} -> {} ({} bps)",
+        activation_time,
+        prev_price,
+        new_price,
+        deviation_bps
+    );
+
+    require!(
+        deviation_bps <= max_nav_jump_bps as u128,
+        AddOfferVectorErrorCode::NavJumpTooLarge
+    );
+
+    Ok(())
+}
+
 /// Removes old inactive pricing vectors to maintain storage efficiency
 ///
 /// This function preserves the currently active vector and the most recent
@@ -249,32 +433,7 @@ fn clean_old_vectors(offer: &mut Offer, new_vector: &OfferVector, current_time:
         Err(_) => return Ok(()), // No active vector found, nothing to clean
     };
 
-    // Find previously active vector (closest smaller vector_start_timestamp)
-    let prev_vector = find_active_vector_at(offer, active_vector?.start_time - 1);
-
-    let prev_vector_start_time = match prev_vector {
-        Ok(vector) => vector.start_time,
-        Err(_) => 0, // If no previous vector exists, use 0
-    };
-
-    // Clear all vectors except active and previous
-    for vector in offer.vectors.iter_mut() {
-        if vector.start_time != 0 // Don't touch already empty slots
-            // Keep active vector
-            && vector.start_time != active_vector_start_time
-            // Keep previous vector
-            && vector.start_time != prev_vector_start_time
-            // Keep all future vectors
-            && vector.start_time < active_vector_start_time
-        {
-            emit!(OfferVectorEvictedEvent {
-                offer_token_in_mint: offer.token_in_mint,
-                offer_token_out_mint: offer.token_out_mint,
-                vector_start_time: vector.start_time
-            });
-            *vector = OfferVector::default(); // Clear the vector
-        }
-    }
+    prune_expired_vectors(offer, active_vector_start_time);
 
     Ok(())
 }
@@ -305,6 +464,53 @@ pub enum AddOfferVectorErrorCode {
     #[msg("Invalid input: apr must be <= 10000000")]
     InvalidAPR,
 
-    #[msg("Invalid input: price_fix_duration must be <= 31536000")]
+    /// The price_fix_duration is outside MIN_PRICE_FIX_DURATION..=MAX_PRICE_FIX_DURATION
+    #[msg("Invalid input: price_fix_duration must be between 60 and 31536000")]
     InvalidPriceFixDuration,
+
+    /// The base_time lies more than MAX_VECTOR_STEPS pricing steps before start_time
+    #[msg("Invalid input: vector would span too many pricing steps")]
+    TooManySteps,
+
+    /// The new vector's activation price deviates from the previous vector by more than max_nav_jump_bps
+    #[msg("NAV jump exceeds the configured maximum")]
+    NavJumpTooLarge,
+
+    /// The preceding vector quotes a zero price at the new vector's activation time
+    #[msg("NAV jump cannot be measured: previous vector price is zero")]
+    ZeroPreviousNav,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_SECONDS_PER_YEAR;
+    use crate::instructions::PricingMode;
+
+    #[test]
+    fn validate_nav_jump_rejects_a_previous_price_decayed_to_zero() {
+        let mut offer = Box::new(<Offer as bytemuck::Zeroable>::zeroed());
+        offer.vectors[0] = OfferVector::new(
+            1_000,
+            1_000,
+            1_000_000_000,
+            -1_000_000,
+            86_400,
+            PricingMode::Linear,
+        );
+        let activation_time = 1_000 + DEFAULT_SECONDS_PER_YEAR;
+        let new_vector = OfferVector::new(
+            activation_time,
+            activation_time,
+            1_000_000_000,
+            0,
+            86_400,
+            PricingMode::Linear,
+        );
+
+        assert_eq!(
+            validate_nav_jump(&offer, &new_vector, 500, DEFAULT_SECONDS_PER_YEAR).unwrap_err(),
+            AddOfferVectorErrorCode::ZeroPreviousNav.into()
+        );
+    }
 }
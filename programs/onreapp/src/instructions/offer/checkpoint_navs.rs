@@ -0,0 +1,138 @@
+use crate::constants::seeds;
+use crate::instructions::keeper::accrue_keeper_reward;
+use crate::instructions::offer::offer_utils::{calculate_vector_price_at, find_active_vector_at};
+use crate::instructions::{KeeperCrank, KeeperRewardConfig, KeeperRewards, Offer};
+use crate::state::Config;
+use crate::utils::event_cpi::event_emitter;
+use anchor_lang::prelude::*;
+use solana_program::compute_units::sol_remaining_compute_units;
+
+/// Compute units kept in reserve before checkpointing another offer
+///
+/// Covers loading one offer, scanning its vectors and writing the checkpoint, plus
+/// the event emitted at the end of the instruction.
+pub const CHECKPOINT_COMPUTE_RESERVE: u64 = 20_000;
+
+/// Event emitted when a batch of offer NAV checkpoints has been written
+///
+/// Provides transparency for tracking keeper progress through the offer set.
+#[event]
+pub struct NavsCheckpointedEvent {
+    /// Number of leading offer accounts processed in this batch
+    pub processed: u32,
+    /// Number of offer accounts passed to the instruction
+    pub total: u32,
+    /// Unix timestamp recorded as the checkpoint time
+    pub timestamp: u64,
+}
+
+/// Account structure for checkpointing the NAV of many offers at once
+///
+/// The offers to checkpoint are passed as writable remaining accounts. Each one is
+/// validated as an Offer account owned by this program.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct CheckpointNavs<'info> {
+    /// The keeper submitting the checkpoint batch
+    pub keeper: Signer<'info>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Optional keeper reward configuration; rewards accrue when passed together
+    /// with `keeper_rewards`
+    #[account(mut, seeds = [seeds::KEEPER_REWARD_CONFIG], bump = keeper_reward_config.bump)]
+    pub keeper_reward_config: Option<Box<Account<'info, KeeperRewardConfig>>>,
+
+    /// Optional reward account of the keeper, rewarded per checkpointed offer
+    #[account(
+        mut,
+        seeds = [seeds::KEEPER_REWARDS, keeper.key().as_ref()],
+        bump = keeper_rewards.bump
+    )]
+    pub keeper_rewards: Option<Box<Account<'info, KeeperRewards>>>,
+}
+
+/// Records the current NAV of every offer passed in remaining accounts
+///
+/// Offers are processed in order in a single pass. Processing stops early once the
+/// remaining compute budget falls below `CHECKPOINT_COMPUTE_RESERVE`, and the number
+/// of processed offers is returned so keepers can resubmit the rest. Offers without
+/// an active pricing vector are skipped but still count as processed.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing the offers as remaining accounts
+///
+/// # Returns
+/// * `Ok(processed)` - Number of leading remaining accounts processed
+/// * `Err(_)` - If a remaining account is not a writable Offer account
+///
+/// # Access Control
+/// - Permissionless: any keeper may checkpoint offers since the recorded NAV is
+///   derived from the offer's own pricing vectors
+///
+/// # Effects
+/// - Updates `nav_checkpoint` and `nav_checkpoint_time` of each processed offer
+/// - Accrues the crank reward for each checkpointed offer when the reward accounts
+///   are passed; skipped offers earn no reward
+///
+/// # Events
+/// * `NavsCheckpointedEvent` - Emitted with the batch progress
+/// * `KeeperRewardAccruedEvent` - Emitted when a keeper reward is accrued
+pub fn checkpoint_navs<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CheckpointNavs<'info>>,
+) -> Result<u32> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let total = ctx.remaining_accounts.len() as u32;
+    let mut processed: u32 = 0;
+    let mut checkpointed: u64 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        if sol_remaining_compute_units() < CHECKPOINT_COMPUTE_RESERVE {
+            break;
+        }
+
+        let offer_loader = AccountLoader::<Offer>::try_from(account_info)?;
+        let mut offer = offer_loader.load_mut()?;
+
+        if let Ok(active_vector) = find_active_vector_at(&offer, current_time) {
+            offer.nav_checkpoint = calculate_vector_price_at(
+                &active_vector,
+                current_time,
+                ctx.accounts.config.seconds_per_year,
+            )?;
+            offer.nav_checkpoint_time = current_time;
+            checkpointed += 1;
+        }
+
+        processed += 1;
+    }
+
+    let events = event_emitter!(ctx);
+    accrue_keeper_reward(
+        &events,
+        ctx.accounts
+            .keeper_reward_config
+            .as_deref_mut()
+            .map(|c| &mut **c),
+        ctx.accounts.keeper_rewards.as_deref_mut().map(|r| &mut **r),
+        KeeperCrank::CheckpointNavs,
+        checkpointed,
+    )?;
+
+    msg!(
+        "NAVs checkpointed: {}/{} offers at {}",
+        processed,
+        total,
+        current_time
+    );
+
+    emit!(NavsCheckpointedEvent {
+        processed,
+        total,
+        timestamp: current_time,
+    });
+
+    Ok(processed)
+}
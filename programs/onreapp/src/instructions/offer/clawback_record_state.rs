@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Audit record of a take reversed by `clawback_take`
+///
+/// One record exists per reversed take, addressed by the take's receipt.
+#[account]
+#[derive(InitSpace)]
+pub struct ClawbackRecord {
+    /// The offer PDA the reversed take was executed against
+    pub offer: Pubkey,
+    /// The user whose take was reversed
+    pub user: Pubkey,
+    /// Unix timestamp of the reversed take
+    pub take_timestamp: u64,
+    /// Amount of token_in refunded to the user
+    pub token_in_amount: u64,
+    /// Amount of token_out returned by the user
+    pub token_out_amount: u64,
+    /// Unix timestamp of the clawback
+    pub clawback_timestamp: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+}
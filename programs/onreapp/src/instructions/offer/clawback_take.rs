@@ -0,0 +1,332 @@
+use crate::constants::{seeds, CLAWBACK_WINDOW_SECONDS};
+use crate::instructions::{ClawbackRecord, Offer, TakeReceipt};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::{burn_tokens, program_controls_mint, transfer_tokens};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a take is clawed back
+///
+/// Audit trail of every emergency reversal, including both authorizing signers.
+#[event]
+pub struct TakeClawedBackEvent {
+    /// The PDA address of the clawback record
+    pub clawback_record_pda: Pubkey,
+    /// The PDA address of the offer the take was executed against
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// The user whose take was reversed
+    pub user: Pubkey,
+    /// Unix timestamp of the reversed take
+    pub take_timestamp: u64,
+    /// Amount of token_in refunded to the user
+    pub token_in_amount: u64,
+    /// Amount of token_out returned by the user
+    pub token_out_amount: u64,
+    /// Whether the returned token_out was burned (true) or sent back to the offer vault (false)
+    pub token_out_burned: bool,
+    /// The boss account that authorized the clawback
+    pub boss: Pubkey,
+    /// The guardian account that co-signed the clawback
+    pub guardian: Pubkey,
+    /// Unix timestamp of the clawback
+    pub timestamp: u64,
+}
+
+/// Account structure for clawing back a take
+///
+/// This struct defines the accounts required to reverse a take executed at a wrong
+/// NAV. The boss, the guardian and the user all sign: the user returns token_out and
+/// the boss refunds token_in, both as recorded by the take's receipt.
+#[derive(Accounts)]
+pub struct ClawbackTake<'info> {
+    /// The offer the reversed take was executed against
+    ///
+    /// Must be flagged for clawbacks.
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump,
+        constraint = offer.load()?.clawback_enabled() @ ClawbackTakeErrorCode::ClawbackNotEnabled
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Receipt written by the take being reversed
+    ///
+    /// Closed to the user by the clawback, so the same take cannot be reversed twice.
+    #[account(
+        mut,
+        close = user,
+        constraint = take_receipt.offer == offer.key() @ ClawbackTakeErrorCode::InvalidTakeReceipt,
+        constraint = take_receipt.user == user.key() @ ClawbackTakeErrorCode::InvalidTakeReceipt
+    )]
+    pub take_receipt: Box<Account<'info, TakeReceipt>>,
+
+    /// Audit record of the clawback
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + ClawbackRecord::INIT_SPACE,
+        seeds = [seeds::CLAWBACK_RECORD, take_receipt.key().as_ref()],
+        bump
+    )]
+    pub clawback_record: Box<Account<'info, ClawbackRecord>>,
+
+    /// Program state account containing boss and guardian authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ ClawbackTakeErrorCode::InvalidBoss,
+        has_one = guardian @ ClawbackTakeErrorCode::InvalidGuardian,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorizing the clawback, refunding token_in and paying for the record
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// The guardian account co-signing the clawback
+    #[account(
+        constraint = guardian.key() != Pubkey::default() @ ClawbackTakeErrorCode::InvalidGuardian
+    )]
+    pub guardian: Signer<'info>,
+
+    /// The user returning token_out, receiving the token_in refund and the receipt's rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// The input token mint of the offer
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the input token
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// The output token mint of the offer
+    ///
+    /// Must be mutable since returned token_out is burned when the program has mint authority.
+    #[account(
+        mut,
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the output token
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Boss's input token account funding the refund
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's input token account receiving the refund
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_in_program
+    )]
+    pub user_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's output token account returning the tokens received from the take
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_program
+    )]
+    pub user_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived authority that controls offer vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Offer vault account receiving returned token_out when the program lacks mint authority
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority used to decide whether token_out is burned
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Reverses a take executed at a wrong NAV
+///
+/// The take is identified by the receipt it wrote, which holds its timestamp and
+/// amounts. The refund covers the full payment including the fee. The user returns
+/// the token_out received, which is burned if the program has mint authority of
+/// token_out or sent back to the offer vault otherwise, and the boss refunds the
+/// token_in paid.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the take is successfully reversed
+/// * `Err(ClawbackTakeErrorCode::ClawbackNotEnabled)` - If the offer is not flagged for clawbacks
+/// * `Err(ClawbackTakeErrorCode::InvalidTakeReceipt)` - If the receipt belongs to another offer or user
+/// * `Err(ClawbackTakeErrorCode::ClawbackWindowExpired)` - If the take is older than the dispute window
+///
+/// # Access Control
+/// - Boss, guardian and the user must all sign
+/// - The offer must be flagged through `set_offer_clawback`
+/// - Only takes within `CLAWBACK_WINDOW_SECONDS` can be reversed
+/// - Each take receipt can be consumed at most once
+///
+/// # Effects
+/// - Burns or returns the user's token_out
+/// - Refunds token_in from the boss to the user
+/// - Closes the take receipt, returning its rent to the user
+/// - Creates a clawback record storing the reversal
+///
+/// # Events
+/// * `TakeClawedBackEvent` - Emitted with the reversal details and both signers
+pub fn clawback_take(ctx: Context<ClawbackTake>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let take_timestamp = ctx.accounts.take_receipt.take_timestamp;
+    let token_in_amount = ctx.accounts.take_receipt.token_in_amount;
+    let token_out_amount = ctx.accounts.take_receipt.token_out_amount;
+
+    require!(
+        current_time <= take_timestamp.saturating_add(CLAWBACK_WINDOW_SECONDS),
+        ClawbackTakeErrorCode::ClawbackWindowExpired
+    );
+
+    // Step 1: User returns token_out
+    let token_out_burned = program_controls_mint(
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.mint_authority.to_account_info(),
+    );
+
+    if token_out_burned {
+        burn_tokens(
+            &ctx.accounts.token_out_program,
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.user_token_out_account,
+            &ctx.accounts.user.to_account_info(),
+            &[],
+            token_out_amount,
+        )?;
+    } else {
+        transfer_tokens(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.token_out_program,
+            &ctx.accounts.user_token_out_account,
+            &ctx.accounts.vault_token_out_account,
+            &ctx.accounts.user.to_account_info(),
+            None,
+            token_out_amount,
+        )?;
+    }
+
+    // Step 2: Boss refunds token_in
+    transfer_tokens(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_in_program,
+        &ctx.accounts.boss_token_in_account,
+        &ctx.accounts.user_token_in_account,
+        &ctx.accounts.boss.to_account_info(),
+        None,
+        token_in_amount,
+    )?;
+
+    let clawback_record = &mut ctx.accounts.clawback_record;
+    clawback_record.offer = ctx.accounts.offer.key();
+    clawback_record.user = ctx.accounts.user.key();
+    clawback_record.take_timestamp = take_timestamp;
+    clawback_record.token_in_amount = token_in_amount;
+    clawback_record.token_out_amount = token_out_amount;
+    clawback_record.clawback_timestamp = current_time;
+    clawback_record.bump = ctx.bumps.clawback_record;
+
+    msg!(
+        "CLAWBACK: offer={}, user={}, take_timestamp={}, token_in_refunded={}, token_out_returned={}, burned={}",
+        ctx.accounts.offer.key(),
+        ctx.accounts.user.key(),
+        take_timestamp,
+        token_in_amount,
+        token_out_amount,
+        token_out_burned
+    );
+
+    emit!(TakeClawedBackEvent {
+        clawback_record_pda: ctx.accounts.clawback_record.key(),
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: ctx.accounts.offer.load()?.offer_version,
+        user: ctx.accounts.user.key(),
+        take_timestamp,
+        token_in_amount,
+        token_out_amount,
+        token_out_burned,
+        boss: ctx.accounts.boss.key(),
+        guardian: ctx.accounts.guardian.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Error codes for clawback operations
+#[error_code]
+pub enum ClawbackTakeErrorCode {
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+
+    /// The guardian is unset or does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+
+    /// The offer is not flagged for clawbacks
+    #[msg("Clawback is not enabled for this offer")]
+    ClawbackNotEnabled,
+
+    /// The take receipt was written for another offer or user
+    #[msg("Take receipt does not belong to this offer and user")]
+    InvalidTakeReceipt,
+
+    /// The take is older than the dispute window
+    #[msg("Clawback window has expired")]
+    ClawbackWindowExpired,
+}
@@ -0,0 +1,438 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::{
+    record_immediate_withdrawal, OfferVaultWithdrawEvent, VaultAuthorityType, VaultCoreError,
+    WithdrawalAllowlist,
+};
+use crate::instructions::{Offer, OfferRegistry, RedemptionOffer};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Number of remaining accounts describing one residual balance sweep
+const ACCOUNTS_PER_SWEEP: usize = 4;
+
+/// Event emitted when an offer is closed, with its lifetime statistics
+///
+/// Provides a final record of the offer for indexers once the account is gone.
+#[event]
+pub struct OfferClosedFinalEvent {
+    /// The PDA address of the closed offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Input token mint of the closed offer
+    pub token_in_mint: Pubkey,
+    /// Output token mint of the closed offer
+    pub token_out_mint: Pubkey,
+    /// Cumulative token_in paid by takes of the offer, including fees
+    pub total_volume_token_in: u128,
+    /// Cumulative token_in fees collected by takes of the offer
+    pub total_fees_collected_token_in: u128,
+    /// Amount of token_in returned from the offer vault to the boss
+    pub token_in_swept: u64,
+    /// Amount of token_out returned from the offer vault to the boss
+    pub token_out_swept: u64,
+    /// The boss account receiving the offer's rent
+    pub boss: Pubkey,
+}
+
+/// Account structure for closing an offer
+///
+/// Residual offer vault balances to return to the boss are passed as remaining
+/// accounts, four per mint: the mint, the offer vault token account, the boss's
+/// token account and the mint's writable withdrawal timelock PDA. Only the offer's token_in
+/// and token_out mints can be swept, and only from the offer's own vault, whose
+/// accounts must both be swept for the close to succeed.
+#[derive(Accounts)]
+pub struct CloseOffer<'info> {
+    /// The offer account to close, with its rent returned to the boss
+    #[account(
+        mut,
+        close = boss,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the input token
+    #[account(address = *token_in_mint.to_account_info().owner)]
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Token program interface for the output token
+    #[account(address = *token_out_mint.to_account_info().owner)]
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Program-derived authority that controls offer vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The withdrawal destination allowlist, which must allow the boss for sweeps
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; deserialized in the
+    /// handler only if it has been created
+    #[account(seeds = [seeds::WITHDRAWAL_ALLOWLIST], bump)]
+    pub withdrawal_allowlist: UncheckedAccount<'info>,
+
+    /// Registry of the live offers, from which the offer is removed
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; updated in the handler
+    /// only if it has been created
+    #[account(mut, seeds = [seeds::OFFER_REGISTRY], bump)]
+    pub offer_registry: UncheckedAccount<'info>,
+
+    /// The redemption offer of the inverse pair, which prices redemptions with the offer
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; the close is rejected in
+    /// the handler if it has been created
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_out_mint.key().as_ref(),
+            token_in_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: UncheckedAccount<'info>,
+
+    /// Program state account containing boss authorization and the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to close the offer and receive the rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Closes an offer, optionally returning its residual vault balances to the boss
+///
+/// Every sweep passed in remaining accounts transfers the whole balance of the
+/// offer's own vault in its token_in or token_out mint to the boss. An offer with its
+/// own vault must sweep both mints, so the vault is left empty for an offer made
+/// again at the same PDA; a vault account that was closed counts as empty. The shared
+/// offer vault holds inventory of other offers, so offers without their own vault
+/// cannot sweep; their residual balance is withdrawn with `offer_vault_withdraw`.
+/// Non-empty sweeps are offer vault withdrawals: the boss must be an allowed
+/// destination and amounts above the mint's immediate threshold still have to go
+/// through `propose_vault_withdraw` before the offer can be closed.
+/// Offers backing a redemption offer cannot be closed until it is closed, which in
+/// turn requires its redemption requests to be settled. The offer pricing the ONyc
+/// supply in `get_global_tvl` cannot be closed either. Offers already closed by
+/// `crank_expired_offer` are closed the same way, reclaiming their rent.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts and the sweeps as
+///   remaining accounts
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully closed
+/// * `Err(CloseOfferErrorCode::PrimaryOffer)` - If the offer is the primary offer
+/// * `Err(CloseOfferErrorCode::PendingRedemptions)` - If redemption requests priced by the offer are outstanding
+/// * `Err(CloseOfferErrorCode::RedemptionOfferExists)` - If a redemption offer is priced by the offer
+/// * `Err(CloseOfferErrorCode::SharedVaultSweep)` - If sweeps are passed for an offer without its own vault
+/// * `Err(CloseOfferErrorCode::InvalidSweepAccounts)` - If the sweep accounts are malformed
+/// * `Err(CloseOfferErrorCode::DuplicateSweepMint)` - If a mint is swept twice
+/// * `Err(CloseOfferErrorCode::UnsweptVault)` - If an own vault account is not swept
+/// * `Err(CloseOfferErrorCode::TimelockRequired)` - If a balance exceeds the immediate threshold
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the boss is not an allowed destination
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Transfers the swept offer vault balances to the boss
+/// - Advances the vault event sequence once per non-empty sweep
+/// - Removes the offer from the offer registry
+/// - Closes the offer account and returns its rent to the boss
+///
+/// # Events
+/// * `OfferVaultWithdrawEvent` - Emitted for every non-empty sweep
+/// * `OfferClosedFinalEvent` - Emitted with the lifetime statistics and swept amounts
+pub fn close_offer<'info>(ctx: Context<'_, '_, 'info, 'info, CloseOffer<'info>>) -> Result<()> {
+    let offer_pda = ctx.accounts.offer.key();
+    require!(
+        offer_pda != ctx.accounts.state.primary_offer,
+        CloseOfferErrorCode::PrimaryOffer
+    );
+    ensure_no_dependent_redemption_offer(&ctx.accounts.redemption_offer, &offer_pda)?;
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        remaining_accounts.len().is_multiple_of(ACCOUNTS_PER_SWEEP),
+        CloseOfferErrorCode::InvalidSweepAccounts
+    );
+    let own_vault = ctx.accounts.offer.load()?.has_own_vault();
+    require!(
+        own_vault || remaining_accounts.is_empty(),
+        CloseOfferErrorCode::SharedVaultSweep
+    );
+
+    let vault_authority_seed = ctx
+        .accounts
+        .offer
+        .load()?
+        .vault_authority_seed(&ctx.accounts.offer.key());
+    let vault_authority_seeds = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.vault_authority],
+    ];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+
+    let mut token_in_swept: Option<u64> = None;
+    let mut token_out_swept: Option<u64> = None;
+
+    for accounts in remaining_accounts.chunks(ACCOUNTS_PER_SWEEP) {
+        let (token_program, swept) = if accounts[0].key() == ctx.accounts.token_in_mint.key() {
+            (&ctx.accounts.token_in_program, &mut token_in_swept)
+        } else if accounts[0].key() == ctx.accounts.token_out_mint.key() {
+            (&ctx.accounts.token_out_program, &mut token_out_swept)
+        } else {
+            return err!(CloseOfferErrorCode::InvalidSweepAccounts);
+        };
+        require!(swept.is_none(), CloseOfferErrorCode::DuplicateSweepMint);
+
+        let mint = InterfaceAccount::<Mint>::try_from(&accounts[0])?;
+        require_keys_eq!(
+            accounts[1].key(),
+            get_associated_token_address_with_program_id(
+                &ctx.accounts.vault_authority.key(),
+                &mint.key(),
+                &token_program.key()
+            ),
+            CloseOfferErrorCode::InvalidSweepAccounts
+        );
+        // A vault account closed by `sweep_vault_dust` holds nothing to sweep
+        if accounts[1].data_is_empty() {
+            *swept = Some(0);
+            continue;
+        }
+        let mut vault_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+
+        let amount = vault_token_account.amount;
+        if amount > 0 {
+            let boss_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[2])?;
+            require!(
+                boss_token_account.owner == ctx.accounts.boss.key()
+                    && boss_token_account.mint == mint.key(),
+                CloseOfferErrorCode::InvalidSweepAccounts
+            );
+            require!(
+                boss_is_allowed_destination(
+                    &ctx.accounts.withdrawal_allowlist,
+                    &ctx.accounts.boss.key()
+                )?,
+                VaultCoreError::DestinationNotAllowed
+            );
+            require!(
+                record_sweep(&accounts[3], &mint.key(), amount, ctx.program_id)?,
+                CloseOfferErrorCode::TimelockRequired
+            );
+
+            transfer_tokens(
+                &mint,
+                token_program,
+                &vault_token_account,
+                &boss_token_account,
+                &ctx.accounts.vault_authority.to_account_info(),
+                Some(signer_seeds),
+                amount,
+            )?;
+
+            vault_token_account.reload()?;
+            let sequence = ctx.accounts.state.next_vault_event_sequence();
+
+            emit!(OfferVaultWithdrawEvent {
+                mint: mint.key(),
+                amount,
+                boss: ctx.accounts.boss.key(),
+                destination: ctx.accounts.boss.key(),
+                authority_type: VaultAuthorityType::Offer,
+                pre_balance: amount,
+                post_balance: vault_token_account.amount,
+                sequence,
+            });
+        }
+
+        *swept = Some(amount);
+    }
+
+    if own_vault {
+        require!(
+            token_in_swept.is_some() && token_out_swept.is_some(),
+            CloseOfferErrorCode::UnsweptVault
+        );
+    }
+
+    unregister_offer(&ctx.accounts.offer_registry, &offer_pda)?;
+
+    let offer = ctx.accounts.offer.load()?;
+    let event = OfferClosedFinalEvent {
+        offer_pda,
+        offer_version: offer.offer_version,
+        token_in_mint: offer.token_in_mint,
+        token_out_mint: offer.token_out_mint,
+        total_volume_token_in: offer.total_volume_token_in(),
+        total_fees_collected_token_in: offer.total_fees_collected_token_in(),
+        token_in_swept: token_in_swept.unwrap_or(0),
+        token_out_swept: token_out_swept.unwrap_or(0),
+        boss: ctx.accounts.boss.key(),
+    };
+
+    msg!(
+        "Offer closed: {}, volume: {}, fees: {}, swept token_in: {}, swept token_out: {}",
+        offer_pda,
+        event.total_volume_token_in,
+        event.total_fees_collected_token_in,
+        event.token_in_swept,
+        event.token_out_swept
+    );
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Removes an offer from the offer registry
+///
+/// A registry that has not been created yet lists no offers. Kept out of line so the
+/// deserialized registry gets a stack frame of its own.
+#[inline(never)]
+pub(crate) fn unregister_offer(registry_info: &AccountInfo, offer: &Pubkey) -> Result<()> {
+    if registry_info.data_is_empty() {
+        return Ok(());
+    }
+    let mut data = registry_info.try_borrow_mut_data()?;
+    let mut registry = OfferRegistry::try_deserialize(&mut &data[..])?;
+    if registry.unregister(offer) {
+        registry.try_serialize(&mut &mut data[..])?;
+    }
+    Ok(())
+}
+
+/// Rejects closing an offer that a redemption offer prices its redemptions with
+///
+/// The redemption offer of the inverse pair refers to the offer; once it is closed, no
+/// redemption requests depend on the offer any more.
+fn ensure_no_dependent_redemption_offer(
+    redemption_offer_info: &AccountInfo,
+    offer: &Pubkey,
+) -> Result<()> {
+    if redemption_offer_info.data_is_empty() {
+        return Ok(());
+    }
+    let redemption_offer =
+        RedemptionOffer::try_deserialize(&mut &redemption_offer_info.try_borrow_data()?[..])?;
+    if redemption_offer.offer != *offer {
+        return Ok(());
+    }
+    require!(
+        redemption_offer.requested_redemptions == 0,
+        CloseOfferErrorCode::PendingRedemptions
+    );
+    err!(CloseOfferErrorCode::RedemptionOfferExists)
+}
+
+/// Returns whether the boss may receive offer vault withdrawals
+///
+/// A withdrawal allowlist that has not been created yet restricts withdrawals to the boss.
+fn boss_is_allowed_destination(allowlist_info: &AccountInfo, boss: &Pubkey) -> Result<bool> {
+    if allowlist_info.data_is_empty() {
+        return Ok(true);
+    }
+    let allowlist =
+        WithdrawalAllowlist::try_deserialize(&mut &allowlist_info.try_borrow_data()?[..])?;
+    Ok(allowlist.allows(boss, boss))
+}
+
+/// Records sweeping `amount` of `mint` against its withdrawal timelock
+///
+/// Checks that `timelock_info` is the mint's timelock PDA before reading it, then
+/// returns whether the sweep may proceed; see `record_immediate_withdrawal`.
+fn record_sweep(
+    timelock_info: &AccountInfo,
+    mint: &Pubkey,
+    amount: u64,
+    program_id: &Pubkey,
+) -> Result<bool> {
+    let (timelock_pda, _) =
+        Pubkey::find_program_address(&[seeds::VAULT_WITHDRAW_TIMELOCK, mint.as_ref()], program_id);
+    require_keys_eq!(
+        timelock_info.key(),
+        timelock_pda,
+        CloseOfferErrorCode::InvalidSweepAccounts
+    );
+    record_immediate_withdrawal(timelock_info, amount)
+}
+
+/// Error codes for offer close operations
+#[error_code]
+pub enum CloseOfferErrorCode {
+    /// The offer prices the ONyc supply in `get_global_tvl`
+    #[msg("The primary offer cannot be closed")]
+    PrimaryOffer,
+
+    /// The sweep remaining accounts are malformed or do not belong to the offer
+    #[msg("Invalid sweep accounts")]
+    InvalidSweepAccounts,
+
+    /// The same mint is swept more than once
+    #[msg("Duplicate sweep mint")]
+    DuplicateSweepMint,
+
+    /// A residual balance exceeds the immediate threshold of the mint's withdrawal timelock
+    #[msg("Residual balance exceeds the immediate threshold and must be proposed")]
+    TimelockRequired,
+
+    /// Sweeps were passed for an offer served by the shared offer vault
+    #[msg("Only offers with their own vault can sweep residual balances")]
+    SharedVaultSweep,
+
+    /// A redemption offer prices its redemptions with the offer
+    #[msg("Offer backs a redemption offer, close the redemption offer first")]
+    RedemptionOfferExists,
+
+    /// Redemption requests priced by the offer are still outstanding
+    #[msg("Offer backs outstanding redemption requests")]
+    PendingRedemptions,
+
+    /// An account of the offer's own vault is not swept, so it could hold a balance
+    #[msg("Both accounts of the offer's own vault must be swept to close it")]
+    UnsweptVault,
+}
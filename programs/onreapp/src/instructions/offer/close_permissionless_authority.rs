@@ -0,0 +1,158 @@
+use crate::constants::seeds;
+use crate::state::{PermissionlessAuthority, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount};
+
+/// Event emitted when a permissionless authority is retired
+///
+/// Provides transparency for tracking the closure of distribution routes.
+#[event]
+pub struct PermissionlessAuthorityClosedEvent {
+    /// The PDA address of the closed permissionless authority
+    pub permissionless_authority: Pubkey,
+    /// Index the authority was seeded with
+    pub authority_index: u8,
+    /// Number of intermediary token accounts closed with the authority
+    pub intermediary_accounts_closed: u32,
+    /// The boss account receiving the recovered rent
+    pub boss: Pubkey,
+}
+
+/// Account structure for closing a permissionless authority
+///
+/// The intermediary token accounts of the authority are passed as writable remaining
+/// accounts. Each one must be owned by the SPL Token or Token-2022 program and
+/// controlled by the authority.
+#[derive(Accounts)]
+#[instruction(authority_index: u8)]
+pub struct ClosePermissionlessAuthority<'info> {
+    /// The permissionless authority to close, with its rent returned to the boss
+    #[account(
+        mut,
+        close = boss,
+        seeds = [PermissionlessAuthority::seed(authority_index).as_slice()],
+        bump
+    )]
+    pub permissionless_authority: Account<'info, PermissionlessAuthority>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to close the authority and receive the rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// SPL Token program for closing intermediary accounts of SPL mints
+    pub token_program: Program<'info, Token>,
+
+    /// Token-2022 program for closing intermediary accounts of Token-2022 mints
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+/// Retires a permissionless authority together with its intermediary accounts
+///
+/// Every intermediary token account passed in remaining accounts must be empty; it
+/// is closed with the authority's signature. Takes leave no balance behind in the
+/// intermediaries, so a non-zero balance means tokens were sent to them directly and
+/// the close is rejected rather than stranding them. The authority account is closed
+/// last and the rent of all closed accounts is returned to the boss. Intermediary
+/// accounts that are not passed stay open.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing the intermediary accounts as remaining accounts
+/// * `authority_index` - Index of the permissionless authority to close
+///
+/// # Returns
+/// * `Ok(())` - If the authority and its intermediary accounts are successfully closed
+/// * `Err(ClosePermissionlessAuthorityErrorCode::InvalidIntermediaryAccount)` - If an account
+///   is not a token account controlled by the authority
+/// * `Err(ClosePermissionlessAuthorityErrorCode::IntermediaryAccountNotEmpty)` - If an
+///   intermediary account still holds tokens
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Closes each intermediary token account passed in remaining accounts
+/// - Closes the permissionless authority account
+/// - Transfers the recovered rent to the boss
+///
+/// # Events
+/// * `PermissionlessAuthorityClosedEvent` - Emitted with the authority and closed account count
+pub fn close_permissionless_authority<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClosePermissionlessAuthority<'info>>,
+    authority_index: u8,
+) -> Result<()> {
+    let authority_key = ctx.accounts.permissionless_authority.key();
+    let authority_seed = PermissionlessAuthority::seed(authority_index);
+    let authority_seeds: &[&[u8]] = &[&authority_seed, &[ctx.bumps.permissionless_authority]];
+
+    let mut intermediary_accounts_closed: u32 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let token_program = if *account_info.owner == ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else if *account_info.owner == ctx.accounts.token_2022_program.key() {
+            ctx.accounts.token_2022_program.to_account_info()
+        } else {
+            return err!(ClosePermissionlessAuthorityErrorCode::InvalidIntermediaryAccount);
+        };
+
+        let intermediary = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require_keys_eq!(
+            intermediary.owner,
+            authority_key,
+            ClosePermissionlessAuthorityErrorCode::InvalidIntermediaryAccount
+        );
+        require!(
+            intermediary.amount == 0,
+            ClosePermissionlessAuthorityErrorCode::IntermediaryAccountNotEmpty
+        );
+
+        close_account(CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: account_info.clone(),
+                destination: ctx.accounts.boss.to_account_info(),
+                authority: ctx.accounts.permissionless_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ))?;
+
+        intermediary_accounts_closed += 1;
+    }
+
+    msg!(
+        "Permissionless authority {} closed with {} intermediary accounts",
+        authority_key,
+        intermediary_accounts_closed
+    );
+
+    emit!(PermissionlessAuthorityClosedEvent {
+        permissionless_authority: authority_key,
+        authority_index,
+        intermediary_accounts_closed,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for closing permissionless authorities
+#[error_code]
+pub enum ClosePermissionlessAuthorityErrorCode {
+    /// Remaining account is not a token account controlled by the authority
+    #[msg("Invalid intermediary token account")]
+    InvalidIntermediaryAccount,
+    /// An intermediary token account still holds tokens
+    #[msg("Intermediary token account is not empty")]
+    IntermediaryAccountNotEmpty,
+}
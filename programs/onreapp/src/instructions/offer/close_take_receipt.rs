@@ -0,0 +1,96 @@
+use crate::constants::CLAWBACK_WINDOW_SECONDS;
+use crate::instructions::TakeReceipt;
+use anchor_lang::prelude::*;
+
+/// Event emitted when a take receipt is closed after the clawback window
+///
+/// Provides transparency for tracking takes that can no longer be reversed.
+#[event]
+pub struct TakeReceiptClosedEvent {
+    /// The PDA address of the closed take receipt
+    pub take_receipt_pda: Pubkey,
+    /// The PDA address of the offer the take was executed against
+    pub offer_pda: Pubkey,
+    /// The user the receipt's rent is returned to
+    pub user: Pubkey,
+    /// Unix timestamp of the take
+    pub take_timestamp: u64,
+}
+
+/// Account structure for closing a take receipt
+///
+/// The receipt's rent is returned to the user who paid it with the take.
+#[derive(Accounts)]
+pub struct CloseTakeReceipt<'info> {
+    /// The take receipt to close
+    #[account(
+        mut,
+        close = user,
+        has_one = user @ CloseTakeReceiptErrorCode::InvalidUser
+    )]
+    pub take_receipt: Box<Account<'info, TakeReceipt>>,
+
+    /// The user who took the offer, receiving the receipt's rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Closes a take receipt once its take can no longer be clawed back
+///
+/// Receipts are only needed by `clawback_take` within `CLAWBACK_WINDOW_SECONDS` of
+/// the take; afterwards the user can reclaim their rent.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the receipt is successfully closed
+/// * `Err(CloseTakeReceiptErrorCode::ClawbackWindowOpen)` - If the take can still be clawed back
+///
+/// # Access Control
+/// - Only the user of the receipt can call this instruction
+///
+/// # Effects
+/// - Closes the take receipt, returning its rent to the user
+///
+/// # Events
+/// * `TakeReceiptClosedEvent` - Emitted with the receipt, offer and take timestamp
+pub fn close_take_receipt(ctx: Context<CloseTakeReceipt>) -> Result<()> {
+    let take_receipt = &ctx.accounts.take_receipt;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require!(
+        current_time
+            > take_receipt
+                .take_timestamp
+                .saturating_add(CLAWBACK_WINDOW_SECONDS),
+        CloseTakeReceiptErrorCode::ClawbackWindowOpen
+    );
+
+    msg!(
+        "Take receipt closed: {}, offer: {}, user: {}",
+        take_receipt.key(),
+        take_receipt.offer,
+        take_receipt.user
+    );
+    emit!(TakeReceiptClosedEvent {
+        take_receipt_pda: take_receipt.key(),
+        offer_pda: take_receipt.offer,
+        user: take_receipt.user,
+        take_timestamp: take_receipt.take_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Error codes for closing take receipts
+#[error_code]
+pub enum CloseTakeReceiptErrorCode {
+    /// The user does not match the one recorded on the receipt
+    #[msg("Invalid user account")]
+    InvalidUser,
+
+    /// The take can still be clawed back
+    #[msg("Clawback window has not ended")]
+    ClawbackWindowOpen,
+}
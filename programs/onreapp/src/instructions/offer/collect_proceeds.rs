@@ -0,0 +1,265 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::{
+    record_immediate_withdrawal, VaultCoreError, WithdrawalAllowlist,
+};
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when take proceeds are collected from the offer vault
+///
+/// Carries the vault balances and the vault event sequence like other vault
+/// balance changes.
+#[event]
+pub struct ProceedsCollectedEvent {
+    /// The PDA address of the offer whose proceeds were collected
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// The token_in mint of the collected proceeds
+    pub mint: Pubkey,
+    /// Amount of token_in collected
+    pub amount: u64,
+    /// The boss account that collected the proceeds
+    pub boss: Pubkey,
+    /// Owner of the token account that received the proceeds
+    pub destination: Pubkey,
+    /// Vault balance before the collection
+    pub pre_balance: u64,
+    /// Vault balance after the collection
+    pub post_balance: u64,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
+}
+
+/// Account structure for collecting take proceeds from the offer vault
+#[derive(Accounts)]
+pub struct CollectProceeds<'info> {
+    /// The offer whose token_in proceeds are collected
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint of the offer
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the input token
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Program-derived authority that controls vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The offer vault's token_in account holding the proceeds
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner of the token account receiving the proceeds
+    ///
+    /// Must be listed in the withdrawal allowlist, or be the boss while the list has never been populated.
+    /// CHECK: Validated against the withdrawal allowlist in the handler
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token_in account receiving the proceeds
+    ///
+    /// Created automatically if it doesn't exist, paid for by the boss.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_in_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The withdrawal destination allowlist
+    ///
+    /// Created empty on first use, which restricts collections to the boss.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The withdrawal timelock of the token_in mint
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; deserialized in the
+    /// handler only if it has been created
+    #[account(
+        mut,
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_in_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_timelock: UncheckedAccount<'info>,
+
+    /// The boss account collecting the proceeds and paying for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Program state account containing boss authorization and the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Sweeps take proceeds of an offer from the offer vault
+///
+/// Offers routing proceeds to the vault accumulate token_in there; this instruction
+/// moves them to the boss or another allowlisted destination. At most the offer's
+/// uncollected proceeds, credited by its takes, can be collected, so the vault's
+/// inventory and other offers' proceeds stay out of reach. Collections are vault
+/// withdrawals, so amounts above the mint's immediate threshold have to go through
+/// `propose_vault_withdraw` like any other withdrawal.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - Amount of token_in to collect
+///
+/// # Returns
+/// * `Ok(())` - If the proceeds are collected successfully
+/// * `Err(CollectProceedsErrorCode::InvalidAmount)` - If the amount is zero
+/// * `Err(CollectProceedsErrorCode::ExceedsUncollectedProceeds)` - If the amount exceeds the offer's uncollected proceeds
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the destination is not allowed
+/// * `Err(VaultCoreError::TimelockRequired)` - If the amount exceeds the immediate threshold
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - The destination must be allowed by the withdrawal allowlist
+/// - Amounts above the immediate threshold of an enabled timelock are rejected
+///
+/// # Effects
+/// - Advances the vault event sequence in program state
+/// - Deducts the amount from the offer's uncollected proceeds
+/// - Transfers token_in from the offer vault to the destination
+/// - Creates destination token account and allowlist if they don't exist
+///
+/// # Events
+/// * `ProceedsCollectedEvent` - Emitted with the amount, destination and vault balances
+pub fn collect_proceeds(ctx: Context<CollectProceeds>, amount: u64) -> Result<()> {
+    require!(amount > 0, CollectProceedsErrorCode::InvalidAmount);
+
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+    require!(
+        allowlist.allows(&ctx.accounts.destination.key(), &ctx.accounts.boss.key()),
+        VaultCoreError::DestinationNotAllowed
+    );
+    require!(
+        record_immediate_withdrawal(&ctx.accounts.vault_withdraw_timelock, amount)?,
+        VaultCoreError::TimelockRequired
+    );
+
+    let vault_authority_seed = {
+        let mut offer = ctx.accounts.offer.load_mut()?;
+        offer.uncollected_proceeds = offer
+            .uncollected_proceeds
+            .checked_sub(amount)
+            .ok_or(CollectProceedsErrorCode::ExceedsUncollectedProceeds)?;
+        offer.vault_authority_seed(&ctx.accounts.offer.key())
+    };
+    let vault_authority_seeds = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.vault_authority],
+    ];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+
+    let pre_balance = ctx.accounts.vault_token_in_account.amount;
+
+    transfer_tokens(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_in_program,
+        &ctx.accounts.vault_token_in_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.vault_authority.to_account_info(),
+        Some(signer_seeds),
+        amount,
+    )?;
+
+    ctx.accounts.vault_token_in_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_in_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
+    msg!(
+        "Proceeds collected: offer={}, amount={}, destination={}",
+        ctx.accounts.offer.key(),
+        amount,
+        ctx.accounts.destination.key()
+    );
+
+    emit!(ProceedsCollectedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: ctx.accounts.offer.load()?.offer_version,
+        mint: ctx.accounts.token_in_mint.key(),
+        amount,
+        boss: ctx.accounts.boss.key(),
+        destination: ctx.accounts.destination.key(),
+        pre_balance,
+        post_balance,
+        sequence,
+    });
+
+    Ok(())
+}
+
+/// Error codes for collecting take proceeds
+#[error_code]
+pub enum CollectProceedsErrorCode {
+    /// The collected amount must be greater than zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+    /// The collected amount exceeds the proceeds the offer's takes left in the vault
+    #[msg("Amount exceeds the offer's uncollected proceeds")]
+    ExceedsUncollectedProceeds,
+}
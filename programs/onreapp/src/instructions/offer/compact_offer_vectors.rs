@@ -0,0 +1,116 @@
+use super::offer_state::Offer;
+use crate::constants::seeds;
+use crate::instructions::{find_active_vector_at, prune_expired_vectors};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when expired pricing vectors are compacted out of an offer
+///
+/// Provides transparency for tracking slot recycling. Individual evictions are
+/// additionally reported through `OfferVectorEvictedEvent`.
+#[event]
+pub struct OfferVectorsCompactedEvent {
+    /// The PDA address of the compacted offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Start time of the vector active at compaction time
+    pub active_vector_start_time: u64,
+    /// Number of vector slots that were freed
+    pub evicted_count: u8,
+}
+
+/// Account structure for compacting expired pricing vectors of an offer
+///
+/// This struct defines the accounts required to recycle vector slots whose whole
+/// time range lies in the past. No authority is required since compaction never
+/// changes the price an offer quotes now or in the future.
+#[derive(Accounts)]
+pub struct CompactOfferVectors<'info> {
+    /// The offer account whose expired pricing vectors will be cleared
+    ///
+    /// This account is validated as a PDA derived from token mint addresses
+    /// and contains the array of pricing vectors for the offer.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Frees vector slots occupied by fully expired pricing vectors
+///
+/// This permissionless instruction clears every vector that started before the
+/// vector preceding the currently active one. The active vector, its predecessor
+/// (needed for NAV adjustment calculations) and all future vectors are preserved,
+/// so the offer's current and scheduled prices are unaffected.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If compaction completes, including when there is nothing to prune
+///
+/// # Access Control
+/// - Permissionless: any account may pay for the transaction
+///
+/// # Effects
+/// - Expired vector slots are reset to default values and become reusable
+///
+/// # Events
+/// * `OfferVectorEvictedEvent` - Emitted for each cleared vector
+/// * `OfferVectorsCompactedEvent` - Emitted with the number of freed slots
+pub fn compact_offer_vectors(ctx: Context<CompactOfferVectors>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let active_vector_start_time = match find_active_vector_at(offer, current_time) {
+        Ok(vector) => vector.start_time,
+        Err(_) => {
+            msg!(
+                "No active vector for offer: {}, nothing to compact",
+                ctx.accounts.offer.key()
+            );
+            return Ok(());
+        }
+    };
+
+    let evicted_count = prune_expired_vectors(offer, active_vector_start_time);
+
+    msg!(
+        "Offer vectors compacted: {}, evicted count: {}",
+        ctx.accounts.offer.key(),
+        evicted_count
+    );
+
+    emit!(OfferVectorsCompactedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        active_vector_start_time,
+        evicted_count,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::constants::{seeds, EXPIRED_OFFER_GRACE_SECONDS};
+use crate::instructions::offer::close_offer::unregister_offer;
+use crate::instructions::{Offer, OfferClosedFinalEvent};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
+use anchor_lang::prelude::*;
+
+/// Account structure for closing an expired offer
+///
+/// No signer is required beyond the fee payer since closing only takes effect once
+/// the offer's own expiry and the grace period have passed.
+#[derive(Accounts)]
+pub struct CrankExpiredOffer<'info> {
+    /// The expired offer to mark closed
+    #[account(mut)]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Registry of the live offers, from which the offer is removed
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; updated in the handler
+    /// only if it has been created
+    #[account(mut, seeds = [seeds::OFFER_REGISTRY], bump)]
+    pub offer_registry: UncheckedAccount<'info>,
+
+    /// Program state account providing the primary offer and the boss
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Marks an offer closed once it has been expired for the grace period
+///
+/// Keepers call this to keep the offer registry free of lapsed offers without the
+/// boss having to act. The offer account stays in place with its final statistics;
+/// the boss reclaims its rent and residual vault balances later with `close_offer`.
+/// Closed offers reject takes even if their validity window is moved.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully marked closed
+/// * `Err(CrankExpiredOfferErrorCode::PrimaryOffer)` - If the offer is the primary offer
+/// * `Err(CrankExpiredOfferErrorCode::AlreadyClosed)` - If the offer is already closed
+/// * `Err(CrankExpiredOfferErrorCode::NotExpired)` - If the offer has no expiry or the
+///   grace period after it has not passed yet
+///
+/// # Access Control
+/// - Permissionless: any keeper may crank expired offers
+///
+/// # Effects
+/// - Marks the offer closed
+/// - Removes the offer from the offer registry
+///
+/// # Events
+/// * `OfferClosedFinalEvent` - Emitted with the lifetime statistics and no sweeps
+pub fn crank_expired_offer(ctx: Context<CrankExpiredOffer>) -> Result<()> {
+    let offer_pda = ctx.accounts.offer.key();
+    require!(
+        offer_pda != ctx.accounts.state.primary_offer,
+        CrankExpiredOfferErrorCode::PrimaryOffer
+    );
+
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    require!(
+        !offer.is_closed(),
+        CrankExpiredOfferErrorCode::AlreadyClosed
+    );
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let closable_at = offer
+        .valid_until
+        .saturating_add(EXPIRED_OFFER_GRACE_SECONDS);
+    require_with_context!(
+        offer.valid_until != 0 && current_time >= closable_at,
+        CrankExpiredOfferErrorCode::NotExpired,
+        current_time = current_time,
+        valid_until = offer.valid_until,
+        closable_at = closable_at
+    );
+
+    offer.set_closed(true);
+    unregister_offer(&ctx.accounts.offer_registry, &offer_pda)?;
+
+    let event = OfferClosedFinalEvent {
+        offer_pda,
+        offer_version: offer.offer_version,
+        token_in_mint: offer.token_in_mint,
+        token_out_mint: offer.token_out_mint,
+        total_volume_token_in: offer.total_volume_token_in(),
+        total_fees_collected_token_in: offer.total_fees_collected_token_in(),
+        token_in_swept: 0,
+        token_out_swept: 0,
+        boss: ctx.accounts.state.boss,
+    };
+
+    msg!(
+        "Expired offer closed: {}, valid until: {}, volume: {}, fees: {}",
+        offer_pda,
+        offer.valid_until,
+        event.total_volume_token_in,
+        event.total_fees_collected_token_in
+    );
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Error codes for expired offer cranking
+#[error_code]
+pub enum CrankExpiredOfferErrorCode {
+    /// The offer prices the ONyc supply in `get_global_tvl`
+    #[msg("The primary offer cannot be closed")]
+    PrimaryOffer,
+
+    /// The offer was already closed by an earlier crank
+    #[msg("Offer is already closed")]
+    AlreadyClosed,
+
+    /// The offer never expires, or its expiry grace period is still running
+    #[msg("Offer has not been expired for the grace period")]
+    NotExpired,
+}
@@ -1,6 +1,6 @@
 use super::offer_state::{Offer, OfferVector};
 use crate::constants::{seeds, MAX_VECTORS};
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -12,6 +12,8 @@ use anchor_spl::token_interface::Mint;
 pub struct AllOfferVectorsDeletedEvent {
     /// The PDA address of the offer from which vectors were deleted
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Number of vectors that were deleted (non-empty vectors)
     pub vectors_deleted_count: u8,
 }
@@ -54,7 +56,12 @@ pub struct DeleteAllOfferVectors<'info> {
     pub token_out_mint: InterfaceAccount<'info, Mint>,
 
     /// Program state account containing boss authorization
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The boss account authorized to delete pricing vectors from offers
@@ -105,6 +112,7 @@ pub fn delete_all_offer_vectors(ctx: Context<DeleteAllOfferVectors>) -> Result<(
 
     emit!(AllOfferVectorsDeletedEvent {
         offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
         vectors_deleted_count: deleted_count,
     });
 
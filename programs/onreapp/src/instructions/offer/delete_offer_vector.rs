@@ -1,7 +1,7 @@
 use super::offer_state::{Offer, OfferVector};
 use crate::constants::seeds;
 use crate::instructions::find_vector_index_by_start_time;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -13,6 +13,8 @@ use anchor_spl::token_interface::Mint;
 pub struct OfferVectorDeletedEvent {
     /// The PDA address of the offer from which the vector was deleted
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Start time of the deleted pricing vector
     pub vector_start_time: u64,
 }
@@ -55,7 +57,12 @@ pub struct DeleteOfferVector<'info> {
     pub token_out_mint: InterfaceAccount<'info, Mint>,
 
     /// Program state account containing boss authorization
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The boss account authorized to delete pricing vectors from offers
@@ -98,7 +105,7 @@ pub fn delete_offer_vector(ctx: Context<DeleteOfferVector>, vector_start_time: u
     );
 
     // Find and delete the vector by vector_start_time
-    let vector_index = find_vector_index_by_start_time(&offer, vector_start_time)
+    let vector_index = find_vector_index_by_start_time(offer, vector_start_time)
         .ok_or_else(|| error!(DeleteOfferVectorErrorCode::VectorNotFound))?;
 
     // Delete the vector by setting it to default
@@ -112,6 +119,7 @@ pub fn delete_offer_vector(ctx: Context<DeleteOfferVector>, vector_start_time: u
 
     emit!(OfferVectorDeletedEvent {
         offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
         vector_start_time,
     });
 
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Offer paying out two output tokens for a single token_in payment
+///
+/// A dual offer extends a regular Offer (token_in -> token_out_1) with a second
+/// output leg. The principal leg is priced by the base offer's pricing vectors,
+/// fee, approval and oracle configuration. The second leg (e.g. a reward token) is
+/// paid in proportion to the principal at a fixed ratio.
+#[account]
+#[derive(InitSpace)]
+pub struct DualOffer {
+    /// Reference to the base Offer PDA pricing the principal leg
+    pub offer: Pubkey,
+    /// Input token mint paid by the user
+    pub token_in_mint: Pubkey,
+    /// Principal output token mint (token_out of the base offer)
+    pub token_out_mint_1: Pubkey,
+    /// Secondary output token mint paid alongside the principal
+    pub token_out_mint_2: Pubkey,
+    /// Whole token_out_2 paid per whole token_out_1 with scale=9 (1_000_000_000 = 1.0)
+    pub token_out_2_ratio: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
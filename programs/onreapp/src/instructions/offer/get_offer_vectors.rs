@@ -0,0 +1,177 @@
+use crate::constants::{seeds, OFFER_VECTORS_ENCODING_VERSION, OFFER_VECTORS_PAGE_SIZE};
+use crate::instructions::{find_active_vector_at, Offer, OfferVector, PricingMode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// A pricing vector as returned by `get_offer_vectors`
+///
+/// Decoupled from the zero-copy `OfferVector` layout so the account can evolve
+/// without breaking clients decoding the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OfferVectorInfo {
+    /// Calculated activation time of the vector
+    pub start_time: u64,
+    /// Original requested activation time
+    pub base_time: u64,
+    /// Initial price with scale=9 at vector start
+    pub base_price: u64,
+    /// Annual Percentage Rate scaled by 1_000_000 (1_000_000 = 1% APR)
+    pub apr: i64,
+    /// Duration in seconds for each discrete pricing step
+    pub price_fix_duration: u64,
+    /// How the price evolves between steps
+    pub pricing_mode: PricingMode,
+}
+
+impl From<&OfferVector> for OfferVectorInfo {
+    fn from(vector: &OfferVector) -> Self {
+        Self {
+            start_time: vector.start_time,
+            base_time: vector.base_time,
+            base_price: vector.base_price,
+            apr: vector.apr,
+            price_fix_duration: vector.price_fix_duration,
+            pricing_mode: vector.pricing_mode(),
+        }
+    }
+}
+
+/// A single page of pricing vectors returned by `get_offer_vectors`
+///
+/// The leading `version` identifies the encoding so clients can reject layouts
+/// they do not understand.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OfferVectorsPage {
+    /// Encoding version, equal to `OFFER_VECTORS_ENCODING_VERSION`
+    pub version: u8,
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of active and upcoming vectors across all pages
+    pub total_vectors: u8,
+    /// Active and upcoming vectors on this page, at most `OFFER_VECTORS_PAGE_SIZE` entries
+    pub vectors: Vec<OfferVectorInfo>,
+    /// Whether further pages follow this one
+    pub has_more: bool,
+}
+
+/// Event emitted when an offer vector page is queried
+///
+/// Provides transparency for tracking pricing schedule queries.
+#[event]
+pub struct GetOfferVectorsEvent {
+    /// The PDA address of the queried offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of active and upcoming vectors across all pages
+    pub total_vectors: u8,
+    /// Number of vectors returned on this page
+    pub returned_vectors: u8,
+}
+
+/// Account structure for querying the pricing vectors of an offer
+///
+/// The query is read-only, requires no signer and validates that the mints belong
+/// to the offer.
+#[derive(Accounts)]
+pub struct GetOfferVectors<'info> {
+    /// The offer account containing the pricing vectors
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Returns one page of the pricing schedule of an offer
+///
+/// This read-only instruction collects the currently active vector and all vectors
+/// starting later, sorted by start time, and returns the requested page of at most
+/// `OFFER_VECTORS_PAGE_SIZE` entries, so clients do not need to decode the raw offer
+/// account layout. Expired vectors, superseded by the active one, are left out.
+/// Requesting a page past the end returns an empty list.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `page` - Zero-based page index
+///
+/// # Returns
+/// * `Ok(OfferVectorsPage)` - The requested page with pagination metadata
+///
+/// # Events
+/// * `GetOfferVectorsEvent` - Emitted with the page index and vector counts
+pub fn get_offer_vectors(ctx: Context<GetOfferVectors>, page: u8) -> Result<OfferVectorsPage> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // Vectors starting before the active one no longer price the offer
+    let active_start_time = find_active_vector_at(&offer, current_time)
+        .map(|vector| vector.start_time)
+        .unwrap_or(0);
+
+    let mut configured: Vec<&OfferVector> = offer
+        .vectors
+        .iter()
+        .filter(|vector| vector.start_time != 0 && vector.start_time >= active_start_time)
+        .collect();
+    configured.sort_by_key(|vector| vector.start_time);
+
+    let start = (page as usize).saturating_mul(OFFER_VECTORS_PAGE_SIZE);
+    let vectors: Vec<OfferVectorInfo> = configured
+        .iter()
+        .skip(start)
+        .take(OFFER_VECTORS_PAGE_SIZE)
+        .map(|vector| OfferVectorInfo::from(*vector))
+        .collect();
+    let has_more = start + vectors.len() < configured.len();
+
+    let total_vectors = configured.len() as u8;
+    let returned_vectors = vectors.len() as u8;
+
+    msg!(
+        "Offer Vectors Info - Offer PDA: {}, Page: {}, Returned: {}, Total: {}",
+        ctx.accounts.offer.key(),
+        page,
+        returned_vectors,
+        total_vectors
+    );
+
+    emit!(GetOfferVectorsEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        page,
+        total_vectors,
+        returned_vectors,
+    });
+
+    Ok(OfferVectorsPage {
+        version: OFFER_VECTORS_ENCODING_VERSION,
+        page,
+        total_vectors,
+        vectors,
+        has_more,
+    })
+}
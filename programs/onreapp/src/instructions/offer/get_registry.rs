@@ -0,0 +1,94 @@
+use crate::constants::{seeds, OFFER_REGISTRY_PAGE_SIZE};
+use crate::instructions::OfferRegistry;
+use anchor_lang::prelude::*;
+
+/// A single page of live offers returned by `get_registry`
+///
+/// Returned via return data so clients can enumerate offers without scanning
+/// program accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OfferRegistryPage {
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of registered offers across all pages
+    pub total_offers: u8,
+    /// Registered offer PDAs on this page, at most `OFFER_REGISTRY_PAGE_SIZE` entries
+    pub offers: Vec<Pubkey>,
+    /// Whether further pages follow this one
+    pub has_more: bool,
+}
+
+/// Event emitted when an offer registry page is queried
+///
+/// Provides transparency for tracking offer enumeration queries.
+#[event]
+pub struct GetRegistryEvent {
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of registered offers across all pages
+    pub total_offers: u8,
+    /// Number of offers returned on this page
+    pub returned_offers: u8,
+}
+
+/// Account structure for querying the offer registry
+///
+/// The query is read-only and requires no signer. The registry is created by the
+/// first `make_offer`.
+#[derive(Accounts)]
+pub struct GetRegistry<'info> {
+    /// Registry of the live offers
+    #[account(seeds = [seeds::OFFER_REGISTRY], bump = offer_registry.bump)]
+    pub offer_registry: Box<Account<'info, OfferRegistry>>,
+}
+
+/// Returns one page of the live offers
+///
+/// This read-only instruction collects the registered offers in slot order and
+/// returns the requested page of at most `OFFER_REGISTRY_PAGE_SIZE` entries.
+/// Requesting a page past the end returns an empty list.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `page` - Zero-based page index
+///
+/// # Returns
+/// * `Ok(OfferRegistryPage)` - The requested page with pagination metadata
+///
+/// # Events
+/// * `GetRegistryEvent` - Emitted with the page index and offer counts
+pub fn get_registry(ctx: Context<GetRegistry>, page: u8) -> Result<OfferRegistryPage> {
+    let registered = ctx.accounts.offer_registry.active_offers();
+
+    let start = (page as usize).saturating_mul(OFFER_REGISTRY_PAGE_SIZE);
+    let offers: Vec<Pubkey> = registered
+        .iter()
+        .skip(start)
+        .take(OFFER_REGISTRY_PAGE_SIZE)
+        .copied()
+        .collect();
+    let has_more = start + offers.len() < registered.len();
+
+    let total_offers = registered.len() as u8;
+    let returned_offers = offers.len() as u8;
+
+    msg!(
+        "Offer Registry - Page: {}, Returned: {}, Total: {}",
+        page,
+        returned_offers,
+        total_offers
+    );
+
+    emit!(GetRegistryEvent {
+        page,
+        total_offers,
+        returned_offers,
+    });
+
+    Ok(OfferRegistryPage {
+        page,
+        total_offers,
+        offers,
+        has_more,
+    })
+}
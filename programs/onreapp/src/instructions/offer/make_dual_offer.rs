@@ -0,0 +1,239 @@
+use crate::constants::seeds;
+use crate::instructions::{DualOffer, Offer};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a dual offer is successfully created
+///
+/// Provides transparency for tracking dual offer creation and its payout ratio.
+#[event]
+pub struct DualOfferMadeEvent {
+    /// The PDA address of the newly created dual offer
+    pub dual_offer_pda: Pubkey,
+    /// The PDA address of the base offer pricing the principal leg
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// The input token mint paid by users
+    pub token_in_mint: Pubkey,
+    /// The principal output token mint
+    pub token_out_mint_1: Pubkey,
+    /// The secondary output token mint
+    pub token_out_mint_2: Pubkey,
+    /// Whole token_out_2 paid per whole token_out_1 with scale=9
+    pub token_out_2_ratio: u64,
+    /// The boss account that created the dual offer
+    pub boss: Pubkey,
+}
+
+/// Account structure for creating a dual offer
+///
+/// This struct defines the accounts required to attach a second output token to an
+/// existing offer. The base offer must already exist for the token_in/token_out_1
+/// pair and keeps pricing the principal leg.
+#[derive(Accounts)]
+pub struct MakeDualOffer<'info> {
+    /// Program-derived authority serving the base offer's vault token accounts
+    ///
+    /// The offer's own vault authority once it has one, the shared one otherwise, as
+    /// read by `take_dual_offer`.
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The base offer pricing the principal leg
+    ///
+    /// This account is validated as a PDA derived from the token_in and
+    /// token_out_1 mint addresses.
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint_1.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint of the base offer
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The principal output token mint, i.e. the token_out of the base offer
+    ///
+    /// Must be owned by the provided principal token program.
+    #[account(
+        constraint =
+            token_out_mint_1.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint,
+        constraint = *token_out_mint_1.to_account_info().owner == token_out_1_program.key()
+            @ MakeDualOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_out_mint_1: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the principal output token
+    pub token_out_1_program: Interface<'info, TokenInterface>,
+
+    /// Vault account for storing principal output tokens
+    ///
+    /// Created automatically if needed.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_out_mint_1,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_1_program
+    )]
+    pub vault_token_out_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The secondary output token mint
+    ///
+    /// Must differ from both the input and principal mints and be owned by the
+    /// provided secondary token program.
+    #[account(
+        constraint = token_out_mint_2.key() != token_out_mint_1.key()
+            && token_out_mint_2.key() != token_in_mint.key()
+            @ MakeDualOfferErrorCode::InvalidSecondaryMint,
+        constraint = *token_out_mint_2.to_account_info().owner == token_out_2_program.key()
+            @ MakeDualOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_out_mint_2: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the secondary output token
+    pub token_out_2_program: Interface<'info, TokenInterface>,
+
+    /// Vault account for storing secondary output tokens
+    ///
+    /// Created automatically if needed so the first take does not depend on the
+    /// vault being set up separately.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub vault_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The dual offer account storing the secondary leg configuration
+    ///
+    /// Derived from all three mints ensuring one dual offer per token triple.
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + DualOffer::INIT_SPACE,
+        seeds = [
+            seeds::DUAL_OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint_1.key().as_ref(),
+            token_out_mint_2.key().as_ref()
+        ],
+        bump
+    )]
+    pub dual_offer: Box<Account<'info, DualOffer>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to create dual offers and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a dual offer paying two output tokens for one token_in payment
+///
+/// The principal leg reuses the base offer for pricing, fees, approval and oracle
+/// valuation. For every whole principal token paid out, the user additionally
+/// receives `token_out_2_ratio / 1e9` whole secondary tokens.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_out_2_ratio` - Whole token_out_2 per whole token_out_1 with scale=9
+///
+/// # Returns
+/// * `Ok(())` - If the dual offer is successfully created
+/// * `Err(MakeDualOfferErrorCode::InvalidRatio)` - If the ratio is zero
+/// * `Err(MakeDualOfferErrorCode::InvalidSecondaryMint)` - If the secondary mint repeats another mint
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Creates the dual offer account
+/// - Initializes the vault accounts of both output tokens if needed
+///
+/// # Events
+/// * `DualOfferMadeEvent` - Emitted with the dual offer configuration
+pub fn make_dual_offer(ctx: Context<MakeDualOffer>, token_out_2_ratio: u64) -> Result<()> {
+    require!(token_out_2_ratio > 0, MakeDualOfferErrorCode::InvalidRatio);
+
+    let dual_offer = &mut ctx.accounts.dual_offer;
+    dual_offer.offer = ctx.accounts.offer.key();
+    dual_offer.token_in_mint = ctx.accounts.token_in_mint.key();
+    dual_offer.token_out_mint_1 = ctx.accounts.token_out_mint_1.key();
+    dual_offer.token_out_mint_2 = ctx.accounts.token_out_mint_2.key();
+    dual_offer.token_out_2_ratio = token_out_2_ratio;
+    dual_offer.bump = ctx.bumps.dual_offer;
+
+    msg!(
+        "Dual offer created at: {}, base offer: {}, ratio: {}",
+        ctx.accounts.dual_offer.key(),
+        ctx.accounts.offer.key(),
+        token_out_2_ratio
+    );
+
+    emit!(DualOfferMadeEvent {
+        dual_offer_pda: ctx.accounts.dual_offer.key(),
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: ctx.accounts.offer.load()?.offer_version,
+        token_in_mint: ctx.accounts.token_in_mint.key(),
+        token_out_mint_1: ctx.accounts.token_out_mint_1.key(),
+        token_out_mint_2: ctx.accounts.token_out_mint_2.key(),
+        token_out_2_ratio,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for dual offer creation operations
+#[error_code]
+pub enum MakeDualOfferErrorCode {
+    /// The secondary payout ratio must be greater than zero
+    #[msg("Invalid ratio: must be greater than zero")]
+    InvalidRatio,
+
+    /// The secondary mint must differ from the input and principal mints
+    #[msg("Invalid secondary token mint")]
+    InvalidSecondaryMint,
+
+    /// A mint is not owned by the provided token program
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+}
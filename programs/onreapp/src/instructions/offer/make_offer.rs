@@ -1,6 +1,7 @@
-use crate::constants::{seeds, MAX_ALLOWED_FEE_BPS};
-use crate::instructions::Offer;
-use crate::state::State;
+use crate::constants::seeds;
+use crate::instructions::{FulfillmentMode, Offer, OfferRegistry, OfferVersionRecord};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::{program_controls_mint, validate_mint_decimals, validate_mint_extensions};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -12,6 +13,8 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 pub struct OfferMadeEvent {
     /// The PDA address of the newly created offer
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// The input token mint for the offer
     pub token_in_mint: Pubkey,
     /// The output token mint for the offer
@@ -24,6 +27,12 @@ pub struct OfferMadeEvent {
     pub needs_approval: bool,
     /// Whether the offer allows permissionless operations
     pub allow_permissionless: bool,
+    /// How takes deliver token_out, `None` to decide by the token_out mint authority
+    pub fulfillment_mode: Option<FulfillmentMode>,
+    /// Unix timestamp from which the offer can be taken (0 = immediately)
+    pub valid_from: u64,
+    /// Unix timestamp from which takes are rejected (0 = never expires)
+    pub valid_until: u64,
 }
 
 /// Account structure for creating an offer
@@ -33,16 +42,23 @@ pub struct OfferMadeEvent {
 /// separately using pricing vectors after offer creation.
 #[derive(Accounts)]
 pub struct MakeOffer<'info> {
-    /// Program-derived authority that controls offer vault token accounts
+    /// Program-derived authority of the new offer's own vault
     ///
-    /// This PDA manages token transfers and burning operations when the program
-    /// has mint authority for efficient burn/mint architecture.
+    /// Derived from the offer address, so the offer's balances are kept apart from
+    /// the shared offer vault and every other offer. This PDA manages token transfers
+    /// and burning operations when the program has mint authority.
     /// CHECK: PDA derivation is validated by seeds constraint
-    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY, offer.key().as_ref()], bump)]
     pub vault_authority: UncheckedAccount<'info>,
 
     /// The input token mint for the offer
-    pub token_in_mint: InterfaceAccount<'info, Mint>,
+    ///
+    /// Must be owned by the provided input token program.
+    #[account(
+        constraint = *token_in_mint.to_account_info().owner == token_in_program.key()
+            @ MakeOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Token program interface for the input token
     pub token_in_program: Interface<'info, TokenInterface>,
@@ -58,10 +74,39 @@ pub struct MakeOffer<'info> {
         associated_token::authority = vault_authority,
         associated_token::token_program = token_in_program
     )]
-    pub vault_token_in_account: InterfaceAccount<'info, TokenAccount>,
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// The output token mint for the offer
-    pub token_out_mint: InterfaceAccount<'info, Mint>,
+    ///
+    /// Must be owned by the provided output token program.
+    #[account(
+        constraint = *token_out_mint.to_account_info().owner == token_out_program.key()
+            @ MakeOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the output token
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Vault account for storing output tokens distributed to takers
+    ///
+    /// Created automatically if needed so the first take of the offer does not
+    /// depend on the vault being set up separately.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority, checked against the token_out mint for `Mint`
+    /// fulfillment
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
 
     /// The offer account storing exchange configuration and pricing vectors
     ///
@@ -81,10 +126,46 @@ pub struct MakeOffer<'info> {
     )]
     pub offer: AccountLoader<'info, Offer>,
 
+    /// Record of the latest offer version at the offer PDA
+    ///
+    /// Created with the first offer of the token pair and kept when the offer is
+    /// closed, so a recreated offer receives the next version.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + OfferVersionRecord::INIT_SPACE,
+        seeds = [
+            seeds::OFFER_VERSION,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub offer_version_record: Box<Account<'info, OfferVersionRecord>>,
+
+    /// Registry of the live offers, created with the first offer made
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + OfferRegistry::INIT_SPACE,
+        seeds = [seeds::OFFER_REGISTRY],
+        bump
+    )]
+    pub offer_registry: Box<Account<'info, OfferRegistry>>,
+
     /// Program state account containing boss authorization
-    #[account(seeds = [seeds::STATE], bump = state.bump, has_one = boss)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
+    /// Program-wide configuration providing the maximum fee
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account authorized to create offers and pay for account creation
     #[account(mut)]
     pub boss: Signer<'info>,
@@ -110,10 +191,24 @@ pub struct MakeOffer<'info> {
 /// * `fee_basis_points` - Fee in basis points (10000 = 100%) charged when taking the offer
 /// * `needs_approval` - Whether the offer requires boss approval for taking
 /// * `allow_permissionless` - Whether the offer allows permissionless operations
+/// * `fulfillment_mode` - How takes deliver token_out: `Transfer` from the vault or
+///   `Mint` by the program. `None` keeps the behaviour of offers created before
+///   fulfillment modes, minting whenever the program holds the mint authority.
+/// * `valid_from` - Unix timestamp from which the offer can be taken (0 = immediately)
+/// * `valid_until` - Unix timestamp from which takes are rejected (0 = never expires)
 ///
 /// # Returns
 /// * `Ok(())` - If the offer is successfully created
-/// * `Err(MakeOfferErrorCode::InvalidFee)` - If fee_basis_points exceeds 10000
+/// * `Err(MakeOfferErrorCode::InvalidFee)` - If fee_basis_points exceeds the configured maximum
+/// * `Err(MakeOfferErrorCode::InvalidTokenProgram)` - If a mint is not owned by its token program
+/// * `Err(MakeOfferErrorCode::OfferRegistryFull)` - If the offer registry has no empty slot
+/// * `Err(MakeOfferErrorCode::MintAuthorityRequired)` - If `Mint` fulfillment is requested but
+///   the program does not hold the token_out mint authority
+/// * `Err(MakeOfferErrorCode::InvalidValidityWindow)` - If `valid_until` is set but not after
+///   `valid_from`
+/// * `Err(TokenUtilsErrorCode::PermanentDelegateNotSupported)` - If a mint has a permanent delegate
+/// * `Err(TokenUtilsErrorCode::ConfidentialTransfersNotSupported)` - If a mint enables confidential transfers
+/// * `Err(TokenUtilsErrorCode::UnsupportedMintExtension)` - If a mint has a non-allowlisted extension
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
@@ -121,8 +216,14 @@ pub struct MakeOffer<'info> {
 ///
 /// # Effects
 /// - Creates new offer account with specified configuration
-/// - Initializes vault token account if needed for burn/mint operations
+/// - Assigns the next offer version of the token pair, creating its record if needed
+/// - Adds the offer to the offer registry, creating the registry if needed
+/// - Serves the offer from its own vault and initializes its token_in and token_out
+///   accounts
 /// - Sets up offer parameters for future pricing vector additions
+/// - Routes take proceeds to the offer vault
+/// - Records how takes deliver token_out
+/// - Records the window in which the offer can be taken
 ///
 /// # Events
 /// * `OfferMadeEvent` - Emitted with offer details and configuration
@@ -131,32 +232,173 @@ pub fn make_offer(
     fee_basis_points: u16,
     needs_approval: bool,
     allow_permissionless: bool,
+    fulfillment_mode: Option<FulfillmentMode>,
+    valid_from: u64,
+    valid_until: u64,
 ) -> Result<()> {
-    // Validate fee is within valid range (0-10000 basis points = 0-100%)
+    let mut offer = ctx.accounts.offer.load_init()?;
+
+    create_offer(
+        &mut offer,
+        OfferCreation {
+            offer_pda: ctx.accounts.offer.key(),
+            offer_bump: ctx.bumps.offer,
+            offer_version_record: &mut ctx.accounts.offer_version_record,
+            offer_version_record_bump: ctx.bumps.offer_version_record,
+            offer_registry: &mut ctx.accounts.offer_registry,
+            offer_registry_bump: ctx.bumps.offer_registry,
+            token_in_mint: &ctx.accounts.token_in_mint,
+            token_out_mint: &ctx.accounts.token_out_mint,
+            mint_authority: &ctx.accounts.mint_authority,
+            config: &ctx.accounts.config,
+            boss: ctx.accounts.boss.key(),
+        },
+        fee_basis_points,
+        needs_approval,
+        allow_permissionless,
+        fulfillment_mode,
+        OfferValidity {
+            valid_from,
+            valid_until,
+        },
+    )
+}
+
+/// Window in which a new offer can be taken, 0 leaving either end unbounded
+#[derive(Clone, Copy, Default)]
+pub(crate) struct OfferValidity {
+    /// Unix timestamp from which the offer can be taken
+    pub valid_from: u64,
+    /// Unix timestamp from which takes are rejected
+    pub valid_until: u64,
+}
+
+/// Accounts and bumps used to create an offer
+pub(crate) struct OfferCreation<'a, 'info> {
+    /// Address of the offer being created
+    pub offer_pda: Pubkey,
+    /// PDA bump seed of the offer
+    pub offer_bump: u8,
+    /// Record of the latest offer version of the token pair
+    pub offer_version_record: &'a mut Account<'info, OfferVersionRecord>,
+    /// PDA bump seed of the offer version record
+    pub offer_version_record_bump: u8,
+    /// Registry of the live offers
+    pub offer_registry: &'a mut Account<'info, OfferRegistry>,
+    /// PDA bump seed of the offer registry
+    pub offer_registry_bump: u8,
+    /// The input token mint of the offer
+    pub token_in_mint: &'a InterfaceAccount<'info, Mint>,
+    /// The output token mint of the offer
+    pub token_out_mint: &'a InterfaceAccount<'info, Mint>,
+    /// Program-derived mint authority, checked for `Mint` fulfillment
+    pub mint_authority: &'a AccountInfo<'info>,
+    /// Program-wide configuration providing the maximum fee
+    pub config: &'a Config,
+    /// The boss creating the offer
+    pub boss: Pubkey,
+}
+
+/// Validates the configuration of a new offer and initializes it
+///
+/// Shared by `make_offer` and `make_offer_with_vector`; see `make_offer` for the
+/// checks performed and the errors returned.
+///
+/// # Events
+/// * `OfferMadeEvent` - Emitted with offer details and configuration
+pub(crate) fn create_offer(
+    offer: &mut Offer,
+    accounts: OfferCreation,
+    fee_basis_points: u16,
+    needs_approval: bool,
+    allow_permissionless: bool,
+    fulfillment_mode: Option<FulfillmentMode>,
+    validity: OfferValidity,
+) -> Result<()> {
+    // Validate fee is within the configured cap
     require!(
-        fee_basis_points <= MAX_ALLOWED_FEE_BPS,
+        fee_basis_points <= accounts.config.max_fee_bps,
         MakeOfferErrorCode::InvalidFee
     );
 
+    // An expiry must leave the offer open for some time
+    require!(
+        validity.valid_until == 0 || validity.valid_until > validity.valid_from,
+        MakeOfferErrorCode::InvalidValidityWindow
+    );
+
+    // Minting token_out must be possible from the first take on
+    if fulfillment_mode == Some(FulfillmentMode::Mint) {
+        require!(
+            program_controls_mint(accounts.token_out_mint, accounts.mint_authority),
+            MakeOfferErrorCode::MintAuthorityRequired
+        );
+    }
+
+    // Only allow mints whose Token-2022 extensions are known to be safe
+    validate_mint_extensions(accounts.token_in_mint)?;
+    validate_mint_extensions(accounts.token_out_mint)?;
+    validate_mint_decimals(accounts.token_in_mint)?;
+    validate_mint_decimals(accounts.token_out_mint)?;
+
+    // Assign the next generation of the offer at this PDA
+    let record = accounts.offer_version_record;
+    if record.latest_version == 0 {
+        record.token_in_mint = accounts.token_in_mint.key();
+        record.token_out_mint = accounts.token_out_mint.key();
+        record.bump = accounts.offer_version_record_bump;
+    }
+    let offer_version = record
+        .latest_version
+        .checked_add(1)
+        .ok_or(MakeOfferErrorCode::OfferVersionOverflow)?;
+    record.latest_version = offer_version;
+
+    // List the offer in the registry of live offers
+    let registry = accounts.offer_registry;
+    registry.bump = accounts.offer_registry_bump;
+    require!(
+        registry.register(accounts.offer_pda),
+        MakeOfferErrorCode::OfferRegistryFull
+    );
+
     // Create the offer
-    let mut offer = ctx.accounts.offer.load_init()?;
-    offer.token_in_mint = ctx.accounts.token_in_mint.key();
-    offer.token_out_mint = ctx.accounts.token_out_mint.key();
+    offer.token_in_mint = accounts.token_in_mint.key();
+    offer.token_out_mint = accounts.token_out_mint.key();
     offer.fee_basis_points = fee_basis_points;
     offer.set_approval(needs_approval);
     offer.set_permissionless(allow_permissionless);
-    offer.bump = ctx.bumps.offer;
+    offer.set_proceeds_to_vault(true);
+    offer.set_own_vault(true);
+    offer.set_fulfillment_mode(fulfillment_mode);
+    offer.bump = accounts.offer_bump;
+    offer.offer_version = offer_version;
+    offer.valid_from = validity.valid_from;
+    offer.valid_until = validity.valid_until;
+    offer.record_decimals(
+        accounts.token_in_mint.decimals,
+        accounts.token_out_mint.decimals,
+    );
 
-    msg!("Offer created at: {}", ctx.accounts.offer.key());
+    msg!(
+        "Offer created at: {}, version: {}, fulfillment mode: {:?}",
+        accounts.offer_pda,
+        offer_version,
+        fulfillment_mode
+    );
 
     emit!(OfferMadeEvent {
-        offer_pda: ctx.accounts.offer.key(),
-        token_in_mint: ctx.accounts.token_in_mint.key(),
-        token_out_mint: ctx.accounts.token_out_mint.key(),
+        offer_pda: accounts.offer_pda,
+        offer_version,
+        token_in_mint: accounts.token_in_mint.key(),
+        token_out_mint: accounts.token_out_mint.key(),
         fee_basis_points,
-        boss: ctx.accounts.boss.key(),
+        boss: accounts.boss,
         needs_approval,
         allow_permissionless,
+        fulfillment_mode,
+        valid_from: validity.valid_from,
+        valid_until: validity.valid_until,
     });
 
     Ok(())
@@ -169,11 +411,27 @@ pub enum MakeOfferErrorCode {
     #[msg("Offer account is full, cannot create more offers")]
     AccountFull,
 
-    /// Fee basis points exceeds maximum allowed value of 10000 (100%)
-    #[msg("Invalid fee: fee_basis_points must be <= 10000")]
+    /// Fee basis points exceeds the configured maximum
+    #[msg("Invalid fee: fee_basis_points exceeds the configured maximum")]
     InvalidFee,
 
     /// Invalid token program interface provided
     #[msg("Invalid token program")]
     InvalidTokenProgram,
+
+    /// The token pair has exhausted its offer versions
+    #[msg("Offer version overflow")]
+    OfferVersionOverflow,
+
+    /// Every slot of the offer registry is occupied
+    #[msg("Offer registry is full")]
+    OfferRegistryFull,
+
+    /// Mint fulfillment requested without the program holding the token_out mint authority
+    #[msg("Fulfillment mode Mint requires the program to hold the token_out mint authority")]
+    MintAuthorityRequired,
+
+    /// The offer would expire before or when it becomes takeable
+    #[msg("Invalid validity window: valid_until must be after valid_from")]
+    InvalidValidityWindow,
 }
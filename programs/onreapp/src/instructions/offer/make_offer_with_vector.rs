@@ -0,0 +1,294 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::VaultAuthorityType;
+use crate::instructions::{
+    append_offer_vector, create_offer, FulfillmentMode, MakeOfferErrorCode, Offer, OfferCreation,
+    OfferRegistry, OfferValidity, OfferVaultDepositEvent, OfferVectorParams, OfferVersionRecord,
+};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Account structure for creating a priced and optionally funded offer
+///
+/// Holds the accounts of `make_offer`, plus the boss's token_out account funding the
+/// optional initial vault deposit. The offer is created, priced and funded in one
+/// instruction, so it never exists without a pricing vector.
+#[derive(Accounts)]
+pub struct MakeOfferWithVector<'info> {
+    /// Program-derived authority of the new offer's own vault
+    ///
+    /// Derived from the offer address, so the offer's balances are kept apart from
+    /// the shared offer vault and every other offer. This PDA manages token transfers
+    /// and burning operations when the program has mint authority.
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY, offer.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The input token mint for the offer
+    ///
+    /// Must be owned by the provided input token program.
+    #[account(
+        constraint = *token_in_mint.to_account_info().owner == token_in_program.key()
+            @ MakeOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the input token
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Vault account for storing input tokens during burn/mint operations
+    ///
+    /// Created automatically if needed. Used for temporary token storage
+    /// when the program has mint authority and needs to burn tokens.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The output token mint for the offer
+    ///
+    /// Must be owned by the provided output token program.
+    #[account(
+        constraint = *token_out_mint.to_account_info().owner == token_out_program.key()
+            @ MakeOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the output token
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Vault account for storing output tokens distributed to takers
+    ///
+    /// Created automatically if needed and credited with the initial deposit.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority, checked against the token_out mint for `Mint`
+    /// fulfillment
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The offer account storing exchange configuration and pricing vectors
+    ///
+    /// This account is derived from token mint addresses ensuring unique
+    /// offers per token pair. Contains fee settings, approval requirements,
+    /// and pricing vector array for dynamic pricing.
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Record of the latest offer version at the offer PDA
+    ///
+    /// Created with the first offer of the token pair and kept when the offer is
+    /// closed, so a recreated offer receives the next version.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + OfferVersionRecord::INIT_SPACE,
+        seeds = [
+            seeds::OFFER_VERSION,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub offer_version_record: Box<Account<'info, OfferVersionRecord>>,
+
+    /// Registry of the live offers, created with the first offer made
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + OfferRegistry::INIT_SPACE,
+        seeds = [seeds::OFFER_REGISTRY],
+        bump
+    )]
+    pub offer_registry: Box<Account<'info, OfferRegistry>>,
+
+    /// Program state account containing boss authorization, the NAV jump limit and
+    /// the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// Program-wide configuration providing the maximum fee and the seconds per year
+    /// for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Boss's token_out account funding the initial vault deposit
+    ///
+    /// Required only when an initial deposit is made.
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_out_program
+    )]
+    pub boss_token_out_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The boss account authorized to create offers and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates an offer together with its first pricing vector and optional vault deposit
+///
+/// Performs `make_offer`, `add_offer_vector` and, if `deposit_amount` is provided,
+/// `offer_vault_deposit` of token_out atomically. Since the offer cannot be observed
+/// without pricing, takes never hit a freshly made offer lacking an active vector.
+/// Any failing step reverts the whole instruction. The offer can be taken from
+/// creation on and never expires until scheduled with `update_offer_validity`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `fee_basis_points` - Fee in basis points (10000 = 100%) charged when taking the offer
+/// * `needs_approval` - Whether the offer requires boss approval for taking
+/// * `allow_permissionless` - Whether the offer allows permissionless operations
+/// * `fulfillment_mode` - How takes deliver token_out, as for `make_offer`
+/// * `vector` - The first pricing vector, as for `add_offer_vector`
+/// * `deposit_amount` - token_out to move from the boss into the offer vault, if any
+///
+/// # Returns
+/// * `Ok(())` - If the offer is created, priced and funded
+/// * `Err(MakeOfferErrorCode::_)` - If the offer configuration is rejected as by `make_offer`
+/// * `Err(AddOfferVectorErrorCode::_)` - If the vector is rejected as by `add_offer_vector`
+/// * `Err(MakeOfferWithVectorErrorCode::BossTokenOutAccountRequired)` - If a deposit is
+///   requested without the boss's token_out account
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Creates the offer as `make_offer` does
+/// - Adds the pricing vector to the offer
+/// - Transfers the deposit from the boss to the offer vault and advances the vault
+///   event sequence
+///
+/// # Events
+/// * `OfferMadeEvent` - Emitted with offer details and configuration
+/// * `OfferVectorAddedEvent` - Emitted with the vector parameters
+/// * `OfferVaultDepositEvent` - Emitted with the deposit details, if a deposit is made
+pub fn make_offer_with_vector(
+    ctx: Context<MakeOfferWithVector>,
+    fee_basis_points: u16,
+    needs_approval: bool,
+    allow_permissionless: bool,
+    fulfillment_mode: Option<FulfillmentMode>,
+    vector: OfferVectorParams,
+    deposit_amount: Option<u64>,
+) -> Result<()> {
+    {
+        let mut offer = ctx.accounts.offer.load_init()?;
+
+        create_offer(
+            &mut offer,
+            OfferCreation {
+                offer_pda: ctx.accounts.offer.key(),
+                offer_bump: ctx.bumps.offer,
+                offer_version_record: &mut ctx.accounts.offer_version_record,
+                offer_version_record_bump: ctx.bumps.offer_version_record,
+                offer_registry: &mut ctx.accounts.offer_registry,
+                offer_registry_bump: ctx.bumps.offer_registry,
+                token_in_mint: &ctx.accounts.token_in_mint,
+                token_out_mint: &ctx.accounts.token_out_mint,
+                mint_authority: &ctx.accounts.mint_authority,
+                config: &ctx.accounts.config,
+                boss: ctx.accounts.boss.key(),
+            },
+            fee_basis_points,
+            needs_approval,
+            allow_permissionless,
+            fulfillment_mode,
+            // Scheduled through `update_offer_validity`
+            OfferValidity::default(),
+        )?;
+
+        append_offer_vector(
+            &mut offer,
+            ctx.accounts.offer.key(),
+            vector,
+            ctx.accounts.state.max_nav_jump_bps,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    if let Some(amount) = deposit_amount {
+        let boss_token_out_account = ctx
+            .accounts
+            .boss_token_out_account
+            .as_ref()
+            .ok_or(MakeOfferWithVectorErrorCode::BossTokenOutAccountRequired)?;
+        let pre_balance = ctx.accounts.vault_token_out_account.amount;
+
+        transfer_tokens(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.token_out_program,
+            boss_token_out_account,
+            &ctx.accounts.vault_token_out_account,
+            &ctx.accounts.boss,
+            None,
+            amount,
+        )?;
+
+        ctx.accounts.vault_token_out_account.reload()?;
+        let post_balance = ctx.accounts.vault_token_out_account.amount;
+        let sequence = ctx.accounts.state.next_vault_event_sequence();
+
+        emit!(OfferVaultDepositEvent {
+            mint: ctx.accounts.token_out_mint.key(),
+            amount,
+            boss: ctx.accounts.boss.key(),
+            authority_type: VaultAuthorityType::Offer,
+            pre_balance,
+            post_balance,
+            sequence,
+        });
+
+        msg!("Offer vault deposit successful: {} tokens", amount);
+    }
+
+    Ok(())
+}
+
+/// Error codes for the make_offer_with_vector instruction
+#[error_code]
+pub enum MakeOfferWithVectorErrorCode {
+    /// An initial deposit was requested without the boss's token_out account
+    #[msg("Boss token_out account required for the initial deposit")]
+    BossTokenOutAccountRequired,
+}
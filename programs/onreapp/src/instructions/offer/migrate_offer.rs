@@ -0,0 +1,214 @@
+use crate::constants::{seeds, MAX_VECTORS};
+use crate::instructions::{Offer, OfferVector};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+
+/// Number of pricing vectors stored by offers created before the vector array was enlarged
+pub const LEGACY_MAX_VECTORS: usize = 10;
+
+/// Size of a pricing vector in legacy offers, before the pricing mode was added
+const LEGACY_VECTOR_SIZE: usize = 40;
+
+/// Size of the fields following the vector array in offers with the legacy vector capacity
+const LEGACY_TAIL_SIZE: usize = 136;
+
+/// Byte offset of the vector array inside the offer account (discriminator + two mints)
+const VECTORS_OFFSET: usize = 8 + 32 + 32;
+
+/// Event emitted when a legacy offer account is migrated to the current layout
+///
+/// Provides transparency for tracking offer account reallocations.
+#[event]
+pub struct OfferMigratedEvent {
+    /// The PDA address of the migrated offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Account data length before migration
+    pub old_size: u64,
+    /// Account data length after migration
+    pub new_size: u64,
+}
+
+/// Account structure for migrating an offer to the enlarged vector layout
+///
+/// This struct defines the accounts required to reallocate an offer that was created
+/// with the legacy vector capacity. Only the boss can migrate offers and pays for
+/// the additional rent.
+///
+/// Note: The offer account is NOT deserialized since its legacy size does not match
+/// the current Offer definition.
+#[derive(Accounts)]
+pub struct MigrateOffer<'info> {
+    /// The legacy offer account to be reallocated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    /// Its owner and discriminator are validated manually in the handler.
+    /// CHECK: Manual validation of owner and discriminator without deserialization
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub offer: UncheckedAccount<'info>,
+
+    /// The input token mint of the offer, used for PDA derivation
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint of the offer, used for PDA derivation
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to migrate offers and pay the additional rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for the rent top-up transfer
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy offer account to the current layout
+///
+/// Offers created before the vector array was enlarged hold `LEGACY_MAX_VECTORS`
+/// vector slots of the legacy vector size, followed by the legacy fields.
+/// This instruction reallocates such an account to the current `Offer` size, keeps
+/// the existing vectors in their slots and moves the fields that follow the vector
+/// array (fee, bump, flags, step volume tracking and statistics) to their new
+/// offsets. Migrated legacy vectors use step pricing, and the newly added vector
+/// slots and fields are zero-initialized, i.e. empty.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully migrated
+/// * `Err(MigrateOfferErrorCode::InvalidOfferOwner)` - If the account is not owned by the program
+/// * `Err(MigrateOfferErrorCode::InvalidOfferData)` - If the discriminator does not match
+/// * `Err(MigrateOfferErrorCode::AlreadyMigrated)` - If the offer already has the current size
+/// * `Err(MigrateOfferErrorCode::UnexpectedOfferSize)` - If the account does not have the legacy size
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Offer account is resized to `8 + Offer::INIT_SPACE` bytes
+/// - Boss pays the rent difference for the larger account
+///
+/// # Events
+/// * `OfferMigratedEvent` - Emitted with the old and new account sizes
+pub fn migrate_offer(ctx: Context<MigrateOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+
+    require_keys_eq!(
+        *offer.owner,
+        crate::ID,
+        MigrateOfferErrorCode::InvalidOfferOwner
+    );
+
+    // The legacy tail keeps its size and moves to the start of the current tail
+    let new_size = 8 + Offer::INIT_SPACE;
+    let new_tail_offset = VECTORS_OFFSET + MAX_VECTORS * OfferVector::INIT_SPACE;
+    let legacy_size = VECTORS_OFFSET + LEGACY_MAX_VECTORS * LEGACY_VECTOR_SIZE + LEGACY_TAIL_SIZE;
+
+    // Copy the vectors and the fields following them before touching the account size
+    let old_size = offer.data_len();
+    let (vectors, tail) = {
+        let data = offer.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *Offer::DISCRIMINATOR,
+            MigrateOfferErrorCode::InvalidOfferData
+        );
+        require!(old_size != new_size, MigrateOfferErrorCode::AlreadyMigrated);
+        require!(
+            old_size == legacy_size,
+            MigrateOfferErrorCode::UnexpectedOfferSize
+        );
+        let tail_offset = old_size - LEGACY_TAIL_SIZE;
+        (
+            data[VECTORS_OFFSET..tail_offset].to_vec(),
+            data[tail_offset..].to_vec(),
+        )
+    };
+
+    // Top up rent for the additional space
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let missing_lamports = required_lamports.saturating_sub(offer.lamports());
+    if missing_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.boss.to_account_info(),
+                    to: offer.to_account_info(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    offer.resize(new_size)?;
+
+    // Re-lay the vectors in the current format and move the tail to its new offset
+    {
+        let mut data = offer.try_borrow_mut_data()?;
+        data[VECTORS_OFFSET..].fill(0);
+
+        for (i, vector) in vectors.chunks_exact(LEGACY_VECTOR_SIZE).enumerate() {
+            let offset = VECTORS_OFFSET + i * OfferVector::INIT_SPACE;
+            data[offset..offset + LEGACY_VECTOR_SIZE].copy_from_slice(vector);
+        }
+
+        data[new_tail_offset..new_tail_offset + tail.len()].copy_from_slice(&tail);
+    }
+
+    // The legacy layout predates offer versioning
+    let offer_version = 0;
+
+    msg!(
+        "Offer migrated: {}, size: {} -> {}",
+        offer.key(),
+        old_size,
+        new_size
+    );
+
+    emit!(OfferMigratedEvent {
+        offer_pda: offer.key(),
+        offer_version,
+        old_size: old_size as u64,
+        new_size: new_size as u64,
+    });
+
+    Ok(())
+}
+
+/// Error codes for offer migration operations
+#[error_code]
+pub enum MigrateOfferErrorCode {
+    /// Offer account is not owned by this program
+    #[msg("Offer account must be owned by this program")]
+    InvalidOfferOwner,
+    /// Offer account data does not start with the Offer discriminator
+    #[msg("Invalid offer account data")]
+    InvalidOfferData,
+    /// Offer account already has the current layout
+    #[msg("Offer is already migrated")]
+    AlreadyMigrated,
+    /// Offer account size matches neither the legacy nor the current layout
+    #[msg("Unexpected offer account size")]
+    UnexpectedOfferSize,
+}
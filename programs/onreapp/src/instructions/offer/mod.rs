@@ -1,19 +1,97 @@
 pub mod add_offer_vector;
+pub mod checkpoint_navs;
+pub mod clawback_record_state;
+pub mod clawback_take;
+pub mod close_offer;
+pub mod close_permissionless_authority;
+pub mod close_take_receipt;
+pub mod collect_proceeds;
+pub mod compact_offer_vectors;
+pub mod crank_expired_offer;
 pub mod delete_all_offer_vectors;
 pub mod delete_offer_vector;
+pub mod dual_offer_state;
+pub mod get_offer_vectors;
+pub mod get_registry;
+pub mod make_dual_offer;
 pub mod make_offer;
+pub mod make_offer_with_vector;
+pub mod migrate_offer;
+pub mod nav_history_state;
+pub mod offer_registry_state;
 pub mod offer_state;
 pub mod offer_utils;
+pub mod offer_version_state;
+pub mod prepare_accounts;
+pub mod record_nav_observation;
+pub mod recover_intermediary_funds;
+pub mod set_offer_clawback;
+pub mod set_offer_oracle;
+pub mod set_offer_price_on_net_received;
+pub mod set_offer_proceeds_to_vault;
+pub mod set_offer_strict_accounts;
+pub mod set_permissionless_fee;
+pub mod take_dual_offer;
 pub mod take_offer;
+pub mod take_offer_delegated;
+pub mod take_offer_exact_out;
 pub mod take_offer_permissionless;
+pub mod take_receipt_state;
+pub mod tick_offer;
+pub mod update_offer_dust_threshold;
 pub mod update_offer_fee;
+pub mod update_offer_flags;
+pub mod update_offer_low_inventory_threshold;
+pub mod update_offer_max_step_volume;
+pub mod update_offer_min_take;
+pub mod update_offer_validity;
+pub mod user_position_state;
 
 pub use add_offer_vector::*;
+pub use checkpoint_navs::*;
+pub use clawback_record_state::*;
+pub use clawback_take::*;
+pub use close_offer::*;
+pub use close_permissionless_authority::*;
+pub use close_take_receipt::*;
+pub use collect_proceeds::*;
+pub use compact_offer_vectors::*;
+pub use crank_expired_offer::*;
 pub use delete_all_offer_vectors::*;
 pub use delete_offer_vector::*;
+pub use dual_offer_state::*;
+pub use get_offer_vectors::*;
+pub use get_registry::*;
+pub use make_dual_offer::*;
 pub use make_offer::*;
+pub use make_offer_with_vector::*;
+pub use migrate_offer::*;
+pub use nav_history_state::*;
+pub use offer_registry_state::*;
 pub use offer_state::*;
 pub use offer_utils::*;
+pub use offer_version_state::*;
+pub use prepare_accounts::*;
+pub use record_nav_observation::*;
+pub use recover_intermediary_funds::*;
+pub use set_offer_clawback::*;
+pub use set_offer_oracle::*;
+pub use set_offer_price_on_net_received::*;
+pub use set_offer_proceeds_to_vault::*;
+pub use set_offer_strict_accounts::*;
+pub use set_permissionless_fee::*;
+pub use take_dual_offer::*;
 pub use take_offer::*;
+pub use take_offer_delegated::*;
+pub use take_offer_exact_out::*;
 pub use take_offer_permissionless::*;
+pub use take_receipt_state::*;
+pub use tick_offer::*;
+pub use update_offer_dust_threshold::*;
 pub use update_offer_fee::*;
+pub use update_offer_flags::*;
+pub use update_offer_low_inventory_threshold::*;
+pub use update_offer_max_step_volume::*;
+pub use update_offer_min_take::*;
+pub use update_offer_validity::*;
+pub use user_position_state::*;
@@ -0,0 +1,70 @@
+use crate::constants::{NAV_HISTORY_SIZE, NAV_OBSERVATION_MIN_INTERVAL};
+use anchor_lang::prelude::*;
+
+/// Ring buffer of the most recent NAV observations of an offer
+///
+/// Observations are recorded by takes and fulfillments that pass the account and by
+/// the permissionless `record_nav_observation` crank, at most one per
+/// `NAV_OBSERVATION_MIN_INTERVAL`. Once full, the oldest observation is overwritten.
+#[account]
+#[derive(InitSpace)]
+pub struct NavHistory {
+    /// Reference to the Offer PDA whose NAV is observed
+    pub offer: Pubkey,
+    /// Stored observations, `next_index` points at the slot written next
+    pub observations: [NavObservation; NAV_HISTORY_SIZE],
+    /// Index of the slot the next observation is written to
+    pub next_index: u8,
+    /// Number of stored observations, at most `NAV_HISTORY_SIZE`
+    pub count: u8,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+/// A single NAV observation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct NavObservation {
+    /// Unix timestamp of the observation
+    pub timestamp: u64,
+    /// NAV with scale=9 at the observation time
+    pub nav: u64,
+}
+
+impl NavHistory {
+    /// Returns the most recent observation, if any
+    pub fn latest(&self) -> Option<NavObservation> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.next_index as usize + NAV_HISTORY_SIZE - 1) % NAV_HISTORY_SIZE;
+        Some(self.observations[index])
+    }
+
+    /// Returns whether enough time passed since the latest observation to record another
+    pub fn can_record(&self, timestamp: u64) -> bool {
+        match self.latest() {
+            Some(latest) => timestamp >= latest.timestamp + NAV_OBSERVATION_MIN_INTERVAL,
+            None => true,
+        }
+    }
+
+    /// Appends an observation, overwriting the oldest one once the buffer is full
+    pub fn record(&mut self, timestamp: u64, nav: u64) {
+        self.observations[self.next_index as usize] = NavObservation { timestamp, nav };
+        self.next_index = ((self.next_index as usize + 1) % NAV_HISTORY_SIZE) as u8;
+        if (self.count as usize) < NAV_HISTORY_SIZE {
+            self.count += 1;
+        }
+    }
+
+    /// Returns the stored observations from oldest to newest
+    pub fn chronological(&self) -> Vec<NavObservation> {
+        let start =
+            (self.next_index as usize + NAV_HISTORY_SIZE - self.count as usize) % NAV_HISTORY_SIZE;
+        (0..self.count as usize)
+            .map(|i| self.observations[(start + i) % NAV_HISTORY_SIZE])
+            .collect()
+    }
+}
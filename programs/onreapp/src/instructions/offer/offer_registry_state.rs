@@ -0,0 +1,61 @@
+use crate::constants::MAX_REGISTERED_OFFERS;
+use anchor_lang::prelude::*;
+
+/// Registry of the live offers of the program
+///
+/// Maintained by `make_offer` and `close_offer` so clients and other programs can
+/// enumerate offers from a single account instead of scanning program accounts.
+/// Offers made before the registry existed are not listed.
+#[account]
+#[derive(InitSpace)]
+pub struct OfferRegistry {
+    /// Registered offer PDAs, empty slots hold the default pubkey
+    pub offers: [Pubkey; MAX_REGISTERED_OFFERS],
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl OfferRegistry {
+    /// Returns the registered offers in slot order
+    pub fn active_offers(&self) -> Vec<Pubkey> {
+        self.offers
+            .iter()
+            .filter(|offer| **offer != Pubkey::default())
+            .copied()
+            .collect()
+    }
+
+    /// Registers an offer in the first empty slot
+    ///
+    /// Returns false if every slot is occupied. Registering an offer that is
+    /// already listed does nothing.
+    pub fn register(&mut self, offer: Pubkey) -> bool {
+        if self.offers.contains(&offer) {
+            return true;
+        }
+        match self
+            .offers
+            .iter_mut()
+            .find(|slot| **slot == Pubkey::default())
+        {
+            Some(slot) => {
+                *slot = offer;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes an offer from the registry, returning whether it was listed
+    pub fn unregister(&mut self, offer: &Pubkey) -> bool {
+        match self.offers.iter_mut().find(|slot| **slot == *offer) {
+            Some(slot) => {
+                *slot = Pubkey::default();
+                true
+            }
+            None => false,
+        }
+    }
+}
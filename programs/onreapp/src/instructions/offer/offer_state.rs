@@ -5,7 +5,7 @@ use anchor_lang::prelude::*;
 ///
 /// Stores configuration for token pair exchanges with time-based pricing vectors
 /// that implement compound interest growth using Annual Percentage Rate (APR).
-/// Each offer is unique per token pair and supports up to 10 pricing vectors.
+/// Each offer is unique per token pair and supports up to 64 pricing vectors.
 #[account(zero_copy)]
 #[repr(C)]
 #[derive(InitSpace)]
@@ -24,8 +24,86 @@ pub struct Offer {
     needs_approval: u8,
     /// Whether the offer allows permissionless operations (0 = false, 1 = true)
     allow_permissionless: u8,
-    /// Reserved space for future fields
-    reserved: [u8; 131],
+    /// Whether takes of the offer may be clawed back within the dispute window (0 = false, 1 = true)
+    clawback_enabled: u8,
+    /// Unit the minimum take is expressed in (0 = Token, 1 = Quote)
+    min_take_denomination: u8,
+    /// Whether take proceeds land in the offer vault instead of the boss's account
+    /// (0 = boss, 1 = vault)
+    proceeds_to_vault: u8,
+    /// Maximum amount of token_out that can be taken within one pricing step (0 = no cap)
+    pub max_step_volume: u64,
+    /// Amount of token_out already taken within the tracked pricing step
+    pub step_volume: u64,
+    /// Start time of the pricing step that step_volume refers to
+    pub step_volume_start_time: u64,
+    /// Pyth price update account valuing token_in in USD (default = no oracle, token_in is USD)
+    pub oracle: Pubkey,
+    /// Maximum accepted age of the oracle price in seconds
+    pub oracle_max_staleness: u64,
+    /// Maximum accepted oracle confidence interval in basis points of the price
+    pub oracle_max_confidence_bps: u16,
+    /// Whether takes are priced on the token_in amount received after Token-2022
+    /// transfer fees instead of the gross amount paid (0 = false, 1 = true)
+    price_on_net_received: u8,
+    /// How takes deliver token_out (0 = by mint authority, 1 = Transfer, 2 = Mint)
+    fulfillment_mode: u8,
+    /// Decimals of token_in_mint when the offer was made
+    token_in_decimals: u8,
+    /// Decimals of token_out_mint when the offer was made
+    token_out_decimals: u8,
+    /// Whether the decimals above were recorded, unset for offers made before
+    /// decimals were stored (0 = false, 1 = true)
+    decimals_recorded: u8,
+    /// Padding keeping the following u64 fields 8-byte aligned
+    padding2: [u8; 1],
+    /// NAV with scale=9 recorded by the latest checkpoint (0 = never checkpointed)
+    pub nav_checkpoint: u64,
+    /// Unix timestamp of the latest NAV checkpoint
+    pub nav_checkpoint_time: u64,
+    /// Cumulative token_in fees collected by takes, as a little-endian u128
+    ///
+    /// Stored as bytes since a u128 field would change the account alignment.
+    total_fees_collected_token_in: [u8; 16],
+    /// Cumulative token_in paid by takes including fees, as a little-endian u128
+    total_volume_token_in: [u8; 16],
+    /// Minimum size of a single take of the offer (0 = no minimum)
+    ///
+    /// Expressed in token_in base units, or in USD with scale=9 when the
+    /// denomination is `Quote`.
+    pub min_take_amount: u64,
+    /// Start time of the active vector recorded by the latest `tick_offer` (0 = none)
+    pub last_active_vector_start_time: u64,
+    /// Dust threshold: minimum token_out a single take must pay out
+    ///
+    /// Takes paying out zero token_out are always rejected; 0 applies only that check.
+    pub min_token_out_amount: u64,
+    /// Generation of the offer at its PDA, from the offer version record
+    ///
+    /// Starts at 1 and grows each time the offer is recreated after `close_offer`;
+    /// 0 marks offers created before versioning.
+    pub offer_version: u32,
+    /// Whether the offer was closed by `crank_expired_offer` (0 = false, 1 = true)
+    closed: u8,
+    /// Whether the offer is served by its own vault authority instead of the shared
+    /// one (0 = shared, 1 = own)
+    own_vault: u8,
+    /// Whether takes require the user's token_out account and position to exist
+    /// already instead of creating them (0 = false, 1 = true)
+    strict_accounts: u8,
+    /// Padding keeping the following u64 field 8-byte aligned
+    padding3: [u8; 1],
+    /// Vault token_out balance at or below which takes flag low inventory (0 = no alerts)
+    pub low_inventory_threshold: u64,
+    /// Unix timestamp from which the offer can be taken (0 = takeable from creation)
+    pub valid_from: u64,
+    /// Unix timestamp from which takes of the offer are rejected (0 = never expires)
+    pub valid_until: u64,
+    /// token_in proceeds takes have left in the offer vault and `collect_proceeds`
+    /// has not collected yet
+    pub uncollected_proceeds: u64,
+    /// Pyth feed id the oracle's price updates must carry (zeroed = no oracle)
+    pub oracle_feed_id: [u8; 32],
 }
 
 impl Offer {
@@ -48,13 +126,162 @@ impl Offer {
     pub fn set_permissionless(&mut self, allow_permissionless: bool) {
         self.allow_permissionless = if allow_permissionless { 1 } else { 0 };
     }
+
+    /// Returns whether takes of the offer may be clawed back
+    pub fn clawback_enabled(&self) -> bool {
+        self.clawback_enabled != 0
+    }
+
+    /// Sets whether takes of the offer may be clawed back
+    pub fn set_clawback_enabled(&mut self, clawback_enabled: bool) {
+        self.clawback_enabled = if clawback_enabled { 1 } else { 0 };
+    }
+
+    /// Returns whether take proceeds land in the offer vault
+    pub fn proceeds_to_vault(&self) -> bool {
+        self.proceeds_to_vault != 0
+    }
+
+    /// Sets whether take proceeds land in the offer vault
+    pub fn set_proceeds_to_vault(&mut self, proceeds_to_vault: bool) {
+        self.proceeds_to_vault = if proceeds_to_vault { 1 } else { 0 };
+    }
+
+    /// Returns whether takes are priced on the token_in amount received after transfer fees
+    pub fn price_on_net_received(&self) -> bool {
+        self.price_on_net_received != 0
+    }
+
+    /// Sets whether takes are priced on the token_in amount received after transfer fees
+    pub fn set_price_on_net_received(&mut self, price_on_net_received: bool) {
+        self.price_on_net_received = if price_on_net_received { 1 } else { 0 };
+    }
+
+    /// Returns whether the offer was closed after its expiry
+    pub fn is_closed(&self) -> bool {
+        self.closed != 0
+    }
+
+    /// Sets whether the offer was closed after its expiry
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = if closed { 1 } else { 0 };
+    }
+
+    /// Returns whether the offer is served by its own vault authority
+    pub fn has_own_vault(&self) -> bool {
+        self.own_vault != 0
+    }
+
+    /// Sets whether the offer is served by its own vault authority
+    pub fn set_own_vault(&mut self, own_vault: bool) {
+        self.own_vault = if own_vault { 1 } else { 0 };
+    }
+
+    /// Returns whether takes require the user's accounts to exist already
+    pub fn strict_accounts(&self) -> bool {
+        self.strict_accounts != 0
+    }
+
+    /// Sets whether takes require the user's accounts to exist already
+    pub fn set_strict_accounts(&mut self, strict_accounts: bool) {
+        self.strict_accounts = if strict_accounts { 1 } else { 0 };
+    }
+
+    /// Returns the token_in and token_out decimals recorded when the offer was made
+    ///
+    /// `None` for offers made before decimals were stored.
+    pub fn recorded_decimals(&self) -> Option<(u8, u8)> {
+        (self.decimals_recorded != 0).then_some((self.token_in_decimals, self.token_out_decimals))
+    }
+
+    /// Records the token_in and token_out decimals the offer is priced with
+    pub fn record_decimals(&mut self, token_in_decimals: u8, token_out_decimals: u8) {
+        self.token_in_decimals = token_in_decimals;
+        self.token_out_decimals = token_out_decimals;
+        self.decimals_recorded = 1;
+    }
+
+    /// Returns the seed following `OFFER_VAULT_AUTHORITY` in the offer's vault authority
+    ///
+    /// Offers with their own vault are served by the authority derived from
+    /// `[OFFER_VAULT_AUTHORITY, offer_pda]`. All other offers share the authority
+    /// derived from `[OFFER_VAULT_AUTHORITY]`, selected by the empty seed since empty
+    /// seeds leave a derived address unchanged.
+    pub fn vault_authority_seed(&self, offer_pda: &Pubkey) -> Vec<u8> {
+        if self.has_own_vault() {
+            offer_pda.to_bytes().to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the cumulative token_in fees collected by takes of the offer
+    pub fn total_fees_collected_token_in(&self) -> u128 {
+        u128::from_le_bytes(self.total_fees_collected_token_in)
+    }
+
+    /// Returns the cumulative token_in paid by takes of the offer, including fees
+    pub fn total_volume_token_in(&self) -> u128 {
+        u128::from_le_bytes(self.total_volume_token_in)
+    }
+
+    /// Sets the cumulative take counters of the offer
+    pub fn set_take_totals(
+        &mut self,
+        total_fees_collected_token_in: u128,
+        total_volume_token_in: u128,
+    ) {
+        self.total_fees_collected_token_in = total_fees_collected_token_in.to_le_bytes();
+        self.total_volume_token_in = total_volume_token_in.to_le_bytes();
+    }
+
+    /// Returns the unit the minimum take is expressed in
+    pub fn min_take_denomination(&self) -> MinimumDenomination {
+        match self.min_take_denomination {
+            1 => MinimumDenomination::Quote,
+            _ => MinimumDenomination::Token,
+        }
+    }
+
+    /// Sets the unit the minimum take is expressed in
+    pub fn set_min_take_denomination(&mut self, denomination: MinimumDenomination) {
+        self.min_take_denomination = denomination as u8;
+    }
+
+    /// Returns how takes deliver token_out
+    ///
+    /// `None` for offers created without an explicit mode, which mint token_out
+    /// whenever the program holds its mint authority and transfer it from the vault
+    /// otherwise.
+    pub fn fulfillment_mode(&self) -> Option<FulfillmentMode> {
+        match self.fulfillment_mode {
+            1 => Some(FulfillmentMode::Transfer),
+            2 => Some(FulfillmentMode::Mint),
+            _ => None,
+        }
+    }
+
+    /// Sets how takes deliver token_out
+    pub fn set_fulfillment_mode(&mut self, fulfillment_mode: Option<FulfillmentMode>) {
+        self.fulfillment_mode = match fulfillment_mode {
+            None => 0,
+            Some(FulfillmentMode::Transfer) => 1,
+            Some(FulfillmentMode::Mint) => 2,
+        };
+    }
+
+    /// Returns whether token_in is valued through an oracle price feed
+    pub fn has_oracle(&self) -> bool {
+        self.oracle != Pubkey::default()
+    }
 }
 
 /// Time-based pricing vector with APR-driven compound growth
 ///
 /// Defines price evolution over time using Annual Percentage Rate (APR) with
-/// discrete pricing steps. Each vector becomes active at start_time and
-/// implements compound interest pricing until the next vector activates.
+/// either discrete pricing steps or per-second linear pricing. Each vector becomes
+/// active at start_time and implements compound interest pricing until the next
+/// vector activates.
 #[zero_copy]
 #[repr(C)]
 #[derive(Default, InitSpace)]
@@ -68,8 +295,75 @@ pub struct OfferVector {
     /// Annual Percentage Rate scaled by 1_000_000 (1_000_000 = 1% APR)
     ///
     /// Determines compound interest rate for price growth over time.
-    /// Scale=6 where 1_000_000 = 1% annual rate.
-    pub apr: u64,
+    /// Scale=6 where 1_000_000 = 1% annual rate. Negative values model a declining NAV.
+    pub apr: i64,
     /// Duration in seconds for each discrete pricing step
     pub price_fix_duration: u64,
+    /// How the price evolves between steps (0 = Step, 1 = Linear)
+    pricing_mode: u8,
+    /// Padding keeping the vector size a multiple of 8 bytes
+    padding: [u8; 7],
+}
+
+impl OfferVector {
+    /// Creates a pricing vector with the given parameters
+    pub fn new(
+        start_time: u64,
+        base_time: u64,
+        base_price: u64,
+        apr: i64,
+        price_fix_duration: u64,
+        pricing_mode: PricingMode,
+    ) -> Self {
+        Self {
+            start_time,
+            base_time,
+            base_price,
+            apr,
+            price_fix_duration,
+            pricing_mode: pricing_mode as u8,
+            padding: [0; 7],
+        }
+    }
+
+    /// Returns how the price of this vector evolves over time
+    pub fn pricing_mode(&self) -> PricingMode {
+        match self.pricing_mode {
+            1 => PricingMode::Linear,
+            _ => PricingMode::Step,
+        }
+    }
+}
+
+/// Price evolution model of an offer vector
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PricingMode {
+    /// Price is fixed within each `price_fix_duration` interval and snaps to the
+    /// interval's end price
+    Step,
+    /// Price is interpolated every second from the vector's base time
+    Linear,
+}
+
+/// Unit a minimum take or redemption size is expressed in
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum MinimumDenomination {
+    /// Minimum is a token amount in base units
+    #[default]
+    Token,
+    /// Minimum is a USD amount with scale=9, converted at the current price so it
+    /// holds its value as the NAV drifts
+    Quote,
+}
+
+/// How takes of an offer deliver token_out to the taker
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FulfillmentMode {
+    /// token_out is transferred from the offer vault, even if the program holds its
+    /// mint authority
+    Transfer,
+    /// token_out is minted by the program, which must hold its mint authority
+    Mint,
 }
@@ -1,10 +1,23 @@
-use crate::instructions::{Offer, OfferVector};
+use crate::constants::{seeds, MAX_BASIS_POINTS, PRICE_DECIMALS};
+use crate::instructions::market_info::get_tvl::read_optional_ata_amount;
+use crate::instructions::{
+    FulfillmentMode, MinimumDenomination, NavHistory, Offer, OfferVector, OfferVectorEvictedEvent,
+    PricingMode, RedemptionOffer, TakeReceipt, UserPosition, UserPositionErrorCode,
+};
+use crate::state::{Config, State};
 use crate::utils::approver::approver_utils;
-use crate::utils::{calculate_fees, calculate_token_out_amount, ApprovalMessage};
+use crate::utils::error_context::{error_with_context, require_with_context};
+use crate::utils::oracle::{read_oracle_usd_price, OracleErrorCode};
+use crate::utils::{
+    calculate_fees, calculate_token_in_amount, calculate_token_out_amount, mul_div_floor_u64,
+    mul_div_u64, scale_amount, ApprovalMessage, REQUIRED_INPUT_ROUNDING,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::Mint;
+use anchor_spl::associated_token::{
+    self, get_associated_token_address_with_program_id, AssociatedToken,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-const SECONDS_IN_YEAR: u128 = 31_536_000;
 const APR_SCALE: u128 = 1_000_000;
 
 /// Common error codes for offer processing operations
@@ -28,11 +41,64 @@ pub enum OfferCoreError {
     /// The offer requires approval but none was provided or verification failed
     #[msg("Approval required for this offer")]
     ApprovalRequired,
+    /// The take would exceed the offer's per-step token_out volume cap
+    #[msg("Step volume cap exceeded")]
+    StepVolumeExceeded,
+    /// The redemption stress policy is enabled but its accounts were not provided
+    #[msg("Redemption offer and vault accounts required by the redemption stress policy")]
+    RedemptionStressAccountsRequired,
+    /// The redemption vault account doesn't match the expected ATA address
+    #[msg("Invalid redemption vault account")]
+    InvalidRedemptionVaultAccount,
+    /// Takes are paused while outstanding redemptions exceed the stress threshold
+    #[msg("Takes paused during redemption stress")]
+    TakesPausedDuringRedemptionStress,
+    /// The take exceeds the per-take limit applied during redemption stress
+    #[msg("Take exceeds the redemption stress limit")]
+    TakeExceedsRedemptionStressLimit,
+    /// The take is smaller than the offer's minimum take size
+    #[msg("Take below the offer's minimum")]
+    TakeBelowMinimum,
+    /// The take pays out zero token_out or less than the offer's dust threshold
+    #[msg("Take output below the offer's dust threshold")]
+    TakeBelowDustThreshold,
+    /// The offer pays proceeds to the boss but the boss token_in account was not provided
+    #[msg("Boss token_in account required by the offer")]
+    BossTokenInAccountRequired,
+    /// The approval was issued for a jurisdiction blocked in program state
+    #[msg("Approval jurisdiction is blocked")]
+    JurisdictionBlocked,
+    /// The offer's validity window has not started yet
+    #[msg("Offer is not active yet")]
+    OfferNotYetActive,
+    /// The offer's validity window has ended
+    #[msg("Offer has expired")]
+    OfferExpired,
+    /// The offer was closed after its expiry
+    #[msg("Offer is closed")]
+    OfferClosed,
+    /// A strict offer's take misses an account it would otherwise create
+    #[msg("Account must exist before taking the offer: create it with prepare_accounts")]
+    TakeAccountNotPrepared,
+    /// The token_in mint's decimals differ from those recorded when the offer was made
+    #[msg("Token_in mint decimals do not match the decimals recorded on the offer")]
+    TokenInDecimalsMismatch,
+    /// The token_out mint's decimals differ from those recorded when the offer was made
+    #[msg("Token_out mint decimals do not match the decimals recorded on the offer")]
+    TokenOutDecimalsMismatch,
+    /// Takes of clawback-enabled offers must pass a take receipt
+    #[msg("Take receipt required for offers flagged for clawbacks")]
+    TakeReceiptRequired,
+    /// Clawback-enabled offers can only be taken through the flows writing take receipts
+    #[msg(
+        "Offers flagged for clawbacks can only be taken with take_offer or take_offer_exact_out"
+    )]
+    ClawbackOfferNotSupported,
 }
 
 /// Result structure containing offer processing calculations
 pub struct OfferProcessResult {
-    /// Current price with scale=9 (1_000_000_000 = 1.0) at the time of processing
+    /// Current price of one token_out in token_in with scale=9 (1_000_000_000 = 1.0)
     pub current_price: u64,
     /// Amount of token_in after fee deduction
     pub token_in_net_amount: u64,
@@ -46,28 +112,30 @@ pub struct OfferProcessResult {
 ///
 /// Checks if the offer requires approval and validates the provided approval message
 /// using cryptographic signature verification against one of the two trusted authorities.
+/// Approvals carrying a jurisdiction code blocked in program state are rejected.
 ///
 /// # Arguments
 /// * `offer` - The offer to check for approval requirement
 /// * `approval_message` - Optional approval message from the user
 /// * `program_id` - The program ID for verification context
 /// * `user_pubkey` - The user's public key
-/// * `approver1` - The first trusted authority's public key for verification
-/// * `approver2` - The second trusted authority's public key for verification
+/// * `state` - Program state holding the two trusted approvers and blocked jurisdictions
 /// * `instructions_sysvar` - The instructions sysvar account for signature verification
+/// * `config` - Program-wide configuration bounding the approval's validity and clock skew
 ///
 /// # Returns
 /// * `Ok(())` - If approval is not needed or verification succeeds with either approver
 /// * `Err(OfferCoreError::ApprovalRequired)` - If approval is required but not provided
+/// * `Err(OfferCoreError::JurisdictionBlocked)` - If the approval's jurisdiction is blocked
 /// * `Err(_)` - If approval verification fails with both approvers
 pub fn verify_offer_approval(
     offer: &Offer,
     approval_message: &Option<ApprovalMessage>,
     program_id: &Pubkey,
     user_pubkey: &Pubkey,
-    approver1: &Pubkey,
-    approver2: &Pubkey,
+    state: &State,
     instructions_sysvar: &UncheckedAccount,
+    config: &Config,
 ) -> Result<()> {
     if offer.needs_approval() {
         match approval_message {
@@ -79,11 +147,17 @@ pub fn verify_offer_approval(
                 approver_utils::verify_approval_message_generic(
                     program_id,
                     user_pubkey,
-                    approver1,
-                    approver2,
+                    &state.approver1,
+                    &state.approver2,
                     instructions_sysvar,
                     msg,
+                    config.approval_window(),
                 )?;
+                require_with_context!(
+                    !state.is_jurisdiction_blocked(msg.jurisdiction),
+                    OfferCoreError::JurisdictionBlocked,
+                    jurisdiction = msg.jurisdiction
+                );
             }
             None => return Err(error!(OfferCoreError::ApprovalRequired)),
         }
@@ -91,17 +165,61 @@ pub fn verify_offer_approval(
     Ok(())
 }
 
+/// Reads the USD price of token_in from the offer's oracle, if one is configured
+///
+/// # Arguments
+/// * `offer` - The offer whose oracle configuration is used
+/// * `oracle` - The oracle account passed to the instruction, if any
+///
+/// # Returns
+/// * `Ok(None)` - If the offer has no oracle (token_in is valued at $1.0)
+/// * `Ok(Some(price))` - USD price of one whole token_in with scale=9
+/// * `Err(OracleErrorCode::OracleAccountMissing)` - If the oracle account was not provided
+/// * `Err(OracleErrorCode::InvalidOracleAccount)` - If the account does not match the offer's oracle
+/// * `Err(OracleErrorCode::FeedMismatch)` - If the price update is not for the offer's feed
+/// * `Err(_)` - If the price fails the staleness or confidence checks
+pub fn load_token_in_usd_price(
+    offer: &Offer,
+    oracle: Option<&UncheckedAccount>,
+) -> Result<Option<u64>> {
+    if !offer.has_oracle() {
+        return Ok(None);
+    }
+
+    let oracle = oracle.ok_or(OracleErrorCode::OracleAccountMissing)?;
+    require_keys_eq!(
+        oracle.key(),
+        offer.oracle,
+        OracleErrorCode::InvalidOracleAccount
+    );
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    read_oracle_usd_price(
+        oracle,
+        &offer.oracle_feed_id,
+        offer.oracle_max_staleness,
+        offer.oracle_max_confidence_bps,
+        current_time,
+    )
+    .map(Some)
+}
+
 /// Core processing logic for offer execution calculations
 ///
 /// Performs comprehensive validation and calculation for offer processing including
 /// active vector identification, price calculation with APR-based growth, fee
 /// calculation, and token amount conversions with decimal adjustments.
 ///
+/// When a token_in USD price is provided, the USD-denominated NAV is converted into
+/// token_in terms first: `price_in_token_in = ceil(nav * 1e9 / token_in_usd_price)`.
+///
 /// # Arguments
 /// * `offer` - The loaded offer containing pricing vectors and configuration
 /// * `token_in_amount` - Amount of token_in being provided by the user
 /// * `token_in_mint` - The token_in mint for decimal and validation information
 /// * `token_out_mint` - The token_out mint for decimal and validation information
+/// * `token_in_usd_price` - Optional oracle USD price of token_in with scale=9
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
 ///
 /// # Returns
 /// * `Ok(OfferProcessResult)` - Containing current price, token amounts, and fees
@@ -111,30 +229,56 @@ pub fn process_offer_core(
     token_in_amount: u64,
     token_in_mint: &InterfaceAccount<Mint>,
     token_out_mint: &InterfaceAccount<Mint>,
+    token_in_usd_price: Option<u64>,
+    seconds_per_year: u64,
 ) -> Result<OfferProcessResult> {
-    let current_time = Clock::get()?.unix_timestamp as u64;
-
-    require!(
-        offer.token_in_mint == token_in_mint.key(),
-        OfferCoreError::InvalidTokenInMint
-    );
-    require!(
-        offer.token_out_mint == token_out_mint.key(),
-        OfferCoreError::InvalidTokenOutMint
-    );
-
-    // Find the currently active pricing vector
-    let active_vector = find_active_vector_at(offer, current_time)?;
+    process_offer_core_with_fee(
+        offer,
+        token_in_amount,
+        offer.fee_basis_points,
+        token_in_mint,
+        token_out_mint,
+        token_in_usd_price,
+        seconds_per_year,
+    )
+}
 
-    // Calculate current price with 9 decimals
-    let current_price = calculate_current_step_price(
-        active_vector.apr,
-        active_vector.base_price,
-        active_vector.base_time,
-        active_vector.price_fix_duration,
+/// Core processing logic for offer execution charging the given fee instead of
+/// the offer fee
+///
+/// Used by routes that negotiate their own spread, such as permissionless
+/// authorities with a fee override.
+///
+/// # Arguments
+/// * `offer` - The loaded offer containing pricing vectors and configuration
+/// * `token_in_amount` - Amount of token_in being provided by the user
+/// * `fee_basis_points` - Fee in basis points charged on token_in_amount
+/// * `token_in_mint` - The token_in mint for decimal and validation information
+/// * `token_out_mint` - The token_out mint for decimal and validation information
+/// * `token_in_usd_price` - Optional oracle USD price of token_in with scale=9
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(OfferProcessResult)` - Containing current price, token amounts, and fees
+/// * `Err(_)` - If validation fails or no active vector exists
+pub fn process_offer_core_with_fee(
+    offer: &Offer,
+    token_in_amount: u64,
+    fee_basis_points: u16,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    token_in_usd_price: Option<u64>,
+    seconds_per_year: u64,
+) -> Result<OfferProcessResult> {
+    let current_price = calculate_offer_price(
+        offer,
+        token_in_mint,
+        token_out_mint,
+        token_in_usd_price,
+        seconds_per_year,
     )?;
 
-    let fee_amounts = calculate_fees(token_in_amount, offer.fee_basis_points)?;
+    let fee_amounts = calculate_fees(token_in_amount, fee_basis_points)?;
 
     // Calculate how many token_out to give for the provided token_in_amount
     let token_out_amount = calculate_token_out_amount(
@@ -152,6 +296,116 @@ pub fn process_offer_core(
     })
 }
 
+/// Core processing logic for exact-out offer execution calculations
+///
+/// Inverts `process_offer_core`: the net token_in needed for `token_out_amount` is
+/// rounded up, then grossed up by the offer fee so that charging the fee on the
+/// gross amount with `calculate_fees` leaves at least the needed net amount. Any
+/// rounding surplus stays with the protocol, never with the taker.
+///
+/// # Arguments
+/// * `offer` - The loaded offer containing pricing vectors and configuration
+/// * `token_out_amount` - Exact amount of token_out the user receives
+/// * `token_in_mint` - The token_in mint for decimal and validation information
+/// * `token_out_mint` - The token_out mint for decimal and validation information
+/// * `token_in_usd_price` - Optional oracle USD price of token_in with scale=9
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(OfferProcessResult)` - Containing current price, token amounts, and fees
+/// * `Err(_)` - If validation fails or no active vector exists
+pub fn process_offer_core_exact_out(
+    offer: &Offer,
+    token_out_amount: u64,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    token_in_usd_price: Option<u64>,
+    seconds_per_year: u64,
+) -> Result<OfferProcessResult> {
+    let current_price = calculate_offer_price(
+        offer,
+        token_in_mint,
+        token_out_mint,
+        token_in_usd_price,
+        seconds_per_year,
+    )?;
+
+    let token_in_net_needed = calculate_token_in_amount(
+        token_out_amount,
+        current_price,
+        token_in_mint.decimals,
+        token_out_mint.decimals,
+    )?;
+
+    // gross = ceil(net * 10000 / (10000 - fee)) leaves at least net after the fee
+    let token_in_amount = mul_div_u64(
+        token_in_net_needed,
+        MAX_BASIS_POINTS as u128,
+        (MAX_BASIS_POINTS - offer.fee_basis_points) as u128,
+        REQUIRED_INPUT_ROUNDING,
+    )
+    .ok_or(OfferCoreError::OverflowError)?;
+
+    let fee_amounts = calculate_fees(token_in_amount, offer.fee_basis_points)?;
+
+    Ok(OfferProcessResult {
+        current_price,
+        token_in_net_amount: fee_amounts.token_in_net_amount,
+        token_out_amount,
+        token_in_fee_amount: fee_amounts.token_in_fee_amount,
+    })
+}
+
+/// Validates the offer mints and returns the current price in token_in terms
+///
+/// # Arguments
+/// * `offer` - The loaded offer containing pricing vectors
+/// * `token_in_mint` - The token_in mint to validate against the offer
+/// * `token_out_mint` - The token_out mint to validate against the offer
+/// * `token_in_usd_price` - Optional oracle USD price of token_in with scale=9
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(price)` - Price of one token_out in token_in with scale=9
+/// * `Err(_)` - If a mint does not match or no active vector exists
+pub fn calculate_offer_price(
+    offer: &Offer,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    token_in_usd_price: Option<u64>,
+    seconds_per_year: u64,
+) -> Result<u64> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require!(
+        offer.token_in_mint == token_in_mint.key(),
+        OfferCoreError::InvalidTokenInMint
+    );
+    require!(
+        offer.token_out_mint == token_out_mint.key(),
+        OfferCoreError::InvalidTokenOutMint
+    );
+
+    // Find the currently active pricing vector
+    let active_vector = find_active_vector_at(offer, current_time)?;
+
+    // Calculate current price with 9 decimals
+    let current_price = calculate_current_vector_price(&active_vector, seconds_per_year)?;
+
+    // Express the price in token_in when token_in is valued through an oracle
+    match token_in_usd_price {
+        Some(usd_price) => {
+            let price = (current_price as u128)
+                .checked_mul(1_000_000_000)
+                .and_then(|n| n.checked_add(usd_price as u128 - 1))
+                .ok_or(OfferCoreError::OverflowError)?
+                / usd_price as u128;
+            u64::try_from(price).map_err(|_| error!(OfferCoreError::OverflowError))
+        }
+        None => Ok(current_price),
+    }
+}
+
 /// Finds the currently active pricing vector at a specific time
 ///
 /// Searches through the offer's pricing vectors to find the one that should be
@@ -171,7 +425,19 @@ pub fn find_active_vector_at(offer: &Offer, time: u64) -> Result<OfferVector> {
         .iter()
         .filter(|vector| vector.start_time != 0 && vector.start_time <= time) // Only consider non-empty vectors
         .max_by_key(|vector| vector.start_time) // Find latest start_time in the past
-        .ok_or(OfferCoreError::NoActiveVector)?;
+        .ok_or_else(|| {
+            error_with_context!(
+                OfferCoreError::NoActiveVector,
+                time = time,
+                earliest_start_time = offer
+                    .vectors
+                    .iter()
+                    .filter(|vector| vector.start_time != 0)
+                    .map(|vector| vector.start_time)
+                    .min()
+                    .unwrap_or(0)
+            )
+        })?;
 
     Ok(*active_vector)
 }
@@ -181,45 +447,51 @@ pub fn find_active_vector_at(offer: &Offer, time: u64) -> Result<OfferVector> {
 /// Implements linear price growth formula for continuous pricing without discrete
 /// intervals. Uses fixed-point arithmetic to maintain precision in calculations.
 ///
-/// Formula: P(t) = P0 * (1 + apr * elapsed_time / SECONDS_IN_YEAR)
-/// where SECONDS_IN_YEAR = 31,536,000 and apr is scaled by 1,000,000.
+/// Formula: P(t) = P0 * (1 + apr * elapsed_time / seconds_per_year)
+/// where seconds_per_year comes from the program config (31,536,000 by default)
+/// and apr is scaled by 1,000,000.
+/// A negative APR yields a declining price, floored at zero.
 ///
 /// # Arguments
-/// * `apr` - Annual Percentage Rate scaled by 1_000_000 (1_000_000 = 1% APR)
+/// * `apr` - Signed Annual Percentage Rate scaled by 1_000_000 (1_000_000 = 1% APR)
 /// * `base_price` - Starting price with scale=9
 /// * `elapsed_time` - Time elapsed since base_time in seconds
+/// * `seconds_per_year` - Seconds per year over which the APR accrues
 ///
 /// # Returns
 /// * `Ok(u64)` - Calculated price with same scale as base_price
 /// * `Err(OfferCoreError::OverflowError)` - If arithmetic overflow occurs
-pub fn calculate_vector_price(apr: u64, base_price: u64, elapsed_time: u64) -> Result<u64> {
-    // Compute: price = P0 * (1 + y * elapsed_time / SECONDS_IN_YEAR)
+pub fn calculate_vector_price(
+    apr: i64,
+    base_price: u64,
+    elapsed_time: u64,
+    seconds_per_year: u64,
+) -> Result<u64> {
+    // Compute: price = P0 * (1 + y * elapsed_time / seconds_per_year)
     // With fixed-point:
-    //   factor_num = SCALE*SECONDS_IN_YEAR + APR*elapsed_time
-    //   factor_den = SCALE*SECONDS_IN_YEAR
+    //   factor_num = SCALE*seconds_per_year + APR*elapsed_time
+    //   factor_den = SCALE*seconds_per_year
     //   price = base_price * (factor_num / factor_den)
     let factor_den = APR_SCALE
-        .checked_mul(SECONDS_IN_YEAR)
+        .checked_mul(seconds_per_year as u128)
         .expect("SCALE*S overflow (should not happen)");
-    let y_part = (apr as u128)
-        .checked_mul(elapsed_time as u128)
+    let y_part = (apr as i128)
+        .checked_mul(elapsed_time as i128)
         .ok_or(OfferCoreError::OverflowError)?;
-    let factor_num = factor_den
+    let factor_num = (factor_den as i128)
         .checked_add(y_part)
         .ok_or(OfferCoreError::OverflowError)?;
 
+    // A declining price cannot go below zero
+    if factor_num <= 0 {
+        return Ok(0);
+    }
+
     // price growth applied to base_price
-    let price_u128 = (base_price as u128)
-        .checked_mul(factor_num)
-        .ok_or(OfferCoreError::OverflowError)?
-        .checked_div(factor_den)
+    let price = mul_div_floor_u64(base_price, factor_num as u128, factor_den)
         .ok_or(OfferCoreError::OverflowError)?;
 
-    if price_u128 > u64::MAX as u128 {
-        return Err(error!(OfferCoreError::OverflowError));
-    }
-
-    Ok(price_u128 as u64)
+    Ok(price)
 }
 
 /// Calculates discrete interval pricing with fixed price windows
@@ -231,26 +503,94 @@ pub fn calculate_vector_price(apr: u64, base_price: u64, elapsed_time: u64) -> R
 /// Formula:
 ///   interval = floor((current_time - base_time) / price_fix_duration)
 ///   effective_time = (interval + 1) * price_fix_duration
-///   price = calculate_vector_price(apr, base_price, effective_time)
+///   price = calculate_vector_price(apr, base_price, effective_time, seconds_per_year)
 ///
 /// # Arguments
-/// * `apr` - Annual Percentage Rate scaled by 1_000_000
+/// * `apr` - Signed Annual Percentage Rate scaled by 1_000_000
 /// * `base_price` - Starting price with scale=9
 /// * `base_time` - Unix timestamp when pricing vector starts
 /// * `price_fix_duration` - Duration of each discrete price interval in seconds
+/// * `seconds_per_year` - Seconds per year over which the APR accrues
 ///
 /// # Returns
 /// * `Ok(u64)` - Current price at the discrete interval
 /// * `Err(_)` - If calculation fails or time is before base_time
 pub fn calculate_current_step_price(
-    apr: u64,
+    apr: i64,
     base_price: u64,
     base_time: u64,
     price_fix_duration: u64,
+    seconds_per_year: u64,
 ) -> Result<u64> {
     let current_time = Clock::get()?.unix_timestamp as u64;
 
-    calculate_step_price_at(apr, base_price, base_time, price_fix_duration, current_time)
+    calculate_step_price_at(
+        apr,
+        base_price,
+        base_time,
+        price_fix_duration,
+        current_time,
+        seconds_per_year,
+    )
+}
+
+/// Calculates the current price of a vector according to its pricing mode
+///
+/// # Arguments
+/// * `vector` - The active pricing vector
+/// * `seconds_per_year` - Seconds per year over which the APR accrues
+///
+/// # Returns
+/// * `Ok(u64)` - Current price with scale=9
+/// * `Err(_)` - If calculation fails or the current time is before base_time
+pub fn calculate_current_vector_price(vector: &OfferVector, seconds_per_year: u64) -> Result<u64> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    calculate_vector_price_at(vector, current_time, seconds_per_year)
+}
+
+/// Calculates the price of a vector at a specific time according to its pricing mode
+///
+/// Step vectors use the discrete interval model of `calculate_step_price_at`, linear
+/// vectors accrue the APR for every elapsed second since base_time.
+///
+/// # Arguments
+/// * `vector` - The pricing vector
+/// * `time` - Specific time to calculate price for
+/// * `seconds_per_year` - Seconds per year over which the APR accrues
+///
+/// # Returns
+/// * `Ok(u64)` - Price at the specified time with scale=9
+/// * `Err(_)` - If calculation fails or time is before base_time
+pub fn calculate_vector_price_at(
+    vector: &OfferVector,
+    time: u64,
+    seconds_per_year: u64,
+) -> Result<u64> {
+    match vector.pricing_mode() {
+        PricingMode::Step => calculate_step_price_at(
+            vector.apr,
+            vector.base_price,
+            vector.base_time,
+            vector.price_fix_duration,
+            time,
+            seconds_per_year,
+        ),
+        PricingMode::Linear => {
+            require_with_context!(
+                vector.base_time <= time,
+                OfferCoreError::NoActiveVector,
+                base_time = vector.base_time,
+                time = time
+            );
+            calculate_vector_price(
+                vector.apr,
+                vector.base_price,
+                time - vector.base_time,
+                seconds_per_year,
+            )
+        }
+    }
 }
 
 /// Calculates discrete step price at a specific time
@@ -259,23 +599,30 @@ pub fn calculate_current_step_price(
 /// using the discrete interval pricing model.
 ///
 /// # Arguments
-/// * `apr` - Annual Percentage Rate scaled by 1_000_000
+/// * `apr` - Signed Annual Percentage Rate scaled by 1_000_000
 /// * `base_price` - Starting price with scale=9
 /// * `base_time` - Unix timestamp when pricing vector starts
 /// * `price_fix_duration` - Duration of each discrete price interval in seconds
 /// * `time` - Specific time to calculate price for
+/// * `seconds_per_year` - Seconds per year over which the APR accrues
 ///
 /// # Returns
 /// * `Ok(u64)` - Price at the specified time
 /// * `Err(_)` - If calculation fails or time is invalid
 pub fn calculate_step_price_at(
-    apr: u64,
+    apr: i64,
     base_price: u64,
     base_time: u64,
     price_fix_duration: u64,
     time: u64,
+    seconds_per_year: u64,
 ) -> Result<u64> {
-    require!(base_time <= time, OfferCoreError::NoActiveVector);
+    require_with_context!(
+        base_time <= time,
+        OfferCoreError::NoActiveVector,
+        base_time = base_time,
+        time = time
+    );
 
     let elapsed_since_start = time.saturating_sub(base_time);
 
@@ -290,7 +637,633 @@ pub fn calculate_step_price_at(
         .ok_or(OfferCoreError::OverflowError)?;
 
     // Use the vector price calculation with the effective elapsed time
-    calculate_vector_price(apr, base_price, step_end_time)
+    calculate_vector_price(apr, base_price, step_end_time, seconds_per_year)
+}
+
+/// Enforces the offer's minimum take size
+///
+/// With a `Token` denomination the gross token_in amount is compared directly. With
+/// a `Quote` denomination the amount is first valued in USD with scale=9 using the
+/// oracle price of token_in, or at $1.0 when the offer has no oracle, so a fixed USD
+/// minimum stays correct as prices drift. A minimum of 0 disables the check.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `token_in_amount` - Gross amount of token_in paid by the take, including fees
+/// * `token_in_mint` - The token_in mint for decimal information
+/// * `token_in_usd_price` - Optional oracle USD price of token_in with scale=9
+///
+/// # Returns
+/// * `Ok(())` - If there is no minimum or the take meets it
+/// * `Err(OfferCoreError::TakeBelowMinimum)` - If the take is below the minimum
+pub fn enforce_minimum_take(
+    offer: &Offer,
+    token_in_amount: u64,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_in_usd_price: Option<u64>,
+) -> Result<()> {
+    if offer.min_take_amount == 0 {
+        return Ok(());
+    }
+
+    let take_size = match offer.min_take_denomination() {
+        MinimumDenomination::Token => token_in_amount as u128,
+        MinimumDenomination::Quote => scale_amount(
+            token_in_amount,
+            // Without an oracle token_in is valued at $1.0
+            token_in_usd_price.unwrap_or(1_000_000_000) as u128,
+            1,
+            0,
+            token_in_mint.decimals as u32,
+        )
+        .ok_or(OfferCoreError::OverflowError)?,
+    };
+
+    require_with_context!(
+        take_size >= offer.min_take_amount as u128,
+        OfferCoreError::TakeBelowMinimum,
+        take_size = take_size,
+        min_take_amount = offer.min_take_amount,
+        denomination = offer.min_take_denomination() as u8
+    );
+
+    Ok(())
+}
+
+/// Enforces the window in which the offer can be taken
+///
+/// Takes are accepted from `valid_from` on and rejected from `valid_until` on,
+/// a bound of 0 leaving that end of the window open. Offers closed by
+/// `crank_expired_offer` reject takes even if their window is moved.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+///
+/// # Returns
+/// * `Ok(())` - If the current time is within the offer's validity window
+/// * `Err(OfferCoreError::OfferClosed)` - If the offer was closed after its expiry
+/// * `Err(OfferCoreError::OfferNotYetActive)` - If the window has not started yet
+/// * `Err(OfferCoreError::OfferExpired)` - If the window has ended
+pub fn enforce_offer_window(offer: &Offer) -> Result<()> {
+    require!(!offer.is_closed(), OfferCoreError::OfferClosed);
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    require_with_context!(
+        current_time >= offer.valid_from,
+        OfferCoreError::OfferNotYetActive,
+        current_time = current_time,
+        valid_from = offer.valid_from
+    );
+    require_with_context!(
+        offer.valid_until == 0 || current_time < offer.valid_until,
+        OfferCoreError::OfferExpired,
+        current_time = current_time,
+        valid_until = offer.valid_until
+    );
+
+    Ok(())
+}
+
+/// Asserts the mints of a take against the decimals recorded on the offer
+///
+/// Offer prices are scaled by the mint decimals seen when the offer was made, so
+/// a take must never price against mints with other decimals. Offers made before
+/// decimals were recorded are not checked.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `token_in_mint` - The token_in mint passed to the take
+/// * `token_out_mint` - The token_out mint passed to the take
+///
+/// # Returns
+/// * `Ok(())` - If both mints have the recorded decimals
+/// * `Err(OfferCoreError::TokenInDecimalsMismatch)` - If token_in decimals differ
+/// * `Err(OfferCoreError::TokenOutDecimalsMismatch)` - If token_out decimals differ
+pub fn require_offer_decimals(
+    offer: &Offer,
+    token_in_mint: &Mint,
+    token_out_mint: &Mint,
+) -> Result<()> {
+    let Some((token_in_decimals, token_out_decimals)) = offer.recorded_decimals() else {
+        return Ok(());
+    };
+    require_with_context!(
+        token_in_mint.decimals == token_in_decimals,
+        OfferCoreError::TokenInDecimalsMismatch,
+        expected = token_in_decimals,
+        actual = token_in_mint.decimals
+    );
+    require_with_context!(
+        token_out_mint.decimals == token_out_decimals,
+        OfferCoreError::TokenOutDecimalsMismatch,
+        expected = token_out_decimals,
+        actual = token_out_mint.decimals
+    );
+
+    Ok(())
+}
+
+/// Rejects takes of strict offers that would create the user's accounts
+///
+/// Offers with strict accounts keep the take's compute and rent predictable: the
+/// user's token_out account and position must have been created ahead, typically
+/// with `prepare_accounts`. The error names the first missing account.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `user_token_out_account` - The user's token_out ATA, possibly uninitialized
+/// * `user_position` - The user's token_out position, just created if it was missing
+///
+/// # Returns
+/// * `Ok(())` - If the offer is not strict or both accounts existed before the take
+/// * `Err(OfferCoreError::TakeAccountNotPrepared)` - If a strict offer's take misses an account
+pub fn require_prepared_take_accounts(
+    offer: &Offer,
+    user_token_out_account: &AccountInfo,
+    user_position: &UserPosition,
+) -> Result<()> {
+    if !offer.strict_accounts() {
+        return Ok(());
+    }
+    if user_token_out_account.owner == &system_program::ID {
+        return Err(error!(OfferCoreError::TakeAccountNotPrepared)
+            .with_account_name("user_token_out_account"));
+    }
+    // Positions are only bound to their user once initialized
+    if user_position.user == Pubkey::default() {
+        return Err(
+            error!(OfferCoreError::TakeAccountNotPrepared).with_account_name("user_position")
+        );
+    }
+
+    Ok(())
+}
+
+/// Creates the user's token_out ATA unless it exists, with rent paid by the user
+///
+/// # Arguments
+/// * `user_token_out_account` - The user's token_out ATA, validated by address
+/// * `user` - The user owning the ATA and paying its rent
+/// * `token_out_mint` - The token_out mint
+/// * `token_out_program` - The token program owning the token_out mint
+/// * `associated_token_program` - The Associated Token Program
+/// * `system_program` - The System Program
+///
+/// # Returns
+/// * `Ok(())` - If the ATA exists or is successfully created
+pub fn create_token_out_account_if_needed<'info>(
+    user_token_out_account: &AccountInfo<'info>,
+    user: &AccountInfo<'info>,
+    token_out_mint: &InterfaceAccount<'info, Mint>,
+    token_out_program: &Interface<'info, TokenInterface>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if user_token_out_account.owner != &system_program::ID {
+        return Ok(());
+    }
+
+    associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: user.clone(),
+            associated_token: user_token_out_account.clone(),
+            authority: user.clone(),
+            mint: token_out_mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_out_program.to_account_info(),
+        },
+    ))
+}
+
+/// Enforces the offer's dust threshold on the token_out paid out by a take
+///
+/// Payouts round down, so a take smaller than one base unit of token_out at the
+/// current price would pay out nothing while the fee rounds up. Such takes are always
+/// rejected, as are takes paying out less than the offer's `min_token_out_amount`.
+/// On rejection the smallest gross token_in amount that clears the threshold at the
+/// current price is logged.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `result` - The take calculations
+/// * `token_in_mint` - The token_in mint for decimal information
+/// * `token_out_mint` - The token_out mint for decimal information
+///
+/// # Returns
+/// * `Ok(())` - If the take pays out at least the threshold
+/// * `Err(OfferCoreError::TakeBelowDustThreshold)` - If the payout is below it
+pub fn enforce_dust_threshold(
+    offer: &Offer,
+    result: &OfferProcessResult,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+) -> Result<()> {
+    let min_token_out_amount = offer.min_token_out_amount.max(1);
+    if result.token_out_amount >= min_token_out_amount {
+        return Ok(());
+    }
+
+    let min_token_in_net_amount = calculate_token_in_amount(
+        min_token_out_amount,
+        result.current_price,
+        token_in_mint.decimals,
+        token_out_mint.decimals,
+    )?;
+    let min_token_in_amount = mul_div_u64(
+        min_token_in_net_amount,
+        MAX_BASIS_POINTS as u128,
+        (MAX_BASIS_POINTS - offer.fee_basis_points) as u128,
+        REQUIRED_INPUT_ROUNDING,
+    )
+    .ok_or(OfferCoreError::OverflowError)?;
+
+    msg!(
+        "Take pays out {} token_out, below the dust threshold of {}; minimum token_in: {}",
+        result.token_out_amount,
+        min_token_out_amount,
+        min_token_in_amount
+    );
+
+    err!(OfferCoreError::TakeBelowDustThreshold)
+}
+
+/// Records a take against the offer's per-step volume cap
+///
+/// Tracks the token_out amount issued within the current discrete pricing step of
+/// the active vector. The counter resets whenever a new step begins, so takes placed
+/// just before a step boundary cannot capture more than `max_step_volume` of the
+/// upcoming price move. When the cap is 0 the take is only recorded.
+///
+/// # Arguments
+/// * `offer` - Mutable reference to the offer being taken
+/// * `token_out_amount` - Amount of token_out issued by the take
+///
+/// # Returns
+/// * `Ok(())` - If the take fits within the remaining step volume
+/// * `Err(OfferCoreError::StepVolumeExceeded)` - If the take would exceed the cap
+pub fn record_step_volume(offer: &mut Offer, token_out_amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let active_vector = find_active_vector_at(offer, current_time)?;
+
+    let elapsed_since_base = current_time.saturating_sub(active_vector.base_time);
+    let step_start_time = active_vector
+        .base_time
+        .checked_add(
+            elapsed_since_base / active_vector.price_fix_duration
+                * active_vector.price_fix_duration,
+        )
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    // Reset the counter at each step boundary
+    if offer.step_volume_start_time != step_start_time {
+        offer.step_volume_start_time = step_start_time;
+        offer.step_volume = 0;
+    }
+
+    let step_volume = offer
+        .step_volume
+        .checked_add(token_out_amount)
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    require_with_context!(
+        offer.max_step_volume == 0 || step_volume <= offer.max_step_volume,
+        OfferCoreError::StepVolumeExceeded,
+        step_volume = step_volume,
+        max_step_volume = offer.max_step_volume,
+        step_start_time = step_start_time
+    );
+
+    offer.step_volume = step_volume;
+
+    Ok(())
+}
+
+/// Adds a take to the offer's cumulative fee and volume counters
+///
+/// # Arguments
+/// * `offer` - Mutable reference to the offer being taken
+/// * `token_in_amount` - Amount of token_in paid by the take, including fees
+/// * `token_in_fee_amount` - Fee portion of the token_in amount
+///
+/// # Returns
+/// * `Ok(())` - If the counters are updated
+/// * `Err(OfferCoreError::OverflowError)` - If a counter would overflow
+pub fn record_take_totals(
+    offer: &mut Offer,
+    token_in_amount: u64,
+    token_in_fee_amount: u64,
+) -> Result<()> {
+    let total_fees = offer
+        .total_fees_collected_token_in()
+        .checked_add(token_in_fee_amount as u128)
+        .ok_or(OfferCoreError::OverflowError)?;
+    let total_volume = offer
+        .total_volume_token_in()
+        .checked_add(token_in_amount as u128)
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    offer.set_take_totals(total_fees, total_volume);
+
+    Ok(())
+}
+
+/// Credits the token_in a take left in the offer vault to the offer's uncollected proceeds
+///
+/// Measured as the growth of the vault's token_in balance, so token_in burned on receipt
+/// and Token-2022 transfer fees are never credited, and takes paying the boss credit
+/// nothing.
+///
+/// # Arguments
+/// * `offer` - Mutable reference to the offer being taken
+/// * `vault_token_in_before` - token_in balance of the offer vault before the take
+/// * `vault_token_in_after` - token_in balance of the offer vault after the take
+///
+/// # Returns
+/// * `Ok(())` - If the proceeds are credited
+/// * `Err(OfferCoreError::OverflowError)` - If the counter would overflow
+pub fn record_uncollected_proceeds(
+    offer: &mut Offer,
+    vault_token_in_before: u64,
+    vault_token_in_after: u64,
+) -> Result<()> {
+    offer.uncollected_proceeds = offer
+        .uncollected_proceeds
+        .checked_add(vault_token_in_after.saturating_sub(vault_token_in_before))
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    Ok(())
+}
+
+/// Records a take in the taker's position of the token_out mint
+///
+/// Binds the position to the user and mint on its first take.
+///
+/// # Arguments
+/// * `user_position` - The taker's position, possibly just created
+/// * `user` - The taker
+/// * `token_out_mint` - The mint bought by the take
+/// * `bump` - PDA bump of the position
+/// * `result` - The processed take, providing the amount bought and its price
+///
+/// # Returns
+/// * `Ok(())` - If the position is updated
+/// * `Err(UserPositionErrorCode::MathOverflow)` - If a counter would overflow
+pub fn record_user_position_purchase(
+    user_position: &mut UserPosition,
+    user: Pubkey,
+    token_out_mint: Pubkey,
+    bump: u8,
+    result: &OfferProcessResult,
+) -> Result<()> {
+    user_position.initialize_if_needed(user, token_out_mint, bump);
+    user_position.record_purchase(
+        result.token_out_amount,
+        result.current_price,
+        Clock::get()?.unix_timestamp as u64,
+    )
+}
+
+/// Writes the receipt of a take of a clawback-enabled offer
+///
+/// `clawback_take` can only reverse takes it finds a receipt for, so takes of
+/// flagged offers must pass one. The receipt's index comes from the user's position,
+/// which is advanced for the next receipt.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `offer_key` - The PDA address of the offer
+/// * `take_receipt` - The receipt created for the take, if passed
+/// * `take_receipt_bump` - PDA bump of the receipt, if passed
+/// * `user_position` - The taker's position of the token_out mint
+/// * `user` - The taker
+/// * `token_in_amount` - Amount of token_in paid, including the fee
+/// * `token_out_amount` - Amount of token_out received
+///
+/// # Returns
+/// * `Ok(())` - If the receipt is written or the offer needs none
+/// * `Err(OfferCoreError::TakeReceiptRequired)` - If the offer is flagged and no receipt was passed
+/// * `Err(UserPositionErrorCode::MathOverflow)` - If the receipt counter would overflow
+#[allow(clippy::too_many_arguments)]
+pub fn record_take_receipt(
+    offer: &Offer,
+    offer_key: Pubkey,
+    take_receipt: Option<&mut TakeReceipt>,
+    take_receipt_bump: Option<u8>,
+    user_position: &mut UserPosition,
+    user: Pubkey,
+    token_in_amount: u64,
+    token_out_amount: u64,
+) -> Result<()> {
+    let Some(take_receipt) = take_receipt else {
+        require!(
+            !offer.clawback_enabled(),
+            OfferCoreError::TakeReceiptRequired
+        );
+        return Ok(());
+    };
+
+    take_receipt.offer = offer_key;
+    take_receipt.user = user;
+    take_receipt.index = user_position.take_receipt_count;
+    take_receipt.take_timestamp = Clock::get()?.unix_timestamp as u64;
+    take_receipt.token_in_amount = token_in_amount;
+    take_receipt.token_out_amount = token_out_amount;
+    take_receipt.bump = take_receipt_bump.unwrap_or_default();
+
+    user_position.take_receipt_count = user_position
+        .take_receipt_count
+        .checked_add(1)
+        .ok_or(UserPositionErrorCode::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Returns the account receiving the token_in proceeds of a take
+///
+/// Offers created before proceeds were routed to the vault, or switched back with
+/// `set_offer_proceeds_to_vault`, still pay the boss's token_in account.
+///
+/// # Arguments
+/// * `offer` - The offer being taken
+/// * `vault_token_in_account` - The offer vault's token_in account
+/// * `boss_token_in_account` - The boss's token_in account, if provided
+///
+/// # Returns
+/// * `Ok(account)` - The vault or boss token_in account, per the offer's flag
+/// * `Err(OfferCoreError::BossTokenInAccountRequired)` - If the offer pays the boss and the account is missing
+pub fn proceeds_destination<'a, 'info>(
+    offer: &Offer,
+    vault_token_in_account: &'a InterfaceAccount<'info, TokenAccount>,
+    boss_token_in_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+) -> Result<&'a InterfaceAccount<'info, TokenAccount>> {
+    if offer.proceeds_to_vault() {
+        return Ok(vault_token_in_account);
+    }
+    boss_token_in_account.ok_or_else(|| OfferCoreError::BossTokenInAccountRequired.into())
+}
+
+/// Returns whether the vault's token_out inventory has fallen to the offer's low
+/// inventory threshold
+///
+/// Takes fulfilled by minting do not draw on the vault, so they never flag low
+/// inventory.
+///
+/// # Arguments
+/// * `offer` - The offer whose threshold applies
+/// * `vault_token_out_remaining` - token_out balance of the offer vault
+/// * `fulfillment_mode` - How takes of the offer deliver token_out
+///
+/// # Returns
+/// `true` if the threshold is set and the balance is at or below it
+pub fn is_low_inventory(
+    offer: &Offer,
+    vault_token_out_remaining: u64,
+    fulfillment_mode: FulfillmentMode,
+) -> bool {
+    fulfillment_mode == FulfillmentMode::Transfer
+        && offer.low_inventory_threshold > 0
+        && vault_token_out_remaining <= offer.low_inventory_threshold
+}
+
+/// Records the offer's current NAV in its history if an observation is due
+///
+/// Observations closer than `NAV_OBSERVATION_MIN_INTERVAL` to the latest one are
+/// skipped, so frequent takes cannot crowd older observations out of the buffer.
+///
+/// # Arguments
+/// * `nav_history` - Mutable reference to the offer's NAV history
+/// * `offer` - The offer whose NAV is observed
+/// * `current_time` - Unix timestamp of the observation
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(true)` - If an observation was recorded
+/// * `Ok(false)` - If the latest observation is too recent
+/// * `Err(OfferCoreError::NoActiveVector)` - If the offer has no active pricing vector
+pub fn observe_nav(
+    nav_history: &mut NavHistory,
+    offer: &Offer,
+    current_time: u64,
+    seconds_per_year: u64,
+) -> Result<bool> {
+    if !nav_history.can_record(current_time) {
+        return Ok(false);
+    }
+
+    let active_vector = find_active_vector_at(offer, current_time)?;
+    let nav = calculate_vector_price_at(&active_vector, current_time, seconds_per_year)?;
+    nav_history.record(current_time, nav);
+
+    Ok(true)
+}
+
+/// Throttles takes while outstanding redemptions strain the redemption vault
+///
+/// The redemption offer paying this offer's token_in in exchange for its token_out is
+/// under stress when the value of its requested redemptions, priced at the take's
+/// current price, exceeds `redemption_stress_threshold_bps` of the token_in held by
+/// the redemption vault. Stressed takes are capped at `stressed_take_max_token_in`,
+/// or rejected altogether when that cap is zero, so new liquidity does not compete
+/// with holders waiting to redeem. Nothing is enforced while the threshold is zero
+/// or when no redemption offer exists for the pair.
+///
+/// # Arguments
+/// * `state` - Program state holding the stress policy
+/// * `result` - The processed take, providing the amount paid and the current price
+/// * `token_in_mint` - The offer's token_in mint, paid out by the redemption offer
+/// * `token_out_mint` - The offer's token_out mint, redeemed by the redemption offer
+/// * `token_in_program` - Token program of the token_in mint
+/// * `redemption_offer` - The redemption offer PDA for the pair, seeds validated by the caller
+/// * `redemption_vault_token_in_account` - The redemption vault's token_in account
+///
+/// # Returns
+/// * `Ok(())` - If the policy is disabled, the pair is not stressed or the take is within the cap
+/// * `Err(OfferCoreError::RedemptionStressAccountsRequired)` - If the policy is enabled and an account is missing
+/// * `Err(OfferCoreError::InvalidRedemptionVaultAccount)` - If the vault account is not the expected ATA
+/// * `Err(OfferCoreError::TakesPausedDuringRedemptionStress)` - If stressed and the cap is zero
+/// * `Err(OfferCoreError::TakeExceedsRedemptionStressLimit)` - If stressed and the take exceeds the cap
+pub fn enforce_redemption_stress_policy(
+    state: &State,
+    result: &OfferProcessResult,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    token_in_program: &Interface<TokenInterface>,
+    redemption_offer: Option<&UncheckedAccount>,
+    redemption_vault_token_in_account: Option<&UncheckedAccount>,
+) -> Result<()> {
+    if state.redemption_stress_threshold_bps == 0 {
+        return Ok(());
+    }
+
+    let (Some(redemption_offer), Some(vault_account)) =
+        (redemption_offer, redemption_vault_token_in_account)
+    else {
+        return Err(error!(OfferCoreError::RedemptionStressAccountsRequired));
+    };
+
+    // The PDA is only ever created by this program, so an empty account means the
+    // pair has no redemption offer and nothing to prioritize
+    if redemption_offer.data_is_empty() {
+        return Ok(());
+    }
+    let requested_redemptions = {
+        let data = redemption_offer.try_borrow_data()?;
+        RedemptionOffer::try_deserialize(&mut &data[..])?.requested_redemptions
+    };
+
+    let (redemption_vault_authority, _) =
+        Pubkey::find_program_address(&[seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], &crate::ID);
+    require_keys_eq!(
+        vault_account.key(),
+        get_associated_token_address_with_program_id(
+            &redemption_vault_authority,
+            &token_in_mint.key(),
+            &token_in_program.key(),
+        ),
+        OfferCoreError::InvalidRedemptionVaultAccount
+    );
+    let coverage = read_optional_ata_amount(vault_account, token_in_program)?;
+
+    // Value the outstanding token_out redemptions in token_in base units
+    let outstanding = u64::try_from(requested_redemptions)
+        .ok()
+        .and_then(|requested| {
+            scale_amount(
+                requested,
+                result.current_price as u128,
+                1,
+                token_in_mint.decimals as u32,
+                (token_out_mint.decimals + PRICE_DECIMALS) as u32,
+            )
+        })
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    let stressed = outstanding
+        .checked_mul(MAX_BASIS_POINTS as u128)
+        .ok_or(OfferCoreError::OverflowError)?
+        > (coverage as u128) * state.redemption_stress_threshold_bps as u128;
+    if !stressed {
+        return Ok(());
+    }
+
+    require!(
+        state.stressed_take_max_token_in > 0,
+        OfferCoreError::TakesPausedDuringRedemptionStress
+    );
+    let token_in_amount = result
+        .token_in_net_amount
+        .checked_add(result.token_in_fee_amount)
+        .ok_or(OfferCoreError::OverflowError)?;
+    require_with_context!(
+        token_in_amount <= state.stressed_take_max_token_in,
+        OfferCoreError::TakeExceedsRedemptionStressLimit,
+        token_in_amount = token_in_amount,
+        stressed_take_max_token_in = state.stressed_take_max_token_in
+    );
+
+    Ok(())
 }
 
 /// Finds the array index of a pricing vector by its start time
@@ -311,3 +1284,48 @@ pub fn find_vector_index_by_start_time(offer: &Offer, start_time: u64) -> Option
         .iter()
         .position(|vector| vector.start_time == start_time)
 }
+
+/// Clears pricing vectors that are no longer needed for pricing calculations
+///
+/// Keeps the vector starting at `active_vector_start_time`, the vector immediately
+/// preceding it (required for NAV adjustment calculations) and all future vectors.
+/// Every older vector is reset to the default empty value and an
+/// `OfferVectorEvictedEvent` is emitted for it.
+///
+/// # Arguments
+/// * `offer` - Mutable reference to the offer containing vectors to prune
+/// * `active_vector_start_time` - Start time of the vector considered active
+///
+/// # Returns
+/// * `u8` - Number of vector slots that were freed
+pub fn prune_expired_vectors(offer: &mut Offer, active_vector_start_time: u64) -> u8 {
+    // Find previously active vector (closest smaller vector_start_timestamp)
+    let prev_vector_start_time = find_active_vector_at(offer, active_vector_start_time - 1)
+        .map(|vector| vector.start_time)
+        .unwrap_or(0); // If no previous vector exists, use 0
+
+    let mut evicted_count: u8 = 0;
+
+    // Clear all vectors except active and previous
+    for vector in offer.vectors.iter_mut() {
+        if vector.start_time != 0 // Don't touch already empty slots
+            // Keep active vector
+            && vector.start_time != active_vector_start_time
+            // Keep previous vector
+            && vector.start_time != prev_vector_start_time
+            // Keep all future vectors
+            && vector.start_time < active_vector_start_time
+        {
+            emit!(OfferVectorEvictedEvent {
+                offer_token_in_mint: offer.token_in_mint,
+                offer_token_out_mint: offer.token_out_mint,
+                offer_version: offer.offer_version,
+                vector_start_time: vector.start_time
+            });
+            *vector = OfferVector::default(); // Clear the vector
+            evicted_count += 1;
+        }
+    }
+
+    evicted_count
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Latest generation of the offer at a token pair's offer PDA
+///
+/// Derived from the token pair like the offer itself, but outlives it: `close_offer`
+/// leaves the record in place, so an offer recreated at the same PDA receives the
+/// next version and indexers can tell offer generations apart.
+#[account]
+#[derive(InitSpace)]
+pub struct OfferVersionRecord {
+    /// Input token mint of the offer PDA
+    pub token_in_mint: Pubkey,
+    /// Output token mint of the offer PDA
+    pub token_out_mint: Pubkey,
+    /// Version of the most recently created offer (0 = none created yet)
+    pub latest_version: u32,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
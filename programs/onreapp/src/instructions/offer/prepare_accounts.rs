@@ -0,0 +1,139 @@
+use crate::constants::seeds;
+use crate::instructions::{Offer, UserPosition};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a user's take accounts are prepared
+///
+/// Provides transparency for tracking account creation ahead of strict takes.
+#[event]
+pub struct TakeAccountsPreparedEvent {
+    /// The PDA address of the offer the accounts were prepared for
+    pub offer_pda: Pubkey,
+    /// The user owning the prepared accounts
+    pub user: Pubkey,
+    /// The user's token_out account
+    pub user_token_out_account: Pubkey,
+    /// The user's position in token_out
+    pub user_position: Pubkey,
+}
+
+/// Account structure for creating the accounts a user's takes of an offer need
+///
+/// Both accounts are created if missing, with rent paid by the user, and left
+/// untouched otherwise.
+#[derive(Accounts)]
+pub struct PrepareAccounts<'info> {
+    /// The offer the accounts are prepared for
+    #[account(
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint of the offer
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The output token mint of the offer
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the output token
+    #[account(address = *token_out_mint.to_account_info().owner)]
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// The user's token_out account receiving the takes' payouts
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_program
+    )]
+    pub user_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user's position in token_out, updated by the takes
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserPosition::INIT_SPACE,
+        seeds = [
+            seeds::USER_POSITION,
+            token_out_mint.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// The user the accounts are prepared for, paying their rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Associated Token Program for token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the user's token_out account and position for takes of an offer
+///
+/// Offers with strict accounts, see `set_offer_strict_accounts`, reject takes that
+/// would create these accounts. Running this in a separate transaction first keeps
+/// the take's compute and rent fixed. Also usable for any other offer; existing
+/// accounts are left as they are.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If both accounts exist afterwards
+///
+/// # Access Control
+/// - Any user can prepare their own accounts
+///
+/// # Effects
+/// - Creates the user's token_out ATA if missing
+/// - Creates and binds the user's token_out position if missing
+///
+/// # Events
+/// * `TakeAccountsPreparedEvent` - Emitted with the prepared accounts
+pub fn prepare_accounts(ctx: Context<PrepareAccounts>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    ctx.accounts.user_position.initialize_if_needed(
+        user,
+        ctx.accounts.token_out_mint.key(),
+        ctx.bumps.user_position,
+    );
+
+    msg!(
+        "Take accounts prepared for user: {}, offer: {}",
+        user,
+        ctx.accounts.offer.key()
+    );
+
+    emit!(TakeAccountsPreparedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        user,
+        user_token_out_account: ctx.accounts.user_token_out_account.key(),
+        user_position: ctx.accounts.user_position.key(),
+    });
+
+    Ok(())
+}
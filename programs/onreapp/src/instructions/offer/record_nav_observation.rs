@@ -0,0 +1,152 @@
+use crate::constants::seeds;
+use crate::instructions::keeper::accrue_keeper_reward;
+use crate::instructions::offer::offer_utils::observe_nav;
+use crate::instructions::{KeeperCrank, KeeperRewardConfig, KeeperRewards, NavHistory, Offer};
+use crate::state::Config;
+use crate::utils::event_cpi::event_emitter;
+use anchor_lang::prelude::*;
+
+/// Event emitted when a NAV observation is recorded by the crank
+///
+/// Provides transparency for tracking NAV history updates.
+#[event]
+pub struct NavObservationRecordedEvent {
+    /// The PDA address of the observed offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// NAV with scale=9 at the observation time
+    pub nav: u64,
+    /// Unix timestamp of the observation
+    pub timestamp: u64,
+}
+
+/// Account structure for recording a NAV observation of an offer
+///
+/// The NAV history account is created on first use, paid for by the keeper.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RecordNavObservation<'info> {
+    /// The offer whose current NAV is observed
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The NAV history of the offer
+    ///
+    /// Derived from the offer address ensuring one history per offer.
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = 8 + NavHistory::INIT_SPACE,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump
+    )]
+    pub nav_history: Box<Account<'info, NavHistory>>,
+
+    /// The keeper recording the observation and paying for account creation
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Optional keeper reward configuration; rewards accrue when passed together
+    /// with `keeper_rewards`
+    #[account(mut, seeds = [seeds::KEEPER_REWARD_CONFIG], bump = keeper_reward_config.bump)]
+    pub keeper_reward_config: Option<Box<Account<'info, KeeperRewardConfig>>>,
+
+    /// Optional reward account of the keeper accruing the crank reward
+    #[account(
+        mut,
+        seeds = [seeds::KEEPER_REWARDS, keeper.key().as_ref()],
+        bump = keeper_rewards.bump
+    )]
+    pub keeper_rewards: Option<Box<Account<'info, KeeperRewards>>>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the current NAV of an offer in its NAV history
+///
+/// Complements the observations recorded lazily by takes and fulfillments so the
+/// history keeps advancing for offers with little activity.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the observation is recorded
+/// * `Err(RecordNavObservationErrorCode::ObservationTooSoon)` - If the latest observation is too recent
+/// * `Err(OfferCoreError::NoActiveVector)` - If the offer has no active pricing vector
+///
+/// # Access Control
+/// - Permissionless: any keeper may record observations since the NAV is derived
+///   from the offer's own pricing vectors
+///
+/// # Effects
+/// - Creates the NAV history account if needed
+/// - Appends an observation, overwriting the oldest once the buffer is full
+/// - Accrues the crank reward to the keeper when the reward accounts are passed
+///
+/// # Events
+/// * `NavObservationRecordedEvent` - Emitted with the observed NAV
+/// * `KeeperRewardAccruedEvent` - Emitted when a keeper reward is accrued
+pub fn record_nav_observation(ctx: Context<RecordNavObservation>) -> Result<()> {
+    let offer = ctx.accounts.offer.load()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let nav_history = &mut ctx.accounts.nav_history;
+    if nav_history.offer == Pubkey::default() {
+        nav_history.offer = ctx.accounts.offer.key();
+        nav_history.bump = ctx.bumps.nav_history;
+    }
+
+    require!(
+        observe_nav(
+            nav_history,
+            &offer,
+            current_time,
+            ctx.accounts.config.seconds_per_year
+        )?,
+        RecordNavObservationErrorCode::ObservationTooSoon
+    );
+
+    let nav = nav_history.latest().map(|o| o.nav).unwrap_or_default();
+
+    let events = event_emitter!(ctx);
+    accrue_keeper_reward(
+        &events,
+        ctx.accounts
+            .keeper_reward_config
+            .as_deref_mut()
+            .map(|c| &mut **c),
+        ctx.accounts.keeper_rewards.as_deref_mut().map(|r| &mut **r),
+        KeeperCrank::RecordNavObservation,
+        1,
+    )?;
+
+    msg!(
+        "NAV observation recorded: offer={}, nav={}, timestamp={}",
+        ctx.accounts.offer.key(),
+        nav,
+        current_time
+    );
+
+    emit!(NavObservationRecordedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        nav,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Error codes for NAV observation operations
+#[error_code]
+pub enum RecordNavObservationErrorCode {
+    /// The latest observation is younger than NAV_OBSERVATION_MIN_INTERVAL
+    #[msg("NAV observation too soon after the previous one")]
+    ObservationTooSoon,
+}
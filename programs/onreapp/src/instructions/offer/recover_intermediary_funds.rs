@@ -0,0 +1,184 @@
+use crate::constants::seeds;
+use crate::state::{PermissionlessAuthority, State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when stranded tokens are recovered from a permissionless intermediary
+///
+/// Provides an audit trail for every balance moved out of the routing accounts.
+#[event]
+pub struct IntermediaryFundsRecoveredEvent {
+    /// The PDA address of the permissionless authority owning the intermediary
+    pub permissionless_authority: Pubkey,
+    /// Index the authority was seeded with
+    pub authority_index: u8,
+    /// The mint of the recovered tokens
+    pub mint: Pubkey,
+    /// Amount of tokens recovered
+    pub amount: u64,
+    /// The token account that received the recovered tokens
+    pub destination: Pubkey,
+    /// Owner of the destination token account (original user or boss)
+    pub recipient: Pubkey,
+    /// The account that executed the recovery (boss or admin)
+    pub signer: Pubkey,
+}
+
+/// Account structure for recovering tokens stranded in a permissionless intermediary
+///
+/// The destination token account must belong to either the boss or the original user
+/// passed as `user`.
+#[derive(Accounts)]
+#[instruction(authority_index: u8)]
+pub struct RecoverIntermediaryFunds<'info> {
+    /// Program-derived authority that controls the intermediary token account
+    ///
+    /// CHECK: PDA derivation from `authority_index` is validated by seeds constraint
+    #[account(
+        seeds = [PermissionlessAuthority::seed(authority_index).as_slice()],
+        bump
+    )]
+    pub permissionless_authority: UncheckedAccount<'info>,
+
+    /// The mint of the stranded tokens
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Intermediary token account holding the stranded tokens
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = permissionless_authority,
+        associated_token::token_program = token_program
+    )]
+    pub intermediary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user whose take left the tokens behind
+    ///
+    /// CHECK: Only compared against the destination owner; recovering to the user
+    /// requires the boss's signature
+    pub user: UncheckedAccount<'info>,
+
+    /// Token account receiving the recovered tokens, owned by the user or the boss
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program state account containing boss and admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The account executing the recovery (boss or admin)
+    pub signer: Signer<'info>,
+
+    /// Token program owning the mint and both token accounts
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Moves tokens stranded in a permissionless intermediary account to their rightful owner
+///
+/// Takes forward every token they route through the intermediaries within the same
+/// transaction, and a take aborted between its legs is rolled back as a whole. Balances
+/// left in an intermediary therefore come from tokens sent to it directly, typically by
+/// a user or integrator addressing the wrong account. This instruction returns such
+/// balances either to the user they belong to or to the boss. Which user a balance belongs to is not
+/// recorded on chain, so only the boss may attest to it by recovering to a user; admins
+/// may only recover to the boss, who can settle with the user off chain.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `authority_index` - Index of the permissionless authority owning the intermediary
+/// * `amount` - Amount of tokens to recover
+///
+/// # Returns
+/// * `Ok(())` - If the tokens are successfully recovered
+/// * `Err(RecoverIntermediaryFundsErrorCode::Unauthorized)` - If the signer is neither boss nor admin
+/// * `Err(RecoverIntermediaryFundsErrorCode::InvalidAmount)` - If the amount is zero
+/// * `Err(RecoverIntermediaryFundsErrorCode::DestinationNotAllowed)` - If the destination
+///   is owned by neither the boss nor the user, or an admin recovers to the user
+///
+/// # Access Control
+/// - Boss or any admin can call this instruction
+/// - Only the boss can recover to the user; admins can only recover to the boss
+///
+/// # Effects
+/// - Transfers `amount` tokens from the intermediary to the destination token account
+///
+/// # Events
+/// * `IntermediaryFundsRecoveredEvent` - Emitted with the recovery details
+pub fn recover_intermediary_funds(
+    ctx: Context<RecoverIntermediaryFunds>,
+    authority_index: u8,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let signer = ctx.accounts.signer.key();
+    let signer_is_boss = state.boss == signer;
+    require!(
+        signer_is_boss || state.admins.contains(&signer),
+        RecoverIntermediaryFundsErrorCode::Unauthorized
+    );
+    require!(amount > 0, RecoverIntermediaryFundsErrorCode::InvalidAmount);
+
+    let recipient = ctx.accounts.destination_token_account.owner;
+    let destination_allowed =
+        recipient == state.boss || (signer_is_boss && recipient == ctx.accounts.user.key());
+    require!(
+        destination_allowed,
+        RecoverIntermediaryFundsErrorCode::DestinationNotAllowed
+    );
+
+    let authority_seed = PermissionlessAuthority::seed(authority_index);
+    let authority_seeds: &[&[u8]] = &[&authority_seed, &[ctx.bumps.permissionless_authority]];
+
+    transfer_tokens(
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.intermediary_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.permissionless_authority.to_account_info(),
+        Some(&[authority_seeds]),
+        amount,
+    )?;
+
+    msg!(
+        "Recovered {} tokens of mint {} from permissionless authority {} to {}",
+        amount,
+        ctx.accounts.mint.key(),
+        ctx.accounts.permissionless_authority.key(),
+        recipient
+    );
+
+    emit!(IntermediaryFundsRecoveredEvent {
+        permissionless_authority: ctx.accounts.permissionless_authority.key(),
+        authority_index,
+        mint: ctx.accounts.mint.key(),
+        amount,
+        destination: ctx.accounts.destination_token_account.key(),
+        recipient,
+        signer,
+    });
+
+    Ok(())
+}
+
+/// Error codes for recovering intermediary funds
+#[error_code]
+pub enum RecoverIntermediaryFundsErrorCode {
+    /// The signer is neither the boss nor an admin
+    #[msg("Unauthorized: only the boss or an admin can recover intermediary funds")]
+    Unauthorized,
+    /// The recovered amount is zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+    /// The destination is not owned by an allowed recipient
+    #[msg("Destination must be owned by the boss, or by the user when the boss recovers")]
+    DestinationNotAllowed,
+}
@@ -0,0 +1,110 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's clawback flag is successfully updated
+///
+/// Provides transparency for tracking which offers are open to clawbacks.
+#[event]
+pub struct OfferClawbackUpdatedEvent {
+    /// The PDA address of the offer whose clawback flag was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Whether takes of the offer may now be clawed back
+    pub clawback_enabled: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for flagging an offer for clawbacks
+///
+/// This struct defines the accounts required to enable or disable clawbacks of an
+/// offer's takes. Only the boss can flag offers.
+#[derive(Accounts)]
+pub struct SetOfferClawback<'info> {
+    /// The offer account whose clawback flag will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to flag offers
+    pub boss: Signer<'info>,
+}
+
+/// Enables or disables clawbacks of an offer's takes
+///
+/// Only takes of flagged offers may be reversed by `clawback_take`. Ops flag an
+/// offer once a misconfiguration is discovered and clear the flag after the
+/// affected takes have been reversed.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `clawback_enabled` - Whether takes of the offer may be clawed back
+///
+/// # Returns
+/// * `Ok(())` - If the flag is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's clawback flag
+///
+/// # Events
+/// * `OfferClawbackUpdatedEvent` - Emitted with the new flag value
+pub fn set_offer_clawback(ctx: Context<SetOfferClawback>, clawback_enabled: bool) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    offer.set_clawback_enabled(clawback_enabled);
+
+    msg!(
+        "Offer clawback updated for offer: {}, enabled: {}",
+        ctx.accounts.offer.key(),
+        clawback_enabled
+    );
+
+    emit!(OfferClawbackUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        clawback_enabled,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
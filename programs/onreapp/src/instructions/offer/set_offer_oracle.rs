@@ -0,0 +1,179 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS};
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's token_in oracle configuration is successfully updated
+///
+/// Provides transparency for tracking changes to token_in valuation.
+#[event]
+pub struct OfferOracleUpdatedEvent {
+    /// The PDA address of the offer whose oracle was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous oracle account (default = no oracle)
+    pub old_oracle: Pubkey,
+    /// New oracle account (default = no oracle)
+    pub new_oracle: Pubkey,
+    /// Pyth feed id the new oracle's price updates must carry
+    pub feed_id: [u8; 32],
+    /// Maximum accepted age of the oracle price in seconds
+    pub max_staleness: u64,
+    /// Maximum accepted oracle confidence interval in basis points of the price
+    pub max_confidence_bps: u16,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for configuring an offer's token_in oracle
+///
+/// This struct defines the accounts required to set or clear the price feed used
+/// to value token_in in USD. Only the boss can configure offer oracles.
+#[derive(Accounts)]
+pub struct SetOfferOracle<'info> {
+    /// The offer account whose oracle configuration will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses
+    /// and contains the oracle configuration fields.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to configure offer oracles
+    pub boss: Signer<'info>,
+}
+
+/// Sets the price feed used to value an offer's token_in in USD
+///
+/// Offers accepting tokens whose USD value floats (e.g. EURC) convert token_in into
+/// USD terms with this feed before applying the ONyc NAV. Takes must then pass the
+/// configured Pyth price update account, which has to carry `feed_id` and a price
+/// fresher than `max_staleness` seconds with a confidence interval of at most
+/// `max_confidence_bps` of the price. Passing the default pubkey removes the oracle,
+/// valuing token_in at $1.0 again. Oracles set before feed ids were stored reject
+/// takes until they are set again with their feed id.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `oracle` - Pyth price update account for token_in/USD (default = no oracle)
+/// * `feed_id` - Pyth feed id of token_in/USD, ignored when the oracle is removed
+/// * `max_staleness` - Maximum accepted age of the price in seconds
+/// * `max_confidence_bps` - Maximum accepted confidence interval in basis points
+///
+/// # Returns
+/// * `Ok(())` - If the oracle configuration is successfully updated
+/// * `Err(SetOfferOracleErrorCode::InvalidMaxStaleness)` - If an oracle is set with zero staleness
+/// * `Err(SetOfferOracleErrorCode::InvalidFeedId)` - If an oracle is set with a zeroed feed id
+/// * `Err(SetOfferOracleErrorCode::InvalidMaxConfidence)` - If the confidence limit exceeds 10000
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's oracle configuration
+/// - Affects the pricing of all future takes of the offer
+///
+/// # Events
+/// * `OfferOracleUpdatedEvent` - Emitted with old and new oracle configuration
+pub fn set_offer_oracle(
+    ctx: Context<SetOfferOracle>,
+    oracle: Pubkey,
+    feed_id: [u8; 32],
+    max_staleness: u64,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    let has_oracle = oracle != Pubkey::default();
+    require!(
+        !has_oracle || max_staleness > 0,
+        SetOfferOracleErrorCode::InvalidMaxStaleness
+    );
+    require!(
+        !has_oracle || feed_id != [0; 32],
+        SetOfferOracleErrorCode::InvalidFeedId
+    );
+    let feed_id = if has_oracle { feed_id } else { [0; 32] };
+    require!(
+        max_confidence_bps <= MAX_BASIS_POINTS,
+        SetOfferOracleErrorCode::InvalidMaxConfidence
+    );
+
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_oracle = offer.oracle;
+    offer.oracle = oracle;
+    offer.oracle_feed_id = feed_id;
+    offer.oracle_max_staleness = max_staleness;
+    offer.oracle_max_confidence_bps = max_confidence_bps;
+
+    msg!(
+        "Offer oracle updated for offer: {}, old: {}, new: {}, max staleness: {}, max confidence bps: {}",
+        ctx.accounts.offer.key(),
+        old_oracle,
+        oracle,
+        max_staleness,
+        max_confidence_bps
+    );
+
+    emit!(OfferOracleUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_oracle,
+        new_oracle: oracle,
+        feed_id,
+        max_staleness,
+        max_confidence_bps,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for offer oracle configuration
+#[error_code]
+pub enum SetOfferOracleErrorCode {
+    /// An oracle requires a non-zero staleness limit
+    #[msg("Invalid max staleness: must be greater than zero")]
+    InvalidMaxStaleness,
+    /// Confidence limit exceeds 100%
+    #[msg("Invalid max confidence: must be <= 10000 basis points")]
+    InvalidMaxConfidence,
+    /// An oracle requires the feed id its price updates are checked against
+    #[msg("Invalid feed id: must be set with an oracle")]
+    InvalidFeedId,
+}
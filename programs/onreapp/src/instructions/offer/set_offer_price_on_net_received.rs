@@ -0,0 +1,117 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's take pricing basis is successfully updated
+///
+/// Provides transparency for tracking how takes of fee-on-transfer token_in are priced.
+#[event]
+pub struct OfferPriceOnNetReceivedUpdatedEvent {
+    /// The PDA address of the offer whose pricing basis was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Whether takes are now priced on the token_in amount received after transfer fees
+    pub price_on_net_received: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for choosing the token_in amount an offer's takes are priced on
+///
+/// This struct defines the accounts required to price takes on the gross token_in
+/// amount paid or on the amount received after transfer fees. Only the boss can
+/// update offers.
+#[derive(Accounts)]
+pub struct SetOfferPriceOnNetReceived<'info> {
+    /// The offer account whose pricing basis will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offers
+    pub boss: Signer<'info>,
+}
+
+/// Prices an offer's takes on the token_in amount received after Token-2022 transfer fees
+///
+/// Takes of token_in mints with a transfer fee are rejected unless this is enabled.
+/// When enabled, `take_offer` computes token_out and the offer fee from the gross
+/// amount less the transfer fee withheld by the mint, so the user is only credited
+/// with what the boss or vault actually receives. The received amount is checked
+/// against the destination's balance change after the transfer. Other take flows
+/// keep rejecting transfer-fee mints.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `price_on_net_received` - Whether takes are priced on the amount received
+///
+/// # Returns
+/// * `Ok(())` - If the flag is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's take pricing basis
+///
+/// # Events
+/// * `OfferPriceOnNetReceivedUpdatedEvent` - Emitted with the new pricing basis
+pub fn set_offer_price_on_net_received(
+    ctx: Context<SetOfferPriceOnNetReceived>,
+    price_on_net_received: bool,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    offer.set_price_on_net_received(price_on_net_received);
+
+    msg!(
+        "Offer pricing basis updated for offer: {}, on net received: {}",
+        ctx.accounts.offer.key(),
+        price_on_net_received
+    );
+
+    emit!(OfferPriceOnNetReceivedUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        price_on_net_received,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
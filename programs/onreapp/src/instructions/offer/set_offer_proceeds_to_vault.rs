@@ -0,0 +1,114 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's proceeds destination is successfully updated
+///
+/// Provides transparency for tracking where take proceeds are sent.
+#[event]
+pub struct OfferProceedsDestinationUpdatedEvent {
+    /// The PDA address of the offer whose proceeds destination was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Whether take proceeds now land in the offer vault instead of the boss's account
+    pub proceeds_to_vault: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for choosing where an offer's take proceeds are sent
+///
+/// This struct defines the accounts required to route an offer's token_in proceeds
+/// to the offer vault or to the boss. Only the boss can update offers.
+#[derive(Accounts)]
+pub struct SetOfferProceedsToVault<'info> {
+    /// The offer account whose proceeds destination will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offers
+    pub boss: Signer<'info>,
+}
+
+/// Routes an offer's take proceeds to the offer vault or to the boss
+///
+/// New offers send proceeds to the offer vault, from which the boss sweeps them with
+/// `collect_proceeds`, so takes do not depend on the current boss's token account.
+/// Legacy offers keep paying the boss directly until switched, and takes of offers
+/// paying the boss must pass the boss's token_in account.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `proceeds_to_vault` - Whether take proceeds land in the offer vault
+///
+/// # Returns
+/// * `Ok(())` - If the flag is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's proceeds destination
+///
+/// # Events
+/// * `OfferProceedsDestinationUpdatedEvent` - Emitted with the new destination
+pub fn set_offer_proceeds_to_vault(
+    ctx: Context<SetOfferProceedsToVault>,
+    proceeds_to_vault: bool,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    offer.set_proceeds_to_vault(proceeds_to_vault);
+
+    msg!(
+        "Offer proceeds destination updated for offer: {}, to vault: {}",
+        ctx.accounts.offer.key(),
+        proceeds_to_vault
+    );
+
+    emit!(OfferProceedsDestinationUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        proceeds_to_vault,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's strict account mode is successfully updated
+///
+/// Provides transparency for tracking which offers' takes create user accounts.
+#[event]
+pub struct OfferStrictAccountsUpdatedEvent {
+    /// The PDA address of the offer whose account mode was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Whether takes now require the user's accounts to exist already
+    pub strict_accounts: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for switching an offer's strict account mode
+///
+/// This struct defines the accounts required to choose whether takes of an offer
+/// create missing user accounts. Only the boss can update offers.
+#[derive(Accounts)]
+pub struct SetOfferStrictAccounts<'info> {
+    /// The offer account whose account mode will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offers
+    pub boss: Signer<'info>,
+}
+
+/// Requires the user's accounts to exist before takes of an offer
+///
+/// By default `take_offer` and `take_offer_exact_out` create the user's token_out
+/// account and position on first use, costing the user rent and the take extra
+/// compute. Strict offers reject such takes instead, naming the missing account,
+/// so integrators can budget takes exactly and create the accounts in a separate
+/// transaction with `prepare_accounts`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `strict_accounts` - Whether takes require the user's accounts to exist already
+///
+/// # Returns
+/// * `Ok(())` - If the mode is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's strict account mode
+///
+/// # Events
+/// * `OfferStrictAccountsUpdatedEvent` - Emitted with the new account mode
+pub fn set_offer_strict_accounts(
+    ctx: Context<SetOfferStrictAccounts>,
+    strict_accounts: bool,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    offer.set_strict_accounts(strict_accounts);
+
+    msg!(
+        "Offer account mode updated for offer: {}, strict: {}",
+        ctx.accounts.offer.key(),
+        strict_accounts
+    );
+
+    emit!(OfferStrictAccountsUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        strict_accounts,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
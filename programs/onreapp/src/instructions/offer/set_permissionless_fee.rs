@@ -0,0 +1,122 @@
+use crate::constants::seeds;
+use crate::state::{Config, PermissionlessAuthority, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a permissionless authority's fee override is updated
+///
+/// Provides transparency for tracking the spreads negotiated per distribution channel.
+#[event]
+pub struct PermissionlessFeeUpdatedEvent {
+    /// The PDA address of the permissionless authority
+    pub permissionless_authority: Pubkey,
+    /// Previous fee override in basis points (None = offer fee)
+    pub old_fee_override_bps: Option<u16>,
+    /// New fee override in basis points (None = offer fee)
+    pub new_fee_override_bps: Option<u16>,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating a permissionless authority's fee override
+///
+/// Authorities created before the fee override existed are grown to the current
+/// size, with the boss paying the additional rent.
+#[derive(Accounts)]
+#[instruction(authority_index: u8)]
+pub struct SetPermissionlessFee<'info> {
+    /// The permissionless authority whose fee override will be updated
+    #[account(
+        mut,
+        seeds = [PermissionlessAuthority::seed(authority_index).as_slice()],
+        bump,
+        realloc = 8 + PermissionlessAuthority::INIT_SPACE,
+        realloc::payer = boss,
+        realloc::zero = false
+    )]
+    pub permissionless_authority: Account<'info, PermissionlessAuthority>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// Program-wide configuration providing the fee cap
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The boss account authorized to update fee overrides and pay for reallocation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for reallocation
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the fee charged on takes routed through a permissionless authority
+///
+/// The override replaces the offer fee for every `take_offer_permissionless` routed
+/// through the authority, so each distribution channel can be given its own spread.
+/// Takes through other authorities and all other take flows keep the offer fee.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `authority_index` - Index of the permissionless authority
+/// * `fee_override_bps` - Fee in basis points replacing the offer fee (None = offer fee)
+///
+/// # Returns
+/// * `Ok(())` - If the fee override is successfully updated
+/// * `Err(SetPermissionlessFeeErrorCode::InvalidFee)` - If the fee exceeds the configured cap
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the authority's fee_override_bps field
+/// - Grows authorities created before the fee override to the current size
+///
+/// # Events
+/// * `PermissionlessFeeUpdatedEvent` - Emitted with old and new fee overrides
+pub fn set_permissionless_fee(
+    ctx: Context<SetPermissionlessFee>,
+    _authority_index: u8,
+    fee_override_bps: Option<u16>,
+) -> Result<()> {
+    if let Some(fee_bps) = fee_override_bps {
+        require!(
+            fee_bps <= ctx.accounts.config.max_fee_bps,
+            SetPermissionlessFeeErrorCode::InvalidFee
+        );
+    }
+
+    let permissionless_authority = &mut ctx.accounts.permissionless_authority;
+    let old_fee_override_bps = permissionless_authority.fee_override_bps;
+    permissionless_authority.fee_override_bps = fee_override_bps;
+
+    msg!(
+        "Permissionless fee updated for authority: {}, old fee: {:?}, new fee: {:?}",
+        permissionless_authority.key(),
+        old_fee_override_bps,
+        fee_override_bps
+    );
+
+    emit!(PermissionlessFeeUpdatedEvent {
+        permissionless_authority: permissionless_authority.key(),
+        old_fee_override_bps,
+        new_fee_override_bps: fee_override_bps,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for permissionless fee updates
+#[error_code]
+pub enum SetPermissionlessFeeErrorCode {
+    /// The fee override exceeds the configured maximum
+    #[msg("Invalid fee: fee_override_bps exceeds the configured maximum")]
+    InvalidFee,
+}
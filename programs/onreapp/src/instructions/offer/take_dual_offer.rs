@@ -0,0 +1,593 @@
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::offer::offer_utils::{
+    enforce_dust_threshold, enforce_minimum_take, enforce_offer_window,
+    enforce_redemption_stress_policy, load_token_in_usd_price, observe_nav, process_offer_core,
+    record_step_volume, record_take_totals, require_offer_decimals, verify_offer_approval,
+};
+use crate::instructions::{DualOffer, FulfillmentMode, ManagedMint, NavHistory, Offer};
+use crate::state::{Config, State, StateSchemaErrorCode, TransferHookMintApproval};
+use crate::utils::{
+    execute_token_operations, has_transfer_fee, mint_tokens, program_controls_mint,
+    resolve_transfer_hook_accounts, transfer_tokens, u64_to_dec9, ApprovalMessage,
+    ExecTokenOpsParams, TokenUtilsErrorCode, MAX_TOKEN_DECIMALS,
+};
+use crate::OfferCoreError;
+use anchor_lang::{prelude::*, solana_program::sysvar, Accounts};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Error codes specific to the take_dual_offer instruction
+#[error_code]
+pub enum TakeDualOfferErrorCode {
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+    /// Arithmetic overflow occurred during calculations
+    #[msg("Math overflow")]
+    MathOverflow,
+    /// The program kill switch is activated, preventing offer operations
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+    /// The base offer does not match the one referenced by the dual offer
+    #[msg("Invalid base offer")]
+    InvalidBaseOffer,
+}
+
+/// Event emitted when a dual offer is successfully taken
+///
+/// Provides transparency for tracking both output legs of the exchange.
+#[event]
+pub struct DualOfferTakenEvent {
+    /// The PDA address of the dual offer that was executed
+    pub dual_offer_pda: Pubkey,
+    /// The PDA address of the base offer pricing the principal leg
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Amount of token_in paid by the user after fee deduction
+    pub token_in_amount: u64,
+    /// Amount of principal token_out_1 received by the user
+    pub token_out_1_amount: u64,
+    /// Amount of secondary token_out_2 received by the user
+    pub token_out_2_amount: u64,
+    /// Fee amount deducted from the original token_in payment
+    pub fee_amount: u64,
+    /// Public key of the user who executed the dual offer
+    pub user: Pubkey,
+    /// How the principal token_out_1 was delivered to the user
+    pub fulfillment_mode: FulfillmentMode,
+}
+
+/// Account structure for executing a dual offer transaction
+///
+/// Extends the accounts of `take_offer` with the dual offer and the mint, vault and
+/// user accounts of the secondary output token.
+#[derive(Accounts)]
+pub struct TakeDualOffer<'info> {
+    /// The dual offer account holding the secondary leg configuration
+    #[account(
+        seeds = [
+            seeds::DUAL_OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint_1.key().as_ref(),
+            token_out_mint_2.key().as_ref()
+        ],
+        bump = dual_offer.bump,
+        has_one = offer @ TakeDualOfferErrorCode::InvalidBaseOffer
+    )]
+    pub dual_offer: Box<Account<'info, DualOffer>>,
+
+    /// The base offer pricing the principal leg
+    ///
+    /// Mutable to track the per-step volume of the principal leg.
+    #[account(mut)]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program state account containing authorization and kill switch status
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ TakeDualOfferErrorCode::InvalidBoss,
+        constraint = !state.is_kill_switch_active()? @ TakeDualOfferErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the approval validity limit and the
+    /// seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account authorized to receive token_in payments
+    ///
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// Program-derived authority that controls vault token operations
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Vault account for temporary token_in storage during burn operations
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault account for principal token distribution when using transfer mechanism
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint_1,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_1_program
+    )]
+    pub vault_token_out_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault account for secondary token distribution when using transfer mechanism
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub vault_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Input token mint account for the exchange
+    ///
+    /// Must be mutable to allow burning operations when program has mint authority.
+    #[account(
+        mut,
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for input token operations
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Principal output token mint account
+    ///
+    /// Must be mutable to allow minting operations when program has mint authority.
+    #[account(
+        mut,
+        constraint =
+            token_out_mint_1.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint_1: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for principal output token operations
+    pub token_out_1_program: Interface<'info, TokenInterface>,
+
+    /// Secondary output token mint account
+    ///
+    /// Must be mutable to allow minting operations when program has mint authority.
+    /// Validated through the dual offer PDA derivation.
+    #[account(mut)]
+    pub token_out_mint_2: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for secondary output token operations
+    pub token_out_2_program: Interface<'info, TokenInterface>,
+
+    /// User's input token account for payment
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_in_program
+    )]
+    pub user_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's principal token account, created automatically if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint_1,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_1_program
+    )]
+    pub user_token_out_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's secondary token account, created automatically if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub user_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's input token account for receiving payments
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority for direct token minting
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Managed mint registry entry of token_out_1, capping its supply when minted
+    ///
+    /// May be uninitialized when token_out_1 is not registered with `add_managed_mint`.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(seeds = [seeds::MANAGED_MINT, token_out_mint_1.key().as_ref()], bump)]
+    pub managed_mint_1: UncheckedAccount<'info>,
+    /// Managed mint registry entry of token_out_2, capping its supply when minted
+    ///
+    /// May be uninitialized when token_out_2 is not registered with `add_managed_mint`.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(seeds = [seeds::MANAGED_MINT, token_out_mint_2.key().as_ref()], bump)]
+    pub managed_mint_2: UncheckedAccount<'info>,
+
+    /// Instructions sysvar for approval signature verification
+    ///
+    /// CHECK: Validated through address constraint to instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the base offer has an oracle configured.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
+    /// Redemption offer redeeming token_out back into token_in
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// May be uninitialized when the pair has no redemption offer.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_out_mint_1.key().as_ref(),
+            token_in_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: Option<UncheckedAccount<'info>>,
+
+    /// Redemption vault's token_in account covering outstanding redemptions
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// CHECK: Validated against the redemption vault ATA address in the handler
+    pub redemption_vault_token_in_account: Option<UncheckedAccount<'info>>,
+
+    /// Boss approval of the token_in mint's transfer hook
+    ///
+    /// Required only when token_in is a Token-2022 transfer-hook mint, whose extra
+    /// hook accounts are then passed as remaining accounts.
+    #[account(
+        seeds = [seeds::TRANSFER_HOOK_MINT, token_in_mint.key().as_ref()],
+        bump = token_in_hook_approval.bump
+    )]
+    pub token_in_hook_approval: Option<Box<Account<'info, TransferHookMintApproval>>>,
+
+    /// The user executing the dual offer and paying for account creation
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes a dual offer, paying out the principal and the secondary token
+///
+/// The principal leg is processed exactly like `take_offer` against the base offer,
+/// including approval, oracle valuation, fees and the per-step volume cap. The
+/// secondary leg is then paid in proportion to the principal amount.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_in_amount` - Amount of token_in the user is willing to pay (including fees)
+/// * `approval_message` - Optional cryptographic approval from trusted authority
+///
+/// # Process Flow
+/// 1. Verify approval requirements if the base offer needs approval
+/// 2. Calculate the principal token_out_1 amount and fees from the base offer and
+///    enforce its minimum take size
+/// 3. Derive the token_out_2 amount from the principal and the configured ratio
+/// 4. Record the principal against the per-step volume cap and the take against the
+///    cumulative fee and volume counters
+/// 5. Collect token_in and pay out token_out_1 (burn/mint or transfer)
+/// 6. Pay out token_out_2 (mint or transfer)
+///
+/// # Returns
+/// * `Ok(())` - If the dual offer is successfully executed
+/// * `Err(_)` - If validation fails, no active vector, or token operations fail
+///
+/// # Access Control
+/// - Any user can execute dual offers unless approval is required
+/// - Kill switch prevents execution when activated
+/// - Transfer-hook token_in mints require a boss approval
+///
+/// # Events
+/// * `DualOfferTakenEvent` - Emitted with the amounts of both output legs
+pub fn take_dual_offer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeDualOffer<'info>>,
+    token_in_amount: u64,
+    approval_message: Option<ApprovalMessage>,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    // Reject takes outside the offer's validity window
+    enforce_offer_window(&offer)?;
+
+    // Takes of clawback-enabled offers need a receipt, which this flow does not write
+    require!(
+        !offer.clawback_enabled(),
+        OfferCoreError::ClawbackOfferNotSupported
+    );
+
+    // Reject mints priced with other decimals than the offer was made for
+    require_offer_decimals(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_1,
+    )?;
+
+    verify_offer_approval(
+        &offer,
+        &approval_message,
+        ctx.program_id,
+        &ctx.accounts.user.key(),
+        &ctx.accounts.state,
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.config,
+    )?;
+
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+
+    // Principal leg priced by the base offer
+    let result = process_offer_core(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_1,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+
+    // Secondary leg proportional to the principal
+    let token_out_2_amount = calculate_dual_offer_secondary_amount(
+        result.token_out_amount,
+        ctx.accounts.dual_offer.token_out_2_ratio,
+        ctx.accounts.token_out_mint_1.decimals,
+        ctx.accounts.token_out_mint_2.decimals,
+    )?;
+
+    // Reject takes below the offer's minimum size
+    enforce_minimum_take(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        token_in_usd_price,
+    )?;
+
+    // Reject takes paying out zero or dust amounts of token_out
+    enforce_dust_threshold(
+        &offer,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_1,
+    )?;
+
+    // Throttle the take while outstanding redemptions strain the redemption vault
+    enforce_redemption_stress_policy(
+        &ctx.accounts.state,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_1,
+        &ctx.accounts.token_in_program,
+        ctx.accounts.redemption_offer.as_ref(),
+        ctx.accounts.redemption_vault_token_in_account.as_ref(),
+    )?;
+
+    record_step_volume(&mut offer, result.token_out_amount)?;
+    record_take_totals(
+        &mut offer,
+        result
+            .token_in_net_amount
+            .checked_add(result.token_in_fee_amount)
+            .ok_or(OfferCoreError::OverflowError)?,
+        result.token_in_fee_amount,
+    )?;
+
+    // Only approved transfer-hook mints receive the remaining accounts
+    let token_in_hook_accounts = resolve_transfer_hook_accounts(
+        &ctx.accounts.token_in_mint,
+        ctx.accounts
+            .token_in_hook_approval
+            .as_deref()
+            .map(|approval| &**approval),
+        ctx.remaining_accounts,
+    )?;
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    let vault_authority_seed = offer.vault_authority_seed(&ctx.accounts.offer.key());
+    let vault_authority_seeds: &[&[&[u8]]] = &[&[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.vault_authority],
+    ]];
+
+    let fulfillment_mode = execute_token_operations(ExecTokenOpsParams {
+        // Token in params
+        token_in_program: &ctx.accounts.token_in_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: result.token_in_net_amount,
+        token_in_fee_amount: result.token_in_fee_amount,
+        token_in_transfer_fee: None,
+        token_in_authority: &ctx.accounts.user,
+        token_in_source_signer_seeds: None,
+        vault_authority_signer_seeds: Some(vault_authority_seeds),
+        token_in_source_account: &ctx.accounts.user_token_in_account,
+        token_in_destination_account: &ctx.accounts.boss_token_in_account,
+        token_in_burn_account: &ctx.accounts.vault_token_in_account,
+        token_in_burn_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_in_transfer_hook_accounts: token_in_hook_accounts,
+        // Token out params
+        token_out_program: &ctx.accounts.token_out_1_program,
+        token_out_mint: &ctx.accounts.token_out_mint_1,
+        token_out_amount: result.token_out_amount,
+        token_out_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_out_source_account: &ctx.accounts.vault_token_out_1_account,
+        token_out_destination_account: &ctx.accounts.user_token_out_1_account.to_account_info(),
+        mint_authority_pda: &ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_bump: &[ctx.bumps.mint_authority],
+        token_out_max_supply: ManagedMint::max_supply_or(
+            &ctx.accounts.managed_mint_1,
+            ctx.accounts.state.max_supply,
+        )?,
+        token_out_fulfillment_mode: offer.fulfillment_mode(),
+    })?;
+
+    // Pay out the secondary leg, minting when the program controls the mint
+    require!(
+        !has_transfer_fee(&ctx.accounts.token_out_mint_2)?,
+        TokenUtilsErrorCode::TransferFeeNotSupported
+    );
+    if program_controls_mint(
+        &ctx.accounts.token_out_mint_2,
+        &ctx.accounts.mint_authority.to_account_info(),
+    ) {
+        // The program max supply only caps ONyc, managed mints carry their own cap
+        let state_max_supply =
+            if ctx.accounts.token_out_mint_2.key() == ctx.accounts.state.onyc_mint {
+                ctx.accounts.state.max_supply
+            } else {
+                0
+            };
+        let max_supply =
+            ManagedMint::max_supply_or(&ctx.accounts.managed_mint_2, state_max_supply)?;
+        mint_tokens(
+            &ctx.accounts.token_out_2_program,
+            &ctx.accounts.token_out_mint_2,
+            &ctx.accounts.user_token_out_2_account.to_account_info(),
+            &ctx.accounts.mint_authority.to_account_info(),
+            &[&[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]]],
+            token_out_2_amount,
+            max_supply,
+        )?;
+    } else {
+        transfer_tokens(
+            &ctx.accounts.token_out_mint_2,
+            &ctx.accounts.token_out_2_program,
+            &ctx.accounts.vault_token_out_2_account,
+            &ctx.accounts.user_token_out_2_account,
+            &ctx.accounts.vault_authority.to_account_info(),
+            Some(vault_authority_seeds),
+            token_out_2_amount,
+        )?;
+    }
+
+    msg!(
+        "Dual offer taken - PDA: {}, token_in(+fee): {}(+{}), token_out_1: {}, token_out_2: {}, user: {}, price: {}",
+        ctx.accounts.dual_offer.key(),
+        result.token_in_net_amount,
+        result.token_in_fee_amount,
+        result.token_out_amount,
+        token_out_2_amount,
+        ctx.accounts.user.key,
+        u64_to_dec9(result.current_price)
+    );
+
+    emit!(DualOfferTakenEvent {
+        dual_offer_pda: ctx.accounts.dual_offer.key(),
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        token_in_amount: result.token_in_net_amount,
+        token_out_1_amount: result.token_out_amount,
+        token_out_2_amount,
+        fee_amount: result.token_in_fee_amount,
+        user: ctx.accounts.user.key(),
+        fulfillment_mode,
+    });
+
+    Ok(())
+}
+
+/// Calculates the secondary payout of a dual offer from the principal payout
+///
+/// Formula: token_out_2 = token_out_1 * ratio * 10^token_out_2_decimals
+///                        / (10^9 * 10^token_out_1_decimals)
+///
+/// The result is rounded down.
+///
+/// # Arguments
+/// * `token_out_1_amount` - Principal payout in token_out_1 base units
+/// * `ratio` - Whole token_out_2 per whole token_out_1 with scale=9
+/// * `token_out_1_decimals` - Decimal places of the principal token
+/// * `token_out_2_decimals` - Decimal places of the secondary token
+///
+/// # Returns
+/// * `Ok(u64)` - Secondary payout in token_out_2 base units
+/// * `Err(TakeDualOfferErrorCode::MathOverflow)` - If the calculation overflows
+pub fn calculate_dual_offer_secondary_amount(
+    token_out_1_amount: u64,
+    ratio: u64,
+    token_out_1_decimals: u8,
+    token_out_2_decimals: u8,
+) -> Result<u64> {
+    require!(
+        token_out_1_decimals <= MAX_TOKEN_DECIMALS && token_out_2_decimals <= MAX_TOKEN_DECIMALS,
+        TokenUtilsErrorCode::DecimalsExceedMax
+    );
+
+    let value = (token_out_1_amount as u128)
+        .checked_mul(ratio as u128)
+        .ok_or(TakeDualOfferErrorCode::MathOverflow)?;
+
+    let amount = if token_out_2_decimals >= token_out_1_decimals {
+        value
+            .checked_mul(10_u128.pow((token_out_2_decimals - token_out_1_decimals) as u32))
+            .ok_or(TakeDualOfferErrorCode::MathOverflow)?
+            / 10_u128.pow(PRICE_DECIMALS as u32)
+    } else {
+        value / 10_u128.pow((PRICE_DECIMALS + token_out_1_decimals - token_out_2_decimals) as u32)
+    };
+
+    u64::try_from(amount).map_err(|_| error!(TakeDualOfferErrorCode::MathOverflow))
+}
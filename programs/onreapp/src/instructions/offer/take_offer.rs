@@ -1,8 +1,23 @@
 use crate::constants::seeds;
-use crate::instructions::offer::offer_utils::{process_offer_core, verify_offer_approval};
-use crate::instructions::Offer;
-use crate::state::State;
-use crate::utils::{execute_token_operations, u64_to_dec9, ApprovalMessage, ExecTokenOpsParams};
+use crate::instructions::offer::offer_utils::{
+    create_token_out_account_if_needed, enforce_dust_threshold, enforce_minimum_take,
+    enforce_offer_window, enforce_redemption_stress_policy, is_low_inventory,
+    load_token_in_usd_price, observe_nav, proceeds_destination, process_offer_core,
+    record_step_volume, record_take_receipt, record_take_totals, record_uncollected_proceeds,
+    record_user_position_purchase, require_offer_decimals, require_prepared_take_accounts,
+    verify_offer_approval,
+};
+use crate::instructions::{
+    FulfillmentMode, ManagedMint, NavHistory, Offer, TakeReceipt, UserPosition,
+};
+use crate::state::{
+    BlocklistBucket, Config, State, StateSchemaErrorCode, TransferHookMintApproval,
+};
+use crate::utils::event_cpi::event_emitter;
+use crate::utils::{
+    execute_token_operations, resolve_transfer_hook_accounts, screen_take, token_account_amount,
+    transfer_fee_for, u64_to_dec9, ApprovalMessage, ExecTokenOpsParams,
+};
 use crate::OfferCoreError;
 use anchor_lang::{prelude::*, solana_program::sysvar, Accounts};
 use anchor_spl::{
@@ -31,6 +46,8 @@ pub enum TakeOfferErrorCode {
 pub struct OfferTakenEvent {
     /// The PDA address of the offer that was executed
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Amount of token_in paid by the user after fee deduction
     pub token_in_amount: u64,
     /// Amount of token_out received by the user
@@ -39,6 +56,12 @@ pub struct OfferTakenEvent {
     pub fee_amount: u64,
     /// Public key of the user who executed the offer
     pub user: Pubkey,
+    /// How token_out was delivered to the user
+    pub fulfillment_mode: FulfillmentMode,
+    /// token_out balance left in the offer vault after the take
+    pub vault_token_out_remaining: u64,
+    /// Whether the vault balance is at or below the offer's low inventory threshold
+    pub low_inventory: bool,
 }
 
 /// Account structure for executing an offer transaction
@@ -46,6 +69,7 @@ pub struct OfferTakenEvent {
 /// This struct defines all accounts required for offer execution including token
 /// operations, approval verification, and flexible burn/mint or transfer mechanisms
 /// depending on program mint authority status.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct TakeOffer<'info> {
     /// The offer account containing pricing vectors and exchange configuration
@@ -68,10 +92,16 @@ pub struct TakeOffer<'info> {
         seeds = [seeds::STATE],
         bump = state.bump,
         has_one = boss @ TakeOfferErrorCode::InvalidBoss,
-        constraint = state.is_killed == false @ TakeOfferErrorCode::KillSwitchActivated
+        constraint = !state.is_kill_switch_active()? @ TakeOfferErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
+    /// Program-wide configuration providing the approval validity limit and the
+    /// seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account authorized to receive token_in payments
     ///
     /// Must match the boss stored in program state for security validation.
@@ -81,10 +111,14 @@ pub struct TakeOffer<'info> {
     /// Program-derived authority that controls vault token operations
     ///
     /// This PDA manages token transfers and burning operations for the
-    /// burn/mint architecture when program has mint authority.
+    /// burn/mint architecture when program has mint authority. Offers with their
+    /// own vault derive it from the offer, all others share it.
     /// CHECK: PDA derivation is validated by seeds constraint
     #[account(
-        seeds = [seeds::OFFER_VAULT_AUTHORITY],
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
         bump
     )]
     pub vault_authority: UncheckedAccount<'info>,
@@ -92,7 +126,8 @@ pub struct TakeOffer<'info> {
     /// Vault account for temporary token_in storage during burn operations
     ///
     /// Used for burning input tokens when the program has mint authority
-    /// for efficient burn/mint token exchange architecture.
+    /// for efficient burn/mint token exchange architecture, and receives the
+    /// take proceeds when the offer routes them to the vault.
     #[account(
         mut,
         associated_token::mint = token_in_mint,
@@ -158,27 +193,31 @@ pub struct TakeOffer<'info> {
     /// User's output token account for receiving exchanged tokens
     ///
     /// Destination account where the user receives token_out from the exchange.
-    /// Created automatically if it doesn't exist using init_if_needed.
+    /// Created by the take if it doesn't exist, unless the offer has strict accounts.
+    /// CHECK: Validated as the user's token_out ATA by seeds constraint, created in the handler
     #[account(
-        init_if_needed,
-        payer = user,
-        associated_token::mint = token_out_mint,
-        associated_token::authority = user,
-        associated_token::token_program = token_out_program
+        mut,
+        seeds = [
+            user.key().as_ref(),
+            token_out_program.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        seeds::program = associated_token_program.key(),
+        bump
     )]
-    pub user_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub user_token_out_account: UncheckedAccount<'info>,
 
     /// Boss's input token account for receiving payments
     ///
-    /// Destination account where the boss receives token_in payments
-    /// from users taking offers.
+    /// Required only when the offer pays take proceeds to the boss instead of
+    /// the offer vault.
     #[account(
         mut,
         associated_token::mint = token_in_mint,
         associated_token::authority = boss,
         associated_token::token_program = token_in_program
     )]
-    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub boss_token_in_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     /// Program-derived mint authority for direct token minting
     ///
@@ -191,6 +230,13 @@ pub struct TakeOffer<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
+    /// Managed mint registry entry of token_out, capping its supply when minted
+    ///
+    /// May be uninitialized when token_out is not registered with `add_managed_mint`.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(seeds = [seeds::MANAGED_MINT, token_out_mint.key().as_ref()], bump)]
+    pub managed_mint: UncheckedAccount<'info>,
+
     /// Instructions sysvar for approval signature verification
     ///
     /// Required for cryptographic verification of approval messages
@@ -199,6 +245,104 @@ pub struct TakeOffer<'info> {
     #[account(address = sysvar::instructions::id())]
     pub instructions_sysvar: UncheckedAccount<'info>,
 
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the offer has an oracle configured via `set_offer_oracle`.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
+    /// Redemption offer redeeming token_out back into token_in
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// May be uninitialized when the pair has no redemption offer.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_out_mint.key().as_ref(),
+            token_in_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: Option<UncheckedAccount<'info>>,
+
+    /// Redemption vault's token_in account covering outstanding redemptions
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// CHECK: Validated against the redemption vault ATA address in the handler
+    pub redemption_vault_token_in_account: Option<UncheckedAccount<'info>>,
+
+    /// Boss approval of the token_in mint's transfer hook
+    ///
+    /// Required only when token_in is a Token-2022 transfer-hook mint, whose extra
+    /// hook accounts are then passed as remaining accounts.
+    #[account(
+        seeds = [seeds::TRANSFER_HOOK_MINT, token_in_mint.key().as_ref()],
+        bump = token_in_hook_approval.bump
+    )]
+    pub token_in_hook_approval: Option<Box<Account<'info, TransferHookMintApproval>>>,
+
+    /// Program screening the take through its `check(user, amount)` instruction
+    ///
+    /// Required only when a screening program is configured in program state.
+    /// CHECK: Validated against the state's screening program in the handler
+    pub screening_program: Option<UncheckedAccount<'info>>,
+
+    /// Blocklist bucket covering the user, rejecting sanctioned users
+    ///
+    /// May be uninitialized when no address of the bucket was ever blocked.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [seeds::BLOCKLIST, &[BlocklistBucket::index_for(&user.key())]],
+        bump
+    )]
+    pub blocklist_bucket: UncheckedAccount<'info>,
+
+    /// Position of the user in token_out, updated with the take
+    ///
+    /// Created on the user's first take, with rent paid by the user, unless the
+    /// offer has strict accounts.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserPosition::INIT_SPACE,
+        seeds = [
+            seeds::USER_POSITION,
+            token_out_mint.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// Receipt of the take, letting `clawback_take` reverse exactly its amounts
+    ///
+    /// Required only when the offer is flagged for clawbacks. Created with rent paid
+    /// by the user and addressed by the next receipt index of the user's position.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + TakeReceipt::INIT_SPACE,
+        seeds = [
+            seeds::TAKE_RECEIPT,
+            offer.key().as_ref(),
+            user.key().as_ref(),
+            user_position.take_receipt_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub take_receipt: Option<Box<Account<'info, TakeReceipt>>>,
+
     /// The user executing the offer and paying for account creation
     #[account(mut)]
     pub user: Signer<'info>,
@@ -225,29 +369,72 @@ pub struct TakeOffer<'info> {
 /// * `approval_message` - Optional cryptographic approval from trusted authority
 ///
 /// # Process Flow
-/// 1. Verify approval requirements if offer needs approval
-/// 2. Find active pricing vector and calculate current price
-/// 3. Calculate token_out amount and fees based on current price
-/// 4. Execute token operations (burn/mint or transfer based on mint authority)
-/// 5. Emit event with transaction details
+/// 1. Verify approval requirements if offer needs approval and screen the take
+///    through the configured screening program
+/// 2. Find active pricing vector and calculate current price, converted into
+///    token_in terms through the offer's oracle if configured
+/// 3. Calculate token_out amount and fees based on current price and enforce the
+///    offer's minimum take size; offers priced on the net received amount deduct
+///    the token_in transfer fee from `token_in_amount` first
+/// 4. Record the take against the per-step volume cap and the cumulative fee and
+///    volume counters
+/// 5. Execute token operations (burn/mint or transfer based on mint authority) and
+///    check that every balance moved by exactly the computed amounts
+/// 6. Record the take in the user's token_out position, creating it on the first take
+/// 7. Emit event with transaction details
 ///
 /// # Returns
 /// * `Ok(())` - If the offer is successfully executed
+/// * `Err(TokenUtilsErrorCode::InvariantViolation)` - If a balance deviates from the
+///   computed amounts after the transfers
 /// * `Err(_)` - If validation fails, no active vector, or token operations fail
 ///
 /// # Access Control
 /// - Any user can execute offers unless approval is required
 /// - Kill switch prevents execution when activated
+/// - Takes outside the offer's validity window are rejected
+/// - Users on the sanctioned-address blocklist are rejected
 /// - Approval verification against trusted authority when needed
+/// - The configured screening program must accept the user and token_in amount
+/// - Transfer-hook token_in mints require a boss approval, the hook accounts being
+///   passed as remaining accounts
 ///
 /// # Events
 /// * `TakeOfferEvent` - Emitted with execution details and token amounts
-pub fn take_offer(
-    ctx: Context<TakeOffer>,
+pub fn take_offer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeOffer<'info>>,
     token_in_amount: u64,
     approval_message: Option<ApprovalMessage>,
 ) -> Result<()> {
-    let offer = ctx.accounts.offer.load()?;
+    // Reject sanctioned users
+    BlocklistBucket::require_not_blocked(&ctx.accounts.blocklist_bucket, &ctx.accounts.user.key())?;
+
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    // Reject takes outside the offer's validity window
+    enforce_offer_window(&offer)?;
+
+    // Reject mints priced with other decimals than the offer was made for
+    require_offer_decimals(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Create the user's token_out account, unless the offer requires it up front
+    require_prepared_take_accounts(
+        &offer,
+        &ctx.accounts.user_token_out_account,
+        &ctx.accounts.user_position,
+    )?;
+    create_token_out_account_if_needed(
+        &ctx.accounts.user_token_out_account,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_out_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
 
     // Verify approval if needed
     verify_offer_approval(
@@ -255,47 +442,188 @@ pub fn take_offer(
         &approval_message,
         ctx.program_id,
         &ctx.accounts.user.key(),
-        &ctx.accounts.state.approver1,
-        &ctx.accounts.state.approver2,
+        &ctx.accounts.state,
         &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.config,
+    )?;
+
+    // Run the configured AML rules on the user and payment
+    screen_take(
+        &ctx.accounts.state.screening_program,
+        ctx.accounts.screening_program.as_ref(),
+        &ctx.accounts.user.to_account_info(),
+        token_in_amount,
     )?;
 
+    // Value token_in through the offer's oracle if configured
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+
+    // Price on what the destination receives when the offer accounts for the
+    // Token-2022 transfer fee withheld from the user's payment
+    let token_in_transfer_fee = if offer.price_on_net_received() {
+        Some(transfer_fee_for(
+            &ctx.accounts.token_in_mint,
+            token_in_amount,
+        )?)
+    } else {
+        None
+    };
+    let token_in_received = token_in_amount
+        .checked_sub(token_in_transfer_fee.unwrap_or(0))
+        .ok_or(OfferCoreError::OverflowError)?;
+
     // Use shared core processing logic for main exchange amount
     let result = process_offer_core(
         &offer,
-        token_in_amount,
+        token_in_received,
         &ctx.accounts.token_in_mint,
         &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+
+    // Reject takes below the offer's minimum size
+    enforce_minimum_take(
+        &offer,
+        token_in_received,
+        &ctx.accounts.token_in_mint,
+        token_in_usd_price,
     )?;
 
-    execute_token_operations(ExecTokenOpsParams {
+    // Reject takes paying out zero or dust amounts of token_out
+    enforce_dust_threshold(
+        &offer,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Throttle the take while outstanding redemptions strain the redemption vault
+    enforce_redemption_stress_policy(
+        &ctx.accounts.state,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_in_program,
+        ctx.accounts.redemption_offer.as_ref(),
+        ctx.accounts.redemption_vault_token_in_account.as_ref(),
+    )?;
+
+    // Enforce the per-step volume cap
+    record_step_volume(&mut offer, result.token_out_amount)?;
+
+    // Track cumulative revenue and volume
+    record_take_totals(
+        &mut offer,
+        result
+            .token_in_net_amount
+            .checked_add(result.token_in_fee_amount)
+            .ok_or(OfferCoreError::OverflowError)?,
+        result.token_in_fee_amount,
+    )?;
+
+    // Only approved transfer-hook mints receive the remaining accounts
+    let token_in_hook_accounts = resolve_transfer_hook_accounts(
+        &ctx.accounts.token_in_mint,
+        ctx.accounts
+            .token_in_hook_approval
+            .as_deref()
+            .map(|approval| &**approval),
+        ctx.remaining_accounts,
+    )?;
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    let vault_authority_seed = offer.vault_authority_seed(&ctx.accounts.offer.key());
+    let vault_token_in_before =
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?;
+    let fulfillment_mode = execute_token_operations(ExecTokenOpsParams {
         // Token in params
         token_in_program: &ctx.accounts.token_in_program,
         token_in_mint: &ctx.accounts.token_in_mint,
         token_in_net_amount: result.token_in_net_amount,
         token_in_fee_amount: result.token_in_fee_amount,
+        token_in_transfer_fee,
         token_in_authority: &ctx.accounts.user,
         token_in_source_signer_seeds: None,
         vault_authority_signer_seeds: Some(&[&[
             seeds::OFFER_VAULT_AUTHORITY,
+            &vault_authority_seed,
             &[ctx.bumps.vault_authority],
         ]]),
         token_in_source_account: &ctx.accounts.user_token_in_account,
-        token_in_destination_account: &ctx.accounts.boss_token_in_account,
+        token_in_destination_account: proceeds_destination(
+            &offer,
+            &ctx.accounts.vault_token_in_account,
+            ctx.accounts.boss_token_in_account.as_deref(),
+        )?,
         token_in_burn_account: &ctx.accounts.vault_token_in_account,
         token_in_burn_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_in_transfer_hook_accounts: token_in_hook_accounts,
         // Token out params
         token_out_program: &ctx.accounts.token_out_program,
         token_out_mint: &ctx.accounts.token_out_mint,
         token_out_amount: result.token_out_amount,
         token_out_authority: &ctx.accounts.vault_authority.to_account_info(),
         token_out_source_account: &ctx.accounts.vault_token_out_account,
-        token_out_destination_account: &ctx.accounts.user_token_out_account,
+        token_out_destination_account: &ctx.accounts.user_token_out_account.to_account_info(),
         mint_authority_pda: &ctx.accounts.mint_authority.to_account_info(),
         mint_authority_bump: &[ctx.bumps.mint_authority],
-        token_out_max_supply: ctx.accounts.state.max_supply,
+        token_out_max_supply: ManagedMint::max_supply_or(
+            &ctx.accounts.managed_mint,
+            ctx.accounts.state.max_supply,
+        )?,
+        token_out_fulfillment_mode: offer.fulfillment_mode(),
     })?;
 
+    // Credit the proceeds the take left in the offer vault to the offer
+    record_uncollected_proceeds(
+        &mut offer,
+        vault_token_in_before,
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?,
+    )?;
+
+    // Track the user's holdings and cost basis in token_out
+    record_user_position_purchase(
+        &mut ctx.accounts.user_position,
+        ctx.accounts.user.key(),
+        ctx.accounts.token_out_mint.key(),
+        ctx.bumps.user_position,
+        &result,
+    )?;
+
+    // Record the take for clawbacks when the offer is flagged
+    record_take_receipt(
+        &offer,
+        ctx.accounts.offer.key(),
+        ctx.accounts
+            .take_receipt
+            .as_deref_mut()
+            .map(|take_receipt| &mut **take_receipt),
+        ctx.bumps.take_receipt,
+        &mut ctx.accounts.user_position,
+        ctx.accounts.user.key(),
+        result
+            .token_in_net_amount
+            .checked_add(result.token_in_fee_amount)
+            .ok_or(OfferCoreError::OverflowError)?,
+        result.token_out_amount,
+    )?;
+
+    // Report the inventory left in the vault so monitoring can alert before it runs out
+    let vault_token_out_remaining =
+        token_account_amount(&ctx.accounts.vault_token_out_account.to_account_info())?;
+    let low_inventory = is_low_inventory(&offer, vault_token_out_remaining, fulfillment_mode);
+
     msg!(
         "Offer taken - PDA: {}, token_in(+fee): {}(+{}), token_out: {}, user: {}, price: {}",
         ctx.accounts.offer.key(),
@@ -306,13 +634,17 @@ pub fn take_offer(
         u64_to_dec9(result.current_price)
     );
 
-    emit!(OfferTakenEvent {
+    event_emitter!(ctx).emit(OfferTakenEvent {
         offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
         token_in_amount: result.token_in_net_amount,
         token_out_amount: result.token_out_amount,
         fee_amount: result.token_in_fee_amount,
         user: ctx.accounts.user.key(),
-    });
+        fulfillment_mode,
+        vault_token_out_remaining,
+        low_inventory,
+    })?;
 
     Ok(())
 }
@@ -0,0 +1,512 @@
+use crate::constants::seeds;
+use crate::instructions::offer::offer_utils::{
+    enforce_dust_threshold, enforce_minimum_take, enforce_offer_window,
+    enforce_redemption_stress_policy, load_token_in_usd_price, observe_nav, proceeds_destination,
+    process_offer_core, record_step_volume, record_take_totals, record_uncollected_proceeds,
+    require_offer_decimals, verify_offer_approval,
+};
+use crate::instructions::{FulfillmentMode, ManagedMint, NavHistory, Offer};
+use crate::state::{Config, State, StateSchemaErrorCode, TransferHookMintApproval};
+use crate::utils::{
+    execute_token_operations, resolve_transfer_hook_accounts, token_account_amount, u64_to_dec9,
+    ApprovalMessage, ExecTokenOpsParams,
+};
+use crate::OfferCoreError;
+use anchor_lang::{
+    prelude::*, solana_program::program_option::COption, solana_program::sysvar, Accounts,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Error codes specific to the take_offer_delegated instruction
+#[error_code]
+pub enum TakeOfferDelegatedErrorCode {
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+    /// The program kill switch is activated, preventing offer operations
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+    /// The signer is not the delegate of the owner's token_in account
+    #[msg("Signer is not the delegate of the owner's token account")]
+    NotDelegated,
+}
+
+/// Event emitted when an offer is taken on behalf of a token owner
+///
+/// Provides transparency for tracking delegated executions, naming both the owner
+/// whose assets were exchanged and the delegate that signed.
+#[event]
+pub struct OfferTakenDelegatedEvent {
+    /// The PDA address of the offer that was executed
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Amount of token_in paid by the owner after fee deduction
+    pub token_in_amount: u64,
+    /// Amount of token_out received by the owner
+    pub token_out_amount: u64,
+    /// Fee amount deducted from the original token_in payment
+    pub fee_amount: u64,
+    /// Public key of the owner the offer was taken for
+    pub owner: Pubkey,
+    /// Public key of the delegate that executed the offer
+    pub delegate: Pubkey,
+    /// How token_out was delivered to the owner
+    pub fulfillment_mode: FulfillmentMode,
+}
+
+/// Account structure for executing an offer on behalf of a token owner
+///
+/// Mirrors `TakeOffer`, except that token_in is pulled from the owner's account by a
+/// signing delegate holding SPL delegate authority over it, and token_out is credited
+/// to the owner's account.
+#[derive(Accounts)]
+pub struct TakeOfferDelegated<'info> {
+    /// The offer account containing pricing vectors and exchange configuration
+    ///
+    /// This account is validated as a PDA derived from token mint addresses
+    /// and contains the pricing vectors used for dynamic price calculation.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// Program state account containing authorization and kill switch status
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ TakeOfferDelegatedErrorCode::InvalidBoss,
+        constraint = !state.is_kill_switch_active()? @ TakeOfferDelegatedErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the approval validity limit and the
+    /// seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account authorized to receive token_in payments
+    ///
+    /// Must match the boss stored in program state for security validation.
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// Program-derived authority that controls vault token operations
+    ///
+    /// This PDA manages token transfers and burning operations for the
+    /// burn/mint architecture when program has mint authority.
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer.load()?.vault_authority_seed(&offer.key()).as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Vault account for temporary token_in storage during burn operations
+    ///
+    /// Used for burning input tokens when the program has mint authority
+    /// for efficient burn/mint token exchange architecture, and receives the
+    /// take proceeds when the offer routes them to the vault.
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault account for token_out distribution when using transfer mechanism
+    ///
+    /// Source of output tokens when the program lacks mint authority
+    /// and must transfer from pre-funded vault instead of minting.
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Input token mint account for the exchange
+    ///
+    /// Must be mutable to allow burning operations when program has mint authority.
+    /// Validated against the offer's expected token_in_mint.
+    #[account(
+        mut,
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for input token operations
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Output token mint account for the exchange
+    ///
+    /// Must be mutable to allow minting operations when program has mint authority.
+    /// Validated against the offer's expected token_out_mint.
+    #[account(
+        mut,
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for output token operations
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Owner's input token account for payment
+    ///
+    /// Source account from which token_in is pulled under the delegate's authority.
+    /// The delegated allowance must cover the requested token_in_amount.
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_in_program,
+        constraint =
+            owner_token_in_account.delegate == COption::Some(delegate.key())
+            @ TakeOfferDelegatedErrorCode::NotDelegated
+    )]
+    pub owner_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Owner's output token account for receiving exchanged tokens
+    ///
+    /// Destination account where the owner receives token_out from the exchange.
+    /// Created automatically if it doesn't exist, paid for by the delegate.
+    #[account(
+        init_if_needed,
+        payer = delegate,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_out_program
+    )]
+    pub owner_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's input token account for receiving payments
+    ///
+    /// Required only when the offer pays take proceeds to the boss instead of
+    /// the offer vault.
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Program-derived mint authority for direct token minting
+    ///
+    /// Used when the program has mint authority and can mint token_out
+    /// directly to users instead of transferring from vault.
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(
+        seeds = [seeds::MINT_AUTHORITY],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Managed mint registry entry of token_out, capping its supply when minted
+    ///
+    /// May be uninitialized when token_out is not registered with `add_managed_mint`.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(seeds = [seeds::MANAGED_MINT, token_out_mint.key().as_ref()], bump)]
+    pub managed_mint: UncheckedAccount<'info>,
+
+    /// Instructions sysvar for approval signature verification
+    ///
+    /// Required for cryptographic verification of approval messages
+    /// when offers require boss approval for execution.
+    /// CHECK: Validated through address constraint to instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the offer has an oracle configured via `set_offer_oracle`.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
+    /// Redemption offer redeeming token_out back into token_in
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// May be uninitialized when the pair has no redemption offer.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_out_mint.key().as_ref(),
+            token_in_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: Option<UncheckedAccount<'info>>,
+
+    /// Redemption vault's token_in account covering outstanding redemptions
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// CHECK: Validated against the redemption vault ATA address in the handler
+    pub redemption_vault_token_in_account: Option<UncheckedAccount<'info>>,
+
+    /// Boss approval of the token_in mint's transfer hook
+    ///
+    /// Required only when token_in is a Token-2022 transfer-hook mint, whose extra
+    /// hook accounts are then passed as remaining accounts.
+    #[account(
+        seeds = [seeds::TRANSFER_HOOK_MINT, token_in_mint.key().as_ref()],
+        bump = token_in_hook_approval.bump
+    )]
+    pub token_in_hook_approval: Option<Box<Account<'info, TransferHookMintApproval>>>,
+
+    /// The owner of the token accounts the offer is taken on behalf of
+    ///
+    /// CHECK: Only used as the token account authority, validated by the ATA constraints
+    pub owner: UncheckedAccount<'info>,
+
+    /// The delegate executing the offer and paying for account creation
+    ///
+    /// Must be the SPL token delegate of the owner's token_in account.
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes an offer on behalf of a token owner through SPL delegate authority
+///
+/// Lets an operational key trade for a cold wallet without moving its assets
+/// first: the owner approves the delegate on its token_in account, and the delegate
+/// signs the take. Pricing, fees, limits and token operations are those of
+/// `take_offer`; approvals are verified for the owner, the party that trades.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_in_amount` - Amount of token_in pulled from the owner (including fees)
+/// * `approval_message` - Optional cryptographic approval issued for the owner
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully executed
+/// * `Err(TakeOfferDelegatedErrorCode::NotDelegated)` - If the signer is not the delegate of the owner's token_in account
+/// * `Err(_)` - If validation fails, the allowance is insufficient, or token operations fail
+///
+/// # Access Control
+/// - Only the SPL delegate of the owner's token_in account can execute
+/// - Kill switch prevents execution when activated
+/// - Approval verification for the owner when the offer needs approval
+///
+/// # Effects
+/// - Pulls token_in from the owner's account, consuming the delegated allowance
+/// - Credits token_out to the owner's account
+///
+/// # Events
+/// * `OfferTakenDelegatedEvent` - Emitted with execution details, owner and delegate
+pub fn take_offer_delegated<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeOfferDelegated<'info>>,
+    token_in_amount: u64,
+    approval_message: Option<ApprovalMessage>,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    // Reject takes outside the offer's validity window
+    enforce_offer_window(&offer)?;
+
+    // Takes of clawback-enabled offers need a receipt, which this flow does not write
+    require!(
+        !offer.clawback_enabled(),
+        OfferCoreError::ClawbackOfferNotSupported
+    );
+
+    // Reject mints priced with other decimals than the offer was made for
+    require_offer_decimals(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Verify approval if needed
+    verify_offer_approval(
+        &offer,
+        &approval_message,
+        ctx.program_id,
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.state,
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.config,
+    )?;
+
+    // Value token_in through the offer's oracle if configured
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+
+    // Use shared core processing logic for main exchange amount
+    let result = process_offer_core(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+
+    // Reject takes below the offer's minimum size
+    enforce_minimum_take(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        token_in_usd_price,
+    )?;
+
+    // Reject takes paying out zero or dust amounts of token_out
+    enforce_dust_threshold(
+        &offer,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Throttle the take while outstanding redemptions strain the redemption vault
+    enforce_redemption_stress_policy(
+        &ctx.accounts.state,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_in_program,
+        ctx.accounts.redemption_offer.as_ref(),
+        ctx.accounts.redemption_vault_token_in_account.as_ref(),
+    )?;
+
+    // Enforce the per-step volume cap
+    record_step_volume(&mut offer, result.token_out_amount)?;
+
+    // Track cumulative revenue and volume
+    record_take_totals(
+        &mut offer,
+        result
+            .token_in_net_amount
+            .checked_add(result.token_in_fee_amount)
+            .ok_or(OfferCoreError::OverflowError)?,
+        result.token_in_fee_amount,
+    )?;
+
+    // Only approved transfer-hook mints receive the remaining accounts
+    let token_in_hook_accounts = resolve_transfer_hook_accounts(
+        &ctx.accounts.token_in_mint,
+        ctx.accounts
+            .token_in_hook_approval
+            .as_deref()
+            .map(|approval| &**approval),
+        ctx.remaining_accounts,
+    )?;
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    let vault_authority_seed = offer.vault_authority_seed(&ctx.accounts.offer.key());
+    let vault_token_in_before =
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?;
+    let fulfillment_mode = execute_token_operations(ExecTokenOpsParams {
+        // Token in params
+        token_in_program: &ctx.accounts.token_in_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: result.token_in_net_amount,
+        token_in_fee_amount: result.token_in_fee_amount,
+        token_in_transfer_fee: None,
+        token_in_authority: &ctx.accounts.delegate,
+        token_in_source_signer_seeds: None,
+        vault_authority_signer_seeds: Some(&[&[
+            seeds::OFFER_VAULT_AUTHORITY,
+            &vault_authority_seed,
+            &[ctx.bumps.vault_authority],
+        ]]),
+        token_in_source_account: &ctx.accounts.owner_token_in_account,
+        token_in_destination_account: proceeds_destination(
+            &offer,
+            &ctx.accounts.vault_token_in_account,
+            ctx.accounts.boss_token_in_account.as_deref(),
+        )?,
+        token_in_burn_account: &ctx.accounts.vault_token_in_account,
+        token_in_burn_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_in_transfer_hook_accounts: token_in_hook_accounts,
+        // Token out params
+        token_out_program: &ctx.accounts.token_out_program,
+        token_out_mint: &ctx.accounts.token_out_mint,
+        token_out_amount: result.token_out_amount,
+        token_out_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_out_source_account: &ctx.accounts.vault_token_out_account,
+        token_out_destination_account: &ctx.accounts.owner_token_out_account.to_account_info(),
+        mint_authority_pda: &ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_bump: &[ctx.bumps.mint_authority],
+        token_out_max_supply: ManagedMint::max_supply_or(
+            &ctx.accounts.managed_mint,
+            ctx.accounts.state.max_supply,
+        )?,
+        token_out_fulfillment_mode: offer.fulfillment_mode(),
+    })?;
+
+    // Credit the proceeds the take left in the offer vault to the offer
+    record_uncollected_proceeds(
+        &mut offer,
+        vault_token_in_before,
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?,
+    )?;
+
+    msg!(
+        "Offer taken (delegated) - PDA: {}, token_in(+fee): {}(+{}), token_out: {}, owner: {}, delegate: {}, price: {}",
+        ctx.accounts.offer.key(),
+        result.token_in_net_amount,
+        result.token_in_fee_amount,
+        result.token_out_amount,
+        ctx.accounts.owner.key,
+        ctx.accounts.delegate.key,
+        u64_to_dec9(result.current_price)
+    );
+
+    emit!(OfferTakenDelegatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        token_in_amount: result.token_in_net_amount,
+        token_out_amount: result.token_out_amount,
+        fee_amount: result.token_in_fee_amount,
+        owner: ctx.accounts.owner.key(),
+        delegate: ctx.accounts.delegate.key(),
+        fulfillment_mode,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use crate::constants::seeds;
+use crate::instructions::offer::offer_utils::{
+    create_token_out_account_if_needed, enforce_dust_threshold, enforce_minimum_take,
+    enforce_offer_window, enforce_redemption_stress_policy, is_low_inventory,
+    load_token_in_usd_price, observe_nav, proceeds_destination, process_offer_core_exact_out,
+    record_step_volume, record_take_receipt, record_take_totals, record_uncollected_proceeds,
+    record_user_position_purchase, require_offer_decimals, require_prepared_take_accounts,
+    verify_offer_approval,
+};
+use crate::instructions::{ManagedMint, OfferTakenEvent, TakeOffer};
+use crate::state::BlocklistBucket;
+use crate::utils::{
+    execute_token_operations, resolve_transfer_hook_accounts, screen_take, token_account_amount,
+    u64_to_dec9, ApprovalMessage, ExecTokenOpsParams,
+};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+
+/// Executes an offer for an exact amount of token_out
+///
+/// Counterpart of `take_offer` for takers who need a precise token_out amount, such
+/// as treasuries acquiring a fixed amount of ONyc. The token_in owed is derived by
+/// inverting the offer price, rounded up, and grossed up by the offer fee, so the
+/// fee is the same one `take_offer` would charge on that gross amount. The take uses
+/// the same accounts, limits and burn/mint or transfer paths as `take_offer`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated `TakeOffer` accounts
+/// * `token_out_amount` - Exact amount of token_out the user receives
+/// * `max_token_in_amount` - Maximum token_in the user is willing to pay (including fees)
+/// * `approval_message` - Optional cryptographic approval from trusted authority
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully executed
+/// * `Err(TakeOfferExactOutErrorCode::InvalidAmount)` - If token_out_amount is zero
+/// * `Err(TakeOfferExactOutErrorCode::MaxTokenInExceeded)` - If the token_in owed exceeds the maximum
+/// * `Err(_)` - If validation fails, no active vector, or token operations fail
+///
+/// # Access Control
+/// - Same as `take_offer`: any user unless approval is required or the user is blocklisted,
+///   blocked by the kill switch
+///
+/// # Events
+/// * `OfferTakenEvent` - Emitted with execution details and token amounts
+pub fn take_offer_exact_out<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeOffer<'info>>,
+    token_out_amount: u64,
+    max_token_in_amount: u64,
+    approval_message: Option<ApprovalMessage>,
+) -> Result<()> {
+    require!(
+        token_out_amount > 0,
+        TakeOfferExactOutErrorCode::InvalidAmount
+    );
+
+    // Reject sanctioned users
+    BlocklistBucket::require_not_blocked(&ctx.accounts.blocklist_bucket, &ctx.accounts.user.key())?;
+
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    // Reject takes outside the offer's validity window
+    enforce_offer_window(&offer)?;
+
+    // Reject mints priced with other decimals than the offer was made for
+    require_offer_decimals(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Create the user's token_out account, unless the offer requires it up front
+    require_prepared_take_accounts(
+        &offer,
+        &ctx.accounts.user_token_out_account,
+        &ctx.accounts.user_position,
+    )?;
+    create_token_out_account_if_needed(
+        &ctx.accounts.user_token_out_account,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_out_program,
+        &ctx.accounts.associated_token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Verify approval if needed
+    verify_offer_approval(
+        &offer,
+        &approval_message,
+        ctx.program_id,
+        &ctx.accounts.user.key(),
+        &ctx.accounts.state,
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.config,
+    )?;
+
+    // Value token_in through the offer's oracle if configured
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+
+    // Derive the token_in owed for the requested token_out
+    let result = process_offer_core_exact_out(
+        &offer,
+        token_out_amount,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    let token_in_amount = result
+        .token_in_net_amount
+        .checked_add(result.token_in_fee_amount)
+        .ok_or(OfferCoreError::OverflowError)?;
+
+    require!(
+        token_in_amount <= max_token_in_amount,
+        TakeOfferExactOutErrorCode::MaxTokenInExceeded
+    );
+
+    // Run the configured AML rules on the user and the derived payment
+    screen_take(
+        &ctx.accounts.state.screening_program,
+        ctx.accounts.screening_program.as_ref(),
+        &ctx.accounts.user.to_account_info(),
+        token_in_amount,
+    )?;
+
+    // Reject takes below the offer's minimum size
+    enforce_minimum_take(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        token_in_usd_price,
+    )?;
+
+    // Reject takes paying out zero or dust amounts of token_out
+    enforce_dust_threshold(
+        &offer,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Throttle the take while outstanding redemptions strain the redemption vault
+    enforce_redemption_stress_policy(
+        &ctx.accounts.state,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_in_program,
+        ctx.accounts.redemption_offer.as_ref(),
+        ctx.accounts.redemption_vault_token_in_account.as_ref(),
+    )?;
+
+    // Enforce the per-step volume cap
+    record_step_volume(&mut offer, result.token_out_amount)?;
+
+    // Track cumulative revenue and volume
+    record_take_totals(&mut offer, token_in_amount, result.token_in_fee_amount)?;
+
+    // Only approved transfer-hook mints receive the remaining accounts
+    let token_in_hook_accounts = resolve_transfer_hook_accounts(
+        &ctx.accounts.token_in_mint,
+        ctx.accounts
+            .token_in_hook_approval
+            .as_deref()
+            .map(|approval| &**approval),
+        ctx.remaining_accounts,
+    )?;
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    let vault_authority_seed = offer.vault_authority_seed(&ctx.accounts.offer.key());
+    let vault_token_in_before =
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?;
+    let fulfillment_mode = execute_token_operations(ExecTokenOpsParams {
+        // Token in params
+        token_in_program: &ctx.accounts.token_in_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: result.token_in_net_amount,
+        token_in_fee_amount: result.token_in_fee_amount,
+        token_in_transfer_fee: None,
+        token_in_authority: &ctx.accounts.user,
+        token_in_source_signer_seeds: None,
+        vault_authority_signer_seeds: Some(&[&[
+            seeds::OFFER_VAULT_AUTHORITY,
+            &vault_authority_seed,
+            &[ctx.bumps.vault_authority],
+        ]]),
+        token_in_source_account: &ctx.accounts.user_token_in_account,
+        token_in_destination_account: proceeds_destination(
+            &offer,
+            &ctx.accounts.vault_token_in_account,
+            ctx.accounts.boss_token_in_account.as_deref(),
+        )?,
+        token_in_burn_account: &ctx.accounts.vault_token_in_account,
+        token_in_burn_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_in_transfer_hook_accounts: token_in_hook_accounts,
+        // Token out params
+        token_out_program: &ctx.accounts.token_out_program,
+        token_out_mint: &ctx.accounts.token_out_mint,
+        token_out_amount: result.token_out_amount,
+        token_out_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_out_source_account: &ctx.accounts.vault_token_out_account,
+        token_out_destination_account: &ctx.accounts.user_token_out_account.to_account_info(),
+        mint_authority_pda: &ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_bump: &[ctx.bumps.mint_authority],
+        token_out_max_supply: ManagedMint::max_supply_or(
+            &ctx.accounts.managed_mint,
+            ctx.accounts.state.max_supply,
+        )?,
+        token_out_fulfillment_mode: offer.fulfillment_mode(),
+    })?;
+
+    // Credit the proceeds the take left in the offer vault to the offer
+    record_uncollected_proceeds(
+        &mut offer,
+        vault_token_in_before,
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?,
+    )?;
+
+    // Track the user's holdings and cost basis in token_out
+    record_user_position_purchase(
+        &mut ctx.accounts.user_position,
+        ctx.accounts.user.key(),
+        ctx.accounts.token_out_mint.key(),
+        ctx.bumps.user_position,
+        &result,
+    )?;
+
+    // Record the take for clawbacks when the offer is flagged
+    record_take_receipt(
+        &offer,
+        ctx.accounts.offer.key(),
+        ctx.accounts
+            .take_receipt
+            .as_deref_mut()
+            .map(|take_receipt| &mut **take_receipt),
+        ctx.bumps.take_receipt,
+        &mut ctx.accounts.user_position,
+        ctx.accounts.user.key(),
+        token_in_amount,
+        result.token_out_amount,
+    )?;
+
+    // Report the inventory left in the vault so monitoring can alert before it runs out
+    let vault_token_out_remaining =
+        token_account_amount(&ctx.accounts.vault_token_out_account.to_account_info())?;
+    let low_inventory = is_low_inventory(&offer, vault_token_out_remaining, fulfillment_mode);
+
+    msg!(
+        "Offer taken (exact out) - PDA: {}, token_in(+fee): {}(+{}), token_out: {}, user: {}, price: {}",
+        ctx.accounts.offer.key(),
+        result.token_in_net_amount,
+        result.token_in_fee_amount,
+        result.token_out_amount,
+        ctx.accounts.user.key,
+        u64_to_dec9(result.current_price)
+    );
+
+    emit!(OfferTakenEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        token_in_amount: result.token_in_net_amount,
+        token_out_amount: result.token_out_amount,
+        fee_amount: result.token_in_fee_amount,
+        user: ctx.accounts.user.key(),
+        fulfillment_mode,
+        vault_token_out_remaining,
+        low_inventory,
+    });
+
+    Ok(())
+}
+
+/// Error codes specific to the take_offer_exact_out instruction
+#[error_code]
+pub enum TakeOfferExactOutErrorCode {
+    /// The requested token_out amount is zero
+    #[msg("Invalid amount: token_out amount must be greater than zero")]
+    InvalidAmount,
+    /// The token_in owed for the requested token_out exceeds the user's maximum
+    #[msg("Required token_in exceeds the maximum")]
+    MaxTokenInExceeded,
+}
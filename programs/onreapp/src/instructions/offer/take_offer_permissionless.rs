@@ -1,14 +1,25 @@
 use crate::constants::seeds;
-use crate::instructions::offer::offer_utils::{process_offer_core, verify_offer_approval};
-use crate::instructions::Offer;
-use crate::state::State;
+use crate::instructions::offer::offer_utils::{
+    enforce_dust_threshold, enforce_minimum_take, enforce_offer_window,
+    enforce_redemption_stress_policy, load_token_in_usd_price, observe_nav, proceeds_destination,
+    process_offer_core_with_fee, record_step_volume, record_take_totals,
+    record_uncollected_proceeds, record_user_position_purchase, require_offer_decimals,
+    verify_offer_approval,
+};
+use crate::instructions::{FulfillmentMode, ManagedMint, NavHistory, Offer, UserPosition};
+use crate::state::{
+    BlocklistBucket, Config, PermissionlessAuthority, State, StateSchemaErrorCode,
+    TransferHookMintApproval,
+};
 use crate::utils::{
-    execute_token_operations, transfer_tokens, u64_to_dec9, ApprovalMessage, ExecTokenOpsParams,
+    execute_token_operations, resolve_transfer_hook_accounts, token_account_amount,
+    transfer_checked_with_hook, transfer_fee_for, transfer_tokens, u64_to_dec9, ApprovalMessage,
+    BalanceInvariants, ExecTokenOpsParams,
 };
 use crate::OfferCoreError;
 use anchor_lang::{prelude::*, solana_program::sysvar, Accounts};
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
 /// Error codes specific to the take_offer_permissionless instruction
 #[error_code]
@@ -22,6 +33,9 @@ pub enum TakeOfferPermissionlessErrorCode {
     /// The offer does not allow permissionless operations
     #[msg("Permissionless take offer not allowed")]
     PermissionlessNotAllowed,
+    /// The selected permissionless authority has not been initialized
+    #[msg("Permissionless authority not initialized")]
+    PermissionlessAuthorityNotInitialized,
 }
 
 /// Event emitted when an offer is successfully executed via permissionless flow
@@ -31,6 +45,8 @@ pub enum TakeOfferPermissionlessErrorCode {
 pub struct OfferTakenPermissionlessEvent {
     /// The PDA address of the offer that was executed
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Amount of token_in paid by the user after fee deduction
     pub token_in_amount: u64,
     /// Amount of token_out received by the user
@@ -39,6 +55,10 @@ pub struct OfferTakenPermissionlessEvent {
     pub fee_amount: u64,
     /// Public key of the user who executed the offer
     pub user: Pubkey,
+    /// Index of the permissionless authority the take was routed through
+    pub authority_index: u8,
+    /// How token_out was delivered to the user
+    pub fulfillment_mode: FulfillmentMode,
 }
 
 /// Account structure for executing offers via permissionless flow with intermediary routing
@@ -67,11 +87,17 @@ pub struct TakeOfferPermissionless<'info> {
     #[account(
         seeds = [seeds::STATE],
         bump = state.bump,
-        constraint = state.is_killed == false @ TakeOfferPermissionlessErrorCode::KillSwitchActivated,
-        has_one = boss @ TakeOfferPermissionlessErrorCode::InvalidBoss
+        constraint = !state.is_kill_switch_active()? @ TakeOfferPermissionlessErrorCode::KillSwitchActivated,
+        has_one = boss @ TakeOfferPermissionlessErrorCode::InvalidBoss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
+    /// Program-wide configuration providing the approval validity limit and the
+    /// seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account authorized to receive token_in payments
     ///
     /// Must match the boss stored in program state for security validation.
@@ -81,14 +107,16 @@ pub struct TakeOfferPermissionless<'info> {
     /// Program-derived authority that controls vault token operations
     ///
     /// This PDA manages token transfers and burning operations for the
-    /// burn/mint architecture when program has mint authority.
-    /// CHECK: PDA derivation is validated by seeds constraint
+    /// burn/mint architecture when program has mint authority. Offers with their
+    /// own vault derive it from the offer, all others share it.
+    /// CHECK: PDA derivation is validated by the handler
     pub vault_authority: UncheckedAccount<'info>,
 
     /// Vault account for temporary token_in storage during burn operations
     ///
     /// Used for burning input tokens when the program has mint authority
-    /// for efficient burn/mint token exchange architecture.
+    /// for efficient burn/mint token exchange architecture, and receives the
+    /// take proceeds when the offer routes them to the vault.
     #[account(
         mut,
         associated_token::mint = token_in_mint,
@@ -113,7 +141,9 @@ pub struct TakeOfferPermissionless<'info> {
     ///
     /// This PDA manages the intermediary accounts used for permissionless token
     /// routing, enabling secure transfers without direct user-boss relationships.
-    /// CHECK: PDA derivation is validated by seeds constraint
+    /// Selected by `authority_index`, so each distribution partner routes through
+    /// its own intermediary accounts.
+    /// CHECK: PDA derivation from `authority_index` is validated in the handler
     pub permissionless_authority: UncheckedAccount<'info>,
 
     /// Intermediary account for routing token_in payments
@@ -187,15 +217,15 @@ pub struct TakeOfferPermissionless<'info> {
 
     /// Boss's input token account for receiving payments
     ///
-    /// Final destination account where the boss receives token_in payments
-    /// from users taking offers via intermediary routing.
+    /// Required only when the offer pays take proceeds to the boss instead of
+    /// the offer vault.
     #[account(
         mut,
         associated_token::mint = token_in_mint,
         associated_token::authority = boss,
         associated_token::token_program = token_in_program
     )]
-    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub boss_token_in_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     /// Program-derived mint authority for direct token minting
     ///
@@ -204,6 +234,13 @@ pub struct TakeOfferPermissionless<'info> {
     /// CHECK: PDA derivation is validated through seeds constraint
     pub mint_authority: UncheckedAccount<'info>,
 
+    /// Managed mint registry entry of token_out, capping its supply when minted
+    ///
+    /// May be uninitialized when token_out is not registered with `add_managed_mint`.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(seeds = [seeds::MANAGED_MINT, token_out_mint.key().as_ref()], bump)]
+    pub managed_mint: UncheckedAccount<'info>,
+
     /// Instructions sysvar for approval signature verification
     ///
     /// Required for cryptographic verification of approval messages
@@ -212,6 +249,79 @@ pub struct TakeOfferPermissionless<'info> {
     #[account(address = sysvar::instructions::id())]
     pub instructions_sysvar: UncheckedAccount<'info>,
 
+    /// Oracle price update valuing token_in in USD
+    ///
+    /// Required only when the offer has an oracle configured via `set_offer_oracle`.
+    /// CHECK: Validated against the offer's oracle and parsed in the handler
+    pub token_in_oracle: Option<UncheckedAccount<'info>>,
+
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
+    /// Redemption offer redeeming token_out back into token_in
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// May be uninitialized when the pair has no redemption offer.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_out_mint.key().as_ref(),
+            token_in_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: Option<UncheckedAccount<'info>>,
+
+    /// Redemption vault's token_in account covering outstanding redemptions
+    ///
+    /// Required only while the redemption stress policy is enabled in program state.
+    /// CHECK: Validated against the redemption vault ATA address in the handler
+    pub redemption_vault_token_in_account: Option<UncheckedAccount<'info>>,
+
+    /// Boss approval of the token_in mint's transfer hook
+    ///
+    /// Required only when token_in is a Token-2022 transfer-hook mint, whose extra
+    /// hook accounts are then passed as remaining accounts.
+    #[account(
+        seeds = [seeds::TRANSFER_HOOK_MINT, token_in_mint.key().as_ref()],
+        bump = token_in_hook_approval.bump
+    )]
+    pub token_in_hook_approval: Option<Box<Account<'info, TransferHookMintApproval>>>,
+
+    /// Blocklist bucket covering the user, rejecting sanctioned users
+    ///
+    /// May be uninitialized when no address of the bucket was ever blocked.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [seeds::BLOCKLIST, &[BlocklistBucket::index_for(&user.key())]],
+        bump
+    )]
+    pub blocklist_bucket: UncheckedAccount<'info>,
+
+    /// Position of the user in token_out, updated with the take
+    ///
+    /// Created on the user's first take, with rent paid by the user.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserPosition::INIT_SPACE,
+        seeds = [
+            seeds::USER_POSITION,
+            token_out_mint.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
     /// The user executing the offer and paying for account creation
     #[account(mut)]
     pub user: Signer<'info>,
@@ -235,42 +345,89 @@ pub struct TakeOfferPermissionless<'info> {
 /// * `ctx` - The instruction context containing validated accounts
 /// * `token_in_amount` - Amount of token_in the user is willing to pay (including fees)
 /// * `approval_message` - Optional cryptographic approval from trusted authority
+/// * `authority_index` - Index of the permissionless authority routing the take
 ///
 /// # Process Flow
 /// 1. Validate offer allows permissionless operations
 /// 2. Verify approval requirements if offer needs approval
-/// 3. Calculate current price and token amounts
-/// 4. Execute atomic transfers through intermediary accounts
-/// 5. Emit event with transaction details
+/// 3. Calculate current price (oracle-adjusted if configured) and token amounts,
+///    charging the authority's fee override instead of the offer fee if set, and
+///    enforce the offer's minimum take size
+/// 4. Record the take against the per-step volume cap and the cumulative fee and
+///    volume counters
+/// 5. Execute atomic transfers through intermediary accounts, checking after each leg
+///    that every balance moved by exactly the computed amounts
+/// 6. Record the take in the user's token_out position, creating it on the first take
+/// 7. Emit event with transaction details
 ///
 /// # Returns
 /// * `Ok(())` - If the offer is successfully executed
 /// * `Err(PermissionlessNotAllowed)` - If offer doesn't allow permissionless operations
+/// * `Err(InvariantViolation)` - If a balance deviates from the computed amounts
+/// * `Err(PermissionlessAuthorityNotInitialized)` - If the selected authority was never initialized
 /// * `Err(_)` - If validation fails or token operations fail
 ///
 /// # Access Control
 /// - Only available for offers with allow_permissionless enabled
 /// - Kill switch prevents execution when activated
+/// - Users on the sanctioned-address blocklist are rejected
 /// - Approval verification when required
+/// - Transfer-hook token_in mints require a boss approval
 ///
 /// # Events
 /// * `TakeOfferPermissionlessEvent` - Emitted with execution details and routing information
 #[inline(never)]
-pub fn take_offer_permissionless(
-    ctx: Context<TakeOfferPermissionless>,
+pub fn take_offer_permissionless<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeOfferPermissionless<'info>>,
     token_in_amount: u64,
     approval_message: Option<ApprovalMessage>,
+    authority_index: u8,
 ) -> Result<()> {
-    let (va, va_bump) =
-        Pubkey::find_program_address(&[seeds::OFFER_VAULT_AUTHORITY], ctx.program_id);
+    let vault_authority_seed = ctx
+        .accounts
+        .offer
+        .load()?
+        .vault_authority_seed(&ctx.accounts.offer.key());
+    let (va, va_bump) = Pubkey::find_program_address(
+        &[seeds::OFFER_VAULT_AUTHORITY, &vault_authority_seed],
+        ctx.program_id,
+    );
     require_keys_eq!(va, ctx.accounts.vault_authority.key());
-    let (pa, pa_bump) =
-        Pubkey::find_program_address(&[seeds::PERMISSIONLESS_AUTHORITY], ctx.program_id);
+    let pa_seed = PermissionlessAuthority::seed(authority_index);
+    let (pa, pa_bump) = Pubkey::find_program_address(&[&pa_seed], ctx.program_id);
     require_keys_eq!(pa, ctx.accounts.permissionless_authority.key());
+    require_keys_eq!(
+        *ctx.accounts.permissionless_authority.owner,
+        *ctx.program_id,
+        TakeOfferPermissionlessErrorCode::PermissionlessAuthorityNotInitialized
+    );
+    let fee_override_bps = PermissionlessAuthority::try_deserialize(
+        &mut &ctx.accounts.permissionless_authority.try_borrow_data()?[..],
+    )?
+    .fee_override_bps;
     let (ma, ma_bump) = Pubkey::find_program_address(&[seeds::MINT_AUTHORITY], ctx.program_id);
     require_keys_eq!(ma, ctx.accounts.mint_authority.key());
 
-    let offer = ctx.accounts.offer.load()?;
+    // Reject sanctioned users
+    BlocklistBucket::require_not_blocked(&ctx.accounts.blocklist_bucket, &ctx.accounts.user.key())?;
+
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    // Reject takes outside the offer's validity window
+    enforce_offer_window(&offer)?;
+
+    // Takes of clawback-enabled offers need a receipt, which this flow does not write
+    require!(
+        !offer.clawback_enabled(),
+        OfferCoreError::ClawbackOfferNotSupported
+    );
+
+    // Reject mints priced with other decimals than the offer was made for
+    require_offer_decimals(
+        &offer,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
 
     // Validate offer mints
     require_keys_eq!(
@@ -295,66 +452,196 @@ pub fn take_offer_permissionless(
         &approval_message,
         ctx.program_id,
         &ctx.accounts.user.key(),
-        &ctx.accounts.state.approver1,
-        &ctx.accounts.state.approver2,
+        &ctx.accounts.state,
         &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.config,
     )?;
 
-    // Use shared core processing logic
-    let result = process_offer_core(
+    // Value token_in through the offer's oracle if configured
+    let token_in_usd_price =
+        load_token_in_usd_price(&offer, ctx.accounts.token_in_oracle.as_ref())?;
+
+    // Use shared core processing logic, charging the authority's fee override if set
+    let result = process_offer_core_with_fee(
         &offer,
         token_in_amount,
+        fee_override_bps.unwrap_or(offer.fee_basis_points),
         &ctx.accounts.token_in_mint,
         &ctx.accounts.token_out_mint,
+        token_in_usd_price,
+        ctx.accounts.config.seconds_per_year,
     )?;
 
-    // 1. Transfer token_in from user to permissionless intermediary
-    transfer_tokens(
+    // Reject takes below the offer's minimum size
+    enforce_minimum_take(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        token_in_usd_price,
+    )?;
+
+    // Reject takes paying out zero or dust amounts of token_out
+    enforce_dust_threshold(
+        &offer,
+        &result,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+    )?;
+
+    // Throttle the take while outstanding redemptions strain the redemption vault
+    enforce_redemption_stress_policy(
+        &ctx.accounts.state,
+        &result,
         &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_in_program,
+        ctx.accounts.redemption_offer.as_ref(),
+        ctx.accounts.redemption_vault_token_in_account.as_ref(),
+    )?;
+
+    // Enforce the per-step volume cap
+    record_step_volume(&mut offer, result.token_out_amount)?;
+
+    // Track cumulative revenue and volume
+    record_take_totals(
+        &mut offer,
+        result
+            .token_in_net_amount
+            .checked_add(result.token_in_fee_amount)
+            .ok_or(OfferCoreError::OverflowError)?,
+        result.token_in_fee_amount,
+    )?;
+
+    // Only approved transfer-hook mints receive the remaining accounts
+    let token_in_hook_accounts = resolve_transfer_hook_accounts(
+        &ctx.accounts.token_in_mint,
+        ctx.accounts
+            .token_in_hook_approval
+            .as_deref()
+            .map(|approval| &**approval),
+        ctx.remaining_accounts,
+    )?;
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+
+    // 1. Transfer token_in from user to permissionless intermediary
+    let mut token_in_invariants = BalanceInvariants::default();
+    token_in_invariants.expect_transfer(
+        &ctx.accounts.user_token_in_account.to_account_info(),
+        &ctx.accounts
+            .permissionless_token_in_account
+            .to_account_info(),
+        token_in_amount,
+        transfer_fee_for(&ctx.accounts.token_in_mint, token_in_amount)?,
+    )?;
+    transfer_checked_with_hook(
         &ctx.accounts.token_in_program,
-        &ctx.accounts.user_token_in_account,
-        &ctx.accounts.permissionless_token_in_account,
-        &ctx.accounts.user,
+        TransferChecked {
+            mint: ctx.accounts.token_in_mint.to_account_info(),
+            from: ctx.accounts.user_token_in_account.to_account_info(),
+            to: ctx
+                .accounts
+                .permissionless_token_in_account
+                .to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
         None,
         token_in_amount,
+        ctx.accounts.token_in_mint.decimals,
+        token_in_hook_accounts,
     )?;
+    token_in_invariants.verify()?;
     msg!("Transferred token_in from user to permissionless intermediary");
 
     // 2. Execute token operations (transfer + burn for token_in, transfer for token_out)
-    execute_token_operations(ExecTokenOpsParams {
+    let vault_token_in_before =
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?;
+    let fulfillment_mode = execute_token_operations(ExecTokenOpsParams {
         // Token in params
         token_in_program: &ctx.accounts.token_in_program,
         token_in_mint: &ctx.accounts.token_in_mint,
         token_in_net_amount: result.token_in_net_amount,
         token_in_fee_amount: result.token_in_fee_amount,
+        token_in_transfer_fee: None,
         token_in_authority: &ctx.accounts.permissionless_authority.to_account_info(),
-        token_in_source_signer_seeds: Some(&[&[seeds::PERMISSIONLESS_AUTHORITY, &[pa_bump]]]),
-        vault_authority_signer_seeds: Some(&[&[seeds::OFFER_VAULT_AUTHORITY, &[va_bump]]]),
+        token_in_source_signer_seeds: Some(&[&[&pa_seed, &[pa_bump]]]),
+        vault_authority_signer_seeds: Some(&[&[
+            seeds::OFFER_VAULT_AUTHORITY,
+            &vault_authority_seed,
+            &[va_bump],
+        ]]),
         token_in_source_account: &ctx.accounts.permissionless_token_in_account,
-        token_in_destination_account: &ctx.accounts.boss_token_in_account,
+        token_in_destination_account: proceeds_destination(
+            &offer,
+            &ctx.accounts.vault_token_in_account,
+            ctx.accounts.boss_token_in_account.as_deref(),
+        )?,
         token_in_burn_account: &ctx.accounts.vault_token_in_account,
         token_in_burn_authority: &ctx.accounts.vault_authority.to_account_info(),
+        token_in_transfer_hook_accounts: token_in_hook_accounts,
         // Token out params
         token_out_program: &ctx.accounts.token_out_program,
         token_out_mint: &ctx.accounts.token_out_mint,
         token_out_amount: result.token_out_amount,
         token_out_authority: &ctx.accounts.vault_authority.to_account_info(),
         token_out_source_account: &ctx.accounts.vault_token_out_account,
-        token_out_destination_account: &ctx.accounts.permissionless_token_out_account,
+        token_out_destination_account: &ctx
+            .accounts
+            .permissionless_token_out_account
+            .to_account_info(),
         mint_authority_pda: &ctx.accounts.mint_authority.to_account_info(),
         mint_authority_bump: &[ma_bump],
-        token_out_max_supply: ctx.accounts.state.max_supply,
+        token_out_max_supply: ManagedMint::max_supply_or(
+            &ctx.accounts.managed_mint,
+            ctx.accounts.state.max_supply,
+        )?,
+        token_out_fulfillment_mode: offer.fulfillment_mode(),
     })?;
 
+    // Credit the proceeds the take left in the offer vault to the offer
+    record_uncollected_proceeds(
+        &mut offer,
+        vault_token_in_before,
+        token_account_amount(&ctx.accounts.vault_token_in_account.to_account_info())?,
+    )?;
+
+    // 3. Forward token_out from permissionless intermediary to user
+    let mut token_out_invariants = BalanceInvariants::default();
+    token_out_invariants.expect_transfer(
+        &ctx.accounts
+            .permissionless_token_out_account
+            .to_account_info(),
+        &ctx.accounts.user_token_out_account.to_account_info(),
+        result.token_out_amount,
+        transfer_fee_for(&ctx.accounts.token_out_mint, result.token_out_amount)?,
+    )?;
     transfer_tokens(
         &ctx.accounts.token_out_mint,
         &ctx.accounts.token_out_program,
         &ctx.accounts.permissionless_token_out_account,
         &ctx.accounts.user_token_out_account,
         &ctx.accounts.permissionless_authority.to_account_info(),
-        Some(&[&[seeds::PERMISSIONLESS_AUTHORITY, &[pa_bump]]]),
+        Some(&[&[&pa_seed, &[pa_bump]]]),
         result.token_out_amount,
     )?;
+    token_out_invariants.verify()?;
+
+    // Track the user's holdings and cost basis in token_out
+    record_user_position_purchase(
+        &mut ctx.accounts.user_position,
+        ctx.accounts.user.key(),
+        ctx.accounts.token_out_mint.key(),
+        ctx.bumps.user_position,
+        &result,
+    )?;
 
     msg!(
         "Offer taken (permissionless) - PDA: {}, token_in(excluding fee): {}, fee: {}, token_out: {}, user: {}, price: {}",
@@ -368,10 +655,13 @@ pub fn take_offer_permissionless(
 
     emit!(OfferTakenPermissionlessEvent {
         offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
         token_in_amount: result.token_in_net_amount,
         token_out_amount: result.token_out_amount,
         fee_amount: result.token_in_fee_amount,
         user: ctx.accounts.user.key(),
+        authority_index,
+        fulfillment_mode,
     });
 
     Ok(())
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Receipt of a take of a clawback-enabled offer
+///
+/// Written by the take and consumed by `clawback_take`, which reverses exactly the
+/// recorded amounts and closes the receipt. Receipts are addressed by the offer, the
+/// user and an index taken from the user's position, so every take gets its own.
+#[account]
+#[derive(InitSpace)]
+pub struct TakeReceipt {
+    /// The offer PDA the take was executed against
+    pub offer: Pubkey,
+    /// The user who took the offer and paid the receipt's rent
+    pub user: Pubkey,
+    /// Index of the receipt among the user's receipts of the token_out mint
+    pub index: u64,
+    /// Unix timestamp of the take
+    pub take_timestamp: u64,
+    /// Amount of token_in paid by the take, including the fee
+    pub token_in_amount: u64,
+    /// Amount of token_out received by the take
+    pub token_out_amount: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+}
@@ -0,0 +1,90 @@
+use crate::instructions::offer::offer_utils::find_active_vector_at;
+use crate::instructions::Offer;
+use anchor_lang::prelude::*;
+
+/// Event emitted when the active pricing vector of an offer has rolled over
+///
+/// Provides push-style notifications of schedule changes to listeners.
+#[event]
+pub struct ActiveVectorChangedEvent {
+    /// The PDA address of the ticked offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Start time of the previously recorded active vector (0 = none)
+    pub previous_start_time: u64,
+    /// Start time of the now active vector (0 = none)
+    pub active_start_time: u64,
+    /// Unix timestamp of the tick that observed the change
+    pub timestamp: u64,
+}
+
+/// Account structure for ticking an offer
+///
+/// No signer is required beyond the fee payer since the recorded vector is derived
+/// from the offer's own pricing schedule.
+#[derive(Accounts)]
+pub struct TickOffer<'info> {
+    /// The offer whose active vector is tracked
+    #[account(mut)]
+    pub offer: AccountLoader<'info, Offer>,
+}
+
+/// Records the active pricing vector of an offer and signals a rollover
+///
+/// The active vector is identified by its start time. When it differs from the one
+/// recorded by the previous tick, either because a new vector started or because
+/// the active vector was deleted, the change is recorded and announced. Ticking an
+/// offer whose active vector is unchanged is a no-op.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(true)` - If the active vector changed since the previous tick
+/// * `Ok(false)` - If the active vector is unchanged
+///
+/// # Access Control
+/// - Permissionless: any keeper may tick offers
+///
+/// # Effects
+/// - Updates `last_active_vector_start_time` of the offer on a change
+///
+/// # Events
+/// * `ActiveVectorChangedEvent` - Emitted with the previous and new active vector
+pub fn tick_offer(ctx: Context<TickOffer>) -> Result<bool> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let active_start_time = find_active_vector_at(&offer, current_time)
+        .map(|vector| vector.start_time)
+        .unwrap_or(0);
+    let previous_start_time = offer.last_active_vector_start_time;
+
+    if active_start_time == previous_start_time {
+        msg!(
+            "Offer ticked: {}, active vector unchanged",
+            ctx.accounts.offer.key()
+        );
+        return Ok(false);
+    }
+
+    offer.last_active_vector_start_time = active_start_time;
+
+    msg!(
+        "Offer ticked: {}, active vector start time {} -> {}",
+        ctx.accounts.offer.key(),
+        previous_start_time,
+        active_start_time
+    );
+
+    emit!(ActiveVectorChangedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        previous_start_time,
+        active_start_time,
+        timestamp: current_time,
+    });
+
+    Ok(true)
+}
@@ -0,0 +1,123 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's dust threshold is successfully updated
+///
+/// Provides transparency for tracking changes to take size limits.
+#[event]
+pub struct OfferDustThresholdUpdatedEvent {
+    /// The PDA address of the offer whose threshold was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous minimum token_out per take (0 = only non-zero payouts)
+    pub old_min_token_out_amount: u64,
+    /// New minimum token_out per take (0 = only non-zero payouts)
+    pub new_min_token_out_amount: u64,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's dust threshold
+///
+/// This struct defines the accounts required to modify the minimum amount of
+/// token_out a single take must pay out. Only the boss can update the threshold.
+#[derive(Accounts)]
+pub struct UpdateOfferDustThreshold<'info> {
+    /// The offer account whose dust threshold will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offer dust thresholds
+    pub boss: Signer<'info>,
+}
+
+/// Updates the minimum token_out a single take of the offer must pay out
+///
+/// Takes paying out zero token_out are rejected regardless of the threshold. A
+/// higher threshold also keeps economically meaningless fills out of the vault
+/// and the offer statistics.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_min_token_out_amount` - Minimum token_out per take in base units
+///   (0 = only non-zero payouts)
+///
+/// # Returns
+/// * `Ok(())` - If the threshold is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's min_token_out_amount field
+/// - Affects all future takes of the offer
+///
+/// # Events
+/// * `OfferDustThresholdUpdatedEvent` - Emitted with old and new thresholds
+pub fn update_offer_dust_threshold(
+    ctx: Context<UpdateOfferDustThreshold>,
+    new_min_token_out_amount: u64,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_min_token_out_amount = offer.min_token_out_amount;
+    offer.min_token_out_amount = new_min_token_out_amount;
+
+    msg!(
+        "Offer dust threshold updated for offer: {}, old: {}, new: {}",
+        ctx.accounts.offer.key(),
+        old_min_token_out_amount,
+        new_min_token_out_amount
+    );
+
+    emit!(OfferDustThresholdUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_min_token_out_amount,
+        new_min_token_out_amount,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
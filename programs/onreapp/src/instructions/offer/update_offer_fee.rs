@@ -1,6 +1,6 @@
 use crate::constants::{seeds, MAX_BASIS_POINTS};
 use crate::instructions::Offer;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::OfferCoreError;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
@@ -12,6 +12,8 @@ use anchor_spl::token_interface::Mint;
 pub struct OfferFeeUpdatedEvent {
     /// The PDA address of the offer whose fee was updated
     pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
     /// Previous fee in basis points (10000 = 100%)
     pub old_fee_basis_points: u16,
     /// New fee in basis points (10000 = 100%)
@@ -61,7 +63,9 @@ pub struct UpdateOfferFee<'info> {
     #[account(
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss)]
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
 
     /// The boss account authorized to update offer fees
@@ -117,6 +121,7 @@ pub fn update_offer_fee(ctx: Context<UpdateOfferFee>, new_fee_basis_points: u16)
 
     emit!(OfferFeeUpdatedEvent {
         offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
         old_fee_basis_points,
         new_fee_basis_points,
         boss: ctx.accounts.boss.key(),
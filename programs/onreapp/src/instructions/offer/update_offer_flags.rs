@@ -0,0 +1,134 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's access flags are successfully updated
+///
+/// Provides transparency for tracking who may take the offer.
+#[event]
+pub struct OfferFlagsUpdatedEvent {
+    /// The PDA address of the offer whose flags were updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous approval requirement
+    pub old_needs_approval: bool,
+    /// New approval requirement
+    pub new_needs_approval: bool,
+    /// Previous permissionless setting
+    pub old_allow_permissionless: bool,
+    /// New permissionless setting
+    pub new_allow_permissionless: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's access flags
+///
+/// This struct defines the accounts required to change whether an offer requires
+/// approval and whether it can be taken permissionlessly. Only the boss can update
+/// offers.
+#[derive(Accounts)]
+pub struct UpdateOfferFlags<'info> {
+    /// The offer account whose flags will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offers
+    pub boss: Signer<'info>,
+}
+
+/// Updates whether an offer requires approval and allows permissionless takes
+///
+/// Flags are initially set by `make_offer`. Updating them in place lets an offer move
+/// between permissioned and public access while keeping its PDA, pricing vectors and
+/// statistics, which closing and recreating it would reset. Both flags are written on
+/// every call.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `needs_approval` - Whether takes of the offer require boss approval
+/// * `allow_permissionless` - Whether the offer can be taken through `take_offer_permissionless`
+///
+/// # Returns
+/// * `Ok(())` - If the flags are successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's approval and permissionless flags
+/// - Affects all future takes of the offer
+///
+/// # Events
+/// * `OfferFlagsUpdatedEvent` - Emitted with old and new flags
+pub fn update_offer_flags(
+    ctx: Context<UpdateOfferFlags>,
+    needs_approval: bool,
+    allow_permissionless: bool,
+) -> Result<()> {
+    let mut offer = ctx.accounts.offer.load_mut()?;
+
+    let old_needs_approval = offer.needs_approval();
+    let old_allow_permissionless = offer.allow_permissionless();
+    offer.set_approval(needs_approval);
+    offer.set_permissionless(allow_permissionless);
+
+    msg!(
+        "Offer flags updated for offer: {}, needs approval: {} -> {}, permissionless: {} -> {}",
+        ctx.accounts.offer.key(),
+        old_needs_approval,
+        needs_approval,
+        old_allow_permissionless,
+        allow_permissionless
+    );
+
+    emit!(OfferFlagsUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_needs_approval,
+        new_needs_approval: needs_approval,
+        old_allow_permissionless,
+        new_allow_permissionless: allow_permissionless,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's low inventory threshold is successfully updated
+///
+/// Provides transparency for tracking changes to inventory alerting.
+#[event]
+pub struct OfferLowInventoryThresholdUpdatedEvent {
+    /// The PDA address of the offer whose threshold was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous vault token_out balance flagged as low inventory (0 = no alerts)
+    pub old_low_inventory_threshold: u64,
+    /// New vault token_out balance flagged as low inventory (0 = no alerts)
+    pub new_low_inventory_threshold: u64,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's low inventory threshold
+///
+/// This struct defines the accounts required to modify the vault token_out balance
+/// at which takes flag low inventory. Only the boss can update the threshold.
+#[derive(Accounts)]
+pub struct UpdateOfferLowInventoryThreshold<'info> {
+    /// The offer account whose low inventory threshold will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offer low inventory thresholds
+    pub boss: Signer<'info>,
+}
+
+/// Updates the vault token_out balance at which takes of the offer flag low inventory
+///
+/// Takes report the vault balance left after them and set `low_inventory` in
+/// `OfferTakenEvent` once it is at or below the threshold, so monitoring can alert
+/// before the vault runs dry. The threshold does not restrict takes.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_low_inventory_threshold` - Vault token_out balance in base units
+///   (0 = no alerts)
+///
+/// # Returns
+/// * `Ok(())` - If the threshold is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's low_inventory_threshold field
+/// - Affects the events of all future takes of the offer
+///
+/// # Events
+/// * `OfferLowInventoryThresholdUpdatedEvent` - Emitted with old and new thresholds
+pub fn update_offer_low_inventory_threshold(
+    ctx: Context<UpdateOfferLowInventoryThreshold>,
+    new_low_inventory_threshold: u64,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_low_inventory_threshold = offer.low_inventory_threshold;
+    offer.low_inventory_threshold = new_low_inventory_threshold;
+
+    msg!(
+        "Offer low inventory threshold updated for offer: {}, old: {}, new: {}",
+        ctx.accounts.offer.key(),
+        old_low_inventory_threshold,
+        new_low_inventory_threshold
+    );
+
+    emit!(OfferLowInventoryThresholdUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_low_inventory_threshold,
+        new_low_inventory_threshold,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
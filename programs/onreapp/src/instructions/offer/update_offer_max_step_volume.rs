@@ -0,0 +1,125 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's per-step volume cap is successfully updated
+///
+/// Provides transparency for tracking changes to take size limits.
+#[event]
+pub struct OfferMaxStepVolumeUpdatedEvent {
+    /// The PDA address of the offer whose cap was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous maximum token_out volume per pricing step (0 = no cap)
+    pub old_max_step_volume: u64,
+    /// New maximum token_out volume per pricing step (0 = no cap)
+    pub new_max_step_volume: u64,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's per-step volume cap
+///
+/// This struct defines the accounts required to modify the maximum amount of
+/// token_out that can be taken within a single pricing step. Only the boss can
+/// update the cap.
+#[derive(Accounts)]
+pub struct UpdateOfferMaxStepVolume<'info> {
+    /// The offer account whose volume cap will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses
+    /// and contains the step volume tracking fields.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offer volume caps
+    pub boss: Signer<'info>,
+}
+
+/// Updates the maximum token_out volume that can be taken per pricing step
+///
+/// Large takes placed right before a step boundary lock in the current price while
+/// the next step is already known. Capping the volume per step bounds how much of
+/// the upcoming price move a single step can hand out. The running counter is reset
+/// automatically at each step boundary and is not modified by this instruction.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_max_step_volume` - Maximum token_out amount per step in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the cap is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's max_step_volume field
+/// - Affects all future takes of the offer
+///
+/// # Events
+/// * `OfferMaxStepVolumeUpdatedEvent` - Emitted with old and new cap values
+pub fn update_offer_max_step_volume(
+    ctx: Context<UpdateOfferMaxStepVolume>,
+    new_max_step_volume: u64,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_max_step_volume = offer.max_step_volume;
+    offer.max_step_volume = new_max_step_volume;
+
+    msg!(
+        "Offer max step volume updated for offer: {}, old: {}, new: {}",
+        ctx.accounts.offer.key(),
+        old_max_step_volume,
+        new_max_step_volume
+    );
+
+    emit!(OfferMaxStepVolumeUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_max_step_volume,
+        new_max_step_volume,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
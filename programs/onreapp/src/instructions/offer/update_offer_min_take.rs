@@ -0,0 +1,135 @@
+use crate::constants::seeds;
+use crate::instructions::{MinimumDenomination, Offer};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's minimum take size is successfully updated
+///
+/// Provides transparency for tracking changes to take size limits.
+#[event]
+pub struct OfferMinTakeUpdatedEvent {
+    /// The PDA address of the offer whose minimum was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous minimum take size (0 = no minimum)
+    pub old_min_take_amount: u64,
+    /// Previous unit of the minimum take size
+    pub old_denomination: MinimumDenomination,
+    /// New minimum take size (0 = no minimum)
+    pub new_min_take_amount: u64,
+    /// New unit of the minimum take size
+    pub new_denomination: MinimumDenomination,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's minimum take size
+///
+/// This struct defines the accounts required to modify the smallest take the
+/// offer accepts. Only the boss can update the minimum.
+#[derive(Accounts)]
+pub struct UpdateOfferMinTake<'info> {
+    /// The offer account whose minimum take size will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offer take limits
+    pub boss: Signer<'info>,
+}
+
+/// Updates the minimum size of a single take of an offer
+///
+/// A `Token` minimum is compared against the gross token_in amount in base units. A
+/// `Quote` minimum is a USD amount with scale=9 (e.g. 1_000_000_000_000 = $1,000)
+/// that takes are valued against at the current token_in USD price, so it keeps
+/// its value when the token_in price or NAV moves.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `min_take_amount` - Minimum take size in the chosen unit (0 = no minimum)
+/// * `denomination` - Unit the minimum is expressed in
+///
+/// # Returns
+/// * `Ok(())` - If the minimum is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's min_take_amount and min_take_denomination fields
+/// - Affects all future takes of the offer
+///
+/// # Events
+/// * `OfferMinTakeUpdatedEvent` - Emitted with old and new minimum values
+pub fn update_offer_min_take(
+    ctx: Context<UpdateOfferMinTake>,
+    min_take_amount: u64,
+    denomination: MinimumDenomination,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_min_take_amount = offer.min_take_amount;
+    let old_denomination = offer.min_take_denomination();
+    offer.min_take_amount = min_take_amount;
+    offer.set_min_take_denomination(denomination);
+
+    msg!(
+        "Offer min take updated for offer: {}, old: {} ({:?}), new: {} ({:?})",
+        ctx.accounts.offer.key(),
+        old_min_take_amount,
+        old_denomination,
+        min_take_amount,
+        denomination
+    );
+
+    emit!(OfferMinTakeUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_min_take_amount,
+        old_denomination,
+        new_min_take_amount: min_take_amount,
+        new_denomination: denomination,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when an offer's validity window is successfully updated
+///
+/// Provides transparency for tracking scheduled offer launches and expiries.
+#[event]
+pub struct OfferValidityUpdatedEvent {
+    /// The PDA address of the offer whose window was updated
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// Previous timestamp from which the offer can be taken (0 = immediately)
+    pub old_valid_from: u64,
+    /// Previous timestamp from which takes are rejected (0 = never expires)
+    pub old_valid_until: u64,
+    /// New timestamp from which the offer can be taken (0 = immediately)
+    pub new_valid_from: u64,
+    /// New timestamp from which takes are rejected (0 = never expires)
+    pub new_valid_until: u64,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating an offer's validity window
+///
+/// This struct defines the accounts required to reschedule when an offer can be
+/// taken. Only the boss can update the window.
+#[derive(Accounts)]
+pub struct UpdateOfferValidity<'info> {
+    /// The offer account whose validity window will be updated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint account for offer validation
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: InterfaceAccount<'info, Mint>,
+
+    /// The output token mint account for offer validation
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update offer validity windows
+    pub boss: Signer<'info>,
+}
+
+/// Updates the window in which an offer can be taken
+///
+/// Takes are rejected before `valid_from` and from `valid_until` on, letting the
+/// boss schedule a launch or let an offer lapse without closing it. The window only
+/// restricts takes; vectors, vault operations and views keep working outside of it.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_valid_from` - Unix timestamp from which the offer can be taken (0 = immediately)
+/// * `new_valid_until` - Unix timestamp from which takes are rejected (0 = never expires)
+///
+/// # Returns
+/// * `Ok(())` - If the window is successfully updated
+/// * `Err(UpdateOfferValidityErrorCode::InvalidValidityWindow)` - If `new_valid_until` is
+///   set but not after `new_valid_from`
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the offer's valid_from and valid_until fields
+/// - Affects all future takes of the offer
+///
+/// # Events
+/// * `OfferValidityUpdatedEvent` - Emitted with old and new windows
+pub fn update_offer_validity(
+    ctx: Context<UpdateOfferValidity>,
+    new_valid_from: u64,
+    new_valid_until: u64,
+) -> Result<()> {
+    require!(
+        new_valid_until == 0 || new_valid_until > new_valid_from,
+        UpdateOfferValidityErrorCode::InvalidValidityWindow
+    );
+
+    let offer = &mut ctx.accounts.offer.load_mut()?;
+
+    let old_valid_from = offer.valid_from;
+    let old_valid_until = offer.valid_until;
+    offer.valid_from = new_valid_from;
+    offer.valid_until = new_valid_until;
+
+    msg!(
+        "Offer validity updated for offer: {}, old: {}..{}, new: {}..{}",
+        ctx.accounts.offer.key(),
+        old_valid_from,
+        old_valid_until,
+        new_valid_from,
+        new_valid_until
+    );
+
+    emit!(OfferValidityUpdatedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        old_valid_from,
+        old_valid_until,
+        new_valid_from,
+        new_valid_until,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for offer validity window updates
+#[error_code]
+pub enum UpdateOfferValidityErrorCode {
+    /// The offer would expire before or when it becomes takeable
+    #[msg("Invalid validity window: valid_until must be after valid_from")]
+    InvalidValidityWindow,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+/// Error codes for user position bookkeeping
+#[error_code]
+pub enum UserPositionErrorCode {
+    /// Arithmetic overflow while updating the position
+    #[msg("User position math overflow")]
+    MathOverflow,
+}
+
+/// Aggregate holdings and cost basis of a user in one mint
+///
+/// Created lazily by the user's first take or instant redemption, with rent paid by
+/// the user, and updated by every take and redemption that passes it. One position
+/// exists per mint and user; takes update the position of their token_out mint and
+/// redemptions that of their token_in mint.
+#[account]
+#[derive(InitSpace)]
+pub struct UserPosition {
+    /// The user the position belongs to
+    pub user: Pubkey,
+    /// The tracked mint, such as ONyc
+    pub mint: Pubkey,
+    /// Cumulative amount of the mint bought through takes
+    pub total_purchased: u64,
+    /// Average take price with scale=9, weighted by the amount bought at each price
+    pub average_entry_price: u64,
+    /// Cumulative amount of the mint redeemed
+    pub total_redeemed: u64,
+    /// Unix timestamp of the last take or redemption recorded
+    pub last_updated: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Number of take receipts written for the user, the index of the next one
+    pub take_receipt_count: u64,
+    /// Reserved space for future fields
+    pub reserved: [u8; 24],
+}
+
+impl UserPosition {
+    /// Binds a freshly created position to its user and mint
+    ///
+    /// Does nothing for positions that were already initialized.
+    pub fn initialize_if_needed(&mut self, user: Pubkey, mint: Pubkey, bump: u8) {
+        if self.user == Pubkey::default() {
+            self.user = user;
+            self.mint = mint;
+            self.bump = bump;
+        }
+    }
+
+    /// Returns the amount bought through takes and not redeemed since
+    ///
+    /// Tokens received or sent outside of the program are not tracked.
+    pub fn holdings(&self) -> u64 {
+        self.total_purchased.saturating_sub(self.total_redeemed)
+    }
+
+    /// Records a take buying `amount` at `price`, folding it into the average entry price
+    pub fn record_purchase(&mut self, amount: u64, price: u64, timestamp: u64) -> Result<()> {
+        let total_purchased = self
+            .total_purchased
+            .checked_add(amount)
+            .ok_or(UserPositionErrorCode::MathOverflow)?;
+        if total_purchased > 0 {
+            let total_cost = (self.average_entry_price as u128)
+                .checked_mul(self.total_purchased as u128)
+                .and_then(|cost| cost.checked_add((price as u128).checked_mul(amount as u128)?))
+                .ok_or(UserPositionErrorCode::MathOverflow)?;
+            self.average_entry_price = u64::try_from(total_cost / total_purchased as u128)
+                .map_err(|_| UserPositionErrorCode::MathOverflow)?;
+        }
+        self.total_purchased = total_purchased;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    /// Records a redemption of `amount`
+    pub fn record_redemption(&mut self, amount: u64, timestamp: u64) -> Result<()> {
+        self.total_redeemed = self
+            .total_redeemed
+            .checked_add(amount)
+            .ok_or(UserPositionErrorCode::MathOverflow)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a settlement mint is added to a redemption offer's allowlist
+///
+/// Provides transparency for tracking which mints redemptions may be settled in.
+#[event]
+pub struct RedemptionSettlementMintAddedEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// The newly allowlisted settlement mint
+    pub settlement_mint: Pubkey,
+    /// The boss who added the settlement mint
+    pub boss: Pubkey,
+}
+
+/// Account structure for allowlisting a settlement mint on a redemption offer
+///
+/// This struct defines the accounts required to allow redemptions of a redemption
+/// offer to be settled in an alternative stable token. The redemption vault account
+/// for the settlement mint is created if needed.
+#[derive(Accounts)]
+pub struct AddRedemptionSettlementMint<'info> {
+    /// The redemption offer whose settlement allowlist is extended
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program-derived authority that controls redemption offer vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The settlement mint to allowlist (e.g., USDT)
+    ///
+    /// Must differ from the redemption offer's token_in and token_out mints.
+    #[account(
+        constraint = settlement_mint.key() != redemption_offer.token_in_mint
+            && settlement_mint.key() != redemption_offer.token_out_mint
+            @ AddRedemptionSettlementMintErrorCode::InvalidSettlementMint
+    )]
+    pub settlement_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the settlement mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Redemption vault account for settlement token payouts
+    ///
+    /// Created automatically if needed.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = settlement_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_settlement_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to manage settlement mints and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a settlement mint to a redemption offer's allowlist
+///
+/// Once allowlisted, the redemption admin may pay out a redemption request in the
+/// settlement mint instead of the offer's token_out_mint. The payout is converted
+/// at the same NAV using the settlement mint's decimals.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the settlement mint is successfully added
+/// * `Err(AddRedemptionSettlementMintErrorCode::SettlementMintAlreadyExists)` - If the mint is already allowlisted
+/// * `Err(AddRedemptionSettlementMintErrorCode::MaxSettlementMintsReached)` - If the allowlist is full
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Adds the settlement mint to the first available allowlist slot
+/// - Initializes the redemption vault account of the settlement mint if needed
+///
+/// # Events
+/// * `RedemptionSettlementMintAddedEvent` - Emitted with the added settlement mint
+pub fn add_redemption_settlement_mint(ctx: Context<AddRedemptionSettlementMint>) -> Result<()> {
+    let settlement_mint = ctx.accounts.settlement_mint.key();
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    require!(
+        !redemption_offer.settlement_mints.contains(&settlement_mint),
+        AddRedemptionSettlementMintErrorCode::SettlementMintAlreadyExists
+    );
+
+    let slot = redemption_offer
+        .settlement_mints
+        .iter_mut()
+        .find(|mint| **mint == Pubkey::default())
+        .ok_or(AddRedemptionSettlementMintErrorCode::MaxSettlementMintsReached)?;
+    *slot = settlement_mint;
+
+    msg!(
+        "Settlement mint {} added to redemption offer {}",
+        settlement_mint,
+        ctx.accounts.redemption_offer.key()
+    );
+
+    emit!(RedemptionSettlementMintAddedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        settlement_mint,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for adding redemption settlement mints
+#[error_code]
+pub enum AddRedemptionSettlementMintErrorCode {
+    /// The settlement mint repeats the offer's token_in or token_out mint
+    #[msg("Invalid settlement mint")]
+    InvalidSettlementMint,
+
+    /// The settlement mint is already present in the allowlist
+    #[msg("Settlement mint already exists in the allowlist")]
+    SettlementMintAlreadyExists,
+
+    /// The allowlist has reached its maximum capacity
+    #[msg("Maximum number of settlement mints (3) reached")]
+    MaxSettlementMintsReached,
+}
@@ -0,0 +1,141 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{RedemptionOffer, RedemptionRequest};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a redemption request is assigned to another redeemer
+///
+/// Provides transparency for tracking who a pending redemption request pays out to.
+#[event]
+pub struct RedemptionRequestAssignedEvent {
+    /// The PDA address of the assigned redemption request
+    pub redemption_request_pda: Pubkey,
+    /// Reference to the redemption offer
+    pub redemption_offer: Pubkey,
+    /// Redeemer who held the request before the assignment
+    pub previous_redeemer: Pubkey,
+    /// Redeemer who holds the request after the assignment
+    pub new_redeemer: Pubkey,
+    /// Amount of token_in tokens locked by the request
+    pub amount: u64,
+}
+
+/// Account structure for assigning a redemption request to another redeemer
+///
+/// Only the current redeemer of the request can sign the assignment.
+#[derive(Accounts)]
+pub struct AssignRedemptionRequest<'info> {
+    /// Program state account for kill switch validation
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = !state.is_kill_switch_active()? @ AssignRedemptionRequestErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The redemption offer the request belongs to
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The redemption request whose redeemer is updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_REQUEST,
+            redemption_request.offer.as_ref(),
+            redemption_request.request_id.to_le_bytes().as_ref()
+        ],
+        bump = redemption_request.bump,
+        constraint = redemption_request.offer == redemption_offer.key()
+            @ AssignRedemptionRequestErrorCode::OfferMismatch,
+        has_one = redeemer @ AssignRedemptionRequestErrorCode::Unauthorized
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// The current redeemer of the request
+    pub redeemer: Signer<'info>,
+}
+
+/// Assigns an unfulfilled redemption request to another redeemer
+///
+/// Moves the claim on the locked token_in to `new_redeemer` without cancelling
+/// and recreating the request, so it keeps its request id and queue position.
+/// From then on fulfillments pay out to, and cancellations refund to, the token
+/// accounts of the new redeemer only.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_redeemer` - Wallet the request is assigned to
+///
+/// # Returns
+/// * `Ok(())` - If the request is successfully assigned
+/// * `Err(AssignRedemptionRequestErrorCode::Unauthorized)` - If the signer is not the request's redeemer
+/// * `Err(AssignRedemptionRequestErrorCode::InvalidNewRedeemer)` - If the new redeemer is the default pubkey or the current redeemer
+///
+/// # Access Control
+/// - Only the current redeemer of the request can call this instruction
+/// - Kill switch prevents assignment when activated
+///
+/// # Effects
+/// - Updates the redemption request's redeemer field
+///
+/// # Events
+/// * `RedemptionRequestAssignedEvent` - Emitted with the previous and new redeemer
+pub fn assign_redemption_request(
+    ctx: Context<AssignRedemptionRequest>,
+    new_redeemer: Pubkey,
+) -> Result<()> {
+    let previous_redeemer = ctx.accounts.redeemer.key();
+    require!(
+        new_redeemer != Pubkey::default() && new_redeemer != previous_redeemer,
+        AssignRedemptionRequestErrorCode::InvalidNewRedeemer
+    );
+
+    let redemption_request = &mut ctx.accounts.redemption_request;
+    redemption_request.redeemer = new_redeemer;
+
+    msg!(
+        "Redemption request assigned: {} from {} to {}",
+        redemption_request.key(),
+        previous_redeemer,
+        new_redeemer
+    );
+
+    emit!(RedemptionRequestAssignedEvent {
+        redemption_request_pda: redemption_request.key(),
+        redemption_offer: ctx.accounts.redemption_offer.key(),
+        previous_redeemer,
+        new_redeemer,
+        amount: redemption_request.amount,
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption request assignment operations
+#[error_code]
+pub enum AssignRedemptionRequestErrorCode {
+    /// Program is in kill switch state
+    #[msg("Operation not allowed: program is in kill switch state")]
+    KillSwitchActivated,
+
+    /// Caller is not the redeemer of the request
+    #[msg("Unauthorized: only the redeemer can assign the redemption request")]
+    Unauthorized,
+
+    /// The redemption request doesn't belong to the redemption offer
+    #[msg("Redemption request does not belong to the redemption offer")]
+    OfferMismatch,
+
+    /// The new redeemer is the default pubkey or the current redeemer
+    #[msg("Invalid new redeemer")]
+    InvalidNewRedeemer,
+}
@@ -0,0 +1,234 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{
+    RedemptionOffer, RedemptionRequest, RedemptionRequestCancelledEvent,
+};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Account structure for cancelling several redemption requests of a redeemer at once
+///
+/// The redemption requests to cancel are passed as writable remaining accounts.
+/// Each one must belong to the given redemption offer and to the signing redeemer.
+#[derive(Accounts)]
+pub struct CancelAllRedemptionRequests<'info> {
+    /// Program state account containing the redemption_admin
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = !state.is_kill_switch_active()? @ CancelAllRedemptionRequestsErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The redemption offer the requests belong to
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The redeemer cancelling their requests
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// Redemption admin receives the rent from closing the redemption requests
+    /// CHECK: Validated against state.redemption_admin
+    #[account(
+        mut,
+        constraint = redemption_admin.key() == state.redemption_admin
+            @ CancelAllRedemptionRequestsErrorCode::InvalidRedemptionAdmin
+    )]
+    pub redemption_admin: UncheckedAccount<'info>,
+
+    /// Program-derived authority that controls redemption vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The token mint for token_in (input token)
+    #[account(
+        constraint = token_in_mint.key() == redemption_offer.token_in_mint
+            @ CancelAllRedemptionRequestsErrorCode::InvalidMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Redemption vault's token account holding the locked tokens
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redeemer's token account receiving the returned tokens
+    ///
+    /// Created if needed in case the redeemer closed their account after locking all tokens.
+    #[account(
+        init_if_needed,
+        payer = redeemer,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = redeemer,
+        associated_token::token_program = token_program,
+    )]
+    pub redeemer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program interface for transfer operations
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Cancels all redemption requests of the signer passed in remaining accounts
+///
+/// Batch counterpart of `cancel_redemption_request` for redeemers. The locked tokens
+/// of all requests are returned to the redeemer in a single transfer and every
+/// request account is closed, returning its rent to the redemption_admin like
+/// single cancellations do.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing the redemption requests as remaining accounts
+///
+/// # Returns
+/// * `Ok(cancelled)` - Number of redemption requests cancelled
+/// * `Err(CancelAllRedemptionRequestsErrorCode::NoRequests)` - If no request was passed
+/// * `Err(CancelAllRedemptionRequestsErrorCode::InvalidRedemptionRequest)` - If an account is not
+///   a redemption request of the offer and the signer
+///
+/// # Access Control
+/// - Only the redeemer of all passed requests can call this instruction
+/// - Kill switch prevents cancellation when activated
+///
+/// # Effects
+/// - Returns the locked token_in of all requests from the vault to the redeemer
+/// - Closes every redemption request account and returns its rent to redemption_admin
+/// - Subtracts the total amount from RedemptionOffer::requested_redemptions
+///
+/// # Events
+/// * `RedemptionRequestCancelledEvent` - Emitted for each cancelled request
+pub fn cancel_all_redemption_requests<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelAllRedemptionRequests<'info>>,
+) -> Result<u32> {
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        CancelAllRedemptionRequestsErrorCode::NoRequests
+    );
+
+    let redemption_offer_key = ctx.accounts.redemption_offer.key();
+    let redeemer = ctx.accounts.redeemer.key();
+
+    let mut requests = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut total_amount: u64 = 0;
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(
+            account_info.is_writable,
+            CancelAllRedemptionRequestsErrorCode::InvalidRedemptionRequest
+        );
+        let request = Account::<RedemptionRequest>::try_from(account_info)?;
+        require!(
+            request.offer == redemption_offer_key && request.redeemer == redeemer,
+            CancelAllRedemptionRequestsErrorCode::InvalidRedemptionRequest
+        );
+        require!(
+            requests
+                .iter()
+                .all(|r: &Account<RedemptionRequest>| r.key() != request.key()),
+            CancelAllRedemptionRequestsErrorCode::InvalidRedemptionRequest
+        );
+
+        total_amount = total_amount
+            .checked_add(request.amount)
+            .ok_or(CancelAllRedemptionRequestsErrorCode::ArithmeticOverflow)?;
+        requests.push(request);
+    }
+
+    // Return all locked tokens from vault to redeemer at once
+    let vault_authority_seeds = &[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.redemption_vault_authority],
+    ];
+    let vault_authority_signer_seeds = &[vault_authority_seeds.as_slice()];
+
+    transfer_tokens(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.redeemer_token_account,
+        &ctx.accounts.redemption_vault_authority,
+        Some(vault_authority_signer_seeds),
+        total_amount,
+    )?;
+
+    ctx.accounts.redemption_offer.requested_redemptions = ctx
+        .accounts
+        .redemption_offer
+        .requested_redemptions
+        .checked_sub(total_amount as u128)
+        .ok_or(CancelAllRedemptionRequestsErrorCode::ArithmeticUnderflow)?;
+
+    let cancelled = requests.len() as u32;
+    for request in requests {
+        emit!(RedemptionRequestCancelledEvent {
+            redemption_request_pda: request.key(),
+            redemption_offer: redemption_offer_key,
+            redeemer,
+            amount: request.amount,
+            cancelled_by: redeemer,
+        });
+        request.close(ctx.accounts.redemption_admin.to_account_info())?;
+    }
+
+    msg!(
+        "Redemption requests cancelled: {} for total amount: {} by redeemer: {}",
+        cancelled,
+        total_amount,
+        redeemer
+    );
+
+    Ok(cancelled)
+}
+
+/// Error codes for batch redemption request cancellation operations
+#[error_code]
+pub enum CancelAllRedemptionRequestsErrorCode {
+    /// Program is in kill switch state
+    #[msg("Operation not allowed: program is in kill switch state")]
+    KillSwitchActivated,
+
+    /// No redemption request was passed in remaining accounts
+    #[msg("No redemption requests to cancel")]
+    NoRequests,
+
+    /// A remaining account is not a writable, distinct request of the offer and redeemer
+    #[msg("Invalid redemption request: must be a request of the redemption offer and the signer")]
+    InvalidRedemptionRequest,
+
+    /// Arithmetic overflow occurred
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    /// Arithmetic underflow occurred
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+
+    /// Invalid mint (doesn't match redemption offer's token_in_mint)
+    #[msg("Invalid mint: provided mint doesn't match redemption offer's token_in_mint")]
+    InvalidMint,
+
+    /// Invalid redemption admin (doesn't match state.redemption_admin)
+    #[msg("Invalid redemption admin: provided account doesn't match state.redemption_admin")]
+    InvalidRedemptionAdmin,
+}
@@ -1,6 +1,6 @@
 use crate::constants::seeds;
 use crate::instructions::redemption::{RedemptionOffer, RedemptionRequest};
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -33,7 +33,8 @@ pub struct CancelRedemptionRequest<'info> {
     #[account(
         seeds = [seeds::STATE],
         bump = state.bump,
-        constraint = !state.is_killed @ CancelRedemptionRequestErrorCode::KillSwitchActivated
+        constraint = !state.is_kill_switch_active()? @ CancelRedemptionRequestErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
@@ -0,0 +1,94 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::DualRedemptionOffer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a dual redemption offer is closed
+///
+/// Provides transparency for tracking dual redemption offer removal.
+#[event]
+pub struct DualRedemptionOfferClosedEvent {
+    /// The PDA address of the closed dual redemption offer
+    pub dual_redemption_offer_pda: Pubkey,
+    /// Cumulative ONyc redeemed through the offer before it was closed
+    pub executed_redemptions: u128,
+    /// The boss account that closed the offer and received the rent
+    pub boss: Pubkey,
+}
+
+/// Account structure for closing a dual redemption offer
+///
+/// This struct defines the accounts required to close a dual redemption offer and
+/// return its rent to the boss. The base redemption offer is left untouched.
+#[derive(Accounts)]
+pub struct CloseDualRedemptionOffer<'info> {
+    /// The dual redemption offer account to be closed
+    #[account(
+        mut,
+        seeds = [
+            seeds::DUAL_REDEMPTION_OFFER,
+            dual_redemption_offer.token_in_mint.as_ref(),
+            dual_redemption_offer.token_out_mint_1.as_ref(),
+            dual_redemption_offer.token_out_mint_2.as_ref()
+        ],
+        bump = dual_redemption_offer.bump,
+        close = boss
+    )]
+    pub dual_redemption_offer: Account<'info, DualRedemptionOffer>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ CloseDualRedemptionOfferErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to close dual redemption offers and receive the rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Closes a dual redemption offer
+///
+/// Stops further dual redemptions for the token triple. Tokens held in the
+/// redemption vaults are not affected and can be withdrawn separately.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the dual redemption offer is successfully closed
+/// * `Err(CloseDualRedemptionOfferErrorCode::Unauthorized)` - If caller is not the boss
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Closes the dual redemption offer account and returns its rent to the boss
+///
+/// # Events
+/// * `DualRedemptionOfferClosedEvent` - Emitted with the closed offer details
+pub fn close_dual_redemption_offer(ctx: Context<CloseDualRedemptionOffer>) -> Result<()> {
+    msg!(
+        "Dual redemption offer closed: {}",
+        ctx.accounts.dual_redemption_offer.key()
+    );
+
+    emit!(DualRedemptionOfferClosedEvent {
+        dual_redemption_offer_pda: ctx.accounts.dual_redemption_offer.key(),
+        executed_redemptions: ctx.accounts.dual_redemption_offer.executed_redemptions,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for dual redemption offer close operations
+#[error_code]
+pub enum CloseDualRedemptionOfferErrorCode {
+    /// Caller is not authorized (boss mismatch)
+    #[msg("Unauthorized: only boss can close dual redemption offers")]
+    Unauthorized,
+}
@@ -0,0 +1,212 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::instructions::vault_operations::{record_immediate_withdrawal, VaultCoreError};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a redemption offer is closed
+///
+/// Provides transparency for tracking redemption offer retirement and recovered funds.
+#[event]
+pub struct RedemptionOfferClosedEvent {
+    /// The PDA address of the closed redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Amount of token_out returned from the redemption vault to the boss
+    pub swept_amount: u64,
+    /// Cumulative ONyc redeemed through the offer before it was closed
+    pub executed_redemptions: u128,
+    /// The account that closed the offer and received the rent
+    pub closed_by: Pubkey,
+}
+
+/// Account structure for closing a redemption offer
+///
+/// This struct defines the accounts required to retire a redemption offer that has
+/// no outstanding redemption requests. The remaining token_out balance of the
+/// redemption vault is returned to the boss before the offer is closed.
+#[derive(Accounts)]
+pub struct CloseRedemptionOffer<'info> {
+    /// Program state account containing boss and redemption_admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ CloseRedemptionOfferErrorCode::InvalidBoss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account receiving the remaining vault balance
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// The redemption offer account to be closed
+    ///
+    /// Must not have any pending redemption requests. Rent is returned to the signer.
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump,
+        close = signer,
+        constraint = redemption_offer.requested_redemptions == 0
+            @ CloseRedemptionOfferErrorCode::PendingRedemptions
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program-derived authority that controls redemption offer vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The output token mint of the redemption offer
+    #[account(
+        constraint = token_out_mint.key() == redemption_offer.token_out_mint
+            @ CloseRedemptionOfferErrorCode::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the output token
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Redemption vault account holding the remaining token_out balance
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's token_out account receiving the remaining vault balance
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_out_program
+    )]
+    pub boss_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The withdrawal timelock of the token_out mint
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; deserialized in the
+    /// handler only if it has been created
+    #[account(
+        mut,
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_out_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_timelock: UncheckedAccount<'info>,
+
+    /// The account closing the redemption offer (must be boss or redemption_admin)
+    #[account(
+        mut,
+        constraint = signer.key() == state.boss || signer.key() == state.redemption_admin
+            @ CloseRedemptionOfferErrorCode::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes a redemption offer and recovers its rent
+///
+/// This instruction retires a redemption offer once all of its redemption requests
+/// have been fulfilled or cancelled. The remaining token_out balance of the
+/// redemption vault is transferred to the boss, and the offer account is closed.
+/// The token_in vault is left untouched since it may lock requests of other
+/// redemption offers sharing the same input token. The sweep is a vault withdrawal,
+/// so a balance above the mint's immediate threshold has to be withdrawn through
+/// `propose_vault_withdraw` first.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the redemption offer is successfully closed
+/// * `Err(CloseRedemptionOfferErrorCode::Unauthorized)` - If caller is neither boss nor redemption_admin
+/// * `Err(CloseRedemptionOfferErrorCode::PendingRedemptions)` - If redemption requests are still pending
+/// * `Err(VaultCoreError::TimelockRequired)` - If the balance exceeds the immediate threshold
+///
+/// # Access Control
+/// - Only the boss or redemption_admin can call this instruction
+///
+/// # Effects
+/// - Transfers the remaining token_out vault balance to the boss
+/// - Closes the redemption offer account and returns its rent to the signer
+///
+/// # Events
+/// * `RedemptionOfferClosedEvent` - Emitted with the swept amount and offer statistics
+pub fn close_redemption_offer(ctx: Context<CloseRedemptionOffer>) -> Result<()> {
+    let swept_amount = ctx.accounts.vault_token_out_account.amount;
+    require!(
+        record_immediate_withdrawal(&ctx.accounts.vault_withdraw_timelock, swept_amount)?,
+        VaultCoreError::TimelockRequired
+    );
+
+    if swept_amount > 0 {
+        let redemption_vault_authority_seeds = &[
+            seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+            &[ctx.bumps.redemption_vault_authority],
+        ];
+        let signer_seeds = &[&redemption_vault_authority_seeds[..]];
+
+        transfer_tokens(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.token_out_program,
+            &ctx.accounts.vault_token_out_account,
+            &ctx.accounts.boss_token_out_account,
+            &ctx.accounts.redemption_vault_authority.to_account_info(),
+            Some(signer_seeds),
+            swept_amount,
+        )?;
+    }
+
+    msg!(
+        "Redemption offer closed: {}, swept: {}",
+        ctx.accounts.redemption_offer.key(),
+        swept_amount
+    );
+
+    emit!(RedemptionOfferClosedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        swept_amount,
+        executed_redemptions: ctx.accounts.redemption_offer.executed_redemptions,
+        closed_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption offer close operations
+#[error_code]
+pub enum CloseRedemptionOfferErrorCode {
+    /// Caller is not authorized (must be boss or redemption_admin)
+    #[msg("Unauthorized: only boss or redemption_admin can close redemption offers")]
+    Unauthorized,
+
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+
+    /// The redemption offer still has pending redemption requests
+    #[msg("Redemption offer has pending redemption requests")]
+    PendingRedemptions,
+
+    /// Invalid token_out mint
+    #[msg("Invalid token_out mint")]
+    InvalidTokenOutMint,
+}
@@ -0,0 +1,166 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS, MAX_REDEMPTION_BASKET_MINTS};
+use crate::instructions::redemption::{RedemptionBasket, RedemptionOffer};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the in-kind redemption basket of a redemption offer is configured
+///
+/// Provides transparency for tracking which underlying tokens in-kind redemptions pay out.
+#[event]
+pub struct RedemptionBasketConfiguredEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Underlying mints of the basket
+    pub mints: Vec<Pubkey>,
+    /// Weight of each mint in basis points
+    pub weights_bps: Vec<u16>,
+    /// The boss who configured the basket
+    pub boss: Pubkey,
+}
+
+/// Account structure for configuring the in-kind redemption basket of a redemption offer
+#[derive(Accounts)]
+pub struct ConfigureRedemptionBasket<'info> {
+    /// The redemption offer whose basket is configured
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The redemption basket of the offer
+    ///
+    /// Created on first configuration.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + RedemptionBasket::INIT_SPACE,
+        seeds = [seeds::REDEMPTION_BASKET, redemption_offer.key().as_ref()],
+        bump
+    )]
+    pub redemption_basket: Box<Account<'info, RedemptionBasket>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to configure the basket and pay for its creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the underlying mints and weights an in-kind redemption offer pays out
+///
+/// Replaces the whole basket. The weights are shares of the redeemed value in basis
+/// points and must sum to 10000. The basket only takes effect for fulfillments once
+/// the offer is switched to in-kind with `set_redemption_offer_in_kind`; the
+/// redemption vault must hold the underlying tokens unless the program controls
+/// their mints.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `mints` - Underlying mints of the basket
+/// * `weights_bps` - Weight of each mint in basis points
+///
+/// # Returns
+/// * `Ok(())` - If the basket is successfully configured
+/// * `Err(ConfigureRedemptionBasketErrorCode::InvalidBasketSize)` - If the lists are empty, too long or of different lengths
+/// * `Err(ConfigureRedemptionBasketErrorCode::InvalidBasketMint)` - If a mint is the default pubkey or the offer's token_in mint
+/// * `Err(ConfigureRedemptionBasketErrorCode::DuplicateBasketMint)` - If a mint is listed twice
+/// * `Err(ConfigureRedemptionBasketErrorCode::InvalidWeights)` - If a weight is zero or the weights don't sum to 10000
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates the redemption basket if it doesn't exist
+/// - Overwrites the basket's mints and weights
+///
+/// # Events
+/// * `RedemptionBasketConfiguredEvent` - Emitted with the new mints and weights
+pub fn configure_redemption_basket(
+    ctx: Context<ConfigureRedemptionBasket>,
+    mints: Vec<Pubkey>,
+    weights_bps: Vec<u16>,
+) -> Result<()> {
+    require!(
+        !mints.is_empty()
+            && mints.len() <= MAX_REDEMPTION_BASKET_MINTS
+            && mints.len() == weights_bps.len(),
+        ConfigureRedemptionBasketErrorCode::InvalidBasketSize
+    );
+
+    let token_in_mint = ctx.accounts.redemption_offer.token_in_mint;
+    for (index, mint) in mints.iter().enumerate() {
+        require!(
+            *mint != Pubkey::default() && *mint != token_in_mint,
+            ConfigureRedemptionBasketErrorCode::InvalidBasketMint
+        );
+        require!(
+            !mints[..index].contains(mint),
+            ConfigureRedemptionBasketErrorCode::DuplicateBasketMint
+        );
+    }
+
+    require!(
+        weights_bps.iter().all(|weight| *weight > 0)
+            && weights_bps.iter().map(|weight| *weight as u32).sum::<u32>()
+                == MAX_BASIS_POINTS as u32,
+        ConfigureRedemptionBasketErrorCode::InvalidWeights
+    );
+
+    let basket = &mut ctx.accounts.redemption_basket;
+    basket.redemption_offer = ctx.accounts.redemption_offer.key();
+    basket.mints = [Pubkey::default(); MAX_REDEMPTION_BASKET_MINTS];
+    basket.weights_bps = [0; MAX_REDEMPTION_BASKET_MINTS];
+    basket.mints[..mints.len()].copy_from_slice(&mints);
+    basket.weights_bps[..weights_bps.len()].copy_from_slice(&weights_bps);
+    basket.bump = ctx.bumps.redemption_basket;
+
+    msg!(
+        "Redemption basket configured for offer: {}, mints: {}",
+        ctx.accounts.redemption_offer.key(),
+        mints.len()
+    );
+
+    emit!(RedemptionBasketConfiguredEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        mints,
+        weights_bps,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption basket configuration
+#[error_code]
+pub enum ConfigureRedemptionBasketErrorCode {
+    /// The mint and weight lists are empty, too long or of different lengths
+    #[msg("Invalid basket size")]
+    InvalidBasketSize,
+
+    /// A basket mint is the default pubkey or the redemption offer's token_in mint
+    #[msg("Invalid basket mint")]
+    InvalidBasketMint,
+
+    /// A basket mint is listed more than once
+    #[msg("Duplicate basket mint")]
+    DuplicateBasketMint,
+
+    /// A weight is zero or the weights don't sum to 10000 basis points
+    #[msg("Basket weights must be non-zero and sum to 10000 basis points")]
+    InvalidWeights,
+}
@@ -1,6 +1,10 @@
 use crate::constants::seeds;
-use crate::instructions::redemption::{RedemptionOffer, RedemptionRequest};
-use crate::state::State;
+use crate::instructions::redemption::{
+    enforce_minimum_redemption, RedemptionCoreError, RedemptionOffer, RedemptionPriceMode,
+    RedemptionRequest,
+};
+use crate::instructions::{calculate_current_vector_price, find_active_vector_at, Offer};
+use crate::state::{BlocklistBucket, Config, State, StateSchemaErrorCode};
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -21,6 +25,14 @@ pub struct RedemptionRequestCreatedEvent {
     pub amount: u64,
     /// Unique identifier for this request (counter value used for PDA derivation)
     pub id: u64,
+    /// Slot in which the request was created
+    pub slot: u64,
+    /// Index of the request among the offer's requests created in the same slot
+    pub slot_index: u32,
+    /// NAV locked on the request with scale=9, or 0 if it is priced at fulfillment
+    pub nav_used: u64,
+    /// Unix timestamp at which `nav_used` was read, or 0 if priced at fulfillment
+    pub price_timestamp: i64,
 }
 
 /// Account structure for creating a redemption request
@@ -34,10 +46,15 @@ pub struct CreateRedemptionRequest<'info> {
     #[account(
         seeds = [seeds::STATE],
         bump = state.bump,
-        constraint = !state.is_killed @ CreateRedemptionRequestErrorCode::KillSwitchActivated
+        constraint = !state.is_kill_switch_active()? @ CreateRedemptionRequestErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The redemption offer account
     #[account(
         mut,
@@ -46,10 +63,22 @@ pub struct CreateRedemptionRequest<'info> {
             redemption_offer.token_in_mint.as_ref(),
             redemption_offer.token_out_mint.as_ref()
         ],
-        bump = redemption_offer.bump
+        bump = redemption_offer.bump,
+        constraint = !redemption_offer.pause_new_requests
+            @ CreateRedemptionRequestErrorCode::NewRequestsPaused
     )]
     pub redemption_offer: Account<'info, RedemptionOffer>,
 
+    /// The underlying offer whose NAV values quote-denominated minimums
+    ///
+    /// Only required when the redemption offer's minimum is denominated in `Quote`
+    /// or its price mode locks the NAV at request creation.
+    #[account(
+        address = redemption_offer.offer
+            @ CreateRedemptionRequestErrorCode::InvalidOffer
+    )]
+    pub offer: Option<AccountLoader<'info, Offer>>,
+
     /// The redemption request account
     /// PDA derived from redemption_offer and its counter value
     #[account(
@@ -69,6 +98,16 @@ pub struct CreateRedemptionRequest<'info> {
     #[account(mut)]
     pub redeemer: Signer<'info>,
 
+    /// Blocklist bucket covering the redeemer, rejecting sanctioned redeemers
+    ///
+    /// May be uninitialized when no address of the bucket was ever blocked.
+    /// CHECK: PDA derivation is validated by seeds constraint, parsed in the handler
+    #[account(
+        seeds = [seeds::BLOCKLIST, &[BlocklistBucket::index_for(&redeemer.key())]],
+        bump
+    )]
+    pub blocklist_bucket: UncheckedAccount<'info>,
+
     /// Program-derived authority that controls redemption vault token accounts
     ///
     /// This PDA manages the redemption vault token accounts and enables the program
@@ -129,20 +168,35 @@ pub struct CreateRedemptionRequest<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the redemption request is successfully created
+/// * `Err(RedemptionCoreError::OfferAccountRequired)` - If the NAV must be locked or valued
+///   and the underlying offer was not provided
 ///
 /// # Access Control
-/// - Anyone can create a redemption request (no admin signature required)
+/// - Anyone can create a redemption request (no admin signature required), except
+///   redeemers on the sanctioned-address blocklist
 /// - Redeemer pays for the redemption request PDA rent
 ///
 /// # Effects
+/// - Rejects requests below the redemption offer's minimum size, valued at the
+///   underlying offer's current NAV for `Quote` minimums
 /// - Creates new redemption request account (PDA derived from offer and counter)
 /// - Transfers token_in tokens from redeemer to redemption vault (locking them)
 /// - Increments counter on RedemptionOffer for next request
+/// - Records the creation slot and intra-slot index on the request, ordering requests
+///   created in the same slot deterministically for FIFO fulfillment
 /// - Updates requested_redemptions in RedemptionOffer
+/// - Locks the underlying offer's current NAV on the request when the redemption
+///   offer's price mode is `LockedAtRequest`
 ///
 /// # Events
 /// * `RedemptionRequestCreatedEvent` - Emitted with redemption request details
 pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount: u64) -> Result<()> {
+    // Reject sanctioned redeemers
+    BlocklistBucket::require_not_blocked(
+        &ctx.accounts.blocklist_bucket,
+        &ctx.accounts.redeemer.key(),
+    )?;
+
     // Validate the redemption offer is properly initialized (offer is not default)
     require!(
         ctx.accounts.redemption_offer.offer != Pubkey::default(),
@@ -155,6 +209,37 @@ pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount:
         CreateRedemptionRequestErrorCode::InvalidRedemptionOffer
     );
 
+    // Reject requests below the redemption offer's minimum size and lock the NAV
+    // when the offer prices requests at creation
+    let (nav_used, price_timestamp) = {
+        let offer = ctx
+            .accounts
+            .offer
+            .as_ref()
+            .map(|offer| offer.load())
+            .transpose()?;
+        enforce_minimum_redemption(
+            &ctx.accounts.redemption_offer,
+            offer.as_deref(),
+            amount,
+            &ctx.accounts.token_in_mint,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+
+        match ctx.accounts.redemption_offer.price_mode {
+            RedemptionPriceMode::AtFulfillment => (0, 0),
+            RedemptionPriceMode::LockedAtRequest => {
+                let offer = offer.ok_or(RedemptionCoreError::OfferAccountRequired)?;
+                let now = Clock::get()?.unix_timestamp;
+                let nav = calculate_current_vector_price(
+                    &find_active_vector_at(&offer, now as u64)?,
+                    ctx.accounts.config.seconds_per_year,
+                )?;
+                (nav, now)
+            }
+        }
+    };
+
     // Capture counter before incrementing (used for PDA derivation)
     let request_id = ctx.accounts.redemption_offer.request_counter;
 
@@ -169,6 +254,21 @@ pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount:
         amount,
     )?;
 
+    // Order requests created in the same slot by their arrival within the slot
+    let slot = Clock::get()?.slot;
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+    let slot_index =
+        if redemption_offer.request_counter > 0 && redemption_offer.last_request_slot == slot {
+            redemption_offer
+                .last_request_slot_index
+                .checked_add(1)
+                .ok_or(CreateRedemptionRequestErrorCode::ArithmeticOverflow)?
+        } else {
+            0
+        };
+    redemption_offer.last_request_slot = slot;
+    redemption_offer.last_request_slot_index = slot_index;
+
     // Initialize the redemption request
     let redemption_request = &mut ctx.accounts.redemption_request;
     redemption_request.offer = ctx.accounts.redemption_offer.key();
@@ -176,6 +276,10 @@ pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount:
     redemption_request.redeemer = ctx.accounts.redeemer.key();
     redemption_request.amount = amount;
     redemption_request.bump = ctx.bumps.redemption_request;
+    redemption_request.slot = slot;
+    redemption_request.slot_index = slot_index;
+    redemption_request.nav_used = nav_used;
+    redemption_request.price_timestamp = price_timestamp;
 
     // Update requested redemptions in the offer
     ctx.accounts.redemption_offer.requested_redemptions = ctx
@@ -194,11 +298,13 @@ pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount:
         .ok_or(CreateRedemptionRequestErrorCode::ArithmeticOverflow)?;
 
     msg!(
-        "Redemption request created at: {} for amount: {} by redeemer: {} (id: {})",
+        "Redemption request created at: {} for amount: {} by redeemer: {} (id: {}, slot: {}, index: {})",
         ctx.accounts.redemption_request.key(),
         amount,
         ctx.accounts.redeemer.key(),
-        request_id
+        request_id,
+        slot,
+        slot_index
     );
 
     emit!(RedemptionRequestCreatedEvent {
@@ -207,6 +313,10 @@ pub fn create_redemption_request(ctx: Context<CreateRedemptionRequest>, amount:
         redeemer: ctx.accounts.redeemer.key(),
         amount,
         id: request_id,
+        slot,
+        slot_index,
+        nav_used,
+        price_timestamp,
     });
 
     Ok(())
@@ -219,6 +329,10 @@ pub enum CreateRedemptionRequestErrorCode {
     #[msg("Redemption system is paused: kill switch activated")]
     KillSwitchActivated,
 
+    /// New redemption requests are paused on the redemption offer
+    #[msg("New redemption requests are paused")]
+    NewRequestsPaused,
+
     /// Arithmetic overflow occurred
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
@@ -230,4 +344,8 @@ pub enum CreateRedemptionRequestErrorCode {
     /// Invalid redemption offer (not properly initialized)
     #[msg("Invalid redemption offer: offer is not properly initialized")]
     InvalidRedemptionOffer,
+
+    /// Offer account doesn't match the redemption offer's underlying offer
+    #[msg("Invalid offer: doesn't match the redemption offer's underlying offer")]
+    InvalidOffer,
 }
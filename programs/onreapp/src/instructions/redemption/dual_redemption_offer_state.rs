@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Redemption offer paying out two output tokens for a single ONyc redemption
+///
+/// A dual redemption offer extends a RedemptionOffer (ONyc -> token_out_1) with a
+/// second output leg. Both legs are valued at the NAV of the underlying Offer and
+/// the redemption offer's fee. The net redeemed amount is split between the two
+/// output tokens by a fixed ratio.
+#[account]
+#[derive(InitSpace)]
+pub struct DualRedemptionOffer {
+    /// Reference to the base RedemptionOffer PDA providing NAV and fee configuration
+    pub redemption_offer: Pubkey,
+    /// Input token mint for redemptions (e.g., ONyc)
+    pub token_in_mint: Pubkey,
+    /// Principal output token mint (token_out of the base redemption offer)
+    pub token_out_mint_1: Pubkey,
+    /// Secondary output token mint paid alongside the principal
+    pub token_out_mint_2: Pubkey,
+    /// Share of the net redeemed amount paid in token_out_2, in basis points (10000 = 100%)
+    pub token_out_2_basis_points: u16,
+    /// Cumulative total of ONyc redeemed through this dual redemption offer
+    pub executed_redemptions: u128,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Cumulative ONyc fees collected through this dual redemption offer
+    pub total_fees_collected_token_in: u128,
+    /// Reserved space for future fields
+    pub reserved: [u8; 48],
+}
@@ -1,10 +1,12 @@
 use crate::constants::seeds;
+use crate::instructions::offer::offer_utils::observe_nav;
 use crate::instructions::redemption::{
-    execute_redemption_operations, process_redemption_core, ExecuteRedemptionOpsParams,
-    RedemptionOffer, RedemptionRequest,
+    execute_redemption_operations, process_redemption_request, require_redemption_offer_decimals,
+    ExecuteRedemptionOpsParams, RedemptionCoreError, RedemptionOffer, RedemptionRequest,
 };
-use crate::instructions::Offer;
-use crate::state::State;
+use crate::instructions::{NavHistory, Offer, UserPosition};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::event_cpi::event_emitter;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -26,10 +28,20 @@ pub struct RedemptionRequestFulfilledEvent {
     pub token_in_net_amount: u64,
     /// Fee amount deducted from token_in
     pub token_in_fee_amount: u64,
-    /// Amount of token_out tokens received by the user
+    /// The mint the redemption was settled in (token_out_mint or an allowlisted settlement mint)
+    pub token_out_mint: Pubkey,
+    /// Amount of token_out tokens received by the user (after fees)
     pub token_out_amount: u64,
-    /// Current price used for the redemption
+    /// Fee amount deducted from token_out
+    pub token_out_fee_amount: u64,
+    /// NAV used for the redemption, locked at request creation or read at fulfillment
     pub current_price: u64,
+    /// Unix timestamp at which the NAV used was read
+    pub price_timestamp: i64,
+    /// Slot in which the fulfilled request was created
+    pub request_slot: u64,
+    /// Index of the fulfilled request within its creation slot
+    pub request_slot_index: u32,
 }
 
 /// Account structure for fulfilling a redemption request
@@ -37,6 +49,7 @@ pub struct RedemptionRequestFulfilledEvent {
 /// This struct defines the accounts required to fulfill a redemption request,
 /// handling token burning/transfer for token_in (typically ONyc) and minting/transfer
 /// for token_out (typically stablecoins like USDC).
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct FulfillRedemptionRequest<'info> {
     /// Program state account containing redemption_admin and boss authorization
@@ -44,10 +57,15 @@ pub struct FulfillRedemptionRequest<'info> {
         seeds = [seeds::STATE],
         bump = state.bump,
         has_one = boss @ FulfillRedemptionRequestErrorCode::InvalidBoss,
-        constraint = !state.is_killed @ FulfillRedemptionRequestErrorCode::KillSwitchActivated
+        constraint = !state.is_kill_switch_active()? @ FulfillRedemptionRequestErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account that may receive tokens when program lacks mint authority
     /// CHECK: Account validation is enforced through state account constraint
     pub boss: UncheckedAccount<'info>,
@@ -56,7 +74,19 @@ pub struct FulfillRedemptionRequest<'info> {
     /// CHECK: offer address is validated through redemption_offer constraint
     pub offer: AccountLoader<'info, Offer>,
 
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
     /// The redemption offer account
+    ///
+    /// In-kind redemption offers are fulfilled by `fulfill_redemption_request_basket`.
     #[account(
         mut,
         seeds = [
@@ -66,7 +96,11 @@ pub struct FulfillRedemptionRequest<'info> {
         ],
         bump = redemption_offer.bump,
         constraint = redemption_offer.offer == offer.key()
-            @ FulfillRedemptionRequestErrorCode::OfferMismatch
+            @ FulfillRedemptionRequestErrorCode::OfferMismatch,
+        constraint = !redemption_offer.in_kind
+            @ FulfillRedemptionRequestErrorCode::InKindRedemptionOffer,
+        constraint = !redemption_offer.pause_fulfillment
+            @ FulfillRedemptionRequestErrorCode::FulfillmentPaused
     )]
     pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
 
@@ -135,10 +169,12 @@ pub struct FulfillRedemptionRequest<'info> {
 
     /// Output token mint (typically stablecoin like USDC)
     ///
+    /// Either the redemption offer's token_out_mint or one of its allowlisted
+    /// settlement mints, chosen by the redemption admin at fulfillment time.
     /// Must be mutable to allow minting operations when program has mint authority.
     #[account(
         mut,
-        constraint = token_out_mint.key() == redemption_offer.token_out_mint
+        constraint = redemption_offer.accepts_payout_mint(&token_out_mint.key())
             @ FulfillRedemptionRequestErrorCode::InvalidTokenOutMint
     )]
     pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -170,6 +206,17 @@ pub struct FulfillRedemptionRequest<'info> {
     )]
     pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Boss's output token account for receiving fees charged in token_out
+    ///
+    /// Required when the redemption offer charges its fee in token_out.
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_out_program
+    )]
+    pub boss_token_out_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// Program-derived mint authority for direct token minting
     ///
     /// Used when the program has mint authority and can mint token_out directly.
@@ -186,6 +233,18 @@ pub struct FulfillRedemptionRequest<'info> {
         @ FulfillRedemptionRequestErrorCode::InvalidRedeemer)]
     pub redeemer: UncheckedAccount<'info>,
 
+    /// Position of the redeemer in token_in, updated with the redemption when passed
+    #[account(
+        mut,
+        seeds = [
+            seeds::USER_POSITION,
+            token_in_mint.key().as_ref(),
+            redeemer.key().as_ref()
+        ],
+        bump = user_position.bump
+    )]
+    pub user_position: Option<Box<Account<'info, UserPosition>>>,
+
     /// Redemption admin must sign to authorize fulfillment
     #[account(
         mut,
@@ -204,14 +263,22 @@ pub struct FulfillRedemptionRequest<'info> {
 /// Fulfills a redemption request
 ///
 /// This instruction fulfills a pending redemption request by:
-/// 1. Getting the current price from the underlying offer (inverse calculation)
-/// 2. Calculating token_out amount based on token_in and current price
+/// 1. Getting the current price from the underlying offer (inverse calculation), or the
+///    NAV locked on the request at creation
+/// 2. Calculating token_out amount based on token_in, current price and the decimals
+///    of the selected payout mint
 /// 3. If program has mint authority of token_in : burn it from vault
 /// 4. If program lacks mint authority of token_int: send to boss from vault
 /// 5. If token_out program has mint authority: mint token_out to user
 /// 6. If token_out program lacks mint authority: transfer from vault to user
 /// 7. Update redemption request status and offer statistics
 ///
+/// The payout mint is selected by the redemption admin through the token_out_mint
+/// account and may be any boss-allowlisted settlement mint of the redemption offer.
+///
+/// The fee is charged on the side selected by the redemption offer's `fee_currency`.
+/// Fees charged in token_out are paid to the boss in the payout mint.
+///
 /// Note: token_in is already locked in the vault from create_redemption_request
 ///
 /// # Arguments
@@ -219,38 +286,66 @@ pub struct FulfillRedemptionRequest<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the redemption is successfully fulfilled
+/// * `Err(RedemptionCoreError::MissingBossTokenOutAccount)` - If a token_out fee is due
+///   and no boss token_out account was passed
 /// * `Err(_)` - If validation fails or token operations fail
 ///
 /// # Access Control
 /// - Only redemption_admin can fulfill redemptions
 /// - Kill switch prevents fulfillment when activated
 /// - Request must be pending (status == 0) and not expired
+/// - The redemption offer must not pay out in kind
 ///
 /// # Effects
 /// - Marks redemption request as fulfilled (status = 1)
-/// - Updates executed_redemptions and requested_redemptions in RedemptionOffer
+/// - Updates executed_redemptions, requested_redemptions and total_fees_collected_token_in
+///   in RedemptionOffer
 /// - Burns or transfers token_in based on mint authority
 /// - Mints or transfers token_out to user
+/// - Records the redemption in the redeemer's token_in position when passed
 ///
 /// # Events
 /// * `RedemptionRequestFulfilledEvent` - Emitted with fulfillment details
 pub fn fulfill_redemption_request(ctx: Context<FulfillRedemptionRequest>) -> Result<()> {
-    let redemption_request = &mut ctx.accounts.redemption_request;
-    let token_in_amount = redemption_request.amount;
+    let token_in_amount = ctx.accounts.redemption_request.amount;
+
+    // Reject mints priced with other decimals than the redemption offer was made for
+    require_redemption_offer_decimals(
+        &ctx.accounts.redemption_offer,
+        &ctx.accounts.token_in_mint,
+        Some(&ctx.accounts.token_out_mint),
+    )?;
 
     // Use shared core processing logic for redemption
     let offer = ctx.accounts.offer.load()?;
-    let result = process_redemption_core(
+    let (result, price_timestamp) = process_redemption_request(
         &offer,
-        token_in_amount,
+        &ctx.accounts.redemption_request,
         &ctx.accounts.token_in_mint,
         &ctx.accounts.token_out_mint,
         ctx.accounts.redemption_offer.fee_basis_points,
+        ctx.accounts.redemption_offer.fee_currency,
+        ctx.accounts.config.seconds_per_year,
     )?;
     let price = result.price;
     let token_in_net_amount = result.token_in_net_amount;
     let token_in_fee_amount = result.token_in_fee_amount;
     let token_out_amount = result.token_out_amount;
+    let token_out_fee_amount = result.token_out_fee_amount;
+    require!(
+        token_out_amount > 0,
+        RedemptionCoreError::ZeroRedemptionPayout
+    );
+
+    // Record a NAV observation when the history is passed
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
     drop(offer);
 
     // Execute token operations (burn/transfer token_in_net, mint/transfer token_out)
@@ -267,8 +362,10 @@ pub fn fulfill_redemption_request(ctx: Context<FulfillRedemptionRequest>) -> Res
         redemption_vault_authority_bump: ctx.bumps.redemption_vault_authority,
         token_out_mint: &ctx.accounts.token_out_mint,
         token_out_amount,
+        token_out_fee_amount,
         vault_token_out_account: &ctx.accounts.vault_token_out_account,
         user_token_out_account: &ctx.accounts.user_token_out_account,
+        boss_token_out_account: ctx.accounts.boss_token_out_account.as_deref(),
         mint_authority_pda: &ctx.accounts.mint_authority,
         mint_authority_bump: ctx.bumps.mint_authority,
         token_out_max_supply: 0, // No max supply cap for redemptions
@@ -279,32 +376,49 @@ pub fn fulfill_redemption_request(ctx: Context<FulfillRedemptionRequest>) -> Res
         .executed_redemptions
         .checked_add(token_in_amount as u128)
         .ok_or(FulfillRedemptionRequestErrorCode::ArithmeticOverflow)?;
+    redemption_offer.total_fees_collected_token_in = redemption_offer
+        .total_fees_collected_token_in
+        .checked_add(token_in_fee_amount)
+        .ok_or(FulfillRedemptionRequestErrorCode::ArithmeticOverflow)?;
 
     redemption_offer.requested_redemptions = redemption_offer
         .requested_redemptions
         .checked_sub(token_in_amount as u128)
         .ok_or(FulfillRedemptionRequestErrorCode::ArithmeticUnderflow)?;
 
+    // Track the redemption in the redeemer's position when passed
+    if let Some(user_position) = ctx.accounts.user_position.as_mut() {
+        user_position.record_redemption(token_in_amount, Clock::get()?.unix_timestamp as u64)?;
+    }
+
     msg!(
-        "Redemption request fulfilled: request={}, token_in={} (net={}, fee={}), token_out={}, price={}, redeemer={}",
+        "Redemption request fulfilled: request={}, token_in={} (net={}, fee={}), token_out={} (fee={}) of {}, price={}, redeemer={}",
         ctx.accounts.redemption_request.key(),
         token_in_amount,
         token_in_net_amount,
         token_in_fee_amount,
         token_out_amount,
+        token_out_fee_amount,
+        ctx.accounts.token_out_mint.key(),
         price,
         ctx.accounts.redeemer.key()
     );
 
-    emit!(RedemptionRequestFulfilledEvent {
+    let (request_slot, request_slot_index) = ctx.accounts.redemption_request.queue_position();
+    event_emitter!(ctx).emit(RedemptionRequestFulfilledEvent {
         redemption_request_pda: ctx.accounts.redemption_request.key(),
         redemption_offer_pda: ctx.accounts.redemption_offer.key(),
         redeemer: ctx.accounts.redeemer.key(),
         token_in_net_amount,
         token_in_fee_amount,
+        token_out_mint: ctx.accounts.token_out_mint.key(),
         token_out_amount,
+        token_out_fee_amount,
         current_price: price,
-    });
+        price_timestamp,
+        request_slot,
+        request_slot_index,
+    })?;
 
     Ok(())
 }
@@ -336,7 +450,7 @@ pub enum FulfillRedemptionRequestErrorCode {
     #[msg("Invalid token_in mint")]
     InvalidTokenInMint,
 
-    /// Invalid token_out mint
+    /// token_out mint is neither the offer's token_out mint nor an allowlisted settlement mint
     #[msg("Invalid token_out mint")]
     InvalidTokenOutMint,
 
@@ -344,6 +458,14 @@ pub enum FulfillRedemptionRequestErrorCode {
     #[msg("Redeemer does not match redemption request")]
     InvalidRedeemer,
 
+    /// The redemption offer pays out in kind
+    #[msg("In-kind redemption offers must be fulfilled with the redemption basket")]
+    InKindRedemptionOffer,
+
+    /// Fulfillment is paused on the redemption offer
+    #[msg("Redemption fulfillment is paused")]
+    FulfillmentPaused,
+
     /// Arithmetic overflow occurred
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
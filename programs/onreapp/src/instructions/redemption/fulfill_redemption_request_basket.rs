@@ -0,0 +1,638 @@
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::offer::offer_utils::observe_nav;
+use crate::instructions::redemption::{
+    process_redemption_request, require_redemption_offer_decimals, settle_redemption_token_in,
+    FeeCurrency, RedemptionBasket, RedemptionCoreError, RedemptionOffer, RedemptionRequest,
+    RedemptionTokenInParams,
+};
+use crate::instructions::{NavHistory, Offer};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::{
+    mint_tokens, program_controls_mint, scale_amount, scale_amount_rounded, transfer_tokens,
+    PAYOUT_ROUNDING,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Number of remaining accounts describing one payout leg of a basket fulfillment
+const ACCOUNTS_PER_LEG: usize = 3;
+
+/// Event emitted when a redemption request is fulfilled across several payout mints
+///
+/// The legs are listed in the order they were passed; all legs share the same price.
+#[event]
+pub struct RedemptionRequestBasketFulfilledEvent {
+    /// The PDA address of the fulfilled redemption request
+    pub redemption_request_pda: Pubkey,
+    /// Reference to the redemption offer pda
+    pub redemption_offer_pda: Pubkey,
+    /// User who created the redemption request
+    pub redeemer: Pubkey,
+    /// Net amount of token_in tokens burned/transferred (after fees)
+    pub token_in_net_amount: u64,
+    /// Fee amount deducted from token_in
+    pub token_in_fee_amount: u64,
+    /// Payout mint of each leg
+    pub token_out_mints: Vec<Pubkey>,
+    /// Share of the net token_in amount settled by each leg
+    pub leg_token_in_amounts: Vec<u64>,
+    /// Amount of token_out received by the user in each leg
+    pub token_out_amounts: Vec<u64>,
+    /// NAV used for every leg of the redemption, locked at request creation or read at fulfillment
+    pub current_price: u64,
+    /// Unix timestamp at which the NAV used was read
+    pub price_timestamp: i64,
+    /// Slot in which the fulfilled request was created
+    pub request_slot: u64,
+    /// Index of the fulfilled request within its creation slot
+    pub request_slot_index: u32,
+}
+
+/// Account structure for fulfilling a redemption request across several payout mints
+///
+/// Identical to `FulfillRedemptionRequest` for the token_in side. The payout legs are
+/// passed as remaining accounts, three per leg and in this order:
+/// 1. the payout mint (writable when the program mints it)
+/// 2. the redemption vault's token account of that mint (writable)
+/// 3. the redeemer's existing token account of that mint (writable)
+///
+/// All payout mints must belong to `token_out_program`.
+#[derive(Accounts)]
+pub struct FulfillRedemptionRequestBasket<'info> {
+    /// Program state account containing redemption_admin and boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ FulfillRedemptionRequestBasketErrorCode::InvalidBoss,
+        constraint = !state.is_kill_switch_active()? @ FulfillRedemptionRequestBasketErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account that may receive tokens when program lacks mint authority
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// The underlying offer that defines pricing
+    /// CHECK: offer address is validated through redemption_offer constraint
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// NAV history of the offer, updated with the current NAV when passed
+    ///
+    /// Optional; no observation is recorded when the latest one is too recent.
+    #[account(
+        mut,
+        seeds = [seeds::NAV_HISTORY, offer.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Option<Box<Account<'info, NavHistory>>>,
+
+    /// The redemption offer account
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump,
+        constraint = redemption_offer.offer == offer.key()
+            @ FulfillRedemptionRequestBasketErrorCode::OfferMismatch,
+        constraint = !redemption_offer.pause_fulfillment
+            @ FulfillRedemptionRequestBasketErrorCode::FulfillmentPaused
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The in-kind redemption basket of the offer
+    ///
+    /// Required when the redemption offer pays out in kind.
+    #[account(
+        seeds = [seeds::REDEMPTION_BASKET, redemption_offer.key().as_ref()],
+        bump = redemption_basket.bump
+    )]
+    pub redemption_basket: Option<Box<Account<'info, RedemptionBasket>>>,
+
+    /// The redemption request account to fulfill
+    /// Account is closed after fulfillment and rent is returned to redemption_admin
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_REQUEST,
+            redemption_request.offer.as_ref(),
+            redemption_request.request_id.to_le_bytes().as_ref()
+        ],
+        bump = redemption_request.bump,
+        close = redemption_admin,
+        constraint = redemption_request.offer == redemption_offer.key()
+            @ FulfillRedemptionRequestBasketErrorCode::OfferMismatch
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequest>>,
+
+    /// Program-derived redemption vault authority that controls token operations
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY],
+        bump
+    )]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// Redemption vault account holding the locked token_in
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Input token mint (typically ONyc)
+    ///
+    /// Must be mutable to allow burning operations when program has mint authority.
+    #[account(
+        mut,
+        constraint = token_in_mint.key() == redemption_offer.token_in_mint
+            @ FulfillRedemptionRequestBasketErrorCode::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for input token operations
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Token program interface shared by all payout mints
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Boss's input token account for receiving tokens when program lacks mint authority
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority for direct token minting
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(
+        seeds = [seeds::MINT_AUTHORITY],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The user who created the redemption request
+    /// CHECK: Validated against redemption_request.redeemer
+    #[account(constraint = redeemer.key() == redemption_request.redeemer
+        @ FulfillRedemptionRequestBasketErrorCode::InvalidRedeemer)]
+    pub redeemer: UncheckedAccount<'info>,
+
+    /// Redemption admin must sign to authorize fulfillment
+    #[account(
+        mut,
+        constraint = redemption_admin.key() == state.redemption_admin
+            @ FulfillRedemptionRequestBasketErrorCode::Unauthorized
+    )]
+    pub redemption_admin: Signer<'info>,
+}
+
+/// A validated payout leg read from remaining accounts
+struct BasketLeg<'info> {
+    mint: InterfaceAccount<'info, Mint>,
+    vault_token_out_account: InterfaceAccount<'info, TokenAccount>,
+    user_token_out_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Fulfills a redemption request by paying out in several settlement mints
+///
+/// Works like `fulfill_redemption_request`, but splits the payout over up to one leg
+/// per accepted payout mint of the redemption offer (its token_out_mint and its
+/// allowlisted settlement mints). Every leg is valued at the same NAV, so the user
+/// receives the same total value as with a single-mint fulfillment. The NAV is the
+/// one locked on the request at creation, if any.
+///
+/// The net token_in amount is split between the legs either:
+/// - pro-rata to the vault balances of the payout mints, normalized by decimals,
+///   when `leg_token_in_amounts` is empty, or
+/// - by `leg_token_in_amounts`, one entry per leg summing to the net token_in amount.
+///
+/// Rounding remainders of the pro-rata split are assigned to the last leg.
+///
+/// For in-kind redemption offers the legs must be exactly the mints of the offer's
+/// `RedemptionBasket`, and the net token_in amount is split by the basket weights.
+///
+/// Fees are only supported in token_in, since a token_out fee would need a boss
+/// account per payout mint.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts and the legs as remaining accounts
+/// * `leg_token_in_amounts` - Explicit net token_in share per leg, or empty for a pro-rata
+///   split; must be empty for in-kind offers
+///
+/// # Returns
+/// * `Ok(())` - If the redemption is successfully fulfilled
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::InvalidLegAccounts)` - If the legs are malformed
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::InvalidTokenOutMint)` - If a leg mint is not accepted
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::DuplicateLegMint)` - If a mint appears twice
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::LegAmountsMismatch)` - If explicit amounts don't match the legs
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::TokenOutFeeNotSupported)` - If the fee is charged in token_out
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::RedemptionBasketRequired)` - If an in-kind offer's basket is missing
+/// * `Err(FulfillRedemptionRequestBasketErrorCode::IncompleteBasket)` - If an in-kind fulfillment omits a basket mint
+///
+/// # Access Control
+/// - Only redemption_admin can fulfill redemptions
+/// - Kill switch prevents fulfillment when activated
+///
+/// # Effects
+/// - Burns or transfers token_in based on mint authority
+/// - Mints or transfers each leg's token_out to the user
+/// - Updates executed_redemptions, requested_redemptions and total_fees_collected_token_in
+///   in RedemptionOffer
+/// - Closes the redemption request
+///
+/// # Events
+/// * `RedemptionRequestBasketFulfilledEvent` - Emitted with the per-leg payouts
+pub fn fulfill_redemption_request_basket<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FulfillRedemptionRequestBasket<'info>>,
+    leg_token_in_amounts: Vec<u64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.redemption_offer.fee_currency == FeeCurrency::TokenIn,
+        FulfillRedemptionRequestBasketErrorCode::TokenOutFeeNotSupported
+    );
+    // Legs pay out in several mints, so only token_in is held to the recorded decimals
+    require_redemption_offer_decimals(
+        &ctx.accounts.redemption_offer,
+        &ctx.accounts.token_in_mint,
+        None,
+    )?;
+
+    let basket = if ctx.accounts.redemption_offer.in_kind {
+        let basket: &RedemptionBasket = ctx
+            .accounts
+            .redemption_basket
+            .as_deref()
+            .ok_or(FulfillRedemptionRequestBasketErrorCode::RedemptionBasketRequired)?;
+        Some(basket)
+    } else {
+        None
+    };
+
+    let legs = load_basket_legs(&ctx, basket)?;
+    let token_in_amount = ctx.accounts.redemption_request.amount;
+
+    // Price and fee are computed once so that every leg uses the same NAV
+    let offer = ctx.accounts.offer.load()?;
+    let (result, price_timestamp) = process_redemption_request(
+        &offer,
+        &ctx.accounts.redemption_request,
+        &ctx.accounts.token_in_mint,
+        &legs[0].mint,
+        ctx.accounts.redemption_offer.fee_basis_points,
+        ctx.accounts.redemption_offer.fee_currency,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    if let Some(nav_history) = ctx.accounts.nav_history.as_mut() {
+        observe_nav(
+            nav_history,
+            &offer,
+            Clock::get()?.unix_timestamp as u64,
+            ctx.accounts.config.seconds_per_year,
+        )?;
+    }
+    drop(offer);
+
+    let price = result.price;
+    let token_in_net_amount = result.token_in_net_amount;
+    let token_in_fee_amount = result.token_in_fee_amount;
+
+    let leg_token_in_amounts = if let Some(basket) = basket {
+        require!(
+            leg_token_in_amounts.is_empty(),
+            FulfillRedemptionRequestBasketErrorCode::LegAmountsMismatch
+        );
+        require!(
+            legs.len() == basket.active_count(),
+            FulfillRedemptionRequestBasketErrorCode::IncompleteBasket
+        );
+        let weights = legs
+            .iter()
+            .map(|leg| basket.weight_of(&leg.mint.key()).unwrap_or(0) as u128)
+            .collect::<Vec<u128>>();
+        split_by_weights(&weights, token_in_net_amount)?
+    } else if leg_token_in_amounts.is_empty() {
+        split_pro_rata(&legs, token_in_net_amount)?
+    } else {
+        require!(
+            leg_token_in_amounts.len() == legs.len()
+                && leg_token_in_amounts
+                    .iter()
+                    .map(|a| *a as u128)
+                    .sum::<u128>()
+                    == token_in_net_amount as u128,
+            FulfillRedemptionRequestBasketErrorCode::LegAmountsMismatch
+        );
+        leg_token_in_amounts
+    };
+
+    settle_redemption_token_in(&RedemptionTokenInParams {
+        token_in_program: &ctx.accounts.token_in_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount,
+        token_in_fee_amount,
+        vault_token_in_account: &ctx.accounts.vault_token_in_account,
+        boss_token_in_account: &ctx.accounts.boss_token_in_account,
+        redemption_vault_authority: &ctx.accounts.redemption_vault_authority,
+        redemption_vault_authority_bump: ctx.bumps.redemption_vault_authority,
+        mint_authority_pda: &ctx.accounts.mint_authority,
+    })?;
+
+    let vault_authority_signer_seeds: &[&[&[u8]]] = &[&[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.redemption_vault_authority],
+    ]];
+    let mint_authority_signer_seeds: &[&[&[u8]]] =
+        &[&[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]]];
+
+    let mut token_out_amounts = Vec::with_capacity(legs.len());
+    for (leg, leg_token_in_amount) in legs.iter().zip(leg_token_in_amounts.iter()) {
+        let token_out_amount = scale_amount_rounded(
+            *leg_token_in_amount,
+            price as u128,
+            1,
+            leg.mint.decimals as u32,
+            ctx.accounts.token_in_mint.decimals as u32 + PRICE_DECIMALS as u32,
+            PAYOUT_ROUNDING,
+        )
+        .filter(|amount| *amount <= u64::MAX as u128)
+        .ok_or(RedemptionCoreError::OverflowError)? as u64;
+
+        if token_out_amount > 0 {
+            if program_controls_mint(&leg.mint, &ctx.accounts.mint_authority) {
+                mint_tokens(
+                    &ctx.accounts.token_out_program,
+                    &leg.mint,
+                    &leg.user_token_out_account.to_account_info(),
+                    &ctx.accounts.mint_authority,
+                    mint_authority_signer_seeds,
+                    token_out_amount,
+                    0, // No max supply cap for redemptions
+                )?;
+            } else {
+                transfer_tokens(
+                    &leg.mint,
+                    &ctx.accounts.token_out_program,
+                    &leg.vault_token_out_account,
+                    &leg.user_token_out_account,
+                    &ctx.accounts.redemption_vault_authority,
+                    Some(vault_authority_signer_seeds),
+                    token_out_amount,
+                )?;
+            }
+        }
+        token_out_amounts.push(token_out_amount);
+    }
+    require!(
+        token_out_amounts.iter().any(|amount| *amount > 0),
+        RedemptionCoreError::ZeroRedemptionPayout
+    );
+
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+    redemption_offer.executed_redemptions = redemption_offer
+        .executed_redemptions
+        .checked_add(token_in_amount as u128)
+        .ok_or(FulfillRedemptionRequestBasketErrorCode::ArithmeticOverflow)?;
+    redemption_offer.total_fees_collected_token_in = redemption_offer
+        .total_fees_collected_token_in
+        .checked_add(token_in_fee_amount)
+        .ok_or(FulfillRedemptionRequestBasketErrorCode::ArithmeticOverflow)?;
+    redemption_offer.requested_redemptions = redemption_offer
+        .requested_redemptions
+        .checked_sub(token_in_amount as u128)
+        .ok_or(FulfillRedemptionRequestBasketErrorCode::ArithmeticUnderflow)?;
+
+    msg!(
+        "Redemption request fulfilled in basket: request={}, token_in={} (net={}, fee={}), legs={}, price={}, redeemer={}",
+        ctx.accounts.redemption_request.key(),
+        token_in_amount,
+        token_in_net_amount,
+        token_in_fee_amount,
+        legs.len(),
+        price,
+        ctx.accounts.redeemer.key()
+    );
+
+    let (request_slot, request_slot_index) = ctx.accounts.redemption_request.queue_position();
+    emit!(RedemptionRequestBasketFulfilledEvent {
+        redemption_request_pda: ctx.accounts.redemption_request.key(),
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        redeemer: ctx.accounts.redeemer.key(),
+        token_in_net_amount,
+        token_in_fee_amount,
+        token_out_mints: legs.iter().map(|leg| leg.mint.key()).collect(),
+        leg_token_in_amounts,
+        token_out_amounts,
+        current_price: price,
+        price_timestamp,
+        request_slot,
+        request_slot_index,
+    });
+
+    Ok(())
+}
+
+/// Reads and validates the payout legs passed as remaining accounts
+///
+/// Leg mints must be accepted payout mints of the redemption offer, or mints of the
+/// in-kind basket when one is passed.
+fn load_basket_legs<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, FulfillRedemptionRequestBasket<'info>>,
+    basket: Option<&RedemptionBasket>,
+) -> Result<Vec<BasketLeg<'info>>> {
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len().is_multiple_of(ACCOUNTS_PER_LEG),
+        FulfillRedemptionRequestBasketErrorCode::InvalidLegAccounts
+    );
+
+    let token_out_program = ctx.accounts.token_out_program.key();
+    let vault_authority = ctx.accounts.redemption_vault_authority.key();
+    let redeemer = ctx.accounts.redeemer.key();
+
+    let mut legs: Vec<BasketLeg<'info>> =
+        Vec::with_capacity(remaining_accounts.len() / ACCOUNTS_PER_LEG);
+    for accounts in remaining_accounts.chunks(ACCOUNTS_PER_LEG) {
+        for account_info in accounts {
+            require_keys_eq!(
+                *account_info.owner,
+                token_out_program,
+                FulfillRedemptionRequestBasketErrorCode::InvalidLegAccounts
+            );
+        }
+
+        let mint = InterfaceAccount::<Mint>::try_from(&accounts[0])?;
+        let accepted = match basket {
+            Some(basket) => basket.weight_of(&mint.key()).is_some(),
+            None => ctx
+                .accounts
+                .redemption_offer
+                .accepts_payout_mint(&mint.key()),
+        };
+        require!(
+            accepted,
+            FulfillRedemptionRequestBasketErrorCode::InvalidTokenOutMint
+        );
+        require!(
+            legs.iter().all(|leg| leg.mint.key() != mint.key()),
+            FulfillRedemptionRequestBasketErrorCode::DuplicateLegMint
+        );
+
+        require_keys_eq!(
+            accounts[1].key(),
+            get_associated_token_address_with_program_id(
+                &vault_authority,
+                &mint.key(),
+                &token_out_program
+            ),
+            FulfillRedemptionRequestBasketErrorCode::InvalidLegAccounts
+        );
+        let vault_token_out_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+
+        let user_token_out_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[2])?;
+        require!(
+            user_token_out_account.owner == redeemer && user_token_out_account.mint == mint.key(),
+            FulfillRedemptionRequestBasketErrorCode::InvalidLegAccounts
+        );
+
+        legs.push(BasketLeg {
+            mint,
+            vault_token_out_account,
+            user_token_out_account,
+        });
+    }
+
+    Ok(legs)
+}
+
+/// Splits the net token_in amount pro-rata to the vault balances of the legs
+///
+/// Balances are normalized to the largest decimals among the legs so that mints
+/// with different decimals are weighted by value.
+fn split_pro_rata(legs: &[BasketLeg], token_in_net_amount: u64) -> Result<Vec<u64>> {
+    let max_decimals = legs.iter().map(|leg| leg.mint.decimals).max().unwrap_or(0);
+    let weights = legs
+        .iter()
+        .map(|leg| {
+            10u128
+                .checked_pow((max_decimals - leg.mint.decimals) as u32)
+                .and_then(|factor| (leg.vault_token_out_account.amount as u128).checked_mul(factor))
+                .ok_or_else(|| error!(RedemptionCoreError::OverflowError))
+        })
+        .collect::<Result<Vec<u128>>>()?;
+    require!(
+        weights.iter().any(|weight| *weight > 0),
+        FulfillRedemptionRequestBasketErrorCode::EmptyBasketVaults
+    );
+
+    split_by_weights(&weights, token_in_net_amount)
+}
+
+/// Splits the net token_in amount between the legs proportionally to the weights
+///
+/// The last leg receives the rounding remainder.
+fn split_by_weights(weights: &[u128], token_in_net_amount: u64) -> Result<Vec<u64>> {
+    let total_weight: u128 = weights.iter().sum();
+
+    let mut amounts = Vec::with_capacity(weights.len());
+    let mut allocated: u64 = 0;
+    for (index, weight) in weights.iter().enumerate() {
+        let amount = if index == weights.len() - 1 {
+            token_in_net_amount - allocated
+        } else {
+            scale_amount(token_in_net_amount, *weight, total_weight, 0, 0)
+                .ok_or(RedemptionCoreError::OverflowError)? as u64
+        };
+        allocated += amount;
+        amounts.push(amount);
+    }
+
+    Ok(amounts)
+}
+
+/// Error codes for basket redemption fulfillment operations
+#[error_code]
+pub enum FulfillRedemptionRequestBasketErrorCode {
+    /// Caller is not authorized (redemption_admin mismatch)
+    #[msg("Unauthorized: redemption_admin signature required")]
+    Unauthorized,
+
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+
+    /// Fulfillment is paused on the redemption offer
+    #[msg("Redemption fulfillment is paused")]
+    FulfillmentPaused,
+
+    /// The program kill switch is activated
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+
+    /// Redemption offer mismatch
+    #[msg("Redemption offer does not match request")]
+    OfferMismatch,
+
+    /// Invalid token_in mint
+    #[msg("Invalid token_in mint")]
+    InvalidTokenInMint,
+
+    /// A leg mint is neither the offer's token_out mint nor an allowlisted settlement mint,
+    /// or not a basket mint for in-kind offers
+    #[msg("Invalid token_out mint")]
+    InvalidTokenOutMint,
+
+    /// Invalid redeemer
+    #[msg("Redeemer does not match redemption request")]
+    InvalidRedeemer,
+
+    /// Remaining accounts are not a list of (mint, vault account, user account) legs
+    #[msg("Invalid basket leg accounts")]
+    InvalidLegAccounts,
+
+    /// The same payout mint was passed in more than one leg
+    #[msg("Duplicate payout mint in basket")]
+    DuplicateLegMint,
+
+    /// Explicit leg amounts don't match the legs or the net token_in amount, or were
+    /// passed for an in-kind offer
+    #[msg("Leg amounts must match the legs and sum to the net token_in amount")]
+    LegAmountsMismatch,
+
+    /// None of the basket's vault accounts hold a balance to split pro-rata
+    #[msg("Basket vaults are empty")]
+    EmptyBasketVaults,
+
+    /// The redemption offer pays out in kind but its basket was not passed
+    #[msg("Redemption basket required for in-kind redemption offers")]
+    RedemptionBasketRequired,
+
+    /// An in-kind fulfillment does not pay out every mint of the basket
+    #[msg("In-kind fulfillment must pay out every basket mint")]
+    IncompleteBasket,
+
+    /// The redemption offer charges its fee in token_out
+    #[msg("Basket fulfillment requires the fee to be charged in token_in")]
+    TokenOutFeeNotSupported,
+
+    /// Arithmetic overflow occurred
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    /// Arithmetic underflow occurred
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+}
@@ -0,0 +1,208 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS};
+use crate::instructions::redemption::{DualRedemptionOffer, RedemptionOffer};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when a dual redemption offer is successfully created
+///
+/// Provides transparency for tracking dual redemption offer creation and its payout split.
+#[event]
+pub struct DualRedemptionOfferCreatedEvent {
+    /// The PDA address of the newly created dual redemption offer
+    pub dual_redemption_offer_pda: Pubkey,
+    /// The PDA address of the base redemption offer providing NAV and fee
+    pub redemption_offer_pda: Pubkey,
+    /// The input token mint for redemptions (ONyc)
+    pub token_in_mint: Pubkey,
+    /// The principal output token mint
+    pub token_out_mint_1: Pubkey,
+    /// The secondary output token mint
+    pub token_out_mint_2: Pubkey,
+    /// Share of the net redeemed amount paid in token_out_2, in basis points
+    pub token_out_2_basis_points: u16,
+}
+
+/// Account structure for creating a dual redemption offer
+///
+/// This struct defines the accounts required to attach a second output token to an
+/// existing redemption offer. The base redemption offer must already exist for the
+/// ONyc/token_out_1 pair and keeps providing the NAV and fee for both legs.
+#[derive(Accounts)]
+pub struct MakeDualRedemptionOffer<'info> {
+    /// Program state account containing boss and redemption_admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The base redemption offer for the token_in/token_out_1 pair
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint_1.key().as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program-derived authority that controls redemption offer vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The input token mint for redemptions (ONyc)
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The principal output token mint, i.e. the token_out of the base redemption offer
+    pub token_out_mint_1: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The secondary output token mint
+    ///
+    /// Must differ from both the input and principal mints and be owned by the
+    /// provided secondary token program.
+    #[account(
+        constraint = token_out_mint_2.key() != token_out_mint_1.key()
+            && token_out_mint_2.key() != token_in_mint.key()
+            @ MakeDualRedemptionOfferErrorCode::InvalidSecondaryMint,
+        constraint = *token_out_mint_2.to_account_info().owner == token_out_2_program.key()
+            @ MakeDualRedemptionOfferErrorCode::InvalidTokenProgram
+    )]
+    pub token_out_mint_2: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the secondary output token
+    pub token_out_2_program: Interface<'info, TokenInterface>,
+
+    /// Redemption vault account storing secondary output tokens for payouts
+    ///
+    /// Created automatically if needed. The vaults of the base redemption offer
+    /// are reused for the input and principal tokens.
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub vault_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The dual redemption offer account storing the secondary leg configuration
+    ///
+    /// Derived from all three mints ensuring one dual redemption offer per token triple.
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + DualRedemptionOffer::INIT_SPACE,
+        seeds = [
+            seeds::DUAL_REDEMPTION_OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint_1.key().as_ref(),
+            token_out_mint_2.key().as_ref()
+        ],
+        bump
+    )]
+    pub dual_redemption_offer: Box<Account<'info, DualRedemptionOffer>>,
+
+    /// The account creating the dual redemption offer (must be boss or redemption_admin)
+    #[account(
+        mut,
+        constraint = signer.key() == state.boss || signer.key() == state.redemption_admin
+            @ MakeDualRedemptionOfferErrorCode::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a dual redemption offer paying two output tokens per redemption
+///
+/// Both output legs are valued at the NAV of the offer underlying the base
+/// redemption offer, after deducting the base redemption offer's fee. The net
+/// redeemed amount is split so that `token_out_2_basis_points / 10000` of it is paid
+/// in token_out_2 and the remainder in token_out_1.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_out_2_basis_points` - Share of the net redeemed amount paid in token_out_2
+///
+/// # Returns
+/// * `Ok(())` - If the dual redemption offer is successfully created
+/// * `Err(MakeDualRedemptionOfferErrorCode::Unauthorized)` - If caller is neither boss nor redemption_admin
+/// * `Err(MakeDualRedemptionOfferErrorCode::InvalidRatio)` - If the share is zero or exceeds 10000
+/// * `Err(MakeDualRedemptionOfferErrorCode::InvalidSecondaryMint)` - If the secondary mint repeats another mint
+///
+/// # Access Control
+/// - Only the boss or redemption_admin can call this instruction
+///
+/// # Effects
+/// - Creates the dual redemption offer account
+/// - Initializes the redemption vault account of the secondary token if needed
+///
+/// # Events
+/// * `DualRedemptionOfferCreatedEvent` - Emitted with the dual redemption offer configuration
+pub fn make_dual_redemption_offer(
+    ctx: Context<MakeDualRedemptionOffer>,
+    token_out_2_basis_points: u16,
+) -> Result<()> {
+    require!(
+        token_out_2_basis_points > 0 && token_out_2_basis_points <= MAX_BASIS_POINTS,
+        MakeDualRedemptionOfferErrorCode::InvalidRatio
+    );
+
+    let dual_redemption_offer = &mut ctx.accounts.dual_redemption_offer;
+    dual_redemption_offer.redemption_offer = ctx.accounts.redemption_offer.key();
+    dual_redemption_offer.token_in_mint = ctx.accounts.token_in_mint.key();
+    dual_redemption_offer.token_out_mint_1 = ctx.accounts.token_out_mint_1.key();
+    dual_redemption_offer.token_out_mint_2 = ctx.accounts.token_out_mint_2.key();
+    dual_redemption_offer.token_out_2_basis_points = token_out_2_basis_points;
+    dual_redemption_offer.executed_redemptions = 0;
+    dual_redemption_offer.total_fees_collected_token_in = 0;
+    dual_redemption_offer.bump = ctx.bumps.dual_redemption_offer;
+
+    msg!(
+        "Dual redemption offer created at: {}, base redemption offer: {}, token_out_2 share: {}",
+        ctx.accounts.dual_redemption_offer.key(),
+        ctx.accounts.redemption_offer.key(),
+        token_out_2_basis_points
+    );
+
+    emit!(DualRedemptionOfferCreatedEvent {
+        dual_redemption_offer_pda: ctx.accounts.dual_redemption_offer.key(),
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        token_in_mint: ctx.accounts.token_in_mint.key(),
+        token_out_mint_1: ctx.accounts.token_out_mint_1.key(),
+        token_out_mint_2: ctx.accounts.token_out_mint_2.key(),
+        token_out_2_basis_points,
+    });
+
+    Ok(())
+}
+
+/// Error codes for dual redemption offer creation operations
+#[error_code]
+pub enum MakeDualRedemptionOfferErrorCode {
+    /// Caller is not authorized (must be boss or redemption_admin)
+    #[msg("Unauthorized: only boss or redemption_admin can create dual redemption offers")]
+    Unauthorized,
+
+    /// The secondary share must be within 1..=10000 basis points
+    #[msg("Invalid ratio: token_out_2_basis_points must be > 0 and <= 10000")]
+    InvalidRatio,
+
+    /// The secondary mint must differ from the input and principal mints
+    #[msg("Invalid secondary token mint")]
+    InvalidSecondaryMint,
+
+    /// A mint is not owned by the provided token program
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+}
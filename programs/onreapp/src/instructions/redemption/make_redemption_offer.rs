@@ -1,7 +1,8 @@
-use crate::constants::{seeds, MAX_ALLOWED_FEE_BPS};
-use crate::instructions::redemption::RedemptionOffer;
-use crate::instructions::Offer;
-use crate::state::State;
+use crate::constants::{seeds, MAX_SETTLEMENT_MINTS};
+use crate::instructions::redemption::{FeeCurrency, RedemptionOffer, RedemptionPriceMode};
+use crate::instructions::{MinimumDenomination, Offer};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::{validate_mint_decimals, validate_mint_extensions};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -31,9 +32,17 @@ pub struct RedemptionOfferCreatedEvent {
 #[derive(Accounts)]
 pub struct MakeRedemptionOffer<'info> {
     /// Program state account containing boss and redemption_admin authorization
-    #[account(seeds = [seeds::STATE], bump = state.bump)]
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Box<Account<'info, State>>,
 
+    /// Program-wide configuration providing the maximum fee
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The original offer that this redemption offer is associated with
     ///
     /// The redemption offer uses the inverse token pair of the original offer.
@@ -142,7 +151,10 @@ pub struct MakeRedemptionOffer<'info> {
 /// # Returns
 /// * `Ok(())` - If the redemption offer is successfully created
 /// * `Err(MakeRedemptionOfferErrorCode::Unauthorized)` - If caller is neither boss nor redemption_admin (validated in accounts)
-/// * `Err(MakeRedemptionOfferErrorCode::InvalidFee)` - If fee_basis_points exceeds 10000
+/// * `Err(MakeRedemptionOfferErrorCode::InvalidFee)` - If fee_basis_points exceeds the configured maximum
+/// * `Err(TokenUtilsErrorCode::PermanentDelegateNotSupported)` - If a mint has a permanent delegate
+/// * `Err(TokenUtilsErrorCode::ConfidentialTransfersNotSupported)` - If a mint enables confidential transfers
+/// * `Err(TokenUtilsErrorCode::UnsupportedMintExtension)` - If a mint has a non-allowlisted extension
 ///
 /// # Access Control
 /// - Only the boss or redemption_admin can call this instruction
@@ -158,12 +170,18 @@ pub fn make_redemption_offer(
     ctx: Context<MakeRedemptionOffer>,
     fee_basis_points: u16,
 ) -> Result<()> {
-    // Validate fee is within valid range (0-1000 basis points = 0-10%)
+    // Validate fee is within the configured cap
     require!(
-        fee_basis_points <= MAX_ALLOWED_FEE_BPS,
+        fee_basis_points <= ctx.accounts.config.max_fee_bps,
         MakeRedemptionOfferErrorCode::InvalidFee
     );
 
+    // Only allow mints whose Token-2022 extensions are known to be safe
+    validate_mint_extensions(&ctx.accounts.token_in_mint)?;
+    validate_mint_extensions(&ctx.accounts.token_out_mint)?;
+    validate_mint_decimals(&ctx.accounts.token_in_mint)?;
+    validate_mint_decimals(&ctx.accounts.token_out_mint)?;
+
     // Initialize the redemption offer
     let redemption_offer = &mut ctx.accounts.redemption_offer;
     redemption_offer.offer = ctx.accounts.offer.key();
@@ -173,6 +191,20 @@ pub fn make_redemption_offer(
     redemption_offer.executed_redemptions = 0;
     redemption_offer.requested_redemptions = 0;
     redemption_offer.request_counter = 0;
+    redemption_offer.settlement_mints = [Pubkey::default(); MAX_SETTLEMENT_MINTS];
+    redemption_offer.total_fees_collected_token_in = 0;
+    redemption_offer.fee_currency = FeeCurrency::TokenIn;
+    redemption_offer.min_redemption_amount = 0;
+    redemption_offer.min_redemption_denomination = MinimumDenomination::Token;
+    redemption_offer.in_kind = false;
+    redemption_offer.price_mode = RedemptionPriceMode::AtFulfillment;
+    redemption_offer.pause_new_requests = false;
+    redemption_offer.pause_fulfillment = false;
+    redemption_offer.allow_instant = false;
+    redemption_offer.instant_fee_basis_points = 0;
+    redemption_offer.token_in_decimals = ctx.accounts.token_in_mint.decimals;
+    redemption_offer.token_out_decimals = ctx.accounts.token_out_mint.decimals;
+    redemption_offer.decimals_recorded = true;
     redemption_offer.bump = ctx.bumps.redemption_offer;
 
     msg!(
@@ -199,7 +231,7 @@ pub enum MakeRedemptionOfferErrorCode {
     #[msg("Unauthorized: only boss or redemption_admin can create redemption offers")]
     Unauthorized,
 
-    /// Fee basis points exceeds maximum allowed value of 1000 (10%)
-    #[msg("Invalid fee: fee_basis_points must be <= 1000")]
+    /// Fee basis points exceeds the configured maximum
+    #[msg("Invalid fee: fee_basis_points exceeds the configured maximum")]
     InvalidFee,
 }
@@ -0,0 +1,177 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Bytes appended to the redemption offer layout by the per-slot request ordering
+/// fields and the enlarged reserved space
+const ORDERING_LAYOUT_GROWTH: usize = 40;
+
+/// Event emitted when a legacy redemption offer account is migrated to the current layout
+///
+/// Provides transparency for tracking redemption offer account reallocations.
+#[event]
+pub struct RedemptionOfferMigratedEvent {
+    /// The PDA address of the migrated redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Account data length before migration
+    pub old_size: u64,
+    /// Account data length after migration
+    pub new_size: u64,
+}
+
+/// Account structure for migrating a redemption offer to the current layout
+///
+/// Only the boss can migrate redemption offers and pays for the additional rent.
+///
+/// Note: The redemption offer account is NOT deserialized since its legacy size does
+/// not match the current RedemptionOffer definition.
+#[derive(Accounts)]
+pub struct MigrateRedemptionOffer<'info> {
+    /// The legacy redemption offer account to be reallocated
+    ///
+    /// This account is validated as a PDA derived from token mint addresses.
+    /// Its owner and discriminator are validated manually in the handler.
+    /// CHECK: Manual validation of owner and discriminator without deserialization
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_offer: UncheckedAccount<'info>,
+
+    /// The input token mint of the redemption offer, used for PDA derivation
+    /// CHECK: Only used as a PDA seed
+    pub token_in_mint: UncheckedAccount<'info>,
+
+    /// The output token mint of the redemption offer, used for PDA derivation
+    /// CHECK: Only used as a PDA seed
+    pub token_out_mint: UncheckedAccount<'info>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to migrate redemption offers and pay the additional rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for the rent top-up transfer
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy redemption offer account to the current layout
+///
+/// Redemption offers created before requests recorded their creation slot lack the
+/// per-slot ordering fields. Those fields and the enlarged reserved space follow all
+/// existing fields, so the account only needs to grow; the appended bytes are zeroed,
+/// which means no request has been ordered within the current slot yet.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the redemption offer is successfully migrated
+/// * `Err(MigrateRedemptionOfferErrorCode::InvalidRedemptionOfferOwner)` - If the account is not owned by the program
+/// * `Err(MigrateRedemptionOfferErrorCode::InvalidRedemptionOfferData)` - If the discriminator does not match
+/// * `Err(MigrateRedemptionOfferErrorCode::AlreadyMigrated)` - If the account already has the current size
+/// * `Err(MigrateRedemptionOfferErrorCode::UnexpectedRedemptionOfferSize)` - If the account has an unknown size
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Redemption offer account is resized to `8 + RedemptionOffer::INIT_SPACE` bytes
+/// - Boss pays the rent difference for the larger account
+///
+/// # Events
+/// * `RedemptionOfferMigratedEvent` - Emitted with the old and new account sizes
+pub fn migrate_redemption_offer(ctx: Context<MigrateRedemptionOffer>) -> Result<()> {
+    let redemption_offer = &ctx.accounts.redemption_offer;
+
+    require_keys_eq!(
+        *redemption_offer.owner,
+        crate::ID,
+        MigrateRedemptionOfferErrorCode::InvalidRedemptionOfferOwner
+    );
+
+    let new_size = 8 + RedemptionOffer::INIT_SPACE;
+    let legacy_size = new_size - ORDERING_LAYOUT_GROWTH;
+    let old_size = redemption_offer.data_len();
+    {
+        let data = redemption_offer.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *RedemptionOffer::DISCRIMINATOR,
+            MigrateRedemptionOfferErrorCode::InvalidRedemptionOfferData
+        );
+    }
+    require!(
+        old_size != new_size,
+        MigrateRedemptionOfferErrorCode::AlreadyMigrated
+    );
+    require!(
+        old_size == legacy_size,
+        MigrateRedemptionOfferErrorCode::UnexpectedRedemptionOfferSize
+    );
+
+    // Top up rent for the additional space
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let missing_lamports = required_lamports.saturating_sub(redemption_offer.lamports());
+    if missing_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.boss.to_account_info(),
+                    to: redemption_offer.to_account_info(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    redemption_offer.resize(new_size)?;
+    redemption_offer.try_borrow_mut_data()?[legacy_size..].fill(0);
+
+    msg!(
+        "Redemption offer migrated: {}, size: {} -> {}",
+        redemption_offer.key(),
+        old_size,
+        new_size
+    );
+
+    emit!(RedemptionOfferMigratedEvent {
+        redemption_offer_pda: redemption_offer.key(),
+        old_size: old_size as u64,
+        new_size: new_size as u64,
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption offer migration operations
+#[error_code]
+pub enum MigrateRedemptionOfferErrorCode {
+    /// Redemption offer account is not owned by this program
+    #[msg("Redemption offer account must be owned by this program")]
+    InvalidRedemptionOfferOwner,
+    /// Redemption offer account data does not start with the RedemptionOffer discriminator
+    #[msg("Invalid redemption offer account data")]
+    InvalidRedemptionOfferData,
+    /// Redemption offer account already has the current layout
+    #[msg("Redemption offer is already migrated")]
+    AlreadyMigrated,
+    /// Redemption offer account size matches neither the legacy nor the current layout
+    #[msg("Unexpected redemption offer account size")]
+    UnexpectedRedemptionOfferSize,
+}
@@ -1,15 +1,57 @@
+pub mod add_redemption_settlement_mint;
+pub mod assign_redemption_request;
+pub mod cancel_all_redemption_requests;
 pub mod cancel_redemption_request;
+pub mod close_dual_redemption_offer;
+pub mod close_redemption_offer;
+pub mod configure_redemption_basket;
 pub mod create_redemption_request;
+pub mod dual_redemption_offer_state;
 pub mod fulfill_redemption_request;
+pub mod fulfill_redemption_request_basket;
+pub mod make_dual_redemption_offer;
 pub mod make_redemption_offer;
+pub mod migrate_redemption_offer;
+pub mod redemption_basket_state;
 pub mod redemption_offer_state;
 pub mod redemption_utils;
+pub mod remove_redemption_settlement_mint;
+pub mod reprice_redemption_request;
+pub mod set_redemption_offer_fee_currency;
+pub mod set_redemption_offer_in_kind;
+pub mod set_redemption_offer_instant;
+pub mod set_redemption_offer_pause;
+pub mod set_redemption_offer_price_mode;
+pub mod take_dual_redemption_offer;
+pub mod take_redemption_instant;
 pub mod update_redemption_offer_fee;
+pub mod update_redemption_offer_min_amount;
 
+pub use add_redemption_settlement_mint::*;
+pub use assign_redemption_request::*;
+pub use cancel_all_redemption_requests::*;
 pub use cancel_redemption_request::*;
+pub use close_dual_redemption_offer::*;
+pub use close_redemption_offer::*;
+pub use configure_redemption_basket::*;
 pub use create_redemption_request::*;
+pub use dual_redemption_offer_state::*;
 pub use fulfill_redemption_request::*;
+pub use fulfill_redemption_request_basket::*;
+pub use make_dual_redemption_offer::*;
 pub use make_redemption_offer::*;
+pub use migrate_redemption_offer::*;
+pub use redemption_basket_state::*;
 pub use redemption_offer_state::*;
 pub use redemption_utils::*;
+pub use remove_redemption_settlement_mint::*;
+pub use reprice_redemption_request::*;
+pub use set_redemption_offer_fee_currency::*;
+pub use set_redemption_offer_in_kind::*;
+pub use set_redemption_offer_instant::*;
+pub use set_redemption_offer_pause::*;
+pub use set_redemption_offer_price_mode::*;
+pub use take_dual_redemption_offer::*;
+pub use take_redemption_instant::*;
 pub use update_redemption_offer_fee::*;
+pub use update_redemption_offer_min_amount::*;
@@ -0,0 +1,43 @@
+use crate::constants::MAX_REDEMPTION_BASKET_MINTS;
+use anchor_lang::prelude::*;
+
+/// Underlying tokens an in-kind redemption offer pays out
+///
+/// Derived from the redemption offer address and configured by the boss. Each
+/// mint receives its weight's share of the redeemed value, valued at the NAV of
+/// the underlying offer.
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionBasket {
+    /// The redemption offer this basket belongs to
+    pub redemption_offer: Pubkey,
+    /// Underlying mints of the basket, empty slots hold the default pubkey
+    pub mints: [Pubkey; MAX_REDEMPTION_BASKET_MINTS],
+    /// Share of the redeemed value paid in each mint, in basis points summing to 10000
+    pub weights_bps: [u16; MAX_REDEMPTION_BASKET_MINTS],
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl RedemptionBasket {
+    /// Returns the number of mints in the basket
+    pub fn active_count(&self) -> usize {
+        self.mints
+            .iter()
+            .filter(|mint| **mint != Pubkey::default())
+            .count()
+    }
+
+    /// Returns the weight of the given mint, or None if it is not in the basket
+    pub fn weight_of(&self, mint: &Pubkey) -> Option<u16> {
+        if *mint == Pubkey::default() {
+            return None;
+        }
+        self.mints
+            .iter()
+            .position(|m| m == mint)
+            .map(|index| self.weights_bps[index])
+    }
+}
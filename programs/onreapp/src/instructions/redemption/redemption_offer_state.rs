@@ -1,3 +1,5 @@
+use crate::constants::MAX_SETTLEMENT_MINTS;
+use crate::instructions::MinimumDenomination;
 use anchor_lang::prelude::*;
 
 /// Redemption offer for converting ONyc tokens back to stable tokens
@@ -31,8 +33,94 @@ pub struct RedemptionOffer {
     pub request_counter: u64,
     /// PDA bump seed for account derivation
     pub bump: u8,
+    /// Boss-allowlisted alternative mints redemptions may be settled in (e.g., USDT)
+    ///
+    /// Empty slots hold the default pubkey.
+    pub settlement_mints: [Pubkey; MAX_SETTLEMENT_MINTS],
+    /// Cumulative token_in fees collected by fulfilled redemptions
+    ///
+    /// The cumulative token_in volume is tracked by `executed_redemptions`. Uses u64
+    /// since the remaining reserved space cannot hold a u128. Fees charged in the
+    /// payout currency are not included.
+    pub total_fees_collected_token_in: u64,
+    /// Side of the redemption the fee is charged on when fulfilling requests and on
+    /// instant redemptions
+    pub fee_currency: FeeCurrency,
+    /// Slot in which the latest redemption request was created
+    pub last_request_slot: u64,
+    /// Intra-slot index of the latest redemption request
+    pub last_request_slot_index: u32,
+    /// Minimum size of a single redemption request (0 = no minimum)
+    ///
+    /// Expressed in token_in base units, or in USD with scale=9 when the
+    /// denomination is `Quote`.
+    pub min_redemption_amount: u64,
+    /// Unit the minimum redemption size is expressed in
+    pub min_redemption_denomination: MinimumDenomination,
+    /// Whether requests are paid out in kind from the offer's `RedemptionBasket`
+    ///
+    /// In-kind requests can only be fulfilled by `fulfill_redemption_request_basket`.
+    pub in_kind: bool,
+    /// When the NAV used to price a redemption request is taken
+    pub price_mode: RedemptionPriceMode,
+    /// Whether new redemption requests are rejected
+    pub pause_new_requests: bool,
+    /// Whether fulfillment of pending redemption requests is halted
+    pub pause_fulfillment: bool,
+    /// Whether users may redeem instantly against the funded redemption vault
+    pub allow_instant: bool,
+    /// Fee in basis points charged on instant redemptions, in the fee currency
+    pub instant_fee_basis_points: u16,
+    /// Decimals of token_in_mint when the redemption offer was made
+    pub token_in_decimals: u8,
+    /// Decimals of token_out_mint when the redemption offer was made
+    pub token_out_decimals: u8,
+    /// Whether the decimals above were recorded, unset for redemption offers made
+    /// before decimals were stored
+    pub decimals_recorded: bool,
     /// Reserved space for future fields
-    pub reserved: [u8; 109],
+    pub reserved: [u8; 13],
+}
+
+/// Currency a redemption fee is charged in
+///
+/// Defaults to `TokenIn`, which is also the zeroed value of redemption offers
+/// created before the selector existed. Dual redemption takes always charge the
+/// fee in token_in.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum FeeCurrency {
+    /// Fee is deducted from the redeemed token_in (e.g., ONyc) before pricing
+    #[default]
+    TokenIn,
+    /// Fee is deducted from the token_out payout (e.g., USDC) and paid to the boss
+    TokenOut,
+}
+
+/// When the NAV pricing a redemption request is taken
+///
+/// Defaults to `AtFulfillment`, which is also the zeroed value of redemption offers
+/// created before the selector existed.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum RedemptionPriceMode {
+    /// The NAV is read when the request is fulfilled, accruing until then
+    #[default]
+    AtFulfillment,
+    /// The NAV is read and stored on the request when it is created
+    LockedAtRequest,
+}
+
+impl RedemptionOffer {
+    /// Returns whether redemptions may be paid out in the given mint
+    ///
+    /// Accepts the offer's own token_out_mint and any allowlisted settlement mint.
+    pub fn accepts_payout_mint(&self, mint: &Pubkey) -> bool {
+        *mint == self.token_out_mint
+            || (*mint != Pubkey::default() && self.settlement_mints.contains(mint))
+    }
 }
 
 #[account]
@@ -48,6 +136,30 @@ pub struct RedemptionRequest {
     pub amount: u64,
     /// PDA bump seed for account derivation
     pub bump: u8,
+    /// Slot in which the request was created
+    pub slot: u64,
+    /// Index of the request among the offer's requests created in the same slot
+    pub slot_index: u32,
+    /// NAV locked at creation with scale=9, or 0 if priced at fulfillment
+    pub nav_used: u64,
+    /// Unix timestamp at which `nav_used` was read, or 0 if priced at fulfillment
+    pub price_timestamp: i64,
     /// Reserved space for future fields
-    pub reserved: [u8; 127],
+    pub reserved: [u8; 99],
+}
+
+impl RedemptionRequest {
+    /// Returns the request's position in the fulfillment queue
+    ///
+    /// Requests are ordered by creation slot and then by their index within the
+    /// slot, so two requests created in the same slot have a documented order.
+    /// Requests created before the ordering fields existed report slot 0.
+    pub fn queue_position(&self) -> (u64, u32) {
+        (self.slot, self.slot_index)
+    }
+
+    /// Returns the NAV and its timestamp if the price was locked at creation
+    pub fn locked_price(&self) -> Option<(u64, i64)> {
+        (self.price_timestamp != 0).then_some((self.nav_used, self.price_timestamp))
+    }
 }
@@ -1,6 +1,13 @@
 use crate::constants::{seeds, PRICE_DECIMALS};
-use crate::instructions::{calculate_current_step_price, find_active_vector_at, Offer};
-use crate::utils::{burn_tokens, calculate_fees, mint_tokens, program_controls_mint, transfer_tokens};
+use crate::instructions::redemption::{FeeCurrency, RedemptionOffer, RedemptionRequest};
+use crate::instructions::{
+    calculate_current_vector_price, find_active_vector_at, MinimumDenomination, Offer,
+};
+use crate::utils::error_context::require_with_context;
+use crate::utils::{
+    burn_tokens, calculate_fees, mint_tokens, program_controls_mint, scale_amount,
+    scale_amount_rounded, transfer_tokens, PAYOUT_ROUNDING,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -13,6 +20,24 @@ pub enum RedemptionCoreError {
     /// Arithmetic overflow occurred during calculations
     #[msg("Overflow error")]
     OverflowError,
+    /// A token_out fee is due but no boss token_out account was provided
+    #[msg("Boss token_out account required for fees charged in token_out")]
+    MissingBossTokenOutAccount,
+    /// The redemption is smaller than the redemption offer's minimum size
+    #[msg("Redemption below the offer's minimum")]
+    RedemptionBelowMinimum,
+    /// A quote-denominated minimum is configured but the underlying offer was not provided
+    #[msg("Underlying offer account required to value the redemption")]
+    OfferAccountRequired,
+    /// The redemption would pay out zero token_out after rounding down
+    #[msg("Redemption pays out zero tokens")]
+    ZeroRedemptionPayout,
+    /// The token_in mint's decimals differ from those recorded on the redemption offer
+    #[msg("Token_in mint decimals do not match the decimals recorded on the redemption offer")]
+    TokenInDecimalsMismatch,
+    /// The token_out mint's decimals differ from those recorded on the redemption offer
+    #[msg("Token_out mint decimals do not match the decimals recorded on the redemption offer")]
+    TokenOutDecimalsMismatch,
 }
 
 /// Result structure containing redemption processing calculations
@@ -23,8 +48,10 @@ pub struct RedemptionProcessResult {
     pub token_in_net_amount: u64,
     /// Fee amount deducted from the original token_in amount
     pub token_in_fee_amount: u64,
-    /// Calculated amount of token_out to be provided to the user
+    /// Calculated amount of token_out to be provided to the user (after fee deduction)
     pub token_out_amount: u64,
+    /// Fee amount deducted from the token_out payout
+    pub token_out_fee_amount: u64,
 }
 
 /// Core processing logic for redemption execution calculations
@@ -32,7 +59,8 @@ pub struct RedemptionProcessResult {
 /// Calculates token amount for redemption offers using direct price multiplication.
 /// The underlying offer has price "X token_out per token_in" (e.g., "2 USDC per ONyc"),
 /// and we multiply the token_in amount by this price to get the token_out amount.
-/// Fees are deducted from the token_in amount before calculating token_out.
+/// Depending on `fee_currency`, fees are deducted from the token_in amount before
+/// calculating token_out, or from the calculated token_out amount.
 ///
 /// # Arguments
 /// * `offer` - The underlying offer containing pricing vectors and configuration
@@ -40,6 +68,8 @@ pub struct RedemptionProcessResult {
 /// * `token_in_mint` - The token_in mint for decimal information (what user is redeeming)
 /// * `token_out_mint` - The token_out mint for decimal information (what user receives)
 /// * `redemption_fee_basis_points` - Fee in basis points (10000 = 100%)
+/// * `fee_currency` - Whether the fee is charged in token_in or token_out
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
 ///
 /// # Returns
 /// * `Ok(RedemptionProcessResult)` - Containing price, fees, and token_out amount
@@ -48,7 +78,9 @@ pub struct RedemptionProcessResult {
 /// # Price Calculation
 /// Uses the formula: `token_out = (token_in_net * price * 10^token_out_decimals) / (10^token_in_decimals * 10^9)`
 /// Price has 9 decimal places, so we divide by 10^9 to account for this.
-/// Fees are calculated as: `fee = token_in_amount * fee_basis_points / 10000`
+/// Fees are calculated as: `fee = ceil(token_in_amount * fee_basis_points / 10000)`, or on
+/// the gross token_out amount when charged in token_out. The payout rounds down, so a
+/// tiny redemption can pay out zero tokens
 ///
 /// # Example
 /// - Offer price: 2.0 USDC per ONyc (2_000_000_000 with 9 decimals)
@@ -56,12 +88,15 @@ pub struct RedemptionProcessResult {
 /// - Fee: 1% (100 basis points) = 0.1 ONyc
 /// - Net: 9.9 ONyc
 /// - User receives: 19.8 USDC (9.9 ONyc * 2.0 USDC/ONyc)
+/// - With the fee charged in token_out: 20 USDC gross, 0.2 USDC fee, user receives 19.8 USDC
 pub fn process_redemption_core(
     offer: &Offer,
     token_in_amount: u64,
     token_in_mint: &InterfaceAccount<Mint>,
     token_out_mint: &InterfaceAccount<Mint>,
     redemption_fee_basis_points: u16,
+    fee_currency: FeeCurrency,
+    seconds_per_year: u64,
 ) -> Result<RedemptionProcessResult> {
     let current_time = Clock::get()?.unix_timestamp as u64;
 
@@ -69,47 +104,241 @@ pub fn process_redemption_core(
     let active_vector = find_active_vector_at(offer, current_time)?;
 
     // Calculate current price with 9 decimals
-    let current_price = calculate_current_step_price(
-        active_vector.apr,
-        active_vector.base_price,
-        active_vector.base_time,
-        active_vector.price_fix_duration,
-    )?;
+    let current_price = calculate_current_vector_price(&active_vector, seconds_per_year)?;
 
-    // Calculate fees
-    let fee_amounts = calculate_fees(token_in_amount, redemption_fee_basis_points)?;
+    process_redemption_core_at_price(
+        current_price,
+        token_in_amount,
+        token_in_mint,
+        token_out_mint,
+        redemption_fee_basis_points,
+        fee_currency,
+    )
+}
+
+/// Redemption calculations of `process_redemption_core` at a given price
+///
+/// Used when the NAV was locked when the redemption request was created.
+///
+/// # Arguments
+/// * `current_price` - Price with scale=9 to value the redemption at
+/// * `token_in_amount` - Amount of token_in being redeemed by the user
+/// * `token_in_mint` - The token_in mint for decimal information
+/// * `token_out_mint` - The token_out mint for decimal information
+/// * `redemption_fee_basis_points` - Fee in basis points (10000 = 100%)
+/// * `fee_currency` - Whether the fee is charged in token_in or token_out
+///
+/// # Returns
+/// * `Ok(RedemptionProcessResult)` - Containing price, fees, and token_out amount
+/// * `Err(RedemptionCoreError::OverflowError)` - If the calculation overflows
+pub fn process_redemption_core_at_price(
+    current_price: u64,
+    token_in_amount: u64,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    redemption_fee_basis_points: u16,
+    fee_currency: FeeCurrency,
+) -> Result<RedemptionProcessResult> {
+    // Calculate fees on the side selected by the redemption offer
+    let token_in_fee_basis_points = match fee_currency {
+        FeeCurrency::TokenIn => redemption_fee_basis_points,
+        FeeCurrency::TokenOut => 0,
+    };
+    let fee_amounts = calculate_fees(token_in_amount, token_in_fee_basis_points)?;
 
     // Calculate token_out using direct multiplication with price (after fee deduction)
     // token_out_amount = (token_in_net_amount * price * 10^token_out_decimals) / (10^(token_in_decimals + 9))
     // price has 9 decimals, so we need to account for that in our calculation
-    let price_u128 = current_price as u128;
-    let token_in_net_amount_u128 = fee_amounts.token_in_net_amount as u128;
-
-    let numerator = token_in_net_amount_u128
-        .checked_mul(price_u128)
-        .ok_or(RedemptionCoreError::OverflowError)?
-        .checked_mul(10_u128.pow(token_out_mint.decimals as u32))
-        .ok_or(RedemptionCoreError::OverflowError)?;
-
-    let denominator = 10_u128.pow(token_in_mint.decimals as u32)
-        .checked_mul(10_u128.pow(PRICE_DECIMALS as u32))
-        .ok_or(RedemptionCoreError::OverflowError)?;
-
-    let result = numerator / denominator;
+    let result = scale_amount_rounded(
+        fee_amounts.token_in_net_amount,
+        current_price as u128,
+        1,
+        token_out_mint.decimals as u32,
+        token_in_mint.decimals as u32 + PRICE_DECIMALS as u32,
+        PAYOUT_ROUNDING,
+    )
+    .ok_or(RedemptionCoreError::OverflowError)?;
 
     // Validate result fits in u64 before casting
-    require!(result <= u64::MAX as u128, RedemptionCoreError::OverflowError);
+    require!(
+        result <= u64::MAX as u128,
+        RedemptionCoreError::OverflowError
+    );
 
-    let token_out_amount = result as u64;
+    let gross_token_out_amount = result as u64;
+
+    // Deduct the token_out fee from the payout, rounded up in favor of the protocol
+    let token_out_fee_basis_points = match fee_currency {
+        FeeCurrency::TokenIn => 0,
+        FeeCurrency::TokenOut => redemption_fee_basis_points,
+    };
+    let token_out_fee_amounts = calculate_fees(gross_token_out_amount, token_out_fee_basis_points)?;
 
     Ok(RedemptionProcessResult {
         price: current_price,
         token_in_net_amount: fee_amounts.token_in_net_amount,
         token_in_fee_amount: fee_amounts.token_in_fee_amount,
-        token_out_amount,
+        token_out_amount: token_out_fee_amounts.token_in_net_amount,
+        token_out_fee_amount: token_out_fee_amounts.token_in_fee_amount,
     })
 }
 
+/// Prices a pending redemption request for fulfillment
+///
+/// Uses the NAV locked on the request at creation if there is one, and the
+/// current NAV of the underlying offer otherwise.
+///
+/// # Arguments
+/// * `offer` - The underlying offer containing pricing vectors
+/// * `redemption_request` - The request being fulfilled
+/// * `token_in_mint` - The token_in mint for decimal information
+/// * `token_out_mint` - The token_out mint for decimal information
+/// * `redemption_fee_basis_points` - Fee in basis points (10000 = 100%)
+/// * `fee_currency` - Whether the fee is charged in token_in or token_out
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok((RedemptionProcessResult, price_timestamp))` - The calculations and the Unix
+///   timestamp at which the NAV used was read
+/// * `Err(_)` - If no active vector exists or the calculation overflows
+pub fn process_redemption_request(
+    offer: &Offer,
+    redemption_request: &RedemptionRequest,
+    token_in_mint: &InterfaceAccount<Mint>,
+    token_out_mint: &InterfaceAccount<Mint>,
+    redemption_fee_basis_points: u16,
+    fee_currency: FeeCurrency,
+    seconds_per_year: u64,
+) -> Result<(RedemptionProcessResult, i64)> {
+    match redemption_request.locked_price() {
+        Some((nav, price_timestamp)) => Ok((
+            process_redemption_core_at_price(
+                nav,
+                redemption_request.amount,
+                token_in_mint,
+                token_out_mint,
+                redemption_fee_basis_points,
+                fee_currency,
+            )?,
+            price_timestamp,
+        )),
+        None => Ok((
+            process_redemption_core(
+                offer,
+                redemption_request.amount,
+                token_in_mint,
+                token_out_mint,
+                redemption_fee_basis_points,
+                fee_currency,
+                seconds_per_year,
+            )?,
+            Clock::get()?.unix_timestamp,
+        )),
+    }
+}
+
+/// Enforces the redemption offer's minimum redemption size
+///
+/// With a `Token` denomination the token_in amount is compared directly. With a
+/// `Quote` denomination the amount is valued in USD with scale=9 at the current NAV
+/// of the underlying offer, so a fixed USD minimum stays correct as the NAV drifts.
+/// A minimum of 0 disables the check.
+///
+/// # Arguments
+/// * `redemption_offer` - The redemption offer holding the minimum configuration
+/// * `offer` - The underlying offer, required for `Quote` minimums
+/// * `token_in_amount` - Amount of token_in being redeemed
+/// * `token_in_mint` - The token_in mint for decimal information
+/// * `seconds_per_year` - Seconds per year over which vector APRs accrue
+///
+/// # Returns
+/// * `Ok(())` - If there is no minimum or the redemption meets it
+/// * `Err(RedemptionCoreError::OfferAccountRequired)` - If a `Quote` minimum is set and no offer was provided
+/// * `Err(RedemptionCoreError::RedemptionBelowMinimum)` - If the redemption is below the minimum
+pub fn enforce_minimum_redemption(
+    redemption_offer: &RedemptionOffer,
+    offer: Option<&Offer>,
+    token_in_amount: u64,
+    token_in_mint: &InterfaceAccount<Mint>,
+    seconds_per_year: u64,
+) -> Result<()> {
+    if redemption_offer.min_redemption_amount == 0 {
+        return Ok(());
+    }
+
+    let redemption_size = match redemption_offer.min_redemption_denomination {
+        MinimumDenomination::Token => token_in_amount as u128,
+        MinimumDenomination::Quote => {
+            let offer = offer.ok_or(RedemptionCoreError::OfferAccountRequired)?;
+            let current_time = Clock::get()?.unix_timestamp as u64;
+            let nav = calculate_current_vector_price(
+                &find_active_vector_at(offer, current_time)?,
+                seconds_per_year,
+            )?;
+            scale_amount(
+                token_in_amount,
+                nav as u128,
+                1,
+                0,
+                token_in_mint.decimals as u32,
+            )
+            .ok_or(RedemptionCoreError::OverflowError)?
+        }
+    };
+
+    require_with_context!(
+        redemption_size >= redemption_offer.min_redemption_amount as u128,
+        RedemptionCoreError::RedemptionBelowMinimum,
+        redemption_size = redemption_size,
+        min_redemption_amount = redemption_offer.min_redemption_amount
+    );
+
+    Ok(())
+}
+
+/// Asserts the mints of a redemption against the decimals recorded on the redemption offer
+///
+/// The payout mint is only checked against token_out decimals when it is the
+/// redemption offer's own token_out_mint, since settlement mints may have other
+/// decimals. Redemption offers made before decimals were recorded are not checked.
+///
+/// # Arguments
+/// * `redemption_offer` - The redemption offer being redeemed against
+/// * `token_in_mint` - The token_in mint passed to the redemption
+/// * `payout_mint` - The mint the redemption pays out in, if a single one
+///
+/// # Returns
+/// * `Ok(())` - If the mints have the recorded decimals
+/// * `Err(RedemptionCoreError::TokenInDecimalsMismatch)` - If token_in decimals differ
+/// * `Err(RedemptionCoreError::TokenOutDecimalsMismatch)` - If token_out decimals differ
+pub fn require_redemption_offer_decimals(
+    redemption_offer: &RedemptionOffer,
+    token_in_mint: &InterfaceAccount<Mint>,
+    payout_mint: Option<&InterfaceAccount<Mint>>,
+) -> Result<()> {
+    if !redemption_offer.decimals_recorded {
+        return Ok(());
+    }
+    require_with_context!(
+        token_in_mint.decimals == redemption_offer.token_in_decimals,
+        RedemptionCoreError::TokenInDecimalsMismatch,
+        expected = redemption_offer.token_in_decimals,
+        actual = token_in_mint.decimals
+    );
+    if let Some(payout_mint) =
+        payout_mint.filter(|mint| mint.key() == redemption_offer.token_out_mint)
+    {
+        require_with_context!(
+            payout_mint.decimals == redemption_offer.token_out_decimals,
+            RedemptionCoreError::TokenOutDecimalsMismatch,
+            expected = redemption_offer.token_out_decimals,
+            actual = payout_mint.decimals
+        );
+    }
+
+    Ok(())
+}
+
 /// Parameters for executing redemption token operations
 ///
 /// This structure contains all the accounts and parameters needed to execute
@@ -142,10 +371,14 @@ pub struct ExecuteRedemptionOpsParams<'a, 'info> {
     pub token_out_mint: &'a InterfaceAccount<'info, Mint>,
     /// Amount of token_out to distribute
     pub token_out_amount: u64,
+    /// Fee amount in token_out to pay to boss
+    pub token_out_fee_amount: u64,
     /// Vault account for token_out distribution
     pub vault_token_out_account: &'a InterfaceAccount<'info, TokenAccount>,
     /// User's account for receiving token_out
     pub user_token_out_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Boss's account for receiving token_out fees, required when a token_out fee is due
+    pub boss_token_out_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
 
     // Mint authority params
     /// PDA for mint authority operations
@@ -173,6 +406,7 @@ pub struct ExecuteRedemptionOpsParams<'a, 'info> {
 /// # Token Out Processing
 /// - If program has mint authority: mint directly to user
 /// - If program lacks mint authority: transfer from vault to user
+/// - A token_out fee is minted or transferred to the boss the same way
 ///
 /// # Arguments
 /// * `params` - Complete parameter structure containing all required accounts and amounts
@@ -187,10 +421,129 @@ pub fn execute_redemption_operations(params: ExecuteRedemptionOpsParams) -> Resu
     ]];
 
     // Step 1: Handle token_in (burn or transfer to boss)
-    let has_token_in_mint_authority = program_controls_mint(
-        params.token_in_mint,
-        params.mint_authority_pda,
-    );
+    settle_redemption_token_in(&RedemptionTokenInParams {
+        token_in_program: params.token_in_program,
+        token_in_mint: params.token_in_mint,
+        token_in_net_amount: params.token_in_net_amount,
+        token_in_fee_amount: params.token_in_fee_amount,
+        vault_token_in_account: params.vault_token_in_account,
+        boss_token_in_account: params.boss_token_in_account,
+        redemption_vault_authority: params.redemption_vault_authority,
+        redemption_vault_authority_bump: params.redemption_vault_authority_bump,
+        mint_authority_pda: params.mint_authority_pda,
+    })?;
+
+    // Step 2: Distribute token_out to user
+    let has_token_out_mint_authority =
+        program_controls_mint(params.token_out_mint, params.mint_authority_pda);
+
+    let boss_token_out_account = if params.token_out_fee_amount > 0 {
+        Some(
+            params
+                .boss_token_out_account
+                .ok_or(RedemptionCoreError::MissingBossTokenOutAccount)?,
+        )
+    } else {
+        None
+    };
+
+    if has_token_out_mint_authority {
+        // Mint token_out directly to user
+        let mint_authority_signer_seeds: &[&[&[u8]]] =
+            &[&[seeds::MINT_AUTHORITY, &[params.mint_authority_bump]]];
+
+        mint_tokens(
+            params.token_out_program,
+            params.token_out_mint,
+            &params.user_token_out_account.to_account_info(),
+            params.mint_authority_pda,
+            mint_authority_signer_seeds,
+            params.token_out_amount,
+            params.token_out_max_supply,
+        )?;
+
+        if let Some(boss_token_out_account) = boss_token_out_account {
+            msg!("Minting token_out fee amount to boss account");
+            mint_tokens(
+                params.token_out_program,
+                params.token_out_mint,
+                &boss_token_out_account.to_account_info(),
+                params.mint_authority_pda,
+                mint_authority_signer_seeds,
+                params.token_out_fee_amount,
+                params.token_out_max_supply,
+            )?;
+        }
+    } else {
+        // Transfer token_out from vault to user
+        transfer_tokens(
+            params.token_out_mint,
+            params.token_out_program,
+            params.vault_token_out_account,
+            params.user_token_out_account,
+            params.redemption_vault_authority,
+            Some(vault_authority_signer_seeds),
+            params.token_out_amount,
+        )?;
+
+        if let Some(boss_token_out_account) = boss_token_out_account {
+            msg!("Transferring token_out fee amount to boss account");
+            transfer_tokens(
+                params.token_out_mint,
+                params.token_out_program,
+                params.vault_token_out_account,
+                boss_token_out_account,
+                params.redemption_vault_authority,
+                Some(vault_authority_signer_seeds),
+                params.token_out_fee_amount,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parameters for settling the locked token_in of a redemption
+pub struct RedemptionTokenInParams<'a, 'info> {
+    /// SPL Token program for token_in operations
+    pub token_in_program: &'a Interface<'info, TokenInterface>,
+    /// Mint account for the input token
+    pub token_in_mint: &'a InterfaceAccount<'info, Mint>,
+    /// Amount of token_in to process (net amount after fee)
+    pub token_in_net_amount: u64,
+    /// Fee amount to transfer to boss
+    pub token_in_fee_amount: u64,
+    /// Vault account containing locked token_in
+    pub vault_token_in_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Boss's account for receiving token_in when program lacks mint authority (or fees)
+    pub boss_token_in_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Authority for vault operations
+    pub redemption_vault_authority: &'a AccountInfo<'info>,
+    /// Bump seed for vault authority
+    pub redemption_vault_authority_bump: u8,
+    /// PDA for mint authority operations
+    pub mint_authority_pda: &'a AccountInfo<'info>,
+}
+
+/// Burns or forwards the token_in locked in the redemption vault
+///
+/// If the program has mint authority the net amount is burned and the fee is
+/// transferred to the boss; otherwise the full amount (net + fee) goes to the boss.
+///
+/// # Arguments
+/// * `params` - The token_in accounts and amounts of the redemption
+///
+/// # Returns
+/// * `Ok(())` - If the token_in is burned or transferred successfully
+/// * `Err(_)` - If any transfer or burn operation fails
+pub fn settle_redemption_token_in(params: &RedemptionTokenInParams) -> Result<()> {
+    let vault_authority_signer_seeds: &[&[&[u8]]] = &[&[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[params.redemption_vault_authority_bump],
+    ]];
+
+    let has_token_in_mint_authority =
+        program_controls_mint(params.token_in_mint, params.mint_authority_pda);
 
     if has_token_in_mint_authority {
         // Burn net amount from vault
@@ -235,40 +588,5 @@ pub fn execute_redemption_operations(params: ExecuteRedemptionOpsParams) -> Resu
         )?;
     }
 
-    // Step 2: Distribute token_out to user
-    let has_token_out_mint_authority = program_controls_mint(
-        params.token_out_mint,
-        params.mint_authority_pda,
-    );
-
-    if has_token_out_mint_authority {
-        // Mint token_out directly to user
-        let mint_authority_signer_seeds: &[&[&[u8]]] = &[&[
-            seeds::MINT_AUTHORITY,
-            &[params.mint_authority_bump],
-        ]];
-
-        mint_tokens(
-            params.token_out_program,
-            params.token_out_mint,
-            params.user_token_out_account,
-            params.mint_authority_pda,
-            mint_authority_signer_seeds,
-            params.token_out_amount,
-            params.token_out_max_supply,
-        )?;
-    } else {
-        // Transfer token_out from vault to user
-        transfer_tokens(
-            params.token_out_mint,
-            params.token_out_program,
-            params.vault_token_out_account,
-            params.user_token_out_account,
-            params.redemption_vault_authority,
-            Some(vault_authority_signer_seeds),
-            params.token_out_amount,
-        )?;
-    }
-
     Ok(())
 }
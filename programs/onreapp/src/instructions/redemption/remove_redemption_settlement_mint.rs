@@ -0,0 +1,106 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a settlement mint is removed from a redemption offer's allowlist
+///
+/// Provides transparency for tracking which mints redemptions may be settled in.
+#[event]
+pub struct RedemptionSettlementMintRemovedEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// The settlement mint removed from the allowlist
+    pub settlement_mint: Pubkey,
+    /// The boss who removed the settlement mint
+    pub boss: Pubkey,
+}
+
+/// Account structure for removing a settlement mint from a redemption offer
+#[derive(Accounts)]
+pub struct RemoveRedemptionSettlementMint<'info> {
+    /// The redemption offer whose settlement allowlist is reduced
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Account<'info, RedemptionOffer>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to manage settlement mints
+    pub boss: Signer<'info>,
+}
+
+/// Removes a settlement mint from a redemption offer's allowlist
+///
+/// Pending redemption requests can no longer be settled in the removed mint. Tokens
+/// already held in its redemption vault are not affected.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `settlement_mint` - The settlement mint to remove
+///
+/// # Returns
+/// * `Ok(())` - If the settlement mint is successfully removed
+/// * `Err(RemoveRedemptionSettlementMintErrorCode::SettlementMintNotFound)` - If the mint is not allowlisted
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Clears the allowlist slot holding the settlement mint
+///
+/// # Events
+/// * `RedemptionSettlementMintRemovedEvent` - Emitted with the removed settlement mint
+pub fn remove_redemption_settlement_mint(
+    ctx: Context<RemoveRedemptionSettlementMint>,
+    settlement_mint: Pubkey,
+) -> Result<()> {
+    require!(
+        settlement_mint != Pubkey::default(),
+        RemoveRedemptionSettlementMintErrorCode::SettlementMintNotFound
+    );
+
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+    let slot = redemption_offer
+        .settlement_mints
+        .iter_mut()
+        .find(|mint| **mint == settlement_mint)
+        .ok_or(RemoveRedemptionSettlementMintErrorCode::SettlementMintNotFound)?;
+    *slot = Pubkey::default();
+
+    msg!(
+        "Settlement mint {} removed from redemption offer {}",
+        settlement_mint,
+        ctx.accounts.redemption_offer.key()
+    );
+
+    emit!(RedemptionSettlementMintRemovedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        settlement_mint,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for removing redemption settlement mints
+#[error_code]
+pub enum RemoveRedemptionSettlementMintErrorCode {
+    /// The settlement mint is not present in the allowlist
+    #[msg("Settlement mint not found in the allowlist")]
+    SettlementMintNotFound,
+}
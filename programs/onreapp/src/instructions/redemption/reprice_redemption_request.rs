@@ -0,0 +1,181 @@
+use crate::constants::{seeds, REPRICE_WINDOW_SECONDS};
+use crate::instructions::redemption::{RedemptionOffer, RedemptionRequest};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
+use anchor_lang::prelude::*;
+
+/// Event emitted when the locked NAV of a redemption request is restated
+///
+/// Audit trail of every correction, including both authorizing signers.
+#[event]
+pub struct RedemptionRequestRepricedEvent {
+    /// The PDA address of the repriced redemption request
+    pub redemption_request_pda: Pubkey,
+    /// The redemption offer the request belongs to
+    pub redemption_offer_pda: Pubkey,
+    /// Sequential identifier of the request
+    pub request_id: u64,
+    /// NAV locked on the request before the restatement, with scale=9
+    pub old_nav: u64,
+    /// Restated NAV the request is fulfilled at, with scale=9
+    pub new_nav: u64,
+    /// Unix timestamp at which the NAV was originally locked
+    pub price_timestamp: i64,
+    /// The boss account that authorized the restatement
+    pub boss: Pubkey,
+    /// The guardian account that co-signed the restatement
+    pub guardian: Pubkey,
+    /// Unix timestamp of the restatement
+    pub timestamp: i64,
+}
+
+/// Account structure for restating the locked NAV of a redemption request
+///
+/// The boss and the guardian both sign, so neither can move a pending request's
+/// price alone.
+#[derive(Accounts)]
+pub struct RepriceRedemptionRequest<'info> {
+    /// The redemption offer the request belongs to
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The pending redemption request whose locked NAV is restated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_REQUEST,
+            redemption_request.offer.as_ref(),
+            redemption_request.request_id.to_le_bytes().as_ref()
+        ],
+        bump = redemption_request.bump,
+        constraint = redemption_request.offer == redemption_offer.key()
+            @ RepriceRedemptionRequestErrorCode::OfferMismatch
+    )]
+    pub redemption_request: Box<Account<'info, RedemptionRequest>>,
+
+    /// Program state account containing boss and guardian authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema,
+        constraint = state.guardian != Pubkey::default()
+            @ RepriceRedemptionRequestErrorCode::GuardianNotSet
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorizing the restatement
+    pub boss: Signer<'info>,
+
+    /// The guardian co-signing the restatement
+    #[account(
+        constraint = guardian.key() == state.guardian
+            @ RepriceRedemptionRequestErrorCode::InvalidGuardian
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// Restates the NAV locked on a pending redemption request
+///
+/// Requests of redemption offers in `RedemptionPriceMode::LockedAtRequest` store the
+/// NAV read at creation and are fulfilled at it. When that NAV is restated, this
+/// corrects the request in place instead of cancelling and recreating it. Requests
+/// priced at fulfillment carry no NAV and read the corrected vectors anyway. The
+/// correction is only possible within `REPRICE_WINDOW_SECONDS` of the original lock,
+/// which repricing does not extend.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_nav` - Restated NAV with scale=9
+///
+/// # Returns
+/// * `Ok(())` - If the request is successfully repriced
+/// * `Err(RepriceRedemptionRequestErrorCode::NotLocked)` - If the request is priced at fulfillment
+/// * `Err(RepriceRedemptionRequestErrorCode::InvalidNav)` - If the new NAV is zero or unchanged
+/// * `Err(RepriceRedemptionRequestErrorCode::WindowElapsed)` - If the restatement window has passed
+/// * `Err(RepriceRedemptionRequestErrorCode::GuardianNotSet)` - If no guardian is set in program state
+/// * `Err(RepriceRedemptionRequestErrorCode::InvalidGuardian)` - If the co-signer is not the guardian
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Also requires the guardian, which must be set
+///
+/// # Effects
+/// - Replaces the NAV the request is fulfilled at, keeping its lock timestamp
+///
+/// # Events
+/// * `RedemptionRequestRepricedEvent` - Emitted with the old and new NAV and both signers
+pub fn reprice_redemption_request(
+    ctx: Context<RepriceRedemptionRequest>,
+    new_nav: u64,
+) -> Result<()> {
+    let redemption_request = &mut ctx.accounts.redemption_request;
+    let (old_nav, price_timestamp) = redemption_request
+        .locked_price()
+        .ok_or(RepriceRedemptionRequestErrorCode::NotLocked)?;
+    require!(
+        new_nav > 0 && new_nav != old_nav,
+        RepriceRedemptionRequestErrorCode::InvalidNav
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require_with_context!(
+        now < price_timestamp.saturating_add(REPRICE_WINDOW_SECONDS),
+        RepriceRedemptionRequestErrorCode::WindowElapsed,
+        now = now,
+        price_timestamp = price_timestamp
+    );
+
+    redemption_request.nav_used = new_nav;
+
+    msg!(
+        "Redemption request {} repriced: old NAV: {}, new NAV: {}",
+        redemption_request.request_id,
+        old_nav,
+        new_nav
+    );
+
+    emit!(RedemptionRequestRepricedEvent {
+        redemption_request_pda: redemption_request.key(),
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        request_id: redemption_request.request_id,
+        old_nav,
+        new_nav,
+        price_timestamp,
+        boss: ctx.accounts.boss.key(),
+        guardian: ctx.accounts.guardian.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption request repricing
+#[error_code]
+pub enum RepriceRedemptionRequestErrorCode {
+    /// The request does not belong to the redemption offer
+    #[msg("Redemption request does not belong to the redemption offer")]
+    OfferMismatch,
+    /// The request is priced at fulfillment and has no locked NAV to restate
+    #[msg("Redemption request has no locked NAV")]
+    NotLocked,
+    /// The restated NAV is zero or equal to the locked one
+    #[msg("Invalid NAV: must be non-zero and differ from the locked NAV")]
+    InvalidNav,
+    /// The request locked its NAV longer ago than the restatement window
+    #[msg("Restatement window has elapsed")]
+    WindowElapsed,
+    /// Repricing requires a guardian to be set in program state
+    #[msg("A guardian must be set to reprice a redemption request")]
+    GuardianNotSet,
+    /// The guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
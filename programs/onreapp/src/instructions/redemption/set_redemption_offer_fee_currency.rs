@@ -0,0 +1,117 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{FeeCurrency, RedemptionOffer};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a redemption offer's fee currency is updated
+///
+/// Provides transparency for tracking which side of redemptions fees are charged on.
+#[event]
+pub struct RedemptionOfferFeeCurrencyUpdatedEvent {
+    /// The PDA address of the redemption offer whose fee currency was updated
+    pub redemption_offer_pda: Pubkey,
+    /// Previous fee currency
+    pub old_fee_currency: FeeCurrency,
+    /// New fee currency
+    pub new_fee_currency: FeeCurrency,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for selecting the currency a redemption offer charges its fee in
+///
+/// Only the boss can change the fee currency.
+#[derive(Accounts)]
+pub struct SetRedemptionOfferFeeCurrency<'info> {
+    /// The redemption offer account whose fee currency will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Account<'info, RedemptionOffer>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ SetRedemptionOfferFeeCurrencyErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update the fee currency
+    pub boss: Signer<'info>,
+}
+
+/// Selects whether a redemption offer charges its fee in token_in or token_out
+///
+/// With `FeeCurrency::TokenIn` the fee is deducted from the redeemed tokens before
+/// pricing. With `FeeCurrency::TokenOut` the full amount is priced and the fee is
+/// deducted from the payout and sent to the boss in the payout mint. The fee rate
+/// itself is unchanged.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `fee_currency` - The side of the redemption the fee is charged on
+///
+/// # Returns
+/// * `Ok(())` - If the fee currency is successfully updated
+/// * `Err(SetRedemptionOfferFeeCurrencyErrorCode::NoChange)` - If the fee currency is already set
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's fee_currency field
+/// - Affects all future fulfillments, including of already pending requests, and
+///   instant redemptions
+///
+/// # Events
+/// * `RedemptionOfferFeeCurrencyUpdatedEvent` - Emitted with old and new fee currency
+pub fn set_redemption_offer_fee_currency(
+    ctx: Context<SetRedemptionOfferFeeCurrency>,
+    fee_currency: FeeCurrency,
+) -> Result<()> {
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    require!(
+        redemption_offer.fee_currency != fee_currency,
+        SetRedemptionOfferFeeCurrencyErrorCode::NoChange
+    );
+
+    let old_fee_currency = redemption_offer.fee_currency;
+    redemption_offer.fee_currency = fee_currency;
+
+    msg!(
+        "Redemption offer fee currency updated for offer: {}, old: {:?}, new: {:?}",
+        ctx.accounts.redemption_offer.key(),
+        old_fee_currency,
+        fee_currency
+    );
+
+    emit!(RedemptionOfferFeeCurrencyUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        old_fee_currency,
+        new_fee_currency: fee_currency,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for set redemption offer fee currency operations
+#[error_code]
+pub enum SetRedemptionOfferFeeCurrencyErrorCode {
+    /// Caller is not authorized (must be boss)
+    #[msg("Unauthorized: only boss can update redemption offer fee currency")]
+    Unauthorized,
+
+    /// The new fee currency is the same as the current one
+    #[msg("No change: fee currency is already set")]
+    NoChange,
+}
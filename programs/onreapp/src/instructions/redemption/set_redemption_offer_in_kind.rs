@@ -0,0 +1,131 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{RedemptionBasket, RedemptionOffer};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a redemption offer is switched to or from in-kind payouts
+///
+/// Provides transparency for tracking how redemption requests are paid out.
+#[event]
+pub struct RedemptionOfferInKindUpdatedEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Whether requests are now paid out in kind
+    pub in_kind: bool,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for switching a redemption offer to or from in-kind payouts
+#[derive(Accounts)]
+pub struct SetRedemptionOfferInKind<'info> {
+    /// The redemption offer whose payout mode is updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The redemption basket of the offer
+    ///
+    /// Required when enabling in-kind payouts.
+    #[account(
+        seeds = [seeds::REDEMPTION_BASKET, redemption_offer.key().as_ref()],
+        bump = redemption_basket.bump
+    )]
+    pub redemption_basket: Option<Box<Account<'info, RedemptionBasket>>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ SetRedemptionOfferInKindErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to update the payout mode
+    pub boss: Signer<'info>,
+}
+
+/// Switches a redemption offer to or from in-kind payouts
+///
+/// While in-kind, requests of the offer are fulfilled with
+/// `fulfill_redemption_request_basket` in the mints and weights of its
+/// `RedemptionBasket` instead of a single token_out. Pending requests are paid out
+/// in whichever mode is active when they are fulfilled.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `in_kind` - Whether requests are paid out in kind
+///
+/// # Returns
+/// * `Ok(())` - If the payout mode is successfully updated
+/// * `Err(SetRedemptionOfferInKindErrorCode::NoChange)` - If the mode is already set
+/// * `Err(SetRedemptionOfferInKindErrorCode::BasketNotConfigured)` - If enabling without a configured basket
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's in_kind field
+///
+/// # Events
+/// * `RedemptionOfferInKindUpdatedEvent` - Emitted with the new payout mode
+pub fn set_redemption_offer_in_kind(
+    ctx: Context<SetRedemptionOfferInKind>,
+    in_kind: bool,
+) -> Result<()> {
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    require!(
+        redemption_offer.in_kind != in_kind,
+        SetRedemptionOfferInKindErrorCode::NoChange
+    );
+    if in_kind {
+        require!(
+            ctx.accounts
+                .redemption_basket
+                .as_deref()
+                .is_some_and(|basket| basket.active_count() > 0),
+            SetRedemptionOfferInKindErrorCode::BasketNotConfigured
+        );
+    }
+
+    redemption_offer.in_kind = in_kind;
+
+    msg!(
+        "Redemption offer in-kind payouts updated for offer: {}, in_kind: {}",
+        ctx.accounts.redemption_offer.key(),
+        in_kind
+    );
+
+    emit!(RedemptionOfferInKindUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        in_kind,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for set redemption offer in-kind operations
+#[error_code]
+pub enum SetRedemptionOfferInKindErrorCode {
+    /// Caller is not authorized (must be boss)
+    #[msg("Unauthorized: only boss can update redemption offer in-kind payouts")]
+    Unauthorized,
+
+    /// The payout mode is already set
+    #[msg("No change: in-kind payouts are already set")]
+    NoChange,
+
+    /// In-kind payouts need a configured redemption basket
+    #[msg("Redemption basket not configured")]
+    BasketNotConfigured,
+}
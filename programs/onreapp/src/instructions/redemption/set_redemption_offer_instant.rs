@@ -0,0 +1,129 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::state::{Config, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the instant redemption configuration of a redemption offer is updated
+///
+/// Provides transparency for tracking whether users can redeem without waiting for fulfillment.
+#[event]
+pub struct RedemptionOfferInstantUpdatedEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Whether instant redemptions are allowed
+    pub allow_instant: bool,
+    /// Fee in basis points charged on instant redemptions
+    pub instant_fee_basis_points: u16,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for configuring instant redemptions of a redemption offer
+#[derive(Accounts)]
+pub struct SetRedemptionOfferInstant<'info> {
+    /// The redemption offer whose instant redemption configuration is updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ SetRedemptionOfferInstantErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the maximum fee
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account authorized to configure instant redemptions
+    pub boss: Signer<'info>,
+}
+
+/// Allows or disallows instant redemptions and sets their fee
+///
+/// Instant redemptions are taken by users with `take_redemption_instant` and paid
+/// out of the redemption vault in the same transaction. Their fee is separate from
+/// the fee charged when fulfilling redemption requests, so the immediate liquidity
+/// can be priced differently.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `allow_instant` - Whether instant redemptions are allowed
+/// * `instant_fee_basis_points` - Fee in basis points charged on instant redemptions
+///
+/// # Returns
+/// * `Ok(())` - If the configuration is successfully updated
+/// * `Err(SetRedemptionOfferInstantErrorCode::InvalidFee)` - If the fee exceeds the configured maximum
+/// * `Err(SetRedemptionOfferInstantErrorCode::NoChange)` - If the configuration is already set
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's allow_instant and instant_fee_basis_points fields
+///
+/// # Events
+/// * `RedemptionOfferInstantUpdatedEvent` - Emitted with the new configuration
+pub fn set_redemption_offer_instant(
+    ctx: Context<SetRedemptionOfferInstant>,
+    allow_instant: bool,
+    instant_fee_basis_points: u16,
+) -> Result<()> {
+    require!(
+        instant_fee_basis_points <= ctx.accounts.config.max_fee_bps,
+        SetRedemptionOfferInstantErrorCode::InvalidFee
+    );
+
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+    require!(
+        redemption_offer.allow_instant != allow_instant
+            || redemption_offer.instant_fee_basis_points != instant_fee_basis_points,
+        SetRedemptionOfferInstantErrorCode::NoChange
+    );
+
+    redemption_offer.allow_instant = allow_instant;
+    redemption_offer.instant_fee_basis_points = instant_fee_basis_points;
+
+    msg!(
+        "Redemption offer instant redemptions updated for offer: {}, allow_instant: {}, fee: {}",
+        ctx.accounts.redemption_offer.key(),
+        allow_instant,
+        instant_fee_basis_points
+    );
+
+    emit!(RedemptionOfferInstantUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        allow_instant,
+        instant_fee_basis_points,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for set redemption offer instant operations
+#[error_code]
+pub enum SetRedemptionOfferInstantErrorCode {
+    /// Caller is not authorized (must be boss)
+    #[msg("Unauthorized: only boss can configure instant redemptions")]
+    Unauthorized,
+
+    /// Instant fee exceeds the configured maximum
+    #[msg("Invalid fee: instant_fee_basis_points exceeds the configured maximum")]
+    InvalidFee,
+
+    /// The configuration is already set
+    #[msg("No change: instant redemption configuration is already set")]
+    NoChange,
+}
@@ -0,0 +1,121 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the pause flags of a redemption offer are updated
+///
+/// Provides transparency for tracking which side of the redemption flow is halted.
+#[event]
+pub struct RedemptionOfferPauseUpdatedEvent {
+    /// The PDA address of the redemption offer
+    pub redemption_offer_pda: Pubkey,
+    /// Whether new redemption requests are rejected
+    pub pause_new_requests: bool,
+    /// Whether fulfillment of pending redemption requests is halted
+    pub pause_fulfillment: bool,
+    /// The signer that updated the flags
+    pub signer: Pubkey,
+}
+
+/// Account structure for updating the pause flags of a redemption offer
+#[derive(Accounts)]
+pub struct SetRedemptionOfferPause<'info> {
+    /// The redemption offer whose pause flags are updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program state account containing boss and redemption_admin for authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The account updating the flags (must be boss or redemption_admin)
+    #[account(
+        constraint = signer.key() == state.boss || signer.key() == state.redemption_admin
+            @ SetRedemptionOfferPauseErrorCode::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+}
+
+/// Pauses new redemption requests and fulfillment of a redemption offer independently
+///
+/// Unlike the program-wide kill switch, the flags only affect the given redemption
+/// offer and each side can be halted on its own, e.g. to stop accepting new requests
+/// while the existing backlog is still being fulfilled. Cancellations stay available
+/// in either case.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `pause_new_requests` - Whether `create_redemption_request` is rejected
+/// * `pause_fulfillment` - Whether `fulfill_redemption_request` and
+///   `fulfill_redemption_request_basket` are rejected
+///
+/// # Returns
+/// * `Ok(())` - If the flags are successfully updated
+/// * `Err(SetRedemptionOfferPauseErrorCode::Unauthorized)` - If caller is neither boss nor redemption_admin
+/// * `Err(SetRedemptionOfferPauseErrorCode::NoChange)` - If both flags are already set
+///
+/// # Access Control
+/// - Only the boss or redemption_admin can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's pause_new_requests and pause_fulfillment fields
+///
+/// # Events
+/// * `RedemptionOfferPauseUpdatedEvent` - Emitted with the new flags
+pub fn set_redemption_offer_pause(
+    ctx: Context<SetRedemptionOfferPause>,
+    pause_new_requests: bool,
+    pause_fulfillment: bool,
+) -> Result<()> {
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    require!(
+        redemption_offer.pause_new_requests != pause_new_requests
+            || redemption_offer.pause_fulfillment != pause_fulfillment,
+        SetRedemptionOfferPauseErrorCode::NoChange
+    );
+
+    redemption_offer.pause_new_requests = pause_new_requests;
+    redemption_offer.pause_fulfillment = pause_fulfillment;
+
+    msg!(
+        "Redemption offer pause updated for offer: {}, new_requests: {}, fulfillment: {}",
+        ctx.accounts.redemption_offer.key(),
+        pause_new_requests,
+        pause_fulfillment
+    );
+
+    emit!(RedemptionOfferPauseUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        pause_new_requests,
+        pause_fulfillment,
+        signer: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for set redemption offer pause operations
+#[error_code]
+pub enum SetRedemptionOfferPauseErrorCode {
+    /// Caller is not authorized (must be boss or redemption_admin)
+    #[msg("Unauthorized: only boss or redemption_admin can pause the redemption offer")]
+    Unauthorized,
+
+    /// Both flags are already set to the requested values
+    #[msg("No change: pause flags are already set")]
+    NoChange,
+}
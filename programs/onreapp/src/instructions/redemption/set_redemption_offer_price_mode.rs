@@ -0,0 +1,116 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{RedemptionOffer, RedemptionPriceMode};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a redemption offer's price mode is updated
+///
+/// Provides transparency for tracking when redemption requests are priced.
+#[event]
+pub struct RedemptionOfferPriceModeUpdatedEvent {
+    /// The PDA address of the redemption offer whose price mode was updated
+    pub redemption_offer_pda: Pubkey,
+    /// Previous price mode
+    pub old_price_mode: RedemptionPriceMode,
+    /// New price mode
+    pub new_price_mode: RedemptionPriceMode,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for selecting when a redemption offer prices its requests
+///
+/// Only the boss can change the price mode.
+#[derive(Accounts)]
+pub struct SetRedemptionOfferPriceMode<'info> {
+    /// The redemption offer account whose price mode will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Account<'info, RedemptionOffer>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ SetRedemptionOfferPriceModeErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update the price mode
+    pub boss: Signer<'info>,
+}
+
+/// Selects whether redemption requests are priced at fulfillment or at creation
+///
+/// With `RedemptionPriceMode::AtFulfillment` requests keep accruing NAV until they
+/// are fulfilled. With `RedemptionPriceMode::LockedAtRequest` the NAV is stored on
+/// each request when it is created and used to fulfill it. The mode is applied when
+/// a request is created, so pending requests keep the pricing they were created with.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `price_mode` - When the NAV pricing a request is taken
+///
+/// # Returns
+/// * `Ok(())` - If the price mode is successfully updated
+/// * `Err(SetRedemptionOfferPriceModeErrorCode::NoChange)` - If the price mode is already set
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's price_mode field
+/// - Affects requests created afterwards
+///
+/// # Events
+/// * `RedemptionOfferPriceModeUpdatedEvent` - Emitted with old and new price mode
+pub fn set_redemption_offer_price_mode(
+    ctx: Context<SetRedemptionOfferPriceMode>,
+    price_mode: RedemptionPriceMode,
+) -> Result<()> {
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    require!(
+        redemption_offer.price_mode != price_mode,
+        SetRedemptionOfferPriceModeErrorCode::NoChange
+    );
+
+    let old_price_mode = redemption_offer.price_mode;
+    redemption_offer.price_mode = price_mode;
+
+    msg!(
+        "Redemption offer price mode updated for offer: {}, old: {:?}, new: {:?}",
+        ctx.accounts.redemption_offer.key(),
+        old_price_mode,
+        price_mode
+    );
+
+    emit!(RedemptionOfferPriceModeUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        old_price_mode,
+        new_price_mode: price_mode,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for set redemption offer price mode operations
+#[error_code]
+pub enum SetRedemptionOfferPriceModeErrorCode {
+    /// Caller is not authorized (must be boss)
+    #[msg("Unauthorized: only boss can update redemption offer price mode")]
+    Unauthorized,
+
+    /// The new price mode is the same as the current one
+    #[msg("No change: price mode is already set")]
+    NoChange,
+}
@@ -0,0 +1,432 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS};
+use crate::instructions::redemption::{
+    execute_redemption_operations, process_redemption_core, require_redemption_offer_decimals,
+    DualRedemptionOffer, ExecuteRedemptionOpsParams, FeeCurrency, RedemptionCoreError,
+    RedemptionOffer,
+};
+use crate::instructions::Offer;
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::{calculate_fees, transfer_tokens};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Event emitted when a dual redemption offer is successfully taken
+///
+/// Provides transparency for tracking both payout legs of a dual redemption.
+#[event]
+pub struct DualRedemptionOfferTakenEvent {
+    /// The PDA address of the dual redemption offer that was taken
+    pub dual_redemption_offer_pda: Pubkey,
+    /// User who redeemed the tokens
+    pub redeemer: Pubkey,
+    /// Net amount of token_in burned/transferred across both legs (after fees)
+    pub token_in_net_amount: u64,
+    /// Fee amount deducted from token_in
+    pub token_in_fee_amount: u64,
+    /// Amount of principal token_out received by the user
+    pub token_out_1_amount: u64,
+    /// Amount of secondary token_out received by the user
+    pub token_out_2_amount: u64,
+    /// Current price used for the redemption
+    pub current_price: u64,
+}
+
+/// Account structure for taking a dual redemption offer
+///
+/// This struct defines the accounts required to redeem ONyc instantly for two
+/// output tokens. token_in is burned if the program has mint authority or sent to
+/// the boss otherwise, and each output token is minted or transferred from the
+/// redemption vault depending on mint authority.
+#[derive(Accounts)]
+pub struct TakeDualRedemptionOffer<'info> {
+    /// Program state account containing boss authorization and kill switch
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ TakeDualRedemptionOfferErrorCode::InvalidBoss,
+        constraint = !state.is_kill_switch_active()? @ TakeDualRedemptionOfferErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account that receives fees and token_in when program lacks mint authority
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// The underlying offer that defines pricing
+    /// CHECK: offer address is validated through redemption_offer constraint
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The base redemption offer providing the fee configuration
+    #[account(
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump,
+        constraint = redemption_offer.offer == offer.key()
+            @ TakeDualRedemptionOfferErrorCode::OfferMismatch
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// The dual redemption offer account storing the payout split
+    #[account(
+        mut,
+        seeds = [
+            seeds::DUAL_REDEMPTION_OFFER,
+            dual_redemption_offer.token_in_mint.as_ref(),
+            dual_redemption_offer.token_out_mint_1.as_ref(),
+            dual_redemption_offer.token_out_mint_2.as_ref()
+        ],
+        bump = dual_redemption_offer.bump,
+        has_one = redemption_offer @ TakeDualRedemptionOfferErrorCode::OfferMismatch
+    )]
+    pub dual_redemption_offer: Box<Account<'info, DualRedemptionOffer>>,
+
+    /// Program-derived redemption vault authority that controls token operations
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// Input token mint (typically ONyc)
+    ///
+    /// Must be mutable to allow burning operations when program has mint authority.
+    #[account(
+        mut,
+        constraint = token_in_mint.key() == dual_redemption_offer.token_in_mint
+            @ TakeDualRedemptionOfferErrorCode::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for input token operations
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Principal output token mint
+    ///
+    /// Must be mutable to allow minting operations when program has mint authority.
+    #[account(
+        mut,
+        constraint = token_out_mint_1.key() == dual_redemption_offer.token_out_mint_1
+            @ TakeDualRedemptionOfferErrorCode::InvalidTokenOutMint
+    )]
+    pub token_out_mint_1: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for principal output token operations
+    pub token_out_1_program: Interface<'info, TokenInterface>,
+
+    /// Secondary output token mint
+    ///
+    /// Must be mutable to allow minting operations when program has mint authority.
+    #[account(
+        mut,
+        constraint = token_out_mint_2.key() == dual_redemption_offer.token_out_mint_2
+            @ TakeDualRedemptionOfferErrorCode::InvalidTokenOutMint
+    )]
+    pub token_out_mint_2: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for secondary output token operations
+    pub token_out_2_program: Interface<'info, TokenInterface>,
+
+    /// Redemption vault account receiving token_in before it is burned or forwarded
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption vault account for principal token_out distribution
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint_1,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_out_1_program
+    )]
+    pub vault_token_out_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption vault account for secondary token_out distribution
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub vault_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's input token account (source of redeemed tokens)
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_in_program
+    )]
+    pub user_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's principal output token account
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint_1,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_1_program
+    )]
+    pub user_token_out_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's secondary output token account
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint_2,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_2_program
+    )]
+    pub user_token_out_2_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's input token account for receiving fees and token_in when program lacks mint authority
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived mint authority for direct token minting
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The user redeeming tokens and paying for account creation
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Redeems ONyc instantly for two output tokens through a dual redemption offer
+///
+/// This instruction:
+/// 1. Deducts the base redemption offer's fee from token_in_amount
+/// 2. Splits the net amount by `token_out_2_basis_points` between the two legs
+/// 3. Values each leg at the current NAV of the underlying offer using the decimals
+///    of its output mint
+/// 4. Moves token_in into the redemption vault, then burns it (or sends it to the
+///    boss) and mints or transfers each output token to the user
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_in_amount` - Amount of token_in to redeem, including the fee
+///
+/// # Returns
+/// * `Ok(())` - If the redemption is successfully executed
+/// * `Err(TakeDualRedemptionOfferErrorCode::InvalidAmount)` - If token_in_amount is zero
+/// * `Err(_)` - If no vector is active or token operations fail
+///
+/// # Access Control
+/// - Any user can take a dual redemption offer
+/// - Kill switch prevents redemptions when activated
+///
+/// # Effects
+/// - Burns or transfers token_in based on mint authority
+/// - Mints or transfers both output tokens to the user
+/// - Updates executed_redemptions and total_fees_collected_token_in in DualRedemptionOffer
+///
+/// # Events
+/// * `DualRedemptionOfferTakenEvent` - Emitted with the amounts of both legs
+pub fn take_dual_redemption_offer(
+    ctx: Context<TakeDualRedemptionOffer>,
+    token_in_amount: u64,
+) -> Result<()> {
+    require!(
+        token_in_amount > 0,
+        TakeDualRedemptionOfferErrorCode::InvalidAmount
+    );
+
+    // Reject mints priced with other decimals than the redemption offer was made for
+    require_redemption_offer_decimals(
+        &ctx.accounts.redemption_offer,
+        &ctx.accounts.token_in_mint,
+        Some(&ctx.accounts.token_out_mint_1),
+    )?;
+
+    // The fee is taken once on the whole amount, then the net amount is split
+    let fee_amounts = calculate_fees(
+        token_in_amount,
+        ctx.accounts.redemption_offer.fee_basis_points,
+    )?;
+    let token_in_net_amount = fee_amounts.token_in_net_amount;
+    let token_in_fee_amount = fee_amounts.token_in_fee_amount;
+
+    let token_in_net_amount_2 = (token_in_net_amount as u128)
+        .checked_mul(ctx.accounts.dual_redemption_offer.token_out_2_basis_points as u128)
+        .ok_or(TakeDualRedemptionOfferErrorCode::ArithmeticOverflow)?
+        / MAX_BASIS_POINTS as u128;
+    let token_in_net_amount_2 = token_in_net_amount_2 as u64;
+    let token_in_net_amount_1 = token_in_net_amount - token_in_net_amount_2;
+
+    let offer = ctx.accounts.offer.load()?;
+    let leg_1 = process_redemption_core(
+        &offer,
+        token_in_net_amount_1,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_1,
+        0,
+        FeeCurrency::TokenIn,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    let leg_2 = process_redemption_core(
+        &offer,
+        token_in_net_amount_2,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint_2,
+        0,
+        FeeCurrency::TokenIn,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    drop(offer);
+
+    require!(
+        leg_1.token_out_amount > 0 || leg_2.token_out_amount > 0,
+        RedemptionCoreError::ZeroRedemptionPayout
+    );
+
+    // Lock the full amount in the redemption vault before burning or forwarding it
+    transfer_tokens(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_in_program,
+        &ctx.accounts.user_token_in_account,
+        &ctx.accounts.vault_token_in_account,
+        &ctx.accounts.user,
+        None,
+        token_in_amount,
+    )?;
+
+    // Principal leg carries the fee transfer
+    execute_redemption_operations(ExecuteRedemptionOpsParams {
+        token_in_program: &ctx.accounts.token_in_program,
+        token_out_program: &ctx.accounts.token_out_1_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: token_in_net_amount_1,
+        token_in_fee_amount,
+        vault_token_in_account: &ctx.accounts.vault_token_in_account,
+        boss_token_in_account: &ctx.accounts.boss_token_in_account,
+        redemption_vault_authority: &ctx.accounts.redemption_vault_authority,
+        redemption_vault_authority_bump: ctx.bumps.redemption_vault_authority,
+        token_out_mint: &ctx.accounts.token_out_mint_1,
+        token_out_amount: leg_1.token_out_amount,
+        token_out_fee_amount: 0,
+        vault_token_out_account: &ctx.accounts.vault_token_out_1_account,
+        user_token_out_account: &ctx.accounts.user_token_out_1_account,
+        boss_token_out_account: None,
+        mint_authority_pda: &ctx.accounts.mint_authority,
+        mint_authority_bump: ctx.bumps.mint_authority,
+        token_out_max_supply: 0, // No max supply cap for redemptions
+    })?;
+
+    execute_redemption_operations(ExecuteRedemptionOpsParams {
+        token_in_program: &ctx.accounts.token_in_program,
+        token_out_program: &ctx.accounts.token_out_2_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: token_in_net_amount_2,
+        token_in_fee_amount: 0,
+        vault_token_in_account: &ctx.accounts.vault_token_in_account,
+        boss_token_in_account: &ctx.accounts.boss_token_in_account,
+        redemption_vault_authority: &ctx.accounts.redemption_vault_authority,
+        redemption_vault_authority_bump: ctx.bumps.redemption_vault_authority,
+        token_out_mint: &ctx.accounts.token_out_mint_2,
+        token_out_amount: leg_2.token_out_amount,
+        token_out_fee_amount: 0,
+        vault_token_out_account: &ctx.accounts.vault_token_out_2_account,
+        user_token_out_account: &ctx.accounts.user_token_out_2_account,
+        boss_token_out_account: None,
+        mint_authority_pda: &ctx.accounts.mint_authority,
+        mint_authority_bump: ctx.bumps.mint_authority,
+        token_out_max_supply: 0, // No max supply cap for redemptions
+    })?;
+
+    let dual_redemption_offer = &mut ctx.accounts.dual_redemption_offer;
+    dual_redemption_offer.executed_redemptions = dual_redemption_offer
+        .executed_redemptions
+        .checked_add(token_in_amount as u128)
+        .ok_or(TakeDualRedemptionOfferErrorCode::ArithmeticOverflow)?;
+    dual_redemption_offer.total_fees_collected_token_in = dual_redemption_offer
+        .total_fees_collected_token_in
+        .checked_add(token_in_fee_amount as u128)
+        .ok_or(TakeDualRedemptionOfferErrorCode::ArithmeticOverflow)?;
+
+    msg!(
+        "Dual redemption offer taken: offer={}, token_in={} (net={}, fee={}), token_out_1={}, token_out_2={}, price={}, redeemer={}",
+        ctx.accounts.dual_redemption_offer.key(),
+        token_in_amount,
+        token_in_net_amount,
+        token_in_fee_amount,
+        leg_1.token_out_amount,
+        leg_2.token_out_amount,
+        leg_1.price,
+        ctx.accounts.user.key()
+    );
+
+    emit!(DualRedemptionOfferTakenEvent {
+        dual_redemption_offer_pda: ctx.accounts.dual_redemption_offer.key(),
+        redeemer: ctx.accounts.user.key(),
+        token_in_net_amount,
+        token_in_fee_amount,
+        token_out_1_amount: leg_1.token_out_amount,
+        token_out_2_amount: leg_2.token_out_amount,
+        current_price: leg_1.price,
+    });
+
+    Ok(())
+}
+
+/// Error codes for dual redemption offer take operations
+#[error_code]
+pub enum TakeDualRedemptionOfferErrorCode {
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+
+    /// The program kill switch is activated
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+
+    /// The offer accounts do not belong to the dual redemption offer
+    #[msg("Offer does not match dual redemption offer")]
+    OfferMismatch,
+
+    /// Invalid token_in mint
+    #[msg("Invalid token_in mint")]
+    InvalidTokenInMint,
+
+    /// Invalid token_out mint
+    #[msg("Invalid token_out mint")]
+    InvalidTokenOutMint,
+
+    /// The redeemed amount must be greater than zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+
+    /// Arithmetic overflow occurred
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+}
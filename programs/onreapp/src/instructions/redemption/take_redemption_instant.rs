@@ -0,0 +1,458 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::{
+    enforce_minimum_redemption, process_redemption_core, require_redemption_offer_decimals,
+    settle_redemption_token_in, RedemptionCoreError, RedemptionOffer, RedemptionTokenInParams,
+};
+use crate::instructions::{Offer, UserPosition};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Event emitted when a user redeems instantly against the redemption vault
+///
+/// Provides transparency for tracking redemptions that bypass the request queue.
+#[event]
+pub struct RedemptionInstantTakenEvent {
+    /// Reference to the redemption offer pda
+    pub redemption_offer_pda: Pubkey,
+    /// User who redeemed the tokens
+    pub redeemer: Pubkey,
+    /// Net amount of token_in burned/transferred (after fees)
+    pub token_in_net_amount: u64,
+    /// Instant fee amount deducted from token_in
+    pub token_in_fee_amount: u64,
+    /// The mint the redemption was paid out in
+    pub token_out_mint: Pubkey,
+    /// Amount of token_out tokens received by the user
+    pub token_out_amount: u64,
+    /// Instant fee amount deducted from the token_out payout
+    pub token_out_fee_amount: u64,
+    /// NAV used for the redemption
+    pub current_price: u64,
+}
+
+/// Account structure for redeeming instantly against the redemption vault
+///
+/// token_in is burned if the program has mint authority or sent to the boss
+/// otherwise. token_out is always transferred from the redemption vault, which must
+/// hold enough of it to pay out the redemption.
+#[derive(Accounts)]
+pub struct TakeRedemptionInstant<'info> {
+    /// Program state account containing boss authorization and kill switch
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ TakeRedemptionInstantErrorCode::InvalidBoss,
+        constraint = !state.is_kill_switch_active()? @ TakeRedemptionInstantErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Program-wide configuration providing the seconds per year for NAV accrual
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The boss account that receives fees and token_in when program lacks mint authority
+    /// CHECK: Account validation is enforced through state account constraint
+    pub boss: UncheckedAccount<'info>,
+
+    /// The underlying offer that defines pricing
+    /// CHECK: offer address is validated through redemption_offer constraint
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The redemption offer account
+    ///
+    /// Must allow instant redemptions, pay out in a single token and have neither
+    /// new requests nor fulfillment paused.
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump,
+        constraint = redemption_offer.offer == offer.key()
+            @ TakeRedemptionInstantErrorCode::OfferMismatch,
+        constraint = redemption_offer.allow_instant
+            @ TakeRedemptionInstantErrorCode::InstantNotAllowed,
+        constraint = !redemption_offer.in_kind
+            @ TakeRedemptionInstantErrorCode::InKindRedemptionOffer,
+        constraint = !redemption_offer.pause_new_requests && !redemption_offer.pause_fulfillment
+            @ TakeRedemptionInstantErrorCode::RedemptionsPaused
+    )]
+    pub redemption_offer: Box<Account<'info, RedemptionOffer>>,
+
+    /// Program-derived redemption vault authority that controls token operations
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// Input token mint (typically ONyc)
+    ///
+    /// Must be mutable to allow burning operations when program has mint authority.
+    #[account(
+        mut,
+        constraint = token_in_mint.key() == redemption_offer.token_in_mint
+            @ TakeRedemptionInstantErrorCode::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for input token operations
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Output token mint (typically stablecoin like USDC)
+    ///
+    /// Either the redemption offer's token_out_mint or one of its allowlisted
+    /// settlement mints, chosen by the user.
+    #[account(
+        constraint = redemption_offer.accepts_payout_mint(&token_out_mint.key())
+            @ TakeRedemptionInstantErrorCode::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for output token operations
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Redemption vault account receiving token_in before it is burned or forwarded
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption vault account paying out token_out
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's input token account (source of redeemed tokens)
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_in_program
+    )]
+    pub user_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's output token account
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_out_program
+    )]
+    pub user_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's input token account for receiving fees and token_in when program lacks mint authority
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_in_program
+    )]
+    pub boss_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Boss's output token account for receiving fees charged in token_out
+    ///
+    /// Required when the redemption offer charges its fee in token_out.
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_out_program
+    )]
+    pub boss_token_out_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Program-derived mint authority used to burn token_in
+    ///
+    /// CHECK: PDA derivation is validated through seeds constraint
+    #[account(seeds = [seeds::MINT_AUTHORITY], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Position of the user in token_in, updated with the redemption
+    ///
+    /// Created on the user's first instant redemption, with rent paid by the user.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserPosition::INIT_SPACE,
+        seeds = [
+            seeds::USER_POSITION,
+            token_in_mint.key().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// The user redeeming tokens and paying for account creation
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Redeems token_in instantly when the redemption vault holds enough token_out
+///
+/// This instruction:
+/// 1. Enforces the redemption offer's minimum redemption size
+/// 2. Deducts the instant fee and values the redemption at the current NAV of the
+///    underlying offer
+/// 3. Checks that the redemption vault holds the token_out payout and fee
+/// 4. Moves token_in into the redemption vault, then burns it (or sends it to the
+///    boss) and transfers token_out from the vault to the user
+///
+/// No redemption request is created, so the redemption admin doesn't need to act.
+/// The instant fee is charged on the side selected by the redemption offer's
+/// `fee_currency`: deducted from token_in before pricing, or from the token_out
+/// payout and transferred from the vault to the boss's token_out account.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_in_amount` - Amount of token_in to redeem, including the fee
+///
+/// # Returns
+/// * `Ok(())` - If the redemption is successfully executed
+/// * `Err(TakeRedemptionInstantErrorCode::InvalidAmount)` - If token_in_amount is zero
+/// * `Err(TakeRedemptionInstantErrorCode::InsufficientVaultBalance)` - If the vault can't pay out the redemption
+/// * `Err(RedemptionCoreError::RedemptionBelowMinimum)` - If the redemption is below the minimum
+/// * `Err(RedemptionCoreError::ZeroRedemptionPayout)` - If the redemption pays out nothing
+/// * `Err(RedemptionCoreError::MissingBossTokenOutAccount)` - If a token_out fee is due
+///   without the boss's token_out account
+///
+/// # Access Control
+/// - Any user can redeem instantly on redemption offers that allow it
+/// - Kill switch and the redemption offer's pause flags prevent instant redemptions
+///
+/// # Effects
+/// - Burns or transfers token_in based on mint authority
+/// - Transfers token_out from the redemption vault to the user, and a token_out fee
+///   to the boss
+/// - Updates executed_redemptions and total_fees_collected_token_in in RedemptionOffer
+/// - Records the redemption in the user's token_in position, creating it if needed
+///
+/// # Events
+/// * `RedemptionInstantTakenEvent` - Emitted with the redemption details
+pub fn take_redemption_instant(
+    ctx: Context<TakeRedemptionInstant>,
+    token_in_amount: u64,
+) -> Result<()> {
+    require!(
+        token_in_amount > 0,
+        TakeRedemptionInstantErrorCode::InvalidAmount
+    );
+
+    // Reject mints priced with other decimals than the redemption offer was made for
+    require_redemption_offer_decimals(
+        &ctx.accounts.redemption_offer,
+        &ctx.accounts.token_in_mint,
+        Some(&ctx.accounts.token_out_mint),
+    )?;
+
+    let offer = ctx.accounts.offer.load()?;
+    enforce_minimum_redemption(
+        &ctx.accounts.redemption_offer,
+        Some(&offer),
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    let result = process_redemption_core(
+        &offer,
+        token_in_amount,
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_out_mint,
+        ctx.accounts.redemption_offer.instant_fee_basis_points,
+        ctx.accounts.redemption_offer.fee_currency,
+        ctx.accounts.config.seconds_per_year,
+    )?;
+    drop(offer);
+
+    require!(
+        result.token_out_amount > 0,
+        RedemptionCoreError::ZeroRedemptionPayout
+    );
+    let token_out_gross_amount = result
+        .token_out_amount
+        .checked_add(result.token_out_fee_amount)
+        .ok_or(TakeRedemptionInstantErrorCode::ArithmeticOverflow)?;
+    require_with_context!(
+        ctx.accounts.vault_token_out_account.amount >= token_out_gross_amount,
+        TakeRedemptionInstantErrorCode::InsufficientVaultBalance,
+        vault_balance = ctx.accounts.vault_token_out_account.amount,
+        token_out_amount = token_out_gross_amount
+    );
+    let boss_token_out_account = if result.token_out_fee_amount > 0 {
+        Some(
+            ctx.accounts
+                .boss_token_out_account
+                .as_deref()
+                .ok_or(RedemptionCoreError::MissingBossTokenOutAccount)?,
+        )
+    } else {
+        None
+    };
+
+    // Lock the full amount in the redemption vault before burning or forwarding it
+    transfer_tokens(
+        &ctx.accounts.token_in_mint,
+        &ctx.accounts.token_in_program,
+        &ctx.accounts.user_token_in_account,
+        &ctx.accounts.vault_token_in_account,
+        &ctx.accounts.user,
+        None,
+        token_in_amount,
+    )?;
+
+    settle_redemption_token_in(&RedemptionTokenInParams {
+        token_in_program: &ctx.accounts.token_in_program,
+        token_in_mint: &ctx.accounts.token_in_mint,
+        token_in_net_amount: result.token_in_net_amount,
+        token_in_fee_amount: result.token_in_fee_amount,
+        vault_token_in_account: &ctx.accounts.vault_token_in_account,
+        boss_token_in_account: &ctx.accounts.boss_token_in_account,
+        redemption_vault_authority: &ctx.accounts.redemption_vault_authority,
+        redemption_vault_authority_bump: ctx.bumps.redemption_vault_authority,
+        mint_authority_pda: &ctx.accounts.mint_authority,
+    })?;
+
+    let vault_authority_seeds = &[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.redemption_vault_authority],
+    ];
+    transfer_tokens(
+        &ctx.accounts.token_out_mint,
+        &ctx.accounts.token_out_program,
+        &ctx.accounts.vault_token_out_account,
+        &ctx.accounts.user_token_out_account,
+        &ctx.accounts.redemption_vault_authority,
+        Some(&[vault_authority_seeds.as_slice()]),
+        result.token_out_amount,
+    )?;
+
+    if let Some(boss_token_out_account) = boss_token_out_account {
+        transfer_tokens(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.token_out_program,
+            &ctx.accounts.vault_token_out_account,
+            boss_token_out_account,
+            &ctx.accounts.redemption_vault_authority,
+            Some(&[vault_authority_seeds.as_slice()]),
+            result.token_out_fee_amount,
+        )?;
+    }
+
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+    redemption_offer.executed_redemptions = redemption_offer
+        .executed_redemptions
+        .checked_add(token_in_amount as u128)
+        .ok_or(TakeRedemptionInstantErrorCode::ArithmeticOverflow)?;
+    redemption_offer.total_fees_collected_token_in = redemption_offer
+        .total_fees_collected_token_in
+        .checked_add(result.token_in_fee_amount)
+        .ok_or(TakeRedemptionInstantErrorCode::ArithmeticOverflow)?;
+
+    // Track the redemption in the user's position of token_in
+    let user_position = &mut ctx.accounts.user_position;
+    user_position.initialize_if_needed(
+        ctx.accounts.user.key(),
+        ctx.accounts.token_in_mint.key(),
+        ctx.bumps.user_position,
+    );
+    user_position.record_redemption(token_in_amount, Clock::get()?.unix_timestamp as u64)?;
+
+    msg!(
+        "Instant redemption taken: offer={}, token_in={} (net={}, fee={}), token_out={} (fee={}) of {}, price={}, redeemer={}",
+        ctx.accounts.redemption_offer.key(),
+        token_in_amount,
+        result.token_in_net_amount,
+        result.token_in_fee_amount,
+        result.token_out_amount,
+        result.token_out_fee_amount,
+        ctx.accounts.token_out_mint.key(),
+        result.price,
+        ctx.accounts.user.key()
+    );
+
+    emit!(RedemptionInstantTakenEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        redeemer: ctx.accounts.user.key(),
+        token_in_net_amount: result.token_in_net_amount,
+        token_in_fee_amount: result.token_in_fee_amount,
+        token_out_mint: ctx.accounts.token_out_mint.key(),
+        token_out_amount: result.token_out_amount,
+        token_out_fee_amount: result.token_out_fee_amount,
+        current_price: result.price,
+    });
+
+    Ok(())
+}
+
+/// Error codes for instant redemption operations
+#[error_code]
+pub enum TakeRedemptionInstantErrorCode {
+    /// The boss account does not match the one stored in program state
+    #[msg("Invalid boss account")]
+    InvalidBoss,
+
+    /// The program kill switch is activated
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+
+    /// Redemption offer doesn't belong to the offer
+    #[msg("Redemption offer does not match offer")]
+    OfferMismatch,
+
+    /// The redemption offer doesn't allow instant redemptions
+    #[msg("Instant redemptions are not allowed on this redemption offer")]
+    InstantNotAllowed,
+
+    /// The redemption offer pays out in kind
+    #[msg("In-kind redemption offers can't be redeemed instantly")]
+    InKindRedemptionOffer,
+
+    /// New requests or fulfillment are paused on the redemption offer
+    #[msg("Redemptions are paused")]
+    RedemptionsPaused,
+
+    /// Invalid token_in mint
+    #[msg("Invalid token_in mint")]
+    InvalidTokenInMint,
+
+    /// token_out mint is neither the offer's token_out mint nor an allowlisted settlement mint
+    #[msg("Invalid token_out mint")]
+    InvalidTokenOutMint,
+
+    /// The redeemed amount is zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+
+    /// The redemption vault doesn't hold enough token_out for the payout
+    #[msg("Insufficient redemption vault balance for instant redemption")]
+    InsufficientVaultBalance,
+
+    /// Arithmetic overflow occurred
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+}
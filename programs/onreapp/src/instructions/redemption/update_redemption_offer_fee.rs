@@ -1,6 +1,6 @@
-use crate::constants::{seeds, MAX_ALLOWED_FEE_BPS};
+use crate::constants::seeds;
 use crate::instructions::redemption::RedemptionOffer;
-use crate::state::State;
+use crate::state::{Config, State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Event emitted when a redemption offer's fee is successfully updated
@@ -40,10 +40,15 @@ pub struct UpdateRedemptionOfferFee<'info> {
     #[account(
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss @ UpdateRedemptionOfferFeeErrorCode::Unauthorized
+        has_one = boss @ UpdateRedemptionOfferFeeErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
+    /// Program-wide configuration providing the maximum fee
+    #[account(seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The boss account authorized to update redemption offer fees
     pub boss: Signer<'info>,
 }
@@ -61,7 +66,7 @@ pub struct UpdateRedemptionOfferFee<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the fee is successfully updated
-/// * `Err(UpdateRedemptionOfferFeeErrorCode::InvalidFee)` - If fee exceeds 10000 basis points
+/// * `Err(UpdateRedemptionOfferFeeErrorCode::InvalidFee)` - If fee exceeds the configured maximum
 /// * `Err(UpdateRedemptionOfferFeeErrorCode::Unauthorized)` - If caller is not the boss
 ///
 /// # Access Control
@@ -79,9 +84,9 @@ pub fn update_redemption_offer_fee(
     ctx: Context<UpdateRedemptionOfferFee>,
     new_fee_basis_points: u16,
 ) -> Result<()> {
-    // Validate fee is within valid range (0-1000 basis points = 0-10%)
+    // Validate fee is within the configured cap
     require!(
-        new_fee_basis_points <= MAX_ALLOWED_FEE_BPS,
+        new_fee_basis_points <= ctx.accounts.config.max_fee_bps,
         UpdateRedemptionOfferFeeErrorCode::InvalidFee
     );
 
@@ -123,8 +128,8 @@ pub enum UpdateRedemptionOfferFeeErrorCode {
     #[msg("Unauthorized: only boss can update redemption offer fee")]
     Unauthorized,
 
-    /// Fee basis points exceeds maximum allowed value of 1000 (10%)
-    #[msg("Invalid fee: fee_basis_points must be <= 1000")]
+    /// Fee basis points exceeds the configured maximum
+    #[msg("Invalid fee: fee_basis_points exceeds the configured maximum")]
     InvalidFee,
 
     /// The new fee is the same as the current fee
@@ -0,0 +1,119 @@
+use crate::constants::seeds;
+use crate::instructions::redemption::RedemptionOffer;
+use crate::instructions::MinimumDenomination;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a redemption offer's minimum redemption size is updated
+///
+/// Provides transparency for tracking changes to redemption size limits.
+#[event]
+pub struct RedemptionOfferMinAmountUpdatedEvent {
+    /// The PDA address of the redemption offer whose minimum was updated
+    pub redemption_offer_pda: Pubkey,
+    /// Previous minimum redemption size (0 = no minimum)
+    pub old_min_redemption_amount: u64,
+    /// Previous unit of the minimum redemption size
+    pub old_denomination: MinimumDenomination,
+    /// New minimum redemption size (0 = no minimum)
+    pub new_min_redemption_amount: u64,
+    /// New unit of the minimum redemption size
+    pub new_denomination: MinimumDenomination,
+    /// The boss account that authorized the update
+    pub boss: Pubkey,
+}
+
+/// Account structure for updating a redemption offer's minimum redemption size
+///
+/// Only the boss can change the minimum.
+#[derive(Accounts)]
+pub struct UpdateRedemptionOfferMinAmount<'info> {
+    /// The redemption offer account whose minimum will be updated
+    #[account(
+        mut,
+        seeds = [
+            seeds::REDEMPTION_OFFER,
+            redemption_offer.token_in_mint.as_ref(),
+            redemption_offer.token_out_mint.as_ref()
+        ],
+        bump = redemption_offer.bump
+    )]
+    pub redemption_offer: Account<'info, RedemptionOffer>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ UpdateRedemptionOfferMinAmountErrorCode::Unauthorized,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update the minimum
+    pub boss: Signer<'info>,
+}
+
+/// Updates the minimum size of a single redemption request
+///
+/// A `Token` minimum is compared against the requested token_in amount in base
+/// units. A `Quote` minimum is a USD amount with scale=9 that requests are valued
+/// against at the underlying offer's current NAV, in which case redeemers must pass
+/// the underlying offer when creating requests. Pending requests are not affected.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `min_redemption_amount` - Minimum redemption size in the chosen unit (0 = no minimum)
+/// * `denomination` - Unit the minimum is expressed in
+///
+/// # Returns
+/// * `Ok(())` - If the minimum is successfully updated
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the redemption offer's min_redemption_amount and min_redemption_denomination fields
+/// - Affects all future redemption requests
+///
+/// # Events
+/// * `RedemptionOfferMinAmountUpdatedEvent` - Emitted with old and new minimum values
+pub fn update_redemption_offer_min_amount(
+    ctx: Context<UpdateRedemptionOfferMinAmount>,
+    min_redemption_amount: u64,
+    denomination: MinimumDenomination,
+) -> Result<()> {
+    let redemption_offer = &mut ctx.accounts.redemption_offer;
+
+    let old_min_redemption_amount = redemption_offer.min_redemption_amount;
+    let old_denomination = redemption_offer.min_redemption_denomination;
+    redemption_offer.min_redemption_amount = min_redemption_amount;
+    redemption_offer.min_redemption_denomination = denomination;
+
+    msg!(
+        "Redemption offer min amount updated for offer: {}, old: {} ({:?}), new: {} ({:?})",
+        ctx.accounts.redemption_offer.key(),
+        old_min_redemption_amount,
+        old_denomination,
+        min_redemption_amount,
+        denomination
+    );
+
+    emit!(RedemptionOfferMinAmountUpdatedEvent {
+        redemption_offer_pda: ctx.accounts.redemption_offer.key(),
+        old_min_redemption_amount,
+        old_denomination,
+        new_min_redemption_amount: min_redemption_amount,
+        new_denomination: denomination,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for update redemption offer minimum operations
+#[error_code]
+pub enum UpdateRedemptionOfferMinAmountErrorCode {
+    /// Caller is not authorized (must be boss)
+    #[msg("Unauthorized: only boss can update redemption offer minimum")]
+    Unauthorized,
+}
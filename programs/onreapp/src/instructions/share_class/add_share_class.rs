@@ -0,0 +1,123 @@
+use crate::constants::seeds;
+use crate::instructions::share_class::ShareClass;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a share class is successfully registered
+///
+/// Provides transparency for tracking share class creation and configuration.
+#[event]
+pub struct ShareClassAddedEvent {
+    /// The PDA address of the new share class registry entry
+    pub share_class_pda: Pubkey,
+    /// The token mint of the share class
+    pub mint: Pubkey,
+    /// Book value of one whole class token with scale=9
+    pub conversion_rate: u64,
+    /// Maximum supply of the class in base units (0 = no cap)
+    pub max_supply: u64,
+}
+
+/// Account structure for registering a share class
+///
+/// This struct defines the accounts required to add a class mint to the share
+/// class registry. Only the boss can register share classes.
+#[derive(Accounts)]
+pub struct AddShareClass<'info> {
+    /// The share class registry entry being created
+    ///
+    /// Derived from the class mint so each mint can be registered only once.
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + ShareClass::INIT_SPACE,
+        seeds = [seeds::SHARE_CLASS, mint.key().as_ref()],
+        bump
+    )]
+    pub share_class: Account<'info, ShareClass>,
+
+    /// The token mint of the share class
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to register share classes and pay for the account
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a token mint as a share class with its conversion parameters
+///
+/// Conversions between classes are only possible once both mints are registered.
+/// The program must additionally hold the mint authority of a class for it to be
+/// the destination of a conversion.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `conversion_rate` - Book value of one whole class token with scale=9
+/// * `max_supply` - Maximum supply of the class in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the share class is successfully registered
+/// * `Err(AddShareClassErrorCode::InvalidConversionRate)` - If the conversion rate is zero
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Creates the share class registry entry for the mint
+///
+/// # Events
+/// * `ShareClassAddedEvent` - Emitted with the share class configuration
+pub fn add_share_class(
+    ctx: Context<AddShareClass>,
+    conversion_rate: u64,
+    max_supply: u64,
+) -> Result<()> {
+    require!(
+        conversion_rate > 0,
+        AddShareClassErrorCode::InvalidConversionRate
+    );
+
+    let share_class = &mut ctx.accounts.share_class;
+    share_class.mint = ctx.accounts.mint.key();
+    share_class.conversion_rate = conversion_rate;
+    share_class.max_supply = max_supply;
+    share_class.bump = ctx.bumps.share_class;
+
+    msg!(
+        "Share class added for mint: {}, conversion rate: {}, max supply: {}",
+        share_class.mint,
+        conversion_rate,
+        max_supply
+    );
+
+    emit!(ShareClassAddedEvent {
+        share_class_pda: ctx.accounts.share_class.key(),
+        mint: ctx.accounts.mint.key(),
+        conversion_rate,
+        max_supply,
+    });
+
+    Ok(())
+}
+
+/// Error codes for share class registration
+#[error_code]
+pub enum AddShareClassErrorCode {
+    /// Conversion rate must be greater than zero
+    #[msg("Invalid conversion rate: must be greater than zero")]
+    InvalidConversionRate,
+}
@@ -0,0 +1,275 @@
+use crate::constants::seeds;
+use crate::instructions::share_class::ShareClass;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::token_utils::{burn_tokens, mint_tokens};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when tokens are successfully converted between share classes
+///
+/// Provides transparency for tracking movements between share classes.
+#[event]
+pub struct ShareClassConvertedEvent {
+    /// The share class mint the tokens were burned from
+    pub source_mint: Pubkey,
+    /// The share class mint the tokens were minted from
+    pub destination_mint: Pubkey,
+    /// The user who converted the tokens
+    pub user: Pubkey,
+    /// Amount of source class tokens burned in base units
+    pub source_amount: u64,
+    /// Amount of destination class tokens minted in base units
+    pub destination_amount: u64,
+}
+
+/// Account structure for converting tokens between two share classes
+///
+/// This struct defines the accounts required to burn tokens of one registered
+/// share class and mint the equivalent book value of another. The program must
+/// hold the mint authority of the destination class.
+#[derive(Accounts)]
+pub struct ConvertShareClass<'info> {
+    /// Program state account, used to block conversions while the kill switch is active
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = !state.is_kill_switch_active()? @ ConvertShareClassErrorCode::KillSwitchActivated,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Registry entry of the share class being converted from
+    #[account(
+        seeds = [seeds::SHARE_CLASS, source_mint.key().as_ref()],
+        bump = source_share_class.bump
+    )]
+    pub source_share_class: Box<Account<'info, ShareClass>>,
+
+    /// Registry entry of the share class being converted to
+    #[account(
+        seeds = [seeds::SHARE_CLASS, destination_mint.key().as_ref()],
+        bump = destination_share_class.bump
+    )]
+    pub destination_share_class: Box<Account<'info, ShareClass>>,
+
+    /// The source share class mint, mutable to allow supply updates during burning
+    #[account(
+        mut,
+        mint::token_program = source_token_program
+    )]
+    pub source_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The destination share class mint, mutable to allow supply updates during minting
+    #[account(
+        mut,
+        mint::token_program = destination_token_program,
+        constraint = source_mint.key() != destination_mint.key()
+            @ ConvertShareClassErrorCode::SameShareClass
+    )]
+    pub destination_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The user's source class token account the tokens are burned from
+    #[account(
+        mut,
+        associated_token::mint = source_mint,
+        associated_token::authority = user,
+        associated_token::token_program = source_token_program
+    )]
+    pub user_source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user's destination class token account receiving the minted tokens
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = destination_mint,
+        associated_token::authority = user,
+        associated_token::token_program = destination_token_program
+    )]
+    pub user_destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-derived account that serves as the mint authority of the destination class
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [seeds::MINT_AUTHORITY],
+        constraint = destination_mint.mint_authority == Some(mint_authority.key()).into()
+            @ ConvertShareClassErrorCode::NoMintAuthority,
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// The user converting tokens, who authorizes the burn and pays for account creation
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Token program of the source share class
+    pub source_token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the destination share class
+    pub destination_token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program required for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Converts tokens of one share class into another at the registered ratio
+///
+/// Burns `amount` of the source class from the user and mints the destination
+/// class amount holding the same book value:
+///
+/// `destination_amount = amount * source_rate / destination_rate`, adjusted for the
+/// decimals of both mints and rounded down.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - Amount of source class tokens to convert in base units
+///
+/// # Returns
+/// * `Ok(())` - If the conversion completes successfully
+/// * `Err(ConvertShareClassErrorCode::InvalidAmount)` - If the amount or resulting amount is zero
+/// * `Err(ConvertShareClassErrorCode::MathOverflow)` - If the conversion overflows
+/// * `Err(TokenUtilsErrorCode::MaxSupplyExceeded)` - If the destination class cap would be exceeded
+///
+/// # Access Control
+/// - Any holder of a registered share class can convert
+/// - Blocked while the kill switch is active
+///
+/// # Effects
+/// - Burns source class tokens from the user
+/// - Mints destination class tokens to the user
+///
+/// # Events
+/// * `ShareClassConvertedEvent` - Emitted with the burned and minted amounts
+pub fn convert_share_class(ctx: Context<ConvertShareClass>, amount: u64) -> Result<()> {
+    require!(amount > 0, ConvertShareClassErrorCode::InvalidAmount);
+
+    let destination_amount = calculate_share_class_conversion(
+        amount,
+        ctx.accounts.source_share_class.conversion_rate,
+        ctx.accounts.source_mint.decimals,
+        ctx.accounts.destination_share_class.conversion_rate,
+        ctx.accounts.destination_mint.decimals,
+    )?;
+    require!(
+        destination_amount > 0,
+        ConvertShareClassErrorCode::InvalidAmount
+    );
+
+    burn_tokens(
+        &ctx.accounts.source_token_program,
+        &ctx.accounts.source_mint,
+        &ctx.accounts.user_source_account,
+        &ctx.accounts.user.to_account_info(),
+        &[],
+        amount,
+    )?;
+
+    let mint_authority_seeds = &[seeds::MINT_AUTHORITY, &[ctx.bumps.mint_authority]];
+    let mint_authority_signer_seeds = &[mint_authority_seeds.as_slice()];
+
+    mint_tokens(
+        &ctx.accounts.destination_token_program,
+        &ctx.accounts.destination_mint,
+        &ctx.accounts.user_destination_account.to_account_info(),
+        &ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_signer_seeds,
+        destination_amount,
+        destination_max_supply(&ctx.accounts.state, &ctx.accounts.destination_share_class),
+    )?;
+
+    msg!(
+        "Share class converted: {} {} -> {} {} for user {}",
+        amount,
+        ctx.accounts.source_mint.key(),
+        destination_amount,
+        ctx.accounts.destination_mint.key(),
+        ctx.accounts.user.key()
+    );
+
+    emit!(ShareClassConvertedEvent {
+        source_mint: ctx.accounts.source_mint.key(),
+        destination_mint: ctx.accounts.destination_mint.key(),
+        user: ctx.accounts.user.key(),
+        source_amount: amount,
+        destination_amount,
+    });
+
+    Ok(())
+}
+
+/// Calculates the destination class amount holding the same book value as `amount`
+///
+/// # Arguments
+/// * `amount` - Source class amount in base units
+/// * `source_rate` - Book value of one whole source class token with scale=9
+/// * `source_decimals` - Decimals of the source class mint
+/// * `destination_rate` - Book value of one whole destination class token with scale=9
+/// * `destination_decimals` - Decimals of the destination class mint
+///
+/// # Returns
+/// * `Ok(u64)` - Destination class amount in base units, rounded down
+/// * `Err(ConvertShareClassErrorCode::MathOverflow)` - If the result does not fit in u64
+pub fn calculate_share_class_conversion(
+    amount: u64,
+    source_rate: u64,
+    source_decimals: u8,
+    destination_rate: u64,
+    destination_decimals: u8,
+) -> Result<u64> {
+    let pow10 = |exp: u8| 10u128.checked_pow(exp as u32);
+
+    // amount * source_rate * 10^destination_decimals / (destination_rate * 10^source_decimals)
+    let numerator = (amount as u128)
+        .checked_mul(source_rate as u128)
+        .and_then(|n| n.checked_mul(pow10(destination_decimals)?))
+        .ok_or(ConvertShareClassErrorCode::MathOverflow)?;
+    let denominator = (destination_rate as u128)
+        .checked_mul(pow10(source_decimals).ok_or(ConvertShareClassErrorCode::MathOverflow)?)
+        .ok_or(ConvertShareClassErrorCode::MathOverflow)?;
+
+    let result = numerator / denominator;
+    u64::try_from(result).map_err(|_| error!(ConvertShareClassErrorCode::MathOverflow))
+}
+
+/// Returns the effective supply cap of the destination class (0 = no cap)
+///
+/// The ONyc mint keeps its global cap from program state, so registering it as a
+/// share class can only tighten, never lift, that cap.
+fn destination_max_supply(state: &State, share_class: &ShareClass) -> u64 {
+    let state_cap = if share_class.mint == state.onyc_mint {
+        state.max_supply
+    } else {
+        0
+    };
+
+    match (state_cap, share_class.max_supply) {
+        (0, class_cap) => class_cap,
+        (state_cap, 0) => state_cap,
+        (state_cap, class_cap) => state_cap.min(class_cap),
+    }
+}
+
+/// Error codes for share class conversion operations
+#[error_code]
+pub enum ConvertShareClassErrorCode {
+    /// The kill switch is active
+    #[msg("Kill switch is activated")]
+    KillSwitchActivated,
+    /// Source and destination share classes are the same
+    #[msg("Source and destination share classes must differ")]
+    SameShareClass,
+    /// The program doesn't have mint authority for the destination class
+    #[msg("Program does not have mint authority for the destination share class")]
+    NoMintAuthority,
+    /// The amount to convert or the converted amount is zero
+    #[msg("Invalid amount: conversion must produce a non-zero amount")]
+    InvalidAmount,
+    /// Arithmetic overflow during conversion
+    #[msg("Math overflow")]
+    MathOverflow,
+}
@@ -0,0 +1,9 @@
+pub mod add_share_class;
+pub mod convert_share_class;
+pub mod share_class_state;
+pub mod update_share_class;
+
+pub use add_share_class::*;
+pub use convert_share_class::*;
+pub use share_class_state::*;
+pub use update_share_class::*;
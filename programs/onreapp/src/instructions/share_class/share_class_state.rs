@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry for one ONyc share class backed by the shared book
+///
+/// Each share class (e.g. a distributing and an accumulating ONyc variant) has its
+/// own mint and one registry entry derived from that mint. The conversion rate
+/// expresses the value of the class relative to the common book, allowing holders
+/// to move between classes at a consistent ratio.
+#[account]
+#[derive(InitSpace)]
+pub struct ShareClass {
+    /// Token mint of this share class
+    pub mint: Pubkey,
+    /// Book value of one whole class token with scale=9 (1_000_000_000 = 1.0)
+    pub conversion_rate: u64,
+    /// Maximum supply of this class in base units (0 = no cap)
+    pub max_supply: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
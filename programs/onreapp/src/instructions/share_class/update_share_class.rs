@@ -0,0 +1,117 @@
+use crate::constants::seeds;
+use crate::instructions::share_class::ShareClass;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a share class configuration is successfully updated
+///
+/// Provides transparency for tracking conversion rate and supply cap changes.
+#[event]
+pub struct ShareClassUpdatedEvent {
+    /// The PDA address of the updated share class registry entry
+    pub share_class_pda: Pubkey,
+    /// Previous book value of one whole class token with scale=9
+    pub old_conversion_rate: u64,
+    /// New book value of one whole class token with scale=9
+    pub new_conversion_rate: u64,
+    /// Previous maximum supply of the class in base units (0 = no cap)
+    pub old_max_supply: u64,
+    /// New maximum supply of the class in base units (0 = no cap)
+    pub new_max_supply: u64,
+}
+
+/// Account structure for updating a share class configuration
+///
+/// This struct defines the accounts required to modify the conversion rate and
+/// supply cap of a registered share class. Only the boss can update share classes.
+#[derive(Accounts)]
+pub struct UpdateShareClass<'info> {
+    /// The share class registry entry to update
+    #[account(
+        mut,
+        seeds = [seeds::SHARE_CLASS, share_class.mint.as_ref()],
+        bump = share_class.bump
+    )]
+    pub share_class: Account<'info, ShareClass>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to update share classes
+    pub boss: Signer<'info>,
+}
+
+/// Updates the conversion rate and supply cap of a share class
+///
+/// Accumulating classes reprice over time relative to the book, so the boss keeps
+/// their conversion rate in line with the class NAV. Lowering the supply cap below
+/// the current supply only blocks further conversions into the class.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `conversion_rate` - New book value of one whole class token with scale=9
+/// * `max_supply` - New maximum supply of the class in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the share class is successfully updated
+/// * `Err(UpdateShareClassErrorCode::InvalidConversionRate)` - If the conversion rate is zero
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the share class conversion rate and supply cap
+/// - Affects all future conversions involving the class
+///
+/// # Events
+/// * `ShareClassUpdatedEvent` - Emitted with old and new configuration values
+pub fn update_share_class(
+    ctx: Context<UpdateShareClass>,
+    conversion_rate: u64,
+    max_supply: u64,
+) -> Result<()> {
+    require!(
+        conversion_rate > 0,
+        UpdateShareClassErrorCode::InvalidConversionRate
+    );
+
+    let share_class = &mut ctx.accounts.share_class;
+    let old_conversion_rate = share_class.conversion_rate;
+    let old_max_supply = share_class.max_supply;
+    share_class.conversion_rate = conversion_rate;
+    share_class.max_supply = max_supply;
+
+    msg!(
+        "Share class updated for mint: {}, conversion rate: {} -> {}, max supply: {} -> {}",
+        share_class.mint,
+        old_conversion_rate,
+        conversion_rate,
+        old_max_supply,
+        max_supply
+    );
+
+    emit!(ShareClassUpdatedEvent {
+        share_class_pda: ctx.accounts.share_class.key(),
+        old_conversion_rate,
+        new_conversion_rate: conversion_rate,
+        old_max_supply,
+        new_max_supply: max_supply,
+    });
+
+    Ok(())
+}
+
+/// Error codes for share class update operations
+#[error_code]
+pub enum UpdateShareClassErrorCode {
+    /// Conversion rate must be greater than zero
+    #[msg("Invalid conversion rate: must be greater than zero")]
+    InvalidConversionRate,
+}
@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Error codes for the accept_boss instruction
@@ -35,6 +35,7 @@ pub struct AcceptBoss<'info> {
         mut,
         seeds = [seeds::STATE],
         bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
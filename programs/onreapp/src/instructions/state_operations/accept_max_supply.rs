@@ -0,0 +1,85 @@
+use crate::constants::seeds;
+use crate::instructions::MaxSupplyConfiguredEvent;
+use crate::state::{MaxSupplyProposal, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Error codes for the accept_max_supply instruction
+#[error_code]
+pub enum AcceptMaxSupplyErrorCode {
+    /// The proposal's timelock has not elapsed yet
+    #[msg("Max supply proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+}
+
+/// Account structure for accepting a pending ONyc maximum supply proposal
+#[derive(Accounts)]
+pub struct AcceptMaxSupply<'info> {
+    /// Program state account receiving the new max supply
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The pending max supply proposal, closed to the boss once applied
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::MAX_SUPPLY_PROPOSAL],
+        bump = max_supply_proposal.bump
+    )]
+    pub max_supply_proposal: Account<'info, MaxSupplyProposal>,
+
+    /// The boss account accepting the proposal and receiving the proposal rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Applies a pending max supply proposal after its timelock
+///
+/// This instruction is the second step of a cap change that raises or removes the
+/// cap, started with `propose_max_supply`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the proposed cap is applied
+/// * `Err(AcceptMaxSupplyErrorCode::TimelockNotElapsed)` - If the timelock has not elapsed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the program state's max_supply field
+/// - Closes the proposal account, returning its rent to the boss
+///
+/// # Events
+/// * `MaxSupplyConfiguredEvent` - Emitted with old and new max supply values
+pub fn accept_max_supply(ctx: Context<AcceptMaxSupply>) -> Result<()> {
+    let proposal = &ctx.accounts.max_supply_proposal;
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.effective_at,
+        AcceptMaxSupplyErrorCode::TimelockNotElapsed
+    );
+
+    let state = &mut ctx.accounts.state;
+    let old_max_supply = state.max_supply;
+    state.max_supply = proposal.max_supply;
+
+    msg!(
+        "Max supply accepted: {} (previous: {})",
+        state.max_supply,
+        old_max_supply
+    );
+
+    emit!(MaxSupplyConfiguredEvent {
+        old_max_supply,
+        new_max_supply: state.max_supply,
+    });
+
+    Ok(())
+}
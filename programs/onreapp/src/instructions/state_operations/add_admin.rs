@@ -1,5 +1,5 @@
 use crate::constants::{seeds, MAX_ADMINS};
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Event emitted when a new admin is successfully added
@@ -11,6 +11,8 @@ pub struct AdminAddedEvent {
     pub admin: Pubkey,
     /// The boss who added the admin
     pub boss: Pubkey,
+    /// Number of admins in the list after the addition
+    pub active_admin_count: u8,
 }
 
 /// Account structure for adding a new admin to the program state
@@ -27,7 +29,8 @@ pub struct AddAdmin<'info> {
         mut,
         has_one = boss,
         seeds = [seeds::STATE],
-        bump = state.bump
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -57,6 +60,9 @@ pub struct AddAdmin<'info> {
 /// # Effects
 /// - Adds the new admin to the first available slot in the admin array
 /// - Grants admin privileges for program operations
+///
+/// # Events
+/// * `AdminAddedEvent` - Emitted with the new admin and the resulting admin count
 pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
     let state = &mut ctx.accounts.state;
 
@@ -70,10 +76,19 @@ pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
     for i in 0..MAX_ADMINS {
         if state.admins[i] == Pubkey::default() {
             state.admins[i] = new_admin;
+            let active_admin_count = state.active_admin_count();
+
+            msg!(
+                "Admin added: {} by boss: {}, active admins: {}",
+                new_admin,
+                ctx.accounts.boss.key(),
+                active_admin_count
+            );
 
             emit!(AdminAddedEvent {
                 admin: new_admin,
                 boss: ctx.accounts.boss.key(),
+                active_admin_count,
             });
 
             return Ok(());
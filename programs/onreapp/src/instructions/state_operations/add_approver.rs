@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Event emitted when an approver is successfully added
@@ -18,7 +18,9 @@ pub struct AddApprover<'info> {
     #[account(mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss)]
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
     pub state: Account<'info, State>,
     pub boss: Signer<'info>,
 }
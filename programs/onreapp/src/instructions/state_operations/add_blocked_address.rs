@@ -0,0 +1,130 @@
+use crate::constants::seeds;
+use crate::state::{BlocklistBucket, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when an address is added to the blocklist
+///
+/// Provides transparency for tracking sanctions screening changes.
+#[event]
+pub struct BlockedAddressAddedEvent {
+    /// The blocked address
+    pub address: Pubkey,
+    /// Index of the blocklist bucket holding the address
+    pub bucket_index: u8,
+    /// The account that blocked the address (boss or admin)
+    pub signer: Pubkey,
+}
+
+/// Account structure for adding an address to the blocklist
+///
+/// The bucket covering the address is created on its first entry, with rent paid
+/// by the signer.
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddBlockedAddress<'info> {
+    /// Program state account containing boss and admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The blocklist bucket covering the address
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + BlocklistBucket::INIT_SPACE,
+        seeds = [seeds::BLOCKLIST, &[BlocklistBucket::index_for(&address)]],
+        bump
+    )]
+    pub blocklist_bucket: Box<Account<'info, BlocklistBucket>>,
+
+    /// The account blocking the address (boss or admin) and paying for bucket creation
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// System program required for bucket creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds an address to the sanctioned-address blocklist
+///
+/// Blocked addresses can no longer sign `take_offer`, `take_offer_exact_out`,
+/// `take_offer_permissionless` or `create_redemption_request`.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `address` - The address to block
+///
+/// # Returns
+/// * `Ok(())` - If the address is successfully blocked
+/// * `Err(AddBlockedAddressErrorCode::Unauthorized)` - If the signer is neither boss nor admin
+/// * `Err(AddBlockedAddressErrorCode::AlreadyBlocked)` - If the address is already blocked
+/// * `Err(AddBlockedAddressErrorCode::BucketFull)` - If the address's bucket is full
+///
+/// # Access Control
+/// - Boss or any admin can call this instruction
+///
+/// # Effects
+/// - Creates the address's blocklist bucket if needed
+/// - Adds the address to the first available slot of its bucket
+///
+/// # Events
+/// * `BlockedAddressAddedEvent` - Emitted with the address and its bucket
+pub fn add_blocked_address(ctx: Context<AddBlockedAddress>, address: Pubkey) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let signer = ctx.accounts.signer.key();
+    require!(
+        state.boss == signer || state.admins.contains(&signer),
+        AddBlockedAddressErrorCode::Unauthorized
+    );
+    require!(
+        address != Pubkey::default(),
+        AddBlockedAddressErrorCode::InvalidAddress
+    );
+
+    let bucket = &mut ctx.accounts.blocklist_bucket;
+    bucket.index = BlocklistBucket::index_for(&address);
+    bucket.bump = ctx.bumps.blocklist_bucket;
+
+    require!(
+        !bucket.addresses.contains(&address),
+        AddBlockedAddressErrorCode::AlreadyBlocked
+    );
+    let slot = bucket
+        .addresses
+        .iter_mut()
+        .find(|slot| **slot == Pubkey::default())
+        .ok_or(AddBlockedAddressErrorCode::BucketFull)?;
+    *slot = address;
+
+    msg!("Address {} blocked in bucket {}", address, bucket.index);
+    emit!(BlockedAddressAddedEvent {
+        address,
+        bucket_index: bucket.index,
+        signer,
+    });
+
+    Ok(())
+}
+
+/// Error codes for adding blocked addresses
+#[error_code]
+pub enum AddBlockedAddressErrorCode {
+    /// The signer is neither the boss nor an admin
+    #[msg("Unauthorized: boss or admin required")]
+    Unauthorized,
+
+    /// The default pubkey marks empty bucket slots and cannot be blocked
+    #[msg("Invalid address")]
+    InvalidAddress,
+
+    /// The address is already on the blocklist
+    #[msg("Address is already blocked")]
+    AlreadyBlocked,
+
+    /// The bucket covering the address has no free slot
+    #[msg("Blocklist bucket is full")]
+    BucketFull,
+}
@@ -0,0 +1,106 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode, TransferHookMintApproval};
+use crate::utils::transfer_hook_program_id;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a transfer-hook mint is approved for use as token_in
+///
+/// Provides transparency for tracking permissioned token onboarding.
+#[event]
+pub struct TransferHookMintApprovedEvent {
+    /// The approved transfer-hook mint
+    pub mint: Pubkey,
+    /// Transfer hook program bound to the approval
+    pub hook_program: Pubkey,
+}
+
+/// Account structure for approving a Token-2022 transfer-hook mint
+///
+/// Creates the mint's approval PDA. Only the boss can approve mints.
+#[derive(Accounts)]
+pub struct ApproveTransferHookMint<'info> {
+    /// Program state account for boss validation
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The transfer-hook mint to approve
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The approval account for the mint
+    ///
+    /// Derived from the mint address ensuring one approval per mint.
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + TransferHookMintApproval::INIT_SPACE,
+        seeds = [seeds::TRANSFER_HOOK_MINT, mint.key().as_ref()],
+        bump
+    )]
+    pub approval: Account<'info, TransferHookMintApproval>,
+
+    /// The boss account authorized to approve mints and paying for the approval
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Approves a Token-2022 transfer-hook mint for use as token_in of offers
+///
+/// Takes reject token_in mints with a transfer hook unless they are approved, since
+/// the hook program runs on every transfer and may block or reroute payments. The
+/// approval records the hook program the mint currently invokes and stops applying
+/// if the mint's hook authority later points it at another program.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the mint is approved
+/// * `Err(ApproveTransferHookMintErrorCode::NoTransferHook)` - If the mint has no transfer hook
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates the approval account bound to the mint's current hook program
+///
+/// # Events
+/// * `TransferHookMintApprovedEvent` - Emitted with the mint and hook program
+pub fn approve_transfer_hook_mint(ctx: Context<ApproveTransferHookMint>) -> Result<()> {
+    let hook_program = transfer_hook_program_id(&ctx.accounts.mint)?
+        .ok_or(ApproveTransferHookMintErrorCode::NoTransferHook)?;
+
+    let approval = &mut ctx.accounts.approval;
+    approval.mint = ctx.accounts.mint.key();
+    approval.hook_program = hook_program;
+    approval.bump = ctx.bumps.approval;
+
+    msg!(
+        "Transfer-hook mint approved: mint={}, hook_program={}",
+        approval.mint,
+        hook_program
+    );
+
+    emit!(TransferHookMintApprovedEvent {
+        mint: approval.mint,
+        hook_program,
+    });
+
+    Ok(())
+}
+
+/// Error codes for transfer-hook mint approval
+#[error_code]
+pub enum ApproveTransferHookMintErrorCode {
+    /// The mint has no Token-2022 transfer hook program configured
+    #[msg("Mint has no transfer hook")]
+    NoTransferHook,
+}
@@ -1,5 +1,5 @@
 use crate::constants::{seeds, MAX_ADMINS};
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::AccountInfo;
 use anchor_lang::prelude::*;
 
@@ -10,6 +10,10 @@ use anchor_lang::prelude::*;
 pub struct AdminsClearedEvent {
     /// The boss who cleared all admins
     pub boss: Pubkey,
+    /// Admins that were in the list before it was cleared
+    pub removed_admins: Vec<Pubkey>,
+    /// Number of admins in the list after clearing, always zero
+    pub active_admin_count: u8,
 }
 
 /// Account structure for clearing all admins from the program state
@@ -26,7 +30,8 @@ pub struct ClearAdmins<'info> {
         mut,
         has_one = boss,
         seeds = [seeds::STATE],
-        bump = state.bump
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -54,16 +59,34 @@ pub struct ClearAdmins<'info> {
 /// - Sets all admin array entries to default (empty) public keys
 /// - Revokes admin privileges from all previously authorized accounts
 /// - Does not affect the boss account's authority
+///
+/// # Events
+/// * `AdminsClearedEvent` - Emitted with every admin that lost its privileges
 pub fn clear_admins(ctx: Context<ClearAdmins>) -> Result<()> {
     let state = &mut ctx.accounts.state;
 
+    let removed_admins: Vec<Pubkey> = state
+        .admins
+        .iter()
+        .filter(|admin| **admin != Pubkey::default())
+        .copied()
+        .collect();
+
     // Clear all admins
     for i in 0..MAX_ADMINS {
         state.admins[i] = Pubkey::default();
     }
 
+    msg!(
+        "Admins cleared by boss: {}, removed admins: {}",
+        ctx.accounts.boss.key(),
+        removed_admins.len()
+    );
+
     emit!(AdminsClearedEvent {
         boss: ctx.accounts.boss.key(),
+        removed_admins,
+        active_admin_count: state.active_admin_count(),
     });
 
     Ok(())
@@ -1,4 +1,7 @@
 use crate::constants::seeds;
+use crate::instructions::redemption::{DualRedemptionOffer, RedemptionOffer};
+use crate::instructions::Offer;
+use crate::state::CloseStateProposal;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
@@ -17,7 +20,8 @@ pub struct StateClosedEvent {
 ///
 /// This struct defines the accounts required to permanently close the program's
 /// main state account and transfer its rent balance back to the boss.
-/// Only the boss can close the state account.
+/// Only the boss can close the state account, after proposing the closure with
+/// `propose_close_state`.
 ///
 /// Note: The state account is NOT deserialized to allow closing accounts with
 /// incompatible or outdated State structures.
@@ -39,6 +43,15 @@ pub struct CloseState<'info> {
     #[account(mut)]
     pub boss: Signer<'info>,
 
+    /// The pending state closure proposal, closed to the boss with the state
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::CLOSE_STATE_PROPOSAL],
+        bump = close_state_proposal.bump
+    )]
+    pub close_state_proposal: Account<'info, CloseStateProposal>,
+
     /// System program required for account closure and rent transfer
     pub system_program: Program<'info, System>,
 }
@@ -49,6 +62,12 @@ pub struct CloseState<'info> {
 /// balance back to the boss. The state account is permanently deleted and cannot
 /// be recovered. All program configuration and governance settings are lost.
 ///
+/// It is the second step of the closure started with `propose_close_state`: the
+/// proposal's timelock must have elapsed and the kill switch must still be enabled.
+/// The offer, redemption offer and dual redemption offer PDAs known to the caller
+/// are passed as remaining accounts, and the closure is refused while any of them
+/// still exists.
+///
 /// This operation effectively disables the program, as most instructions require
 /// the state account to function. Use with extreme caution.
 ///
@@ -60,13 +79,18 @@ pub struct CloseState<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the state is successfully closed and rent reclaimed
+/// * `Err(CloseStateErrorCode::TimelockNotElapsed)` - If the proposal's timelock has not elapsed
+/// * `Err(CloseStateErrorCode::KillSwitchNotActive)` - If the kill switch is not enabled
+/// * `Err(CloseStateErrorCode::OffersStillOpen)` - If a remaining account is a live offer
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
 /// - Boss account must match the one stored in program state
+/// - Kill switch must be enabled
 ///
 /// # Effects
 /// - State account is permanently deleted
+/// - Closure proposal account is closed, returning its rent to the boss
 /// - Rent balance is transferred to the boss
 /// - Program becomes effectively non-functional
 ///
@@ -90,18 +114,18 @@ pub fn close_state(ctx: Context<CloseState>) -> Result<()> {
         CloseStateErrorCode::InvalidStatePda
     );
 
-    // 2) Read the stored boss pubkey from raw bytes (no deserialize),
-    //    ensure we DROP the data borrow before mutating the account later.
-    //    Layout: [8-byte discriminator][32-byte boss][...]
-    let stored_boss = {
+    // 2) Read the stored boss pubkey and kill switch flag from raw bytes (no
+    //    deserialize), ensure we DROP the data borrow before mutating the account later.
+    //    Layout: [8-byte discriminator][32-byte boss][32-byte proposed_boss][1-byte is_killed][...]
+    let (stored_boss, is_killed) = {
         let data = state.try_borrow_data()?;
-        require!(data.len() >= 40, CloseStateErrorCode::InvalidStateData);
+        require!(data.len() >= 73, CloseStateErrorCode::InvalidStateData);
 
         // bytes 8..40 -> boss pubkey
         let arr: [u8; 32] = data[8..40]
             .try_into()
             .map_err(|_| error!(CloseStateErrorCode::InvalidStateData))?;
-        Pubkey::new_from_array(arr)
+        (Pubkey::new_from_array(arr), data[72] != 0)
     };
 
     let boss = &ctx.accounts.boss.to_account_info();
@@ -113,7 +137,27 @@ pub fn close_state(ctx: Context<CloseState>) -> Result<()> {
         CloseStateErrorCode::UnauthorizedSigner
     );
 
-    // 4) Drain lamports safely (checked math), then zero the source.
+    // 4) Closure must have been proposed long enough ago, with the kill switch still on
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.close_state_proposal.effective_at,
+        CloseStateErrorCode::TimelockNotElapsed
+    );
+    require!(is_killed, CloseStateErrorCode::KillSwitchNotActive);
+
+    // 5) Refuse while any of the provided offer PDAs still exists
+    for account in ctx.remaining_accounts {
+        if account.owner != &crate::ID {
+            continue;
+        }
+        let data = account.try_borrow_data()?;
+        let is_offer = data.len() >= 8
+            && (data[..8] == *Offer::DISCRIMINATOR
+                || data[..8] == *RedemptionOffer::DISCRIMINATOR
+                || data[..8] == *DualRedemptionOffer::DISCRIMINATOR);
+        require!(!is_offer, CloseStateErrorCode::OffersStillOpen);
+    }
+
+    // 6) Drain lamports safely (checked math), then zero the source.
     let state_lamports = state.lamports();
 
     // add first (checked), then zero state
@@ -124,7 +168,7 @@ pub fn close_state(ctx: Context<CloseState>) -> Result<()> {
     **boss.try_borrow_mut_lamports()? = boss_lamport_after;
     **state.try_borrow_mut_lamports()? = 0;
 
-    // 5) Deallocate & hand ownership back to System Program
+    // 7) Deallocate & hand ownership back to System Program
     // (Make sure we no longer hold any data borrows at this point.)
     state.resize(0)?;
     state.assign(&system_program::ID);
@@ -155,4 +199,13 @@ pub enum CloseStateErrorCode {
     /// Lamport arithmetic overflow
     #[msg("Lamport overflow")]
     LamportOverflow,
+    /// The closure proposal's timelock has not elapsed yet
+    #[msg("State closure proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+    /// The kill switch is not enabled
+    #[msg("Kill switch must be enabled before closing the state")]
+    KillSwitchNotActive,
+    /// An offer, redemption offer or dual redemption offer still exists
+    #[msg("Cannot close state while offers still exist")]
+    OffersStillOpen,
 }
@@ -0,0 +1,100 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the maximum NAV jump guardrail is successfully configured
+///
+/// Provides transparency for tracking changes to the vector activation guardrail.
+#[event]
+pub struct MaxNavJumpConfiguredEvent {
+    /// The previous maximum NAV jump in basis points (0 = no limit)
+    pub old_max_nav_jump_bps: u16,
+    /// The new maximum NAV jump in basis points (0 = no limit)
+    pub new_max_nav_jump_bps: u16,
+}
+
+/// Account structure for configuring the maximum NAV jump guardrail
+///
+/// This struct defines the accounts required to set or update the maximum price
+/// deviation allowed when a new offer vector takes over from its predecessor.
+/// Only the boss can configure this setting.
+#[derive(Accounts)]
+pub struct ConfigureMaxNavJump<'info> {
+    /// Program state account containing the max NAV jump configuration
+    ///
+    /// Must be mutable to allow guardrail updates and have the boss account
+    /// as the authorized signer.
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to configure the max NAV jump
+    pub boss: Signer<'info>,
+}
+
+/// Configures the maximum NAV jump allowed between consecutive offer vectors
+///
+/// When set to a non-zero value, `add_offer_vector` rejects any vector whose price at
+/// its activation time deviates from the preceding vector's price at that same time
+/// by more than the configured number of basis points. This allows future vectors to
+/// be scheduled ahead of time without risking accidental NAV gaps.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_nav_jump_bps` - Maximum allowed deviation in basis points (0 = no limit)
+///
+/// # Returns
+/// * `Ok(())` - If the guardrail is successfully configured
+/// * `Err(ConfigureMaxNavJumpErrorCode::InvalidMaxNavJump)` - If the value exceeds 10000
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the program state's max_nav_jump_bps field
+/// - All future vector additions will be validated against this limit
+/// - Setting to 0 disables the guardrail
+///
+/// # Events
+/// * `MaxNavJumpConfiguredEvent` - Emitted with old and new guardrail values
+pub fn configure_max_nav_jump(
+    ctx: Context<ConfigureMaxNavJump>,
+    max_nav_jump_bps: u16,
+) -> Result<()> {
+    require!(
+        max_nav_jump_bps <= MAX_BASIS_POINTS,
+        ConfigureMaxNavJumpErrorCode::InvalidMaxNavJump
+    );
+
+    let state = &mut ctx.accounts.state;
+
+    let old_max_nav_jump_bps = state.max_nav_jump_bps;
+    state.max_nav_jump_bps = max_nav_jump_bps;
+
+    msg!(
+        "Max NAV jump configured: {} bps (previous: {} bps)",
+        max_nav_jump_bps,
+        old_max_nav_jump_bps
+    );
+
+    emit!(MaxNavJumpConfiguredEvent {
+        old_max_nav_jump_bps,
+        new_max_nav_jump_bps: max_nav_jump_bps,
+    });
+
+    Ok(())
+}
+
+/// Error codes for max NAV jump configuration
+#[error_code]
+pub enum ConfigureMaxNavJumpErrorCode {
+    /// The provided max NAV jump exceeds 10000 basis points
+    #[msg("Invalid max NAV jump: must be <= 10000 basis points")]
+    InvalidMaxNavJump,
+}
@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Event emitted when the ONyc maximum supply is successfully configured
@@ -27,7 +27,8 @@ pub struct ConfigureMaxSupply<'info> {
         mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -37,16 +38,20 @@ pub struct ConfigureMaxSupply<'info> {
 
 /// Configures the maximum supply cap for ONyc token minting
 ///
-/// This instruction allows the boss to set or update the maximum supply cap
+/// This instruction allows the boss to set or lower the maximum supply cap
 /// that restricts ONyc token minting. When set to a non-zero value, all minting
 /// operations will be validated against this cap to prevent unbounded inflation.
 ///
+/// Only tightening changes apply immediately. Raising or removing the cap goes
+/// through `propose_max_supply` and `accept_max_supply` with a timelock.
+///
 /// # Arguments
 /// * `ctx` - The instruction context containing validated accounts
-/// * `max_supply` - The maximum supply cap in base units (0 = no cap)
+/// * `max_supply` - The maximum supply cap in base units
 ///
 /// # Returns
 /// * `Ok(())` - If the max supply is successfully configured
+/// * `Err(ConfigureMaxSupplyErrorCode::TimelockRequired)` - If the change raises or removes the cap
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
@@ -55,7 +60,6 @@ pub struct ConfigureMaxSupply<'info> {
 /// # Effects
 /// - Updates the program state's max_supply field
 /// - All future minting operations will validate against this cap
-/// - Setting to 0 removes the cap (unlimited minting)
 ///
 /// # Events
 /// * `MaxSupplyConfigured` - Emitted with old and new max supply values
@@ -63,6 +67,11 @@ pub fn configure_max_supply(ctx: Context<ConfigureMaxSupply>, max_supply: u64) -
     let state = &mut ctx.accounts.state;
 
     let old_max_supply = state.max_supply;
+    require!(
+        max_supply == old_max_supply
+            || (max_supply != 0 && (old_max_supply == 0 || max_supply < old_max_supply)),
+        ConfigureMaxSupplyErrorCode::TimelockRequired
+    );
     state.max_supply = max_supply;
 
     msg!(
@@ -78,3 +87,11 @@ pub fn configure_max_supply(ctx: Context<ConfigureMaxSupply>, max_supply: u64) -
 
     Ok(())
 }
+
+/// Error codes for the configure_max_supply instruction
+#[error_code]
+pub enum ConfigureMaxSupplyErrorCode {
+    /// Raising or removing the cap must go through propose_max_supply
+    #[msg("Raising or removing the max supply requires propose_max_supply")]
+    TimelockRequired,
+}
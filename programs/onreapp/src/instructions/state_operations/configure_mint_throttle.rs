@@ -0,0 +1,155 @@
+use crate::constants::seeds;
+use crate::state::{MintThrottle, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the ONyc mint throttle is configured
+///
+/// Provides transparency for tracking mint limit changes.
+#[event]
+pub struct MintThrottleConfiguredEvent {
+    /// The previous maximum mint per window (0 = no limit)
+    pub old_max_mint_per_window: u64,
+    /// The new maximum mint per window (0 = no limit)
+    pub new_max_mint_per_window: u64,
+    /// The previous window length in seconds
+    pub old_window_secs: u64,
+    /// The new window length in seconds
+    pub new_window_secs: u64,
+    /// The guardian that co-signed a loosening of the throttle, if any
+    pub guardian: Option<Pubkey>,
+}
+
+/// Account structure for configuring the ONyc mint throttle
+///
+/// The throttle account is created on first use, paid for by the boss.
+#[derive(Accounts)]
+pub struct ConfigureMintThrottle<'info> {
+    /// Program state account containing boss and guardian authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The mint throttle holding the limit and the rolling consumption
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MintThrottle::INIT_SPACE,
+        seeds = [seeds::MINT_THROTTLE],
+        bump
+    )]
+    pub mint_throttle: Box<Account<'info, MintThrottle>>,
+
+    /// The boss account authorized to configure the throttle and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// The guardian co-signing changes that loosen the throttle
+    ///
+    /// Required only when a guardian is set in program state and the change raises
+    /// the limit, shortens the window or removes the limit.
+    #[account(
+        constraint = guardian.key() == state.guardian @ ConfigureMintThrottleErrorCode::InvalidGuardian
+    )]
+    pub guardian: Option<Signer<'info>>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Configures the rolling limit on ONyc minted by the boss
+///
+/// Bounds how much ONyc a compromised boss key can mint in a short time. Tightening
+/// the throttle only needs the boss, while loosening it also needs the guardian once
+/// one is set, so the boss key alone cannot lift the limit it is subject to.
+/// Consumption already charged carries over to the new configuration.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_mint_per_window` - Maximum ONyc mintable per window in base units (0 = no limit)
+/// * `window_secs` - Length of the rolling window in seconds
+///
+/// # Returns
+/// * `Ok(())` - If the throttle is successfully configured
+/// * `Err(ConfigureMintThrottleErrorCode::InvalidWindow)` - If a limit is set with a zero window
+/// * `Err(ConfigureMintThrottleErrorCode::GuardianRequired)` - If a loosening change lacks the guardian
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Loosening changes also require the guardian when one is set
+///
+/// # Effects
+/// - Creates the mint throttle account if needed
+/// - Updates the limit and window length
+///
+/// # Events
+/// * `MintThrottleConfiguredEvent` - Emitted with old and new configuration
+pub fn configure_mint_throttle(
+    ctx: Context<ConfigureMintThrottle>,
+    max_mint_per_window: u64,
+    window_secs: u64,
+) -> Result<()> {
+    require!(
+        max_mint_per_window == 0 || window_secs > 0,
+        ConfigureMintThrottleErrorCode::InvalidWindow
+    );
+
+    let throttle = &mut ctx.accounts.mint_throttle;
+    throttle.bump = ctx.bumps.mint_throttle;
+
+    let old_max_mint_per_window = throttle.max_mint_per_window;
+    let old_window_secs = throttle.window_secs;
+
+    let was_limited = old_max_mint_per_window != 0;
+    let loosens = was_limited
+        && (max_mint_per_window == 0
+            || max_mint_per_window > old_max_mint_per_window
+            || window_secs < old_window_secs);
+    if loosens && ctx.accounts.state.guardian != Pubkey::default() {
+        require!(
+            ctx.accounts.guardian.is_some(),
+            ConfigureMintThrottleErrorCode::GuardianRequired
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    throttle.consumed = throttle.consumed_at(now);
+    throttle.last_update = now;
+    throttle.max_mint_per_window = max_mint_per_window;
+    throttle.window_secs = window_secs;
+
+    msg!(
+        "Mint throttle configured: {} per {}s (previous: {} per {}s)",
+        max_mint_per_window,
+        window_secs,
+        old_max_mint_per_window,
+        old_window_secs
+    );
+
+    emit!(MintThrottleConfiguredEvent {
+        old_max_mint_per_window,
+        new_max_mint_per_window: max_mint_per_window,
+        old_window_secs,
+        new_window_secs: window_secs,
+        guardian: ctx.accounts.guardian.as_ref().map(|g| g.key()),
+    });
+
+    Ok(())
+}
+
+/// Error codes for mint throttle configuration
+#[error_code]
+pub enum ConfigureMintThrottleErrorCode {
+    /// A limit requires a window longer than zero seconds
+    #[msg("Invalid window: must be greater than zero")]
+    InvalidWindow,
+    /// Loosening the throttle requires the guardian's signature
+    #[msg("Guardian signature required to loosen the mint throttle")]
+    GuardianRequired,
+    /// The guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
@@ -0,0 +1,110 @@
+use crate::constants::{seeds, MAX_BASIS_POINTS};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the redemption stress take throttling policy is configured
+///
+/// Provides transparency for tracking changes to the liquidity prioritization policy.
+#[event]
+pub struct RedemptionStressConfiguredEvent {
+    /// The previous stress threshold in basis points (0 = disabled)
+    pub old_threshold_bps: u16,
+    /// The new stress threshold in basis points (0 = disabled)
+    pub new_threshold_bps: u16,
+    /// The previous per-take token_in limit while stressed (0 = takes paused)
+    pub old_stressed_take_max_token_in: u64,
+    /// The new per-take token_in limit while stressed (0 = takes paused)
+    pub new_stressed_take_max_token_in: u64,
+}
+
+/// Account structure for configuring the redemption stress policy
+///
+/// Only the boss can configure this setting.
+#[derive(Accounts)]
+pub struct ConfigureRedemptionStress<'info> {
+    /// Program state account containing the redemption stress policy
+    ///
+    /// Must be mutable to allow policy updates and have the boss account
+    /// as the authorized signer.
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to configure the redemption stress policy
+    pub boss: Signer<'info>,
+}
+
+/// Configures when and how takes are throttled during redemption stress
+///
+/// A redemption offer is stressed when the token_in value of its outstanding
+/// redemption requests exceeds `threshold_bps` of the token_in held by the
+/// redemption vault. While stressed, takes of the matching offer are limited to
+/// `stressed_take_max_token_in` per take, or paused entirely when the limit is zero,
+/// so that vault liquidity goes to redeeming holders first.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `threshold_bps` - Outstanding redemptions relative to vault coverage in basis
+///   points above which takes are throttled (0 = disabled)
+/// * `stressed_take_max_token_in` - Maximum token_in per take while stressed (0 = paused)
+///
+/// # Returns
+/// * `Ok(())` - If the policy is successfully configured
+/// * `Err(ConfigureRedemptionStressErrorCode::InvalidThreshold)` - If the threshold exceeds 10000
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates the program state's redemption stress policy
+/// - Take instructions must pass the redemption offer and vault accounts while enabled
+///
+/// # Events
+/// * `RedemptionStressConfiguredEvent` - Emitted with old and new policy values
+pub fn configure_redemption_stress(
+    ctx: Context<ConfigureRedemptionStress>,
+    threshold_bps: u16,
+    stressed_take_max_token_in: u64,
+) -> Result<()> {
+    require!(
+        threshold_bps <= MAX_BASIS_POINTS,
+        ConfigureRedemptionStressErrorCode::InvalidThreshold
+    );
+
+    let state = &mut ctx.accounts.state;
+
+    let old_threshold_bps = state.redemption_stress_threshold_bps;
+    let old_stressed_take_max_token_in = state.stressed_take_max_token_in;
+    state.redemption_stress_threshold_bps = threshold_bps;
+    state.stressed_take_max_token_in = stressed_take_max_token_in;
+
+    msg!(
+        "Redemption stress configured: threshold {} bps, stressed take max {} (previous: {} bps, {})",
+        threshold_bps,
+        stressed_take_max_token_in,
+        old_threshold_bps,
+        old_stressed_take_max_token_in
+    );
+
+    emit!(RedemptionStressConfiguredEvent {
+        old_threshold_bps,
+        new_threshold_bps: threshold_bps,
+        old_stressed_take_max_token_in,
+        new_stressed_take_max_token_in: stressed_take_max_token_in,
+    });
+
+    Ok(())
+}
+
+/// Error codes for redemption stress configuration
+#[error_code]
+pub enum ConfigureRedemptionStressErrorCode {
+    /// The provided threshold exceeds 10000 basis points
+    #[msg("Invalid threshold: must be <= 10000 basis points")]
+    InvalidThreshold,
+}
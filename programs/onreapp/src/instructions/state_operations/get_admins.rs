@@ -0,0 +1,105 @@
+use crate::constants::{seeds, ADMINS_PAGE_SIZE};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// A single page of configured admin accounts returned by `get_admins`
+///
+/// Returned via return data so clients can list admins without parsing the raw
+/// State account layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AdminsPage {
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of configured admins across all pages
+    pub total_admins: u8,
+    /// Configured admins on this page, at most `ADMINS_PAGE_SIZE` entries
+    pub admins: Vec<Pubkey>,
+    /// Whether further pages follow this one
+    pub has_more: bool,
+}
+
+/// Event emitted when an admin page is queried
+///
+/// Provides transparency for tracking governance queries.
+#[event]
+pub struct GetAdminsEvent {
+    /// Zero-based index of the returned page
+    pub page: u8,
+    /// Total number of configured admins across all pages
+    pub total_admins: u8,
+    /// Number of admins returned on this page
+    pub returned_admins: u8,
+}
+
+/// Account structure for querying the configured admin accounts
+///
+/// This struct defines the accounts required to list admins. The query is
+/// read-only and requires no signer.
+#[derive(Accounts)]
+pub struct GetAdmins<'info> {
+    /// Program state account containing the admin list
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Returns one page of the configured admin accounts
+///
+/// This read-only instruction collects the non-empty admin slots from program state
+/// in storage order and returns the requested page of at most `ADMINS_PAGE_SIZE`
+/// entries. Requesting a page past the end returns an empty list.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `page` - Zero-based page index
+///
+/// # Returns
+/// * `Ok(AdminsPage)` - The requested page with pagination metadata
+///
+/// # Events
+/// * `GetAdminsEvent` - Emitted with the page index and admin counts
+pub fn get_admins(ctx: Context<GetAdmins>, page: u8) -> Result<AdminsPage> {
+    let configured: Vec<Pubkey> = ctx
+        .accounts
+        .state
+        .admins
+        .iter()
+        .filter(|admin| **admin != Pubkey::default())
+        .copied()
+        .collect();
+
+    let start = (page as usize).saturating_mul(ADMINS_PAGE_SIZE);
+    let admins: Vec<Pubkey> = configured
+        .iter()
+        .skip(start)
+        .take(ADMINS_PAGE_SIZE)
+        .copied()
+        .collect();
+    let has_more = start + admins.len() < configured.len();
+
+    let total_admins = configured.len() as u8;
+    let returned_admins = admins.len() as u8;
+
+    msg!(
+        "Admins Info - Page: {}, Returned: {}, Total: {}",
+        page,
+        returned_admins,
+        total_admins
+    );
+
+    emit!(GetAdminsEvent {
+        page,
+        total_admins,
+        returned_admins,
+    });
+
+    Ok(AdminsPage {
+        page,
+        total_admins,
+        admins,
+        has_more,
+    })
+}
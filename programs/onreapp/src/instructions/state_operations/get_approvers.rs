@@ -0,0 +1,56 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the configured approvers are queried
+///
+/// Provides transparency for tracking governance queries.
+#[event]
+pub struct GetApproversEvent {
+    /// Number of configured approvers returned
+    pub total_approvers: u8,
+}
+
+/// Account structure for querying the configured approval authorities
+///
+/// This struct defines the accounts required to list approvers. The query is
+/// read-only and requires no signer.
+#[derive(Accounts)]
+pub struct GetApprovers<'info> {
+    /// Program state account containing the approver slots
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Returns the configured trusted authorities for approval verification
+///
+/// This read-only instruction returns the non-empty approver slots in slot order,
+/// so clients do not depend on how approvers are laid out in program state.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(approvers)` - The configured approvers (zero to two entries)
+///
+/// # Events
+/// * `GetApproversEvent` - Emitted with the number of configured approvers
+pub fn get_approvers(ctx: Context<GetApprovers>) -> Result<Vec<Pubkey>> {
+    let state = &ctx.accounts.state;
+    let approvers: Vec<Pubkey> = [state.approver1, state.approver2]
+        .into_iter()
+        .filter(|approver| *approver != Pubkey::default())
+        .collect();
+
+    let total_approvers = approvers.len() as u8;
+
+    msg!("Approvers Info - Total: {}", total_approvers);
+
+    emit!(GetApproversEvent { total_approvers });
+
+    Ok(approvers)
+}
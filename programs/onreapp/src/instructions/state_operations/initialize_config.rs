@@ -0,0 +1,97 @@
+use crate::constants::{seeds, DEFAULT_SECONDS_PER_YEAR, MAX_ALLOWED_FEE_BPS};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the program-wide configuration account is created
+///
+/// Provides transparency for tracking the initial tunables.
+#[event]
+pub struct ConfigInitializedEvent {
+    /// The PDA address of the configuration account
+    pub config_pda: Pubkey,
+    /// Maximum fee in basis points offers and redemption offers may charge
+    pub max_fee_bps: u16,
+    /// Maximum approval validity in seconds (0 = no limit)
+    pub max_approval_validity_secs: u64,
+    /// Number of seconds per year over which vector APRs accrue
+    pub seconds_per_year: u64,
+    /// Clock skew grace for approval messages in seconds
+    pub clock_skew_grace_secs: u64,
+}
+
+/// Account structure for creating the program-wide configuration account
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The configuration account to create
+    #[account(
+        init,
+        payer = boss,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [seeds::CONFIG],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The boss account creating the configuration and paying for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the program-wide configuration account with its default tunables
+///
+/// The defaults match the values previously compiled into the program: a 1000
+/// basis points fee cap, no limit on approval validity, a 365 day year and no clock
+/// skew grace for approvals. Offer and redemption instructions that read the
+/// configuration fail until it exists.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the configuration account is successfully created
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates the configuration account, paid for by the boss
+///
+/// # Events
+/// * `ConfigInitializedEvent` - Emitted with the default tunables
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.max_fee_bps = MAX_ALLOWED_FEE_BPS;
+    config.max_approval_validity_secs = 0;
+    config.seconds_per_year = DEFAULT_SECONDS_PER_YEAR;
+    config.clock_skew_grace_secs = 0;
+    config.bump = ctx.bumps.config;
+
+    msg!(
+        "Config initialized: max fee {} bps, max approval validity {}s, {} seconds per year",
+        config.max_fee_bps,
+        config.max_approval_validity_secs,
+        config.seconds_per_year
+    );
+
+    emit!(ConfigInitializedEvent {
+        config_pda: config.key(),
+        max_fee_bps: config.max_fee_bps,
+        max_approval_validity_secs: config.max_approval_validity_secs,
+        seconds_per_year: config.seconds_per_year,
+        clock_skew_grace_secs: config.clock_skew_grace_secs,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::constants::{seeds, CURRENT_STATE_SCHEMA_VERSION};
+use crate::state::State;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Event emitted when the state account is migrated to a newer schema version
+///
+/// Provides transparency for tracking state layout upgrades.
+#[event]
+pub struct StateMigratedEvent {
+    /// Schema version before the migration
+    pub old_version: u16,
+    /// Schema version after the migration
+    pub new_version: u16,
+    /// Account data length before migration
+    pub old_size: u64,
+    /// Account data length after migration
+    pub new_size: u64,
+    /// The boss account that ran the migration
+    pub boss: Pubkey,
+}
+
+/// Account structure for migrating the program state to a newer schema version
+///
+/// Note: The state account is NOT deserialized by Anchor since an outdated layout
+/// may not match the current State definition.
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    /// The state account to be upgraded
+    ///
+    /// Its owner, discriminator and boss are validated manually in the handler.
+    /// CHECK: Manual validation of owner, discriminator and boss without deserialization
+    #[account(mut, seeds = [seeds::STATE], bump)]
+    pub state: UncheckedAccount<'info>,
+
+    /// The boss account authorized to migrate the state and pay any additional rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program required for rent top-ups
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrades the program state to `target_version` one schema version at a time
+///
+/// The account is first grown to the size of the current layout, zero-filling the
+/// appended bytes, and each version step then fills in the fields it introduced.
+/// Instructions reject states below `MIN_STATE_SCHEMA_VERSION`, except
+/// `initialize`, `close_state`, `set_kill_switch` and this instruction.
+///
+/// Version steps:
+/// - `0 -> 1`: Records the schema version; the layout is unchanged
+/// - `1 -> 2`: Adds the blocked jurisdiction bitmap, zero-filled so no jurisdiction
+///   is blocked
+/// - `2 -> 3`: Adds the screening program, zero-filled so takes are not screened
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `target_version` - Schema version to migrate to
+///
+/// # Returns
+/// * `Ok(())` - If the state is successfully migrated
+/// * `Err(MigrateStateErrorCode::InvalidTargetVersion)` - If the target is not above the
+///   stored version or is newer than this program supports
+/// * `Err(MigrateStateErrorCode::UnauthorizedSigner)` - If the signer is not the stored boss
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Resizes the state account when a version step needs more space
+/// - Boss pays the rent difference for a larger account
+/// - Updates the state's schema_version
+///
+/// # Events
+/// * `StateMigratedEvent` - Emitted with the old and new versions and sizes
+pub fn migrate_state(ctx: Context<MigrateState>, target_version: u16) -> Result<()> {
+    let state_info: &AccountInfo = ctx.accounts.state.as_ref();
+
+    require_keys_eq!(
+        *state_info.owner,
+        crate::ID,
+        MigrateStateErrorCode::InvalidStateOwner
+    );
+
+    // Layout: [8-byte discriminator][32-byte boss][...]
+    let stored_boss = {
+        let data = state_info.try_borrow_data()?;
+        require!(
+            data.len() >= 40 && data[..8] == *State::DISCRIMINATOR,
+            MigrateStateErrorCode::InvalidStateData
+        );
+        Pubkey::try_from(&data[8..40]).map_err(|_| MigrateStateErrorCode::InvalidStateData)?
+    };
+    require_keys_eq!(
+        ctx.accounts.boss.key(),
+        stored_boss,
+        MigrateStateErrorCode::UnauthorizedSigner
+    );
+
+    // Grow the account to the current layout before deserializing it
+    let old_size = state_info.data_len();
+    let new_size = old_size.max(8 + State::INIT_SPACE);
+    if new_size > old_size {
+        let required_lamports = Rent::get()?.minimum_balance(new_size);
+        let missing_lamports = required_lamports.saturating_sub(state_info.lamports());
+        if missing_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.boss.to_account_info(),
+                        to: state_info.clone(),
+                    },
+                ),
+                missing_lamports,
+            )?;
+        }
+        state_info.resize(new_size)?;
+        state_info.try_borrow_mut_data()?[old_size..].fill(0);
+    }
+
+    let mut state = State::try_deserialize(&mut &state_info.try_borrow_data()?[..])?;
+    let old_version = state.schema_version;
+    require!(
+        target_version > old_version && target_version <= CURRENT_STATE_SCHEMA_VERSION,
+        MigrateStateErrorCode::InvalidTargetVersion
+    );
+
+    while state.schema_version < target_version {
+        match state.schema_version {
+            // Versioning was introduced without layout changes
+            0 => {}
+            // The resize zero-filled the blocked jurisdiction bitmap
+            1 => {}
+            // The resize zero-filled the screening program
+            2 => {}
+            _ => return err!(MigrateStateErrorCode::MissingMigrationStep),
+        }
+        state.schema_version += 1;
+    }
+    state.try_serialize(&mut &mut state_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "State migrated: version {} -> {}, size: {} -> {}",
+        old_version,
+        target_version,
+        old_size,
+        new_size
+    );
+
+    emit!(StateMigratedEvent {
+        old_version,
+        new_version: target_version,
+        old_size: old_size as u64,
+        new_size: new_size as u64,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for state migration operations
+#[error_code]
+pub enum MigrateStateErrorCode {
+    /// State account is not owned by this program
+    #[msg("State account must be owned by this program")]
+    InvalidStateOwner,
+
+    /// State account data does not start with the State discriminator
+    #[msg("Invalid state account data")]
+    InvalidStateData,
+
+    /// The signer is not the boss stored in the state
+    #[msg("Unauthorized signer: must be the boss")]
+    UnauthorizedSigner,
+
+    /// The target version is not newer than the stored one or not supported
+    #[msg("Invalid target schema version")]
+    InvalidTargetVersion,
+
+    /// No upgrade step exists for a version below the target
+    #[msg("No migration step for the state schema version")]
+    MissingMigrationStep,
+}
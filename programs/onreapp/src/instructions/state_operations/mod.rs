@@ -1,25 +1,65 @@
 pub mod accept_boss;
+pub mod accept_max_supply;
 pub mod add_admin;
 pub mod add_approver;
+pub mod add_blocked_address;
+pub mod approve_transfer_hook_mint;
 pub mod clear_admins;
 pub mod close_state;
+pub mod configure_max_nav_jump;
 pub mod configure_max_supply;
+pub mod configure_mint_throttle;
+pub mod configure_redemption_stress;
+pub mod get_admins;
+pub mod get_approvers;
+pub mod initialize_config;
+pub mod migrate_state;
 pub mod propose_boss;
+pub mod propose_close_state;
+pub mod propose_max_supply;
 pub mod remove_admin;
 pub mod remove_approver;
+pub mod remove_blocked_address;
+pub mod revoke_transfer_hook_mint;
+pub mod set_config;
+pub mod set_guardian;
+pub mod set_jurisdiction_blocked;
 pub mod set_kill_switch;
 pub mod set_onyc_mint;
+pub mod set_primary_offer;
 pub mod set_redemption_admin;
+pub mod set_screening_program;
+pub mod view_state;
 
 pub use accept_boss::*;
+pub use accept_max_supply::*;
 pub use add_admin::*;
 pub use add_approver::*;
+pub use add_blocked_address::*;
+pub use approve_transfer_hook_mint::*;
 pub use clear_admins::*;
 pub use close_state::*;
+pub use configure_max_nav_jump::*;
 pub use configure_max_supply::*;
+pub use configure_mint_throttle::*;
+pub use configure_redemption_stress::*;
+pub use get_admins::*;
+pub use get_approvers::*;
+pub use initialize_config::*;
+pub use migrate_state::*;
 pub use propose_boss::*;
+pub use propose_close_state::*;
+pub use propose_max_supply::*;
 pub use remove_admin::*;
 pub use remove_approver::*;
+pub use remove_blocked_address::*;
+pub use revoke_transfer_hook_mint::*;
+pub use set_config::*;
+pub use set_guardian::*;
+pub use set_jurisdiction_blocked::*;
 pub use set_kill_switch::*;
 pub use set_onyc_mint::*;
+pub use set_primary_offer::*;
 pub use set_redemption_admin::*;
+pub use set_screening_program::*;
+pub use view_state::*;
@@ -1,5 +1,5 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Error codes for the propose_boss instruction
@@ -34,7 +34,8 @@ pub struct ProposeBoss<'info> {
         mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -0,0 +1,99 @@
+use crate::constants::{seeds, CLOSE_STATE_TIMELOCK_SECS};
+use crate::state::{CloseStateProposal, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the closure of the program state is proposed
+///
+/// Gives users on-chain notice that the program is about to be shut down.
+#[event]
+pub struct StateClosureProposedEvent {
+    /// The boss account that proposed the closure
+    pub boss: Pubkey,
+    /// Unix timestamp from which the state can be closed
+    pub effective_at: i64,
+}
+
+/// Account structure for proposing the closure of the program state
+///
+/// The proposal account is created on first use, paid for by the boss, and replaced
+/// by later proposals.
+#[derive(Accounts)]
+pub struct ProposeCloseState<'info> {
+    /// Program state account to be closed once the timelock elapses
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss @ ProposeCloseStateErrorCode::Unauthorized,
+        constraint = state.is_kill_switch_active()? @ ProposeCloseStateErrorCode::KillSwitchNotActive,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The pending state closure proposal
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + CloseStateProposal::INIT_SPACE,
+        seeds = [seeds::CLOSE_STATE_PROPOSAL],
+        bump
+    )]
+    pub close_state_proposal: Account<'info, CloseStateProposal>,
+
+    /// The boss account proposing the closure and paying for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes the permanent closure of the program state
+///
+/// This instruction is the first step of closing the state. The kill switch must be
+/// enabled, so no new activity starts while the closure is pending, and the state can
+/// be closed with `close_state` once `CLOSE_STATE_TIMELOCK_SECS` have passed.
+/// Proposing again replaces the pending proposal and restarts the timelock.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the proposal is recorded successfully
+/// * `Err(ProposeCloseStateErrorCode::KillSwitchNotActive)` - If the kill switch is not enabled
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates or replaces the state closure proposal account
+///
+/// # Events
+/// * `StateClosureProposedEvent` - Emitted with the time the closure becomes possible
+pub fn propose_close_state(ctx: Context<ProposeCloseState>) -> Result<()> {
+    let effective_at = Clock::get()?.unix_timestamp + CLOSE_STATE_TIMELOCK_SECS;
+
+    let proposal = &mut ctx.accounts.close_state_proposal;
+    proposal.effective_at = effective_at;
+    proposal.bump = ctx.bumps.close_state_proposal;
+
+    msg!("State closure proposed, effective at {}", effective_at);
+
+    emit!(StateClosureProposedEvent {
+        boss: ctx.accounts.boss.key(),
+        effective_at,
+    });
+
+    Ok(())
+}
+
+/// Error codes for the propose_close_state instruction
+#[error_code]
+pub enum ProposeCloseStateErrorCode {
+    /// Caller is not the boss
+    #[msg("Unauthorized: only the boss can propose closing the state")]
+    Unauthorized,
+
+    /// The kill switch has to be enabled before the closure is proposed
+    #[msg("Kill switch must be enabled before closing the state")]
+    KillSwitchNotActive,
+}
@@ -0,0 +1,97 @@
+use crate::constants::{seeds, MAX_SUPPLY_TIMELOCK_SECS};
+use crate::state::{MaxSupplyProposal, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a change of the ONyc maximum supply is proposed
+///
+/// Gives token holders on-chain notice of a loosening of the supply cap before it
+/// takes effect.
+#[event]
+pub struct MaxSupplyProposedEvent {
+    /// The current maximum supply cap (0 = no cap)
+    pub current_max_supply: u64,
+    /// The proposed maximum supply cap (0 = no cap)
+    pub proposed_max_supply: u64,
+    /// Unix timestamp from which the proposal can be accepted
+    pub effective_at: i64,
+}
+
+/// Account structure for proposing a change of the ONyc maximum supply
+///
+/// The proposal account is created on first use, paid for by the boss, and replaced
+/// by later proposals.
+#[derive(Accounts)]
+pub struct ProposeMaxSupply<'info> {
+    /// Program state account containing the current max supply
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The pending max supply proposal
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + MaxSupplyProposal::INIT_SPACE,
+        seeds = [seeds::MAX_SUPPLY_PROPOSAL],
+        bump
+    )]
+    pub max_supply_proposal: Account<'info, MaxSupplyProposal>,
+
+    /// The boss account proposing the change and paying for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a new maximum supply cap for ONyc token minting
+///
+/// This instruction is the first step of a cap change that raises or removes the
+/// cap. The proposal can be accepted with `accept_max_supply` once
+/// `MAX_SUPPLY_TIMELOCK_SECS` have passed. Proposing again replaces the pending
+/// proposal and restarts the timelock. Decreases can be applied immediately with
+/// `configure_max_supply` instead.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_supply` - The proposed maximum supply cap in base units (0 = no cap)
+///
+/// # Returns
+/// * `Ok(())` - If the proposal is recorded successfully
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates or replaces the max supply proposal account
+///
+/// # Events
+/// * `MaxSupplyProposedEvent` - Emitted with the proposed cap and its effective time
+pub fn propose_max_supply(ctx: Context<ProposeMaxSupply>, max_supply: u64) -> Result<()> {
+    let effective_at = Clock::get()?.unix_timestamp + MAX_SUPPLY_TIMELOCK_SECS;
+
+    let proposal = &mut ctx.accounts.max_supply_proposal;
+    proposal.max_supply = max_supply;
+    proposal.effective_at = effective_at;
+    proposal.bump = ctx.bumps.max_supply_proposal;
+
+    msg!(
+        "Max supply proposed: {} (current: {}), effective at {}",
+        max_supply,
+        ctx.accounts.state.max_supply,
+        effective_at
+    );
+
+    emit!(MaxSupplyProposedEvent {
+        current_max_supply: ctx.accounts.state.max_supply,
+        proposed_max_supply: max_supply,
+        effective_at,
+    });
+
+    Ok(())
+}
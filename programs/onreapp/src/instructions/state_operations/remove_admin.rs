@@ -1,5 +1,5 @@
 use crate::constants::{seeds, MAX_ADMINS};
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 
 /// Event emitted when an admin is successfully removed
@@ -11,6 +11,8 @@ pub struct AdminRemovedEvent {
     pub admin: Pubkey,
     /// The boss who removed the admin
     pub boss: Pubkey,
+    /// Number of admins remaining in the list after the removal
+    pub active_admin_count: u8,
 }
 
 /// Account structure for removing an admin from the program state
@@ -27,7 +29,8 @@ pub struct RemoveAdmin<'info> {
         mut,
         has_one = boss,
         seeds = [seeds::STATE],
-        bump = state.bump
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -57,6 +60,9 @@ pub struct RemoveAdmin<'info> {
 /// - Sets the admin array entry to default (empty) public key
 /// - Revokes admin privileges from the specified account
 /// - Makes the admin slot available for future use
+///
+/// # Events
+/// * `AdminRemovedEvent` - Emitted with the removed admin and the remaining admin count
 pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Result<()> {
     let state = &mut ctx.accounts.state;
 
@@ -64,10 +70,19 @@ pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Resul
     for i in 0..MAX_ADMINS {
         if state.admins[i] == admin_to_remove {
             state.admins[i] = Pubkey::default();
+            let active_admin_count = state.active_admin_count();
+
+            msg!(
+                "Admin removed: {} by boss: {}, active admins: {}",
+                admin_to_remove,
+                ctx.accounts.boss.key(),
+                active_admin_count
+            );
 
             emit!(AdminRemovedEvent {
                 admin: admin_to_remove,
                 boss: ctx.accounts.boss.key(),
+                active_admin_count,
             });
 
             return Ok(());
@@ -0,0 +1,99 @@
+use crate::constants::seeds;
+use crate::state::{BlocklistBucket, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when an address is removed from the blocklist
+///
+/// Provides transparency for tracking sanctions screening changes.
+#[event]
+pub struct BlockedAddressRemovedEvent {
+    /// The unblocked address
+    pub address: Pubkey,
+    /// Index of the blocklist bucket that held the address
+    pub bucket_index: u8,
+    /// The account that unblocked the address (boss or admin)
+    pub signer: Pubkey,
+}
+
+/// Account structure for removing an address from the blocklist
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct RemoveBlockedAddress<'info> {
+    /// Program state account containing boss and admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The blocklist bucket holding the address
+    #[account(
+        mut,
+        seeds = [seeds::BLOCKLIST, &[BlocklistBucket::index_for(&address)]],
+        bump = blocklist_bucket.bump
+    )]
+    pub blocklist_bucket: Box<Account<'info, BlocklistBucket>>,
+
+    /// The account unblocking the address (boss or admin)
+    pub signer: Signer<'info>,
+}
+
+/// Removes an address from the sanctioned-address blocklist
+///
+/// The bucket account is kept when its last address is removed.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `address` - The address to unblock
+///
+/// # Returns
+/// * `Ok(())` - If the address is successfully unblocked
+/// * `Err(RemoveBlockedAddressErrorCode::Unauthorized)` - If the signer is neither boss nor admin
+/// * `Err(RemoveBlockedAddressErrorCode::NotBlocked)` - If the address is not blocked
+///
+/// # Access Control
+/// - Boss or any admin can call this instruction
+///
+/// # Effects
+/// - Clears the address's slot in its bucket
+///
+/// # Events
+/// * `BlockedAddressRemovedEvent` - Emitted with the address and its bucket
+pub fn remove_blocked_address(ctx: Context<RemoveBlockedAddress>, address: Pubkey) -> Result<()> {
+    let state = &ctx.accounts.state;
+    let signer = ctx.accounts.signer.key();
+    require!(
+        state.boss == signer || state.admins.contains(&signer),
+        RemoveBlockedAddressErrorCode::Unauthorized
+    );
+
+    let bucket = &mut ctx.accounts.blocklist_bucket;
+    let slot = bucket
+        .addresses
+        .iter_mut()
+        .find(|slot| **slot == address)
+        .ok_or(RemoveBlockedAddressErrorCode::NotBlocked)?;
+    *slot = Pubkey::default();
+
+    msg!("Address {} unblocked in bucket {}", address, bucket.index);
+    emit!(BlockedAddressRemovedEvent {
+        address,
+        bucket_index: bucket.index,
+        signer,
+    });
+
+    Ok(())
+}
+
+/// Error codes for removing blocked addresses
+#[error_code]
+pub enum RemoveBlockedAddressErrorCode {
+    /// The signer is neither the boss nor an admin
+    #[msg("Unauthorized: boss or admin required")]
+    Unauthorized,
+
+    /// The address is not on the blocklist
+    #[msg("Address is not blocked")]
+    NotBlocked,
+}
@@ -0,0 +1,68 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode, TransferHookMintApproval};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a transfer-hook mint approval is revoked
+///
+/// Provides transparency for tracking permissioned token offboarding.
+#[event]
+pub struct TransferHookMintRevokedEvent {
+    /// The mint whose approval was revoked
+    pub mint: Pubkey,
+}
+
+/// Account structure for revoking a transfer-hook mint approval
+///
+/// Closes the mint's approval PDA and returns its rent to the boss.
+#[derive(Accounts)]
+pub struct RevokeTransferHookMint<'info> {
+    /// Program state account for boss validation
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The approval account to close
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::TRANSFER_HOOK_MINT, approval.mint.as_ref()],
+        bump = approval.bump
+    )]
+    pub approval: Account<'info, TransferHookMintApproval>,
+
+    /// The boss account authorized to revoke approvals and receiving the rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+}
+
+/// Revokes the approval of a transfer-hook mint
+///
+/// Subsequent takes paying with the mint are rejected until it is approved again.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the approval is closed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Closes the approval account, returning its rent to the boss
+///
+/// # Events
+/// * `TransferHookMintRevokedEvent` - Emitted with the revoked mint
+pub fn revoke_transfer_hook_mint(ctx: Context<RevokeTransferHookMint>) -> Result<()> {
+    let mint = ctx.accounts.approval.mint;
+
+    msg!("Transfer-hook mint approval revoked: mint={}", mint);
+
+    emit!(TransferHookMintRevokedEvent { mint });
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use crate::constants::{
+    seeds, MAX_APPROVAL_VALIDITY_SECS, MAX_BASIS_POINTS, MAX_CLOCK_SKEW_GRACE_SECS,
+    MAX_SECONDS_PER_YEAR, MIN_SECONDS_PER_YEAR,
+};
+use crate::state::{Config, State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the program-wide configuration is updated
+///
+/// Provides transparency for tracking changes to the tunables.
+#[event]
+pub struct ConfigUpdatedEvent {
+    /// Previous maximum fee in basis points
+    pub old_max_fee_bps: u16,
+    /// New maximum fee in basis points
+    pub new_max_fee_bps: u16,
+    /// Previous maximum approval validity in seconds (0 = no limit)
+    pub old_max_approval_validity_secs: u64,
+    /// New maximum approval validity in seconds (0 = no limit)
+    pub new_max_approval_validity_secs: u64,
+    /// Previous number of seconds per year
+    pub old_seconds_per_year: u64,
+    /// New number of seconds per year
+    pub new_seconds_per_year: u64,
+    /// Previous clock skew grace for approval messages in seconds
+    pub old_clock_skew_grace_secs: u64,
+    /// New clock skew grace for approval messages in seconds
+    pub new_clock_skew_grace_secs: u64,
+}
+
+/// Account structure for updating the program-wide configuration
+#[derive(Accounts)]
+pub struct SetConfig<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The configuration account to update
+    #[account(mut, seeds = [seeds::CONFIG], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The boss account authorized to update the configuration
+    pub boss: Signer<'info>,
+}
+
+/// Updates the program-wide tunables
+///
+/// A lower fee cap only applies to fees set afterwards; fees already configured on
+/// offers and redemption offers stay in effect. A new seconds-per-year value changes
+/// the price of every vector from the next instruction on, so it should be set while
+/// the program is paused or before vectors are scheduled.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `max_fee_bps` - Maximum fee in basis points (at most 10000)
+/// * `max_approval_validity_secs` - Maximum approval validity in seconds (0 = no limit,
+///   at most 30 days)
+/// * `seconds_per_year` - Seconds per year for APR accrual (between 360 and 366 days)
+/// * `clock_skew_grace_secs` - Grace in seconds for clock skew between approvers and
+///   the validator (at most 5 minutes)
+///
+/// # Returns
+/// * `Ok(())` - If the configuration is successfully updated
+/// * `Err(SetConfigErrorCode::InvalidMaxFee)` - If the fee cap exceeds 10000
+/// * `Err(SetConfigErrorCode::InvalidApprovalValidity)` - If the validity exceeds 30 days
+/// * `Err(SetConfigErrorCode::InvalidSecondsPerYear)` - If the year length is out of range
+/// * `Err(SetConfigErrorCode::InvalidClockSkewGrace)` - If the grace exceeds 5 minutes
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Updates all fields of the configuration account
+///
+/// # Events
+/// * `ConfigUpdatedEvent` - Emitted with old and new tunables
+pub fn set_config(
+    ctx: Context<SetConfig>,
+    max_fee_bps: u16,
+    max_approval_validity_secs: u64,
+    seconds_per_year: u64,
+    clock_skew_grace_secs: u64,
+) -> Result<()> {
+    require!(
+        max_fee_bps <= MAX_BASIS_POINTS,
+        SetConfigErrorCode::InvalidMaxFee
+    );
+    require!(
+        max_approval_validity_secs <= MAX_APPROVAL_VALIDITY_SECS,
+        SetConfigErrorCode::InvalidApprovalValidity
+    );
+    require!(
+        (MIN_SECONDS_PER_YEAR..=MAX_SECONDS_PER_YEAR).contains(&seconds_per_year),
+        SetConfigErrorCode::InvalidSecondsPerYear
+    );
+    require!(
+        clock_skew_grace_secs <= MAX_CLOCK_SKEW_GRACE_SECS,
+        SetConfigErrorCode::InvalidClockSkewGrace
+    );
+
+    let config = &mut ctx.accounts.config;
+    let event = ConfigUpdatedEvent {
+        old_max_fee_bps: config.max_fee_bps,
+        new_max_fee_bps: max_fee_bps,
+        old_max_approval_validity_secs: config.max_approval_validity_secs,
+        new_max_approval_validity_secs: max_approval_validity_secs,
+        old_seconds_per_year: config.seconds_per_year,
+        new_seconds_per_year: seconds_per_year,
+        old_clock_skew_grace_secs: config.clock_skew_grace_secs,
+        new_clock_skew_grace_secs: clock_skew_grace_secs,
+    };
+
+    config.max_fee_bps = max_fee_bps;
+    config.max_approval_validity_secs = max_approval_validity_secs;
+    config.seconds_per_year = seconds_per_year;
+    config.clock_skew_grace_secs = clock_skew_grace_secs;
+
+    msg!(
+        "Config updated: max fee {} bps, max approval validity {}s, {} seconds per year, clock skew grace {}s",
+        max_fee_bps,
+        max_approval_validity_secs,
+        seconds_per_year,
+        clock_skew_grace_secs
+    );
+
+    emit!(event);
+
+    Ok(())
+}
+
+/// Error codes for configuration updates
+#[error_code]
+pub enum SetConfigErrorCode {
+    /// The fee cap exceeds 10000 basis points
+    #[msg("Invalid max fee: must be <= 10000 basis points")]
+    InvalidMaxFee,
+
+    /// The approval validity exceeds 30 days
+    #[msg("Invalid max approval validity: must be <= 30 days")]
+    InvalidApprovalValidity,
+
+    /// The year length is outside 360 to 366 days
+    #[msg("Invalid seconds per year: must be between 360 and 366 days")]
+    InvalidSecondsPerYear,
+
+    /// The clock skew grace exceeds 5 minutes
+    #[msg("Invalid clock skew grace: must be <= 5 minutes")]
+    InvalidClockSkewGrace,
+}
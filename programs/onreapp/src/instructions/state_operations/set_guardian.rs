@@ -0,0 +1,122 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the guardian is successfully updated
+///
+/// Provides transparency for tracking changes to the clawback co-signer.
+#[event]
+pub struct GuardianUpdatedEvent {
+    /// The previous guardian public key before the update
+    pub old_guardian: Pubkey,
+    /// The new guardian public key after the update
+    pub new_guardian: Pubkey,
+}
+
+/// Account structure for configuring the guardian
+///
+/// This struct defines the accounts required to set or update the guardian
+/// address in the program state. The boss configures this setting, together with
+/// the current guardian once one is set.
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// Program state account containing the guardian configuration
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The boss account authorized to configure the guardian
+    pub boss: Signer<'info>,
+
+    /// The current guardian consenting to its replacement
+    ///
+    /// Required only when a guardian is already set in program state.
+    #[account(
+        constraint = current_guardian.key() == state.guardian
+            @ SetGuardianErrorCode::InvalidGuardian
+    )]
+    pub current_guardian: Option<Signer<'info>>,
+}
+
+/// Configures the guardian address in program state
+///
+/// The guardian co-signs emergency clawbacks together with the boss, so a single
+/// compromised key cannot reverse takes. The boss alone appoints the first guardian;
+/// replacing or clearing it afterwards needs the current guardian's signature, so
+/// the boss cannot swap in a key of its own. Setting the default pubkey disables
+/// clawbacks entirely.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_guardian` - Public key of the new guardian
+///
+/// # Returns
+/// * `Ok(())` - If the guardian is successfully configured
+/// * `Err(SetGuardianErrorCode::NoChange)` - If the guardian is already set to this key
+/// * `Err(SetGuardianErrorCode::GuardianIsBoss)` - If the guardian would be the boss itself
+/// * `Err(SetGuardianErrorCode::GuardianRequired)` - If a guardian is set but did not sign
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+/// - Also requires the current guardian when one is set
+///
+/// # Effects
+/// - Updates the program state's guardian field
+///
+/// # Events
+/// * `GuardianUpdatedEvent` - Emitted with old and new guardian addresses
+pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+
+    require!(
+        new_guardian != state.guardian,
+        SetGuardianErrorCode::NoChange
+    );
+    require!(
+        new_guardian != state.boss,
+        SetGuardianErrorCode::GuardianIsBoss
+    );
+    if state.guardian != Pubkey::default() {
+        require!(
+            ctx.accounts.current_guardian.is_some(),
+            SetGuardianErrorCode::GuardianRequired
+        );
+    }
+
+    let old_guardian = state.guardian;
+    state.guardian = new_guardian;
+
+    msg!("Guardian updated: {}", state.guardian);
+    emit!(GuardianUpdatedEvent {
+        old_guardian,
+        new_guardian: state.guardian,
+    });
+
+    Ok(())
+}
+
+/// Error codes for set guardian operations
+#[error_code]
+pub enum SetGuardianErrorCode {
+    /// The new guardian is the same as the current one
+    #[msg("No change: new guardian is the same as current")]
+    NoChange,
+
+    /// The guardian must be a different key than the boss
+    #[msg("Guardian cannot be the boss")]
+    GuardianIsBoss,
+
+    /// Replacing a set guardian requires its signature
+    #[msg("Current guardian signature required to replace the guardian")]
+    GuardianRequired,
+
+    /// The current guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
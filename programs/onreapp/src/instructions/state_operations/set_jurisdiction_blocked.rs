@@ -0,0 +1,102 @@
+use crate::constants::{seeds, JURISDICTION_BITMAP_BYTES};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a jurisdiction is blocked or unblocked
+///
+/// Provides transparency for tracking geofencing changes of the approval flow.
+#[event]
+pub struct JurisdictionBlockUpdatedEvent {
+    /// The jurisdiction code that was updated
+    pub jurisdiction: u16,
+    /// Whether approvals carrying the code are now rejected
+    pub blocked: bool,
+}
+
+/// Account structure for blocking or unblocking a jurisdiction
+///
+/// This struct defines the accounts required to update the blocked jurisdiction
+/// bitmap in the program state. Only the boss can configure this setting.
+#[derive(Accounts)]
+pub struct SetJurisdictionBlocked<'info> {
+    /// Program state account containing the blocked jurisdiction bitmap
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to update jurisdiction restrictions
+    pub boss: Signer<'info>,
+}
+
+/// Blocks or unblocks approvals carrying a jurisdiction code
+///
+/// Approvers assign each user a jurisdiction code in the signed approval message.
+/// Blocking a code rejects all of its approvals, including ones already issued,
+/// without rotating the approver keys.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `jurisdiction` - Jurisdiction code to update, below `JURISDICTION_BITMAP_BYTES * 8`
+/// * `blocked` - Whether approvals carrying the code are rejected
+///
+/// # Returns
+/// * `Ok(())` - If the jurisdiction is successfully updated
+/// * `Err(SetJurisdictionBlockedErrorCode::InvalidJurisdiction)` - If the code is beyond the bitmap
+/// * `Err(SetJurisdictionBlockedErrorCode::NoChange)` - If the code is already in the requested state
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Sets or clears the jurisdiction's bit in the program state's bitmap
+///
+/// # Events
+/// * `JurisdictionBlockUpdatedEvent` - Emitted with the jurisdiction code and its new status
+pub fn set_jurisdiction_blocked(
+    ctx: Context<SetJurisdictionBlocked>,
+    jurisdiction: u16,
+    blocked: bool,
+) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+
+    require!(
+        (jurisdiction as usize) < JURISDICTION_BITMAP_BYTES * 8,
+        SetJurisdictionBlockedErrorCode::InvalidJurisdiction
+    );
+    require!(
+        state.is_jurisdiction_blocked(jurisdiction) != blocked,
+        SetJurisdictionBlockedErrorCode::NoChange
+    );
+
+    state.set_jurisdiction_blocked(jurisdiction, blocked);
+
+    msg!(
+        "Jurisdiction {} {}",
+        jurisdiction,
+        if blocked { "blocked" } else { "unblocked" }
+    );
+    emit!(JurisdictionBlockUpdatedEvent {
+        jurisdiction,
+        blocked,
+    });
+
+    Ok(())
+}
+
+/// Error codes for jurisdiction restriction operations
+#[error_code]
+pub enum SetJurisdictionBlockedErrorCode {
+    /// The jurisdiction code does not fit in the blocked jurisdiction bitmap
+    #[msg("Invalid jurisdiction code")]
+    InvalidJurisdiction,
+
+    /// The jurisdiction is already blocked or unblocked as requested
+    #[msg("No change: jurisdiction already in the requested state")]
+    NoChange,
+}
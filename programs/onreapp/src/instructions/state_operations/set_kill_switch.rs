@@ -10,6 +10,8 @@ use crate::state::State;
 pub struct KillSwitchToggledEvent {
     /// Whether the kill switch was enabled (true) or disabled (false)
     pub enabled: bool,
+    /// Unix timestamp at which the kill switch lapses (0 = no expiry)
+    pub expiry: i64,
     /// The account that toggled the kill switch
     pub signer: Pubkey,
 }
@@ -30,7 +32,7 @@ pub struct SetKillSwitch<'info> {
         bump = state.bump,
     )]
     pub state: Box<Account<'info, State>>,
-    
+
     /// The account attempting to modify the kill switch (boss or admin)
     pub signer: Signer<'info>,
 }
@@ -41,24 +43,34 @@ pub struct SetKillSwitch<'info> {
 /// offer operations when activated. The kill switch has asymmetric access control:
 /// both boss and admins can enable it, but only the boss can disable it.
 ///
+/// An enabling call may set a duration after which the kill switch lapses on its
+/// own; operations treat it as disabled from then on. Enabling again replaces the
+/// previous expiry, and disabling clears it.
+///
 /// # Arguments
 /// * `ctx` - The instruction context containing validated accounts
 /// * `enable` - Whether to enable (true) or disable (false) the kill switch
+/// * `duration` - Seconds until an enabled kill switch lapses (None = until disabled)
 ///
 /// # Returns
 /// * `Ok(())` - If the kill switch state is successfully updated
 /// * `Err(ErrorCode::UnauthorizedToEnable)` - If non-authorized user tries to enable
 /// * `Err(ErrorCode::OnlyBossCanDisable)` - If non-boss user tries to disable
+/// * `Err(ErrorCode::InvalidDuration)` - If the duration is zero or set when disabling
 ///
 /// # Access Control
 /// - Enable: Boss or any admin can activate the kill switch
 /// - Disable: Only the boss can deactivate the kill switch
 ///
 /// # Effects
-/// - Updates the program state's is_killed field
+/// - Updates the program state's is_killed and kill_switch_expiry fields
 /// - When enabled, prevents offer execution operations
 /// - Provides emergency halt capability for security incidents
-pub fn set_kill_switch(ctx: Context<SetKillSwitch>, enable: bool) -> Result<()> {
+pub fn set_kill_switch(
+    ctx: Context<SetKillSwitch>,
+    enable: bool,
+    duration: Option<u64>,
+) -> Result<()> {
     let state = &mut ctx.accounts.state;
     let signer = &ctx.accounts.signer;
 
@@ -68,13 +80,27 @@ pub fn set_kill_switch(ctx: Context<SetKillSwitch>, enable: bool) -> Result<()>
     if enable {
         require!(boss_signed || admin_signed, ErrorCode::UnauthorizedToEnable);
         state.is_killed = true;
+        state.kill_switch_expiry = match duration {
+            Some(duration) => {
+                require!(duration > 0, ErrorCode::InvalidDuration);
+                let duration = i64::try_from(duration).map_err(|_| ErrorCode::InvalidDuration)?;
+                Clock::get()?
+                    .unix_timestamp
+                    .checked_add(duration)
+                    .ok_or(ErrorCode::InvalidDuration)?
+            }
+            None => 0,
+        };
     } else {
         require!(boss_signed, ErrorCode::OnlyBossCanDisable);
+        require!(duration.is_none(), ErrorCode::InvalidDuration);
         state.is_killed = false;
+        state.kill_switch_expiry = 0;
     }
 
     emit!(KillSwitchToggledEvent {
         enabled: enable,
+        expiry: state.kill_switch_expiry,
         signer: signer.key(),
     });
 
@@ -90,4 +116,7 @@ pub enum ErrorCode {
     /// Signer is neither boss nor admin and cannot enable the kill switch
     #[msg("Unauthorized to enable the kill switch")]
     UnauthorizedToEnable,
+    /// The duration is zero, too large, or passed when disabling
+    #[msg("Invalid kill switch duration")]
+    InvalidDuration,
 }
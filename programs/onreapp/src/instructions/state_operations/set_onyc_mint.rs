@@ -1,6 +1,5 @@
-use crate::account;
 use crate::constants::seeds;
-use crate::state::State;
+use crate::state::{State, StateSchemaErrorCode};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
@@ -29,7 +28,8 @@ pub struct SetOnycMint<'info> {
         mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Account<'info, State>,
 
@@ -0,0 +1,97 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the primary offer is successfully updated
+///
+/// Provides transparency for tracking which offer prices the global TVL.
+#[event]
+pub struct PrimaryOfferUpdatedEvent {
+    /// The previous primary offer before the update
+    pub old_primary_offer: Pubkey,
+    /// The new primary offer after the update
+    pub new_primary_offer: Pubkey,
+}
+
+/// Account structure for configuring the primary offer
+///
+/// This struct defines the accounts required to designate the offer whose NAV
+/// is used for global TVL calculations. Only the boss can configure this setting.
+#[derive(Accounts)]
+pub struct SetPrimaryOffer<'info> {
+    /// Program state account containing the primary offer configuration
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Account<'info, State>,
+
+    /// The offer to designate, which must sell the program's ONyc mint
+    #[account(
+        constraint = offer.load()?.token_out_mint == state.onyc_mint
+            @ SetPrimaryOfferErrorCode::InvalidPrimaryOffer
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The boss account authorized to configure the primary offer
+    pub boss: Signer<'info>,
+}
+
+/// Designates the offer whose NAV prices the whole ONyc supply
+///
+/// `get_global_tvl` values the circulating ONyc supply at the current NAV of this
+/// offer, so it should be the offer quoting ONyc against the reporting currency.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the primary offer is successfully configured
+/// * `Err(SetPrimaryOfferErrorCode::InvalidPrimaryOffer)` - If the offer does not sell ONyc
+/// * `Err(SetPrimaryOfferErrorCode::NoChange)` - If the offer is already the primary offer
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the program state's primary_offer field
+///
+/// # Events
+/// * `PrimaryOfferUpdatedEvent` - Emitted with old and new primary offer addresses
+pub fn set_primary_offer(ctx: Context<SetPrimaryOffer>) -> Result<()> {
+    let new_primary_offer = ctx.accounts.offer.key();
+    let state = &mut ctx.accounts.state;
+
+    require!(
+        new_primary_offer != state.primary_offer,
+        SetPrimaryOfferErrorCode::NoChange
+    );
+
+    let old_primary_offer = state.primary_offer;
+    state.primary_offer = new_primary_offer;
+
+    msg!("Primary offer updated: {}", state.primary_offer);
+    emit!(PrimaryOfferUpdatedEvent {
+        old_primary_offer,
+        new_primary_offer,
+    });
+
+    Ok(())
+}
+
+/// Error codes for set primary offer operations
+#[error_code]
+pub enum SetPrimaryOfferErrorCode {
+    /// The offer's token_out mint is not the program's ONyc mint
+    #[msg("Invalid primary offer: token_out mint must be the ONyc mint")]
+    InvalidPrimaryOffer,
+
+    /// The offer is already the primary offer
+    #[msg("No change: offer is already the primary offer")]
+    NoChange,
+}
@@ -0,0 +1,98 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when the screening program is successfully updated
+///
+/// Provides transparency for tracking changes to the on-chain AML rules applied to takes.
+#[event]
+pub struct ScreeningProgramUpdatedEvent {
+    /// The previous screening program before the update
+    pub old_screening_program: Pubkey,
+    /// The new screening program after the update
+    pub new_screening_program: Pubkey,
+}
+
+/// Account structure for configuring the screening program
+///
+/// This struct defines the accounts required to set or update the screening
+/// program in the program state. Only the boss can configure this setting.
+#[derive(Accounts)]
+pub struct SetScreeningProgram<'info> {
+    /// Program state account containing the screening program configuration
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to configure the screening program
+    pub boss: Signer<'info>,
+}
+
+/// Configures the program screening takes before they complete
+///
+/// While set, `take_offer` and `take_offer_exact_out` call the program's
+/// `check(user, amount)` instruction and abort when it fails, so AML rules can be
+/// swapped without upgrading this program. Setting the default pubkey disables
+/// screening.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `new_screening_program` - Program implementing the screening interface
+///
+/// # Returns
+/// * `Ok(())` - If the screening program is successfully configured
+/// * `Err(SetScreeningProgramErrorCode::NoChange)` - If the screening program is already set
+/// * `Err(SetScreeningProgramErrorCode::InvalidScreeningProgram)` - If the program is this one
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Updates the program state's screening_program field
+///
+/// # Events
+/// * `ScreeningProgramUpdatedEvent` - Emitted with old and new screening programs
+pub fn set_screening_program(
+    ctx: Context<SetScreeningProgram>,
+    new_screening_program: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.state;
+
+    require!(
+        new_screening_program != state.screening_program,
+        SetScreeningProgramErrorCode::NoChange
+    );
+    require!(
+        new_screening_program != crate::ID,
+        SetScreeningProgramErrorCode::InvalidScreeningProgram
+    );
+
+    let old_screening_program = state.screening_program;
+    state.screening_program = new_screening_program;
+
+    msg!("Screening program updated: {}", state.screening_program);
+    emit!(ScreeningProgramUpdatedEvent {
+        old_screening_program,
+        new_screening_program: state.screening_program,
+    });
+
+    Ok(())
+}
+
+/// Error codes for set screening program operations
+#[error_code]
+pub enum SetScreeningProgramErrorCode {
+    /// The new screening program is the same as the current one
+    #[msg("No change: new screening program is the same as current")]
+    NoChange,
+
+    /// The program cannot screen its own takes
+    #[msg("Invalid screening program")]
+    InvalidScreeningProgram,
+}
@@ -0,0 +1,117 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Governance and configuration of the program returned by `view_state`
+///
+/// Decoupled from the `State` account layout so clients can decode it through the
+/// IDL. Admins are listed page by page by `get_admins`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct StateView {
+    /// Primary program authority
+    pub boss: Pubkey,
+    /// Proposed new boss awaiting acceptance (default = none)
+    pub proposed_boss: Pubkey,
+    /// Whether the kill switch currently halts operations, taking its expiry into account
+    pub kill_switch_active: bool,
+    /// Unix timestamp at which an enabled kill switch lapses (0 = no expiry)
+    pub kill_switch_expiry: i64,
+    /// ONyc token mint
+    pub onyc_mint: Pubkey,
+    /// Number of configured admins
+    pub admin_count: u8,
+    /// First trusted approval authority (default = unset)
+    pub approver1: Pubkey,
+    /// Second trusted approval authority (default = unset)
+    pub approver2: Pubkey,
+    /// Maximum supply cap for ONyc minting (0 = no cap)
+    pub max_supply: u64,
+    /// Admin authorized to manage redemptions
+    pub redemption_admin: Pubkey,
+    /// Maximum NAV jump between consecutive vectors in basis points (0 = no limit)
+    pub max_nav_jump_bps: u16,
+    /// Guardian co-signing emergency clawbacks
+    pub guardian: Pubkey,
+    /// Offer pricing the whole ONyc supply in `get_global_tvl`
+    pub primary_offer: Pubkey,
+    /// Redemption stress threshold in basis points (0 = disabled)
+    pub redemption_stress_threshold_bps: u16,
+    /// Maximum token_in per take while redemptions are stressed
+    pub stressed_take_max_token_in: u64,
+    /// Program screening takes (default = no screening)
+    pub screening_program: Pubkey,
+    /// Layout version of the state account
+    pub schema_version: u16,
+}
+
+/// Event emitted when the program state is viewed
+///
+/// Provides transparency for tracking governance queries.
+#[event]
+pub struct ViewStateEvent {
+    /// The current boss
+    pub boss: Pubkey,
+    /// Whether the kill switch currently halts operations
+    pub kill_switch_active: bool,
+}
+
+/// Account structure for viewing the program state
+///
+/// The query is read-only and requires no signer.
+#[derive(Accounts)]
+pub struct ViewState<'info> {
+    /// Program state account to view
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+}
+
+/// Returns the governance and configuration settings of the program
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(StateView)` - The program's governance and configuration settings
+///
+/// # Events
+/// * `ViewStateEvent` - Emitted with the boss and kill switch status
+pub fn view_state(ctx: Context<ViewState>) -> Result<StateView> {
+    let state = &ctx.accounts.state;
+
+    let view = StateView {
+        boss: state.boss,
+        proposed_boss: state.proposed_boss,
+        kill_switch_active: state.is_kill_switch_active()?,
+        kill_switch_expiry: state.kill_switch_expiry,
+        onyc_mint: state.onyc_mint,
+        admin_count: state.active_admin_count(),
+        approver1: state.approver1,
+        approver2: state.approver2,
+        max_supply: state.max_supply,
+        redemption_admin: state.redemption_admin,
+        max_nav_jump_bps: state.max_nav_jump_bps,
+        guardian: state.guardian,
+        primary_offer: state.primary_offer,
+        redemption_stress_threshold_bps: state.redemption_stress_threshold_bps,
+        stressed_take_max_token_in: state.stressed_take_max_token_in,
+        screening_program: state.screening_program,
+        schema_version: state.schema_version,
+    };
+
+    msg!(
+        "State View - Boss: {}, Kill switch active: {}",
+        view.boss,
+        view.kill_switch_active
+    );
+
+    emit!(ViewStateEvent {
+        boss: view.boss,
+        kill_switch_active: view.kill_switch_active,
+    });
+
+    Ok(view)
+}
@@ -0,0 +1,153 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::WithdrawalAllowlist;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a destination is added to the vault withdrawal allowlist
+///
+/// Provides transparency for tracking where vault funds may be sent.
+#[event]
+pub struct WithdrawalDestinationAddedEvent {
+    /// The destination owner added to the allowlist
+    pub destination: Pubkey,
+    /// The boss who added the destination
+    pub boss: Pubkey,
+    /// The guardian that co-signed the addition
+    pub guardian: Pubkey,
+    /// Number of destinations in the allowlist after the addition
+    pub active_destination_count: u8,
+}
+
+/// Account structure for adding a destination to the vault withdrawal allowlist
+///
+/// The allowlist account is created on first use, paid for by the boss.
+#[derive(Accounts)]
+pub struct AddWithdrawalDestination<'info> {
+    /// Program state account containing boss and guardian authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema,
+        constraint = state.guardian != Pubkey::default()
+            @ AddWithdrawalDestinationErrorCode::GuardianNotSet
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The vault withdrawal destination allowlist
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The boss account authorized to manage the allowlist and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// The guardian co-signing the addition
+    #[account(
+        constraint = guardian.key() == state.guardian
+            @ AddWithdrawalDestinationErrorCode::InvalidGuardian
+    )]
+    pub guardian: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a destination owner to the vault withdrawal allowlist
+///
+/// Adding a destination widens where vault funds can go, so it always needs the
+/// guardian; a leaked boss key alone cannot list its own wallet, and the
+/// instruction fails until a guardian is appointed.
+/// The list supports up to MAX_WITHDRAWAL_DESTINATIONS entries.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `destination` - Owner of the token accounts withdrawals may be sent to
+///
+/// # Returns
+/// * `Ok(())` - If the destination is successfully added
+/// * `Err(AddWithdrawalDestinationErrorCode::InvalidDestination)` - If the destination is the default pubkey
+/// * `Err(AddWithdrawalDestinationErrorCode::DestinationAlreadyExists)` - If the destination is already listed
+/// * `Err(AddWithdrawalDestinationErrorCode::MaxDestinationsReached)` - If the allowlist is full
+/// * `Err(AddWithdrawalDestinationErrorCode::GuardianNotSet)` - If no guardian is set in program state
+/// * `Err(AddWithdrawalDestinationErrorCode::InvalidGuardian)` - If the co-signer is not the guardian
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Also requires the guardian, which must be set
+///
+/// # Effects
+/// - Creates the allowlist account if needed
+/// - Adds the destination to the first available slot
+/// - Marks the allowlist as populated, ending withdrawals to the unlisted boss
+///
+/// # Events
+/// * `WithdrawalDestinationAddedEvent` - Emitted with the destination and the resulting count
+pub fn add_withdrawal_destination(
+    ctx: Context<AddWithdrawalDestination>,
+    destination: Pubkey,
+) -> Result<()> {
+    require!(
+        destination != Pubkey::default(),
+        AddWithdrawalDestinationErrorCode::InvalidDestination
+    );
+
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+
+    require!(
+        !allowlist.destinations.contains(&destination),
+        AddWithdrawalDestinationErrorCode::DestinationAlreadyExists
+    );
+
+    let slot = allowlist
+        .destinations
+        .iter()
+        .position(|d| *d == Pubkey::default())
+        .ok_or(AddWithdrawalDestinationErrorCode::MaxDestinationsReached)?;
+    allowlist.destinations[slot] = destination;
+    allowlist.ever_populated = true;
+    let active_destination_count = allowlist.active_count();
+
+    msg!(
+        "Withdrawal destination added: {} by boss: {}, active destinations: {}",
+        destination,
+        ctx.accounts.boss.key(),
+        active_destination_count
+    );
+
+    emit!(WithdrawalDestinationAddedEvent {
+        destination,
+        boss: ctx.accounts.boss.key(),
+        guardian: ctx.accounts.guardian.key(),
+        active_destination_count,
+    });
+
+    Ok(())
+}
+
+/// Error codes for adding withdrawal destinations
+#[error_code]
+pub enum AddWithdrawalDestinationErrorCode {
+    /// The destination must not be the default pubkey
+    #[msg("Invalid destination")]
+    InvalidDestination,
+    /// The destination is already present in the allowlist
+    #[msg("Destination already exists in the withdrawal allowlist")]
+    DestinationAlreadyExists,
+    /// The allowlist has reached MAX_WITHDRAWAL_DESTINATIONS entries
+    #[msg("Maximum number of withdrawal destinations reached")]
+    MaxDestinationsReached,
+    /// Adding a destination requires a guardian to be set in program state
+    #[msg("A guardian must be set to add a withdrawal destination")]
+    GuardianNotSet,
+    /// The guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
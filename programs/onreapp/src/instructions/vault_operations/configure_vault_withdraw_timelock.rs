@@ -0,0 +1,162 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::VaultWithdrawTimelock;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a vault withdrawal timelock is configured
+///
+/// Provides transparency for tracking treasury withdrawal policy changes.
+#[event]
+pub struct VaultWithdrawTimelockConfiguredEvent {
+    /// The mint whose withdrawals are timelocked
+    pub mint: Pubkey,
+    /// The previous delay in seconds (0 = disabled)
+    pub old_delay_secs: u64,
+    /// The new delay in seconds (0 = disabled)
+    pub new_delay_secs: u64,
+    /// The previous immediate withdrawal threshold
+    pub old_immediate_threshold: u64,
+    /// The new immediate withdrawal threshold
+    pub new_immediate_threshold: u64,
+    /// The guardian that co-signed a loosening of the timelock, if any
+    pub guardian: Option<Pubkey>,
+}
+
+/// Account structure for configuring the vault withdrawal timelock of a mint
+///
+/// The timelock account is created on first use, paid for by the boss.
+#[derive(Accounts)]
+pub struct ConfigureVaultWithdrawTimelock<'info> {
+    /// Program state account containing boss and guardian authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The token mint whose withdrawals are timelocked
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The withdrawal timelock of the mint
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + VaultWithdrawTimelock::INIT_SPACE,
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_timelock: Box<Account<'info, VaultWithdrawTimelock>>,
+
+    /// The boss account authorized to configure the timelock and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// The guardian co-signing changes that loosen the timelock
+    ///
+    /// Required when the change shortens the delay or raises the immediate threshold
+    /// of an enabled timelock.
+    #[account(
+        constraint = guardian.key() == state.guardian
+            @ ConfigureVaultWithdrawTimelockErrorCode::InvalidGuardian
+    )]
+    pub guardian: Option<Signer<'info>>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Configures the vault withdrawal timelock for a mint
+///
+/// Immediate withdrawals of the mint are tallied per window of `delay_secs`, and
+/// anything above the immediate threshold must be proposed and wait out the delay,
+/// giving depositors time to notice large treasury movements. Tightening the
+/// timelock only needs the boss, while loosening it also needs the guardian; without
+/// a guardian an enabled timelock cannot be loosened at all. Pending proposals keep
+/// the effective time they were created with.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `delay_secs` - Delay between proposal and execution in seconds (0 = disabled)
+/// * `immediate_threshold` - Largest amount in base units withdrawable without a proposal per window
+///
+/// # Returns
+/// * `Ok(())` - If the timelock is successfully configured
+/// * `Err(ConfigureVaultWithdrawTimelockErrorCode::GuardianNotSet)` - If a loosening change is made without a guardian set
+/// * `Err(ConfigureVaultWithdrawTimelockErrorCode::GuardianRequired)` - If a loosening change lacks the guardian
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Loosening changes also require the guardian, which must be set
+///
+/// # Effects
+/// - Creates the timelock account if needed
+/// - Updates the delay and immediate threshold
+///
+/// # Events
+/// * `VaultWithdrawTimelockConfiguredEvent` - Emitted with old and new configuration
+pub fn configure_vault_withdraw_timelock(
+    ctx: Context<ConfigureVaultWithdrawTimelock>,
+    delay_secs: u64,
+    immediate_threshold: u64,
+) -> Result<()> {
+    let timelock = &mut ctx.accounts.vault_withdraw_timelock;
+    timelock.mint = ctx.accounts.token_mint.key();
+    timelock.bump = ctx.bumps.vault_withdraw_timelock;
+
+    let old_delay_secs = timelock.delay_secs;
+    let old_immediate_threshold = timelock.immediate_threshold;
+
+    let was_enabled = old_delay_secs != 0;
+    let loosens = was_enabled
+        && (delay_secs < old_delay_secs || immediate_threshold > old_immediate_threshold);
+    if loosens {
+        require!(
+            ctx.accounts.state.guardian != Pubkey::default(),
+            ConfigureVaultWithdrawTimelockErrorCode::GuardianNotSet
+        );
+        require!(
+            ctx.accounts.guardian.is_some(),
+            ConfigureVaultWithdrawTimelockErrorCode::GuardianRequired
+        );
+    }
+
+    timelock.delay_secs = delay_secs;
+    timelock.immediate_threshold = immediate_threshold;
+
+    msg!(
+        "Vault withdraw timelock configured: mint={}, delay={}s, threshold={} (previous: {}s, {})",
+        timelock.mint,
+        delay_secs,
+        immediate_threshold,
+        old_delay_secs,
+        old_immediate_threshold
+    );
+
+    emit!(VaultWithdrawTimelockConfiguredEvent {
+        mint: timelock.mint,
+        old_delay_secs,
+        new_delay_secs: delay_secs,
+        old_immediate_threshold,
+        new_immediate_threshold: immediate_threshold,
+        guardian: ctx.accounts.guardian.as_ref().map(|g| g.key()),
+    });
+
+    Ok(())
+}
+
+/// Error codes for vault withdrawal timelock configuration
+#[error_code]
+pub enum ConfigureVaultWithdrawTimelockErrorCode {
+    /// Loosening the timelock requires the guardian's signature
+    #[msg("Guardian signature required to loosen the vault withdraw timelock")]
+    GuardianRequired,
+    /// Loosening the timelock requires a guardian to be set in program state
+    #[msg("A guardian must be set to loosen the vault withdraw timelock")]
+    GuardianNotSet,
+    /// The guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
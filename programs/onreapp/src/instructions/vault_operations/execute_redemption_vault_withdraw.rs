@@ -0,0 +1,181 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::{
+    ExecuteVaultWithdrawErrorCode, RedemptionVaultWithdrawEvent, VaultAuthorityType,
+    VaultCoreError, VaultWithdrawProposal, WithdrawalAllowlist,
+};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Account structure for executing a proposed withdrawal from the redemption vault
+#[derive(Accounts)]
+pub struct ExecuteRedemptionVaultWithdraw<'info> {
+    /// Program-derived authority that controls redemption vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// The token mint for the withdrawal operation
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The pending withdrawal proposal, closed to the boss once executed
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::VAULT_WITHDRAW_PROPOSAL, token_mint.key().as_ref()],
+        bump = vault_withdraw_proposal.bump
+    )]
+    pub vault_withdraw_proposal: Box<Account<'info, VaultWithdrawProposal>>,
+
+    /// Owner of the token account receiving the withdrawn tokens
+    ///
+    /// Must be listed in the withdrawal allowlist, or be the boss while the list has never been populated.
+    /// CHECK: Validated against the withdrawal allowlist in the handler
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account receiving the withdrawn tokens
+    ///
+    /// Created automatically if it doesn't exist, paid for by the boss.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption vault's token account serving as the source of withdrawn tokens
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = redemption_vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The withdrawal destination allowlist
+    ///
+    /// Created empty on first use, which restricts withdrawals to the boss until a destination is added.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The boss account executing the withdrawal and receiving the proposal rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Program state account containing boss authorization and the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Token program interface for transfer operations
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes a proposed withdrawal from the redemption vault after its delay
+///
+/// Proposals are per mint, so a proposal made with `propose_vault_withdraw` can be
+/// paid out by the redemption vault instead of an offer vault. The proposed amount
+/// is transferred in full.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the withdrawal completes successfully
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the destination is not allowed
+/// * `Err(ExecuteVaultWithdrawErrorCode::TimelockNotElapsed)` - If the delay has not passed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - The destination must be allowed by the withdrawal allowlist
+///
+/// # Effects
+/// - Advances the vault event sequence in program state
+/// - Transfers the proposed amount from the redemption vault to the destination
+/// - Closes the proposal account, returning its rent to the boss
+///
+/// # Events
+/// * `RedemptionVaultWithdrawEvent` - Emitted with mint, amount and vault balances
+pub fn execute_redemption_vault_withdraw(
+    ctx: Context<ExecuteRedemptionVaultWithdraw>,
+) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+    require_with_context!(
+        allowlist.allows(&ctx.accounts.destination.key(), &ctx.accounts.boss.key()),
+        VaultCoreError::DestinationNotAllowed,
+        destination = ctx.accounts.destination.key()
+    );
+
+    let proposal = &ctx.accounts.vault_withdraw_proposal;
+    let now = Clock::get()?.unix_timestamp;
+    require_with_context!(
+        now >= proposal.effective_at,
+        ExecuteVaultWithdrawErrorCode::TimelockNotElapsed,
+        now = now,
+        effective_at = proposal.effective_at
+    );
+    let amount = proposal.amount;
+
+    let redemption_vault_authority_seeds = &[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.redemption_vault_authority],
+    ];
+    let signer_seeds = &[&redemption_vault_authority_seeds[..]];
+
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
+    transfer_tokens(
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.redemption_vault_authority.to_account_info(),
+        Some(signer_seeds),
+        amount,
+    )?;
+
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
+    emit!(RedemptionVaultWithdrawEvent {
+        mint: ctx.accounts.token_mint.key(),
+        amount,
+        boss: ctx.accounts.boss.key(),
+        destination: ctx.accounts.destination.key(),
+        authority_type: VaultAuthorityType::Redemption,
+        pre_balance,
+        post_balance,
+        sequence,
+    });
+
+    msg!(
+        "Timelocked redemption vault withdraw executed: {} tokens",
+        amount
+    );
+    Ok(())
+}
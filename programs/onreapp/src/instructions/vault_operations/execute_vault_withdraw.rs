@@ -0,0 +1,197 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::{
+    offer_vault_authority_seed, OfferVaultWithdrawEvent, VaultAuthorityType, VaultCoreError,
+    VaultWithdrawProposal, WithdrawalAllowlist,
+};
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Account structure for executing a proposed offer vault withdrawal
+#[derive(Accounts)]
+pub struct ExecuteVaultWithdraw<'info> {
+    /// The offer whose own vault pays out the proposal, `None` for the shared offer vault
+    ///
+    /// Proposals are per mint, so the boss picks the paying vault on execution.
+    pub offer: Option<AccountLoader<'info, Offer>>,
+
+    /// Program-derived authority that controls vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer_vault_authority_seed(offer.as_ref())?.as_slice()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The token mint for the withdrawal operation
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The pending withdrawal proposal, closed to the boss once executed
+    #[account(
+        mut,
+        close = boss,
+        seeds = [seeds::VAULT_WITHDRAW_PROPOSAL, token_mint.key().as_ref()],
+        bump = vault_withdraw_proposal.bump
+    )]
+    pub vault_withdraw_proposal: Box<Account<'info, VaultWithdrawProposal>>,
+
+    /// Owner of the token account receiving the withdrawn tokens
+    ///
+    /// Must be listed in the withdrawal allowlist, or be the boss while the list has never been populated.
+    /// CHECK: Validated against the withdrawal allowlist in the handler
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account receiving the withdrawn tokens
+    ///
+    /// Created automatically if it doesn't exist, paid for by the boss.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault's token account serving as the source of withdrawn tokens
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The withdrawal destination allowlist
+    ///
+    /// Created empty on first use, which restricts withdrawals to the boss until a destination is added.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The boss account executing the withdrawal and receiving the proposal rent
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Program state account containing boss authorization and the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Token program interface for transfer operations
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes a proposed offer vault withdrawal after its delay
+///
+/// This instruction is the second step of a timelocked withdrawal, started with
+/// `propose_vault_withdraw`. The proposed amount is transferred in full.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+///
+/// # Returns
+/// * `Ok(())` - If the withdrawal completes successfully
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the destination is not allowed
+/// * `Err(ExecuteVaultWithdrawErrorCode::TimelockNotElapsed)` - If the delay has not passed
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - The destination must be allowed by the withdrawal allowlist
+///
+/// # Effects
+/// - Advances the vault event sequence in program state
+/// - Transfers the proposed amount from the vault to the destination
+/// - Closes the proposal account, returning its rent to the boss
+///
+/// # Events
+/// * `OfferVaultWithdrawEvent` - Emitted with mint, amount and vault balances
+pub fn execute_vault_withdraw(ctx: Context<ExecuteVaultWithdraw>) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+    require_with_context!(
+        allowlist.allows(&ctx.accounts.destination.key(), &ctx.accounts.boss.key()),
+        VaultCoreError::DestinationNotAllowed,
+        destination = ctx.accounts.destination.key()
+    );
+
+    let proposal = &ctx.accounts.vault_withdraw_proposal;
+    let now = Clock::get()?.unix_timestamp;
+    require_with_context!(
+        now >= proposal.effective_at,
+        ExecuteVaultWithdrawErrorCode::TimelockNotElapsed,
+        now = now,
+        effective_at = proposal.effective_at
+    );
+    let amount = proposal.amount;
+
+    let vault_authority_seed = offer_vault_authority_seed(ctx.accounts.offer.as_ref())?;
+    let vault_authority_seeds = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.vault_authority],
+    ];
+    let signer_seeds = &[&vault_authority_seeds[..]];
+
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
+    transfer_tokens(
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.vault_authority.to_account_info(),
+        Some(signer_seeds),
+        amount,
+    )?;
+
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
+    emit!(OfferVaultWithdrawEvent {
+        mint: ctx.accounts.token_mint.key(),
+        amount,
+        boss: ctx.accounts.boss.key(),
+        destination: ctx.accounts.destination.key(),
+        authority_type: VaultAuthorityType::Offer,
+        pre_balance,
+        post_balance,
+        sequence,
+    });
+
+    msg!("Timelocked vault withdraw executed: {} tokens", amount);
+    Ok(())
+}
+
+/// Error codes for executing timelocked vault withdrawals
+#[error_code]
+pub enum ExecuteVaultWithdrawErrorCode {
+    /// The proposal's delay has not elapsed yet
+    #[msg("Vault withdraw proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+}
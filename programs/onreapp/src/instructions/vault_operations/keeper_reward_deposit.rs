@@ -0,0 +1,116 @@
+use crate::constants::seeds;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when ONyc is deposited to the keeper reward vault
+///
+/// Provides transparency for tracking keeper reward funding.
+#[event]
+pub struct KeeperRewardVaultDepositEvent {
+    /// Amount of ONyc deposited to the vault
+    pub amount: u64,
+    /// The boss account that made the deposit
+    pub boss: Pubkey,
+}
+
+/// Account structure for depositing ONyc to the keeper reward vault
+#[derive(Accounts)]
+pub struct KeeperRewardVaultDeposit<'info> {
+    /// Program-derived authority that controls the keeper reward vault
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::KEEPER_REWARD_VAULT_AUTHORITY], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The ONyc mint keeper rewards are paid in
+    #[account(address = state.onyc_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Boss's token account serving as the source of deposited tokens
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = boss,
+        associated_token::token_program = token_program
+    )]
+    pub boss_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Keeper reward vault serving as the destination for deposited tokens
+    ///
+    /// Created automatically if it doesn't exist.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The boss account authorized to deposit tokens and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// Token program interface for transfer operations
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits ONyc into the keeper reward vault that funds `claim_keeper_rewards`
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - Amount of ONyc to deposit into the vault
+///
+/// # Returns
+/// * `Ok(())` - If the deposit completes successfully
+/// * `Err(_)` - If transfer fails or insufficient balance
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Transfers ONyc from boss account to the keeper reward vault
+/// - Creates the vault token account if it doesn't exist
+///
+/// # Events
+/// * `KeeperRewardVaultDepositEvent` - Emitted with amount and depositor
+pub fn keeper_reward_vault_deposit(
+    ctx: Context<KeeperRewardVaultDeposit>,
+    amount: u64,
+) -> Result<()> {
+    transfer_tokens(
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.boss_token_account,
+        &ctx.accounts.vault_token_account,
+        &ctx.accounts.boss,
+        None,
+        amount,
+    )?;
+
+    emit!(KeeperRewardVaultDepositEvent {
+        amount,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    msg!("Keeper reward vault deposit successful: {} tokens", amount);
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use crate::constants::seeds;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::transfer_tokens;
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Event emitted when an offer moves from the shared offer vault to its own vault
+///
+/// Provides transparency for tracking inventory separated from the shared vault.
+#[event]
+pub struct OfferVaultMigratedEvent {
+    /// The PDA address of the migrated offer
+    pub offer_pda: Pubkey,
+    /// Generation of the offer at its PDA
+    pub offer_version: u32,
+    /// The offer's own vault authority, serving the offer from now on
+    pub vault_authority: Pubkey,
+    /// Amount of token_in moved from the shared vault to the offer's vault
+    pub token_in_amount: u64,
+    /// Amount of token_out moved from the shared vault to the offer's vault
+    pub token_out_amount: u64,
+    /// The boss account that migrated the offer
+    pub boss: Pubkey,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
+}
+
+/// Account structure for moving an offer onto its own vault
+///
+/// Holds the shared offer vault accounts the inventory is taken from and the offer's
+/// own vault accounts, created if needed, the inventory is moved to.
+#[derive(Accounts)]
+pub struct MigrateOfferVault<'info> {
+    /// The offer to serve from its own vault
+    #[account(
+        mut,
+        seeds = [
+            seeds::OFFER,
+            token_in_mint.key().as_ref(),
+            token_out_mint.key().as_ref()
+        ],
+        bump = offer.load()?.bump
+    )]
+    pub offer: AccountLoader<'info, Offer>,
+
+    /// The input token mint of the offer
+    #[account(
+        constraint =
+            token_in_mint.key() == offer.load()?.token_in_mint
+            @ OfferCoreError::InvalidTokenInMint
+    )]
+    pub token_in_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The output token mint of the offer
+    #[account(
+        constraint =
+            token_out_mint.key() == offer.load()?.token_out_mint
+            @ OfferCoreError::InvalidTokenOutMint
+    )]
+    pub token_out_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program interface for the input token
+    #[account(address = *token_in_mint.to_account_info().owner)]
+    pub token_in_program: Interface<'info, TokenInterface>,
+
+    /// Token program interface for the output token
+    #[account(address = *token_out_mint.to_account_info().owner)]
+    pub token_out_program: Interface<'info, TokenInterface>,
+
+    /// Authority of the shared offer vault the inventory is moved out of
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    pub shared_vault_authority: UncheckedAccount<'info>,
+
+    /// The shared offer vault's token_in account
+    #[account(
+        mut,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = shared_vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub shared_vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The shared offer vault's token_out account
+    #[account(
+        mut,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = shared_vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub shared_vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Authority of the offer's own vault, derived from the offer
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY, offer.key().as_ref()], bump)]
+    pub offer_vault_authority: UncheckedAccount<'info>,
+
+    /// The offer's own token_in vault, created if needed
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_in_mint,
+        associated_token::authority = offer_vault_authority,
+        associated_token::token_program = token_in_program
+    )]
+    pub offer_vault_token_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The offer's own token_out vault, created if needed
+    #[account(
+        init_if_needed,
+        payer = boss,
+        associated_token::mint = token_out_mint,
+        associated_token::authority = offer_vault_authority,
+        associated_token::token_program = token_out_program
+    )]
+    pub offer_vault_token_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program state account containing boss authorization and the vault event sequence
+    #[account(
+        mut,
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account authorized to migrate offers and pay for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// Associated Token Program for automatic token account creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves an offer from the shared offer vault onto a vault of its own
+///
+/// The shared vault serves every offer that has no vault of its own, so a pricing
+/// bug in one pair can pay out inventory meant for another. After migration the
+/// offer's takes, deposits, withdrawals, proceeds collection and close sweeps all
+/// use the authority derived from `[OFFER_VAULT_AUTHORITY, offer]`. The boss moves
+/// the offer's share of the shared inventory along; amounts stay in the program's
+/// custody, so no withdrawal timelock or allowlist applies. Migration is one-way.
+/// New offers are created on their own vault, so only offers made before per-offer
+/// vaults need migrating.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `token_in_amount` - Amount of token_in to move to the offer's vault
+/// * `token_out_amount` - Amount of token_out to move to the offer's vault
+///
+/// # Returns
+/// * `Ok(())` - If the offer is successfully migrated
+/// * `Err(MigrateOfferVaultErrorCode::AlreadyMigrated)` - If the offer already has its own vault
+/// * `Err(_)` - If a transfer fails or the shared vault balance is insufficient
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Boss account must match the one stored in program state
+///
+/// # Effects
+/// - Creates the offer's own token_in and token_out vault accounts if needed
+/// - Transfers the given amounts from the shared vault to the offer's vault
+/// - Advances the vault event sequence in program state
+/// - Marks the offer as served by its own vault
+///
+/// # Events
+/// * `OfferVaultMigratedEvent` - Emitted with the new vault authority and moved amounts
+pub fn migrate_offer_vault(
+    ctx: Context<MigrateOfferVault>,
+    token_in_amount: u64,
+    token_out_amount: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.offer.load()?.has_own_vault(),
+        MigrateOfferVaultErrorCode::AlreadyMigrated
+    );
+
+    let shared_vault_authority_seeds = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.shared_vault_authority],
+    ];
+    let signer_seeds = &[&shared_vault_authority_seeds[..]];
+
+    if token_in_amount > 0 {
+        transfer_tokens(
+            &ctx.accounts.token_in_mint,
+            &ctx.accounts.token_in_program,
+            &ctx.accounts.shared_vault_token_in_account,
+            &ctx.accounts.offer_vault_token_in_account,
+            &ctx.accounts.shared_vault_authority.to_account_info(),
+            Some(signer_seeds),
+            token_in_amount,
+        )?;
+    }
+    if token_out_amount > 0 {
+        transfer_tokens(
+            &ctx.accounts.token_out_mint,
+            &ctx.accounts.token_out_program,
+            &ctx.accounts.shared_vault_token_out_account,
+            &ctx.accounts.offer_vault_token_out_account,
+            &ctx.accounts.shared_vault_authority.to_account_info(),
+            Some(signer_seeds),
+            token_out_amount,
+        )?;
+    }
+
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+    let mut offer = ctx.accounts.offer.load_mut()?;
+    offer.set_own_vault(true);
+
+    msg!(
+        "Offer vault migrated: {}, vault authority: {}, token_in: {}, token_out: {}",
+        ctx.accounts.offer.key(),
+        ctx.accounts.offer_vault_authority.key(),
+        token_in_amount,
+        token_out_amount
+    );
+
+    emit!(OfferVaultMigratedEvent {
+        offer_pda: ctx.accounts.offer.key(),
+        offer_version: offer.offer_version,
+        vault_authority: ctx.accounts.offer_vault_authority.key(),
+        token_in_amount,
+        token_out_amount,
+        boss: ctx.accounts.boss.key(),
+        sequence,
+    });
+
+    Ok(())
+}
+
+/// Error codes for offer vault migration operations
+#[error_code]
+pub enum MigrateOfferVaultErrorCode {
+    /// The offer is already served by its own vault
+    #[msg("Offer already has its own vault")]
+    AlreadyMigrated,
+}
@@ -1,9 +1,31 @@
+pub mod add_withdrawal_destination;
+pub mod configure_vault_withdraw_timelock;
+pub mod execute_redemption_vault_withdraw;
+pub mod execute_vault_withdraw;
+pub mod keeper_reward_deposit;
+pub mod migrate_offer_vault;
 pub mod offer_deposit;
 pub mod offer_withdraw;
+pub mod propose_vault_withdraw;
 pub mod redemption_deposit;
 pub mod redemption_withdraw;
+pub mod remove_withdrawal_destination;
+pub mod sweep_vault_dust;
+pub mod vault_utils;
+pub mod vault_withdraw_state;
 
+pub use add_withdrawal_destination::*;
+pub use configure_vault_withdraw_timelock::*;
+pub use execute_redemption_vault_withdraw::*;
+pub use execute_vault_withdraw::*;
+pub use keeper_reward_deposit::*;
+pub use migrate_offer_vault::*;
 pub use offer_deposit::*;
 pub use offer_withdraw::*;
+pub use propose_vault_withdraw::*;
 pub use redemption_deposit::*;
 pub use redemption_withdraw::*;
+pub use remove_withdrawal_destination::*;
+pub use sweep_vault_dust::*;
+pub use vault_utils::*;
+pub use vault_withdraw_state::*;
@@ -1,5 +1,7 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::instructions::vault_operations::{offer_vault_authority_seed, VaultAuthorityType};
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -16,6 +18,14 @@ pub struct OfferVaultDepositEvent {
     pub amount: u64,
     /// The boss account that made the deposit
     pub boss: Pubkey,
+    /// The vault authority controlling the vault
+    pub authority_type: VaultAuthorityType,
+    /// Vault balance before the change
+    pub pre_balance: u64,
+    /// Vault balance after the change
+    pub post_balance: u64,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
 }
 
 /// Account structure for depositing tokens to the offer vault
@@ -25,12 +35,24 @@ pub struct OfferVaultDepositEvent {
 /// lacks mint authority and must transfer from pre-funded reserves.
 #[derive(Accounts)]
 pub struct OfferVaultDeposit<'info> {
+    /// The offer whose vault is funded, `None` for the shared offer vault
+    ///
+    /// Selects the vault the offer's takes draw from: its own vault if it has one,
+    /// otherwise the shared one.
+    pub offer: Option<AccountLoader<'info, Offer>>,
+
     /// Program-derived authority that controls vault token accounts
     ///
     /// This PDA manages the vault token accounts and enables the program
     /// to distribute tokens during offer executions.
     /// CHECK: PDA derivation is validated by seeds constraint
-    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer_vault_authority_seed(offer.as_ref())?.as_slice()
+        ],
+        bump
+    )]
     pub vault_authority: UncheckedAccount<'info>,
 
     /// The token mint for the deposit operation
@@ -64,11 +86,13 @@ pub struct OfferVaultDeposit<'info> {
     #[account(mut)]
     pub boss: Signer<'info>,
 
-    /// Program state account containing boss authorization
+    /// Program state account containing boss authorization and the vault event sequence
     #[account(
+        mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
@@ -102,6 +126,7 @@ pub struct OfferVaultDeposit<'info> {
 /// - Boss account must match the one stored in program state
 ///
 /// # Effects
+/// - Advances the vault event sequence in program state
 /// - Transfers tokens from boss account to vault account
 /// - Creates vault token account if it doesn't exist
 /// - Increases available tokens for offer distributions
@@ -109,6 +134,8 @@ pub struct OfferVaultDeposit<'info> {
 /// # Events
 /// * `OfferVaultDepositEvent` - Emitted with mint, amount, and depositor details
 pub fn offer_vault_deposit(ctx: Context<OfferVaultDeposit>, amount: u64) -> Result<()> {
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
     // Transfer tokens from boss to vault
     transfer_tokens(
         &ctx.accounts.token_mint,
@@ -120,10 +147,18 @@ pub fn offer_vault_deposit(ctx: Context<OfferVaultDeposit>, amount: u64) -> Resu
         amount,
     )?;
 
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
     emit!(OfferVaultDepositEvent {
         mint: ctx.accounts.token_mint.key(),
         amount,
         boss: ctx.accounts.boss.key(),
+        authority_type: VaultAuthorityType::Offer,
+        pre_balance,
+        post_balance,
+        sequence,
     });
 
     msg!("Offer vault deposit successful: {} tokens", amount);
@@ -1,5 +1,11 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::instructions::vault_operations::{
+    offer_vault_authority_seed, VaultAuthorityType, VaultCoreError, VaultWithdrawTimelock,
+    WithdrawalAllowlist,
+};
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -16,6 +22,16 @@ pub struct OfferVaultWithdrawEvent {
     pub amount: u64,
     /// The boss account that performed the withdrawal
     pub boss: Pubkey,
+    /// Owner of the token account that received the withdrawn tokens
+    pub destination: Pubkey,
+    /// The vault authority controlling the vault
+    pub authority_type: VaultAuthorityType,
+    /// Vault balance before the change
+    pub pre_balance: u64,
+    /// Vault balance after the change
+    pub post_balance: u64,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
 }
 
 /// Account structure for withdrawing tokens from the offer vault
@@ -25,29 +41,45 @@ pub struct OfferVaultWithdrawEvent {
 /// vault reserves when needed.
 #[derive(Accounts)]
 pub struct OfferVaultWithdraw<'info> {
+    /// The offer whose vault is withdrawn from, `None` for the shared offer vault
+    ///
+    /// Offers without their own vault select the shared vault as well.
+    pub offer: Option<AccountLoader<'info, Offer>>,
+
     /// Program-derived authority that controls vault token accounts
     ///
     /// This PDA manages the vault token accounts and signs the withdrawal
     /// transfer using program-derived signatures.
     /// CHECK: PDA derivation is validated by seeds constraint
-    #[account(seeds = [seeds::OFFER_VAULT_AUTHORITY], bump)]
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer_vault_authority_seed(offer.as_ref())?.as_slice()
+        ],
+        bump
+    )]
     pub vault_authority: UncheckedAccount<'info>,
 
     /// The token mint for the withdrawal operation
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Boss's token account serving as the destination for withdrawn tokens
+    /// Owner of the token account receiving the withdrawn tokens
     ///
-    /// Created automatically if it doesn't exist. Receives tokens withdrawn
-    /// from the vault for boss fund management.
+    /// Must be listed in the withdrawal allowlist, or be the boss while the list has never been populated.
+    /// CHECK: Validated against the withdrawal allowlist in the handler
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account receiving the withdrawn tokens
+    ///
+    /// Created automatically if it doesn't exist, paid for by the boss.
     #[account(
         init_if_needed,
         payer = boss,
         associated_token::mint = token_mint,
-        associated_token::authority = boss,
+        associated_token::authority = destination,
         associated_token::token_program = token_program
     )]
-    pub boss_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Vault's token account serving as the source of withdrawn tokens
     ///
@@ -61,15 +93,42 @@ pub struct OfferVaultWithdraw<'info> {
     )]
     pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// The withdrawal timelock of the mint
+    ///
+    /// Created disabled on first use, so withdrawals stay immediate until the boss
+    /// configures a delay.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + VaultWithdrawTimelock::INIT_SPACE,
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_timelock: Box<Account<'info, VaultWithdrawTimelock>>,
+
+    /// The withdrawal destination allowlist
+    ///
+    /// Created empty on first use, which restricts withdrawals to the boss until a destination is added.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
     /// The boss account authorized to withdraw tokens and pay for account creation
     #[account(mut)]
     pub boss: Signer<'info>,
 
-    /// Program state account containing boss authorization
+    /// Program state account containing boss authorization and the vault event sequence
     #[account(
+        mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
@@ -96,41 +155,97 @@ pub struct OfferVaultWithdraw<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the withdrawal completes successfully
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the destination is not allowed
+/// * `Err(OfferVaultWithdrawErrorCode::TimelockRequired)` - If the amount would exceed the mint's immediate threshold for the current window
 /// * `Err(_)` - If transfer fails or insufficient vault balance
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
+/// - The destination must be allowed by the withdrawal allowlist
 /// - Boss account must match the one stored in program state
+/// - Amounts above the immediate threshold of an enabled timelock must go through
+///   `propose_vault_withdraw` and `execute_vault_withdraw`, counting earlier immediate
+///   withdrawals of the mint within the current window
 ///
 /// # Effects
-/// - Transfers tokens from vault account to boss account
-/// - Creates boss token account if it doesn't exist
+/// - Advances the vault event sequence in program state
+/// - Transfers tokens from vault account to destination account
+/// - Creates destination token account, withdrawal timelock and allowlist if they don't exist
+/// - Adds the amount to the timelock's immediate withdrawal window tally
 /// - Reduces available tokens in vault reserves
 ///
 /// # Events
 /// * `OfferVaultWithdrawEvent` - Emitted with mint, amount, and withdrawer details
 pub fn offer_vault_withdraw(ctx: Context<OfferVaultWithdraw>, amount: u64) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+    require_with_context!(
+        allowlist.allows(&ctx.accounts.destination.key(), &ctx.accounts.boss.key()),
+        VaultCoreError::DestinationNotAllowed,
+        destination = ctx.accounts.destination.key()
+    );
+
+    let timelock = &mut ctx.accounts.vault_withdraw_timelock;
+    if timelock.mint == Pubkey::default() {
+        timelock.mint = ctx.accounts.token_mint.key();
+        timelock.bump = ctx.bumps.vault_withdraw_timelock;
+    }
+    let now = Clock::get()?.unix_timestamp;
+    require_with_context!(
+        !timelock.requires_proposal(amount, now),
+        OfferVaultWithdrawErrorCode::TimelockRequired,
+        amount = amount,
+        withdrawn_in_window = timelock.withdrawn_in_window,
+        immediate_threshold = timelock.immediate_threshold,
+        delay_secs = timelock.delay_secs
+    );
+    timelock.record_immediate_withdrawal(amount, now);
+
     // Create signer seeds for vault authority
-    let vault_authority_seeds = &[seeds::OFFER_VAULT_AUTHORITY, &[ctx.bumps.vault_authority]];
+    let vault_authority_seed = offer_vault_authority_seed(ctx.accounts.offer.as_ref())?;
+    let vault_authority_seeds = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.vault_authority],
+    ];
     let signer_seeds = &[&vault_authority_seeds[..]];
 
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
     // Transfer tokens from vault to boss
     transfer_tokens(
         &ctx.accounts.token_mint,
         &ctx.accounts.token_program,
         &ctx.accounts.vault_token_account,
-        &ctx.accounts.boss_token_account,
+        &ctx.accounts.destination_token_account,
         &ctx.accounts.vault_authority.to_account_info(),
         Some(signer_seeds),
         amount,
     )?;
 
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
     emit!(OfferVaultWithdrawEvent {
         mint: ctx.accounts.token_mint.key(),
         amount,
         boss: ctx.accounts.boss.key(),
+        destination: ctx.accounts.destination.key(),
+        authority_type: VaultAuthorityType::Offer,
+        pre_balance,
+        post_balance,
+        sequence,
     });
 
     msg!("Offer vault withdraw successful: {} tokens", amount);
     Ok(())
 }
+
+/// Error codes for offer vault withdrawals
+#[error_code]
+pub enum OfferVaultWithdrawErrorCode {
+    /// The amount exceeds the immediate threshold of the mint's withdrawal timelock
+    #[msg("Withdrawal exceeds the immediate threshold and must be proposed")]
+    TimelockRequired,
+}
@@ -0,0 +1,125 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::{VaultWithdrawProposal, VaultWithdrawTimelock};
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+/// Event emitted when a vault withdrawal is proposed
+///
+/// Gives depositors on-chain notice of a treasury movement before it happens.
+#[event]
+pub struct VaultWithdrawProposedEvent {
+    /// The mint to withdraw from a vault
+    pub mint: Pubkey,
+    /// Amount in base units to withdraw
+    pub amount: u64,
+    /// Unix timestamp from which the withdrawal can be executed
+    pub effective_at: i64,
+    /// The boss account that proposed the withdrawal
+    pub boss: Pubkey,
+}
+
+/// Account structure for proposing a timelocked vault withdrawal
+///
+/// The proposal account is created on first use, paid for by the boss, and replaced
+/// by later proposals for the same mint.
+#[derive(Accounts)]
+pub struct ProposeVaultWithdraw<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The token mint to withdraw
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The withdrawal timelock of the mint providing the delay
+    #[account(
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_mint.key().as_ref()],
+        bump = vault_withdraw_timelock.bump
+    )]
+    pub vault_withdraw_timelock: Box<Account<'info, VaultWithdrawTimelock>>,
+
+    /// The pending withdrawal proposal of the mint
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + VaultWithdrawProposal::INIT_SPACE,
+        seeds = [seeds::VAULT_WITHDRAW_PROPOSAL, token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_proposal: Box<Account<'info, VaultWithdrawProposal>>,
+
+    /// The boss account proposing the withdrawal and paying for account creation
+    #[account(mut)]
+    pub boss: Signer<'info>,
+
+    /// System program for account creation and rent payment
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a vault withdrawal that exceeds the immediate threshold
+///
+/// This instruction is the first step of a timelocked withdrawal. The withdrawal can
+/// be executed with `execute_vault_withdraw` from an offer vault, or with
+/// `execute_redemption_vault_withdraw` from the redemption vault, once the mint's
+/// delay has passed.
+/// Proposing again replaces the pending proposal and restarts the delay.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `amount` - Amount of tokens to withdraw from the vault
+///
+/// # Returns
+/// * `Ok(())` - If the proposal is recorded successfully
+/// * `Err(ProposeVaultWithdrawErrorCode::InvalidAmount)` - If the amount is zero
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+///
+/// # Effects
+/// - Creates or replaces the withdrawal proposal account of the mint
+///
+/// # Events
+/// * `VaultWithdrawProposedEvent` - Emitted with the amount and its effective time
+pub fn propose_vault_withdraw(ctx: Context<ProposeVaultWithdraw>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProposeVaultWithdrawErrorCode::InvalidAmount);
+
+    let effective_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(ctx.accounts.vault_withdraw_timelock.delay_secs as i64);
+
+    let proposal = &mut ctx.accounts.vault_withdraw_proposal;
+    proposal.mint = ctx.accounts.token_mint.key();
+    proposal.amount = amount;
+    proposal.effective_at = effective_at;
+    proposal.bump = ctx.bumps.vault_withdraw_proposal;
+
+    msg!(
+        "Vault withdraw proposed: mint={}, amount={}, effective at {}",
+        proposal.mint,
+        amount,
+        effective_at
+    );
+
+    emit!(VaultWithdrawProposedEvent {
+        mint: proposal.mint,
+        amount,
+        effective_at,
+        boss: ctx.accounts.boss.key(),
+    });
+
+    Ok(())
+}
+
+/// Error codes for vault withdrawal proposals
+#[error_code]
+pub enum ProposeVaultWithdrawErrorCode {
+    /// The proposed amount must be greater than zero
+    #[msg("Invalid amount: must be greater than zero")]
+    InvalidAmount,
+}
@@ -1,5 +1,6 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::instructions::vault_operations::VaultAuthorityType;
+use crate::state::{State, StateSchemaErrorCode};
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -16,6 +17,14 @@ pub struct RedemptionVaultDepositEvent {
     pub amount: u64,
     /// The boss account that made the deposit
     pub boss: Pubkey,
+    /// The vault authority controlling the vault
+    pub authority_type: VaultAuthorityType,
+    /// Vault balance before the change
+    pub pre_balance: u64,
+    /// Vault balance after the change
+    pub post_balance: u64,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
 }
 
 /// Account structure for depositing tokens to the redemption vault
@@ -64,11 +73,13 @@ pub struct RedemptionVaultDeposit<'info> {
     #[account(mut)]
     pub boss: Signer<'info>,
 
-    /// Program state account containing boss authorization
+    /// Program state account containing boss authorization and the vault event sequence
     #[account(
+        mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
@@ -102,6 +113,7 @@ pub struct RedemptionVaultDeposit<'info> {
 /// - Boss account must match the one stored in program state
 ///
 /// # Effects
+/// - Advances the vault event sequence in program state
 /// - Transfers tokens from boss account to redemption vault account
 /// - Creates redemption vault token account if it doesn't exist
 /// - Increases available tokens for redemption distributions
@@ -109,6 +121,8 @@ pub struct RedemptionVaultDeposit<'info> {
 /// # Events
 /// * `RedemptionVaultDepositEvent` - Emitted with mint, amount, and depositor details
 pub fn redemption_vault_deposit(ctx: Context<RedemptionVaultDeposit>, amount: u64) -> Result<()> {
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
     // Transfer tokens from boss to redemption vault
     transfer_tokens(
         &ctx.accounts.token_mint,
@@ -120,10 +134,18 @@ pub fn redemption_vault_deposit(ctx: Context<RedemptionVaultDeposit>, amount: u6
         amount,
     )?;
 
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
     emit!(RedemptionVaultDepositEvent {
         mint: ctx.accounts.token_mint.key(),
         amount,
         boss: ctx.accounts.boss.key(),
+        authority_type: VaultAuthorityType::Redemption,
+        pre_balance,
+        post_balance,
+        sequence,
     });
 
     msg!("Redemption vault deposit successful: {} tokens", amount);
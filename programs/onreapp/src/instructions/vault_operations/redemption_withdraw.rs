@@ -1,5 +1,9 @@
 use crate::constants::seeds;
-use crate::state::State;
+use crate::instructions::vault_operations::{
+    record_immediate_withdrawal, VaultAuthorityType, VaultCoreError, WithdrawalAllowlist,
+};
+use crate::state::{State, StateSchemaErrorCode};
+use crate::utils::error_context::require_with_context;
 use crate::utils::transfer_tokens;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
@@ -16,6 +20,16 @@ pub struct RedemptionVaultWithdrawEvent {
     pub amount: u64,
     /// The boss account that performed the withdrawal
     pub boss: Pubkey,
+    /// Owner of the token account that received the withdrawn tokens
+    pub destination: Pubkey,
+    /// The vault authority controlling the vault
+    pub authority_type: VaultAuthorityType,
+    /// Vault balance before the change
+    pub pre_balance: u64,
+    /// Vault balance after the change
+    pub post_balance: u64,
+    /// Sequence number of the event across all vault balance changes
+    pub sequence: u64,
 }
 
 /// Account structure for withdrawing tokens from the redemption vault
@@ -36,19 +50,23 @@ pub struct RedemptionVaultWithdraw<'info> {
     /// The token mint for the withdrawal operation
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Boss's token account serving as the destination for withdrawn tokens
+    /// Owner of the token account receiving the withdrawn tokens
     ///
-    /// Created automatically if it doesn't exist. Receives tokens withdrawn
-    /// from the redemption vault for boss fund management.
-    /// Note: init_if_needed implies mutability.
+    /// Must be listed in the withdrawal allowlist, or be the boss while the list has never been populated.
+    /// CHECK: Validated against the withdrawal allowlist in the handler
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account receiving the withdrawn tokens
+    ///
+    /// Created automatically if it doesn't exist, paid for by the boss.
     #[account(
         init_if_needed,
         payer = boss,
         associated_token::mint = token_mint,
-        associated_token::authority = boss,
+        associated_token::authority = destination,
         associated_token::token_program = token_program
     )]
-    pub boss_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Redemption vault's token account serving as the source of withdrawn tokens
     ///
@@ -62,15 +80,40 @@ pub struct RedemptionVaultWithdraw<'info> {
     )]
     pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// The withdrawal destination allowlist
+    ///
+    /// Created empty on first use, which restricts withdrawals to the boss until a destination is added.
+    #[account(
+        init_if_needed,
+        payer = boss,
+        space = 8 + WithdrawalAllowlist::INIT_SPACE,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The withdrawal timelock of the mint
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint; deserialized in the
+    /// handler only if it has been created
+    #[account(
+        mut,
+        seeds = [seeds::VAULT_WITHDRAW_TIMELOCK, token_mint.key().as_ref()],
+        bump
+    )]
+    pub vault_withdraw_timelock: UncheckedAccount<'info>,
+
     /// The boss account authorized to withdraw tokens and pay for account creation
     #[account(mut)]
     pub boss: Signer<'info>,
 
-    /// Program state account containing boss authorization
+    /// Program state account containing boss authorization and the vault event sequence
     #[account(
+        mut,
         seeds = [seeds::STATE],
         bump = state.bump,
-        has_one = boss
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
     )]
     pub state: Box<Account<'info, State>>,
 
@@ -97,20 +140,38 @@ pub struct RedemptionVaultWithdraw<'info> {
 ///
 /// # Returns
 /// * `Ok(())` - If the withdrawal completes successfully
+/// * `Err(VaultCoreError::DestinationNotAllowed)` - If the destination is not allowed
+/// * `Err(VaultCoreError::TimelockRequired)` - If the amount exceeds the mint's immediate threshold
 /// * `Err(_)` - If transfer fails or insufficient vault balance
 ///
 /// # Access Control
 /// - Only the boss can call this instruction
+/// - The destination must be allowed by the withdrawal allowlist
 /// - Boss account must match the one stored in program state
+/// - Amounts above the immediate threshold of an enabled timelock must go through
+///   `propose_vault_withdraw` and `execute_redemption_vault_withdraw`
 ///
 /// # Effects
-/// - Transfers tokens from redemption vault account to boss account
-/// - Creates boss token account if it doesn't exist
+/// - Advances the vault event sequence in program state
+/// - Transfers tokens from redemption vault account to destination account
+/// - Creates destination token account and allowlist if they don't exist
 /// - Reduces available tokens in redemption vault reserves
 ///
 /// # Events
 /// * `RedemptionVaultWithdrawEvent` - Emitted with mint, amount, and withdrawer details
 pub fn redemption_vault_withdraw(ctx: Context<RedemptionVaultWithdraw>, amount: u64) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+    allowlist.bump = ctx.bumps.withdrawal_allowlist;
+    require_with_context!(
+        allowlist.allows(&ctx.accounts.destination.key(), &ctx.accounts.boss.key()),
+        VaultCoreError::DestinationNotAllowed,
+        destination = ctx.accounts.destination.key()
+    );
+    require!(
+        record_immediate_withdrawal(&ctx.accounts.vault_withdraw_timelock, amount)?,
+        VaultCoreError::TimelockRequired
+    );
+
     // Create signer seeds for redemption vault authority
     let redemption_vault_authority_seeds = &[
         seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
@@ -118,21 +179,32 @@ pub fn redemption_vault_withdraw(ctx: Context<RedemptionVaultWithdraw>, amount:
     ];
     let signer_seeds = &[&redemption_vault_authority_seeds[..]];
 
+    let pre_balance = ctx.accounts.vault_token_account.amount;
+
     // Transfer tokens from redemption vault to boss
     transfer_tokens(
         &ctx.accounts.token_mint,
         &ctx.accounts.token_program,
         &ctx.accounts.vault_token_account,
-        &ctx.accounts.boss_token_account,
+        &ctx.accounts.destination_token_account,
         &ctx.accounts.redemption_vault_authority.to_account_info(),
         Some(signer_seeds),
         amount,
     )?;
 
+    ctx.accounts.vault_token_account.reload()?;
+    let post_balance = ctx.accounts.vault_token_account.amount;
+    let sequence = ctx.accounts.state.next_vault_event_sequence();
+
     emit!(RedemptionVaultWithdrawEvent {
         mint: ctx.accounts.token_mint.key(),
         amount,
         boss: ctx.accounts.boss.key(),
+        destination: ctx.accounts.destination.key(),
+        authority_type: VaultAuthorityType::Redemption,
+        pre_balance,
+        post_balance,
+        sequence,
     });
 
     msg!("Redemption vault withdraw successful: {} tokens", amount);
@@ -0,0 +1,122 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::WithdrawalAllowlist;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+
+/// Event emitted when a destination is removed from the vault withdrawal allowlist
+///
+/// Provides transparency for tracking where vault funds may be sent.
+#[event]
+pub struct WithdrawalDestinationRemovedEvent {
+    /// The destination owner removed from the allowlist
+    pub destination: Pubkey,
+    /// The boss who removed the destination
+    pub boss: Pubkey,
+    /// The guardian that co-signed the removal
+    pub guardian: Pubkey,
+    /// Number of destinations in the allowlist after the removal
+    pub active_destination_count: u8,
+}
+
+/// Account structure for removing a destination from the vault withdrawal allowlist
+#[derive(Accounts)]
+pub struct RemoveWithdrawalDestination<'info> {
+    /// Program state account containing boss authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema,
+        constraint = state.guardian != Pubkey::default()
+            @ RemoveWithdrawalDestinationErrorCode::GuardianNotSet
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The vault withdrawal destination allowlist
+    #[account(
+        mut,
+        seeds = [seeds::WITHDRAWAL_ALLOWLIST],
+        bump = withdrawal_allowlist.bump
+    )]
+    pub withdrawal_allowlist: Box<Account<'info, WithdrawalAllowlist>>,
+
+    /// The boss account authorized to manage the allowlist
+    pub boss: Signer<'info>,
+
+    /// The guardian co-signing the removal
+    #[account(
+        constraint = guardian.key() == state.guardian
+            @ RemoveWithdrawalDestinationErrorCode::InvalidGuardian
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// Removes a destination owner from the vault withdrawal allowlist
+///
+/// Removal needs the guardian like additions do, so a leaked boss key cannot empty the
+/// list either. Removing the last entry blocks vault withdrawals until a destination
+/// is added again; it never restores withdrawals to the unlisted boss.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing validated accounts
+/// * `destination` - Destination owner to remove
+///
+/// # Returns
+/// * `Ok(())` - If the destination is successfully removed
+/// * `Err(RemoveWithdrawalDestinationErrorCode::DestinationNotFound)` - If the destination is not listed
+/// * `Err(RemoveWithdrawalDestinationErrorCode::GuardianNotSet)` - If no guardian is set in program state
+/// * `Err(RemoveWithdrawalDestinationErrorCode::InvalidGuardian)` - If the co-signer is not the guardian
+///
+/// # Access Control
+/// - Only the boss can call this instruction
+/// - Also requires the guardian, which must be set
+///
+/// # Effects
+/// - Clears the destination's slot in the allowlist
+///
+/// # Events
+/// * `WithdrawalDestinationRemovedEvent` - Emitted with the destination and the resulting count
+pub fn remove_withdrawal_destination(
+    ctx: Context<RemoveWithdrawalDestination>,
+    destination: Pubkey,
+) -> Result<()> {
+    let allowlist = &mut ctx.accounts.withdrawal_allowlist;
+
+    let slot = allowlist
+        .destinations
+        .iter()
+        .position(|d| *d == destination && destination != Pubkey::default())
+        .ok_or(RemoveWithdrawalDestinationErrorCode::DestinationNotFound)?;
+    allowlist.destinations[slot] = Pubkey::default();
+    let active_destination_count = allowlist.active_count();
+
+    msg!(
+        "Withdrawal destination removed: {} by boss: {}, active destinations: {}",
+        destination,
+        ctx.accounts.boss.key(),
+        active_destination_count
+    );
+
+    emit!(WithdrawalDestinationRemovedEvent {
+        destination,
+        boss: ctx.accounts.boss.key(),
+        guardian: ctx.accounts.guardian.key(),
+        active_destination_count,
+    });
+
+    Ok(())
+}
+
+/// Error codes for removing withdrawal destinations
+#[error_code]
+pub enum RemoveWithdrawalDestinationErrorCode {
+    /// The destination is not present in the allowlist
+    #[msg("Destination not found in the withdrawal allowlist")]
+    DestinationNotFound,
+    /// Removing a destination requires a guardian to be set in program state
+    #[msg("A guardian must be set to remove a withdrawal destination")]
+    GuardianNotSet,
+    /// The guardian account does not match the one stored in program state
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+}
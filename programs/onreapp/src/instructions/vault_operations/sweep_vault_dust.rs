@@ -0,0 +1,200 @@
+use crate::constants::seeds;
+use crate::instructions::vault_operations::offer_vault_authority_seed;
+use crate::instructions::Offer;
+use crate::state::{State, StateSchemaErrorCode};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount, TokenInterface};
+
+/// Event emitted when empty vault token accounts have been closed
+///
+/// Provides transparency for tracking vault cleanup and recovered rent.
+#[event]
+pub struct VaultDustSweptEvent {
+    /// Number of vault token accounts closed in this batch
+    pub closed: u32,
+    /// Number of non-empty vault token accounts left untouched
+    pub skipped: u32,
+    /// Total lamports recovered from the closed accounts
+    pub lamports_recovered: u64,
+    /// The boss account receiving the recovered rent
+    pub boss: Pubkey,
+    /// The account that executed the sweep (boss or admin)
+    pub signer: Pubkey,
+}
+
+/// Account structure for closing empty vault token accounts
+///
+/// The vault token accounts to close are passed as writable remaining accounts.
+/// Each one must be owned by the provided token program and controlled by either
+/// the selected offer vault authority or the redemption vault authority.
+#[derive(Accounts)]
+pub struct SweepVaultDust<'info> {
+    /// The offer whose own vault is swept, `None` for the shared offer vault
+    ///
+    /// Offers without their own vault select the shared vault as well.
+    pub offer: Option<AccountLoader<'info, Offer>>,
+
+    /// Program-derived authority that controls the selected offer vault's token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(
+        seeds = [
+            seeds::OFFER_VAULT_AUTHORITY,
+            offer_vault_authority_seed(offer.as_ref())?.as_slice()
+        ],
+        bump
+    )]
+    pub offer_vault_authority: UncheckedAccount<'info>,
+
+    /// Program-derived authority that controls redemption vault token accounts
+    ///
+    /// CHECK: PDA derivation is validated by seeds constraint
+    #[account(seeds = [seeds::REDEMPTION_OFFER_VAULT_AUTHORITY], bump)]
+    pub redemption_vault_authority: UncheckedAccount<'info>,
+
+    /// Program state account containing boss and admin authorization
+    #[account(
+        seeds = [seeds::STATE],
+        bump = state.bump,
+        has_one = boss,
+        constraint = state.has_supported_schema() @ StateSchemaErrorCode::OutdatedStateSchema
+    )]
+    pub state: Box<Account<'info, State>>,
+
+    /// The boss account receiving the rent of closed vault accounts
+    ///
+    /// CHECK: Validated against the boss stored in program state
+    #[account(mut)]
+    pub boss: UncheckedAccount<'info>,
+
+    /// The account executing the sweep (boss or admin)
+    pub signer: Signer<'info>,
+
+    /// Token program owning the vault token accounts passed in this batch
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Closes empty vault token accounts and returns their rent to the boss
+///
+/// Vault token accounts of retired mints keep holding rent after their balance has
+/// been withdrawn. This instruction iterates over the accounts passed in remaining
+/// accounts and closes every empty one. Accounts that still hold tokens are skipped
+/// so a batch is never aborted by a vault that received a late deposit; their balance
+/// has to be withdrawn first. Vault accounts are recreated on demand by deposits.
+/// Passing an offer with its own vault sweeps that vault's accounts instead of the
+/// shared offer vault's.
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing the vault token accounts as remaining accounts
+///
+/// # Returns
+/// * `Ok(closed)` - Number of vault token accounts closed
+/// * `Err(SweepVaultDustErrorCode::Unauthorized)` - If the signer is neither boss nor admin
+/// * `Err(SweepVaultDustErrorCode::InvalidVaultAccount)` - If an account is not a vault token account
+///
+/// # Access Control
+/// - Boss or any admin can call this instruction
+/// - Rent is always returned to the boss stored in program state
+///
+/// # Effects
+/// - Closes each empty vault token account passed in remaining accounts
+/// - Transfers the recovered rent to the boss
+///
+/// # Events
+/// * `VaultDustSweptEvent` - Emitted with the batch results
+pub fn sweep_vault_dust<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepVaultDust<'info>>,
+) -> Result<u32> {
+    let state = &ctx.accounts.state;
+    let signer = &ctx.accounts.signer;
+    require!(
+        state.boss == signer.key() || state.admins.contains(signer.key),
+        SweepVaultDustErrorCode::Unauthorized
+    );
+
+    let vault_authority_seed = offer_vault_authority_seed(ctx.accounts.offer.as_ref())?;
+    let offer_vault_authority_seeds: &[&[u8]] = &[
+        seeds::OFFER_VAULT_AUTHORITY,
+        &vault_authority_seed,
+        &[ctx.bumps.offer_vault_authority],
+    ];
+    let redemption_vault_authority_seeds: &[&[u8]] = &[
+        seeds::REDEMPTION_OFFER_VAULT_AUTHORITY,
+        &[ctx.bumps.redemption_vault_authority],
+    ];
+
+    let mut closed: u32 = 0;
+    let mut skipped: u32 = 0;
+    let mut lamports_recovered: u64 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(
+            *account_info.owner,
+            ctx.accounts.token_program.key(),
+            SweepVaultDustErrorCode::InvalidVaultAccount
+        );
+        let vault = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+
+        let (authority, authority_seeds) =
+            if vault.owner == ctx.accounts.offer_vault_authority.key() {
+                (
+                    ctx.accounts.offer_vault_authority.to_account_info(),
+                    offer_vault_authority_seeds,
+                )
+            } else if vault.owner == ctx.accounts.redemption_vault_authority.key() {
+                (
+                    ctx.accounts.redemption_vault_authority.to_account_info(),
+                    redemption_vault_authority_seeds,
+                )
+            } else {
+                return err!(SweepVaultDustErrorCode::InvalidVaultAccount);
+            };
+
+        if vault.amount > 0 {
+            skipped += 1;
+            continue;
+        }
+
+        let rent = account_info.lamports();
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: account_info.clone(),
+                destination: ctx.accounts.boss.to_account_info(),
+                authority,
+            },
+            &[authority_seeds],
+        ))?;
+
+        closed += 1;
+        lamports_recovered = lamports_recovered.saturating_add(rent);
+    }
+
+    msg!(
+        "Vault dust swept: {} closed, {} skipped, {} lamports recovered",
+        closed,
+        skipped,
+        lamports_recovered
+    );
+
+    emit!(VaultDustSweptEvent {
+        closed,
+        skipped,
+        lamports_recovered,
+        boss: ctx.accounts.boss.key(),
+        signer: signer.key(),
+    });
+
+    Ok(closed)
+}
+
+/// Error codes for vault dust sweep operations
+#[error_code]
+pub enum SweepVaultDustErrorCode {
+    /// Signer is neither the boss nor an admin
+    #[msg("Unauthorized: signer must be boss or admin")]
+    Unauthorized,
+    /// Remaining account is not a token account controlled by a vault authority
+    #[msg("Invalid vault token account")]
+    InvalidVaultAccount,
+}
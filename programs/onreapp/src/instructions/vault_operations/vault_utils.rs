@@ -0,0 +1,57 @@
+use crate::instructions::vault_operations::VaultWithdrawTimelock;
+use crate::instructions::Offer;
+use anchor_lang::prelude::*;
+
+/// Program-derived authority controlling the vault whose balance changed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultAuthorityType {
+    /// The offer vault, controlled by `OFFER_VAULT_AUTHORITY`
+    Offer,
+    /// The redemption vault, controlled by `REDEMPTION_OFFER_VAULT_AUTHORITY`
+    Redemption,
+}
+
+/// Returns the seed following `OFFER_VAULT_AUTHORITY` in the vault authority serving `offer`
+///
+/// Without an offer the shared offer vault is selected, as it is for offers without
+/// their own vault; see `Offer::vault_authority_seed`.
+pub fn offer_vault_authority_seed(offer: Option<&AccountLoader<Offer>>) -> Result<Vec<u8>> {
+    Ok(match offer {
+        Some(offer) => offer.load()?.vault_authority_seed(&offer.key()),
+        None => Vec::new(),
+    })
+}
+
+/// Records an immediate withdrawal of `amount` against the mint's withdrawal timelock
+///
+/// `timelock_info` is the mint's writable `VaultWithdrawTimelock` PDA, validated by the
+/// caller. A withdrawal timelock that has not been created yet is disabled.
+///
+/// # Returns
+/// * `Ok(true)` - If the withdrawal may proceed, after adding it to the window tally
+/// * `Ok(false)` - If the withdrawal requires a proposal; nothing is recorded
+pub fn record_immediate_withdrawal(timelock_info: &AccountInfo, amount: u64) -> Result<bool> {
+    if timelock_info.data_is_empty() {
+        return Ok(true);
+    }
+    let mut timelock =
+        VaultWithdrawTimelock::try_deserialize(&mut &timelock_info.try_borrow_data()?[..])?;
+    let now = Clock::get()?.unix_timestamp;
+    if timelock.requires_proposal(amount, now) {
+        return Ok(false);
+    }
+    timelock.record_immediate_withdrawal(amount, now);
+    timelock.try_serialize(&mut &mut timelock_info.try_borrow_mut_data()?[..])?;
+    Ok(true)
+}
+
+/// Common error codes for vault operations
+#[error_code]
+pub enum VaultCoreError {
+    /// The withdrawal destination is not in the withdrawal allowlist
+    #[msg("Withdrawal destination not allowed")]
+    DestinationNotAllowed,
+    /// The amount exceeds the immediate threshold of the mint's withdrawal timelock
+    #[msg("Withdrawal exceeds the immediate threshold and must be proposed")]
+    TimelockRequired,
+}
@@ -0,0 +1,123 @@
+use crate::constants::MAX_WITHDRAWAL_DESTINATIONS;
+use anchor_lang::prelude::*;
+
+/// Withdrawal timelock of the offer and redemption vaults for one mint
+///
+/// Derived from the mint address. While `delay_secs` is non-zero, at most
+/// `immediate_threshold` can be withdrawn immediately per window of `delay_secs`;
+/// anything beyond must be announced with `propose_vault_withdraw` and can only be
+/// executed once the delay has passed. It covers every instruction moving the mint
+/// out of a vault to the boss or an allowlisted destination.
+#[account]
+#[derive(InitSpace)]
+pub struct VaultWithdrawTimelock {
+    /// The mint whose vault withdrawals are timelocked
+    pub mint: Pubkey,
+    /// Delay in seconds between proposing and executing a withdrawal (0 = disabled)
+    pub delay_secs: u64,
+    /// Largest amount in base units that can be withdrawn immediately per window
+    pub immediate_threshold: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Unix timestamp at which the current immediate withdrawal window started
+    pub window_start: i64,
+    /// Amount in base units withdrawn immediately since `window_start`
+    pub withdrawn_in_window: u64,
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl VaultWithdrawTimelock {
+    /// Returns whether the window started at `window_start` has ended by `now`
+    fn window_ended(&self, now: i64) -> bool {
+        now >= self.window_start.saturating_add(self.delay_secs as i64)
+    }
+
+    /// Returns the amount already withdrawn immediately in the window containing `now`
+    fn withdrawn_at(&self, now: i64) -> u64 {
+        if self.window_ended(now) {
+            0
+        } else {
+            self.withdrawn_in_window
+        }
+    }
+
+    /// Returns whether withdrawing `amount` at `now` requires a proposal
+    ///
+    /// Immediate withdrawals earlier in the same window count towards the threshold,
+    /// so splitting a large withdrawal into smaller ones does not avoid the delay.
+    pub fn requires_proposal(&self, amount: u64, now: i64) -> bool {
+        self.delay_secs != 0
+            && self.withdrawn_at(now).saturating_add(amount) > self.immediate_threshold
+    }
+
+    /// Adds an immediate withdrawal of `amount` at `now` to the window tally
+    ///
+    /// Starts a new window when the previous one has ended. Nothing is tracked while
+    /// the timelock is disabled.
+    pub fn record_immediate_withdrawal(&mut self, amount: u64, now: i64) {
+        if self.delay_secs == 0 {
+            return;
+        }
+        if self.window_ended(now) {
+            self.window_start = now;
+            self.withdrawn_in_window = 0;
+        }
+        self.withdrawn_in_window = self.withdrawn_in_window.saturating_add(amount);
+    }
+}
+
+/// Pending vault withdrawal awaiting its timelock
+///
+/// Created by `propose_vault_withdraw` and closed by `execute_vault_withdraw` or
+/// `execute_redemption_vault_withdraw`, so its
+/// existence is on-chain notice of an upcoming treasury movement.
+#[account]
+#[derive(InitSpace)]
+pub struct VaultWithdrawProposal {
+    /// The mint to withdraw from a vault
+    pub mint: Pubkey,
+    /// Amount in base units to withdraw
+    pub amount: u64,
+    /// Unix timestamp from which the withdrawal can be executed
+    pub effective_at: i64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+}
+
+/// Destination owners vault withdrawals may be sent to
+///
+/// Until the first entry is added, withdrawals can only go to the boss. From then on,
+/// every offer and redemption vault withdrawal must target a listed owner, the boss
+/// included only if listed; removing every entry blocks withdrawals instead of falling
+/// back to the boss.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalAllowlist {
+    /// Allowed destination owners, empty slots hold the default pubkey
+    pub destinations: [Pubkey; MAX_WITHDRAWAL_DESTINATIONS],
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Whether a destination has ever been added to the allowlist
+    pub ever_populated: bool,
+    /// Reserved space for future fields
+    pub reserved: [u8; 31],
+}
+
+impl WithdrawalAllowlist {
+    /// Returns the number of occupied slots in the allowlist
+    pub fn active_count(&self) -> u8 {
+        self.destinations
+            .iter()
+            .filter(|destination| **destination != Pubkey::default())
+            .count() as u8
+    }
+
+    /// Returns whether withdrawals may be sent to `destination` on behalf of `boss`
+    pub fn allows(&self, destination: &Pubkey, boss: &Pubkey) -> bool {
+        if !self.ever_populated {
+            return destination == boss;
+        }
+        self.destinations.contains(destination)
+    }
+}
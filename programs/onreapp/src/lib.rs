@@ -7,6 +7,10 @@ declare_id!("onreuGhHHgVzMWSkj2oQDLDtvvGvoepBPkqyaubFcwe");
 
 pub mod constants;
 pub mod instructions;
+#[cfg(feature = "no-entrypoint")]
+pub mod pricing;
+#[cfg(not(feature = "no-entrypoint"))]
+mod pricing;
 pub mod state;
 pub mod utils;
 
@@ -53,13 +57,20 @@ pub mod onreapp {
     /// Initializes a permissionless account.
     ///
     /// Delegates to `initialize::initialize_permissionless_authority` to create a new permissionless account.
-    /// The account is created as a PDA with the seed "permissionless-1".
+    /// The account is created as a PDA with the seed "permissionless-<index>", so index 1
+    /// is the original "permissionless-1" authority.
     /// Only the boss can initialize permissionless accounts.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `InitializePermissionlessAuthority`.
+    /// - `index`: Index the authority PDA is seeded with.
+    /// - `name`: Name stored in the authority.
     pub fn initialize_permissionless_authority(
         ctx: Context<InitializePermissionlessAuthority>,
+        index: u8,
         name: String,
     ) -> Result<()> {
-        initialize_permissionless_authority::initialize_permissionless_authority(ctx, name)
+        initialize_permissionless_authority::initialize_permissionless_authority(ctx, index, name)
     }
 
     /// Deposits tokens into the offer vault.
@@ -76,11 +87,28 @@ pub mod onreapp {
         vault_operations::offer_vault_deposit(ctx, amount)
     }
 
+    /// Deposits ONyc into the keeper reward vault.
+    ///
+    /// Delegates to `vault_operations::keeper_reward_vault_deposit`.
+    /// Funds the payouts of `claim_keeper_rewards`.
+    /// Only the boss can call this instruction.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `KeeperRewardVaultDeposit`.
+    /// - `amount`: Amount of ONyc to deposit.
+    pub fn keeper_reward_vault_deposit(
+        ctx: Context<KeeperRewardVaultDeposit>,
+        amount: u64,
+    ) -> Result<()> {
+        vault_operations::keeper_reward_vault_deposit(ctx, amount)
+    }
+
     /// Withdraws tokens from the offer vault.
     ///
     /// Delegates to `vault_operations::offer_vault_withdraw`.
-    /// Transfers tokens from offer vault's token account to boss's account for the specified mint.
-    /// Creates boss token account if it doesn't exist using init_if_needed.
+    /// Transfers tokens from offer vault's token account to an allowlisted destination for the specified mint.
+    /// Creates destination token account if it doesn't exist using init_if_needed.
+    /// Amounts above the mint's timelock threshold must be proposed instead.
     /// Only the boss can call this instruction.
     ///
     /// # Arguments
@@ -90,6 +118,83 @@ pub mod onreapp {
         vault_operations::offer_vault_withdraw(ctx, amount)
     }
 
+    /// Moves an offer from the shared offer vault onto a vault of its own.
+    ///
+    /// Delegates to `vault_operations::migrate_offer_vault`.
+    /// Transfers the given inventory from the shared vault to the offer's vault, whose
+    /// authority is derived from the offer; all vault paths of the offer use it afterwards.
+    /// Only the boss can call this instruction.
+    /// Emits an `OfferVaultMigratedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MigrateOfferVault`.
+    /// - `token_in_amount`: Amount of token_in to move to the offer's vault.
+    /// - `token_out_amount`: Amount of token_out to move to the offer's vault.
+    pub fn migrate_offer_vault(
+        ctx: Context<MigrateOfferVault>,
+        token_in_amount: u64,
+        token_out_amount: u64,
+    ) -> Result<()> {
+        vault_operations::migrate_offer_vault(ctx, token_in_amount, token_out_amount)
+    }
+
+    /// Configures the vault withdrawal timelock of a mint.
+    ///
+    /// Delegates to `vault_operations::configure_vault_withdraw_timelock`.
+    /// Withdrawals above the immediate threshold must then be proposed and wait out the delay.
+    /// Only the boss can call this instruction; loosening also requires the guardian, which must be set.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureVaultWithdrawTimelock`.
+    /// - `delay_secs`: Delay between proposal and execution in seconds (0 = disabled).
+    /// - `immediate_threshold`: Largest amount withdrawable without a proposal per delay window.
+    pub fn configure_vault_withdraw_timelock(
+        ctx: Context<ConfigureVaultWithdrawTimelock>,
+        delay_secs: u64,
+        immediate_threshold: u64,
+    ) -> Result<()> {
+        vault_operations::configure_vault_withdraw_timelock(ctx, delay_secs, immediate_threshold)
+    }
+
+    /// Proposes a timelocked withdrawal from an offer vault or the redemption vault.
+    ///
+    /// Delegates to `vault_operations::propose_vault_withdraw`.
+    /// Replaces any pending proposal for the mint and restarts its delay.
+    /// Only the boss can call this instruction.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ProposeVaultWithdraw`.
+    /// - `amount`: Amount of tokens to withdraw.
+    pub fn propose_vault_withdraw(ctx: Context<ProposeVaultWithdraw>, amount: u64) -> Result<()> {
+        vault_operations::propose_vault_withdraw(ctx, amount)
+    }
+
+    /// Executes a proposed offer vault withdrawal once its delay has passed.
+    ///
+    /// Delegates to `vault_operations::execute_vault_withdraw`.
+    /// Transfers the proposed amount to the boss and closes the proposal.
+    /// Only the boss can call this instruction.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ExecuteVaultWithdraw`.
+    pub fn execute_vault_withdraw(ctx: Context<ExecuteVaultWithdraw>) -> Result<()> {
+        vault_operations::execute_vault_withdraw(ctx)
+    }
+
+    /// Executes a proposed withdrawal from the redemption vault once its delay has passed.
+    ///
+    /// Delegates to `vault_operations::execute_redemption_vault_withdraw`.
+    /// Transfers the proposed amount to an allowlisted destination and closes the proposal.
+    /// Only the boss can call this instruction.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ExecuteRedemptionVaultWithdraw`.
+    pub fn execute_redemption_vault_withdraw(
+        ctx: Context<ExecuteRedemptionVaultWithdraw>,
+    ) -> Result<()> {
+        vault_operations::execute_redemption_vault_withdraw(ctx)
+    }
+
     /// Deposits tokens into the redemption vault.
     ///
     /// Delegates to `vault_operations::redemption_vault_deposit`.
@@ -107,8 +212,8 @@ pub mod onreapp {
     /// Withdraws tokens from the redemption vault.
     ///
     /// Delegates to `vault_operations::redemption_vault_withdraw`.
-    /// Transfers tokens from redemption vault's token account to boss's account for the specified mint.
-    /// Creates boss token account if it doesn't exist using init_if_needed.
+    /// Transfers tokens from redemption vault's token account to an allowlisted destination for the specified mint.
+    /// Creates destination token account if it doesn't exist using init_if_needed.
     /// Only the boss can call this instruction.
     ///
     /// # Arguments
@@ -118,6 +223,55 @@ pub mod onreapp {
         vault_operations::redemption_vault_withdraw(ctx, amount)
     }
 
+    /// Adds a destination owner to the vault withdrawal allowlist.
+    ///
+    /// Delegates to `vault_operations::add_withdrawal_destination`.
+    /// Once the list is non-empty, vault withdrawals can only target listed owners.
+    /// Only the boss can call this instruction; the guardian must be set and co-sign.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddWithdrawalDestination`.
+    /// - `destination`: Owner of the token accounts withdrawals may be sent to.
+    pub fn add_withdrawal_destination(
+        ctx: Context<AddWithdrawalDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        vault_operations::add_withdrawal_destination(ctx, destination)
+    }
+
+    /// Removes a destination owner from the vault withdrawal allowlist.
+    ///
+    /// Delegates to `vault_operations::remove_withdrawal_destination`.
+    /// Only the boss can call this instruction; the guardian must be set and co-sign.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveWithdrawalDestination`.
+    /// - `destination`: Destination owner to remove.
+    pub fn remove_withdrawal_destination(
+        ctx: Context<RemoveWithdrawalDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        vault_operations::remove_withdrawal_destination(ctx, destination)
+    }
+
+    /// Closes empty vault token accounts and returns their rent to the boss.
+    ///
+    /// Delegates to `vault_operations::sweep_vault_dust`.
+    /// Vault token accounts of the offer and redemption vault authorities are passed as
+    /// writable remaining accounts. Empty accounts are closed, non-empty ones are skipped.
+    /// Passing an offer with its own vault selects that vault instead of the shared one.
+    /// Returns the number of closed accounts.
+    /// Only the boss or an admin can call this instruction.
+    /// Emits a `VaultDustSweptEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SweepVaultDust`, with the vault token accounts as remaining accounts.
+    pub fn sweep_vault_dust<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepVaultDust<'info>>,
+    ) -> Result<u32> {
+        vault_operations::sweep_vault_dust(ctx)
+    }
+
     /// Creates an offer.
     ///
     /// Delegates to `offer::make_offer`.
@@ -128,13 +282,63 @@ pub mod onreapp {
     /// # Arguments
     /// - `ctx`: Context for `MakeOffer`.
     /// - `fee_basis_points`: Fee in basis points (e.g., 500 = 5%) charged when taking the offer.
+    /// - `fulfillment_mode`: Whether takes transfer token_out from the vault or mint it,
+    ///   `None` to decide by the token_out mint authority on each take.
+    /// - `valid_from`: Unix timestamp from which the offer can be taken (0 = immediately).
+    /// - `valid_until`: Unix timestamp from which takes are rejected (0 = never expires).
     pub fn make_offer(
         ctx: Context<MakeOffer>,
         fee_basis_points: u16,
         needs_approval: bool,
         allow_permissionless: bool,
+        fulfillment_mode: Option<FulfillmentMode>,
+        valid_from: u64,
+        valid_until: u64,
+    ) -> Result<()> {
+        offer::make_offer(
+            ctx,
+            fee_basis_points,
+            needs_approval,
+            allow_permissionless,
+            fulfillment_mode,
+            valid_from,
+            valid_until,
+        )
+    }
+
+    /// Creates an offer with its first pricing vector and an optional vault deposit.
+    ///
+    /// Delegates to `offer::make_offer_with_vector`.
+    /// Performs `make_offer`, `add_offer_vector` and `offer_vault_deposit` atomically,
+    /// so the offer never exists without pricing.
+    /// Emits `OfferMadeEvent`, `OfferVectorAddedEvent` and, with a deposit,
+    /// `OfferVaultDepositEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MakeOfferWithVector`.
+    /// - `fee_basis_points`: Fee in basis points (e.g., 500 = 5%) charged when taking the offer.
+    /// - `fulfillment_mode`: Whether takes transfer token_out from the vault or mint it,
+    ///   `None` to decide by the token_out mint authority on each take.
+    /// - `vector`: The first pricing vector of the offer.
+    /// - `deposit_amount`: token_out to deposit into the offer vault, `None` for no deposit.
+    pub fn make_offer_with_vector(
+        ctx: Context<MakeOfferWithVector>,
+        fee_basis_points: u16,
+        needs_approval: bool,
+        allow_permissionless: bool,
+        fulfillment_mode: Option<FulfillmentMode>,
+        vector: OfferVectorParams,
+        deposit_amount: Option<u64>,
     ) -> Result<()> {
-        offer::make_offer(ctx, fee_basis_points, needs_approval, allow_permissionless)
+        offer::make_offer_with_vector(
+            ctx,
+            fee_basis_points,
+            needs_approval,
+            allow_permissionless,
+            fulfillment_mode,
+            vector,
+            deposit_amount,
+        )
     }
 
     /// Adds a time vector to an existing offer.
@@ -150,13 +354,15 @@ pub mod onreapp {
     /// - `base_price`: Price at the beginning of the vector.
     /// - `apr`: Annual Percentage Rate (APR) (see OfferVector::apr for details).
     /// - `price_fix_duration`: Duration in seconds for each price interval.
+    /// - `pricing_mode`: Step or per-second linear price evolution.
     pub fn add_offer_vector(
         ctx: Context<AddOfferVector>,
         start_time: Option<u64>,
         base_time: u64,
         base_price: u64,
-        apr: u64,
+        apr: i64,
         price_fix_duration: u64,
+        pricing_mode: PricingMode,
     ) -> Result<()> {
         offer::add_offer_vector(
             ctx,
@@ -165,6 +371,7 @@ pub mod onreapp {
             base_price,
             apr,
             price_fix_duration,
+            pricing_mode,
         )
     }
 
@@ -198,251 +405,1055 @@ pub mod onreapp {
         offer::delete_all_offer_vectors(ctx)
     }
 
-    /// Updates the fee basis points for an offer.
+    /// Compacts expired time vectors out of an offer.
     ///
-    /// Delegates to `offer::update_offer_fee`.
-    /// Allows the boss to modify the fee charged when users take the offer.
-    /// Emits a `OfferFeeUpdatedEvent` upon success.
+    /// Delegates to `offer::compact_offer_vectors`.
+    /// Clears vectors older than the predecessor of the currently active vector,
+    /// freeing slots without affecting current or scheduled pricing.
+    /// Permissionless: anyone can call this instruction.
+    /// Emits a `OfferVectorsCompactedEvent` event upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `UpdateOfferFee`.
-    /// - `new_fee_basis_points`: New fee in basis points (0-10000).
-    pub fn update_offer_fee(ctx: Context<UpdateOfferFee>, new_fee_basis_points: u16) -> Result<()> {
-        offer::update_offer_fee(ctx, new_fee_basis_points)
+    /// - `ctx`: Context for `CompactOfferVectors`.
+    pub fn compact_offer_vectors(ctx: Context<CompactOfferVectors>) -> Result<()> {
+        offer::compact_offer_vectors(ctx)
     }
 
-    /// Takes a offer.
+    /// Records the current NAV of many offers in one transaction.
     ///
-    /// Delegates to `offer::take_offer`.
-    /// Allows a user to exchange token_in for token_out based on the offer's dynamic price.
-    /// Emits a `TakeOfferEvent` upon success.
+    /// Delegates to `offer::checkpoint_navs`.
+    /// Offers are passed as writable remaining accounts and processed in order until the
+    /// compute budget runs low. Returns the number of processed offers for paging.
+    /// Permissionless: anyone can call this instruction.
+    /// Emits a `NavsCheckpointedEvent` event upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `TakeOffer`.
-    /// - `token_in_amount`: Amount of token_in to provide.
-    pub fn take_offer(
-        ctx: Context<TakeOffer>,
-        token_in_amount: u64,
-        approval_message: Option<ApprovalMessage>,
-    ) -> Result<()> {
-        offer::take_offer(ctx, token_in_amount, approval_message)
+    /// - `ctx`: Context for `CheckpointNavs`, with the offers as remaining accounts.
+    pub fn checkpoint_navs<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CheckpointNavs<'info>>,
+    ) -> Result<u32> {
+        offer::checkpoint_navs(ctx)
     }
 
-    /// Takes a offer using permissionless flow with intermediary accounts.
+    /// Records the current NAV of an offer in its NAV history.
     ///
-    /// Delegates to `offer::take_offer_permissionless`.
-    /// Similar to take_offer but routes token transfers through intermediary accounts
-    /// owned by the program instead of direct user-to-boss and vault-to-user transfers.
-    /// Emits a `TakeOfferPermissionlessEvent` upon success.
+    /// Delegates to `offer::record_nav_observation`.
+    /// Creates the offer's NAV history on first use and appends an observation, at most
+    /// one per `NAV_OBSERVATION_MIN_INTERVAL`.
+    /// Permissionless: anyone can call this instruction.
+    /// Emits a `NavObservationRecordedEvent` event upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `TakeOfferPermissionless`.
-    /// - `token_in_amount`: Amount of token_in to provide.
-    pub fn take_offer_permissionless(
-        ctx: Context<TakeOfferPermissionless>,
-        token_in_amount: u64,
-        approval_message: Option<ApprovalMessage>,
-    ) -> Result<()> {
-        offer::take_offer_permissionless(ctx, token_in_amount, approval_message)
+    /// - `ctx`: Context for `RecordNavObservation`.
+    pub fn record_nav_observation(ctx: Context<RecordNavObservation>) -> Result<()> {
+        offer::record_nav_observation(ctx)
     }
 
-    /// Proposes a new boss for ownership transfer.
+    /// Records which pricing vector of an offer is active and signals rollovers.
     ///
-    /// Delegates to `propose_boss::propose_boss` to propose a new boss authority.
-    /// This is the first step in a two-step ownership transfer process.
-    /// The proposed boss must then call accept_boss to complete the transfer.
-    /// Emits a `BossProposedEvent` upon success.
+    /// Delegates to `offer::tick_offer`.
+    /// Compares the currently active vector with the one recorded by the previous tick.
+    /// Permissionless: anyone can call this instruction.
+    /// Emits an `ActiveVectorChangedEvent` event when the active vector has changed.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `ProposeBoss`.
-    /// - `new_boss`: Public key of the proposed new boss.
-    pub fn propose_boss(ctx: Context<ProposeBoss>, new_boss: Pubkey) -> Result<()> {
-        state_operations::propose_boss(ctx, new_boss)
+    /// - `ctx`: Context for `TickOffer`.
+    ///
+    /// # Returns
+    /// - `Ok(changed)`: Whether the active vector changed since the previous tick
+    pub fn tick_offer(ctx: Context<TickOffer>) -> Result<bool> {
+        offer::tick_offer(ctx)
     }
 
-    /// Accepts the proposed boss transfer.
+    /// Sets the reward a permissionless crank accrues to its keeper per unit of work.
     ///
-    /// Delegates to `accept_boss::accept_boss` to complete the ownership transfer.
-    /// This is the second step in a two-step ownership transfer process.
-    /// Only the proposed boss can call this instruction to accept and become the new boss.
-    /// Emits a `BossAcceptedEvent` upon success.
+    /// Delegates to `keeper::configure_keeper_reward`.
+    /// Creates the keeper reward configuration on first use.
+    /// Only the boss can call this instruction.
+    /// Emits a `KeeperRewardConfiguredEvent` event upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `AcceptBoss`.
-    pub fn accept_boss(ctx: Context<AcceptBoss>) -> Result<()> {
-        state_operations::accept_boss(ctx)
+    /// - `ctx`: Context for `ConfigureKeeperReward`.
+    /// - `crank`: The crank whose rate is configured.
+    /// - `reward_rate`: Reward per unit of crank work in ONyc base units.
+    pub fn configure_keeper_reward(
+        ctx: Context<ConfigureKeeperReward>,
+        crank: KeeperCrank,
+        reward_rate: u64,
+    ) -> Result<()> {
+        keeper::configure_keeper_reward(ctx, crank, reward_rate)
     }
 
-    /// Adds a new admin to the state.
+    /// Opens the reward account that cranks accrue a keeper's rewards into.
+    ///
+    /// Delegates to `keeper::open_keeper_rewards`.
+    /// Permissionless: any keeper can open its own reward account.
+    /// Emits a `KeeperRewardsOpenedEvent` event upon success.
     ///
-    /// Delegates to `admin::add_admin` to add a new admin to the admin list.
-    /// Only the boss can call this instruction to add new admins.
     /// # Arguments
-    /// - `ctx`: Context for `AddAdmin`.
-    /// - `new_admin`: Public key of the new admin to be added.
-    pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
-        state_operations::add_admin(ctx, new_admin)
+    /// - `ctx`: Context for `OpenKeeperRewards`.
+    pub fn open_keeper_rewards(ctx: Context<OpenKeeperRewards>) -> Result<()> {
+        keeper::open_keeper_rewards(ctx)
     }
 
-    /// Removes an admin from the state.
+    /// Pays out all rewards accrued to a keeper from the keeper reward vault.
+    ///
+    /// Delegates to `keeper::claim_keeper_rewards`.
+    /// Only the keeper owning the reward account can call this instruction.
+    /// Emits a `KeeperRewardsClaimedEvent` event upon success.
     ///
-    /// Delegates to `admin::remove_admin` to remove an admin from the admin list.
-    /// Only the boss can call this instruction to remove admins.
     /// # Arguments
-    /// - `ctx`: Context for `RemoveAdmin`.
-    /// - `admin_to_remove`: Public key of the admin to be removed.
-    pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Result<()> {
-        state_operations::remove_admin(ctx, admin_to_remove)
+    /// - `ctx`: Context for `ClaimKeeperRewards`.
+    pub fn claim_keeper_rewards(ctx: Context<ClaimKeeperRewards>) -> Result<()> {
+        keeper::claim_keeper_rewards(ctx)
     }
 
-    /// Clears all admins from the state.
+    /// Gets one page of the pricing schedule of an offer.
     ///
-    /// Delegates to `admin::clear_admins` to remove all admins from the admin list.
-    /// Only the boss can call this instruction to clear all admins.
-    pub fn clear_admins(ctx: Context<ClearAdmins>) -> Result<()> {
-        state_operations::clear_admins(ctx)
+    /// Delegates to `offer::get_offer_vectors`.
+    /// This is a read-only instruction that returns the active and upcoming vectors sorted
+    /// by start time, `OFFER_VECTORS_PAGE_SIZE` entries per page, via return data in a
+    /// versioned encoding. Expired vectors are filtered out.
+    /// Emits a `GetOfferVectorsEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetOfferVectors`.
+    /// - `page`: Zero-based page index.
+    ///
+    /// # Returns
+    /// - `Ok(page)`: The requested `OfferVectorsPage` with pagination metadata
+    pub fn get_offer_vectors(ctx: Context<GetOfferVectors>, page: u8) -> Result<OfferVectorsPage> {
+        offer::get_offer_vectors(ctx, page)
     }
 
-    /// Transfers mint authority from the boss to a program-derived PDA.
+    /// Gets one page of the live offers.
     ///
-    /// Delegates to `mint_authority::transfer_mint_authority_to_program`.
-    /// Only the boss can call this instruction to transfer mint authority for a specific token.
-    /// The PDA is derived from the MINT_AUTHORITY seed and can later be used to mint tokens.
-    /// Emits a `MintAuthorityTransferredToProgramEvent` upon success.
+    /// Delegates to `offer::get_registry`.
+    /// This is a read-only instruction that returns the offers listed in the offer
+    /// registry, `OFFER_REGISTRY_PAGE_SIZE` entries per page, via return data.
+    /// Emits a `GetRegistryEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `TransferMintAuthorityToProgram`.
-    pub fn transfer_mint_authority_to_program(
-        ctx: Context<TransferMintAuthorityToProgram>,
-    ) -> Result<()> {
-        mint_authority::transfer_mint_authority_to_program(ctx)
+    /// - `ctx`: Context for `GetRegistry`.
+    /// - `page`: Zero-based page index.
+    ///
+    /// # Returns
+    /// - `Ok(page)`: The requested `OfferRegistryPage` with pagination metadata
+    pub fn get_registry(ctx: Context<GetRegistry>, page: u8) -> Result<OfferRegistryPage> {
+        offer::get_registry(ctx, page)
     }
 
-    /// Transfers mint authority from a program-derived PDA back to the boss.
+    /// Migrates a legacy offer account to the current layout.
     ///
-    /// Delegates to `mint_authority::transfer_mint_authority_to_boss`.
-    /// Only the boss can call this instruction to recover mint authority for a specific token.
-    /// This serves as an emergency recovery mechanism.
-    /// Emits a `MintAuthorityTransferredToBossEvent` upon success.
+    /// Delegates to `offer::migrate_offer`.
+    /// Reallocates offers created with 10 vector slots to the current layout,
+    /// preserving existing vectors and configuration. The boss pays the extra rent.
+    /// Emits a `OfferMigratedEvent` event upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `TransferMintAuthorityToBoss`.
-    pub fn transfer_mint_authority_to_boss(
-        ctx: Context<TransferMintAuthorityToBoss>,
-    ) -> Result<()> {
-        mint_authority::transfer_mint_authority_to_boss(ctx)
+    /// - `ctx`: Context for `MigrateOffer`.
+    pub fn migrate_offer(ctx: Context<MigrateOffer>) -> Result<()> {
+        offer::migrate_offer(ctx)
     }
 
-    /// Enables or disables the kill switch.
-    ///
-    /// Delegates to `kill_switch::kill_switch` to change the kill switch state.
-    /// When enabled (true), the kill switch can halt critical program operations.
-    /// When disabled (false), normal program operations can proceed.
+    /// Updates the fee basis points for an offer.
     ///
-    /// Access control:
-    /// - Both boss and admins can enable the kill switch
-    /// - Only the boss can disable the kill switch
+    /// Delegates to `offer::update_offer_fee`.
+    /// Allows the boss to modify the fee charged when users take the offer.
+    /// Emits a `OfferFeeUpdatedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `KillSwitch`.
-    /// - `enable`: True to enable the kill switch, false to disable it.
-    pub fn set_kill_switch(ctx: Context<SetKillSwitch>, enable: bool) -> Result<()> {
-        state_operations::set_kill_switch(ctx, enable)
+    /// - `ctx`: Context for `UpdateOfferFee`.
+    /// - `new_fee_basis_points`: New fee in basis points (0-10000).
+    pub fn update_offer_fee(ctx: Context<UpdateOfferFee>, new_fee_basis_points: u16) -> Result<()> {
+        offer::update_offer_fee(ctx, new_fee_basis_points)
     }
 
-    /// Sets the Onyc mint in the state.
+    /// Updates the dust threshold for an offer.
     ///
-    /// Delegates to `state_operations::set_onyc_mint` to change the Onyc mint.
-    /// Only the boss can call this instruction to set the Onyc mint.
-    /// Emits a `OnycMintSetEvent` upon success.
+    /// Delegates to `offer::update_offer_dust_threshold`.
+    /// Allows the boss to set the minimum token_out a single take must pay out.
+    /// Emits a `OfferDustThresholdUpdatedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `SetOnycMint`.
-    pub fn set_onyc_mint(ctx: Context<SetOnycMint>) -> Result<()> {
-        state_operations::set_onyc_mint(ctx)
+    /// - `ctx`: Context for `UpdateOfferDustThreshold`.
+    /// - `new_min_token_out_amount`: Minimum token_out per take (0 = only non-zero payouts).
+    pub fn update_offer_dust_threshold(
+        ctx: Context<UpdateOfferDustThreshold>,
+        new_min_token_out_amount: u64,
+    ) -> Result<()> {
+        offer::update_offer_dust_threshold(ctx, new_min_token_out_amount)
     }
 
-    /// Sets the redemption admin in the state.
+    /// Updates the low inventory threshold for an offer.
     ///
-    /// Delegates to `state_operations::set_redemption_admin` to change the redemption admin.
-    /// Only the boss can call this instruction to set the redemption admin.
-    /// Emits a `RedemptionAdminUpdatedEvent` upon success.
+    /// Delegates to `offer::update_offer_low_inventory_threshold`.
+    /// Allows the boss to set the vault token_out balance at which takes flag low inventory.
+    /// Emits a `OfferLowInventoryThresholdUpdatedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `SetRedemptionAdmin`.
-    /// - `new_redemption_admin`: Public key of the new redemption admin.
-    pub fn set_redemption_admin(
-        ctx: Context<SetRedemptionAdmin>,
-        new_redemption_admin: Pubkey,
+    /// - `ctx`: Context for `UpdateOfferLowInventoryThreshold`.
+    /// - `new_low_inventory_threshold`: Vault token_out balance flagged as low (0 = no alerts).
+    pub fn update_offer_low_inventory_threshold(
+        ctx: Context<UpdateOfferLowInventoryThreshold>,
+        new_low_inventory_threshold: u64,
     ) -> Result<()> {
-        state_operations::set_redemption_admin(ctx, new_redemption_admin)
+        offer::update_offer_low_inventory_threshold(ctx, new_low_inventory_threshold)
     }
 
-    /// Mints ONyc tokens to the boss's account.
+    /// Updates the validity window for an offer.
     ///
-    /// Delegates to `state_operations::mint_to` to mint ONyc tokens.
-    /// Only the boss can call this instruction to mint ONyc tokens to their account.
-    /// The program must have mint authority for the ONyc token.
-    /// Emits a `OnycTokensMinted` event upon success.
+    /// Delegates to `offer::update_offer_validity`.
+    /// Allows the boss to schedule when takes of the offer open and expire.
+    /// Emits a `OfferValidityUpdatedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `MintTo`.
-    /// - `amount`: Amount of ONyc tokens to mint.
-    pub fn mint_to(ctx: Context<MintTo>, amount: u64) -> Result<()> {
-        mint_authority::mint_to(ctx, amount)
+    /// - `ctx`: Context for `UpdateOfferValidity`.
+    /// - `new_valid_from`: Unix timestamp from which the offer can be taken (0 = immediately).
+    /// - `new_valid_until`: Unix timestamp from which takes are rejected (0 = never expires).
+    pub fn update_offer_validity(
+        ctx: Context<UpdateOfferValidity>,
+        new_valid_from: u64,
+        new_valid_until: u64,
+    ) -> Result<()> {
+        offer::update_offer_validity(ctx, new_valid_from, new_valid_until)
     }
 
-    /// Gets the current NAV (price) for a specific offer.
+    /// Updates the approval and permissionless flags of an offer.
     ///
-    /// Delegates to `market_info::get_nav`.
-    /// This is a read-only instruction that calculates and returns the current price
-    /// for an offer based on its time vectors and APR parameters.
-    /// Emits a `GetNAVEvent` upon success.
+    /// Delegates to `offer::update_offer_flags`.
+    /// Allows the boss to open or restrict an offer without recreating it.
+    /// Emits a `OfferFlagsUpdatedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `GetNAV`.
-    ///
-    /// # Returns
-    /// - `Ok(current_price)`: The calculated current price (mantissa) for the offer with scale=9
-    pub fn get_nav(ctx: Context<GetNAV>) -> Result<u64> {
-        market_info::get_nav(ctx)
+    /// - `ctx`: Context for `UpdateOfferFlags`.
+    /// - `needs_approval`: Whether takes of the offer require boss approval.
+    /// - `allow_permissionless`: Whether the offer can be taken permissionlessly.
+    pub fn update_offer_flags(
+        ctx: Context<UpdateOfferFlags>,
+        needs_approval: bool,
+        allow_permissionless: bool,
+    ) -> Result<()> {
+        offer::update_offer_flags(ctx, needs_approval, allow_permissionless)
     }
 
-    /// Gets the current APY (Annual Percentage Yield) for a specific offer.
+    /// Closes an offer and emits its lifetime statistics.
     ///
-    /// Delegates to `market_info::get_apy`.
-    /// This is a read-only instruction that calculates and returns the current APY
-    /// by converting the stored APR using daily compounding formula.
-    /// Emits a `GetAPYEvent` upon success.
+    /// Delegates to `offer::close_offer`.
+    /// An offer with its own vault returns the residual balances of both its mints to the
+    /// boss by passing, per mint, the mint, the vault token account, the boss's token account
+    /// and the mint's withdrawal timelock as remaining accounts. The rent is returned to the boss.
+    /// Only the boss can call this instruction.
+    /// Emits an `OfferClosedFinalEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `GetAPY`.
-    ///
-    /// # Returns
-    /// - `Ok(apy)`: The calculated APY scaled by 1_000_000 (returns the mantissa, with scale=6)
-    pub fn get_apy(ctx: Context<GetAPY>) -> Result<u64> {
-        market_info::get_apy(ctx)
+    /// - `ctx`: Context for `CloseOffer`, with the residual balance sweeps as remaining accounts.
+    pub fn close_offer<'info>(ctx: Context<'_, '_, 'info, 'info, CloseOffer<'info>>) -> Result<()> {
+        offer::close_offer(ctx)
     }
 
-    /// Gets the NAV adjustment (price change) for a specific offer.
+    /// Marks an expired offer closed and removes it from the offer registry.
     ///
-    /// Delegates to `market_info::get_nav_adjustment`.
-    /// This is a read-only instruction that calculates the price difference
-    /// between the current vector and the previous vector at the current time.
-    /// Returns a signed integer representing the price change.
-    /// Emits a `GetNavAdjustmentEvent` upon success.
+    /// Delegates to `offer::crank_expired_offer`.
+    /// Only succeeds once the offer's `valid_until` plus the expiry grace period has passed.
+    /// The boss reclaims the rent later with `close_offer`.
+    /// Permissionless: anyone can call this instruction.
+    /// Emits an `OfferClosedFinalEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `GetNavAdjustment`.
+    /// - `ctx`: Context for `CrankExpiredOffer`.
+    pub fn crank_expired_offer(ctx: Context<CrankExpiredOffer>) -> Result<()> {
+        offer::crank_expired_offer(ctx)
+    }
+
+    /// Closes a permissionless authority and its intermediary accounts.
     ///
-    /// # Returns
-    /// - `Ok(adjustment)`: The calculated price adjustment (current - previous) as a signed integer,
-    /// returns the mantissa with scale=9
-    pub fn get_nav_adjustment(ctx: Context<GetNavAdjustment>) -> Result<i64> {
-        market_info::get_nav_adjustment(ctx)
+    /// Delegates to `offer::close_permissionless_authority`.
+    /// The intermediary token accounts of the authority are passed as writable remaining
+    /// accounts and must be empty. The rent of all closed accounts is returned to the boss.
+    /// Only the boss can call this instruction.
+    /// Emits a `PermissionlessAuthorityClosedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ClosePermissionlessAuthority`, with the intermediary token accounts
+    ///   as remaining accounts.
+    /// - `authority_index`: Index of the permissionless authority to close.
+    pub fn close_permissionless_authority<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClosePermissionlessAuthority<'info>>,
+        authority_index: u8,
+    ) -> Result<()> {
+        offer::close_permissionless_authority(ctx, authority_index)
     }
 
-    /// Gets the current TVL (Total Value Locked) for a specific offer with 9 decimal precision
+    /// Recovers tokens stranded in a permissionless intermediary account.
+    ///
+    /// Delegates to `offer::recover_intermediary_funds`.
+    /// Transfers the tokens to a token account owned by the boss, or by the original
+    /// user when the boss signs. Admins can only recover to the boss.
+    /// Emits an `IntermediaryFundsRecoveredEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RecoverIntermediaryFunds`.
+    /// - `authority_index`: Index of the permissionless authority owning the intermediary.
+    /// - `amount`: Amount of tokens to recover.
+    pub fn recover_intermediary_funds(
+        ctx: Context<RecoverIntermediaryFunds>,
+        authority_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        offer::recover_intermediary_funds(ctx, authority_index, amount)
+    }
+
+    /// Sets the fee override of a permissionless authority.
+    ///
+    /// Delegates to `offer::set_permissionless_fee`.
+    /// Allows the boss to charge a fee other than the offer fee on takes routed
+    /// through the authority. Emits a `PermissionlessFeeUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetPermissionlessFee`.
+    /// - `authority_index`: Index of the permissionless authority.
+    /// - `fee_override_bps`: Fee in basis points replacing the offer fee (None = offer fee).
+    pub fn set_permissionless_fee(
+        ctx: Context<SetPermissionlessFee>,
+        authority_index: u8,
+        fee_override_bps: Option<u16>,
+    ) -> Result<()> {
+        offer::set_permissionless_fee(ctx, authority_index, fee_override_bps)
+    }
+
+    /// Updates the per-step token_out volume cap for an offer.
+    ///
+    /// Delegates to `offer::update_offer_max_step_volume`.
+    /// Allows the boss to limit how much token_out can be taken within a single pricing step.
+    /// Emits a `OfferMaxStepVolumeUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `UpdateOfferMaxStepVolume`.
+    /// - `new_max_step_volume`: Maximum token_out amount per step (0 = no cap).
+    pub fn update_offer_max_step_volume(
+        ctx: Context<UpdateOfferMaxStepVolume>,
+        new_max_step_volume: u64,
+    ) -> Result<()> {
+        offer::update_offer_max_step_volume(ctx, new_max_step_volume)
+    }
+
+    /// Updates the minimum take size for an offer.
+    ///
+    /// Delegates to `offer::update_offer_min_take`.
+    /// Allows the boss to set the minimum in token_in units or in USD valued at the current price.
+    /// Emits a `OfferMinTakeUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `UpdateOfferMinTake`.
+    /// - `min_take_amount`: Minimum take size in the chosen unit (0 = no minimum).
+    /// - `denomination`: Whether the minimum is a token_in amount or a USD amount with scale=9.
+    pub fn update_offer_min_take(
+        ctx: Context<UpdateOfferMinTake>,
+        min_take_amount: u64,
+        denomination: MinimumDenomination,
+    ) -> Result<()> {
+        offer::update_offer_min_take(ctx, min_take_amount, denomination)
+    }
+
+    /// Sets the oracle used to value an offer's token_in in USD.
+    ///
+    /// Delegates to `offer::set_offer_oracle`.
+    /// Only the boss can configure offer oracles. Passing the default pubkey removes the oracle.
+    /// Emits an `OfferOracleUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOfferOracle`.
+    /// - `oracle`: Pyth price update account for token_in/USD.
+    /// - `feed_id`: Pyth feed id the oracle's price updates must carry.
+    /// - `max_staleness`: Maximum accepted age of the price in seconds.
+    /// - `max_confidence_bps`: Maximum accepted confidence interval in basis points of the price.
+    pub fn set_offer_oracle(
+        ctx: Context<SetOfferOracle>,
+        oracle: Pubkey,
+        feed_id: [u8; 32],
+        max_staleness: u64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        offer::set_offer_oracle(ctx, oracle, feed_id, max_staleness, max_confidence_bps)
+    }
+
+    /// Enables or disables clawbacks of an offer's takes.
+    ///
+    /// Delegates to `offer::set_offer_clawback`.
+    /// Only takes of flagged offers can be reversed by `clawback_take`.
+    /// Emits an `OfferClawbackUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOfferClawback`.
+    /// - `clawback_enabled`: Whether takes of the offer may be clawed back.
+    pub fn set_offer_clawback(
+        ctx: Context<SetOfferClawback>,
+        clawback_enabled: bool,
+    ) -> Result<()> {
+        offer::set_offer_clawback(ctx, clawback_enabled)
+    }
+
+    /// Routes an offer's take proceeds to the offer vault or to the boss.
+    ///
+    /// Delegates to `offer::set_offer_proceeds_to_vault`.
+    /// New offers route proceeds to the vault; legacy offers pay the boss until switched.
+    /// Emits an `OfferProceedsDestinationUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOfferProceedsToVault`.
+    /// - `proceeds_to_vault`: Whether take proceeds land in the offer vault.
+    pub fn set_offer_proceeds_to_vault(
+        ctx: Context<SetOfferProceedsToVault>,
+        proceeds_to_vault: bool,
+    ) -> Result<()> {
+        offer::set_offer_proceeds_to_vault(ctx, proceeds_to_vault)
+    }
+
+    /// Prices an offer's takes on the token_in amount received after transfer fees.
+    ///
+    /// Delegates to `offer::set_offer_price_on_net_received`.
+    /// Enables `take_offer` for Token-2022 token_in mints with a transfer fee.
+    /// Emits an `OfferPriceOnNetReceivedUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOfferPriceOnNetReceived`.
+    /// - `price_on_net_received`: Whether takes are priced on the amount received.
+    pub fn set_offer_price_on_net_received(
+        ctx: Context<SetOfferPriceOnNetReceived>,
+        price_on_net_received: bool,
+    ) -> Result<()> {
+        offer::set_offer_price_on_net_received(ctx, price_on_net_received)
+    }
+
+    /// Requires the user's accounts to exist before takes of an offer.
+    ///
+    /// Delegates to `offer::set_offer_strict_accounts`.
+    /// Strict `take_offer` and `take_offer_exact_out` calls fail instead of creating accounts.
+    /// Emits an `OfferStrictAccountsUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOfferStrictAccounts`.
+    /// - `strict_accounts`: Whether takes require the user's accounts to exist already.
+    pub fn set_offer_strict_accounts(
+        ctx: Context<SetOfferStrictAccounts>,
+        strict_accounts: bool,
+    ) -> Result<()> {
+        offer::set_offer_strict_accounts(ctx, strict_accounts)
+    }
+
+    /// Creates the user's token_out account and position for takes of an offer.
+    ///
+    /// Delegates to `offer::prepare_accounts`.
+    /// Lets takes of strict offers run without creating accounts.
+    /// Emits a `TakeAccountsPreparedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `PrepareAccounts`.
+    pub fn prepare_accounts(ctx: Context<PrepareAccounts>) -> Result<()> {
+        offer::prepare_accounts(ctx)
+    }
+
+    /// Sweeps an offer's take proceeds from the offer vault.
+    ///
+    /// Delegates to `offer::collect_proceeds`.
+    /// Sends token_in to the boss or another destination in the withdrawal allowlist.
+    /// Only the boss can call this instruction.
+    /// Emits a `ProceedsCollectedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `CollectProceeds`.
+    /// - `amount`: Amount of token_in to collect.
+    pub fn collect_proceeds(ctx: Context<CollectProceeds>, amount: u64) -> Result<()> {
+        offer::collect_proceeds(ctx, amount)
+    }
+
+    /// Reverses a take executed at a wrong NAV.
+    ///
+    /// Delegates to `offer::clawback_take`.
+    /// The take is read from the receipt it wrote. The user returns the token_out received,
+    /// which is burned or sent back to the offer vault, and the boss refunds the token_in
+    /// paid. Takes can only be reversed on flagged offers, within `CLAWBACK_WINDOW_SECONDS`
+    /// and at most once.
+    /// Emits a `TakeClawedBackEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ClawbackTake`.
+    ///
+    /// # Access Control
+    /// - Boss, guardian and the user must all sign
+    pub fn clawback_take(ctx: Context<ClawbackTake>) -> Result<()> {
+        offer::clawback_take(ctx)
+    }
+
+    /// Closes a take receipt once its take can no longer be clawed back.
+    ///
+    /// Delegates to `offer::close_take_receipt`.
+    /// Returns the receipt's rent to the user after `CLAWBACK_WINDOW_SECONDS`.
+    /// Emits a `TakeReceiptClosedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `CloseTakeReceipt`.
+    pub fn close_take_receipt(ctx: Context<CloseTakeReceipt>) -> Result<()> {
+        offer::close_take_receipt(ctx)
+    }
+
+    /// Takes a offer.
+    ///
+    /// Delegates to `offer::take_offer`.
+    /// Allows a user to exchange token_in for token_out based on the offer's dynamic price.
+    /// Emits a `TakeOfferEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeOffer`, with transfer hook accounts as remaining accounts
+    ///   when token_in is an approved transfer-hook mint.
+    /// - `token_in_amount`: Amount of token_in to provide.
+    pub fn take_offer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeOffer<'info>>,
+        token_in_amount: u64,
+        approval_message: Option<ApprovalMessage>,
+    ) -> Result<()> {
+        offer::take_offer(ctx, token_in_amount, approval_message)
+    }
+
+    /// Takes a offer for an exact amount of token_out.
+    ///
+    /// Delegates to `offer::take_offer_exact_out`.
+    /// Inverts the offer price to charge the token_in, including fees, owed for the requested token_out.
+    /// Emits a `OfferTakenEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeOffer`, with transfer hook accounts as remaining accounts
+    ///   when token_in is an approved transfer-hook mint.
+    /// - `token_out_amount`: Exact amount of token_out to receive.
+    /// - `max_token_in_amount`: Maximum amount of token_in to pay, including fees.
+    pub fn take_offer_exact_out<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeOffer<'info>>,
+        token_out_amount: u64,
+        max_token_in_amount: u64,
+        approval_message: Option<ApprovalMessage>,
+    ) -> Result<()> {
+        offer::take_offer_exact_out(ctx, token_out_amount, max_token_in_amount, approval_message)
+    }
+
+    /// Takes a offer on behalf of a token owner using SPL delegate authority.
+    ///
+    /// Delegates to `offer::take_offer_delegated`.
+    /// Pulls token_in from the owner's account as its approved delegate and credits token_out to the owner.
+    /// Emits a `OfferTakenDelegatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeOfferDelegated`, with transfer hook accounts as remaining accounts
+    ///   when token_in is an approved transfer-hook mint.
+    /// - `token_in_amount`: Amount of token_in to pull from the owner.
+    pub fn take_offer_delegated<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeOfferDelegated<'info>>,
+        token_in_amount: u64,
+        approval_message: Option<ApprovalMessage>,
+    ) -> Result<()> {
+        offer::take_offer_delegated(ctx, token_in_amount, approval_message)
+    }
+
+    /// Takes a offer using permissionless flow with intermediary accounts.
+    ///
+    /// Delegates to `offer::take_offer_permissionless`.
+    /// Similar to take_offer but routes token transfers through intermediary accounts
+    /// owned by the program instead of direct user-to-boss and vault-to-user transfers.
+    /// Emits a `TakeOfferPermissionlessEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeOfferPermissionless`, with transfer hook accounts as remaining accounts
+    ///   when token_in is an approved transfer-hook mint.
+    /// - `token_in_amount`: Amount of token_in to provide.
+    /// - `authority_index`: Index of the permissionless authority whose intermediary
+    ///   accounts route the take.
+    pub fn take_offer_permissionless<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeOfferPermissionless<'info>>,
+        token_in_amount: u64,
+        approval_message: Option<ApprovalMessage>,
+        authority_index: u8,
+    ) -> Result<()> {
+        offer::take_offer_permissionless(ctx, token_in_amount, approval_message, authority_index)
+    }
+
+    /// Creates a dual offer paying a secondary token alongside an existing offer.
+    ///
+    /// Delegates to `offer::make_dual_offer`.
+    /// Only the boss can create dual offers. The base offer for token_in/token_out_1 must exist.
+    /// Emits a `DualOfferMadeEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MakeDualOffer`.
+    /// - `token_out_2_ratio`: Whole token_out_2 paid per whole token_out_1 (scale=9).
+    pub fn make_dual_offer(ctx: Context<MakeDualOffer>, token_out_2_ratio: u64) -> Result<()> {
+        offer::make_dual_offer(ctx, token_out_2_ratio)
+    }
+
+    /// Takes a dual offer, receiving both output tokens.
+    ///
+    /// Delegates to `offer::take_dual_offer`.
+    /// The principal leg is priced by the base offer, the secondary leg follows the configured ratio.
+    /// Emits a `DualOfferTakenEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeDualOffer`, with transfer hook accounts as remaining accounts
+    ///   when token_in is an approved transfer-hook mint.
+    /// - `token_in_amount`: Amount of token_in to provide.
+    pub fn take_dual_offer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeDualOffer<'info>>,
+        token_in_amount: u64,
+        approval_message: Option<ApprovalMessage>,
+    ) -> Result<()> {
+        offer::take_dual_offer(ctx, token_in_amount, approval_message)
+    }
+
+    /// Proposes a new boss for ownership transfer.
+    ///
+    /// Delegates to `propose_boss::propose_boss` to propose a new boss authority.
+    /// This is the first step in a two-step ownership transfer process.
+    /// The proposed boss must then call accept_boss to complete the transfer.
+    /// Emits a `BossProposedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ProposeBoss`.
+    /// - `new_boss`: Public key of the proposed new boss.
+    pub fn propose_boss(ctx: Context<ProposeBoss>, new_boss: Pubkey) -> Result<()> {
+        state_operations::propose_boss(ctx, new_boss)
+    }
+
+    /// Accepts the proposed boss transfer.
+    ///
+    /// Delegates to `accept_boss::accept_boss` to complete the ownership transfer.
+    /// This is the second step in a two-step ownership transfer process.
+    /// Only the proposed boss can call this instruction to accept and become the new boss.
+    /// Emits a `BossAcceptedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AcceptBoss`.
+    pub fn accept_boss(ctx: Context<AcceptBoss>) -> Result<()> {
+        state_operations::accept_boss(ctx)
+    }
+
+    /// Adds a new admin to the state.
+    ///
+    /// Delegates to `admin::add_admin` to add a new admin to the admin list.
+    /// Only the boss can call this instruction to add new admins.
+    /// # Arguments
+    /// - `ctx`: Context for `AddAdmin`.
+    /// - `new_admin`: Public key of the new admin to be added.
+    pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
+        state_operations::add_admin(ctx, new_admin)
+    }
+
+    /// Removes an admin from the state.
+    ///
+    /// Delegates to `admin::remove_admin` to remove an admin from the admin list.
+    /// Only the boss can call this instruction to remove admins.
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveAdmin`.
+    /// - `admin_to_remove`: Public key of the admin to be removed.
+    pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Result<()> {
+        state_operations::remove_admin(ctx, admin_to_remove)
+    }
+
+    /// Clears all admins from the state.
+    ///
+    /// Delegates to `admin::clear_admins` to remove all admins from the admin list.
+    /// Only the boss can call this instruction to clear all admins.
+    pub fn clear_admins(ctx: Context<ClearAdmins>) -> Result<()> {
+        state_operations::clear_admins(ctx)
+    }
+
+    /// Gets one page of the configured admins.
+    ///
+    /// Delegates to `state_operations::get_admins`.
+    /// This is a read-only instruction that returns the non-empty admin slots,
+    /// `ADMINS_PAGE_SIZE` entries per page, via return data.
+    /// Emits a `GetAdminsEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetAdmins`.
+    /// - `page`: Zero-based page index.
+    ///
+    /// # Returns
+    /// - `Ok(page)`: The requested `AdminsPage` with pagination metadata
+    pub fn get_admins(ctx: Context<GetAdmins>, page: u8) -> Result<AdminsPage> {
+        state_operations::get_admins(ctx, page)
+    }
+
+    /// Transfers mint authority from the boss to a program-derived PDA.
+    ///
+    /// Delegates to `mint_authority::transfer_mint_authority_to_program`.
+    /// Only the boss can call this instruction to transfer mint authority for a specific token.
+    /// The PDA is derived from the MINT_AUTHORITY seed and can later be used to mint tokens.
+    /// Emits a `MintAuthorityTransferredToProgramEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TransferMintAuthorityToProgram`.
+    pub fn transfer_mint_authority_to_program(
+        ctx: Context<TransferMintAuthorityToProgram>,
+    ) -> Result<()> {
+        mint_authority::transfer_mint_authority_to_program(ctx)
+    }
+
+    /// Transfers mint authority from a program-derived PDA back to the boss.
+    ///
+    /// Delegates to `mint_authority::transfer_mint_authority_to_boss`.
+    /// Only the boss can call this instruction to recover mint authority for a specific token.
+    /// This serves as an emergency recovery mechanism.
+    /// Emits a `MintAuthorityTransferredToBossEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TransferMintAuthorityToBoss`.
+    pub fn transfer_mint_authority_to_boss(
+        ctx: Context<TransferMintAuthorityToBoss>,
+    ) -> Result<()> {
+        mint_authority::transfer_mint_authority_to_boss(ctx)
+    }
+
+    /// Enables or disables the kill switch.
+    ///
+    /// Delegates to `kill_switch::kill_switch` to change the kill switch state.
+    /// When enabled (true), the kill switch can halt critical program operations.
+    /// When disabled (false), normal program operations can proceed.
+    /// An enabled kill switch with a duration lapses on its own once it has passed.
+    ///
+    /// Access control:
+    /// - Both boss and admins can enable the kill switch
+    /// - Only the boss can disable the kill switch
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `KillSwitch`.
+    /// - `enable`: True to enable the kill switch, false to disable it.
+    /// - `duration`: Optional number of seconds after which an enabled kill switch expires.
+    pub fn set_kill_switch(
+        ctx: Context<SetKillSwitch>,
+        enable: bool,
+        duration: Option<u64>,
+    ) -> Result<()> {
+        state_operations::set_kill_switch(ctx, enable, duration)
+    }
+
+    /// Sets the Onyc mint in the state.
+    ///
+    /// Delegates to `state_operations::set_onyc_mint` to change the Onyc mint.
+    /// Only the boss can call this instruction to set the Onyc mint.
+    /// Emits a `OnycMintSetEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetOnycMint`.
+    pub fn set_onyc_mint(ctx: Context<SetOnycMint>) -> Result<()> {
+        state_operations::set_onyc_mint(ctx)
+    }
+
+    /// Sets the redemption admin in the state.
+    ///
+    /// Delegates to `state_operations::set_redemption_admin` to change the redemption admin.
+    /// Only the boss can call this instruction to set the redemption admin.
+    /// Emits a `RedemptionAdminUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionAdmin`.
+    /// - `new_redemption_admin`: Public key of the new redemption admin.
+    pub fn set_redemption_admin(
+        ctx: Context<SetRedemptionAdmin>,
+        new_redemption_admin: Pubkey,
+    ) -> Result<()> {
+        state_operations::set_redemption_admin(ctx, new_redemption_admin)
+    }
+
+    /// Sets the guardian in the state.
+    ///
+    /// Delegates to `state_operations::set_guardian` to change the guardian.
+    /// The guardian co-signs emergency clawbacks with the boss.
+    /// Only the boss can call this instruction to set the guardian, together with
+    /// the current guardian once one is set.
+    /// Emits a `GuardianUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetGuardian`.
+    /// - `new_guardian`: Public key of the new guardian.
+    pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        state_operations::set_guardian(ctx, new_guardian)
+    }
+
+    /// Blocks or unblocks approvals carrying a jurisdiction code.
+    ///
+    /// Delegates to `state_operations::set_jurisdiction_blocked` to update the blocked
+    /// jurisdiction bitmap in the state.
+    /// Only the boss can call this instruction.
+    /// Emits a `JurisdictionBlockUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetJurisdictionBlocked`.
+    /// - `jurisdiction`: Jurisdiction code to update.
+    /// - `blocked`: Whether approvals carrying the code are rejected.
+    pub fn set_jurisdiction_blocked(
+        ctx: Context<SetJurisdictionBlocked>,
+        jurisdiction: u16,
+        blocked: bool,
+    ) -> Result<()> {
+        state_operations::set_jurisdiction_blocked(ctx, jurisdiction, blocked)
+    }
+
+    /// Sets the program screening takes in the state.
+    ///
+    /// Delegates to `state_operations::set_screening_program` to change the screening
+    /// program whose `check(user, amount)` instruction takes must pass.
+    /// Only the boss can call this instruction.
+    /// Emits a `ScreeningProgramUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetScreeningProgram`.
+    /// - `new_screening_program`: Screening program, or the default pubkey to disable screening.
+    pub fn set_screening_program(
+        ctx: Context<SetScreeningProgram>,
+        new_screening_program: Pubkey,
+    ) -> Result<()> {
+        state_operations::set_screening_program(ctx, new_screening_program)
+    }
+
+    /// Sets the primary offer in the state.
+    ///
+    /// Delegates to `state_operations::set_primary_offer` to designate the offer whose
+    /// NAV prices the whole ONyc supply in `get_global_tvl`.
+    /// Only the boss can call this instruction to set the primary offer.
+    /// Emits a `PrimaryOfferUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetPrimaryOffer`.
+    pub fn set_primary_offer(ctx: Context<SetPrimaryOffer>) -> Result<()> {
+        state_operations::set_primary_offer(ctx)
+    }
+
+    /// Mints ONyc tokens to the boss's account.
+    ///
+    /// Delegates to `state_operations::mint_to` to mint ONyc tokens.
+    /// Only the boss can call this instruction to mint ONyc tokens to their account.
+    /// The program must have mint authority for the ONyc token.
+    /// Emits a `OnycTokensMinted` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MintTo`.
+    /// - `amount`: Amount of ONyc tokens to mint.
+    pub fn mint_to(ctx: Context<MintTo>, amount: u64) -> Result<()> {
+        mint_authority::mint_to(ctx, amount)
+    }
+
+    /// Registers an external program as a mint delegate or updates its mint cap.
+    ///
+    /// Delegates to `mint_authority::configure_mint_delegate`.
+    /// Only the boss can call this instruction.
+    /// Emits a `MintDelegateConfiguredEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureMintDelegate`.
+    /// - `delegate_program`: Program ID of the delegate program.
+    /// - `mint_cap`: Maximum ONyc the delegate may mint over its lifetime.
+    pub fn configure_mint_delegate(
+        ctx: Context<ConfigureMintDelegate>,
+        delegate_program: Pubkey,
+        mint_cap: u64,
+    ) -> Result<()> {
+        mint_authority::configure_mint_delegate(ctx, delegate_program, mint_cap)
+    }
+
+    /// Removes a program from the mint delegate registry.
+    ///
+    /// Delegates to `mint_authority::remove_mint_delegate`.
+    /// Only the boss can call this instruction.
+    /// Emits a `MintDelegateRemovedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveMintDelegate`.
+    pub fn remove_mint_delegate(ctx: Context<RemoveMintDelegate>) -> Result<()> {
+        mint_authority::remove_mint_delegate(ctx)
+    }
+
+    /// Mints ONyc on behalf of a registered delegate program.
+    ///
+    /// Delegates to `mint_authority::mint_to_via_delegate`.
+    /// Called through CPI by the delegate program signing with its delegate authority PDA.
+    /// Mints count against the delegate's cap, the mint throttle and the max supply.
+    /// Emits a `OnycMintedViaDelegateEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MintToViaDelegate`.
+    /// - `amount`: Amount of ONyc tokens to mint.
+    pub fn mint_to_via_delegate(ctx: Context<MintToViaDelegate>, amount: u64) -> Result<()> {
+        mint_authority::mint_to_via_delegate(ctx, amount)
+    }
+
+    /// Registers a yield token to be managed alongside ONyc.
+    ///
+    /// Delegates to `mint_authority::add_managed_mint`.
+    /// Only the boss can call this instruction.
+    /// Emits a `ManagedMintAddedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddManagedMint`.
+    /// - `max_supply`: Maximum supply of the token in base units (0 = no cap).
+    pub fn add_managed_mint(ctx: Context<AddManagedMint>, max_supply: u64) -> Result<()> {
+        mint_authority::add_managed_mint(ctx, max_supply)
+    }
+
+    /// Updates the supply cap of a managed token.
+    ///
+    /// Delegates to `mint_authority::update_managed_mint`.
+    /// Only the boss can call this instruction.
+    /// Emits a `ManagedMintUpdatedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `UpdateManagedMint`.
+    /// - `max_supply`: New maximum supply of the token in base units (0 = no cap).
+    pub fn update_managed_mint(ctx: Context<UpdateManagedMint>, max_supply: u64) -> Result<()> {
+        mint_authority::update_managed_mint(ctx, max_supply)
+    }
+
+    /// Removes a token from the managed mint registry.
+    ///
+    /// Delegates to `mint_authority::remove_managed_mint`.
+    /// Only the boss can call this instruction.
+    /// Emits a `ManagedMintRemovedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveManagedMint`.
+    pub fn remove_managed_mint(ctx: Context<RemoveManagedMint>) -> Result<()> {
+        mint_authority::remove_managed_mint(ctx)
+    }
+
+    /// Mints tokens of a managed token to the boss's account.
+    ///
+    /// Delegates to `mint_authority::mint_managed_to`.
+    /// Mints count against the mint throttle and the token's supply cap.
+    /// Emits a `ManagedTokensMintedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MintManagedTo`.
+    /// - `amount`: Amount of tokens to mint in base units.
+    pub fn mint_managed_to(ctx: Context<MintManagedTo>, amount: u64) -> Result<()> {
+        mint_authority::mint_managed_to(ctx, amount)
+    }
+
+    /// Registers a token mint as an ONyc share class.
+    ///
+    /// Delegates to `share_class::add_share_class`.
+    /// Only the boss can register share classes.
+    /// Emits a `ShareClassAddedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddShareClass`.
+    /// - `conversion_rate`: Book value of one whole class token with scale=9.
+    /// - `max_supply`: Maximum supply of the class in base units (0 = no cap).
+    pub fn add_share_class(
+        ctx: Context<AddShareClass>,
+        conversion_rate: u64,
+        max_supply: u64,
+    ) -> Result<()> {
+        share_class::add_share_class(ctx, conversion_rate, max_supply)
+    }
+
+    /// Updates the conversion rate and supply cap of a share class.
+    ///
+    /// Delegates to `share_class::update_share_class`.
+    /// Only the boss can update share classes.
+    /// Emits a `ShareClassUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `UpdateShareClass`.
+    /// - `conversion_rate`: New book value of one whole class token with scale=9.
+    /// - `max_supply`: New maximum supply of the class in base units (0 = no cap).
+    pub fn update_share_class(
+        ctx: Context<UpdateShareClass>,
+        conversion_rate: u64,
+        max_supply: u64,
+    ) -> Result<()> {
+        share_class::update_share_class(ctx, conversion_rate, max_supply)
+    }
+
+    /// Converts tokens between two registered share classes.
+    ///
+    /// Delegates to `share_class::convert_share_class`.
+    /// Burns the source class tokens and mints the destination class amount with
+    /// the same book value using the program mint authority.
+    /// Emits a `ShareClassConvertedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConvertShareClass`.
+    /// - `amount`: Amount of source class tokens to convert in base units.
+    pub fn convert_share_class(ctx: Context<ConvertShareClass>, amount: u64) -> Result<()> {
+        share_class::convert_share_class(ctx, amount)
+    }
+
+    /// Gets the current NAV (price) for a specific offer.
+    ///
+    /// Delegates to `market_info::get_nav`.
+    /// This is a read-only instruction that calculates and returns the current price
+    /// for an offer based on its time vectors and APR parameters.
+    /// Emits a `GetNAVEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetNAV`.
+    ///
+    /// # Returns
+    /// - `Ok(current_price)`: The calculated current price (mantissa) for the offer with scale=9
+    pub fn get_nav(ctx: Context<GetNAV>) -> Result<u64> {
+        market_info::get_nav(ctx)
+    }
+
+    /// Gets the NAV (price) of a specific offer at a given past or future timestamp.
+    ///
+    /// Delegates to `market_info::get_nav_at`.
+    /// This is a read-only instruction that evaluates the offer's current vector set
+    /// at the supplied timestamp and fails if no vector covers it.
+    /// Emits a `GetNAVAtEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetNAVAt`.
+    /// - `unix_ts`: Unix timestamp to evaluate the NAV at.
+    ///
+    /// # Returns
+    /// - `Ok(price)`: The price (mantissa) of the offer at `unix_ts` with scale=9
+    pub fn get_nav_at(ctx: Context<GetNAVAt>, unix_ts: u64) -> Result<u64> {
+        market_info::get_nav_at(ctx, unix_ts)
+    }
+
+    /// Gets the current APY (Annual Percentage Yield) for a specific offer.
+    ///
+    /// Delegates to `market_info::get_apy`.
+    /// This is a read-only instruction that calculates and returns the current APY
+    /// by compounding the stored APR at the requested frequency, daily by default.
+    /// Emits a `GetAPYEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetAPY`.
+    /// - `compounding_periods_per_year`: Compounding periods per year, 365 if not given.
+    ///
+    /// # Returns
+    /// - `Ok(apy)`: The calculated signed APY scaled by 1_000_000 (returns the mantissa, with scale=6)
+    pub fn get_apy(ctx: Context<GetAPY>, compounding_periods_per_year: Option<u32>) -> Result<i64> {
+        market_info::get_apy(ctx, compounding_periods_per_year)
+    }
+
+    /// Gets the NAV adjustment (price change) for a specific offer.
+    ///
+    /// Delegates to `market_info::get_nav_adjustment`.
+    /// This is a read-only instruction that calculates the price difference
+    /// between the current vector and the previous vector at the current time.
+    /// Returns a signed integer representing the price change.
+    /// Emits a `GetNavAdjustmentEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetNavAdjustment`.
+    ///
+    /// # Returns
+    /// - `Ok(adjustment)`: The calculated price adjustment (current - previous) as a signed integer,
+    /// returns the mantissa with scale=9
+    pub fn get_nav_adjustment(ctx: Context<GetNavAdjustment>) -> Result<i64> {
+        market_info::get_nav_adjustment(ctx)
+    }
+
+    /// Gets the current TVL (Total Value Locked) for a specific offer with 9 decimal precision
     ///
     /// Delegates to `market_info::get_tvl`.
     /// This is a read-only instruction that calculates and returns the current TVL
@@ -451,75 +1462,447 @@ pub mod onreapp {
     /// Emits a `GetTVLEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `GetTVL`.
+    /// - `ctx`: Context for `GetTVL`.
+    ///
+    /// # Returns
+    /// - `Ok(tvl)`: The calculated TVL (mantissa) for the offer with scale=9
+    pub fn get_tvl(ctx: Context<GetTVL>) -> Result<u64> {
+        market_info::get_tvl(ctx)
+    }
+
+    /// Delegates to `market_info::get_circulating_supply`.
+    /// This is a read-only instruction that calculates and returns the current circulating supply
+    /// for an offer based on the total token supply minus the offer and redemption vault amounts.
+    /// circulating_supply = total_supply - offer_vaults - redemption_vault
+    /// Per-offer vaults are passed as remaining accounts, the offer and its ONyc vault account each.
+    /// Emits a `GetCirculatingSupplyEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetCirculatingSupply`, with the per-offer vaults as remaining accounts.
+    ///
+    /// # Returns
+    /// - `Ok(circulating_supply)`: The calculated circulating supply for the offer in base units
+    pub fn get_circulating_supply<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetCirculatingSupply<'info>>,
+    ) -> Result<u64> {
+        market_info::get_circulating_supply(ctx)
+    }
+
+    /// Gets how much token_in the offer vault can still fill at the current NAV.
+    ///
+    /// Delegates to `market_info::get_vault_runway`.
+    /// This is a read-only instruction that prices the vault's token_out balance
+    /// as an exact-out take, including the offer fee.
+    /// Emits a `GetVaultRunwayEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetVaultRunway`.
+    ///
+    /// # Returns
+    /// - `Ok(token_in_capacity)`: The token_in takes can pay before the vault is empty, in base units
+    pub fn get_vault_runway(ctx: Context<GetVaultRunway>) -> Result<u64> {
+        market_info::get_vault_runway(ctx)
+    }
+
+    /// Gets the TVL of the whole ONyc supply.
+    ///
+    /// Delegates to `market_info::get_global_tvl`.
+    /// This is a read-only instruction that values all ONyc outside the offer and
+    /// redemption vaults at the current NAV of the primary offer.
+    /// Per-offer vaults are passed as remaining accounts, the offer and its ONyc vault account each.
+    /// Emits a `GetGlobalTVLEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetGlobalTVL`, with the per-offer vaults as remaining accounts.
+    ///
+    /// # Returns
+    /// - `Ok(tvl)`: The calculated global TVL in base units
+    pub fn get_global_tvl<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetGlobalTVL<'info>>,
+    ) -> Result<u128> {
+        market_info::get_global_tvl(ctx)
+    }
+
+    /// Gets the cumulative take counters of a specific offer.
+    ///
+    /// Delegates to `market_info::get_offer_stats`.
+    /// This is a read-only instruction that returns the total token_in fees collected
+    /// and the total token_in volume of all takes of the offer via return data.
+    /// Emits a `GetOfferStatsEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetOfferStats`.
+    ///
+    /// # Returns
+    /// - `Ok(stats)`: The cumulative fee and volume counters in token_in base units
+    pub fn get_offer_stats(ctx: Context<GetOfferStats>) -> Result<OfferStats> {
+        market_info::get_offer_stats(ctx)
+    }
+
+    /// Gets a user's position in the token_out mint of an offer.
+    ///
+    /// Delegates to `market_info::get_user_position`.
+    /// This is a read-only instruction that returns the user's cumulative purchases and
+    /// redemptions, average entry price, cost basis and unrealized P&L at the current
+    /// price via return data.
+    /// Emits a `GetUserPositionEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetUserPosition`.
+    ///
+    /// # Returns
+    /// - `Ok(position)`: The position valued at the current price
+    pub fn get_user_position(ctx: Context<GetUserPosition>) -> Result<UserPositionInfo> {
+        market_info::get_user_position(ctx)
+    }
+
+    /// Gets the configuration, active pricing vector and current NAV of an offer.
+    ///
+    /// Delegates to `market_info::view_offer`.
+    /// This is a read-only instruction that returns a typed `OfferView` via return
+    /// data, so clients can decode it through the IDL.
+    /// Emits a `ViewOfferEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ViewOffer`.
+    ///
+    /// # Returns
+    /// - `Ok(view)`: The offer's configuration and pricing
+    pub fn view_offer(ctx: Context<ViewOffer>) -> Result<OfferView> {
+        market_info::view_offer(ctx)
+    }
+
+    /// Gets the time-weighted average NAV of a specific offer.
+    ///
+    /// Delegates to `market_info::get_twap`.
+    /// This is a read-only instruction that averages the offer's recorded NAV
+    /// observations over a trailing window, limited to the available history.
+    /// Emits a `GetTWAPEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetTWAP`.
+    /// - `window_secs`: Length of the trailing averaging window in seconds.
+    ///
+    /// # Returns
+    /// - `Ok(twap)`: The time-weighted average NAV with scale=9
+    pub fn get_twap(ctx: Context<GetTWAP>, window_secs: u64) -> Result<u64> {
+        market_info::get_twap(ctx, window_secs)
+    }
+
+    /// Emits a composite market snapshot of a specific offer.
+    ///
+    /// Delegates to `market_info::snapshot_market_info`.
+    /// Calculates the NAV, APY, TVL and circulating token_out supply of the offer and
+    /// emits them in a single `MarketSnapshotEvent` so indexers can build history from
+    /// transaction logs.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SnapshotMarketInfo`.
+    pub fn snapshot_market_info(ctx: Context<SnapshotMarketInfo>) -> Result<()> {
+        market_info::snapshot_market_info(ctx)
+    }
+
+    /// Adds a trusted authority for approval verification.
+    ///
+    /// This instruction allows the boss to add an approver to one of the two available
+    /// approver slots. If both slots are already filled, the instruction will fail.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddApprover`.
+    /// - `approver`: Public key of the approver to add.
+    pub fn add_approver(ctx: Context<AddApprover>, approver: Pubkey) -> Result<()> {
+        state_operations::add_approver(ctx, approver)
+    }
+
+    /// Removes a trusted authority from approval verification.
+    ///
+    /// This instruction allows the boss to remove an approver by their public key.
+    /// The approver must exist in either approver1 or approver2 slot, otherwise
+    /// the instruction will fail.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveApprover`.
+    /// - `approver`: Public key of the approver to remove.
+    pub fn remove_approver(ctx: Context<RemoveApprover>, approver: Pubkey) -> Result<()> {
+        state_operations::remove_approver(ctx, approver)
+    }
+
+    /// Adds an address to the sanctioned-address blocklist.
+    ///
+    /// Delegates to `state_operations::add_blocked_address`, creating the address's
+    /// blocklist bucket if needed. Blocked addresses cannot take offers or request
+    /// redemptions.
+    /// Only the boss or an admin can call this instruction.
+    /// Emits a `BlockedAddressAddedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddBlockedAddress`.
+    /// - `address`: Public key of the address to block.
+    pub fn add_blocked_address(ctx: Context<AddBlockedAddress>, address: Pubkey) -> Result<()> {
+        state_operations::add_blocked_address(ctx, address)
+    }
+
+    /// Removes an address from the sanctioned-address blocklist.
+    ///
+    /// Delegates to `state_operations::remove_blocked_address`.
+    /// Only the boss or an admin can call this instruction.
+    /// Emits a `BlockedAddressRemovedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveBlockedAddress`.
+    /// - `address`: Public key of the address to unblock.
+    pub fn remove_blocked_address(
+        ctx: Context<RemoveBlockedAddress>,
+        address: Pubkey,
+    ) -> Result<()> {
+        state_operations::remove_blocked_address(ctx, address)
+    }
+
+    /// Gets the configured approval authorities.
+    ///
+    /// Delegates to `state_operations::get_approvers`.
+    /// This is a read-only instruction that returns the non-empty approver slots
+    /// via return data.
+    /// Emits a `GetApproversEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `GetApprovers`.
     ///
     /// # Returns
-    /// - `Ok(tvl)`: The calculated TVL (mantissa) for the offer with scale=9
-    pub fn get_tvl(ctx: Context<GetTVL>) -> Result<u64> {
-        market_info::get_tvl(ctx)
+    /// - `Ok(approvers)`: The configured approvers (zero to two entries)
+    pub fn get_approvers(ctx: Context<GetApprovers>) -> Result<Vec<Pubkey>> {
+        state_operations::get_approvers(ctx)
     }
 
-    /// Delegates to `market_info::get_circulating_supply`.
-    /// This is a read-only instruction that calculates and returns the current circulating supply
-    /// for an offer based on the total token supply minus the vault amount.
-    /// circulating_supply = total_supply - vault_amount
-    /// Emits a `GetCirculatingSupplyEvent` upon success.
+    /// Gets the governance and configuration settings of the program.
+    ///
+    /// Delegates to `state_operations::view_state`.
+    /// This is a read-only instruction that returns a typed `StateView` via return
+    /// data, so clients can decode it through the IDL.
+    /// Emits a `ViewStateEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `GetCirculatingSupply`.
+    /// - `ctx`: Context for `ViewState`.
     ///
     /// # Returns
-    /// - `Ok(circulating_supply)`: The calculated circulating supply for the offer in base units
-    pub fn get_circulating_supply(ctx: Context<GetCirculatingSupply>) -> Result<u64> {
-        market_info::get_circulating_supply(ctx)
+    /// - `Ok(view)`: The program's governance and configuration settings
+    pub fn view_state(ctx: Context<ViewState>) -> Result<StateView> {
+        state_operations::view_state(ctx)
     }
 
-    /// Adds a trusted authority for approval verification.
+    /// Approves a Token-2022 transfer-hook mint for use as token_in.
     ///
-    /// This instruction allows the boss to add an approver to one of the two available
-    /// approver slots. If both slots are already filled, the instruction will fail.
+    /// Delegates to `state_operations::approve_transfer_hook_mint`.
+    /// This instruction allows the boss to allowlist a mint whose transfers invoke a hook
+    /// program. The approval is bound to the mint's current hook program.
+    /// Emits a `TransferHookMintApprovedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `AddApprover`.
-    /// - `approver`: Public key of the approver to add.
-    pub fn add_approver(ctx: Context<AddApprover>, approver: Pubkey) -> Result<()> {
-        state_operations::add_approver(ctx, approver)
+    /// - `ctx`: Context for `ApproveTransferHookMint`.
+    pub fn approve_transfer_hook_mint(ctx: Context<ApproveTransferHookMint>) -> Result<()> {
+        state_operations::approve_transfer_hook_mint(ctx)
     }
 
-    /// Removes a trusted authority from approval verification.
+    /// Revokes the approval of a transfer-hook mint.
     ///
-    /// This instruction allows the boss to remove an approver by their public key.
-    /// The approver must exist in either approver1 or approver2 slot, otherwise
-    /// the instruction will fail.
+    /// Delegates to `state_operations::revoke_transfer_hook_mint`.
+    /// This instruction allows the boss to close a mint's approval, rejecting further
+    /// takes paying with it. Emits a `TransferHookMintRevokedEvent` upon success.
     ///
     /// # Arguments
-    /// - `ctx`: Context for `RemoveApprover`.
-    /// - `approver`: Public key of the approver to remove.
-    pub fn remove_approver(ctx: Context<RemoveApprover>, approver: Pubkey) -> Result<()> {
-        state_operations::remove_approver(ctx, approver)
+    /// - `ctx`: Context for `RevokeTransferHookMint`.
+    pub fn revoke_transfer_hook_mint(ctx: Context<RevokeTransferHookMint>) -> Result<()> {
+        state_operations::revoke_transfer_hook_mint(ctx)
     }
 
     /// Configures the maximum supply cap for ONyc token minting.
     ///
     /// Delegates to `state_operations::configure_max_supply`.
-    /// This instruction allows the boss to set or update the maximum supply cap
-    /// that restricts ONyc token minting. Setting to 0 removes the cap.
+    /// This instruction allows the boss to set or lower the maximum supply cap
+    /// that restricts ONyc token minting. Raising or removing the cap requires
+    /// `propose_max_supply` and `accept_max_supply`.
     /// Emits a `MaxSupplyConfigured` event upon success.
     ///
     /// # Arguments
     /// - `ctx`: Context for `ConfigureMaxSupply`.
-    /// - `max_supply`: The maximum supply cap in base units (0 = no cap).
+    /// - `max_supply`: The maximum supply cap in base units.
     pub fn configure_max_supply(ctx: Context<ConfigureMaxSupply>, max_supply: u64) -> Result<()> {
         state_operations::configure_max_supply(ctx, max_supply)
     }
 
+    /// Proposes a new maximum supply cap for ONyc token minting.
+    ///
+    /// Delegates to `state_operations::propose_max_supply`.
+    /// The proposal can be accepted once `MAX_SUPPLY_TIMELOCK_SECS` have passed.
+    /// Only the boss can call this instruction.
+    /// Emits a `MaxSupplyProposedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ProposeMaxSupply`.
+    /// - `max_supply`: The proposed maximum supply cap in base units (0 = no cap).
+    pub fn propose_max_supply(ctx: Context<ProposeMaxSupply>, max_supply: u64) -> Result<()> {
+        state_operations::propose_max_supply(ctx, max_supply)
+    }
+
+    /// Applies a pending maximum supply proposal after its timelock.
+    ///
+    /// Delegates to `state_operations::accept_max_supply`.
+    /// Only the boss can call this instruction.
+    /// Emits a `MaxSupplyConfigured` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AcceptMaxSupply`.
+    pub fn accept_max_supply(ctx: Context<AcceptMaxSupply>) -> Result<()> {
+        state_operations::accept_max_supply(ctx)
+    }
+
+    /// Configures the rolling limit on ONyc minted by the boss.
+    ///
+    /// Delegates to `state_operations::configure_mint_throttle`.
+    /// `mint_to` consumes from the budget. Loosening the throttle requires the guardian
+    /// to co-sign when a guardian is set.
+    /// Emits a `MintThrottleConfiguredEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureMintThrottle`.
+    /// - `max_mint_per_window`: Maximum ONyc mintable per window in base units (0 = no limit).
+    /// - `window_secs`: Length of the rolling window in seconds.
+    pub fn configure_mint_throttle(
+        ctx: Context<ConfigureMintThrottle>,
+        max_mint_per_window: u64,
+        window_secs: u64,
+    ) -> Result<()> {
+        state_operations::configure_mint_throttle(ctx, max_mint_per_window, window_secs)
+    }
+
+    /// Configures the maximum NAV jump allowed between consecutive offer vectors.
+    ///
+    /// Delegates to `state_operations::configure_max_nav_jump`.
+    /// This instruction allows the boss to bound how far a new vector's activation price
+    /// may deviate from the preceding vector's price at that time. Setting to 0 disables the check.
+    /// Emits a `MaxNavJumpConfiguredEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureMaxNavJump`.
+    /// - `max_nav_jump_bps`: The maximum allowed deviation in basis points (0 = no limit).
+    pub fn configure_max_nav_jump(
+        ctx: Context<ConfigureMaxNavJump>,
+        max_nav_jump_bps: u16,
+    ) -> Result<()> {
+        state_operations::configure_max_nav_jump(ctx, max_nav_jump_bps)
+    }
+
+    /// Configures the take throttling applied while redemptions are under stress.
+    ///
+    /// Delegates to `state_operations::configure_redemption_stress`.
+    /// This instruction allows the boss to limit or pause takes while outstanding redemptions
+    /// exceed a share of the redemption vault's coverage. Setting the threshold to 0 disables it.
+    /// Emits a `RedemptionStressConfiguredEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureRedemptionStress`.
+    /// - `threshold_bps`: Outstanding redemptions to vault coverage in basis points (0 = disabled).
+    /// - `stressed_take_max_token_in`: Maximum token_in per take while stressed (0 = takes paused).
+    pub fn configure_redemption_stress(
+        ctx: Context<ConfigureRedemptionStress>,
+        threshold_bps: u16,
+        stressed_take_max_token_in: u64,
+    ) -> Result<()> {
+        state_operations::configure_redemption_stress(
+            ctx,
+            threshold_bps,
+            stressed_take_max_token_in,
+        )
+    }
+
+    /// Creates the program-wide configuration account.
+    ///
+    /// Delegates to `state_operations::initialize_config`.
+    /// Initializes the fee cap, approval validity limit and seconds per year with
+    /// the defaults formerly compiled into the program.
+    /// Emits a `ConfigInitializedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `InitializeConfig`.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        state_operations::initialize_config(ctx)
+    }
+
+    /// Updates the program-wide configuration.
+    ///
+    /// Delegates to `state_operations::set_config`.
+    /// Each tunable is validated against its allowed range.
+    /// Emits a `ConfigUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetConfig`.
+    /// - `max_fee_bps`: Maximum fee in basis points offers and redemption offers may charge.
+    /// - `max_approval_validity_secs`: Maximum approval validity in seconds (0 = no limit).
+    /// - `seconds_per_year`: Seconds per year over which vector APRs accrue.
+    /// - `clock_skew_grace_secs`: Grace in seconds for clock skew on approval expiries.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn set_config(
+        ctx: Context<SetConfig>,
+        max_fee_bps: u16,
+        max_approval_validity_secs: u64,
+        seconds_per_year: u64,
+        clock_skew_grace_secs: u64,
+    ) -> Result<()> {
+        state_operations::set_config(
+            ctx,
+            max_fee_bps,
+            max_approval_validity_secs,
+            seconds_per_year,
+            clock_skew_grace_secs,
+        )
+    }
+
+    /// Upgrades the program state to a newer schema version.
+    ///
+    /// Delegates to `state_operations::migrate_state`.
+    /// Runs the upgrade step of every version between the stored and the target one.
+    /// Emits a `StateMigratedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MigrateState`.
+    /// - `target_version`: Schema version to migrate to.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn migrate_state(ctx: Context<MigrateState>, target_version: u16) -> Result<()> {
+        state_operations::migrate_state(ctx, target_version)
+    }
+
+    /// Proposes closing the program state account.
+    ///
+    /// Delegates to `state_operations::propose_close_state`.
+    /// The state can be closed with `close_state` once `CLOSE_STATE_TIMELOCK_SECS`
+    /// have passed. Requires the kill switch to be enabled.
+    /// Emits a `StateClosureProposedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ProposeCloseState`.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn propose_close_state(ctx: Context<ProposeCloseState>) -> Result<()> {
+        state_operations::propose_close_state(ctx)
+    }
+
     /// Closes the program state account and returns the rent to the boss.
     ///
     /// Delegates to `state_operations::close_state`.
     /// This instruction permanently deletes the program's main state account
     /// and transfers its rent balance back to the boss. Once closed, the state
     /// cannot be recovered and the program becomes effectively non-functional.
-    /// Only the boss can call this instruction.
+    /// Only the boss can call this instruction, once the closure proposed with
+    /// `propose_close_state` has passed its timelock and while the kill switch is
+    /// enabled. Offer PDAs passed as remaining accounts must no longer exist.
     /// Emits a `StateClosedEvent` upon success.
     ///
     /// # Warning
@@ -557,6 +1940,19 @@ pub mod onreapp {
         redemption::make_redemption_offer(ctx, fee_basis_points)
     }
 
+    /// Migrates a legacy redemption offer account to the current layout.
+    ///
+    /// Delegates to `redemption::migrate_redemption_offer`.
+    /// Grows redemption offers created before per-slot request ordering was recorded,
+    /// zero-initializing the new fields. The boss pays the extra rent.
+    /// Emits a `RedemptionOfferMigratedEvent` event upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MigrateRedemptionOffer`.
+    pub fn migrate_redemption_offer(ctx: Context<MigrateRedemptionOffer>) -> Result<()> {
+        redemption::migrate_redemption_offer(ctx)
+    }
+
     /// Creates a redemption request.
     ///
     /// Delegates to `redemption::create_redemption_request`.
@@ -582,6 +1978,7 @@ pub mod onreapp {
     /// - Burns token_in (ONyc) if program has mint authority, else sends to boss
     /// - Mints token_out if program has mint authority, else transfers from vault
     /// - Uses current price from the underlying offer to calculate token_out amount
+    /// - Pays out in token_out_mint or in an allowlisted settlement mint of the offer
     /// Emits a `RedemptionRequestFulfilledEvent` upon success.
     ///
     /// # Arguments
@@ -593,6 +1990,28 @@ pub mod onreapp {
         redemption::fulfill_redemption_request(ctx)
     }
 
+    /// Fulfills a redemption request across several payout mints.
+    ///
+    /// Delegates to `redemption::fulfill_redemption_request_basket`.
+    /// Splits the payout over the offer's token_out_mint and allowlisted settlement mints
+    /// passed as remaining accounts, pro-rata to vault balances or by explicit per-leg
+    /// token_in amounts, all valued at the same NAV. In-kind offers pay out their
+    /// redemption basket by its weights.
+    /// Emits a `RedemptionRequestBasketFulfilledEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `FulfillRedemptionRequestBasket`.
+    /// - `leg_token_in_amounts`: Net token_in share per leg, or empty for a pro-rata split.
+    ///
+    /// # Access Control
+    /// - Only redemption_admin can fulfill redemptions
+    pub fn fulfill_redemption_request_basket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FulfillRedemptionRequestBasket<'info>>,
+        leg_token_in_amounts: Vec<u64>,
+    ) -> Result<()> {
+        redemption::fulfill_redemption_request_basket(ctx, leg_token_in_amounts)
+    }
+
     /// Cancels a redemption request.
     ///
     /// Delegates to `redemption::cancel_redemption_request`.
@@ -612,6 +2031,63 @@ pub mod onreapp {
         redemption::cancel_redemption_request(ctx)
     }
 
+    /// Cancels all redemption requests of the signer passed in remaining accounts.
+    ///
+    /// Delegates to `redemption::cancel_all_redemption_requests`.
+    /// Returns the locked tokens of all requests to the redeemer in one transfer and
+    /// closes the request accounts.
+    /// Emits a `RedemptionRequestCancelledEvent` for each cancelled request.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `CancelAllRedemptionRequests`.
+    ///
+    /// # Returns
+    /// - Number of redemption requests cancelled
+    ///
+    /// # Access Control
+    /// - Only the redeemer of all passed requests
+    pub fn cancel_all_redemption_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelAllRedemptionRequests<'info>>,
+    ) -> Result<u32> {
+        redemption::cancel_all_redemption_requests(ctx)
+    }
+
+    /// Assigns an unfulfilled redemption request to another redeemer.
+    ///
+    /// Delegates to `redemption::assign_redemption_request`.
+    /// Future payouts and refunds of the request go to the new redeemer.
+    /// Emits a `RedemptionRequestAssignedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AssignRedemptionRequest`.
+    /// - `new_redeemer`: Wallet the request is assigned to.
+    ///
+    /// # Access Control
+    /// - Only the current redeemer of the request
+    pub fn assign_redemption_request(
+        ctx: Context<AssignRedemptionRequest>,
+        new_redeemer: Pubkey,
+    ) -> Result<()> {
+        redemption::assign_redemption_request(ctx, new_redeemer)
+    }
+
+    /// Closes a redemption offer without pending redemption requests.
+    ///
+    /// Delegates to `redemption::close_redemption_offer`.
+    /// Returns the remaining token_out balance of the redemption vault to the boss and
+    /// closes the redemption offer account, returning its rent to the signer.
+    /// Emits a `RedemptionOfferClosedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `CloseRedemptionOffer`.
+    ///
+    /// # Access Control
+    /// - Only the boss or redemption_admin can call this instruction
+    /// - The redemption offer must have no pending redemption requests
+    pub fn close_redemption_offer(ctx: Context<CloseRedemptionOffer>) -> Result<()> {
+        redemption::close_redemption_offer(ctx)
+    }
+
     /// Updates the fee configuration for a specific redemption offer.
     ///
     /// This instruction allows the boss to modify the fee charged when fulfilling
@@ -629,4 +2105,272 @@ pub mod onreapp {
     ) -> Result<()> {
         redemption::update_redemption_offer_fee(ctx, new_fee_basis_points)
     }
+
+    /// Selects the currency a redemption offer charges its fee in.
+    ///
+    /// Delegates to `redemption::set_redemption_offer_fee_currency`.
+    /// With `TokenOut` the fee is deducted from the payout and paid to the boss in the
+    /// payout mint instead of being taken from the redeemed token_in.
+    /// Emits a `RedemptionOfferFeeCurrencyUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionOfferFeeCurrency`.
+    /// - `fee_currency`: The side of the redemption the fee is charged on.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn set_redemption_offer_fee_currency(
+        ctx: Context<SetRedemptionOfferFeeCurrency>,
+        fee_currency: FeeCurrency,
+    ) -> Result<()> {
+        redemption::set_redemption_offer_fee_currency(ctx, fee_currency)
+    }
+
+    /// Selects when a redemption offer prices its requests.
+    ///
+    /// Delegates to `redemption::set_redemption_offer_price_mode`.
+    /// With `LockedAtRequest` the NAV is locked on each request at creation instead of
+    /// being read at fulfillment.
+    /// Emits a `RedemptionOfferPriceModeUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionOfferPriceMode`.
+    /// - `price_mode`: When the NAV pricing a request is taken.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn set_redemption_offer_price_mode(
+        ctx: Context<SetRedemptionOfferPriceMode>,
+        price_mode: RedemptionPriceMode,
+    ) -> Result<()> {
+        redemption::set_redemption_offer_price_mode(ctx, price_mode)
+    }
+
+    /// Restates the NAV locked on a pending redemption request.
+    ///
+    /// Delegates to `redemption::reprice_redemption_request`.
+    /// Only possible within `REPRICE_WINDOW_SECONDS` of the original lock.
+    /// Emits a `RedemptionRequestRepricedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RepriceRedemptionRequest`.
+    /// - `new_nav`: Restated NAV with scale=9.
+    ///
+    /// # Access Control
+    /// - Boss only, co-signed by the guardian
+    pub fn reprice_redemption_request(
+        ctx: Context<RepriceRedemptionRequest>,
+        new_nav: u64,
+    ) -> Result<()> {
+        redemption::reprice_redemption_request(ctx, new_nav)
+    }
+
+    /// Configures the in-kind redemption basket of a redemption offer.
+    ///
+    /// Delegates to `redemption::configure_redemption_basket`.
+    /// Sets the underlying mints and their weights in basis points paid out by
+    /// in-kind redemptions of the offer.
+    /// Emits a `RedemptionBasketConfiguredEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `ConfigureRedemptionBasket`.
+    /// - `mints`: Underlying mints of the basket.
+    /// - `weights_bps`: Weight of each mint in basis points, summing to 10000.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn configure_redemption_basket(
+        ctx: Context<ConfigureRedemptionBasket>,
+        mints: Vec<Pubkey>,
+        weights_bps: Vec<u16>,
+    ) -> Result<()> {
+        redemption::configure_redemption_basket(ctx, mints, weights_bps)
+    }
+
+    /// Switches a redemption offer to or from in-kind payouts.
+    ///
+    /// Delegates to `redemption::set_redemption_offer_in_kind`.
+    /// In-kind requests are fulfilled in the offer's redemption basket with
+    /// `fulfill_redemption_request_basket`.
+    /// Emits a `RedemptionOfferInKindUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionOfferInKind`.
+    /// - `in_kind`: Whether requests are paid out in kind.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn set_redemption_offer_in_kind(
+        ctx: Context<SetRedemptionOfferInKind>,
+        in_kind: bool,
+    ) -> Result<()> {
+        redemption::set_redemption_offer_in_kind(ctx, in_kind)
+    }
+
+    /// Pauses new redemption requests and fulfillment of a redemption offer independently.
+    ///
+    /// Delegates to `redemption::set_redemption_offer_pause`.
+    /// Cancellations are not affected by either flag.
+    /// Emits a `RedemptionOfferPauseUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionOfferPause`.
+    /// - `pause_new_requests`: Whether new redemption requests are rejected.
+    /// - `pause_fulfillment`: Whether pending redemption requests can't be fulfilled.
+    ///
+    /// # Access Control
+    /// - Boss or redemption_admin
+    pub fn set_redemption_offer_pause(
+        ctx: Context<SetRedemptionOfferPause>,
+        pause_new_requests: bool,
+        pause_fulfillment: bool,
+    ) -> Result<()> {
+        redemption::set_redemption_offer_pause(ctx, pause_new_requests, pause_fulfillment)
+    }
+
+    /// Allows or disallows instant redemptions of a redemption offer and sets their fee.
+    ///
+    /// Delegates to `redemption::set_redemption_offer_instant`.
+    /// Emits a `RedemptionOfferInstantUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `SetRedemptionOfferInstant`.
+    /// - `allow_instant`: Whether users may redeem instantly.
+    /// - `instant_fee_basis_points`: Fee in basis points charged on instant redemptions.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn set_redemption_offer_instant(
+        ctx: Context<SetRedemptionOfferInstant>,
+        allow_instant: bool,
+        instant_fee_basis_points: u16,
+    ) -> Result<()> {
+        redemption::set_redemption_offer_instant(ctx, allow_instant, instant_fee_basis_points)
+    }
+
+    /// Redeems token_in instantly against the funded redemption vault.
+    ///
+    /// Delegates to `redemption::take_redemption_instant`.
+    /// Burns token_in if the program has mint authority, else sends it to the boss,
+    /// and transfers token_out from the redemption vault to the user at the current NAV.
+    /// Emits a `RedemptionInstantTakenEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeRedemptionInstant`.
+    /// - `token_in_amount`: Amount of token_in to redeem, including the instant fee.
+    pub fn take_redemption_instant(
+        ctx: Context<TakeRedemptionInstant>,
+        token_in_amount: u64,
+    ) -> Result<()> {
+        redemption::take_redemption_instant(ctx, token_in_amount)
+    }
+
+    /// Updates the minimum redemption request size for a redemption offer.
+    ///
+    /// Delegates to `redemption::update_redemption_offer_min_amount`.
+    /// With a `Quote` denomination the minimum is a USD amount valued at the underlying
+    /// offer's current NAV instead of a token_in amount.
+    /// Emits a `RedemptionOfferMinAmountUpdatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `UpdateRedemptionOfferMinAmount`.
+    /// - `min_redemption_amount`: Minimum redemption size in the chosen unit (0 = no minimum).
+    /// - `denomination`: Whether the minimum is a token_in amount or a USD amount with scale=9.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn update_redemption_offer_min_amount(
+        ctx: Context<UpdateRedemptionOfferMinAmount>,
+        min_redemption_amount: u64,
+        denomination: MinimumDenomination,
+    ) -> Result<()> {
+        redemption::update_redemption_offer_min_amount(ctx, min_redemption_amount, denomination)
+    }
+
+    /// Allowlists a settlement mint for a redemption offer.
+    ///
+    /// Delegates to `redemption::add_redemption_settlement_mint`.
+    /// Redemption requests of the offer may then be fulfilled in the settlement mint,
+    /// converted at the same NAV using the settlement mint's decimals.
+    /// Emits a `RedemptionSettlementMintAddedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `AddRedemptionSettlementMint`.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn add_redemption_settlement_mint(ctx: Context<AddRedemptionSettlementMint>) -> Result<()> {
+        redemption::add_redemption_settlement_mint(ctx)
+    }
+
+    /// Removes a settlement mint from a redemption offer's allowlist.
+    ///
+    /// Delegates to `redemption::remove_redemption_settlement_mint`.
+    /// Emits a `RedemptionSettlementMintRemovedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `RemoveRedemptionSettlementMint`.
+    /// - `settlement_mint`: The settlement mint to remove.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn remove_redemption_settlement_mint(
+        ctx: Context<RemoveRedemptionSettlementMint>,
+        settlement_mint: Pubkey,
+    ) -> Result<()> {
+        redemption::remove_redemption_settlement_mint(ctx, settlement_mint)
+    }
+
+    /// Creates a dual redemption offer paying two output tokens per redemption.
+    ///
+    /// Delegates to `redemption::make_dual_redemption_offer`.
+    /// The dual redemption offer extends an existing redemption offer with a second
+    /// output token. Both legs use the NAV and fee of the base redemption offer, and
+    /// the net redeemed amount is split between them by `token_out_2_basis_points`.
+    /// Emits a `DualRedemptionOfferCreatedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `MakeDualRedemptionOffer`.
+    /// - `token_out_2_basis_points`: Share of the net redeemed amount paid in token_out_2.
+    ///
+    /// # Access Control
+    /// - Only the boss or redemption_admin can call this instruction
+    pub fn make_dual_redemption_offer(
+        ctx: Context<MakeDualRedemptionOffer>,
+        token_out_2_basis_points: u16,
+    ) -> Result<()> {
+        redemption::make_dual_redemption_offer(ctx, token_out_2_basis_points)
+    }
+
+    /// Redeems token_in instantly for two output tokens.
+    ///
+    /// Delegates to `redemption::take_dual_redemption_offer`.
+    /// Burns token_in if the program has mint authority, else sends it to the boss,
+    /// and mints or transfers both output tokens to the user at the current NAV.
+    /// Emits a `DualRedemptionOfferTakenEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `TakeDualRedemptionOffer`.
+    /// - `token_in_amount`: Amount of token_in to redeem, including the fee.
+    pub fn take_dual_redemption_offer(
+        ctx: Context<TakeDualRedemptionOffer>,
+        token_in_amount: u64,
+    ) -> Result<()> {
+        redemption::take_dual_redemption_offer(ctx, token_in_amount)
+    }
+
+    /// Closes a dual redemption offer.
+    ///
+    /// Delegates to `redemption::close_dual_redemption_offer`.
+    /// Closes the dual redemption offer account and returns its rent to the boss.
+    /// Emits a `DualRedemptionOfferClosedEvent` upon success.
+    ///
+    /// # Arguments
+    /// - `ctx`: Context for `CloseDualRedemptionOffer`.
+    ///
+    /// # Access Control
+    /// - Boss only
+    pub fn close_dual_redemption_offer(ctx: Context<CloseDualRedemptionOffer>) -> Result<()> {
+        redemption::close_dual_redemption_offer(ctx)
+    }
 }
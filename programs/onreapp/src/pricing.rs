@@ -0,0 +1,164 @@
+//! Pure pricing helpers for programs that read offers directly
+//!
+//! `get_nav` and `get_apy` report through return data, which a later CPI in the
+//! same instruction overwrites. Programs depending on this crate with the
+//! `no-entrypoint` feature can instead pass the offer account and compute the
+//! same values locally with the calculations the view instructions use.
+use crate::instructions::market_info::{
+    calculate_apy_from_apr, DEFAULT_COMPOUNDING_PERIODS_PER_YEAR,
+};
+use crate::instructions::offer::offer_utils::{calculate_vector_price_at, find_active_vector_at};
+use crate::instructions::{Offer, PricingMode};
+use crate::OfferCoreError;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Reads an offer from the raw data of its account
+///
+/// The data must be the full account data including the discriminator, as passed
+/// to a program; it is borrowed rather than copied because offers are too large
+/// for the stack. Callers must check that the account is owned by this program.
+///
+/// # Returns
+/// * `Ok(&Offer)` - The offer stored in the account
+/// * `Err(ErrorCode::AccountDiscriminatorMismatch)` - If the data is not an offer
+/// * `Err(ErrorCode::AccountDidNotDeserialize)` - If the data has an outdated layout
+#[allow(dead_code)]
+pub fn offer_from_account_data(data: &[u8]) -> Result<&Offer> {
+    require!(
+        data.len() >= 8 && data[..8] == *Offer::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    bytemuck::try_from_bytes(&data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+/// Returns the NAV of an offer at a given time
+///
+/// # Arguments
+/// * `offer` - The offer to price
+/// * `time` - Unix timestamp to price the offer at
+/// * `seconds_per_year` - Seconds per year from the program config
+///
+/// # Returns
+/// * `Ok(nav)` - Price of the active vector with scale=9
+/// * `Err(OfferCoreError::NoActiveVector)` - If no vector is active at that time
+pub fn nav_at(offer: &Offer, time: u64, seconds_per_year: u64) -> Result<u64> {
+    let active_vector = find_active_vector_at(offer, time)?;
+    calculate_vector_price_at(&active_vector, time, seconds_per_year)
+}
+
+/// Returns the APY of an offer at a given time
+///
+/// # Returns
+/// * `Ok(apy)` - APY of the active vector's APR with scale=6, compounded daily
+/// * `Err(OfferCoreError::NoActiveVector)` - If no vector is active at that time
+#[allow(dead_code)]
+pub fn apy_at(offer: &Offer, time: u64) -> Result<i64> {
+    let active_vector = find_active_vector_at(offer, time)?;
+    calculate_apy_from_apr(active_vector.apr, DEFAULT_COMPOUNDING_PERIODS_PER_YEAR)
+}
+
+/// Returns the first time after `time` at which the NAV of an offer changes
+///
+/// That is the end of the active step for step vectors, the next second for
+/// linear vectors, or the start of the next vector if it begins earlier.
+///
+/// # Returns
+/// * `Ok(timestamp)` - Unix timestamp of the next NAV change
+/// * `Err(OfferCoreError::NoActiveVector)` - If no vector is active at that time
+pub fn next_nav_change_at(offer: &Offer, time: u64) -> Result<u64> {
+    let active_vector = find_active_vector_at(offer, time)?;
+
+    let next_interval_timestamp = match active_vector.pricing_mode() {
+        PricingMode::Linear => time.checked_add(1).ok_or(OfferCoreError::OverflowError)?,
+        PricingMode::Step => {
+            let elapsed_since_base = time.saturating_sub(active_vector.base_time);
+            let current_step = elapsed_since_base / active_vector.price_fix_duration;
+            active_vector
+                .base_time
+                .checked_add(
+                    (current_step + 1)
+                        .checked_mul(active_vector.price_fix_duration)
+                        .ok_or(OfferCoreError::OverflowError)?,
+                )
+                .ok_or(OfferCoreError::OverflowError)?
+        }
+    };
+
+    let next_vector_start = offer
+        .vectors
+        .iter()
+        .filter(|vector| vector.start_time > time)
+        .map(|vector| vector.start_time)
+        .min();
+
+    Ok(match next_vector_start {
+        Some(start_time) => next_interval_timestamp.min(start_time),
+        None => next_interval_timestamp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_SECONDS_PER_YEAR;
+    use crate::instructions::OfferVector;
+
+    fn offer_with_vectors(vectors: &[OfferVector]) -> Box<Offer> {
+        let mut offer = Box::new(<Offer as bytemuck::Zeroable>::zeroed());
+        offer.vectors[..vectors.len()].copy_from_slice(vectors);
+        offer
+    }
+
+    #[test]
+    fn nav_at_prices_the_active_vector() {
+        let offer = offer_with_vectors(&[
+            OfferVector::new(1_000, 1_000, 1_000_000_000, 0, 86_400, PricingMode::Step),
+            OfferVector::new(5_000, 5_000, 2_000_000_000, 0, 86_400, PricingMode::Step),
+        ]);
+
+        assert_eq!(
+            nav_at(&offer, 4_999, DEFAULT_SECONDS_PER_YEAR).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(
+            nav_at(&offer, 5_000, DEFAULT_SECONDS_PER_YEAR).unwrap(),
+            2_000_000_000
+        );
+        assert!(nav_at(&offer, 999, DEFAULT_SECONDS_PER_YEAR).is_err());
+    }
+
+    #[test]
+    fn next_nav_change_at_is_the_earlier_of_step_end_and_next_vector() {
+        let offer = offer_with_vectors(&[
+            OfferVector::new(1_000, 1_000, 1_000_000_000, 0, 600, PricingMode::Step),
+            OfferVector::new(2_000, 2_000, 1_000_000_000, 0, 600, PricingMode::Linear),
+        ]);
+
+        assert_eq!(next_nav_change_at(&offer, 1_100).unwrap(), 1_600);
+        assert_eq!(next_nav_change_at(&offer, 1_900).unwrap(), 2_000);
+        assert_eq!(next_nav_change_at(&offer, 2_500).unwrap(), 2_501);
+    }
+
+    #[test]
+    fn offer_from_account_data_checks_the_discriminator() {
+        let offer = offer_with_vectors(&[OfferVector::new(
+            1_000,
+            1_000,
+            1_000_000_000,
+            0,
+            600,
+            PricingMode::Step,
+        )]);
+        // Account data handed to programs is 8-byte aligned, like this buffer
+        let mut words = vec![0u64; 1 + std::mem::size_of::<Offer>() / 8];
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+        data[8..].copy_from_slice(bytemuck::bytes_of(offer.as_ref()));
+        assert!(offer_from_account_data(data).is_err());
+
+        data[..8].copy_from_slice(Offer::DISCRIMINATOR);
+        let read = offer_from_account_data(data).unwrap();
+        assert_eq!(read.vectors[0].base_price, 1_000_000_000);
+        assert!(offer_from_account_data(&data[..100]).is_err());
+    }
+}
@@ -1,4 +1,8 @@
-use crate::constants::MAX_ADMINS;
+use crate::constants::{
+    seeds, BLOCKLIST_BUCKET_CAPACITY, BLOCKLIST_BUCKET_COUNT, JURISDICTION_BITMAP_BYTES,
+    MAX_ADMINS, MIN_STATE_SCHEMA_VERSION,
+};
+use crate::utils::ApprovalWindow;
 use anchor_lang::prelude::*;
 
 /// Global program state containing governance and configuration settings
@@ -28,8 +32,187 @@ pub struct State {
     pub max_supply: u64,
     /// Admin account authorized to manage ONr token mints and redemptions
     pub redemption_admin: Pubkey,
+    /// Maximum allowed price deviation in basis points between a new offer vector and
+    /// its predecessor at the new vector's activation time (0 = no limit)
+    pub max_nav_jump_bps: u16,
+    /// Guardian account that must co-sign emergency clawbacks with the boss
+    pub guardian: Pubkey,
+    /// Offer whose NAV prices the whole ONyc supply in `get_global_tvl`
+    pub primary_offer: Pubkey,
+    /// Share of the redemption vault's coverage, in basis points, that outstanding
+    /// redemptions may reach before takes are throttled (0 = disabled)
+    pub redemption_stress_threshold_bps: u16,
+    /// Maximum token_in per take while redemptions are stressed (0 = takes paused)
+    pub stressed_take_max_token_in: u64,
+    /// Unix timestamp at which an enabled kill switch lapses (0 = no expiry)
+    pub kill_switch_expiry: i64,
+    /// Sequence number of the latest vault balance change event (0 = none yet)
+    pub vault_event_sequence: u64,
+    /// Layout version of this account, upgraded step by step by `migrate_state`
+    ///
+    /// States created before versioning existed hold 0.
+    pub schema_version: u16,
+    /// Bitmap of jurisdiction codes whose approvals are rejected, bit `code % 8` of
+    /// byte `code / 8` being set for a blocked code
+    pub blocked_jurisdictions: [u8; JURISDICTION_BITMAP_BYTES],
+    /// Program whose `check(user, amount)` instruction must pass before takes
+    /// complete (default = no screening)
+    pub screening_program: Pubkey,
     /// Reserved space for future program state extensions
-    pub reserved: [u8; 96],
+    pub reserved: [u8; 2],
+}
+
+impl State {
+    /// Returns the number of occupied slots in the admin list
+    pub fn active_admin_count(&self) -> u8 {
+        self.admins
+            .iter()
+            .filter(|admin| **admin != Pubkey::default())
+            .count() as u8
+    }
+
+    /// Returns whether the state has been migrated far enough for current instructions
+    pub fn has_supported_schema(&self) -> bool {
+        self.schema_version >= MIN_STATE_SCHEMA_VERSION
+    }
+
+    /// Advances and returns the sequence number for the next vault balance change event
+    pub fn next_vault_event_sequence(&mut self) -> u64 {
+        self.vault_event_sequence += 1;
+        self.vault_event_sequence
+    }
+
+    /// Returns whether approvals carrying the jurisdiction code are rejected
+    ///
+    /// Codes beyond the bitmap cannot be allowed and always count as blocked.
+    pub fn is_jurisdiction_blocked(&self, jurisdiction: u16) -> bool {
+        let byte = jurisdiction as usize / 8;
+        match self.blocked_jurisdictions.get(byte) {
+            Some(bits) => bits & (1 << (jurisdiction % 8)) != 0,
+            None => true,
+        }
+    }
+
+    /// Blocks or unblocks a jurisdiction code within the bitmap
+    ///
+    /// Codes beyond the bitmap are ignored.
+    pub fn set_jurisdiction_blocked(&mut self, jurisdiction: u16, blocked: bool) {
+        let byte = jurisdiction as usize / 8;
+        if let Some(bits) = self.blocked_jurisdictions.get_mut(byte) {
+            let mask = 1 << (jurisdiction % 8);
+            if blocked {
+                *bits |= mask;
+            } else {
+                *bits &= !mask;
+            }
+        }
+    }
+
+    /// Returns whether the kill switch currently halts operations
+    ///
+    /// An enabled kill switch whose expiry has passed counts as disabled, without a
+    /// transaction having to reset it.
+    pub fn is_kill_switch_active(&self) -> Result<bool> {
+        if !self.is_killed {
+            return Ok(false);
+        }
+        if self.kill_switch_expiry == 0 {
+            return Ok(true);
+        }
+        Ok(Clock::get()?.unix_timestamp < self.kill_switch_expiry)
+    }
+}
+
+/// Program-wide tunables read by offer and redemption instructions
+///
+/// Created by `initialize_config` with the defaults of the former hard-coded
+/// constants and updated by the boss with `set_config`.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Maximum fee in basis points offers and redemption offers may charge
+    pub max_fee_bps: u16,
+    /// Maximum time in seconds an approval message may remain valid
+    /// from the moment it is used (0 = no limit)
+    pub max_approval_validity_secs: u64,
+    /// Number of seconds per year over which offer vector APRs accrue
+    pub seconds_per_year: u64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Seconds by which the validator clock may deviate from the approver's clock
+    ///
+    /// Approvals are accepted up to this long after their expiry, and may be valid
+    /// for this much longer than `max_approval_validity_secs`.
+    pub clock_skew_grace_secs: u64,
+    /// Reserved space for future tunables
+    pub reserved: [u8; 56],
+}
+
+impl Config {
+    /// Returns the limits approval messages are verified against
+    pub fn approval_window(&self) -> ApprovalWindow {
+        ApprovalWindow {
+            max_validity_secs: self.max_approval_validity_secs,
+            clock_skew_grace_secs: self.clock_skew_grace_secs,
+        }
+    }
+}
+
+/// Error codes shared by all instructions guarding the state schema version
+#[error_code]
+pub enum StateSchemaErrorCode {
+    /// The state is older than the minimum schema version instructions require
+    #[msg("State schema is outdated: run migrate_state first")]
+    OutdatedStateSchema,
+}
+
+/// One bucket of the sanctioned-address blocklist maintained by the boss and admins
+///
+/// Addresses are spread over `BLOCKLIST_BUCKET_COUNT` buckets by their first byte,
+/// so takes and redemption requests only load the bucket of their signer. A bucket
+/// is created with the first address added to it.
+#[account]
+#[derive(InitSpace)]
+pub struct BlocklistBucket {
+    /// Index of the bucket, derived from the first byte of its addresses
+    pub index: u8,
+    /// Blocked addresses, empty slots holding the default pubkey
+    pub addresses: [Pubkey; BLOCKLIST_BUCKET_CAPACITY],
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl BlocklistBucket {
+    /// Returns the index of the bucket holding `address`
+    pub fn index_for(address: &Pubkey) -> u8 {
+        address.to_bytes()[0] % BLOCKLIST_BUCKET_COUNT
+    }
+
+    /// Rejects `address` when the bucket account lists it
+    ///
+    /// The bucket may be uninitialized when no address of it was ever blocked; its
+    /// PDA derivation has to be validated by the caller.
+    pub fn require_not_blocked(bucket: &AccountInfo, address: &Pubkey) -> Result<()> {
+        if bucket.owner != &crate::ID || bucket.data_is_empty() {
+            return Ok(());
+        }
+        let bucket = BlocklistBucket::try_deserialize(&mut &bucket.try_borrow_data()?[..])?;
+        require!(
+            !bucket.addresses.contains(address),
+            BlocklistErrorCode::AddressBlocked
+        );
+        Ok(())
+    }
+}
+
+/// Error codes shared by all instructions rejecting blocklisted signers
+#[error_code]
+pub enum BlocklistErrorCode {
+    /// The signer is on the sanctioned-address blocklist
+    #[msg("Address is blocklisted")]
+    AddressBlocked,
 }
 
 /// Program-derived authority for permissionless token routing operations
@@ -42,4 +225,101 @@ pub struct PermissionlessAuthority {
     /// Optional name identifier for the authority (max 50 characters)
     #[max_len(50)]
     pub name: String,
+    /// Fee in basis points charged instead of the offer fee on takes routed
+    /// through this authority (None = offer fee)
+    pub fee_override_bps: Option<u16>,
+}
+
+impl PermissionlessAuthority {
+    /// Returns the PDA seed of the permissionless authority with the given index
+    pub fn seed(index: u8) -> Vec<u8> {
+        [
+            seeds::PERMISSIONLESS_AUTHORITY,
+            index.to_string().as_bytes(),
+        ]
+        .concat()
+    }
+}
+
+/// Boss approval allowing a Token-2022 transfer-hook mint to be used as token_in
+///
+/// Derived from the mint address, one approval per mint. The approval is bound to
+/// the hook program configured at approval time, so a hook authority repointing the
+/// mint to a different program invalidates it.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferHookMintApproval {
+    /// The approved transfer-hook mint
+    pub mint: Pubkey,
+    /// Transfer hook program the mint invoked when it was approved
+    pub hook_program: Pubkey,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+/// Rolling limit on the ONyc the boss can mint
+///
+/// Companion to `State`, whose reserved space cannot hold the throttle. Minted amounts
+/// are charged against `consumed`, which decays linearly to zero over `window_secs`,
+/// so at most `max_mint_per_window` can be minted in any window of that length.
+#[account]
+#[derive(InitSpace)]
+pub struct MintThrottle {
+    /// Maximum ONyc in base units mintable per rolling window (0 = no limit)
+    pub max_mint_per_window: u64,
+    /// Length of the rolling window in seconds
+    pub window_secs: u64,
+    /// Consumption of the window as of `last_update`, before decay
+    pub consumed: u64,
+    /// Unix timestamp at which `consumed` was last updated
+    pub last_update: i64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl MintThrottle {
+    /// Returns the window consumption remaining at `now` after linear decay
+    pub fn consumed_at(&self, now: i64) -> u64 {
+        if self.window_secs == 0 {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.last_update).max(0) as u64;
+        if elapsed >= self.window_secs {
+            return 0;
+        }
+        ((self.consumed as u128) * ((self.window_secs - elapsed) as u128)
+            / self.window_secs as u128) as u64
+    }
+}
+
+/// Pending change of the ONyc max supply awaiting its timelock
+///
+/// Created by `propose_max_supply` and closed by `accept_max_supply`, so its
+/// existence is on-chain notice of an upcoming loosening of the supply cap.
+#[account]
+#[derive(InitSpace)]
+pub struct MaxSupplyProposal {
+    /// The proposed maximum supply cap in base units (0 = no cap)
+    pub max_supply: u64,
+    /// Unix timestamp from which the proposal can be accepted
+    pub effective_at: i64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
+}
+
+/// Pending closure of the program state awaiting its timelock
+///
+/// Created by `propose_close_state` while the kill switch is enabled and closed
+/// together with the state by `close_state`.
+#[account]
+#[derive(InitSpace)]
+pub struct CloseStateProposal {
+    /// Unix timestamp from which the state can be closed
+    pub effective_at: i64,
+    /// PDA bump seed for account derivation
+    pub bump: u8,
 }
@@ -1,5 +1,6 @@
 use crate::utils::approver::message::ApprovalMessage;
 use crate::utils::ed25519_parser::parse_ed25519_ix;
+use crate::utils::error_context::require_with_context;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar;
 use solana_program::ed25519_program;
@@ -10,6 +11,9 @@ pub enum ErrorCode {
     /// The approval message timestamp has passed the current time
     #[msg("The approval message has expired.")]
     Expired,
+    /// The approval message expires later than the configured validity window allows
+    #[msg("The approval message is valid for too long.")]
+    ValidityTooLong,
     /// The approval message was signed for a different program ID
     #[msg("The approval message is for the wrong program.")]
     WrongProgram,
@@ -41,6 +45,16 @@ pub enum ErrorCode {
     MsgDeserialize,
 }
 
+/// Bounds on how long approval messages are accepted, from the program configuration
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ApprovalWindow {
+    /// Maximum remaining validity of a message in seconds (0 = no limit)
+    pub max_validity_secs: u64,
+    /// Seconds the validator clock may deviate from the approver's clock, tolerated
+    /// on both the expiry and the validity limit (0 = no grace)
+    pub clock_skew_grace_secs: u64,
+}
+
 /// Verifies cryptographic approval messages signed by trusted authorities
 ///
 /// This function performs comprehensive validation of approval messages using Ed25519
@@ -58,13 +72,15 @@ pub enum ErrorCode {
 /// * `approver2` - The second authorized signing authority
 /// * `instructions_sysvar` - Instructions sysvar for accessing previous instructions
 /// * `msg` - The approval message to verify
+/// * `window` - Maximum validity of the message and clock skew grace
 ///
 /// # Returns
 /// * `Ok(())` - If approval signature and content are valid with either approver
 /// * `Err(_)` - If validation fails with both approvers
 ///
 /// # Validation Steps
-/// 1. Expiry time validation against current timestamp
+/// 1. Expiry time validation against current timestamp and the validity window,
+///    both widened by the clock skew grace
 /// 2. Program ID matching verification
 /// 3. User public key matching verification
 /// 4. Ed25519 signature instruction location and parsing
@@ -77,9 +93,32 @@ pub fn verify_approval_message_generic(
     approver2: &Pubkey,
     instructions_sysvar: &UncheckedAccount,
     msg: &ApprovalMessage,
+    window: ApprovalWindow,
 ) -> Result<()> {
+    let ApprovalWindow {
+        max_validity_secs,
+        clock_skew_grace_secs,
+    } = window;
     let now = Clock::get()?.unix_timestamp as u64;
-    require!(now <= msg.expiry_unix, ErrorCode::Expired);
+    // An approver clock running ahead of the validator makes fresh approvals look
+    // both marginally early and marginally long-lived; the grace tolerates both
+    let expiry_with_grace = msg.expiry_unix.saturating_add(clock_skew_grace_secs);
+    require_with_context!(
+        now <= expiry_with_grace,
+        ErrorCode::Expired,
+        now = now,
+        expiry_unix = msg.expiry_unix,
+        clock_skew_grace_secs = clock_skew_grace_secs
+    );
+    require_with_context!(
+        max_validity_secs == 0
+            || msg.expiry_unix.saturating_sub(now)
+                <= max_validity_secs.saturating_add(clock_skew_grace_secs),
+        ErrorCode::ValidityTooLong,
+        remaining_validity_secs = msg.expiry_unix.saturating_sub(now),
+        max_approval_validity_secs = max_validity_secs,
+        clock_skew_grace_secs = clock_skew_grace_secs
+    );
     require!(msg.program_id == *program_id, ErrorCode::WrongProgram);
     require!(msg.user_pubkey.key() == user_pubkey.key(), ErrorCode::WrongUser);
 
@@ -108,7 +147,10 @@ pub fn verify_approval_message_generic(
         .map_err(|_| ErrorCode::MsgDeserialize)?;
     require!(signed_msg.program_id == *program_id, ErrorCode::WrongProgram);
     require!(signed_msg.user_pubkey == *user_pubkey, ErrorCode::WrongUser);
-    require!(signed_msg.expiry_unix >= now, ErrorCode::Expired);
+    require!(
+        signed_msg.expiry_unix.saturating_add(clock_skew_grace_secs) >= now,
+        ErrorCode::Expired
+    );
     require!(signed_msg == *msg, ErrorCode::MsgMismatch);
 
     Ok(())
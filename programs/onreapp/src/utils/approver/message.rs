@@ -12,6 +12,7 @@ use crate::borsh;
 /// - `program_id`: The ID of the program for which this approval is valid
 /// - `user_pubkey`: The public key of the user who is approved to perform the action
 /// - `expiry_unix`: Unix timestamp when this approval expires
+/// - `jurisdiction`: Jurisdiction code the approver assigned to the user
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ApprovalMessage {
     /// The program ID this approval is valid for
@@ -20,4 +21,7 @@ pub struct ApprovalMessage {
     pub user_pubkey: Pubkey,
     /// Unix timestamp when this approval expires
     pub expiry_unix: u64,
+    /// Jurisdiction code of the user, such as an ISO 3166-1 numeric country code,
+    /// checked against the blocked jurisdictions in program state
+    pub jurisdiction: u16,
 }
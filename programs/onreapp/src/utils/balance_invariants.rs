@@ -0,0 +1,94 @@
+use crate::utils::TokenUtilsErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use spl_token_2022::extension::StateWithExtensions;
+
+/// Balance of a token account captured before a token flow, together with the net
+/// change the flow is expected to make to it
+struct ExpectedBalance<'info> {
+    account: AccountInfo<'info>,
+    before: u64,
+    delta: i128,
+}
+
+/// Post-transfer invariants of a token flow
+///
+/// Every leg of the flow registers the debit of its source and the credit of its
+/// destination before any tokens move. Changes to the same account are merged, so a
+/// flow whose source and destination coincide (e.g. the boss taking its own offer) is
+/// expected to leave that account unchanged. After the flow, `verify` compares the live
+/// balances against the expectations and reverts on any mismatch.
+#[derive(Default)]
+pub struct BalanceInvariants<'info> {
+    balances: Vec<ExpectedBalance<'info>>,
+}
+
+impl<'info> BalanceInvariants<'info> {
+    /// Expects a transfer of `amount` from `source` to `destination`, with the
+    /// destination credited less the Token-2022 `transfer_fee` withheld by the mint
+    pub fn expect_transfer(
+        &mut self,
+        source: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+        amount: u64,
+        transfer_fee: u64,
+    ) -> Result<()> {
+        let received = amount
+            .checked_sub(transfer_fee)
+            .ok_or(TokenUtilsErrorCode::InvariantViolation)?;
+        self.expect_change(source, -(amount as i128))?;
+        self.expect_change(destination, received as i128)
+    }
+
+    /// Expects `account` to change by `delta` base units, e.g. through a mint or a burn
+    pub fn expect_change(&mut self, account: &AccountInfo<'info>, delta: i128) -> Result<()> {
+        if let Some(balance) = self
+            .balances
+            .iter_mut()
+            .find(|balance| balance.account.key == account.key)
+        {
+            balance.delta += delta;
+            return Ok(());
+        }
+
+        self.balances.push(ExpectedBalance {
+            account: account.clone(),
+            before: token_account_amount(account)?,
+            delta,
+        });
+        Ok(())
+    }
+
+    /// Checks that every registered account changed by exactly its expected amount
+    ///
+    /// # Returns
+    /// * `Ok(())` - If all balances match their expectations
+    /// * `Err(TokenUtilsErrorCode::InvariantViolation)` - If any balance deviates
+    pub fn verify(&self) -> Result<()> {
+        for balance in self.balances.iter() {
+            let after = token_account_amount(&balance.account)?;
+            if after as i128 != balance.before as i128 + balance.delta {
+                msg!(
+                    "Balance invariant violated for {}: before {}, after {}, expected change {}",
+                    balance.account.key,
+                    balance.before,
+                    after,
+                    balance.delta
+                );
+                return err!(TokenUtilsErrorCode::InvariantViolation);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the live balance of a token account
+///
+/// Deserialized `InterfaceAccount`s cache the balance they were loaded with, so the
+/// balance is read from the account data to observe transfers made earlier in the
+/// same instruction. The base layout is shared by SPL Token and Token-2022 accounts.
+pub fn token_account_amount(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(token_account.base.amount)
+}
@@ -0,0 +1,51 @@
+//! Structured context for program errors
+//!
+//! Error codes only name what failed. The macros below additionally log the values
+//! that led to the failure as a single line of `key=value` pairs, e.g.
+//! `error=OfferCoreError::StepVolumeExceeded step_volume=1500 max_step_volume=1000`,
+//! so a failed transaction can be diagnosed from its explorer logs alone.
+
+/// Logs `key=value` context for an error and evaluates to the error
+///
+/// # Example
+/// ```ignore
+/// .ok_or_else(|| error_with_context!(OfferCoreError::NoActiveVector, time = now))?;
+/// ```
+macro_rules! error_with_context {
+    ($error:expr, $($key:ident = $value:expr),+ $(,)?) => {{
+        anchor_lang::prelude::msg!(
+            concat!("error={}", $(" ", stringify!($key), "={}"),+),
+            stringify!($error),
+            $($value),+
+        );
+        anchor_lang::error!($error)
+    }};
+}
+
+/// Returns the error with logged `key=value` context unless the condition holds
+///
+/// Behaves like `require!`, with the values compared by the condition logged
+/// on failure.
+///
+/// # Example
+/// ```ignore
+/// require_with_context!(
+///     amount <= limit,
+///     OfferCoreError::TakeExceedsRedemptionStressLimit,
+///     amount = amount,
+///     limit = limit
+/// );
+/// ```
+macro_rules! require_with_context {
+    ($condition:expr, $error:expr, $($key:ident = $value:expr),+ $(,)?) => {
+        if !($condition) {
+            return Err($crate::utils::error_context::error_with_context!(
+                $error,
+                $($key = $value),+
+            ));
+        }
+    };
+}
+
+pub(crate) use error_with_context;
+pub(crate) use require_with_context;
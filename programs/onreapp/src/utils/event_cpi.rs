@@ -0,0 +1,91 @@
+//! Event emission through Anchor event CPI
+//!
+//! `emit!` writes events to the program log, which the runtime truncates on busy
+//! transactions. Built with the `event-cpi` feature, the program additionally emits
+//! the events of takes, redemption fulfillments and keeper reward accruals as a
+//! self-CPI signed by the `__event_authority` PDA, so indexers parsing inner
+//! instructions receive them regardless of log truncation. The instructions emitting
+//! those events then take two extra accounts, `event_authority` and `program`,
+//! appended after their regular accounts.
+
+use anchor_lang::prelude::*;
+#[cfg(not(feature = "event-cpi"))]
+use std::marker::PhantomData;
+
+/// Emits events to the program log and, with the `event-cpi` feature, as event CPI
+///
+/// Built by the `event_emitter!` macro from an instruction context whose accounts
+/// struct is marked `#[cfg_attr(feature = "event-cpi", event_cpi)]`. Without the
+/// feature, the default emitter writes events to the program log only.
+#[cfg_attr(not(feature = "event-cpi"), derive(Default))]
+pub struct EventEmitter<'a, 'info> {
+    #[cfg(feature = "event-cpi")]
+    event_authority: &'a AccountInfo<'info>,
+    #[cfg(feature = "event-cpi")]
+    event_authority_bump: u8,
+    #[cfg(not(feature = "event-cpi"))]
+    accounts: PhantomData<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> EventEmitter<'a, 'info> {
+    /// Creates an emitter signing event CPIs with the given event authority
+    #[cfg(feature = "event-cpi")]
+    pub fn new(event_authority: &'a AccountInfo<'info>, event_authority_bump: u8) -> Self {
+        Self {
+            event_authority,
+            event_authority_bump,
+        }
+    }
+
+    /// Emits the event to the program log and, with the `event-cpi` feature, as event CPI
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the event is emitted
+    /// * `Err(_)` - If the event CPI fails
+    pub fn emit<E: anchor_lang::Event>(&self, event: E) -> Result<()> {
+        emit!(event);
+
+        #[cfg(feature = "event-cpi")]
+        {
+            let ix_data: Vec<u8> = anchor_lang::event::EVENT_IX_TAG_LE
+                .iter()
+                .copied()
+                .chain(event.data())
+                .collect();
+            let ix = solana_program::instruction::Instruction::new_with_bytes(
+                crate::ID,
+                &ix_data,
+                vec![solana_program::instruction::AccountMeta::new_readonly(
+                    *self.event_authority.key,
+                    true,
+                )],
+            );
+            solana_program::program::invoke_signed(
+                &ix,
+                std::slice::from_ref(self.event_authority),
+                &[&[b"__event_authority", &[self.event_authority_bump]]],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds an `EventEmitter` from an instruction context
+#[cfg(feature = "event-cpi")]
+macro_rules! event_emitter {
+    ($ctx:expr) => {
+        $crate::utils::EventEmitter::new(&$ctx.accounts.event_authority, $ctx.bumps.event_authority)
+    };
+}
+
+/// Builds an `EventEmitter` from an instruction context
+#[cfg(not(feature = "event-cpi"))]
+macro_rules! event_emitter {
+    ($ctx:expr) => {{
+        let _ = &$ctx;
+        $crate::utils::EventEmitter::default()
+    }};
+}
+
+pub(crate) use event_emitter;
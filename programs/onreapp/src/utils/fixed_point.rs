@@ -0,0 +1,134 @@
+/// Powers of ten up to 10^38, the largest that fits in a u128
+///
+/// A table lookup is much cheaper on-chain than `u128::pow`, which runs a
+/// multiplication loop on software-emulated 128-bit integers.
+const POW10: [u128; 39] = {
+    let mut table = [1u128; 39];
+    let mut i = 1;
+    while i < 39 {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+/// Returns 10^exp, or `None` if it does not fit in a u128
+pub fn pow10(exp: u32) -> Option<u128> {
+    POW10.get(exp as usize).copied()
+}
+
+/// Computes `floor(a * b / denominator)` with a 128-bit intermediate product
+///
+/// The SBF target has no native 128-bit division, so dividing a u128 calls a
+/// software routine that costs several hundred compute units. When the product and
+/// the denominator fit in 64 bits, which covers almost every price calculation, the
+/// division is done natively instead.
+///
+/// # Arguments
+/// * `a` - First factor
+/// * `b` - Second factor
+/// * `denominator` - Divisor
+///
+/// # Returns
+/// * `Some(result)` - The floored quotient
+/// * `None` - If the product overflows or the denominator is zero
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let product = a.checked_mul(b)?;
+    if product <= u64::MAX as u128 && denominator <= u64::MAX as u128 {
+        return Some(((product as u64) / (denominator as u64)) as u128);
+    }
+    Some(product / denominator)
+}
+
+/// Computes `floor(a * b / denominator)` and checks that the result fits in a u64
+///
+/// # Returns
+/// * `Some(result)` - The floored quotient
+/// * `None` - If the product overflows, the denominator is zero or the result exceeds u64
+pub fn mul_div_floor_u64(a: u64, b: u128, denominator: u128) -> Option<u64> {
+    let result = mul_div_floor(a as u128, b, denominator)?;
+    u64::try_from(result).ok()
+}
+
+/// Converts an amount between decimal scales, multiplying by a scaled price
+///
+/// Computes `floor(amount * price_num * 10^to_exp / (price_den * 10^from_exp))`
+/// after cancelling the common power of ten, which keeps the intermediate product
+/// small enough for the native 64-bit division path in most cases. The result is
+/// identical to evaluating the uncancelled expression.
+///
+/// # Arguments
+/// * `amount` - Amount to convert
+/// * `price_num` - Price numerator
+/// * `price_den` - Price denominator
+/// * `to_exp` - Power of ten applied to the numerator
+/// * `from_exp` - Power of ten applied to the denominator
+///
+/// # Returns
+/// * `Some(result)` - The converted amount, which callers must check against u64
+/// * `None` - If a calculation overflows or the denominator is zero
+pub fn scale_amount(
+    amount: u64,
+    price_num: u128,
+    price_den: u128,
+    to_exp: u32,
+    from_exp: u32,
+) -> Option<u128> {
+    let (numerator, denominator) = if to_exp >= from_exp {
+        (price_num.checked_mul(pow10(to_exp - from_exp)?)?, price_den)
+    } else {
+        (price_num, price_den.checked_mul(pow10(from_exp - to_exp)?)?)
+    };
+    mul_div_floor(amount as u128, numerator, denominator)
+}
+
+/// Computes `ceil(a * b / denominator)` with a 128-bit intermediate product
+///
+/// Rounding counterpart of `mul_div_floor`, used where rounding down would favor
+/// the taker over the protocol.
+///
+/// # Returns
+/// * `Some(result)` - The rounded-up quotient
+/// * `None` - If the product overflows or the denominator is zero
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    let floor = mul_div_floor(a, b, denominator)?;
+    let remainder = a.checked_mul(b)? - floor * denominator;
+    if remainder > 0 {
+        floor.checked_add(1)
+    } else {
+        Some(floor)
+    }
+}
+
+/// Computes `ceil(a * b / denominator)` and checks that the result fits in a u64
+///
+/// # Returns
+/// * `Some(result)` - The rounded-up quotient
+/// * `None` - If the product overflows, the denominator is zero or the result exceeds u64
+pub fn mul_div_ceil_u64(a: u64, b: u128, denominator: u128) -> Option<u64> {
+    let result = mul_div_ceil(a as u128, b, denominator)?;
+    u64::try_from(result).ok()
+}
+
+/// Converts an amount between decimal scales like `scale_amount`, rounding up
+///
+/// # Returns
+/// * `Some(result)` - The converted amount, which callers must check against u64
+/// * `None` - If a calculation overflows or the denominator is zero
+pub fn scale_amount_ceil(
+    amount: u64,
+    price_num: u128,
+    price_den: u128,
+    to_exp: u32,
+    from_exp: u32,
+) -> Option<u128> {
+    let (numerator, denominator) = if to_exp >= from_exp {
+        (price_num.checked_mul(pow10(to_exp - from_exp)?)?, price_den)
+    } else {
+        (price_num, price_den.checked_mul(pow10(from_exp - to_exp)?)?)
+    };
+    mul_div_ceil(amount as u128, numerator, denominator)
+}
@@ -1,6 +1,18 @@
 pub mod token_utils;
+pub mod balance_invariants;
+pub mod fixed_point;
 pub mod approver;
 mod ed25519_parser;
+pub mod oracle;
+pub mod screening;
+pub mod rounding;
+pub mod error_context;
+pub mod event_cpi;
 
 pub use token_utils::*;
-pub use approver::*;
\ No newline at end of file
+pub use balance_invariants::*;
+pub use fixed_point::*;
+pub use approver::*;
+pub use rounding::*;
+pub use screening::*;
+pub use event_cpi::EventEmitter;
@@ -0,0 +1,152 @@
+use crate::constants::PRICE_DECIMALS;
+use anchor_lang::prelude::*;
+
+/// Pyth Solana Receiver program owning `PriceUpdateV2` accounts
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of the Pyth `PriceUpdateV2` account
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Borsh variant tag of `VerificationLevel::Full`
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+
+/// Error codes for oracle price reads
+#[error_code]
+pub enum OracleErrorCode {
+    /// The offer has an oracle configured but no oracle account was provided
+    #[msg("Oracle account required for this offer")]
+    OracleAccountMissing,
+    /// The provided account does not match the configured oracle or is not a valid price update
+    #[msg("Invalid oracle account")]
+    InvalidOracleAccount,
+    /// The price update was not fully verified by the Wormhole guardians
+    #[msg("Oracle price is not fully verified")]
+    UnverifiedPrice,
+    /// The oracle price is older than the configured staleness limit
+    #[msg("Oracle price is stale")]
+    StalePrice,
+    /// The oracle confidence interval is wider than the configured limit
+    #[msg("Oracle price confidence interval too wide")]
+    ConfidenceTooWide,
+    /// The oracle price is zero, negative or cannot be represented
+    #[msg("Invalid oracle price")]
+    InvalidPrice,
+    /// The price update was posted for another Pyth feed than the configured one
+    #[msg("Oracle price update is for another feed")]
+    FeedMismatch,
+}
+
+/// Price fields extracted from a Pyth `PriceUpdateV2` account
+pub struct OraclePrice {
+    /// Pyth feed id the price was published for
+    pub feed_id: [u8; 32],
+    /// Price mantissa
+    pub price: i64,
+    /// Confidence interval of the price, same exponent as the price
+    pub conf: u64,
+    /// Price exponent (value = price * 10^exponent)
+    pub exponent: i32,
+    /// Unix timestamp of the price publication
+    pub publish_time: i64,
+}
+
+/// Parses a Pyth `PriceUpdateV2` account into its price fields.
+///
+/// Expected data format (Borsh):
+/// ```text
+/// Bytes 0-7:   Anchor discriminator
+/// Bytes 8-39:  Write authority (Pubkey)
+/// Byte  40:    Verification level tag (0 = Partial, 1 = Full)
+/// [Byte 41:    Number of signatures, only present for Partial]
+/// Then the price message:
+///   32 bytes feed id, i64 price, u64 conf, i32 exponent, i64 publish_time, ...
+/// ```
+///
+/// Returns None if data is malformed or the update is not fully verified.
+pub fn parse_price_update(data: &[u8]) -> Option<OraclePrice> {
+    if data.len() < 41 || data[..8] != PRICE_UPDATE_V2_DISCRIMINATOR {
+        return None;
+    }
+    if data[40] != VERIFICATION_LEVEL_FULL {
+        return None; // Partially verified updates are not accepted
+    }
+
+    let feed_id = data.get(41..41 + 32)?.try_into().ok()?;
+    let message = data.get(41 + 32..41 + 32 + 28)?;
+    Some(OraclePrice {
+        feed_id,
+        price: i64::from_le_bytes(message[0..8].try_into().ok()?),
+        conf: u64::from_le_bytes(message[8..16].try_into().ok()?),
+        exponent: i32::from_le_bytes(message[16..20].try_into().ok()?),
+        publish_time: i64::from_le_bytes(message[20..28].try_into().ok()?),
+    })
+}
+
+/// Reads the USD price of a token from a Pyth price update with scale=9
+///
+/// The write authority of a price update account can post any feed into it, so the
+/// update must carry the expected feed id.
+///
+/// # Arguments
+/// * `oracle` - The Pyth `PriceUpdateV2` account
+/// * `feed_id` - Pyth feed id the price update must carry
+/// * `max_staleness` - Maximum accepted age of the price in seconds
+/// * `max_confidence_bps` - Maximum accepted confidence interval relative to the price
+/// * `current_time` - Current Unix timestamp
+///
+/// # Returns
+/// * `Ok(u64)` - USD price of one whole token with scale=9 (1_000_000_000 = $1.0)
+/// * `Err(OracleErrorCode::*)` - If the account is invalid or the price fails a check
+pub fn read_oracle_usd_price(
+    oracle: &AccountInfo,
+    feed_id: &[u8; 32],
+    max_staleness: u64,
+    max_confidence_bps: u16,
+    current_time: u64,
+) -> Result<u64> {
+    require_keys_eq!(
+        *oracle.owner,
+        PYTH_RECEIVER_PROGRAM_ID,
+        OracleErrorCode::InvalidOracleAccount
+    );
+
+    let data = oracle.try_borrow_data()?;
+    require!(
+        data.len() > 40 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+        OracleErrorCode::InvalidOracleAccount
+    );
+    require!(
+        data[40] == VERIFICATION_LEVEL_FULL,
+        OracleErrorCode::UnverifiedPrice
+    );
+    let price = parse_price_update(&data).ok_or(OracleErrorCode::InvalidOracleAccount)?;
+    require!(price.feed_id == *feed_id, OracleErrorCode::FeedMismatch);
+
+    require!(price.price > 0, OracleErrorCode::InvalidPrice);
+    require!(
+        current_time.saturating_sub(price.publish_time.max(0) as u64) <= max_staleness,
+        OracleErrorCode::StalePrice
+    );
+
+    let price_mantissa = price.price as u128;
+    require!(
+        (price.conf as u128) * 10_000 <= price_mantissa * max_confidence_bps as u128,
+        OracleErrorCode::ConfidenceTooWide
+    );
+
+    // Rescale price * 10^exponent to PRICE_DECIMALS
+    let scale_exponent = PRICE_DECIMALS as i32 + price.exponent;
+    let scaled = if scale_exponent >= 0 {
+        10u128
+            .checked_pow(scale_exponent as u32)
+            .and_then(|factor| price_mantissa.checked_mul(factor))
+    } else {
+        10u128
+            .checked_pow(scale_exponent.unsigned_abs())
+            .map(|divisor| price_mantissa / divisor)
+    }
+    .ok_or(OracleErrorCode::InvalidPrice)?;
+
+    require!(scaled > 0, OracleErrorCode::InvalidPrice);
+    u64::try_from(scaled).map_err(|_| error!(OracleErrorCode::InvalidPrice))
+}
@@ -0,0 +1,187 @@
+use crate::constants::MAX_BASIS_POINTS;
+use crate::utils::{mul_div_ceil, mul_div_floor, scale_amount, scale_amount_ceil};
+
+/// Direction in which a division with a remainder is rounded
+///
+/// Every division in a value flow rounds in the direction that keeps the protocol
+/// whole: payouts to users round down so a user never receives more than the value
+/// paid in, fees round up so a non-zero fee rate never yields a zero fee, and the
+/// input required for an exact output rounds up so it always buys that output.
+/// As a consequence a very small take or redemption would pay out zero tokens while
+/// its fee rounds up to one base unit; takes and redemptions reject such payouts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero, dropping the remainder
+    Down,
+    /// Round away from zero whenever there is a remainder
+    Up,
+}
+
+/// Rounding of amounts paid out to users
+pub const PAYOUT_ROUNDING: Rounding = Rounding::Down;
+
+/// Rounding of fees charged by the protocol
+pub const FEE_ROUNDING: Rounding = Rounding::Up;
+
+/// Rounding of the input amount required to obtain an exact output
+pub const REQUIRED_INPUT_ROUNDING: Rounding = Rounding::Up;
+
+/// Computes `a * b / denominator` rounded in the given direction
+///
+/// # Returns
+/// * `Some(result)` - The rounded quotient
+/// * `None` - If the product overflows or the denominator is zero
+pub fn mul_div(a: u128, b: u128, denominator: u128, rounding: Rounding) -> Option<u128> {
+    match rounding {
+        Rounding::Down => mul_div_floor(a, b, denominator),
+        Rounding::Up => mul_div_ceil(a, b, denominator),
+    }
+}
+
+/// Computes `a * b / denominator` rounded in the given direction and checks that
+/// the result fits in a u64
+///
+/// # Returns
+/// * `Some(result)` - The rounded quotient
+/// * `None` - If the product overflows, the denominator is zero or the result exceeds u64
+pub fn mul_div_u64(a: u64, b: u128, denominator: u128, rounding: Rounding) -> Option<u64> {
+    let result = mul_div(a as u128, b, denominator, rounding)?;
+    u64::try_from(result).ok()
+}
+
+/// Converts an amount between decimal scales like `scale_amount`, rounded in the
+/// given direction
+///
+/// Computes `amount * price_num * 10^to_exp / (price_den * 10^from_exp)`.
+///
+/// # Returns
+/// * `Some(result)` - The converted amount, which callers must check against u64
+/// * `None` - If a calculation overflows or the denominator is zero
+pub fn scale_amount_rounded(
+    amount: u64,
+    price_num: u128,
+    price_den: u128,
+    to_exp: u32,
+    from_exp: u32,
+    rounding: Rounding,
+) -> Option<u128> {
+    match rounding {
+        Rounding::Down => scale_amount(amount, price_num, price_den, to_exp, from_exp),
+        Rounding::Up => scale_amount_ceil(amount, price_num, price_den, to_exp, from_exp),
+    }
+}
+
+/// Computes the fee charged on an amount, rounded up in favor of the protocol
+///
+/// # Arguments
+/// * `amount` - Amount the fee is charged on
+/// * `fee_basis_points` - Fee in basis points (10000 = 100%)
+///
+/// # Returns
+/// * `Some(fee)` - The fee, at most `amount` for fees up to 10000 basis points
+/// * `None` - If the calculation overflows
+pub fn fee_amount(amount: u64, fee_basis_points: u16) -> Option<u64> {
+    mul_div_u64(
+        amount,
+        fee_basis_points as u128,
+        MAX_BASIS_POINTS as u128,
+        FEE_ROUNDING,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down), Some(3));
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up), Some(4));
+    }
+
+    #[test]
+    fn mul_div_is_exact_without_remainder() {
+        assert_eq!(mul_div(9, 1, 3, Rounding::Down), Some(3));
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up), Some(3));
+    }
+
+    #[test]
+    fn mul_div_handles_products_beyond_u64() {
+        let a = u64::MAX as u128;
+        assert_eq!(mul_div(a, 4, 2, Rounding::Down), Some(a * 2));
+        assert_eq!(mul_div(a, 3, 2, Rounding::Up), Some((a * 3).div_ceil(2)));
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_denominator_and_overflow() {
+        assert_eq!(mul_div(1, 1, 0, Rounding::Down), None);
+        assert_eq!(mul_div(1, 1, 0, Rounding::Up), None);
+        assert_eq!(mul_div(u128::MAX, 2, 1, Rounding::Up), None);
+    }
+
+    #[test]
+    fn mul_div_u64_rejects_results_beyond_u64() {
+        assert_eq!(mul_div_u64(u64::MAX, 1, 1, Rounding::Down), Some(u64::MAX));
+        assert_eq!(mul_div_u64(u64::MAX, 2, 1, Rounding::Down), None);
+        assert_eq!(mul_div_u64(u64::MAX, 3, 2, Rounding::Up), None);
+    }
+
+    #[test]
+    fn scale_amount_rounded_converts_between_decimals() {
+        // 1.5 tokens with 6 decimals at a price of 2.0 (scale 9) into 9 decimals
+        assert_eq!(
+            scale_amount_rounded(1_500_000, 2_000_000_000, 1, 9, 6 + 9, Rounding::Down),
+            Some(3_000_000_000)
+        );
+        // 1 base unit with 9 decimals into 6 decimals
+        assert_eq!(
+            scale_amount_rounded(1, 1_000_000_000, 1, 6, 9 + 9, Rounding::Down),
+            Some(0)
+        );
+        assert_eq!(
+            scale_amount_rounded(1, 1_000_000_000, 1, 6, 9 + 9, Rounding::Up),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn fee_amount_rounds_up() {
+        assert_eq!(fee_amount(1_000, 500), Some(50));
+        assert_eq!(fee_amount(1, 1), Some(1));
+        assert_eq!(fee_amount(9_999, 1), Some(1));
+        assert_eq!(fee_amount(10_001, 1), Some(2));
+    }
+
+    #[test]
+    fn fee_amount_is_zero_only_for_zero_rate_or_amount() {
+        assert_eq!(fee_amount(1_000, 0), Some(0));
+        assert_eq!(fee_amount(0, 500), Some(0));
+    }
+
+    #[test]
+    fn fee_amount_never_exceeds_the_amount() {
+        for amount in [1u64, 7, 10_000, u64::MAX] {
+            assert_eq!(fee_amount(amount, MAX_BASIS_POINTS), Some(amount));
+            assert!(fee_amount(amount, 9_999).unwrap() <= amount);
+        }
+    }
+
+    #[test]
+    fn payout_and_fee_together_never_exceed_the_gross_amount() {
+        // Splitting a gross amount into a floored payout and a ceiled fee never
+        // creates value out of the remainder
+        for gross in [1u64, 3, 999, 1_000_001] {
+            for bps in [1u16, 30, 333, 5_000] {
+                let fee = fee_amount(gross, bps).unwrap();
+                let payout = mul_div_u64(
+                    gross,
+                    (MAX_BASIS_POINTS - bps) as u128,
+                    MAX_BASIS_POINTS as u128,
+                    PAYOUT_ROUNDING,
+                )
+                .unwrap();
+                assert!(payout + fee <= gross);
+            }
+        }
+    }
+}
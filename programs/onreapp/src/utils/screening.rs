@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+/// Anchor discriminator of the screening interface's `check` instruction, the first
+/// 8 bytes of sha256("global:check")
+pub const SCREENING_CHECK_DISCRIMINATOR: [u8; 8] = [238, 251, 184, 43, 83, 233, 244, 65];
+
+/// Error codes for take screening
+#[error_code]
+pub enum ScreeningErrorCode {
+    /// A screening program is configured but no screening program account was provided
+    #[msg("Screening program account required")]
+    ScreeningProgramMissing,
+    /// The provided account does not match the configured screening program
+    #[msg("Invalid screening program account")]
+    InvalidScreeningProgram,
+}
+
+/// Runs the configured screening program's `check(user, amount)` instruction
+///
+/// Does nothing when no screening program is configured. A failing check aborts the
+/// whole transaction.
+///
+/// Screening interface:
+/// ```text
+/// Data:     [8-byte discriminator][32-byte user][8-byte amount (u64, little-endian)]
+/// Accounts: [user (read-only)]
+/// ```
+///
+/// # Arguments
+/// * `configured_program` - Screening program stored in program state
+/// * `screening_program` - The screening program account passed to the instruction
+/// * `user` - The user taking the offer
+/// * `amount` - Amount of token_in the user pays, including fees
+pub fn screen_take<'info>(
+    configured_program: &Pubkey,
+    screening_program: Option<&UncheckedAccount<'info>>,
+    user: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if *configured_program == Pubkey::default() {
+        return Ok(());
+    }
+
+    let screening_program = screening_program.ok_or(ScreeningErrorCode::ScreeningProgramMissing)?;
+    require_keys_eq!(
+        screening_program.key(),
+        *configured_program,
+        ScreeningErrorCode::InvalidScreeningProgram
+    );
+
+    let mut data = Vec::with_capacity(48);
+    data.extend_from_slice(&SCREENING_CHECK_DISCRIMINATOR);
+    data.extend_from_slice(user.key.as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    invoke(
+        &Instruction {
+            program_id: *configured_program,
+            accounts: vec![AccountMeta::new_readonly(*user.key, false)],
+            data,
+        },
+        &[user.clone(), screening_program.to_account_info()],
+    )?;
+
+    Ok(())
+}
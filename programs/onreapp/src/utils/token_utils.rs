@@ -1,12 +1,20 @@
-use crate::constants::{seeds, MAX_BASIS_POINTS, PRICE_DECIMALS};
+use crate::constants::{seeds, PRICE_DECIMALS};
+use crate::instructions::FulfillmentMode;
+use crate::state::TransferHookMintApproval;
+use crate::utils::error_context::require_with_context;
+use crate::utils::{
+    fee_amount, scale_amount_rounded, BalanceInvariants, PAYOUT_ROUNDING, REQUIRED_INPUT_ROUNDING,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022 as token_2022_cpi;
 use anchor_spl::token_interface;
 use anchor_spl::token_interface::{
     BurnChecked, Mint, MintToChecked, TokenAccount, TokenInterface, TransferChecked,
 };
 use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
 use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use token_2022_cpi::extension::{BaseStateWithExtensions as _, ExtensionType};
 
 #[error_code]
 pub enum TokenUtilsErrorCode {
@@ -22,6 +30,20 @@ pub enum TokenUtilsErrorCode {
     DecimalsExceedMax,
     #[msg("Result exceeds u64 maximum value")]
     ResultOverflow,
+    #[msg("Token-2022 transfer-hook mint not approved")]
+    TransferHookMintNotApproved,
+    #[msg("Transfer hook program differs from the approved one")]
+    TransferHookProgramMismatch,
+    #[msg("Token-2022 mints with a permanent delegate are not supported")]
+    PermanentDelegateNotSupported,
+    #[msg("Token-2022 mints with confidential transfers are not supported")]
+    ConfidentialTransfersNotSupported,
+    #[msg("Token-2022 mint extension not supported")]
+    UnsupportedMintExtension,
+    #[msg("Token balances changed differently than the computed amounts")]
+    InvariantViolation,
+    #[msg("Fulfillment mode Mint requires the program to hold the token_out mint authority")]
+    MintAuthorityRequired,
 }
 
 /// Generic token transfer function that handles both regular and PDA-signed transfers
@@ -59,6 +81,178 @@ pub fn transfer_tokens<'info>(
     token_interface::transfer_checked(cpi_context, amount, mint.decimals)
 }
 
+/// Token transfer that forwards the extra accounts required by a transfer hook
+///
+/// Without hook accounts this is a plain `transfer_checked` CPI. Otherwise the
+/// instruction is built through Token-2022's on-chain helper, which resolves the
+/// hook's extra account metas from `hook_accounts`. These must contain the hook
+/// program, its validation account and every account the validation data lists.
+///
+/// # Arguments
+/// * `token_program` - The SPL Token program
+/// * `accounts` - Source, mint, destination and authority of the transfer
+/// * `signer_seeds` - Optional PDA seeds for program-signed transfers (None for user-signed)
+/// * `amount` - Amount of tokens to transfer
+/// * `decimals` - Decimals of the mint
+/// * `hook_accounts` - Transfer hook accounts, empty for mints without a hook
+pub fn transfer_checked_with_hook<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    accounts: TransferChecked<'info>,
+    signer_seeds: Option<&[&[&[u8]]]>,
+    amount: u64,
+    decimals: u8,
+    hook_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if hook_accounts.is_empty() {
+        let cpi_context = match signer_seeds {
+            Some(seeds) => {
+                CpiContext::new_with_signer(token_program.to_account_info(), accounts, seeds)
+            }
+            None => CpiContext::new(token_program.to_account_info(), accounts),
+        };
+        return token_interface::transfer_checked(cpi_context, amount, decimals);
+    }
+
+    token_2022_cpi::onchain::invoke_transfer_checked(
+        token_program.key,
+        accounts.from,
+        accounts.mint,
+        accounts.to,
+        accounts.authority,
+        hook_accounts,
+        amount,
+        decimals,
+        signer_seeds.unwrap_or(&[]),
+    )
+    .map_err(Into::into)
+}
+
+/// Returns the transfer hook program of a Token-2022 mint, if it has one
+///
+/// # Arguments
+/// * `mint` - The token mint to check
+///
+/// # Returns
+/// * `Ok(Some(program_id))` - If the mint has a TransferHook extension with a program set
+/// * `Ok(None)` - If the mint has no hook, or is not a Token-2022 mint with extensions
+pub fn transfer_hook_program_id(mint: &InterfaceAccount<Mint>) -> Result<Option<Pubkey>> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+
+    match token_2022_cpi::extension::StateWithExtensions::<token_2022_cpi::state::Mint>::unpack(
+        &mint_data,
+    ) {
+        Ok(mint_state) => Ok(token_2022_cpi::extension::transfer_hook::get_program_id(
+            &mint_state,
+        )),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Validates that a mint only carries Token-2022 extensions the program supports
+///
+/// Transfer fees, metadata and approval-gated transfer hooks are accepted. A
+/// permanent delegate could move tokens out of the program's vaults, and
+/// confidential balances bypass the transfer accounting offers rely on, so both
+/// are rejected explicitly. Any other extension is rejected until reviewed.
+/// SPL Token mints carry no extensions and always pass.
+///
+/// # Arguments
+/// * `mint` - The token mint to check
+///
+/// # Returns
+/// * `Ok(())` - If every extension of the mint is allowlisted
+/// * `Err(TokenUtilsErrorCode::PermanentDelegateNotSupported)` - If the mint has a permanent delegate
+/// * `Err(TokenUtilsErrorCode::ConfidentialTransfersNotSupported)` - If the mint enables confidential transfers
+/// * `Err(TokenUtilsErrorCode::UnsupportedMintExtension)` - If the mint has any other extension
+pub fn validate_mint_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+
+    let Ok(mint_state) = token_2022_cpi::extension::StateWithExtensions::<
+        token_2022_cpi::state::Mint,
+    >::unpack(&mint_data) else {
+        return Ok(());
+    };
+
+    for extension_type in mint_state.get_extension_types()? {
+        match extension_type {
+            ExtensionType::TransferFeeConfig
+            | ExtensionType::MetadataPointer
+            | ExtensionType::TokenMetadata
+            | ExtensionType::TransferHook => {}
+            ExtensionType::PermanentDelegate => {
+                return Err(error!(TokenUtilsErrorCode::PermanentDelegateNotSupported))
+            }
+            ExtensionType::ConfidentialTransferMint
+            | ExtensionType::ConfidentialTransferFeeConfig
+            | ExtensionType::ConfidentialMintBurn => {
+                return Err(error!(
+                    TokenUtilsErrorCode::ConfidentialTransfersNotSupported
+                ))
+            }
+            _ => return Err(error!(TokenUtilsErrorCode::UnsupportedMintExtension)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Rejects mints whose decimals the pricing math cannot handle
+///
+/// Checked when offers are made, so a mint with excessive decimals fails at
+/// configuration time instead of on its first take.
+///
+/// # Arguments
+/// * `mint` - The token mint to check
+///
+/// # Returns
+/// * `Ok(())` - If the mint has at most `MAX_TOKEN_DECIMALS` decimals
+/// * `Err(TokenUtilsErrorCode::DecimalsExceedMax)` - If the mint has more decimals
+pub fn validate_mint_decimals(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    require_with_context!(
+        mint.decimals <= MAX_TOKEN_DECIMALS,
+        TokenUtilsErrorCode::DecimalsExceedMax,
+        mint = mint.key(),
+        decimals = mint.decimals
+    );
+    Ok(())
+}
+
+/// Selects the transfer hook accounts to forward for a token_in mint
+///
+/// Mints without a transfer hook are unaffected and never receive hook accounts.
+/// Transfer-hook mints must have been approved by the boss for their current hook
+/// program, in which case all `remaining_accounts` are forwarded to the transfers.
+///
+/// # Arguments
+/// * `mint` - The token_in mint
+/// * `approval` - The boss approval for the mint, if passed
+/// * `remaining_accounts` - The instruction's remaining accounts
+///
+/// # Returns
+/// * `Ok(accounts)` - The hook accounts to forward, empty for mints without a hook
+/// * `Err(TokenUtilsErrorCode::TransferHookMintNotApproved)` - If the hooked mint is not approved
+/// * `Err(TokenUtilsErrorCode::TransferHookProgramMismatch)` - If the hook program changed since approval
+pub fn resolve_transfer_hook_accounts<'a, 'info>(
+    mint: &InterfaceAccount<Mint>,
+    approval: Option<&TransferHookMintApproval>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a [AccountInfo<'info>]> {
+    let Some(hook_program) = transfer_hook_program_id(mint)? else {
+        return Ok(&[]);
+    };
+
+    let approval = approval.ok_or(TokenUtilsErrorCode::TransferHookMintNotApproved)?;
+    require_keys_eq!(
+        approval.hook_program,
+        hook_program,
+        TokenUtilsErrorCode::TransferHookProgramMismatch
+    );
+
+    Ok(remaining_accounts)
+}
+
 /// Calculates token_out_amount based on token_in_amount, price, and decimals.
 /// This formula is used in both single and dual redemption offers.
 ///
@@ -71,7 +265,8 @@ pub fn transfer_tokens<'info>(
 /// * `token_out_decimals` - Decimal places of output token
 ///
 /// # Returns
-/// The calculated amount of output tokens
+/// The calculated amount of output tokens, rounded down (`PAYOUT_ROUNDING`) so the
+/// payout never exceeds the value of the input
 ///
 /// # Errors
 /// Returns MathOverflow if calculation exceeds u128 limits
@@ -97,22 +292,69 @@ pub fn calculate_token_out_amount(
         TokenUtilsErrorCode::DecimalsExceedMax
     );
 
-    let token_in_amount_u128 = token_in_amount as u128;
-    let price_u128 = price as u128;
+    // Calculate: token_in_amount * 10^(token_out_decimals + 9) / (price * 10^token_in_decimals)
+    // rounded down since the result is paid out
+    let result = scale_amount_rounded(
+        token_in_amount,
+        1,
+        price as u128,
+        (token_out_decimals + PRICE_DECIMALS) as u32,
+        token_in_decimals as u32,
+        PAYOUT_ROUNDING,
+    )
+    .ok_or(TokenUtilsErrorCode::MathOverflow)?;
 
-    // Calculate: numerator = token_in_amount * 10^(token_out_decimals + 9)
-    let numerator = token_in_amount_u128
-        .checked_mul(10_u128.pow((token_out_decimals + PRICE_DECIMALS) as u32))
-        .ok_or(TokenUtilsErrorCode::MathOverflow)?;
+    // Validate result fits in u64 before casting
+    require!(
+        result <= u64::MAX as u128,
+        TokenUtilsErrorCode::ResultOverflow
+    );
 
-    // Calculate: denominator = price * 10^token_in_decimals
-    let denominator = price_u128
-        .checked_mul(10_u128.pow(token_in_decimals as u32))
-        .ok_or(TokenUtilsErrorCode::MathOverflow)?;
+    Ok(result as u64)
+}
+
+/// Calculates the token_in amount worth a given token_out amount, rounding up
+///
+/// Inverse of `calculate_token_out_amount`: computes
+/// `ceil(token_out_amount * price * 10^token_in_decimals / 10^(token_out_decimals + 9))`
+/// (`REQUIRED_INPUT_ROUNDING`) so that the returned token_in amount always buys at
+/// least `token_out_amount`.
+///
+/// # Arguments
+/// * `token_out_amount` - Amount of token_out to acquire
+/// * `price` - Price of one token_out in token_in with scale=9
+/// * `token_in_decimals` - Decimals of the token_in mint
+/// * `token_out_decimals` - Decimals of the token_out mint
+///
+/// # Returns
+/// * `Ok(amount)` - The required token_in amount
+/// * `Err(_)` - If the price is zero, decimals exceed the maximum or the result overflows
+pub fn calculate_token_in_amount(
+    token_out_amount: u64,
+    price: u64,
+    token_in_decimals: u8,
+    token_out_decimals: u8,
+) -> Result<u64> {
+    require!(price > 0, TokenUtilsErrorCode::ZeroPriceNotAllowed);
+    require!(
+        token_in_decimals <= MAX_TOKEN_DECIMALS,
+        TokenUtilsErrorCode::DecimalsExceedMax
+    );
+    require!(
+        token_out_decimals <= MAX_TOKEN_DECIMALS,
+        TokenUtilsErrorCode::DecimalsExceedMax
+    );
 
-    let result = numerator / denominator;
+    let result = scale_amount_rounded(
+        token_out_amount,
+        price as u128,
+        1,
+        token_in_decimals as u32,
+        (token_out_decimals + PRICE_DECIMALS) as u32,
+        REQUIRED_INPUT_ROUNDING,
+    )
+    .ok_or(TokenUtilsErrorCode::MathOverflow)?;
 
-    // Validate result fits in u64 before casting
     require!(
         result <= u64::MAX as u128,
         TokenUtilsErrorCode::ResultOverflow
@@ -161,6 +403,9 @@ pub struct CalculateFeeResult {
 
 /// Calculates fee amount and remaining amount after fee deduction
 ///
+/// The fee rounds up (`FEE_ROUNDING`), so any non-zero fee rate charges at least
+/// one base unit on a non-zero amount and the remainder never favors the payer.
+///
 /// # Arguments
 /// * `token_in_amount` - Total amount of token_in being processed
 /// * `fee_basis_points` - Fee percentage in basis points (e.g., 500 = 5%)
@@ -172,21 +417,16 @@ pub struct CalculateFeeResult {
 /// * `MathOverflow` - If calculations exceed u128 limits
 ///
 /// # Example
-/// ```
+/// ```ignore
 /// // 5% fee on 1000 tokens = 50 fee, 950 remaining
 /// let result = calculate_fees(1000, 500)?;
 /// assert_eq!(result.fee_amount, 50);
 /// assert_eq!(result.remaining_token_in_amount, 950);
 /// ```
 pub fn calculate_fees(token_in_amount: u64, fee_basis_points: u16) -> Result<CalculateFeeResult> {
-    // Calculate fee amount in token_in tokens using ceiling division
-    // This ensures fees always round up in favor of the protocol
-    let token_fee_amount = (token_in_amount as u128)
-        .checked_mul(fee_basis_points as u128)
-        .ok_or(TokenUtilsErrorCode::MathOverflow)?
-        .checked_add(MAX_BASIS_POINTS as u128 - 1)
-        .and_then(|adjusted| adjusted.checked_div(MAX_BASIS_POINTS as u128))
-        .ok_or(TokenUtilsErrorCode::MathOverflow)? as u64;
+    // Fees always round up in favor of the protocol
+    let token_fee_amount =
+        fee_amount(token_in_amount, fee_basis_points).ok_or(TokenUtilsErrorCode::MathOverflow)?;
 
     // Amount after fee deduction for the main offer exchange
     let token_net_amount = token_in_amount
@@ -225,7 +465,7 @@ pub fn calculate_fees(token_in_amount: u64, fee_basis_points: u16) -> Result<Cal
 pub fn mint_tokens<'info>(
     token_program: &Interface<'info, TokenInterface>,
     mint: &InterfaceAccount<'info, Mint>,
-    to_account: &InterfaceAccount<'info, TokenAccount>,
+    to_account: &AccountInfo<'info>,
     authority: &AccountInfo<'info>,
     signer_seeds: &[&[&[u8]]],
     amount: u64,
@@ -238,16 +478,19 @@ pub fn mint_tokens<'info>(
             .checked_add(amount)
             .ok_or(TokenUtilsErrorCode::MathOverflow)?;
 
-        require!(
+        require_with_context!(
             new_supply <= max_supply,
-            TokenUtilsErrorCode::MaxSupplyExceeded
+            TokenUtilsErrorCode::MaxSupplyExceeded,
+            current_supply = current_supply,
+            amount = amount,
+            max_supply = max_supply
         );
     }
 
     // Perform the mint operation
     let mint_accounts = MintToChecked {
         mint: mint.to_account_info(),
-        to: to_account.to_account_info(),
+        to: to_account.clone(),
         authority: authority.to_account_info(),
     };
 
@@ -304,6 +547,12 @@ pub struct ExecTokenOpsParams<'a, 'info> {
     pub token_in_net_amount: u64,
     /// Amount of token_in fee
     pub token_in_fee_amount: u64,
+    /// Token-2022 transfer fee the mint withholds from the user's token_in payment
+    ///
+    /// `None` rejects token_in mints with a transfer fee. `Some(fee)` grosses the
+    /// payment up by `fee`, so that net and fee amounts are what the destination
+    /// receives; used by offers priced on the net received amount.
+    pub token_in_transfer_fee: Option<u64>,
     /// Authority that can transfer from the source account
     pub token_in_authority: &'a AccountInfo<'info>,
     /// Optional PDA seeds for program-signed token_in transfers
@@ -318,6 +567,8 @@ pub struct ExecTokenOpsParams<'a, 'info> {
     pub token_in_burn_account: &'a InterfaceAccount<'info, TokenAccount>,
     /// Authority for burning tokens from the vault
     pub token_in_burn_authority: &'a AccountInfo<'info>,
+    /// Extra accounts forwarded to token_in transfers of transfer-hook mints
+    pub token_in_transfer_hook_accounts: &'a [AccountInfo<'info>],
 
     // Token out params
     /// Mint account for the output token
@@ -329,13 +580,18 @@ pub struct ExecTokenOpsParams<'a, 'info> {
     /// Source account for token_out transfers (vault account)
     pub token_out_source_account: &'a InterfaceAccount<'info, TokenAccount>,
     /// Destination account for token_out (user's account)
-    pub token_out_destination_account: &'a InterfaceAccount<'info, TokenAccount>,
+    ///
+    /// Passed untyped so takes can create it in the handler; only ever written by
+    /// checked token instructions, which validate it as a token_out account.
+    pub token_out_destination_account: &'a AccountInfo<'info>,
     /// PDA for mint authority operations
     pub mint_authority_pda: &'a AccountInfo<'info>,
     /// Bump seed for mint authority PDA
     pub mint_authority_bump: &'a [u8],
     /// Maximum supply cap for token_out minting (0 = no cap)
     pub token_out_max_supply: u64,
+    /// How token_out is delivered, `None` to decide by the token_out mint authority
+    pub token_out_fulfillment_mode: Option<FulfillmentMode>,
 }
 
 /// Executes token operations for exchanging token_in for token_out
@@ -345,22 +601,34 @@ pub struct ExecTokenOpsParams<'a, 'info> {
 /// to provide maximum flexibility for different token configurations.
 ///
 /// # Token In Processing
-/// - Validates that token_in does not have Token-2022 transfer fees
+/// - Validates that token_in does not have Token-2022 transfer fees, unless
+///   `token_in_transfer_fee` grosses the payment up by the withheld fee
 /// - If program has mint authority:
 ///   - Transfers net amount (after fees) to vault → burns only net amount
 ///   - Transfers fee amount directly to boss account
 /// - If program lacks mint authority: transfers full amount directly to boss/destination (standard transfer)
+/// - Transfers of approved transfer-hook mints forward `token_in_transfer_hook_accounts`
 ///
 /// # Token Out Processing
 /// - Validates that token_out does not have Token-2022 transfer fees
-/// - If program has mint authority: mints directly to user (inflationary)
-/// - If program lacks mint authority: transfers from vault to user (standard transfer)
+/// - `Mint` fulfillment: mints directly to user (inflationary), failing if the program
+///   lacks the mint authority
+/// - `Transfer` fulfillment: transfers from vault to user (standard transfer)
+/// - Without a fulfillment mode: mints if the program has the mint authority and
+///   transfers from the vault otherwise
+///
+/// # Invariants
+/// - The balances of all involved accounts are captured before the exchange and
+///   checked afterwards against the computed amounts less Token-2022 transfer fees
 ///
 /// # Arguments
 /// * `params` - Complete parameter structure containing all required accounts and amounts
 ///
 /// # Returns
-/// * `Ok(())` - If all token operations complete successfully
+/// * `Ok(fulfillment_mode)` - How token_out was delivered, if all token operations complete successfully
+/// * `Err(TokenUtilsErrorCode::MintAuthorityRequired)` - If `Mint` fulfillment is configured
+///   but the program lacks the token_out mint authority
+/// * `Err(TokenUtilsErrorCode::InvariantViolation)` - If a balance deviates from the computed amounts
 /// * `Err(_)` - If any transfer, mint, or burn operation fails
 ///
 /// # Security
@@ -368,30 +636,51 @@ pub struct ExecTokenOpsParams<'a, 'info> {
 /// - PDA seeds are used for program-signed operations
 /// - Authority validation ensures only authorized transfers
 /// - Token-2022 tokens with transfer fees are completely blocked to prevent burn path issues and transfer discrepancies
-pub fn execute_token_operations(params: ExecTokenOpsParams) -> Result<()> {
-    // Validate that neither token has Token-2022 transfer fees
-    require!(
-        !has_transfer_fee(params.token_in_mint)?,
-        TokenUtilsErrorCode::TransferFeeNotSupported
-    );
+pub fn execute_token_operations(params: ExecTokenOpsParams) -> Result<FulfillmentMode> {
+    let controls_token_in_mint =
+        program_controls_mint(params.token_in_mint, params.mint_authority_pda);
+
+    // Validate that neither token has Token-2022 transfer fees, unless the caller
+    // accounts for the token_in transfer fee outside of the burn path
+    match params.token_in_transfer_fee {
+        Some(transfer_fee) => require!(
+            transfer_fee == 0 || !controls_token_in_mint,
+            TokenUtilsErrorCode::TransferFeeNotSupported
+        ),
+        None => require!(
+            !has_transfer_fee(params.token_in_mint)?,
+            TokenUtilsErrorCode::TransferFeeNotSupported
+        ),
+    }
     require!(
         !has_transfer_fee(params.token_out_mint)?,
         TokenUtilsErrorCode::TransferFeeNotSupported
     );
+    let fulfillment_mode = match params.token_out_fulfillment_mode {
+        Some(FulfillmentMode::Mint) => {
+            require!(
+                program_controls_mint(params.token_out_mint, params.mint_authority_pda),
+                TokenUtilsErrorCode::MintAuthorityRequired
+            );
+            FulfillmentMode::Mint
+        }
+        Some(FulfillmentMode::Transfer) => FulfillmentMode::Transfer,
+        None if program_controls_mint(params.token_out_mint, params.mint_authority_pda) => {
+            FulfillmentMode::Mint
+        }
+        None => FulfillmentMode::Transfer,
+    };
+    let mints_token_out = fulfillment_mode == FulfillmentMode::Mint;
 
-    // Step 1: User pays token_in
-    let controls_token_in_mint =
-        program_controls_mint(params.token_in_mint, params.mint_authority_pda);
+    // Capture the balances every leg is expected to change before any tokens move
+    let invariants = expect_token_operations(&params, controls_token_in_mint, mints_token_out)?;
 
+    // Step 1: User pays token_in
     if controls_token_in_mint {
         // Transfer net amount to burn account
-        transfer_tokens(
-            params.token_in_mint,
-            params.token_in_program,
-            params.token_in_source_account,
+        transfer_token_in(
+            &params,
             params.token_in_burn_account,
-            params.token_in_authority,
-            params.token_in_source_signer_seeds,
             params.token_in_net_amount,
         )?;
 
@@ -408,37 +697,23 @@ pub fn execute_token_operations(params: ExecTokenOpsParams) -> Result<()> {
         // Transfer fee amount directly to boss account
         if params.token_in_fee_amount > 0 {
             msg!("Transferring fee amount to boss account");
-            transfer_tokens(
-                params.token_in_mint,
-                params.token_in_program,
-                params.token_in_source_account,
+            transfer_token_in(
+                &params,
                 params.token_in_destination_account,
-                params.token_in_authority,
-                params.token_in_source_signer_seeds,
                 params.token_in_fee_amount,
             )?;
         }
     } else {
         // When program lacks mint authority: transfer full amount to boss
-        // Use checked_add to prevent overflow
-        let total_amount = params
-            .token_in_net_amount
-            .checked_add(params.token_in_fee_amount)
-            .ok_or(TokenUtilsErrorCode::MathOverflow)?;
-
-        transfer_tokens(
-            params.token_in_mint,
-            params.token_in_program,
-            params.token_in_source_account,
+        transfer_token_in(
+            &params,
             params.token_in_destination_account,
-            params.token_in_authority,
-            params.token_in_source_signer_seeds,
-            total_amount,
+            token_in_gross_amount(&params)?,
         )?;
     }
 
     // Step 2: Program distributes token_out
-    if program_controls_mint(params.token_out_mint, params.mint_authority_pda) {
+    if mints_token_out {
         let mint_authority_seeds = &[seeds::MINT_AUTHORITY, params.mint_authority_bump];
         let mint_authority_signer_seeds = &[mint_authority_seeds.as_slice()];
 
@@ -452,18 +727,121 @@ pub fn execute_token_operations(params: ExecTokenOpsParams) -> Result<()> {
             params.token_out_max_supply,
         )?;
     } else {
-        transfer_tokens(
-            params.token_out_mint,
+        transfer_checked_with_hook(
             params.token_out_program,
-            params.token_out_source_account,
-            params.token_out_destination_account,
-            params.token_out_authority,
+            TransferChecked {
+                mint: params.token_out_mint.to_account_info(),
+                from: params.token_out_source_account.to_account_info(),
+                to: params.token_out_destination_account.clone(),
+                authority: params.token_out_authority.clone(),
+            },
             params.vault_authority_signer_seeds,
             params.token_out_amount,
+            params.token_out_mint.decimals,
+            &[],
         )?;
     }
 
-    Ok(())
+    // Step 3: Every balance must have moved by exactly the computed amounts
+    invariants.verify()?;
+
+    Ok(fulfillment_mode)
+}
+
+/// Registers the balance changes `execute_token_operations` is expected to make
+///
+/// The user's token_in debit is net amount plus fee. The boss or vault is credited
+/// with that debit less Token-2022 transfer fees; in the burn path the net amount
+/// passes through the burn account and only the fee is credited. The vault's token_out
+/// decrease equals the computed payout, or nothing moves out of it when token_out is
+/// minted.
+fn expect_token_operations<'info>(
+    params: &ExecTokenOpsParams<'_, 'info>,
+    controls_token_in_mint: bool,
+    mints_token_out: bool,
+) -> Result<BalanceInvariants<'info>> {
+    let mut invariants = BalanceInvariants::default();
+    let token_in_source = params.token_in_source_account.to_account_info();
+    let token_in_destination = params.token_in_destination_account.to_account_info();
+
+    if controls_token_in_mint {
+        let burn_account = params.token_in_burn_account.to_account_info();
+        let net_fee = transfer_fee_for(params.token_in_mint, params.token_in_net_amount)?;
+        invariants.expect_transfer(
+            &token_in_source,
+            &burn_account,
+            params.token_in_net_amount,
+            net_fee,
+        )?;
+        invariants.expect_change(&burn_account, -(params.token_in_net_amount as i128))?;
+        if params.token_in_fee_amount > 0 {
+            invariants.expect_transfer(
+                &token_in_source,
+                &token_in_destination,
+                params.token_in_fee_amount,
+                transfer_fee_for(params.token_in_mint, params.token_in_fee_amount)?,
+            )?;
+        }
+    } else {
+        let total_amount = token_in_gross_amount(params)?;
+        let transfer_fee = transfer_fee_for(params.token_in_mint, total_amount)?;
+        // The destination must receive exactly the net and fee amounts priced on
+        require!(
+            transfer_fee == params.token_in_transfer_fee.unwrap_or(0),
+            TokenUtilsErrorCode::InvariantViolation
+        );
+        invariants.expect_transfer(
+            &token_in_source,
+            &token_in_destination,
+            total_amount,
+            transfer_fee,
+        )?;
+    }
+
+    let token_out_destination = params.token_out_destination_account.clone();
+    if mints_token_out {
+        invariants.expect_change(&token_out_destination, params.token_out_amount as i128)?;
+    } else {
+        invariants.expect_transfer(
+            &params.token_out_source_account.to_account_info(),
+            &token_out_destination,
+            params.token_out_amount,
+            transfer_fee_for(params.token_out_mint, params.token_out_amount)?,
+        )?;
+    }
+
+    Ok(invariants)
+}
+
+/// Returns the token_in amount debited from the user: net amount, fee and any
+/// transfer fee withheld by the mint
+fn token_in_gross_amount(params: &ExecTokenOpsParams) -> Result<u64> {
+    params
+        .token_in_net_amount
+        .checked_add(params.token_in_fee_amount)
+        .and_then(|amount| amount.checked_add(params.token_in_transfer_fee.unwrap_or(0)))
+        .ok_or_else(|| error!(TokenUtilsErrorCode::MathOverflow))
+}
+
+/// Transfers token_in from the exchange's source account, forwarding any hook accounts
+fn transfer_token_in<'info>(
+    params: &ExecTokenOpsParams<'_, 'info>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    transfer_checked_with_hook(
+        params.token_in_program,
+        TransferChecked {
+            mint: params.token_in_mint.to_account_info(),
+            from: params.token_in_source_account.to_account_info(),
+            to: destination.to_account_info(),
+            authority: params.token_in_authority.clone(),
+        },
+        params.token_in_source_signer_seeds,
+        amount,
+        params.token_in_mint.decimals,
+        params.token_in_transfer_hook_accounts,
+    )
 }
 
 /// Returns true iff `mint.mint_authority == Some(mint_authority_pda.key())`.
@@ -516,3 +894,25 @@ pub fn has_transfer_fee(mint: &InterfaceAccount<Mint>) -> Result<bool> {
         }
     }
 }
+
+/// Returns the Token-2022 transfer fee the mint withholds from a transfer of `amount`
+///
+/// # Returns
+/// * `Ok(fee)` - The fee of the current epoch, 0 for mints without a transfer fee
+/// * `Err(_)` - If there's an error reading the mint data or the fee overflows
+pub fn transfer_fee_for(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+
+    let Ok(mint_state) = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)
+    else {
+        return Ok(0);
+    };
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or_else(|| error!(TokenUtilsErrorCode::MathOverflow))
+}
@@ -13,7 +13,7 @@ export async function executeMarketApy(opts: GlobalOptions & Record<string, any>
 
         // Call the view method
         const apy = await helper.program.methods
-            .getApy()
+            .getApy(null)
             .accounts({
                 tokenInMint: new PublicKey(params.tokenIn),
                 tokenOutMint: new PublicKey(params.tokenOut),
@@ -1,3 +1,4 @@
+import { PublicKey } from "@solana/web3.js";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { config } from "../../../utils/script-helper";
 import type { GlobalOptions } from "../../prompts";
@@ -15,14 +16,20 @@ export async function executeMarketSupply(opts: GlobalOptions): Promise<void> {
         // Determine the correct token program for ONyc
         const tokenProgram = getTokenProgramId(config.mints.onyc);
 
-        // Get the offer vault token account
+        // Get the offer and redemption vault token accounts
         const onycVaultAccount = getAssociatedTokenAddressSync(config.mints.onyc, helper.pdas.offerVaultAuthorityPda, true, tokenProgram);
+        const [redemptionVaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("redemption_offer_vault_authority")],
+            helper.program.programId
+        );
+        const redemptionVaultOnycAccount = getAssociatedTokenAddressSync(config.mints.onyc, redemptionVaultAuthority, true, tokenProgram);
 
         // Call the view method
         const supply = await helper.program.methods
             .getCirculatingSupply()
             .accounts({
                 onycVaultAccount,
+                redemptionVaultOnycAccount,
                 tokenProgram,
             })
             .view();
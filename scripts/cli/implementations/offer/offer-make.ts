@@ -45,6 +45,7 @@ export async function executeOfferMake(opts: GlobalOptions & Record<string, any>
                     feeBasisPoints: params.fee,
                     needsApproval: params.needsApproval,
                     allowPermissionless: params.permissionless,
+                    fulfillmentMode: params.fulfillmentMode === "auto" ? undefined : params.fulfillmentMode,
                     boss,
                 });
                 instructions.push(makeOfferIx);
@@ -61,6 +62,7 @@ export async function executeOfferMake(opts: GlobalOptions & Record<string, any>
                     fee: `${params.fee / 100}% (${params.fee} bps)`,
                     needsApproval: params.needsApproval,
                     permissionless: params.permissionless,
+                    fulfillmentMode: params.fulfillmentMode,
                 },
             },
         });
@@ -32,6 +32,18 @@ export const makeOfferParams: ParamDefinition[] = [
         flag: "--permissionless",
         default: true,
     },
+    {
+        name: "fulfillmentMode",
+        type: "select",
+        description: "How takes deliver token_out",
+        required: true,
+        flag: "--fulfillment-mode",
+        choices: [
+            { name: "Transfer from the offer vault", value: "transfer" },
+            { name: "Mint (program must hold the mint authority)", value: "mint" },
+            { name: "Auto (mint whenever the program holds the mint authority)", value: "auto" },
+        ],
+    },
 ];
 
 export const addVectorParams: ParamDefinition[] = [
@@ -76,6 +76,13 @@ export class ScriptHelper {
         };
     }
 
+    /**
+     * Derive the PDA of the permissionless authority with the given index
+     */
+    getPermissionlessAuthorityPda(index: number): PublicKey {
+        return PublicKey.findProgramAddressSync([Buffer.from(`permissionless-${index}`)], this.program.programId)[0];
+    }
+
     /**
      * Create IDL with correct program ID for the active network
      */
@@ -269,19 +276,23 @@ export class ScriptHelper {
         feeBasisPoints?: number;
         needsApproval?: boolean;
         allowPermissionless?: boolean;
+        fulfillmentMode?: "transfer" | "mint";
         tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
         boss: PublicKey;
     }) {
         const feeBasisPoints = params.feeBasisPoints ?? 0;
         const needsApproval = params.needsApproval ?? false;
         const allowPermissionless = params.allowPermissionless ?? false;
+        const fulfillmentMode = params.fulfillmentMode ? { [params.fulfillmentMode]: {} } as any : null;
 
         return await this.program.methods
-            .makeOffer(feeBasisPoints, needsApproval, allowPermissionless)
+            .makeOffer(feeBasisPoints, needsApproval, allowPermissionless, fulfillmentMode)
             .accountsPartial({
                 tokenInMint: params.tokenInMint,
                 tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
                 tokenOutMint: params.tokenOutMint,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
                 boss: params.boss
             })
             .instruction();
@@ -302,7 +313,8 @@ export class ScriptHelper {
                 new BN(params.baseTime),
                 new BN(params.basePrice),
                 new BN(params.apr),
-                new BN(params.priceFixDuration)
+                new BN(params.priceFixDuration),
+                { step: {} }
             )
             .accountsPartial({
                 tokenInMint: params.tokenInMint,
@@ -352,6 +364,8 @@ export class ScriptHelper {
         approvalMessage?: any;
         tokenInProgram?: PublicKey;
         tokenOutProgram?: PublicKey;
+        // Required for offers paying take proceeds to the boss
+        bossTokenInAccount?: PublicKey;
     }) {
         return await this.program.methods
             .takeOffer(new BN(params.tokenInAmount), null)
@@ -360,7 +374,8 @@ export class ScriptHelper {
                 tokenOutMint: params.tokenOutMint,
                 user: params.user,
                 tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
+                bossTokenInAccount: params.bossTokenInAccount ?? null
             })
             .instruction();
     }
@@ -373,13 +388,18 @@ export class ScriptHelper {
         approvalMessage?: any;
         tokenInProgram?: PublicKey;
         tokenOutProgram?: PublicKey;
+        // Required for offers paying take proceeds to the boss
+        bossTokenInAccount?: PublicKey;
+        // Permissionless authority routing the take (defaults to permissionless-1)
+        authorityIndex?: number;
     }) {
+        const authorityIndex = params.authorityIndex ?? 1;
         const vaultAuthority = this.pdas.offerVaultAuthorityPda;
-        const permissionlessAuthority = this.pdas.permissionlessVaultAuthorityPda;
+        const permissionlessAuthority = this.getPermissionlessAuthorityPda(authorityIndex);
         const mintAuthority = this.pdas.mintAuthorityPda;
 
         return await this.program.methods
-            .takeOfferPermissionless(new BN(params.tokenInAmount), null)
+            .takeOfferPermissionless(new BN(params.tokenInAmount), null, authorityIndex)
             .accounts({
                 tokenInMint: params.tokenInMint,
                 tokenOutMint: params.tokenOutMint,
@@ -388,7 +408,44 @@ export class ScriptHelper {
                 permissionlessAuthority,
                 mintAuthority,
                 tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
+                bossTokenInAccount: params.bossTokenInAccount ?? null
+            })
+            .instruction();
+    }
+
+    async buildSetOfferProceedsToVaultIx(params: {
+        tokenInMint: PublicKey;
+        tokenOutMint: PublicKey;
+        proceedsToVault: boolean;
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .setOfferProceedsToVault(params.proceedsToVault)
+            .accountsPartial({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildCollectProceedsIx(params: {
+        amount: number;
+        tokenInMint: PublicKey;
+        tokenOutMint: PublicKey;
+        destination?: PublicKey;
+        tokenInProgram?: PublicKey;
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .collectProceeds(new BN(params.amount))
+            .accountsPartial({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                destination: params.destination ?? params.boss,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                boss: params.boss
             })
             .instruction();
     }
@@ -412,6 +469,7 @@ export class ScriptHelper {
     async buildOfferVaultWithdrawIx(params: {
         amount: number,
         tokenMint: PublicKey,
+        destination?: PublicKey,
         tokenProgram?: PublicKey,
         boss: PublicKey;
     }) {
@@ -419,6 +477,38 @@ export class ScriptHelper {
             .offerVaultWithdraw(new BN(params.amount))
             .accountsPartial({
                 tokenMint: params.tokenMint,
+                destination: params.destination ?? params.boss,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildProposeVaultWithdrawIx(params: {
+        amount: number,
+        tokenMint: PublicKey,
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .proposeVaultWithdraw(new BN(params.amount))
+            .accountsPartial({
+                tokenMint: params.tokenMint,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildExecuteVaultWithdrawIx(params: {
+        tokenMint: PublicKey,
+        destination?: PublicKey,
+        tokenProgram?: PublicKey,
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .executeVaultWithdraw()
+            .accountsPartial({
+                tokenMint: params.tokenMint,
+                destination: params.destination ?? params.boss,
                 tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID,
                 boss: params.boss
             })
@@ -444,6 +534,7 @@ export class ScriptHelper {
     async buildRedemptionVaultWithdrawIx(params: {
         amount: number;
         tokenMint: PublicKey;
+        destination?: PublicKey;
         tokenProgram?: PublicKey;
         boss: PublicKey;
     }) {
@@ -451,6 +542,7 @@ export class ScriptHelper {
             .redemptionVaultWithdraw(new BN(params.amount))
             .accountsPartial({
                 tokenMint: params.tokenMint,
+                destination: params.destination ?? params.boss,
                 tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID,
                 boss: params.boss
             })
@@ -531,22 +623,72 @@ export class ScriptHelper {
             .instruction();
     }
 
-    async buildSetKillSwitchIx(params: { enable: boolean; boss: PublicKey }) {
+    async buildSetKillSwitchIx(params: { enable: boolean; duration?: number; boss: PublicKey }) {
         return await this.program.methods
-            .setKillSwitch(params.enable)
+            .setKillSwitch(params.enable, params.duration !== undefined ? new BN(params.duration) : null)
             .accountsPartial({
                 signer: params.boss
             })
             .instruction();
     }
 
-    async buildCloseStateIx(params: { boss: PublicKey }) {
+    async buildProposeCloseStateIx(params: { boss: PublicKey }) {
+        return await this.program.methods
+            .proposeCloseState()
+            .accountsPartial({
+                boss: params.boss,
+                state: this.statePda
+            })
+            .instruction();
+    }
+
+    async buildCloseStateIx(params: { boss: PublicKey; offers?: PublicKey[] }) {
         return await this.program.methods
             .closeState()
             .accountsPartial({
                 boss: params?.boss,
                 state: this.statePda
             })
+            .remainingAccounts((params.offers ?? []).map(pubkey => ({ pubkey, isSigner: false, isWritable: false })))
+            .instruction();
+    }
+
+    async buildMigrateStateIx(params: { targetVersion: number; boss: PublicKey }) {
+        return await this.program.methods
+            .migrateState(params.targetVersion)
+            .accountsPartial({
+                boss: params.boss,
+                state: this.statePda
+            })
+            .instruction();
+    }
+
+    async buildInitializeConfigIx(params: { boss: PublicKey }) {
+        return await this.program.methods
+            .initializeConfig()
+            .accountsPartial({
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildSetConfigIx(params: {
+        maxFeeBps: number;
+        maxApprovalValiditySecs: number;
+        secondsPerYear: number;
+        clockSkewGraceSecs?: number;
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .setConfig(
+                params.maxFeeBps,
+                new BN(params.maxApprovalValiditySecs),
+                new BN(params.secondsPerYear),
+                new BN(params.clockSkewGraceSecs ?? 0)
+            )
+            .accountsPartial({
+                boss: params.boss
+            })
             .instruction();
     }
 
@@ -559,6 +701,24 @@ export class ScriptHelper {
             .instruction();
     }
 
+    async buildProposeMaxSupplyIx(params: { maxSupply: number; boss: PublicKey }) {
+        return await this.program.methods
+            .proposeMaxSupply(new BN(params.maxSupply))
+            .accountsPartial({
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildAcceptMaxSupplyIx(params: { boss: PublicKey }) {
+        return await this.program.methods
+            .acceptMaxSupply()
+            .accountsPartial({
+                boss: params.boss
+            })
+            .instruction();
+    }
+
     async buildAddApproverIx(params: { approver: PublicKey; boss: PublicKey }) {
         return await this.program.methods
             .addApprover(params.approver)
@@ -602,10 +762,12 @@ export class ScriptHelper {
             .instruction();
     }
 
-    async buildInitializePermissionlessAuthorityIx(params: { name: string; boss: PublicKey }) {
+    async buildInitializePermissionlessAuthorityIx(params: { name: string; boss: PublicKey; index?: number }) {
+        const index = params.index ?? 1;
         return await this.program.methods
-            .initializePermissionlessAuthority(params.name)
+            .initializePermissionlessAuthority(index, params.name)
             .accountsPartial({
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(index),
                 boss: params.boss
             })
             .instruction();
@@ -738,6 +900,48 @@ export class ScriptHelper {
             .instruction();
     }
 
+    async buildFulfillRedemptionRequestBasketIx(params: {
+        redemptionOfferPda: PublicKey;
+        redemptionRequestPda: PublicKey;
+        redemptionAdmin: PublicKey;
+        redeemer: PublicKey;
+        legMints: PublicKey[];
+        legTokenInAmounts?: BN[];
+        tokenOutProgram?: PublicKey;
+        redemptionBasket?: PublicKey;
+    }) {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const [redemptionVaultAuthority] = PublicKey.findProgramAddressSync(
+            [Buffer.from("redemption_offer_vault_authority")],
+            this.program.programId
+        );
+        const remainingAccounts = params.legMints.flatMap(mint => [
+            { pubkey: mint, isSigner: false, isWritable: true },
+            {
+                pubkey: getAssociatedTokenAddressSync(mint, redemptionVaultAuthority, true, tokenOutProgram),
+                isSigner: false,
+                isWritable: true
+            },
+            {
+                pubkey: getAssociatedTokenAddressSync(mint, params.redeemer, true, tokenOutProgram),
+                isSigner: false,
+                isWritable: true
+            }
+        ]);
+
+        return await this.program.methods
+            .fulfillRedemptionRequestBasket(params.legTokenInAmounts ?? [])
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                redemptionRequest: params.redemptionRequestPda,
+                redemptionAdmin: params.redemptionAdmin,
+                tokenOutProgram,
+                redemptionBasket: params.redemptionBasket ?? null
+            })
+            .remainingAccounts(remainingAccounts)
+            .instruction();
+    }
+
     async buildCancelRedemptionRequestIx(params: {
         redemptionOfferPda: PublicKey;
         redemptionRequestPda: PublicKey;
@@ -753,6 +957,37 @@ export class ScriptHelper {
             .instruction();
     }
 
+    async buildCancelAllRedemptionRequestsIx(params: {
+        redemptionOfferPda: PublicKey;
+        redemptionRequestPdas: PublicKey[];
+        redeemer: PublicKey;
+    }) {
+        return await this.program.methods
+            .cancelAllRedemptionRequests()
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                redeemer: params.redeemer
+            })
+            .remainingAccounts(params.redemptionRequestPdas.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })))
+            .instruction();
+    }
+
+    async buildAssignRedemptionRequestIx(params: {
+        redemptionOfferPda: PublicKey;
+        redemptionRequestPda: PublicKey;
+        redeemer: PublicKey;
+        newRedeemer: PublicKey;
+    }) {
+        return await this.program.methods
+            .assignRedemptionRequest(params.newRedeemer)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                redemptionRequest: params.redemptionRequestPda,
+                redeemer: params.redeemer
+            })
+            .instruction();
+    }
+
     async buildUpdateRedemptionOfferFeeIx(params: {
         redemptionOfferPda: PublicKey;
         newFeeBasisPoints: number;
@@ -767,6 +1002,85 @@ export class ScriptHelper {
             .instruction();
     }
 
+    async buildSetRedemptionOfferPriceModeIx(params: {
+        redemptionOfferPda: PublicKey;
+        priceMode: "atFulfillment" | "lockedAtRequest";
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .setRedemptionOfferPriceMode({ [params.priceMode]: {} } as any)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildConfigureRedemptionBasketIx(params: {
+        redemptionOfferPda: PublicKey;
+        mints: PublicKey[];
+        weightsBps: number[];
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .configureRedemptionBasket(params.mints, params.weightsBps)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildSetRedemptionOfferInKindIx(params: {
+        redemptionOfferPda: PublicKey;
+        inKind: boolean;
+        boss: PublicKey;
+    }) {
+        const [redemptionBasket] = PublicKey.findProgramAddressSync(
+            [Buffer.from("redemption_basket"), params.redemptionOfferPda.toBuffer()],
+            this.program.programId
+        );
+
+        return await this.program.methods
+            .setRedemptionOfferInKind(params.inKind)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                redemptionBasket,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
+    async buildSetRedemptionOfferPauseIx(params: {
+        redemptionOfferPda: PublicKey;
+        pauseNewRequests: boolean;
+        pauseFulfillment: boolean;
+        signer: PublicKey;
+    }) {
+        return await this.program.methods
+            .setRedemptionOfferPause(params.pauseNewRequests, params.pauseFulfillment)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                signer: params.signer
+            })
+            .instruction();
+    }
+
+    async buildSetRedemptionOfferInstantIx(params: {
+        redemptionOfferPda: PublicKey;
+        allowInstant: boolean;
+        instantFeeBasisPoints: number;
+        boss: PublicKey;
+    }) {
+        return await this.program.methods
+            .setRedemptionOfferInstant(params.allowInstant, params.instantFeeBasisPoints)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOfferPda,
+                boss: params.boss
+            })
+            .instruction();
+    }
+
     async prepareTransactionMultipleIxs(params: { ixs: TransactionInstruction[], payer: PublicKey }) {
         const tx = new Transaction();
         for (const ix of params.ixs) {
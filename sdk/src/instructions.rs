@@ -0,0 +1,373 @@
+//! Instruction builders for onreapp
+//!
+//! Builders take the program's generated instruction struct as the arguments, derive
+//! every PDA and associated token account, and leave optional accounts that only
+//! specific offer configurations need unset.
+use crate::pda;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+
+/// Token pair of an offer together with the token programs owning the mints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OfferMints {
+    /// Input token mint
+    pub token_in_mint: Pubkey,
+    /// Token program owning the input token mint
+    pub token_in_program: Pubkey,
+    /// Output token mint
+    pub token_out_mint: Pubkey,
+    /// Token program owning the output token mint
+    pub token_out_program: Pubkey,
+}
+
+impl OfferMints {
+    /// Returns the offer PDA of the token pair
+    pub fn offer(&self) -> Pubkey {
+        pda::offer(&self.token_in_mint, &self.token_out_mint).0
+    }
+
+    /// Returns the authority owning the offer's vault token accounts
+    ///
+    /// `own_vault` must match whether the offer has its own vault, which is the case for
+    /// offers made since per-offer vaults and offers migrated with `migrate_offer_vault`.
+    pub fn vault_authority(&self, own_vault: bool) -> Pubkey {
+        if own_vault {
+            pda::own_offer_vault_authority(&self.offer()).0
+        } else {
+            pda::offer_vault_authority().0
+        }
+    }
+
+    /// Returns the token_in associated token account of `owner`
+    pub fn token_in_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            owner,
+            &self.token_in_mint,
+            &self.token_in_program,
+        )
+    }
+
+    /// Returns the token_out associated token account of `owner`
+    pub fn token_out_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(
+            owner,
+            &self.token_out_mint,
+            &self.token_out_program,
+        )
+    }
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: onreapp::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Builds a `make_offer` instruction creating the offer of `mints` on its own vault
+pub fn make_offer_ix(
+    boss: &Pubkey,
+    mints: &OfferMints,
+    args: onreapp::instruction::MakeOffer,
+) -> Instruction {
+    let vault_authority = mints.vault_authority(true);
+    instruction(
+        onreapp::accounts::MakeOffer {
+            vault_authority,
+            token_in_mint: mints.token_in_mint,
+            token_in_program: mints.token_in_program,
+            vault_token_in_account: mints.token_in_account(&vault_authority),
+            token_out_mint: mints.token_out_mint,
+            token_out_program: mints.token_out_program,
+            vault_token_out_account: mints.token_out_account(&vault_authority),
+            mint_authority: pda::mint_authority().0,
+            offer: mints.offer(),
+            offer_version_record: pda::offer_version_record(
+                &mints.token_in_mint,
+                &mints.token_out_mint,
+            )
+            .0,
+            offer_registry: pda::offer_registry().0,
+            state: pda::state().0,
+            config: pda::config().0,
+            boss: *boss,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Builds an `add_offer_vector` instruction adding a pricing vector to the offer of `mints`
+pub fn add_offer_vector_ix(
+    boss: &Pubkey,
+    mints: &OfferMints,
+    args: onreapp::instruction::AddOfferVector,
+) -> Instruction {
+    instruction(
+        onreapp::accounts::AddOfferVector {
+            offer: mints.offer(),
+            token_in_mint: mints.token_in_mint,
+            token_out_mint: mints.token_out_mint,
+            state: pda::state().0,
+            config: pda::config().0,
+            boss: *boss,
+        },
+        args,
+    )
+}
+
+/// Builds a `take_offer` instruction for `user` on the offer of `mints`
+///
+/// `boss_token_in_account` must be set when the offer pays its take proceeds to
+/// the boss instead of the offer vault. `own_vault` must be set for offers with
+/// their own vault. No take receipt is passed, so offers flagged for
+/// clawbacks reject the take.
+pub fn take_offer_ix(
+    user: &Pubkey,
+    boss: &Pubkey,
+    mints: &OfferMints,
+    own_vault: bool,
+    boss_token_in_account: Option<Pubkey>,
+    args: onreapp::instruction::TakeOffer,
+) -> Instruction {
+    let vault_authority = mints.vault_authority(own_vault);
+    instruction(
+        onreapp::accounts::TakeOffer {
+            offer: mints.offer(),
+            state: pda::state().0,
+            config: pda::config().0,
+            boss: *boss,
+            vault_authority,
+            vault_token_in_account: mints.token_in_account(&vault_authority),
+            vault_token_out_account: mints.token_out_account(&vault_authority),
+            token_in_mint: mints.token_in_mint,
+            token_in_program: mints.token_in_program,
+            token_out_mint: mints.token_out_mint,
+            token_out_program: mints.token_out_program,
+            user_token_in_account: mints.token_in_account(user),
+            user_token_out_account: mints.token_out_account(user),
+            boss_token_in_account,
+            mint_authority: pda::mint_authority().0,
+            managed_mint: pda::managed_mint(&mints.token_out_mint).0,
+            instructions_sysvar: sysvar::instructions::ID,
+            token_in_oracle: None,
+            nav_history: None,
+            redemption_offer: None,
+            redemption_vault_token_in_account: None,
+            token_in_hook_approval: None,
+            screening_program: None,
+            blocklist_bucket: pda::blocklist_bucket(user).0,
+            user_position: pda::user_position(&mints.token_out_mint, user).0,
+            take_receipt: None,
+            user: *user,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Builds a `get_nav` view instruction for the offer of `mints`
+pub fn get_nav_ix(mints: &OfferMints) -> Instruction {
+    instruction(
+        onreapp::accounts::GetNAV {
+            offer: mints.offer(),
+            config: pda::config().0,
+            token_in_mint: mints.token_in_mint,
+            token_out_mint: mints.token_out_mint,
+        },
+        onreapp::instruction::GetNav {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use onreapp::instructions::FulfillmentMode;
+
+    fn mints() -> OfferMints {
+        OfferMints {
+            token_in_mint: Pubkey::new_unique(),
+            token_in_program: anchor_spl::token::ID,
+            token_out_mint: Pubkey::new_unique(),
+            token_out_program: anchor_spl::token_2022::ID,
+        }
+    }
+
+    #[test]
+    fn make_offer_ix_encodes_the_arguments_after_the_discriminator() {
+        let boss = Pubkey::new_unique();
+        let ix = make_offer_ix(
+            &boss,
+            &mints(),
+            onreapp::instruction::MakeOffer {
+                fee_basis_points: 25,
+                needs_approval: true,
+                allow_permissionless: false,
+                fulfillment_mode: Some(FulfillmentMode::Mint),
+                valid_from: 0,
+                valid_until: 7,
+            },
+        );
+
+        assert_eq!(ix.program_id, onreapp::ID);
+        assert_eq!(
+            &ix.data[..8],
+            onreapp::instruction::MakeOffer::DISCRIMINATOR
+        );
+        assert_eq!(
+            &ix.data[8..],
+            &[25, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == boss && meta.is_signer && meta.is_writable));
+    }
+
+    #[test]
+    fn take_offer_ix_derives_user_accounts_with_each_mint_program() {
+        let mints = mints();
+        let user = Pubkey::new_unique();
+        let ix = take_offer_ix(
+            &user,
+            &Pubkey::new_unique(),
+            &mints,
+            false,
+            None,
+            onreapp::instruction::TakeOffer {
+                token_in_amount: 1_000,
+                approval_message: None,
+            },
+        );
+
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            keys[0],
+            pda::offer(&mints.token_in_mint, &mints.token_out_mint).0
+        );
+        assert_eq!(
+            keys[11],
+            get_associated_token_address_with_program_id(
+                &user,
+                &mints.token_in_mint,
+                &anchor_spl::token::ID
+            )
+        );
+        assert_eq!(
+            keys[12],
+            get_associated_token_address_with_program_id(
+                &user,
+                &mints.token_out_mint,
+                &anchor_spl::token_2022::ID
+            )
+        );
+    }
+
+    #[test]
+    fn take_offer_ix_uses_the_own_vault_of_migrated_offers() {
+        let mints = mints();
+        let ix = take_offer_ix(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &mints,
+            true,
+            None,
+            onreapp::instruction::TakeOffer {
+                token_in_amount: 1_000,
+                approval_message: None,
+            },
+        );
+
+        let vault_authority = pda::own_offer_vault_authority(&mints.offer()).0;
+        let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys[4], vault_authority);
+        assert_eq!(keys[6], mints.token_out_account(&vault_authority));
+    }
+
+    #[test]
+    fn take_offer_ix_passes_the_users_token_out_position() {
+        let mints = mints();
+        let user = Pubkey::new_unique();
+        let ix = take_offer_ix(
+            &user,
+            &Pubkey::new_unique(),
+            &mints,
+            false,
+            None,
+            onreapp::instruction::TakeOffer {
+                token_in_amount: 1_000,
+                approval_message: None,
+            },
+        );
+
+        let position = pda::user_position(&mints.token_out_mint, &user).0;
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == position && meta.is_writable));
+    }
+
+    #[test]
+    fn take_offer_ix_passes_the_users_blocklist_bucket() {
+        let user = Pubkey::new_unique();
+        let ix = take_offer_ix(
+            &user,
+            &Pubkey::new_unique(),
+            &mints(),
+            false,
+            None,
+            onreapp::instruction::TakeOffer {
+                token_in_amount: 1_000,
+                approval_message: None,
+            },
+        );
+
+        let bucket = pda::blocklist_bucket(&user).0;
+        assert!(ix
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == bucket && !meta.is_writable));
+    }
+
+    #[test]
+    fn unset_optional_accounts_are_passed_as_the_program_id() {
+        let ix = take_offer_ix(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &mints(),
+            false,
+            None,
+            onreapp::instruction::TakeOffer {
+                token_in_amount: 1,
+                approval_message: None,
+            },
+        );
+
+        // boss_token_in_account and the oracle, NAV history, redemption, hook
+        // approval, screening program and take receipt accounts
+        let placeholders = ix
+            .accounts
+            .iter()
+            .filter(|meta| meta.pubkey == onreapp::ID)
+            .count();
+        assert_eq!(placeholders, 8);
+    }
+
+    #[test]
+    fn get_nav_ix_is_read_only() {
+        let ix = get_nav_ix(&mints());
+
+        assert_eq!(ix.accounts.len(), 4);
+        assert!(ix
+            .accounts
+            .iter()
+            .all(|meta| !meta.is_signer && !meta.is_writable));
+    }
+}
@@ -0,0 +1,10 @@
+//! Client SDK for the onreapp program
+//!
+//! Derives the program's PDAs and builds its instructions from the account and
+//! instruction structs generated by the program itself, so account order and
+//! data encoding always match the deployed IDL.
+pub mod instructions;
+pub mod pda;
+
+pub use instructions::*;
+pub use onreapp::ID as PROGRAM_ID;
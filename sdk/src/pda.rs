@@ -0,0 +1,83 @@
+//! PDA derivation for onreapp accounts
+//!
+//! Every function returns the address together with its bump seed.
+use anchor_lang::prelude::Pubkey;
+use onreapp::constants::seeds;
+use onreapp::state::{BlocklistBucket, PermissionlessAuthority};
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &onreapp::ID)
+}
+
+/// Program state account
+pub fn state() -> (Pubkey, u8) {
+    find(&[seeds::STATE])
+}
+
+/// Program-wide configuration account
+pub fn config() -> (Pubkey, u8) {
+    find(&[seeds::CONFIG])
+}
+
+/// Offer of a token pair
+pub fn offer(token_in_mint: &Pubkey, token_out_mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[
+        seeds::OFFER,
+        token_in_mint.as_ref(),
+        token_out_mint.as_ref(),
+    ])
+}
+
+/// Record of the latest offer version of a token pair
+pub fn offer_version_record(token_in_mint: &Pubkey, token_out_mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[
+        seeds::OFFER_VERSION,
+        token_in_mint.as_ref(),
+        token_out_mint.as_ref(),
+    ])
+}
+
+/// Registry of the live offers
+pub fn offer_registry() -> (Pubkey, u8) {
+    find(&[seeds::OFFER_REGISTRY])
+}
+
+/// NAV observation history of an offer
+pub fn nav_history(offer: &Pubkey) -> (Pubkey, u8) {
+    find(&[seeds::NAV_HISTORY, offer.as_ref()])
+}
+
+/// Position of a user in a mint
+pub fn user_position(mint: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find(&[seeds::USER_POSITION, mint.as_ref(), user.as_ref()])
+}
+
+/// Blocklist bucket covering an address
+pub fn blocklist_bucket(address: &Pubkey) -> (Pubkey, u8) {
+    find(&[seeds::BLOCKLIST, &[BlocklistBucket::index_for(address)]])
+}
+
+/// Authority owning the offer vault token accounts
+pub fn offer_vault_authority() -> (Pubkey, u8) {
+    find(&[seeds::OFFER_VAULT_AUTHORITY])
+}
+
+/// Authority owning the vault token accounts of an offer migrated to its own vault
+pub fn own_offer_vault_authority(offer: &Pubkey) -> (Pubkey, u8) {
+    find(&[seeds::OFFER_VAULT_AUTHORITY, offer.as_ref()])
+}
+
+/// Authority holding the mint authority of program-minted tokens
+pub fn mint_authority() -> (Pubkey, u8) {
+    find(&[seeds::MINT_AUTHORITY])
+}
+
+/// Registry entry of a token managed alongside ONyc
+pub fn managed_mint(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[seeds::MANAGED_MINT, mint.as_ref()])
+}
+
+/// Permissionless intermediary authority with the given index
+pub fn permissionless_authority(index: u8) -> (Pubkey, u8) {
+    find(&[PermissionlessAuthority::seed(index).as_slice()])
+}
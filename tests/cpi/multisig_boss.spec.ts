@@ -0,0 +1,101 @@
+import path from "path";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { BN, Program } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { OnreCpiCaller } from "../../target/types/onre_cpi_caller";
+import callerIdl from "../../target/idl/onre_cpi_caller.json";
+import { ONREAPP_PROGRAM_ID, TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const CALLER_PROGRAM_ID = new PublicKey((callerIdl as any).address);
+
+describe("Multisig Boss", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let caller: Program<OnreCpiCaller>;
+
+    let vault: PublicKey;
+    let onycMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        testHelper.svm.addProgramFromFile(
+            CALLER_PROGRAM_ID,
+            path.join(process.cwd(), "target/deploy/onre_cpi_caller.so")
+        );
+        caller = new Program<OnreCpiCaller>(callerIdl as OnreCpiCaller, program.program.provider);
+
+        // Like a Squads vault, the mock multisig vault is a system account PDA
+        [vault] = PublicKey.findProgramAddressSync([Buffer.from("vault")], CALLER_PROGRAM_ID);
+        testHelper.svm.airdrop(vault, BigInt(10_000_000_000));
+
+        onycMint = testHelper.createMint(9);
+        await program.initialize({ onycMint });
+        await program.proposeBoss({ newBoss: vault });
+    });
+
+    async function executeAsVault(ix: TransactionInstruction) {
+        await caller.methods
+            .executeAsVault(Buffer.from(ix.data))
+            .accountsPartial({ vault, onreProgram: ONREAPP_PROGRAM_ID })
+            .remainingAccounts(ix.keys.map(key => ({ ...key, isSigner: false })))
+            .rpc();
+    }
+
+    test("A multisig vault PDA can accept the boss authority", async () => {
+        // when
+        await executeAsVault(await program.program.methods.acceptBoss().accounts({ newBoss: vault }).instruction());
+
+        // then
+        const state = await program.getState();
+        expect(state.boss).toEqual(vault);
+        expect(state.proposedBoss).toEqual(PublicKey.default);
+    });
+
+    test("A multisig vault PDA boss can pay for and create an offer", async () => {
+        // given
+        await executeAsVault(await program.program.methods.acceptBoss().accounts({ newBoss: vault }).instruction());
+        const tokenInMint = testHelper.createMint(6);
+
+        // when
+        await executeAsVault(
+            await program.program.methods
+                .makeOffer(0, false, false, null, new BN(0), new BN(0))
+                .accountsPartial({
+                    boss: vault,
+                    tokenInMint,
+                    tokenInProgram: TOKEN_PROGRAM_ID,
+                    tokenOutMint: onycMint,
+                    tokenOutProgram: TOKEN_PROGRAM_ID
+                })
+                .instruction()
+        );
+
+        // then
+        const offer = await program.getOffer(tokenInMint, onycMint);
+        expect(offer.tokenInMint).toEqual(tokenInMint);
+    });
+
+    test("A multisig vault PDA boss can disable the kill switch", async () => {
+        // given
+        await executeAsVault(await program.program.methods.acceptBoss().accounts({ newBoss: vault }).instruction());
+        await executeAsVault(await program.program.methods.setKillSwitch(true, null).accounts({ signer: vault }).instruction());
+
+        // when
+        await executeAsVault(await program.program.methods.setKillSwitch(false, null).accounts({ signer: vault }).instruction());
+
+        // then
+        const state = await program.getState();
+        expect(state.isKilled).toBe(false);
+    });
+
+    test("The previous boss loses its authority to the multisig", async () => {
+        // given
+        await executeAsVault(await program.program.methods.acceptBoss().accounts({ newBoss: vault }).instruction());
+
+        // when/then
+        await expect(program.proposeBoss({ newBoss: testHelper.getBoss() })).rejects.toThrow();
+    });
+});
@@ -0,0 +1,109 @@
+import path from "path";
+import { Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { BN, Program } from "@coral-xyz/anchor";
+import { OnreCpiCaller } from "../../target/types/onre_cpi_caller";
+import callerIdl from "../../target/idl/onre_cpi_caller.json";
+import { ONREAPP_PROGRAM_ID, TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const CALLER_PROGRAM_ID = new PublicKey((callerIdl as any).address);
+
+describe("CPI Caller", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let caller: Program<OnreCpiCaller>;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        testHelper.svm.addProgramFromFile(
+            CALLER_PROGRAM_ID,
+            path.join(process.cwd(), "target/deploy/onre_cpi_caller.so")
+        );
+        caller = new Program<OnreCpiCaller>(callerIdl as OnreCpiCaller, program.program.provider);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(100e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(1_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 1_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+    });
+
+    function offerAccounts() {
+        return {
+            offer: program.getOfferPda(tokenInMint, tokenOutMint),
+            config: PublicKey.findProgramAddressSync([Buffer.from("config")], ONREAPP_PROGRAM_ID)[0],
+            tokenInMint,
+            tokenOutMint,
+            onreProgram: ONREAPP_PROGRAM_ID
+        };
+    }
+
+    test("An external program can read the NAV through CPI", async () => {
+        // when
+        const tx = await caller.methods.getNav().accountsPartial(offerAccounts()).transaction();
+        tx.recentBlockhash = testHelper.svm.latestBlockhash();
+        tx.feePayer = testHelper.payer.publicKey;
+        tx.sign(testHelper.payer);
+        const result = testHelper.svm.simulateTransaction(tx);
+
+        // then
+        const returnData = result.meta().returnData();
+        expect(new PublicKey(returnData.programId())).toEqual(CALLER_PROGRAM_ID);
+        expect(Buffer.from(returnData.data()).readBigUInt64LE(0)).toBe(BigInt(1e9));
+    });
+
+    test("An external program can take an offer through CPI", async () => {
+        // when
+        await caller.methods
+            .takeOffer(new BN(10e6))
+            .accountsPartial({
+                ...offerAccounts(),
+                state: program.pdas.statePda,
+                boss: testHelper.getBoss(),
+                vaultAuthority: program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint),
+                vaultTokenInAccount: getAssociatedTokenAddressSync(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true),
+                tokenInProgram: TOKEN_PROGRAM_ID,
+                tokenOutProgram: TOKEN_PROGRAM_ID,
+                userTokenInAccount: getAssociatedTokenAddressSync(tokenInMint, user.publicKey),
+                userTokenOutAccount: getAssociatedTokenAddressSync(tokenOutMint, user.publicKey),
+                bossTokenInAccount: getAssociatedTokenAddressSync(tokenInMint, testHelper.getBoss()),
+                mintAuthority: program.pdas.mintAuthorityPda,
+                managedMint: program.getManagedMintPda(tokenOutMint),
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                blocklistBucket: program.getBlocklistBucketPda(user.publicKey),
+                userPosition: program.getUserPositionPda(tokenOutMint, user.publicKey),
+                user: user.publicKey,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID
+            })
+            .signers([user])
+            .rpc();
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(getAssociatedTokenAddressSync(tokenOutMint, user.publicKey))).toBe(BigInt(10e9));
+        expect(await testHelper.getTokenAccountBalance(getAssociatedTokenAddressSync(tokenInMint, user.publicKey))).toBe(BigInt(90e6));
+    });
+});
@@ -1,12 +1,14 @@
 import { ComputeBudgetProgram, Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { OnreProgram } from "../onre_program";
 import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { sign } from "tweetnacl";
 
 export interface ApprovalMessage {
     programId: PublicKey;
     userPubkey: PublicKey;
     expiryUnix: BN;
+    jurisdiction: number;
 }
 
 export class Ed25519Helper {
@@ -19,10 +21,14 @@ export class Ed25519Helper {
         // - program_id: 32 bytes (Pubkey)
         // - user_pubkey: 32 bytes (Pubkey)
         // - expiry_unix: 8 bytes (u64, little-endian)
+        // - jurisdiction: 2 bytes (u16, little-endian)
+        const jurisdiction = Buffer.alloc(2);
+        jurisdiction.writeUInt16LE(message.jurisdiction);
         return Buffer.concat([
             message.programId.toBuffer(),
             message.userPubkey.toBuffer(),
-            Buffer.from(message.expiryUnix.toArray("le", 8))
+            Buffer.from(message.expiryUnix.toArray("le", 8)),
+            jurisdiction
         ]);
     }
 
@@ -118,6 +124,7 @@ export class Ed25519Helper {
         trustedAuthority: Keypair;
         boss: PublicKey;
         expiryTime?: number;
+        jurisdiction?: number;
     }) {
         const expiryTime = params.expiryTime || (Math.floor(Date.now() / 1000) + 3600);
 
@@ -125,7 +132,8 @@ export class Ed25519Helper {
         const approvalMessage: ApprovalMessage = {
             programId: params.program.program.programId,
             userPubkey: params.user,
-            expiryUnix: new BN(expiryTime)
+            expiryUnix: new BN(expiryTime),
+            jurisdiction: params.jurisdiction ?? 0
         };
 
         // Create Ed25519 verification instruction
@@ -134,6 +142,10 @@ export class Ed25519Helper {
             params.trustedAuthority
         );
 
+        const vaultAuthority = await params.program.getOfferVaultAuthority(
+            params.program.getOfferPda(params.tokenInMint, params.tokenOutMint)
+        );
+
         // Create transaction with Ed25519 verification and take offer
         const tx = params.program.program.methods
             .takeOffer(new BN(params.tokenInAmount), approvalMessage)
@@ -141,8 +153,12 @@ export class Ed25519Helper {
                 tokenInMint: params.tokenInMint,
                 tokenOutMint: params.tokenOutMint,
                 user: params.user,
-                tokenInProgram: new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
-                tokenOutProgram: new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                tokenInProgram: TOKEN_PROGRAM_ID,
+                tokenOutProgram: TOKEN_PROGRAM_ID,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true),
+                blocklistBucket: params.program.getBlocklistBucketPda(params.user)
             })
             .preInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),
@@ -0,0 +1,92 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Keeper Rewards", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let keeper: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(tokenInMint, onycMint);
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000e9));
+        await program.keeperRewardVaultDeposit({ amount: 100e9, onycMint });
+
+        keeper = testHelper.createUserAccount();
+        await program.openKeeperRewards({ signer: keeper });
+    });
+
+    it("Should only allow the boss to configure reward rates", async () => {
+        await expect(
+            program.configureKeeperReward({ crank: "checkpointNavs", rewardRate: 1_000, signer: keeper })
+        ).rejects.toThrow();
+    });
+
+    it("Should accrue rewards per crank and pay them out on claim", async () => {
+        // given
+        await program.configureKeeperReward({ crank: "recordNavObservation", rewardRate: 5_000 });
+        await program.configureKeeperReward({ crank: "checkpointNavs", rewardRate: 2_000 });
+
+        // when
+        await program.recordNavObservation({ offer: offerPda, signer: keeper, withRewards: true });
+        await program.checkpointNavs([offerPda], { signer: keeper, withRewards: true });
+
+        // then
+        const rewards = await program.getKeeperRewards(keeper.publicKey);
+        expect(rewards.accrued.toNumber()).toBe(7_000);
+        expect((await program.getKeeperRewardConfig()).totalUnclaimed.toNumber()).toBe(7_000);
+
+        // when
+        await program.claimKeeperRewards({ signer: keeper, onycMint });
+
+        // then
+        const keeperAta = getAssociatedTokenAddressSync(onycMint, keeper.publicKey);
+        expect(await testHelper.getTokenAccountBalance(keeperAta)).toBe(BigInt(7_000));
+        const claimed = await program.getKeeperRewards(keeper.publicKey);
+        expect(claimed.accrued.toNumber()).toBe(0);
+        expect(claimed.totalClaimed.toNumber()).toBe(7_000);
+        expect((await program.getKeeperRewardConfig()).totalUnclaimed.toNumber()).toBe(0);
+    });
+
+    it("Should not accrue rewards when the reward accounts are omitted", async () => {
+        // given
+        await program.configureKeeperReward({ crank: "recordNavObservation", rewardRate: 5_000 });
+
+        // when
+        await program.recordNavObservation({ offer: offerPda, signer: keeper });
+
+        // then
+        const rewards = await program.getKeeperRewards(keeper.publicKey);
+        expect(rewards.accrued.toNumber()).toBe(0);
+    });
+
+    it("Should reject a claim with nothing accrued", async () => {
+        await expect(
+            program.claimKeeperRewards({ signer: keeper, onycMint })
+        ).rejects.toThrow("No keeper rewards to claim");
+    });
+});
@@ -154,6 +154,56 @@ describe("Get APY", () => {
         });
     });
 
+    describe("Compounding Frequency Tests", () => {
+        beforeEach(async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            // 10% APR (scaled by 1M)
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 100_000,
+                priceFixDuration: 86400
+            });
+        });
+
+        it("Should default to daily compounding", async () => {
+            const defaultApy = await program.getAPY({ tokenInMint, tokenOutMint });
+            const dailyApy = await program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 365 });
+
+            expect(defaultApy).toBe(105156);
+            expect(dailyApy).toBe(defaultApy);
+        });
+
+        it("Should calculate APY for weekly and monthly compounding", async () => {
+            const weeklyApy = await program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 52 });
+            const monthlyApy = await program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 12 });
+
+            expect(weeklyApy).toBe(105065);
+            expect(monthlyApy).toBe(104713);
+        });
+
+        it("Should return the APR for annual compounding", async () => {
+            const apy = await program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 1 });
+
+            expect(apy).toBe(100_000);
+        });
+
+        it("Should approximate continuous compounding with per-second periods", async () => {
+            const apy = await program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 31_536_000 });
+
+            // e^0.1 - 1 = 10.5171%
+            expect(apy).toBe(105171);
+        });
+
+        it("Should reject zero compounding periods", async () => {
+            await expect(program.getAPY({ tokenInMint, tokenOutMint, compoundingPeriodsPerYear: 0 }))
+                .rejects.toThrow("Compounding periods per year must be greater than zero");
+        });
+    });
+
     describe("Error Condition Tests", () => {
         it("Should fail with non-existent offer", async () => {
             await expect(program.getAPY({ tokenInMint, tokenOutMint: testHelper.createMint(9) }))
@@ -222,6 +272,24 @@ describe("Get APY", () => {
             expect(apy).toBe(105156); // Higher than 10% APR
         });
 
+        it("Should return negative APY for negative APR", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: -100_000, // -10% APR
+                priceFixDuration: 86400
+            });
+
+            const apy = await program.getAPY({ tokenInMint, tokenOutMint });
+
+            // (1 - 0.1/365)^365 - 1 ≈ -9.5175%
+            expect(apy).toBe(-95175);
+        });
+
         it("Should provide consistent results for same APR", async () => {
             const currentTime = await testHelper.getCurrentClockTime();
 
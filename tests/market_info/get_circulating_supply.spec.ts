@@ -73,19 +73,21 @@ describe("Get Circulating Supply", () => {
                     priceFixDuration: 86400
                 });
 
-                // Boss deposits 10 token_out tokens to vault
+                // Boss deposits 10 token_out tokens to the offer's own vault
+                const offer = program.getOfferPda(tokenInMint, tokenOutMint);
                 const bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10e9));
                 await program.offerVaultDeposit({
                     amount: 10e9,
-                    tokenMint: tokenOutMint
+                    tokenMint: tokenOutMint,
+                    offer
                 });
 
-                const vaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, program.pdas.offerVaultAuthorityPda, true);
+                const vaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, program.getOwnOfferVaultAuthorityPda(offer), true);
                 let vaultTokenOutAccountBalance = await testHelper.getTokenAccountBalance(vaultTokenOutAccount);
                 expect(vaultTokenOutAccountBalance).toBe(BigInt(10e9));
 
                 // Check circulating supply with non-zero vault
-                let circulatingSupply = await program.getCirculatingSupply({ onycMint: tokenOutMint });
+                let circulatingSupply = await program.getCirculatingSupply({ onycMint: tokenOutMint, ownVaults: [{ offer }] });
 
                 const mintInfo = await testHelper.getMintInfo(tokenOutMint);
                 expect(circulatingSupply.toString()).toBe((mintInfo.supply - vaultTokenOutAccountBalance).toString());
@@ -108,7 +110,7 @@ describe("Get Circulating Supply", () => {
                 expect(vaultTokenOutAccountBalance).toBe(BigInt(9e9));
 
                 // Check circulating supply with non-zero vault
-                circulatingSupply = await program.getCirculatingSupply({ onycMint: tokenOutMint });
+                circulatingSupply = await program.getCirculatingSupply({ onycMint: tokenOutMint, ownVaults: [{ offer }] });
 
                 expect(circulatingSupply.toString()).toBe((mintInfo.supply - vaultTokenOutAccountBalance).toString());
             });
@@ -121,6 +123,50 @@ describe("Get Circulating Supply", () => {
                 // Should equal total supply when vault is empty
                 expect(circulatingSupply.toString()).toBe(mintInfo.supply.toString());
             });
+
+            it("Should exclude ONyc held by the redemption vault", async () => {
+                testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(10e9), true);
+                testHelper.createTokenAccount(tokenOutMint, program.pdas.redemptionVaultAuthorityPda, BigInt(4e9), true);
+
+                const circulatingSupply = await program.getCirculatingSupply({ onycMint: tokenOutMint });
+
+                const mintInfo = await testHelper.getMintInfo(tokenOutMint);
+                expect(circulatingSupply.toString()).toBe((mintInfo.supply - BigInt(14e9)).toString());
+            });
+
+            it("Should exclude ONyc held by both the shared and the passed per-offer vaults", async () => {
+                await program.makeOffer({ tokenInMint, tokenOutMint });
+                const offer = program.getOfferPda(tokenInMint, tokenOutMint);
+                testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(10e9), true);
+                testHelper.createTokenAccount(tokenOutMint, program.getOwnOfferVaultAuthorityPda(offer), BigInt(3e9), true);
+                const mintInfo = await testHelper.getMintInfo(tokenOutMint);
+
+                const withoutOffer = await program.getCirculatingSupply({ onycMint: tokenOutMint });
+                const withOffer = await program.getCirculatingSupply({ onycMint: tokenOutMint, ownVaults: [{ offer }] });
+
+                expect(withoutOffer.toString()).toBe((mintInfo.supply - BigInt(10e9)).toString());
+                expect(withOffer.toString()).toBe((mintInfo.supply - BigInt(13e9)).toString());
+            });
+
+            it("Should reject a per-offer vault account not derived from the offer", async () => {
+                await program.makeOffer({ tokenInMint, tokenOutMint });
+                const offer = program.getOfferPda(tokenInMint, tokenOutMint);
+                const sharedVaultAccount = getAssociatedTokenAddressSync(tokenOutMint, program.pdas.offerVaultAuthorityPda, true);
+
+                await expect(
+                    program.getCirculatingSupply({ onycMint: tokenOutMint, ownVaults: [{ offer, vaultAccount: sharedVaultAccount }] })
+                ).rejects.toThrow("Invalid token_out vault account");
+            });
+
+            it("Should reject passing a per-offer vault twice", async () => {
+                await program.makeOffer({ tokenInMint, tokenOutMint });
+                const offer = program.getOfferPda(tokenInMint, tokenOutMint);
+                testHelper.createTokenAccount(tokenOutMint, program.getOwnOfferVaultAuthorityPda(offer), BigInt(3e9), true);
+
+                await expect(
+                    program.getCirculatingSupply({ onycMint: tokenOutMint, ownVaults: [{ offer }, { offer }] })
+                ).rejects.toThrow("Invalid token_out vault account");
+            });
         });
     });
 
@@ -140,7 +186,8 @@ describe("Get Circulating Supply", () => {
             await program.makeOffer({
                 tokenInMint,
                 tokenOutMint,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
         });
 
@@ -0,0 +1,124 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Global TVL", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should value the whole supply at the primary offer's NAV", async () => {
+        // given
+        await program.setPrimaryOffer({ offer: offerPda });
+        const totalSupply = (await testHelper.getMintInfo(onycMint)).supply;
+
+        // when
+        const tvl = await program.getGlobalTVL({ onycMint, primaryOffer: offerPda });
+
+        // then
+        expect(tvl).toBe(totalSupply * BigInt(2));
+    });
+
+    it("Should exclude offer and redemption vault holdings", async () => {
+        // given
+        await program.setPrimaryOffer({ offer: offerPda });
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000e9));
+        testHelper.createTokenAccount(onycMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+        testHelper.createTokenAccount(onycMint, program.pdas.redemptionVaultAuthorityPda, BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 400e9, tokenMint: onycMint });
+        await program.redemptionVaultDeposit({ amount: 100e9, tokenMint: onycMint });
+        const totalSupply = (await testHelper.getMintInfo(onycMint)).supply;
+
+        // when
+        const tvl = await program.getGlobalTVL({ onycMint, primaryOffer: offerPda });
+
+        // then
+        expect(tvl).toBe((totalSupply - BigInt(500e9)) * BigInt(2));
+    });
+
+    it("Should exclude the ONyc of the passed per-offer vaults", async () => {
+        // given
+        await program.setPrimaryOffer({ offer: offerPda });
+        const otherMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: onycMint, tokenOutMint: otherMint });
+        const otherOffer = program.getOfferPda(onycMint, otherMint);
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000e9));
+        await program.offerVaultDeposit({ amount: 400e9, tokenMint: onycMint, offer: offerPda });
+        await program.offerVaultDeposit({ amount: 100e9, tokenMint: onycMint, offer: otherOffer });
+        const totalSupply = (await testHelper.getMintInfo(onycMint)).supply;
+
+        // when
+        const tvl = await program.getGlobalTVL({ onycMint, primaryOffer: offerPda, ownVaults: [{ offer: offerPda }, { offer: otherOffer }] });
+
+        // then
+        expect(tvl).toBe((totalSupply - BigInt(500e9)) * BigInt(2));
+    });
+
+    it("Should reject a per-offer vault account not derived from the offer", async () => {
+        // given
+        await program.setPrimaryOffer({ offer: offerPda });
+        const otherOffer = program.getOfferPda(onycMint, usdcMint);
+
+        // when/then
+        await expect(
+            program.getGlobalTVL({
+                onycMint,
+                primaryOffer: offerPda,
+                ownVaults: [{ offer: offerPda, vaultAccount: getAssociatedTokenAddressSync(onycMint, program.getOwnOfferVaultAuthorityPda(otherOffer), true) }]
+            })
+        ).rejects.toThrow("Invalid ONyc vault account");
+    });
+
+    it("Should fail when no primary offer is set", async () => {
+        await expect(
+            program.getGlobalTVL({ onycMint, primaryOffer: offerPda })
+        ).rejects.toThrow("Primary offer not set");
+    });
+
+    it("Should fail when passing an offer other than the primary offer", async () => {
+        const usdtMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: usdtMint, tokenOutMint: onycMint });
+        await program.setPrimaryOffer({ offer: offerPda });
+
+        await expect(
+            program.getGlobalTVL({ onycMint, primaryOffer: program.getOfferPda(usdtMint, onycMint) })
+        ).rejects.toThrow("Offer is not the primary offer");
+    });
+
+    it("Should fail when the primary offer has no active vector", async () => {
+        const usdtMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: usdtMint, tokenOutMint: onycMint });
+        const otherOfferPda = program.getOfferPda(usdtMint, onycMint);
+        await program.setPrimaryOffer({ offer: otherOfferPda });
+
+        await expect(
+            program.getGlobalTVL({ onycMint, primaryOffer: otherOfferPda })
+        ).rejects.toThrow("No active vector");
+    });
+});
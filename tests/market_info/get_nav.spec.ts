@@ -130,6 +130,50 @@ describe("Get NAV", () => {
             expect(nav).toBe(1.0002e9);
         });
 
+        it("Should calculate declining price for negative APR", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: -36_500, // -3.65% APR
+                priceFixDuration: 86400 // 1 day intervals
+            });
+
+            let nav = await program.getNAV({ tokenInMint, tokenOutMint });
+            expect(nav).toBe(0.9999e9);
+
+            await testHelper.advanceClockBy(86401); // 1 day
+
+            nav = await program.getNAV({ tokenInMint, tokenOutMint });
+            expect(nav).toBe(0.9998e9);
+        });
+
+        it("Should calculate per-second price for linear pricing mode", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 36_500, // 3.65% APR
+                priceFixDuration: 86400,
+                pricingMode: "linear"
+            });
+
+            // No time elapsed yet, price equals base price
+            let nav = await program.getNAV({ tokenInMint, tokenOutMint });
+            expect(nav).toBe(1e9);
+
+            await testHelper.advanceClockBy(43_200); // half a day
+
+            nav = await program.getNAV({ tokenInMint, tokenOutMint });
+            expect(nav).toBe(1.00005e9);
+        });
+
         it("Should handle 0 APR values", async () => {
             const currentTime = await testHelper.getCurrentClockTime();
 
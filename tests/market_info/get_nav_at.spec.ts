@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get NAV At", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let baseTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        baseTime = await testHelper.getCurrentClockTime();
+
+        // 3.65% APR with daily steps: +0.0001 per day
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime,
+            basePrice: 1e9,
+            apr: 36_500,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should match get_nav at the current time", async () => {
+        const nav = await program.getNAV({ tokenInMint, tokenOutMint });
+        const navAt = await program.getNAVAt({ tokenInMint, tokenOutMint, unixTs: baseTime });
+
+        expect(navAt).toBe(nav);
+    });
+
+    it("Should return the forward NAV of a later step", async () => {
+        const navAt = await program.getNAVAt({ tokenInMint, tokenOutMint, unixTs: baseTime + 3 * 86400 });
+
+        expect(navAt).toBe(1.0004e9);
+    });
+
+    it("Should return the historical NAV of an earlier step", async () => {
+        await testHelper.advanceClockBy(5 * 86400);
+
+        const navAt = await program.getNAVAt({ tokenInMint, tokenOutMint, unixTs: baseTime + 86400 });
+        const nav = await program.getNAV({ tokenInMint, tokenOutMint });
+
+        expect(navAt).toBe(1.0002e9);
+        expect(nav).toBe(1.0006e9);
+    });
+
+    it("Should evaluate the vector active at the supplied time", async () => {
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: baseTime + 10 * 86400,
+            basePrice: 2e9,
+            apr: 73_000,
+            priceFixDuration: 86400
+        });
+
+        const beforeSecondVector = await program.getNAVAt({
+            tokenInMint,
+            tokenOutMint,
+            unixTs: baseTime + 10 * 86400 - 1
+        });
+        const atSecondVector = await program.getNAVAt({
+            tokenInMint,
+            tokenOutMint,
+            unixTs: baseTime + 10 * 86400
+        });
+
+        expect(beforeSecondVector).toBe(1.001e9);
+        expect(atSecondVector).toBe(2.0004e9);
+    });
+
+    it("Should fail when no vector covers the supplied time", async () => {
+        await expect(program.getNAVAt({ tokenInMint, tokenOutMint, unixTs: baseTime - 1 }))
+            .rejects.toThrow("No active vector");
+    });
+});
@@ -0,0 +1,66 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Offer Stats", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 100 }); // 1% fee
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6));
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+    });
+
+    it("Should return zero counters for an offer without takes", async () => {
+        const stats = await program.getOfferStats({ tokenInMint, tokenOutMint });
+
+        expect(stats.totalFeesCollectedTokenIn).toBe(BigInt(0));
+        expect(stats.totalVolumeTokenIn).toBe(BigInt(0));
+    });
+
+    it("Should accumulate fees and volume across takes", async () => {
+        // when
+        await program.takeOffer({ tokenInAmount: 1_000e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+        await program.takeOffer({ tokenInAmount: 500e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+
+        // then
+        const stats = await program.getOfferStats({ tokenInMint, tokenOutMint });
+        expect(stats.totalFeesCollectedTokenIn).toBe(BigInt(15e6)); // 1% of 1500 USDC
+        expect(stats.totalVolumeTokenIn).toBe(BigInt(1_500e6));
+    });
+
+    it("Should reject mints that do not belong to the offer", async () => {
+        const otherMint = testHelper.createMint(6);
+
+        await expect(
+            program.getOfferStats({ tokenInMint: otherMint, tokenOutMint })
+        ).rejects.toThrow();
+    });
+});
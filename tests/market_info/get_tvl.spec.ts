@@ -302,7 +302,8 @@ describe("Get TVL", () => {
             await program.makeOffer({
                 tokenInMint,
                 tokenOutMint,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
         });
 
@@ -0,0 +1,99 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get TWAP", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let offerPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    async function recordTwoLevels() {
+        // 1.0 for 100 seconds, then 2.0 for 100 seconds
+        await program.recordNavObservation({ offer: offerPda });
+        await testHelper.advanceClockBy(100);
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await program.recordNavObservation({ offer: offerPda });
+        await testHelper.advanceClockBy(100);
+    }
+
+    it("Should return the single recorded NAV", async () => {
+        await program.recordNavObservation({ offer: offerPda });
+        await testHelper.advanceClockBy(100);
+
+        const twap = await program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 3600 });
+
+        expect(twap).toBe(1e9);
+    });
+
+    it("Should weight observations by the time they were in force", async () => {
+        await recordTwoLevels();
+
+        const twap = await program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 200 });
+
+        expect(twap).toBe(1.5e9);
+    });
+
+    it("Should only average observations inside the window", async () => {
+        await recordTwoLevels();
+
+        // Last 150 seconds: 50s at 1.0 and 100s at 2.0
+        const twap = await program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 150 });
+
+        expect(twap).toBe(Math.floor((50 * 1e9 + 100 * 2e9) / 150));
+    });
+
+    it("Should limit a window longer than the history to the available observations", async () => {
+        await recordTwoLevels();
+
+        const twap = await program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 86400 });
+
+        expect(twap).toBe(1.5e9);
+    });
+
+    it("Should reject a zero window", async () => {
+        await program.recordNavObservation({ offer: offerPda });
+
+        await expect(
+            program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 0 })
+        ).rejects.toThrow("Invalid window: must be greater than zero");
+    });
+
+    it("Should reject an offer without NAV history", async () => {
+        await expect(
+            program.getTWAP({ tokenInMint, tokenOutMint, windowSecs: 3600 })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,114 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get User Position", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await program.offerVaultDeposit({ amount: 1_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+    });
+
+    function take(tokenInAmount: number) {
+        return program.takeOffer({ tokenInAmount, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+    }
+
+    it("Should create the position on the user's first take", async () => {
+        await take(100e6);
+
+        const position = await program.getUserPosition(tokenOutMint, user.publicKey);
+        expect(position.user).toEqual(user.publicKey);
+        expect(position.mint).toEqual(tokenOutMint);
+        expect(position.totalPurchased.toNumber()).toBe(100e9);
+        expect(position.averageEntryPrice.toNumber()).toBe(1e9);
+        expect(position.totalRedeemed.toNumber()).toBe(0);
+    });
+
+    it("Should weight the average entry price by the amount bought", async () => {
+        await take(100e6);
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 86400,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await testHelper.advanceClockBy(86400);
+        await take(100e6);
+
+        const position = await program.getUserPosition(tokenOutMint, user.publicKey);
+        expect(position.totalPurchased.toNumber()).toBe(150e9);
+        // (100 * 1.0 + 50 * 2.0) / 150
+        expect(position.averageEntryPrice.toNumber()).toBe(1_333_333_333);
+    });
+
+    it("Should value the holdings at the current price", async () => {
+        await take(100e6);
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 86400,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await testHelper.advanceClockBy(86400);
+        await take(100e6);
+
+        const view = await program.getUserPositionView({ tokenInMint, tokenOutMint, user: user.publicKey });
+
+        expect(view.holdings.toNumber()).toBe(150e9);
+        expect(view.currentPrice.toNumber()).toBe(2e9);
+        expect(view.currentValue.toNumber()).toBe(300e6);
+        expect(view.costBasis.toNumber()).toBe(199_999_999);
+        expect(view.unrealizedPnl.toNumber()).toBe(100_000_001);
+    });
+
+    it("Should keep separate positions per user", async () => {
+        const otherUser = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, otherUser.publicKey, BigInt(100e6), true);
+
+        await take(100e6);
+        await program.takeOffer({ tokenInAmount: 50e6, tokenInMint, tokenOutMint, user: otherUser.publicKey, signer: otherUser });
+
+        expect((await program.getUserPosition(tokenOutMint, user.publicKey)).totalPurchased.toNumber()).toBe(100e9);
+        expect((await program.getUserPosition(tokenOutMint, otherUser.publicKey)).totalPurchased.toNumber()).toBe(50e9);
+    });
+
+    it("Should fail for users without a position", async () => {
+        await expect(program.getUserPositionView({ tokenInMint, tokenOutMint, user: user.publicKey }))
+            .rejects.toThrow();
+    });
+});
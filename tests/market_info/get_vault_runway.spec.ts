@@ -0,0 +1,105 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Vault Runway", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+
+    async function addVector(basePrice: number) {
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    }
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+    });
+
+    it("Should price the vault balance at the current NAV", async () => {
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await addVector(2e9);
+        await program.offerVaultDeposit({ amount: 10e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        const runway = await program.getVaultRunway({ tokenInMint, tokenOutMint });
+
+        expect(runway.vaultTokenOutRemaining.toNumber()).toBe(10e9);
+        expect(runway.currentPrice.toNumber()).toBe(2e9);
+        expect(runway.tokenInCapacity.toNumber()).toBe(20e6);
+        expect(runway.lowInventory).toBe(false);
+    });
+
+    it("Should include the offer fee in the capacity", async () => {
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 100 });
+        await addVector(1e9);
+        await program.offerVaultDeposit({ amount: 10e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        const runway = await program.getVaultRunway({ tokenInMint, tokenOutMint });
+
+        // ceil(10e6 * 10000 / 9900)
+        expect(runway.tokenInCapacity.toNumber()).toBe(10_101_011);
+    });
+
+    it("Should treat an uninitialized vault as empty", async () => {
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await addVector(1e9);
+
+        const runway = await program.getVaultRunway({ tokenInMint, tokenOutMint });
+
+        expect(runway.vaultTokenOutRemaining.toNumber()).toBe(0);
+        expect(runway.tokenInCapacity.toNumber()).toBe(0);
+    });
+
+    it("Should flag low inventory once takes drain the vault to the threshold", async () => {
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await addVector(1e9);
+        await program.offerVaultDeposit({ amount: 10e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+        await program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 5e9 });
+
+        expect((await program.getVaultRunway({ tokenInMint, tokenOutMint })).lowInventory).toBe(false);
+
+        await program.takeOffer({ tokenInAmount: 5e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+
+        const runway = await program.getVaultRunway({ tokenInMint, tokenOutMint });
+        expect(runway.vaultTokenOutRemaining.toNumber()).toBe(5e9);
+        expect(runway.lowInventory).toBe(true);
+    });
+
+    it("Should never flag low inventory for offers fulfilled by minting", async () => {
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.transferMintAuthorityToProgram({ mint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, fulfillmentMode: "mint" });
+        await addVector(1e9);
+        await program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 5e9 });
+
+        const runway = await program.getVaultRunway({ tokenInMint, tokenOutMint });
+
+        expect(runway.vaultTokenOutRemaining.toNumber()).toBe(0);
+        expect(runway.fulfillmentMode).toEqual({ mint: {} });
+        expect(runway.lowInventory).toBe(false);
+    });
+});
@@ -0,0 +1,82 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Snapshot Market Info", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+    });
+
+    it("Should emit a snapshot matching the individual market info views", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500,
+            priceFixDuration: 86400
+        });
+
+        // when
+        const snapshot = await program.snapshotMarketInfo({ tokenInMint, tokenOutMint });
+
+        // then
+        const nav = await program.getNAV({ tokenInMint, tokenOutMint });
+        const apy = await program.getAPY({ tokenInMint, tokenOutMint });
+        const tvl = await program.getTVL({ tokenInMint, tokenOutMint });
+
+        expect(snapshot.offerPda).toEqual(program.getOfferPda(tokenInMint, tokenOutMint));
+        expect(snapshot.nav.toString()).toBe(nav.toString());
+        expect(snapshot.apy.toString()).toBe(apy.toString());
+        expect(snapshot.apr.toNumber()).toBe(36_500);
+        expect(snapshot.tvl.toString()).toBe(tvl.toString());
+        expect(snapshot.timestamp.toNumber()).toBe(currentTime);
+    });
+
+    it("Should exclude vault holdings from the circulating supply", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(1_000e9));
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 400e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+        const totalSupply = (await testHelper.getMintInfo(tokenOutMint)).supply;
+
+        // when
+        const snapshot = await program.snapshotMarketInfo({ tokenInMint, tokenOutMint });
+
+        // then
+        const circulatingSupply = totalSupply - BigInt(400e9);
+        expect(snapshot.circulatingSupply.toString()).toBe(circulatingSupply.toString());
+        expect(snapshot.tvl.toString()).toBe((circulatingSupply * BigInt(2)).toString());
+    });
+
+    it("Should fail when the offer has no active vector", async () => {
+        await expect(
+            program.snapshotMarketInfo({ tokenInMint, tokenOutMint })
+        ).rejects.toThrow("No active vector");
+    });
+});
@@ -0,0 +1,63 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("View Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 100, withApproval: true });
+    });
+
+    it("Should return the configuration of an offer without vectors", async () => {
+        const view = await program.viewOffer({ tokenInMint, tokenOutMint });
+
+        expect(view.tokenInMint).toEqual(tokenInMint);
+        expect(view.tokenOutMint).toEqual(tokenOutMint);
+        expect(view.feeBasisPoints).toBe(100);
+        expect(view.needsApproval).toBe(true);
+        expect(view.allowPermissionless).toBe(false);
+        expect(view.totalVectors).toBe(0);
+        expect(view.activeVector).toBeNull();
+        expect(view.currentNav).toBeNull();
+    });
+
+    it("Should return the active vector and current NAV", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        // when
+        const view = await program.viewOffer({ tokenInMint, tokenOutMint });
+
+        // then
+        expect(view.totalVectors).toBe(1);
+        expect(view.activeVector.basePrice.toNumber()).toBe(1e9);
+        expect(view.activeVector.priceFixDuration.toNumber()).toBe(86400);
+        expect(view.currentNav.toNumber()).toBe(1e9);
+    });
+
+    it("Should reject mints that do not belong to the offer", async () => {
+        const otherMint = testHelper.createMint(6);
+
+        await expect(program.viewOffer({ tokenInMint: otherMint, tokenOutMint })).rejects.toThrow();
+    });
+});
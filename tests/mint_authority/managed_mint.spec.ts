@@ -0,0 +1,107 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Managed Mints", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let nonBoss: Keypair;
+    let onycMint: PublicKey;
+    let onmxMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        nonBoss = testHelper.createUserAccount();
+        onycMint = testHelper.createMint(9);
+        onmxMint = testHelper.createMint(9, null, BigInt(0));
+
+        await program.initialize({ onycMint });
+        await program.transferMintAuthorityToProgram({ mint: onmxMint });
+    });
+
+    test("Boss can register a managed mint", async () => {
+        await program.addManagedMint({ mint: onmxMint, maxSupply: 100e9 });
+
+        const managedMint = await program.getManagedMint(onmxMint);
+        expect(managedMint.mint).toEqual(onmxMint);
+        expect(managedMint.maxSupply.toNumber()).toBe(100e9);
+    });
+
+    test("Cannot register the ONyc mint - should fail", async () => {
+        await expect(program.addManagedMint({ mint: onycMint, maxSupply: 0 }))
+            .rejects.toThrow("ONyc is managed through program state");
+    });
+
+    test("Non-boss cannot register a managed mint - should fail", async () => {
+        await expect(program.addManagedMint({ mint: onmxMint, maxSupply: 0, signer: nonBoss }))
+            .rejects.toThrow();
+    });
+
+    test("Boss can mint a managed token up to its supply cap", async () => {
+        await program.addManagedMint({ mint: onmxMint, maxSupply: 100e9 });
+
+        await program.mintManagedTo({ mint: onmxMint, amount: 60e9 });
+        const bossOnmxAccount = getAssociatedTokenAddressSync(onmxMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossOnmxAccount)).toBe(BigInt(60e9));
+
+        await expect(program.mintManagedTo({ mint: onmxMint, amount: 50e9 }))
+            .rejects.toThrow("Minting would exceed maximum supply cap");
+    });
+
+    test("Cannot mint an unregistered token - should fail", async () => {
+        await expect(program.mintManagedTo({ mint: onmxMint, amount: 1e9 }))
+            .rejects.toThrow();
+    });
+
+    test("Boss can update the supply cap of a managed mint", async () => {
+        await program.addManagedMint({ mint: onmxMint, maxSupply: 10e9 });
+
+        await program.updateManagedMint({ mint: onmxMint, maxSupply: 100e9 });
+        await program.mintManagedTo({ mint: onmxMint, amount: 50e9 });
+
+        expect((await program.getManagedMint(onmxMint)).maxSupply.toNumber()).toBe(100e9);
+    });
+
+    test("Boss can remove a managed mint", async () => {
+        await program.addManagedMint({ mint: onmxMint, maxSupply: 0 });
+
+        await program.removeManagedMint({ mint: onmxMint });
+
+        expect(await testHelper.getAccountInfo(program.getManagedMintPda(onmxMint))).toBeNull();
+        await expect(program.mintManagedTo({ mint: onmxMint, amount: 1e9 }))
+            .rejects.toThrow();
+    });
+
+    test("Takes minting a managed token are capped by its registry entry", async () => {
+        const tokenInMint = testHelper.createMint(6);
+        await program.addManagedMint({ mint: onmxMint, maxSupply: 15e9 });
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint: onmxMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        const user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(100e6));
+        const take = (tokenInAmount: number) => program.takeOffer({
+            tokenInAmount,
+            tokenInMint,
+            tokenOutMint: onmxMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await take(10e6);
+        expect(await testHelper.getTokenAccountBalance(getAssociatedTokenAddressSync(onmxMint, user.publicKey)))
+            .toBe(BigInt(10e9));
+
+        await testHelper.advanceSlot();
+        await expect(take(10e6)).rejects.toThrow("Minting would exceed maximum supply cap");
+    });
+});
@@ -0,0 +1,83 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Mint Delegate", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let onycMint: PublicKey;
+    let delegateProgram: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        onycMint = testHelper.createMint(9);
+        delegateProgram = Keypair.generate().publicKey;
+
+        await program.initialize({ onycMint });
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+    });
+
+    test("Boss can register a mint delegate", async () => {
+        // when
+        await program.configureMintDelegate({ delegateProgram, mintCap: 1_000e9 });
+
+        // then
+        const delegate = await program.getMintDelegate(delegateProgram);
+        const [authority] = PublicKey.findProgramAddressSync([Buffer.from("onre_mint_delegate")], delegateProgram);
+        expect(delegate.program).toEqual(delegateProgram);
+        expect(delegate.authority).toEqual(authority);
+        expect(delegate.mintCap.toNumber()).toBe(1_000e9);
+        expect(delegate.minted.toNumber()).toBe(0);
+    });
+
+    test("Boss can update the cap of a registered delegate", async () => {
+        // given
+        await program.configureMintDelegate({ delegateProgram, mintCap: 1_000e9 });
+
+        // when
+        await program.configureMintDelegate({ delegateProgram, mintCap: 500e9 });
+
+        // then
+        const delegate = await program.getMintDelegate(delegateProgram);
+        expect(delegate.mintCap.toNumber()).toBe(500e9);
+    });
+
+    test("Non-boss cannot register a mint delegate - should fail", async () => {
+        const nonBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.configureMintDelegate({ delegateProgram, mintCap: 1_000e9, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+
+    test("Boss can remove a mint delegate", async () => {
+        // given
+        await program.configureMintDelegate({ delegateProgram, mintCap: 1_000e9 });
+
+        // when
+        await program.removeMintDelegate({ delegateProgram });
+
+        // then
+        expect(await testHelper.getAccountInfo(program.getMintDelegatePda(delegateProgram))).toBeNull();
+    });
+
+    test("Rejects mints not signed by the delegate's authority", async () => {
+        // given
+        await program.configureMintDelegate({ delegateProgram, mintCap: 1_000e9 });
+        const impostor = testHelper.createUserAccount();
+        const destination = testHelper.createTokenAccount(onycMint, impostor.publicKey, BigInt(0));
+
+        // when / then
+        await expect(
+            program.mintToViaDelegate({
+                amount: 1e9,
+                delegateProgram,
+                delegateAuthority: impostor,
+                onycMint,
+                destination
+            })
+        ).rejects.toThrow("Signer is not the mint delegate's authority");
+    });
+});
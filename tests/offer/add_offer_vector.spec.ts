@@ -3,7 +3,7 @@ import { TestHelper } from "../test_helper";
 import { BN } from "@coral-xyz/anchor";
 import { OnreProgram } from "../onre_program.ts";
 
-const MAX_VECTORS = 10;
+const MAX_VECTORS = 64;
 
 describe("Add Offer Vector", () => {
     let testHelper: TestHelper;
@@ -192,6 +192,116 @@ describe("Add Offer Vector", () => {
         ).rejects.toThrow("Invalid input: values cannot be zero");
     });
 
+    it("Should reject price_fix_duration outside the allowed bounds", async () => {
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+
+        await expect(
+            program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 5000,
+                priceFixDuration: 59 // Below MIN_PRICE_FIX_DURATION
+            })
+        ).rejects.toThrow("Invalid input: price_fix_duration must be between 60 and 31536000");
+
+        await expect(
+            program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 5000,
+                priceFixDuration: 31_536_001 // Above MAX_PRICE_FIX_DURATION
+            })
+        ).rejects.toThrow("Invalid input: price_fix_duration must be between 60 and 31536000");
+
+        // Both bounds are inclusive
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 5000,
+            priceFixDuration: 60
+        });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 1000,
+            basePrice: 1e9,
+            apr: 5000,
+            priceFixDuration: 31_536_000
+        });
+    });
+
+    it("Should reject a base_time too many steps before the start_time", async () => {
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        const MAX_VECTOR_STEPS = 100_000;
+
+        await expect(
+            program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime - (MAX_VECTOR_STEPS + 1) * 60,
+                basePrice: 1e9,
+                apr: 5000,
+                priceFixDuration: 60
+            })
+        ).rejects.toThrow("Invalid input: vector would span too many pricing steps");
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime - MAX_VECTOR_STEPS * 60,
+            basePrice: 1e9,
+            apr: 5000,
+            priceFixDuration: 60
+        });
+    });
+
+    it("Should store the pricing mode of the vector", async () => {
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 1000,
+            basePrice: 1000000,
+            apr: 5000,
+            priceFixDuration: 3600
+        });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 2000,
+            basePrice: 1000000,
+            apr: 5000,
+            priceFixDuration: 3600,
+            pricingMode: "linear"
+        });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.vectors[0].pricingMode).toBe(0);
+        expect(offer.vectors[1].pricingMode).toBe(1);
+    });
+
     it("Should allow zero apr", async () => {
         await program.makeOffer({
             tokenInMint,
@@ -545,7 +655,8 @@ describe("Add Offer Vector", () => {
                 new BN(currentTime + 1000),
                 largeStartPrice,
                 largeApr,
-                new BN(3600)
+                new BN(3600),
+                { step: {} }
             )
             .accounts({
                 tokenInMint,
@@ -561,7 +672,7 @@ describe("Add Offer Vector", () => {
         expect(vector.apr.toString()).toBe(largeApr.toString());
     });
 
-    it("Should handle minimum valid values (1 for all fields)", async () => {
+    it("Should handle minimum valid values (1 for base time and price)", async () => {
         await program.makeOffer({
             tokenInMint,
             tokenOutMint
@@ -573,7 +684,7 @@ describe("Add Offer Vector", () => {
             baseTime: 1, // Minimum valid start_time
             basePrice: 1, // Minimum valid start_price
             apr: 0, // Minimum valid apr
-            priceFixDuration: 1  // Minimum valid price_fix_duration
+            priceFixDuration: 31_536_000  // Maximum valid price_fix_duration keeps the backdated steps in bounds
         });
 
         // Verify the vector was added
@@ -587,7 +698,7 @@ describe("Add Offer Vector", () => {
         expect(startTime).toBeGreaterThanOrEqual(currentTime);
         expect(vector.basePrice.toString()).toBe("1");
         expect(vector.apr.toString()).toBe("0");
-        expect(vector.priceFixDuration.toString()).toBe("1");
+        expect(vector.priceFixDuration.toString()).toBe("31536000");
     });
 
     it("Should reject when called by non-boss", async () => {
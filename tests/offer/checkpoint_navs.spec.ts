@@ -0,0 +1,94 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Checkpoint NAVs", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMints: PublicKey[];
+    let offerPdas: PublicKey[];
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMints = [testHelper.createMint(9), testHelper.createMint(9), testHelper.createMint(9)];
+
+        await program.initialize({ onycMint: tokenOutMints[0] });
+
+        currentTime = await testHelper.getCurrentClockTime();
+        offerPdas = [];
+        for (let i = 0; i < tokenOutMints.length; i++) {
+            await program.makeOffer({ tokenInMint, tokenOutMint: tokenOutMints[i] });
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint: tokenOutMints[i],
+                baseTime: currentTime,
+                basePrice: (i + 1) * 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+            offerPdas.push(program.getOfferPda(tokenInMint, tokenOutMints[i]));
+        }
+    });
+
+    it("Should checkpoint every offer in one transaction", async () => {
+        // when
+        const processed = await program.checkpointNavs(offerPdas);
+
+        // then
+        expect(processed).toBe(3);
+        for (let i = 0; i < tokenOutMints.length; i++) {
+            const offer = await program.getOffer(tokenInMint, tokenOutMints[i]);
+            expect(offer.navCheckpoint.toNumber()).toBe((i + 1) * 1e9);
+            expect(offer.navCheckpointTime.toNumber()).toBe(currentTime);
+        }
+    });
+
+    it("Should refresh checkpoints with the current NAV", async () => {
+        await program.checkpointNavs(offerPdas);
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint: tokenOutMints[0],
+            baseTime: currentTime + 1000,
+            basePrice: 1.5e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await testHelper.advanceClockBy(1000);
+
+        await program.checkpointNavs([offerPdas[0]]);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMints[0]);
+        expect(offer.navCheckpoint.toNumber()).toBe(1.5e9);
+        expect(offer.navCheckpointTime.toNumber()).toBe(currentTime + 1000);
+    });
+
+    it("Should skip offers without an active vector", async () => {
+        const otherTokenOut = testHelper.createMint(9);
+        await program.makeOffer({ tokenInMint, tokenOutMint: otherTokenOut });
+        const emptyOfferPda = program.getOfferPda(tokenInMint, otherTokenOut);
+
+        const processed = await program.checkpointNavs([emptyOfferPda, ...offerPdas]);
+
+        expect(processed).toBe(4);
+        const emptyOffer = await program.getOffer(tokenInMint, otherTokenOut);
+        expect(emptyOffer.navCheckpoint.toNumber()).toBe(0);
+        expect(emptyOffer.navCheckpointTime.toNumber()).toBe(0);
+    });
+
+    it("Should return zero when no offers are passed", async () => {
+        expect(await program.checkpointNavs([])).toBe(0);
+    });
+
+    it("Should reject accounts that are not offers", async () => {
+        await expect(
+            program.checkpointNavs([...offerPdas, program.pdas.statePda])
+        ).rejects.toThrow();
+    });
+});
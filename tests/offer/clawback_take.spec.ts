@@ -0,0 +1,279 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Clawback Take", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let guardian: Keypair;
+
+    let userTokenInAccount: PublicKey;
+    let userTokenOutAccount: PublicKey;
+    let bossTokenInAccount: PublicKey;
+    let vaultTokenOutAccount: PublicKey;
+
+    const TOKEN_IN_AMOUNT = 1_000e6;
+    const TOKEN_OUT_AMOUNT = 990e9; // 1% fee, price 1.0
+
+    async function take(taker: Keypair = user) {
+        const takeReceipt = await program.getNextTakeReceiptPda(tokenInMint, tokenOutMint, taker.publicKey);
+        await program.takeOffer({
+            tokenInAmount: TOKEN_IN_AMOUNT,
+            tokenInMint,
+            tokenOutMint,
+            user: taker.publicKey,
+            signer: taker,
+            takeReceipt
+        });
+        return takeReceipt;
+    }
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 100 });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        guardian = testHelper.createUserAccount();
+
+        userTokenInAccount = testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+        bossTokenInAccount = testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await program.setOfferClawback({ tokenInMint, tokenOutMint, clawbackEnabled: true });
+    });
+
+    it("Should reverse a take by returning token_out to the vault and refunding token_in", async () => {
+        // given
+        const takeReceipt = await take();
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(TOKEN_OUT_AMOUNT));
+        expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(TOKEN_IN_AMOUNT));
+
+        // when
+        await program.clawbackTake({
+            tokenInMint,
+            tokenOutMint,
+            user,
+            guardian,
+            takeReceipt
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userTokenInAccount)).toBe(BigInt(10_000e6));
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(10_000e9));
+
+        const offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+        const record = await program.getClawbackRecord(takeReceipt);
+        expect(record.offer).toEqual(offerPda);
+        expect(record.user).toEqual(user.publicKey);
+        expect(record.tokenInAmount.toNumber()).toBe(TOKEN_IN_AMOUNT);
+        expect(record.tokenOutAmount.toNumber()).toBe(TOKEN_OUT_AMOUNT);
+        expect(await testHelper.getAccountInfo(takeReceipt)).toBeNull();
+    });
+
+    it("Should record the take's timestamp and amounts on its receipt", async () => {
+        // given
+        const takeTimestamp = await testHelper.getCurrentClockTime();
+
+        // when
+        const takeReceipt = await take();
+
+        // then
+        const receipt = await program.getTakeReceipt(takeReceipt);
+        expect(receipt.offer).toEqual(program.getOfferPda(tokenInMint, tokenOutMint));
+        expect(receipt.user).toEqual(user.publicKey);
+        expect(receipt.index.toNumber()).toBe(0);
+        expect(receipt.takeTimestamp.toNumber()).toBe(takeTimestamp);
+        expect(receipt.tokenInAmount.toNumber()).toBe(TOKEN_IN_AMOUNT);
+        expect(receipt.tokenOutAmount.toNumber()).toBe(TOKEN_OUT_AMOUNT);
+
+        const secondReceipt = await take();
+        expect(secondReceipt).not.toEqual(takeReceipt);
+        expect((await program.getTakeReceipt(secondReceipt)).index.toNumber()).toBe(1);
+    });
+
+    it("Should reject takes of a flagged offer without a take receipt", async () => {
+        await expect(
+            program.takeOffer({
+                tokenInAmount: TOKEN_IN_AMOUNT,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("Take receipt required for offers flagged for clawbacks");
+    });
+
+    it("Should burn returned token_out when the program controls the mint", async () => {
+        // given
+        await program.transferMintAuthorityToProgram({ mint: tokenOutMint });
+        const takeReceipt = await take();
+        const supplyAfterTake = (await testHelper.getMintInfo(tokenOutMint)).supply;
+
+        // when
+        await program.clawbackTake({
+            tokenInMint,
+            tokenOutMint,
+            user,
+            guardian,
+            takeReceipt
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(0));
+        expect((await testHelper.getMintInfo(tokenOutMint)).supply).toBe(supplyAfterTake - BigInt(TOKEN_OUT_AMOUNT));
+        expect(await testHelper.getTokenAccountBalance(userTokenInAccount)).toBe(BigInt(10_000e6));
+    });
+
+    it("Should reject clawback on an offer that is not flagged", async () => {
+        // given
+        await program.setOfferClawback({ tokenInMint, tokenOutMint, clawbackEnabled: false });
+        const takeReceipt = await take();
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian,
+                takeReceipt
+            })
+        ).rejects.toThrow("Clawback is not enabled for this offer");
+    });
+
+    it("Should reject clawback after the dispute window has expired", async () => {
+        // given
+        const takeReceipt = await take();
+        await testHelper.advanceClockBy(24 * 3600 + 1);
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian,
+                takeReceipt
+            })
+        ).rejects.toThrow("Clawback window has expired");
+    });
+
+    it("Should reject a take receipt of another user", async () => {
+        // given
+        const otherUser = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, otherUser.publicKey, BigInt(10_000e6));
+        await take();
+        const otherTakeReceipt = await take(otherUser);
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian,
+                takeReceipt: otherTakeReceipt
+            })
+        ).rejects.toThrow("Take receipt does not belong to this offer and user");
+    });
+
+    it("Should reject clawing back the same take twice", async () => {
+        // given
+        const takeReceipt = await take();
+        await program.clawbackTake({
+            tokenInMint,
+            tokenOutMint,
+            user,
+            guardian,
+            takeReceipt
+        });
+        await testHelper.advanceSlot();
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian,
+                takeReceipt
+            })
+        ).rejects.toThrow();
+    });
+
+    it("Should reject clawback without the configured guardian", async () => {
+        // given
+        const takeReceipt = await take();
+        const notGuardian = testHelper.createUserAccount();
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian: notGuardian,
+                takeReceipt
+            })
+        ).rejects.toThrow();
+    });
+
+    it("Should reject clawback when no guardian is configured", async () => {
+        // given
+        await program.setGuardian({ guardian: PublicKey.default, currentGuardian: guardian });
+        const takeReceipt = await take();
+
+        // when / then
+        await expect(
+            program.clawbackTake({
+                tokenInMint,
+                tokenOutMint,
+                user,
+                guardian,
+                takeReceipt
+            })
+        ).rejects.toThrow();
+    });
+
+    it("Should let the user close a take receipt once the dispute window has ended", async () => {
+        // given
+        const takeReceipt = await take();
+
+        // when / then
+        await expect(program.closeTakeReceipt({ takeReceipt, user }))
+            .rejects.toThrow("Clawback window has not ended");
+
+        await testHelper.advanceClockBy(24 * 3600 + 1);
+        await program.closeTakeReceipt({ takeReceipt, user });
+        expect(await testHelper.getAccountInfo(takeReceipt)).toBeNull();
+    });
+});
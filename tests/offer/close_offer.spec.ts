@@ -0,0 +1,199 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Close Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let boss: PublicKey;
+    let bossTokenInAccount: PublicKey;
+    let bossTokenOutAccount: PublicKey;
+    let vaultTokenInAccount: PublicKey;
+    let vaultTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+        boss = testHelper.getBoss();
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        bossTokenInAccount = testHelper.createTokenAccount(tokenInMint, boss, BigInt(1_000e6));
+        bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, boss, BigInt(1_000e9));
+        const ownVaultAuthority = program.getOwnOfferVaultAuthorityPda(program.getOfferPda(tokenInMint, tokenOutMint));
+        vaultTokenInAccount = getAssociatedTokenAddressSync(tokenInMint, ownVaultAuthority, true);
+        vaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, ownVaultAuthority, true);
+
+        const offer = program.getOfferPda(tokenInMint, tokenOutMint);
+        await program.offerVaultDeposit({ amount: 3e6, tokenMint: tokenInMint, offer });
+        await program.offerVaultDeposit({ amount: 5e9, tokenMint: tokenOutMint, offer });
+    });
+
+    test("Rejects closing an offer without sweeping its own vault", async () => {
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [] })
+        ).rejects.toThrow("Both accounts of the offer's own vault must be swept to close it");
+        expect(await testHelper.getTokenAccountBalance(vaultTokenInAccount)).toBe(BigInt(3e6));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(5e9));
+    });
+
+    test("Closing sweeps residual vault balances of the offer's mints to the boss", async () => {
+        // given
+        const sequenceBefore = (await program.getState()).vaultEventSequence.toNumber();
+
+        // when
+        await program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenInMint, tokenOutMint] });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(vaultTokenInAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(1_000e6));
+        expect(await testHelper.getTokenAccountBalance(bossTokenOutAccount)).toBe(BigInt(1_000e9));
+
+        const state = await program.getState();
+        expect(state.vaultEventSequence.toNumber()).toBe(sequenceBefore + 2);
+    });
+
+    test("Rejects closing an offer sweeping only the token_in vault", async () => {
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenInMint] })
+        ).rejects.toThrow("Both accounts of the offer's own vault must be swept to close it");
+        expect(await testHelper.getTokenAccountBalance(vaultTokenInAccount)).toBe(BigInt(3e6));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(5e9));
+    });
+
+    test("A closed vault account counts as swept", async () => {
+        // given
+        const offer = program.getOfferPda(tokenInMint, tokenOutMint);
+        await program.offerVaultWithdraw({ amount: 3e6, tokenMint: tokenInMint, offer });
+        await program.sweepVaultDust({ vaultAccounts: [vaultTokenInAccount], offer });
+        expect(await testHelper.getAccountInfo(vaultTokenInAccount)).toBeNull();
+
+        // when
+        await program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenInMint, tokenOutMint] });
+
+        // then
+        expect(await testHelper.getAccountInfo(offer)).toBeNull();
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(0));
+    });
+
+    test("Rejects sweeping a mint that does not belong to the offer", async () => {
+        // given
+        const otherMint = testHelper.createMint(6);
+        testHelper.createTokenAccount(otherMint, boss, BigInt(1e6));
+        await program.offerVaultDeposit({ amount: 1e6, tokenMint: otherMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [otherMint] })
+        ).rejects.toThrow("Invalid sweep accounts");
+    });
+
+    test("Rejects sweeping the same mint twice", async () => {
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenInMint, tokenInMint] })
+        ).rejects.toThrow("Duplicate sweep mint");
+    });
+
+    test("Rejects sweeping a balance above the timelock's immediate threshold", async () => {
+        // given
+        await program.configureVaultWithdrawTimelock({ tokenMint: tokenOutMint, delaySecs: 86_400, immediateThreshold: 1e9 });
+
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenOutMint] })
+        ).rejects.toThrow("Residual balance exceeds the immediate threshold and must be proposed");
+    });
+
+    test("Rejects sweeping the shared offer vault", async () => {
+        // given
+        const sharedVaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, program.pdas.offerVaultAuthorityPda, true);
+        const otherMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: otherMint, tokenOutMint });
+        await program.moveOfferToSharedVault(program.getOfferPda(otherMint, tokenOutMint));
+        await program.offerVaultDeposit({ amount: 5e9, tokenMint: tokenOutMint });
+
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint: otherMint, tokenOutMint, sweepMints: [tokenOutMint] })
+        ).rejects.toThrow("Only offers with their own vault can sweep residual balances");
+        expect(await testHelper.getTokenAccountBalance(sharedVaultTokenOutAccount)).toBe(BigInt(5e9));
+    });
+
+    test("An offer served by the shared offer vault closes without sweeps", async () => {
+        // given
+        const otherMint = testHelper.createMint(6);
+        const otherOffer = program.getOfferPda(otherMint, tokenOutMint);
+        await program.makeOffer({ tokenInMint: otherMint, tokenOutMint });
+        await program.moveOfferToSharedVault(otherOffer);
+
+        // when
+        await program.closeOffer({ tokenInMint: otherMint, tokenOutMint });
+
+        // then
+        expect(await testHelper.getAccountInfo(otherOffer)).toBeNull();
+    });
+
+    test("Rejects closing an offer backing a redemption offer", async () => {
+        // given
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        // when/then
+        await expect(
+            program.closeOffer({ tokenInMint, tokenOutMint })
+        ).rejects.toThrow("Offer backs a redemption offer, close the redemption offer first");
+    });
+
+    test("Rejects closing the primary offer", async () => {
+        // given
+        await program.setPrimaryOffer({ offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        // when/then
+        await expect(program.closeOffer({ tokenInMint, tokenOutMint })).rejects.toThrow("The primary offer cannot be closed");
+    });
+
+    test("A closed offer can be made again", async () => {
+        // given
+        await program.closeOffer({ tokenInMint, tokenOutMint });
+
+        // when
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 50 });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.feeBasisPoints).toBe(50);
+        expect(await testHelper.getTokenAccountBalance(vaultTokenInAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOutAccount)).toBe(BigInt(0));
+    });
+
+    test("Recreating a closed offer bumps its version", async () => {
+        // given
+        expect((await program.getOffer(tokenInMint, tokenOutMint)).offerVersion).toBe(1);
+        await program.closeOffer({ tokenInMint, tokenOutMint });
+
+        // when
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.offerVersion).toBe(2);
+        const record = await program.getOfferVersionRecord(tokenInMint, tokenOutMint);
+        expect(record.latestVersion).toBe(2);
+    });
+
+    test("Non-boss cannot close an offer", async () => {
+        const nonBoss: Keypair = testHelper.createUserAccount();
+        await expect(program.closeOffer({ tokenInMint, tokenOutMint, signer: nonBoss })).rejects.toThrow();
+    });
+});
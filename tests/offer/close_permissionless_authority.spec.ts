@@ -0,0 +1,101 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Close Permissionless Authority", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let authority: PublicKey;
+    let intermediaryTokenInAccount: PublicKey;
+    let intermediaryTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.initializePermissionlessAuthority({ accountName: "partner-2", index: 2 });
+
+        authority = program.getPermissionlessAuthorityPda(2);
+        intermediaryTokenInAccount = testHelper.createTokenAccount(tokenInMint, authority, BigInt(0), true);
+        intermediaryTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, authority, BigInt(0), true);
+    });
+
+    test("Boss can close an authority with empty intermediary accounts", async () => {
+        // given
+        const boss = testHelper.getBoss();
+        const bossLamportsBefore = (await testHelper.getAccountInfo(boss)).lamports;
+
+        // when
+        await program.closePermissionlessAuthority({
+            authorityIndex: 2,
+            intermediaryAccounts: [intermediaryTokenInAccount, intermediaryTokenOutAccount]
+        });
+
+        // then
+        expect(await testHelper.getAccountInfo(authority)).toBeNull();
+        expect(await testHelper.getAccountInfo(intermediaryTokenInAccount)).toBeNull();
+        expect(await testHelper.getAccountInfo(intermediaryTokenOutAccount)).toBeNull();
+        expect((await testHelper.getAccountInfo(boss)).lamports).toBeGreaterThan(bossLamportsBefore);
+    });
+
+    test("Closing one authority leaves the others in place", async () => {
+        // given
+        await program.initializePermissionlessAuthority({ accountName: "partner-1" });
+
+        // when
+        await program.closePermissionlessAuthority({ authorityIndex: 2, intermediaryAccounts: [] });
+
+        // then
+        expect(await testHelper.getAccountInfo(authority)).toBeNull();
+        expect((await program.getPermissionlessAuthority(1)).name).toBe("partner-1");
+    });
+
+    test("A closed authority can be initialized again", async () => {
+        // given
+        await program.closePermissionlessAuthority({ authorityIndex: 2, intermediaryAccounts: [] });
+
+        // when
+        await program.initializePermissionlessAuthority({ accountName: "partner-2b", index: 2 });
+
+        // then
+        expect((await program.getPermissionlessAuthority(2)).name).toBe("partner-2b");
+    });
+
+    test("Rejects closing while an intermediary account holds tokens", async () => {
+        // given
+        const fundedAccount = testHelper.createTokenAccount(testHelper.createMint(6), authority, BigInt(1), true);
+
+        // when/then
+        await expect(
+            program.closePermissionlessAuthority({
+                authorityIndex: 2,
+                intermediaryAccounts: [intermediaryTokenInAccount, fundedAccount]
+            })
+        ).rejects.toThrow("Intermediary token account is not empty");
+    });
+
+    test("Rejects token accounts controlled by another authority", async () => {
+        // given
+        const otherAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+
+        // when/then
+        await expect(
+            program.closePermissionlessAuthority({ authorityIndex: 2, intermediaryAccounts: [otherAccount] })
+        ).rejects.toThrow("Invalid intermediary token account");
+    });
+
+    test("Non-boss cannot close an authority", async () => {
+        const nonBoss = testHelper.createUserAccount();
+        await expect(
+            program.closePermissionlessAuthority({ authorityIndex: 2, intermediaryAccounts: [], signer: nonBoss })
+        ).rejects.toThrow();
+    });
+});
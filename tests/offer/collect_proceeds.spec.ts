@@ -0,0 +1,148 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Offer Proceeds", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+    let vaultTokenInAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+        await program.initialize({ onycMint: tokenOutMint });
+
+        await program.makeOffer({ tokenInMint, tokenOutMint, proceedsToVault: true });
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+    });
+
+    test("New offers route take proceeds to the offer vault without a boss account", async () => {
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenInAccount, BigInt(1_000e6));
+    });
+
+    test("Boss collects proceeds from the offer vault", async () => {
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await program.collectProceeds({ amount: 600e6, tokenInMint, tokenOutMint });
+
+        const bossTokenInAccount = getAssociatedTokenAddressSync(tokenInMint, testHelper.getBoss());
+        await testHelper.expectTokenAccountAmountToBe(bossTokenInAccount, BigInt(600e6));
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenInAccount, BigInt(400e6));
+        expect((await program.getOffer(tokenInMint, tokenOutMint)).uncollectedProceeds.toNumber()).toBe(400e6);
+    });
+
+    test("Cannot collect more than the offer's uncollected proceeds", async () => {
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(5_000e6));
+        await program.offerVaultDeposit({ amount: 5_000e6, tokenMint: tokenInMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await expect(
+            program.collectProceeds({ amount: 1_000e6 + 1, tokenInMint, tokenOutMint })
+        ).rejects.toThrow("Amount exceeds the offer's uncollected proceeds");
+
+        await program.collectProceeds({ amount: 1_000e6, tokenInMint, tokenOutMint });
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenInAccount, BigInt(5_000e6));
+        expect((await program.getOffer(tokenInMint, tokenOutMint)).uncollectedProceeds.toNumber()).toBe(0);
+    });
+
+    test("Collections above the withdrawal timelock threshold are rejected", async () => {
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+        await program.configureVaultWithdrawTimelock({ tokenMint: tokenInMint, delaySecs: 86_400, immediateThreshold: 500e6 });
+
+        await expect(
+            program.collectProceeds({ amount: 600e6, tokenInMint, tokenOutMint })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+
+        await program.collectProceeds({ amount: 500e6, tokenInMint, tokenOutMint });
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenInAccount, BigInt(500e6));
+    });
+
+    test("Offers paying the boss require the boss token_in account", async () => {
+        await program.setOfferProceedsToVault({ tokenInMint, tokenOutMint, proceedsToVault: false });
+
+        await expect(
+            program.takeOffer({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                bossTokenInAccount: null
+            })
+        ).rejects.toThrow("Boss token_in account required by the offer");
+    });
+
+    test("Non-boss cannot collect proceeds", async () => {
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await expect(
+            program.collectProceeds({
+                amount: 1_000e6,
+                tokenInMint,
+                tokenOutMint,
+                destination: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow();
+    });
+});
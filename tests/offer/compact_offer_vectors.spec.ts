@@ -0,0 +1,100 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Compact Offer Vectors", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        // Create mints
+        tokenInMint = testHelper.createMint(9);
+        tokenOutMint = testHelper.createMint(9);
+
+        // Initialize program and offer
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        currentTime = await testHelper.getCurrentClockTime();
+
+        // Schedule four future vectors
+        for (let i = 1; i <= 4; i++) {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + i * 1000,
+                basePrice: 1000000 * i,
+                apr: 5000,
+                priceFixDuration: 100
+            });
+        }
+    });
+
+    it("Should clear expired vectors while keeping active, previous and future ones", async () => {
+        // Vector 3 becomes active, vector 2 is its predecessor, vector 1 is expired
+        await testHelper.advanceClockBy(3500);
+
+        await program.compactOfferVectors(tokenInMint, tokenOutMint);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        const startTimes = offer.vectors
+            .map(v => v.startTime.toNumber())
+            .filter(startTime => startTime !== 0)
+            .sort((a, b) => a - b);
+
+        expect(startTimes).toEqual([
+            currentTime + 2000,
+            currentTime + 3000,
+            currentTime + 4000
+        ]);
+    });
+
+    it("Should be a no-op when no vector is active yet", async () => {
+        await program.compactOfferVectors(tokenInMint, tokenOutMint);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        const activeVectors = offer.vectors.filter(v => v.startTime.toNumber() !== 0);
+        expect(activeVectors.length).toBe(4);
+    });
+
+    it("Should be a no-op when only active and previous vectors are in the past", async () => {
+        await testHelper.advanceClockBy(2500);
+
+        await program.compactOfferVectors(tokenInMint, tokenOutMint);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        const activeVectors = offer.vectors.filter(v => v.startTime.toNumber() !== 0);
+        expect(activeVectors.length).toBe(4);
+    });
+
+    it("Should evict every vector older than the previous one", async () => {
+        // Schedule six more vectors
+        for (let i = 5; i <= 10; i++) {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + i * 1000,
+                basePrice: 1000000 * i,
+                apr: 5000,
+                priceFixDuration: 100
+            });
+        }
+
+        await testHelper.advanceClockBy(4500);
+        await program.compactOfferVectors(tokenInMint, tokenOutMint);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        const activeVectors = offer.vectors.filter(v => v.startTime.toNumber() !== 0);
+        expect(activeVectors.length).toBe(8);
+    });
+});
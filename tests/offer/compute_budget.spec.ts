@@ -0,0 +1,134 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+// Compute unit ceilings guarding against regressions in the pricing math. They
+// leave headroom over the measured cost but fail long before the 200k default
+// per-instruction limit is at risk.
+const TAKE_OFFER_CU_BUDGET = 100_000;
+const TAKE_OFFER_TOKEN_2022_CU_BUDGET = 130_000;
+const GET_NAV_CU_BUDGET = 25_000;
+
+// The price calculation must not get more expensive as steps accumulate
+const MAX_CU_GROWTH_OVER_STEPS = 1_000;
+
+describe("Compute budget", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+        user = testHelper.createUserAccount();
+    });
+
+    async function setupOffer(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey, tokenProgram: PublicKey }) {
+        const { tokenInMint, tokenOutMint, tokenProgram } = params;
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint,
+            tokenInProgram: tokenProgram,
+            tokenOutProgram: tokenProgram
+        });
+
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(1_000_000e6), false, tokenProgram);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0), false, tokenProgram);
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(100_000e9), false, tokenProgram);
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, tokenProgram);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, tokenProgram);
+        await program.offerVaultDeposit({ amount: 100_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint), tokenProgram });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500,
+            priceFixDuration: 86400
+        });
+    }
+
+    async function takeOfferComputeUnits(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey, tokenProgram: PublicKey }) {
+        const tx = await program.program.methods
+            .takeOffer(new BN(1_000e6), null)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: user.publicKey,
+                tokenInProgram: params.tokenProgram,
+                tokenOutProgram: params.tokenProgram,
+                vaultAuthority: program.getOfferVaultAuthorityPda(params.tokenInMint, params.tokenOutMint),
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, program.getOfferVaultAuthorityPda(params.tokenInMint, params.tokenOutMint), true, params.tokenProgram),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, program.getOfferVaultAuthorityPda(params.tokenInMint, params.tokenOutMint), true, params.tokenProgram),
+                tokenInOracle: null,
+                navHistory: null,
+                blocklistBucket: program.getBlocklistBucketPda(user.publicKey)
+            })
+            .transaction();
+
+        return testHelper.simulateComputeUnits(tx, [user]);
+    }
+
+    async function getNavComputeUnits(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }) {
+        const tx = await program.program.methods
+            .getNav()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        return testHelper.simulateComputeUnits(tx);
+    }
+
+    it("take_offer with SPL tokens stays within its compute budget", async () => {
+        const tokenInMint = testHelper.createMint(6);
+        const tokenOutMint = testHelper.createMint(9);
+        await setupOffer({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_PROGRAM_ID });
+
+        const units = await takeOfferComputeUnits({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_PROGRAM_ID });
+
+        expect(units).toBeLessThan(TAKE_OFFER_CU_BUDGET);
+    });
+
+    it("take_offer with Token-2022 transfer fee mints stays within its compute budget", async () => {
+        const tokenInMint = await testHelper.createMint2022WithTransferFee(6, 0, BigInt(0));
+        const tokenOutMint = await testHelper.createMint2022WithTransferFee(9, 0, BigInt(0));
+        await setupOffer({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+        const units = await takeOfferComputeUnits({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+        expect(units).toBeLessThan(TAKE_OFFER_TOKEN_2022_CU_BUDGET);
+    });
+
+    it("get_nav stays within its compute budget", async () => {
+        const tokenInMint = testHelper.createMint(6);
+        const tokenOutMint = testHelper.createMint(9);
+        await setupOffer({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_PROGRAM_ID });
+
+        const units = await getNavComputeUnits({ tokenInMint, tokenOutMint });
+
+        expect(units).toBeLessThan(GET_NAV_CU_BUDGET);
+    });
+
+    it("price calculation cost does not grow with elapsed steps", async () => {
+        const tokenInMint = testHelper.createMint(6);
+        const tokenOutMint = testHelper.createMint(9);
+        await setupOffer({ tokenInMint, tokenOutMint, tokenProgram: TOKEN_PROGRAM_ID });
+
+        const early = await getNavComputeUnits({ tokenInMint, tokenOutMint });
+
+        // ~3 years of daily steps
+        await testHelper.advanceClockBy(1_000 * 86400);
+        const late = await getNavComputeUnits({ tokenInMint, tokenOutMint });
+
+        expect(Math.abs(late - early)).toBeLessThan(MAX_CU_GROWTH_OVER_STEPS);
+    });
+});
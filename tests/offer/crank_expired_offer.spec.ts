@@ -0,0 +1,116 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const GRACE_SECONDS = 7 * 24 * 60 * 60;
+
+describe("Crank expired offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let offerPda: PublicKey;
+    let user: Keypair;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        currentTime = await testHelper.getCurrentClockTime();
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9
+        });
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: currentTime + 100 });
+        offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(1_000e6), true);
+    });
+
+    const take = () => program.takeOffer({
+        tokenInAmount: 10e6,
+        tokenInMint,
+        tokenOutMint,
+        user: user.publicKey,
+        signer: user,
+        bossTokenInAccount: null
+    });
+
+    it("Should close the offer once the grace period after expiry has passed", async () => {
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+
+        await program.crankExpiredOffer({ offer: offerPda });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.closed).toBe(1);
+        const registry = await program.getRegistry();
+        expect(registry.offers.some(offer => offer.equals(offerPda))).toBe(false);
+    });
+
+    it("Should reject cranks during the grace period", async () => {
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS - 1);
+
+        await expect(program.crankExpiredOffer({ offer: offerPda }))
+            .rejects.toThrow("Offer has not been expired for the grace period");
+    });
+
+    it("Should reject cranks of offers without an expiry", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: 0 });
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+
+        await expect(program.crankExpiredOffer({ offer: offerPda }))
+            .rejects.toThrow("Offer has not been expired for the grace period");
+    });
+
+    it("Should reject cranking an offer twice", async () => {
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+        await program.crankExpiredOffer({ offer: offerPda });
+        await testHelper.advanceSlot();
+
+        await expect(program.crankExpiredOffer({ offer: offerPda }))
+            .rejects.toThrow("Offer is already closed");
+    });
+
+    it("Should reject cranking the primary offer", async () => {
+        await program.setPrimaryOffer({ offer: offerPda });
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+
+        await expect(program.crankExpiredOffer({ offer: offerPda }))
+            .rejects.toThrow("The primary offer cannot be closed");
+    });
+
+    it("Should keep a closed offer closed when its window is moved", async () => {
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+        await program.crankExpiredOffer({ offer: offerPda });
+
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: 0 });
+
+        await expect(take()).rejects.toThrow("Offer is closed");
+    });
+
+    it("Should let the boss reclaim the rent of a closed offer", async () => {
+        await testHelper.advanceClockBy(100 + GRACE_SECONDS);
+        await program.crankExpiredOffer({ offer: offerPda });
+
+        await program.closeOffer({ tokenInMint, tokenOutMint });
+
+        expect(testHelper.svm.getAccount(offerPda)).toBeNull();
+        const bossTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossTokenOutAccount)).toBe(BigInt(10_000e9));
+    });
+});
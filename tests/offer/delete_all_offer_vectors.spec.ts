@@ -2,7 +2,7 @@ import { PublicKey } from "@solana/web3.js";
 import { TestHelper } from "../test_helper";
 import { OnreProgram } from "../onre_program.ts";
 
-const MAX_VECTORS = 10;
+const MAX_VECTORS = 64;
 
 describe("Delete All Offer Vectors", () => {
     let testHelper: TestHelper;
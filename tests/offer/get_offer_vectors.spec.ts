@@ -0,0 +1,124 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Offer Vectors", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        currentTime = await testHelper.getCurrentClockTime();
+    });
+
+    it("Should return an empty page for an offer without vectors", async () => {
+        const result = await program.getOfferVectors({ tokenInMint, tokenOutMint });
+
+        expect(result.version).toBe(1);
+        expect(result.totalVectors).toBe(0);
+        expect(result.vectors).toEqual([]);
+        expect(result.hasMore).toBe(false);
+    });
+
+    it("Should return configured vectors with all fields", async () => {
+        // given
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500,
+            priceFixDuration: 86400
+        });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 1000,
+            basePrice: 1.1e9,
+            apr: -5_000,
+            priceFixDuration: 3600,
+            pricingMode: "linear"
+        });
+
+        // when
+        const result = await program.getOfferVectors({ tokenInMint, tokenOutMint });
+
+        // then
+        expect(result.totalVectors).toBe(2);
+        expect(result.vectors).toEqual([
+            { startTime: currentTime, baseTime: currentTime, basePrice: 1e9, apr: 36_500, priceFixDuration: 86400, pricingMode: 0 },
+            { startTime: currentTime + 1000, baseTime: currentTime + 1000, basePrice: 1.1e9, apr: -5_000, priceFixDuration: 3600, pricingMode: 1 }
+        ]);
+        expect(result.hasMore).toBe(false);
+    });
+
+    it("Should filter out vectors superseded by the active one", async () => {
+        // given
+        for (let i = 0; i < 3; i++) {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + i * 1000,
+                basePrice: 1e9 + i,
+                apr: 0,
+                priceFixDuration: 100
+            });
+        }
+
+        // when
+        await testHelper.advanceClockBy(1500);
+        const result = await program.getOfferVectors({ tokenInMint, tokenOutMint });
+
+        // then
+        expect(result.totalVectors).toBe(2);
+        expect(result.vectors.map(vector => vector.startTime)).toEqual([currentTime + 1000, currentTime + 2000]);
+    });
+
+    it("Should page through more vectors than fit in a single page", async () => {
+        // given
+        for (let i = 1; i <= 20; i++) {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + i * 1000,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 100
+            });
+        }
+
+        // when
+        const firstPage = await program.getOfferVectors({ tokenInMint, tokenOutMint, page: 0 });
+        const secondPage = await program.getOfferVectors({ tokenInMint, tokenOutMint, page: 1 });
+        const pastEnd = await program.getOfferVectors({ tokenInMint, tokenOutMint, page: 2 });
+
+        // then
+        expect(firstPage.totalVectors).toBe(20);
+        expect(firstPage.vectors.length).toBe(16);
+        expect(firstPage.hasMore).toBe(true);
+        expect(secondPage.page).toBe(1);
+        expect(secondPage.vectors.length).toBe(4);
+        expect(secondPage.hasMore).toBe(false);
+        expect(pastEnd.vectors).toEqual([]);
+    });
+
+    it("Should reject mints that do not belong to the offer", async () => {
+        const otherMint = testHelper.createMint(6);
+
+        await expect(
+            program.getOfferVectors({ tokenInMint: otherMint, tokenOutMint })
+        ).rejects.toThrow();
+    });
+});
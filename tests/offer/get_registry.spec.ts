@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Registry", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+    });
+
+    it("Should list offers in creation order", async () => {
+        // given
+        const tokenInMints = [testHelper.createMint(6), testHelper.createMint(6)];
+        for (const tokenInMint of tokenInMints) {
+            await program.makeOffer({ tokenInMint, tokenOutMint });
+        }
+
+        // when
+        const result = await program.getRegistry();
+
+        // then
+        expect(result.totalOffers).toBe(2);
+        expect(result.offers).toEqual(tokenInMints.map(mint => program.getOfferPda(mint, tokenOutMint)));
+        expect(result.hasMore).toBe(false);
+    });
+
+    it("Should remove closed offers and reuse their slot", async () => {
+        // given
+        const closedMint = testHelper.createMint(6);
+        const keptMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: closedMint, tokenOutMint });
+        await program.makeOffer({ tokenInMint: keptMint, tokenOutMint });
+
+        // when
+        await program.closeOffer({ tokenInMint: closedMint, tokenOutMint });
+
+        // then
+        let result = await program.getRegistry();
+        expect(result.offers).toEqual([program.getOfferPda(keptMint, tokenOutMint)]);
+
+        // when
+        const newMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: newMint, tokenOutMint });
+
+        // then
+        result = await program.getRegistry();
+        expect(result.offers).toEqual([
+            program.getOfferPda(newMint, tokenOutMint),
+            program.getOfferPda(keptMint, tokenOutMint)
+        ]);
+    });
+
+    it("Should page through the registry", async () => {
+        // given
+        for (let i = 0; i < 17; i++) {
+            await program.makeOffer({ tokenInMint: testHelper.createMint(6), tokenOutMint });
+        }
+
+        // when
+        const first = await program.getRegistry(0);
+        const second = await program.getRegistry(1);
+        const beyond = await program.getRegistry(2);
+
+        // then
+        expect(first.offers.length).toBe(16);
+        expect(first.hasMore).toBe(true);
+        expect(second.offers.length).toBe(1);
+        expect(second.hasMore).toBe(false);
+        expect(second.totalOffers).toBe(17);
+        expect(beyond.offers).toEqual([]);
+    });
+
+    it("Should reject offers beyond the registry capacity", async () => {
+        // given
+        for (let i = 0; i < 64; i++) {
+            await program.makeOffer({ tokenInMint: testHelper.createMint(6), tokenOutMint });
+        }
+
+        // when/then
+        await expect(
+            program.makeOffer({ tokenInMint: testHelper.createMint(6), tokenOutMint })
+        ).rejects.toThrow("Offer registry is full");
+    });
+});
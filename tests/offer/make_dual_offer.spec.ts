@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Make Dual Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint1: PublicKey;
+    let tokenOutMint2: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint1 = testHelper.createMint(9);
+        tokenOutMint2 = testHelper.createMint(6);
+
+        await program.initialize({ onycMint: tokenOutMint1 });
+        await program.makeOffer({ tokenInMint, tokenOutMint: tokenOutMint1 });
+    });
+
+    test("Should create a dual offer linked to the base offer", async () => {
+        // when
+        await program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 1e8 });
+
+        // then
+        const dualOffer = await program.getDualOffer(tokenInMint, tokenOutMint1, tokenOutMint2);
+        expect(dualOffer.offer).toEqual(program.getOfferPda(tokenInMint, tokenOutMint1));
+        expect(dualOffer.tokenInMint).toEqual(tokenInMint);
+        expect(dualOffer.tokenOutMint1).toEqual(tokenOutMint1);
+        expect(dualOffer.tokenOutMint2).toEqual(tokenOutMint2);
+        expect(dualOffer.tokenOut2Ratio.toNumber()).toBe(1e8);
+    });
+
+    test("Should initialize the vault account of the secondary token", async () => {
+        // when
+        await program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 1e8 });
+
+        // then
+        await expect(testHelper.getAccount(
+            getAssociatedTokenAddressSync(tokenOutMint2, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint1), true))
+        ).resolves.toBeDefined();
+    });
+
+    test("Should reject a dual offer without base offer", async () => {
+        const otherTokenIn = testHelper.createMint(6);
+
+        await expect(
+            program.makeDualOffer({ tokenInMint: otherTokenIn, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 1e8 })
+        ).rejects.toThrow();
+    });
+
+    test("Should reject a zero ratio", async () => {
+        await expect(
+            program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 0 })
+        ).rejects.toThrow("Invalid ratio: must be greater than zero");
+    });
+
+    test("Should reject the principal mint as secondary mint", async () => {
+        await expect(
+            program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2: tokenInMint, tokenOut2Ratio: 1e8 })
+        ).rejects.toThrow("Invalid secondary token mint");
+    });
+
+    test("Should reject duplicate dual offers", async () => {
+        await program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 1e8 });
+
+        await expect(
+            program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 2e8 })
+        ).rejects.toThrow();
+    });
+
+    test("Should reject when called by non-boss", async () => {
+        await expect(
+            program.makeDualOffer({
+                tokenInMint,
+                tokenOutMint1,
+                tokenOutMint2,
+                tokenOut2Ratio: 1e8,
+                signer: testHelper.createUserAccount()
+            })
+        ).rejects.toThrow();
+    });
+});
@@ -71,7 +71,7 @@ describe("Make offer", () => {
         expect(secondOffer.tokenOutMint.toString()).toBe(token2Out.toString());
     });
 
-    test("Make an offer should initialize vault token_in account", async () => {
+    test("Make an offer should serve it from its own vault", async () => {
         // when
         await program.makeOffer({
             tokenInMint,
@@ -79,11 +79,75 @@ describe("Make offer", () => {
         });
 
         // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.ownVault).toBe(1);
         await expect(testHelper.getAccount(
             getAssociatedTokenAddressSync(tokenInMint, program.pdas.offerVaultAuthorityPda, true))
+        ).rejects.toThrow("Token account not found");
+    });
+
+    test("Make an offer should initialize vault token_in account", async () => {
+        // when
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        // then
+        await expect(testHelper.getAccount(
+            getAssociatedTokenAddressSync(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true))
         ).resolves.toBeDefined();
     });
 
+    test("Make an offer should initialize vault token_out account", async () => {
+        // when
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        // then
+        const vaultTokenOutAccount = await testHelper.getAccount(
+            getAssociatedTokenAddressSync(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true)
+        );
+        expect(vaultTokenOutAccount.amount).toBe(BigInt(0));
+    });
+
+    test("Make an offer should reuse an existing vault token_out account", async () => {
+        // given
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(1_000e9), true);
+
+        // when
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        // then
+        const vaultTokenOutAccount = await testHelper.getAccount(
+            getAssociatedTokenAddressSync(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true)
+        );
+        expect(vaultTokenOutAccount.amount).toBe(BigInt(1_000e9));
+    });
+
+    test("Make an offer with mismatched token_out program should fail", async () => {
+        const token2022Mint = testHelper.createMint2022(9);
+
+        await expect(program.makeOffer({
+            tokenInMint,
+            tokenOutMint: token2022Mint
+        })).rejects.toThrow();
+    });
+
+    test("Make an offer with mismatched token_in program should fail", async () => {
+        const token2022Mint = testHelper.createMint2022(9);
+
+        await expect(program.makeOffer({
+            tokenInMint: token2022Mint,
+            tokenOutMint
+        })).rejects.toThrow();
+    });
+
     test("Make an offer with invalid token mints should fail", async () => {
         // when
         await expect(program.makeOffer({
@@ -140,7 +204,8 @@ describe("Make offer", () => {
         // when
         await program.makeOffer({
             tokenInMint,
-            tokenOutMint: token2022Mint
+            tokenOutMint: token2022Mint,
+            tokenOutProgram: TOKEN_2022_PROGRAM_ID
         });
 
         // then
@@ -150,6 +215,69 @@ describe("Make offer", () => {
         expect(offer.tokenOutMint.toString()).toBe(token2022Mint.toString());
     });
 
+    test("Should accept Token2022 mint with transfer fee extension", async () => {
+        const transferFeeMint = await testHelper.createMint2022WithTransferFee(9, 100, BigInt(1e9));
+
+        await program.makeOffer({
+            tokenInMint: transferFeeMint,
+            tokenOutMint,
+            tokenInProgram: TOKEN_2022_PROGRAM_ID
+        });
+
+        const offer = await program.getOffer(transferFeeMint, tokenOutMint);
+        expect(offer.tokenInMint.toString()).toBe(transferFeeMint.toString());
+    });
+
+    test("Should reject Token2022 mint with permanent delegate", async () => {
+        const delegateMint = await testHelper.createMint2022WithExtension(9, "permanentDelegate");
+
+        await expect(program.makeOffer({
+            tokenInMint: delegateMint,
+            tokenOutMint,
+            tokenInProgram: TOKEN_2022_PROGRAM_ID
+        })).rejects.toThrow("Token-2022 mints with a permanent delegate are not supported");
+    });
+
+    test("Should reject Token2022 mint with confidential transfers", async () => {
+        const confidentialMint = await testHelper.createMint2022WithExtension(9, "confidentialTransfer");
+
+        await expect(program.makeOffer({
+            tokenInMint,
+            tokenOutMint: confidentialMint,
+            tokenOutProgram: TOKEN_2022_PROGRAM_ID
+        })).rejects.toThrow("Token-2022 mints with confidential transfers are not supported");
+    });
+
+    test("Should reject Token2022 mint with a non-allowlisted extension", async () => {
+        const closableMint = await testHelper.createMint2022WithExtension(9, "mintCloseAuthority");
+
+        await expect(program.makeOffer({
+            tokenInMint: closableMint,
+            tokenOutMint,
+            tokenInProgram: TOKEN_2022_PROGRAM_ID
+        })).rejects.toThrow("Token-2022 mint extension not supported");
+    });
+
+    test("Should record the mint decimals on the offer", async () => {
+        const sixDecimalsMint = testHelper.createMint(6);
+
+        await program.makeOffer({ tokenInMint: sixDecimalsMint, tokenOutMint });
+
+        const offer = await program.getOffer(sixDecimalsMint, tokenOutMint);
+        expect(offer.tokenInDecimals).toBe(6);
+        expect(offer.tokenOutDecimals).toBe(9);
+        expect(offer.decimalsRecorded).toBe(1);
+    });
+
+    test("Should reject mints with more than 18 decimals", async () => {
+        const wideMint = testHelper.createMint(19, null, BigInt(0));
+
+        await expect(program.makeOffer({
+            tokenInMint: wideMint,
+            tokenOutMint
+        })).rejects.toThrow("Token decimals exceed maximum allowed (18)");
+    });
+
     test("Should create offer with permissionless enabled", async () => {
         // Create unique token pair for this test
         const tokenIn = testHelper.createMint(9);
@@ -183,4 +311,55 @@ describe("Make offer", () => {
         const offer = await program.getOffer(tokenIn, tokenOut);
         expect(offer.allowPermissionless).toBe(0);
     });
-});
\ No newline at end of file
+
+    describe("Fulfillment mode", () => {
+        test("Offer made without a mode should leave fulfillment to the mint authority", async () => {
+            await program.makeOffer({ tokenInMint, tokenOutMint });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.fulfillmentMode).toBe(0);
+        });
+
+        test("Offer made in Transfer mode should store the mode", async () => {
+            await program.makeOffer({ tokenInMint, tokenOutMint, fulfillmentMode: "transfer" });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.fulfillmentMode).toBe(1);
+        });
+
+        test("Offer made in Mint mode should store the mode when the program holds the mint authority", async () => {
+            await program.transferMintAuthorityToProgram({ mint: tokenOutMint });
+
+            await program.makeOffer({ tokenInMint, tokenOutMint, fulfillmentMode: "mint" });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.fulfillmentMode).toBe(2);
+        });
+
+        test("Offer made in Mint mode should fail when the program lacks the mint authority", async () => {
+            await expect(
+                program.makeOffer({ tokenInMint, tokenOutMint, fulfillmentMode: "mint" })
+            ).rejects.toThrow("Fulfillment mode Mint requires the program to hold the token_out mint authority");
+        });
+    });
+
+    describe("Validity window", () => {
+        test("Offer made with a window should store it", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.makeOffer({ tokenInMint, tokenOutMint, validFrom: currentTime + 100, validUntil: currentTime + 200 });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.validFrom.toNumber()).toBe(currentTime + 100);
+            expect(offer.validUntil.toNumber()).toBe(currentTime + 200);
+        });
+
+        test("Offer made with a window ending before it starts should fail", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await expect(
+                program.makeOffer({ tokenInMint, tokenOutMint, validFrom: currentTime + 200, validUntil: currentTime + 100 })
+            ).rejects.toThrow("Invalid validity window: valid_until must be after valid_from");
+        });
+    });
+});
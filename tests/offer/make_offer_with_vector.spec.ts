@@ -0,0 +1,165 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Make offer with vector", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let vaultTokenOutAccount: PublicKey;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        vaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), true);
+
+        currentTime = await testHelper.getCurrentClockTime();
+    });
+
+    test("Should create a priced offer without a deposit", async () => {
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            feeBasisPoints: 100,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500,
+            priceFixDuration: 86400
+        });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.feeBasisPoints).toBe(100);
+        expect(offer.offerVersion).toBe(1);
+        expect(offer.vectors[0].startTime.toNumber()).toBe(currentTime);
+        expect(offer.vectors[0].basePrice.toNumber()).toBe(1e9);
+        expect(offer.vectors[0].apr.toNumber()).toBe(36_500);
+
+        expect(await program.getNAV({ tokenInMint, tokenOutMint })).toBe(1e9);
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenOutAccount, BigInt(0));
+    });
+
+    test("Should fund the offer vault with the initial deposit", async () => {
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9
+        });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenOutAccount, BigInt(1_000e9));
+        await testHelper.expectTokenAccountAmountToBe(
+            getAssociatedTokenAddressSync(tokenOutMint, testHelper.getBoss()),
+            BigInt(9_000e9)
+        );
+    });
+
+    test("Should allow taking the offer right after creation", async () => {
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9
+        });
+
+        const user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(100e6), true);
+
+        await program.takeOffer({
+            tokenInAmount: 100e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await testHelper.expectTokenAccountAmountToBe(
+            getAssociatedTokenAddressSync(tokenOutMint, user.publicKey),
+            BigInt(100e9)
+        );
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenOutAccount, BigInt(900e9));
+    });
+
+    test("Should not create the offer when the vector is invalid", async () => {
+        await expect(program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 0,
+            apr: 0,
+            priceFixDuration: 86400
+        })).rejects.toThrow("Invalid input: values cannot be zero");
+
+        expect(testHelper.svm.getAccount(program.getOfferPda(tokenInMint, tokenOutMint))).toBeNull();
+    });
+
+    test("Should not create the offer when the fee is invalid", async () => {
+        await expect(program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            feeBasisPoints: 10_001,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        })).rejects.toThrow("Invalid fee: fee_basis_points exceeds the configured maximum");
+    });
+
+    test("Should not create the offer when the deposit fails", async () => {
+        await expect(program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 20_000e9
+        })).rejects.toThrow();
+
+        expect(testHelper.svm.getAccount(program.getOfferPda(tokenInMint, tokenOutMint))).toBeNull();
+    });
+
+    test("Should require the boss token_out account for a deposit", async () => {
+        await expect(program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9,
+            bossTokenOutAccount: null
+        })).rejects.toThrow("Boss token_out account required for the initial deposit");
+    });
+
+    test("Should reject non-boss callers", async () => {
+        const notBoss: Keypair = testHelper.createUserAccount();
+
+        await expect(program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            signer: notBoss
+        })).rejects.toThrow();
+    });
+});
@@ -0,0 +1,137 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const LEGACY_MAX_VECTORS = 10;
+const VECTOR_SIZE = 40;
+const TAIL_SIZE = 136; // fields following the vector array in the legacy layout
+
+describe("Migrate Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+    });
+
+    function createLegacyOffer(params: { feeBasisPoints: number, vectorStartTime: number }) {
+        const [offerPda, bump] = PublicKey.findProgramAddressSync(
+            [Buffer.from("offer"), tokenInMint.toBuffer(), tokenOutMint.toBuffer()],
+            program.program.programId
+        );
+        const discriminator = program.program.idl.accounts.find(a => a.name === "offer")!.discriminator;
+
+        const data = Buffer.alloc(8 + 64 + LEGACY_MAX_VECTORS * VECTOR_SIZE + TAIL_SIZE);
+        Buffer.from(discriminator).copy(data, 0);
+        tokenInMint.toBuffer().copy(data, 8);
+        tokenOutMint.toBuffer().copy(data, 40);
+
+        // First vector slot
+        data.writeBigUInt64LE(BigInt(params.vectorStartTime), 72); // start_time
+        data.writeBigUInt64LE(BigInt(params.vectorStartTime), 80); // base_time
+        data.writeBigUInt64LE(BigInt(1e9), 88); // base_price
+        data.writeBigUInt64LE(BigInt(0), 96); // apr
+        data.writeBigUInt64LE(BigInt(86400), 104); // price_fix_duration
+
+        // Tail fields
+        const tailOffset = 72 + LEGACY_MAX_VECTORS * VECTOR_SIZE;
+        data.writeUInt16LE(params.feeBasisPoints, tailOffset);
+        data.writeUInt8(bump, tailOffset + 2);
+        data.writeUInt8(1, tailOffset + 3); // needs_approval
+        data.writeUInt8(1, tailOffset + 4); // allow_permissionless
+
+        testHelper.setAccount(offerPda, {
+            executable: false,
+            data,
+            lamports: 10_000_000,
+            owner: program.program.programId
+        });
+
+        return offerPda;
+    }
+
+    it("Should migrate a legacy offer preserving vectors and configuration", async () => {
+        const currentTime = await testHelper.getCurrentClockTime();
+        const offerPda = createLegacyOffer({ feeBasisPoints: 25, vectorStartTime: currentTime });
+
+        await program.migrateOffer({ tokenInMint, tokenOutMint });
+
+        const accountInfo = await testHelper.getAccountInfo(offerPda);
+        expect(accountInfo.data.length).toBe(program.program.account.offer.size);
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.vectors.length).toBe(64);
+        expect(offer.vectors[0].startTime.toNumber()).toBe(currentTime);
+        expect(offer.vectors[0].basePrice.toNumber()).toBe(1e9);
+        expect(offer.vectors.slice(1).every(v => v.startTime.toNumber() === 0)).toBe(true);
+        expect(offer.feeBasisPoints).toBe(25);
+        expect(offer.maxStepVolume.toNumber()).toBe(0);
+        expect(offer.offerVersion).toBe(0);
+    });
+
+    it("Should allow adding vectors beyond the legacy capacity after migration", async () => {
+        const currentTime = await testHelper.getCurrentClockTime();
+        createLegacyOffer({ feeBasisPoints: 0, vectorStartTime: currentTime });
+
+        await program.migrateOffer({ tokenInMint, tokenOutMint });
+
+        for (let i = 1; i <= LEGACY_MAX_VECTORS; i++) {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + i * 1000,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+        }
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        const activeVectors = offer.vectors.filter(v => v.startTime.toNumber() !== 0);
+        expect(activeVectors.length).toBe(LEGACY_MAX_VECTORS + 1);
+    });
+
+    it("Should reject migrating an offer with neither the legacy nor the current layout", async () => {
+        // given
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 25 });
+        const offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+        const current = await testHelper.getAccountInfo(offerPda);
+        testHelper.setAccount(offerPda, {
+            executable: false,
+            data: Buffer.from(current.data).subarray(0, current.data.length - 16),
+            lamports: current.lamports,
+            owner: program.program.programId
+        });
+
+        // when / then
+        await expect(program.migrateOffer({ tokenInMint, tokenOutMint }))
+            .rejects.toThrow("Unexpected offer account size");
+    });
+
+    it("Should reject migrating an offer that already has the current layout", async () => {
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        await expect(
+            program.migrateOffer({ tokenInMint, tokenOutMint })
+        ).rejects.toThrow("Offer is already migrated");
+    });
+
+    it("Should reject migration from non-boss", async () => {
+        const currentTime = await testHelper.getCurrentClockTime();
+        createLegacyOffer({ feeBasisPoints: 0, vectorStartTime: currentTime });
+        const notBoss: Keypair = testHelper.createUserAccount();
+
+        await expect(
+            program.migrateOffer({ tokenInMint, tokenOutMint, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,127 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Record NAV Observation", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let offerPda: PublicKey;
+    let keeper: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        keeper = testHelper.createUserAccount();
+    });
+
+    it("Should create the NAV history and record the current NAV", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+
+        // when
+        await program.recordNavObservation({ offer: offerPda, signer: keeper });
+
+        // then
+        const history = await program.getNavHistory(offerPda);
+        expect(history.offer).toEqual(offerPda);
+        expect(history.count).toBe(1);
+        expect(history.nextIndex).toBe(1);
+        expect(history.observations[0].timestamp.toNumber()).toBe(currentTime);
+        expect(history.observations[0].nav.toNumber()).toBe(1e9);
+    });
+
+    it("Should reject an observation within the minimum interval", async () => {
+        // given
+        await program.recordNavObservation({ offer: offerPda });
+        await testHelper.advanceClockBy(59);
+
+        // when / then
+        await expect(
+            program.recordNavObservation({ offer: offerPda })
+        ).rejects.toThrow("NAV observation too soon after the previous one");
+    });
+
+    it("Should overwrite the oldest observation once the buffer is full", async () => {
+        // given
+        const firstTime = await testHelper.getCurrentClockTime();
+        for (let i = 0; i < 33; i++) {
+            await program.recordNavObservation({ offer: offerPda });
+            await testHelper.advanceClockBy(60);
+        }
+
+        // then
+        const history = await program.getNavHistory(offerPda);
+        expect(history.count).toBe(32);
+        expect(history.nextIndex).toBe(1);
+        expect(history.observations[0].timestamp.toNumber()).toBe(firstTime + 32 * 60);
+        expect(history.observations[1].timestamp.toNumber()).toBe(firstTime + 60);
+    });
+
+    it("Should record observations lazily on takes passing the history", async () => {
+        // given
+        await program.recordNavObservation({ offer: offerPda });
+        await testHelper.advanceClockBy(60);
+
+        const user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(1_000e6));
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        // when
+        await program.takeOffer({
+            tokenInAmount: 100e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            navHistory: program.getNavHistoryPda(offerPda)
+        });
+
+        // a second take within the interval is accepted without a new observation
+        await program.takeOffer({
+            tokenInAmount: 50e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            navHistory: program.getNavHistoryPda(offerPda)
+        });
+
+        // then
+        const history = await program.getNavHistory(offerPda);
+        expect(history.count).toBe(2);
+    });
+
+    it("Should reject an offer without an active vector", async () => {
+        const otherMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: otherMint, tokenOutMint });
+
+        await expect(
+            program.recordNavObservation({ offer: program.getOfferPda(otherMint, tokenOutMint) })
+        ).rejects.toThrow("No active vector");
+    });
+});
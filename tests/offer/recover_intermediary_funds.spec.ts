@@ -0,0 +1,130 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Recover Intermediary Funds", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let mint: PublicKey;
+    let authority: PublicKey;
+    let intermediaryAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        mint = testHelper.createMint(6);
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+        await program.initializePermissionlessAuthority({ accountName: "partner-2", index: 2 });
+
+        authority = program.getPermissionlessAuthorityPda(2);
+        intermediaryAccount = testHelper.createTokenAccount(mint, authority, BigInt(1_000_000), true);
+    });
+
+    test("Boss can recover stranded tokens to the original user", async () => {
+        // given
+        const user = testHelper.createUserAccount();
+        const userTokenAccount = testHelper.createTokenAccount(mint, user.publicKey, BigInt(0));
+
+        // when
+        await program.recoverIntermediaryFunds({
+            authorityIndex: 2,
+            mint,
+            amount: 600_000,
+            user: user.publicKey,
+            destinationTokenAccount: userTokenAccount
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(userTokenAccount, BigInt(600_000));
+        await testHelper.expectTokenAccountAmountToBe(intermediaryAccount, BigInt(400_000));
+    });
+
+    test("Admin can recover stranded tokens to the boss", async () => {
+        // given
+        const admin = testHelper.createUserAccount();
+        await program.addAdmin({ admin: admin.publicKey });
+        const bossTokenAccount = testHelper.createTokenAccount(mint, testHelper.getBoss(), BigInt(0));
+
+        // when
+        await program.recoverIntermediaryFunds({
+            authorityIndex: 2,
+            mint,
+            amount: 1_000_000,
+            user: testHelper.createUserAccount().publicKey,
+            destinationTokenAccount: bossTokenAccount,
+            signer: admin
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(bossTokenAccount, BigInt(1_000_000));
+        await testHelper.expectTokenAccountAmountToBe(intermediaryAccount, BigInt(0));
+    });
+
+    test("Admin cannot recover stranded tokens to a user", async () => {
+        // given
+        const admin = testHelper.createUserAccount();
+        await program.addAdmin({ admin: admin.publicKey });
+        const user = testHelper.createUserAccount();
+        const userTokenAccount = testHelper.createTokenAccount(mint, user.publicKey, BigInt(0));
+
+        // when/then
+        await expect(
+            program.recoverIntermediaryFunds({
+                authorityIndex: 2,
+                mint,
+                amount: 1_000_000,
+                user: user.publicKey,
+                destinationTokenAccount: userTokenAccount,
+                signer: admin
+            })
+        ).rejects.toThrow("Destination must be owned by the boss");
+    });
+
+    test("Rejects a destination owned by neither the boss nor the user", async () => {
+        // given
+        const user = testHelper.createUserAccount();
+        const otherTokenAccount = testHelper.createTokenAccount(mint, testHelper.createUserAccount().publicKey, BigInt(0));
+
+        // when/then
+        await expect(
+            program.recoverIntermediaryFunds({
+                authorityIndex: 2,
+                mint,
+                amount: 1_000_000,
+                user: user.publicKey,
+                destinationTokenAccount: otherTokenAccount
+            })
+        ).rejects.toThrow("Destination must be owned by the boss");
+    });
+
+    test("Rejects a zero amount", async () => {
+        const bossTokenAccount = testHelper.createTokenAccount(mint, testHelper.getBoss(), BigInt(0));
+        await expect(
+            program.recoverIntermediaryFunds({
+                authorityIndex: 2,
+                mint,
+                amount: 0,
+                user: testHelper.getBoss(),
+                destinationTokenAccount: bossTokenAccount
+            })
+        ).rejects.toThrow("Invalid amount");
+    });
+
+    test("Non-admin cannot recover stranded tokens", async () => {
+        const nonAdmin = testHelper.createUserAccount();
+        const nonAdminTokenAccount = testHelper.createTokenAccount(mint, nonAdmin.publicKey, BigInt(0));
+        await expect(
+            program.recoverIntermediaryFunds({
+                authorityIndex: 2,
+                mint,
+                amount: 1_000_000,
+                user: nonAdmin.publicKey,
+                destinationTokenAccount: nonAdminTokenAccount,
+                signer: nonAdmin
+            })
+        ).rejects.toThrow("Unauthorized");
+    });
+});
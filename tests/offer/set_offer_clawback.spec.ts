@@ -0,0 +1,46 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Offer Clawback", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+    });
+
+    it("Should flag and unflag an offer for clawbacks", async () => {
+        // when
+        await program.setOfferClawback({ tokenInMint, tokenOutMint, clawbackEnabled: true });
+
+        // then
+        let offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.clawbackEnabled).toBe(1);
+
+        // when
+        await program.setOfferClawback({ tokenInMint, tokenOutMint, clawbackEnabled: false });
+
+        // then
+        offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.clawbackEnabled).toBe(0);
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setOfferClawback({ tokenInMint, tokenOutMint, clawbackEnabled: true, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
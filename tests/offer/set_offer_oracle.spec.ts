@@ -0,0 +1,212 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const PYTH_RECEIVER_PROGRAM_ID = new PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+const PRICE_UPDATE_V2_DISCRIMINATOR = [34, 241, 35, 99, 157, 126, 244, 205];
+const EUR_USD_FEED_ID = Array.from({ length: 32 }, (_, i) => i + 1);
+
+describe("Set Offer Oracle", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6); // EURC-like (6 decimals)
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({
+            amount: 10_000e9,
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
+        });
+
+        // Flat NAV of $1.0
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    function createPriceUpdate(params: { price: number, conf: number, exponent: number, publishTime: number, fullyVerified?: boolean, feedId?: number[] }) {
+        const data = Buffer.alloc(133);
+        Buffer.from(PRICE_UPDATE_V2_DISCRIMINATOR).copy(data, 0);
+        data.writeUInt8(params.fullyVerified === false ? 0 : 1, 40); // verification level
+        Buffer.from(params.feedId ?? EUR_USD_FEED_ID).copy(data, 41);
+        let offset = 41 + 32; // skip feed id
+        data.writeBigInt64LE(BigInt(params.price), offset);
+        data.writeBigUInt64LE(BigInt(params.conf), offset + 8);
+        data.writeInt32LE(params.exponent, offset + 16);
+        data.writeBigInt64LE(BigInt(params.publishTime), offset + 20);
+
+        const oracle = PublicKey.unique();
+        testHelper.setAccount(oracle, {
+            executable: false,
+            data,
+            lamports: 10_000_000,
+            owner: PYTH_RECEIVER_PROGRAM_ID
+        });
+
+        return oracle;
+    }
+
+    describe("Configuration", () => {
+        it("Should allow boss to set and clear the oracle", async () => {
+            const oracle = PublicKey.unique();
+
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            let offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.oracle).toEqual(oracle);
+            expect(offer.oracleMaxStaleness.toNumber()).toBe(60);
+            expect(offer.oracleMaxConfidenceBps).toBe(100);
+            expect(offer.oracleFeedId).toEqual(EUR_USD_FEED_ID);
+
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle: PublicKey.default, maxStaleness: 0, maxConfidenceBps: 0 });
+
+            offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.oracle).toEqual(PublicKey.default);
+            expect(offer.oracleFeedId).toEqual(Array(32).fill(0));
+        });
+
+        it("Should reject an oracle without feed id", async () => {
+            await expect(
+                program.setOfferOracle({ tokenInMint, tokenOutMint, oracle: PublicKey.unique(), maxStaleness: 60, maxConfidenceBps: 100 })
+            ).rejects.toThrow("Invalid feed id: must be set with an oracle");
+        });
+
+        it("Should reject an oracle without staleness limit", async () => {
+            await expect(
+                program.setOfferOracle({ tokenInMint, tokenOutMint, oracle: PublicKey.unique(), feedId: EUR_USD_FEED_ID, maxStaleness: 0, maxConfidenceBps: 100 })
+            ).rejects.toThrow("Invalid max staleness");
+        });
+
+        it("Should reject update from non-boss", async () => {
+            await expect(
+                program.setOfferOracle({
+                    tokenInMint,
+                    tokenOutMint,
+                    oracle: PublicKey.unique(),
+                    feedId: EUR_USD_FEED_ID,
+                    maxStaleness: 60,
+                    maxConfidenceBps: 100,
+                    signer: testHelper.createUserAccount()
+                })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Pricing", () => {
+        it("Should convert token_in to USD before applying the NAV", async () => {
+            // EUR/USD = 1.25, so 1 EURC buys 1.25 ONyc at a NAV of $1.0
+            const currentTime = await testHelper.getCurrentClockTime();
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await program.takeOffer({
+                tokenInAmount: 1e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                tokenInOracle: oracle
+            });
+
+            const balance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(balance).toBe(BigInt(1.25e9));
+        });
+
+        it("Should reject a take without the oracle account", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user })
+            ).rejects.toThrow("Oracle account required for this offer");
+        });
+
+        it("Should reject a take with a different oracle account", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime });
+            const otherOracle = createPriceUpdate({ price: 200_000_000, conf: 10_000, exponent: -8, publishTime: currentTime });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user, tokenInOracle: otherOracle })
+            ).rejects.toThrow("Invalid oracle account");
+        });
+
+        it("Should reject a price update for another feed", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            const otherFeedId = Array(32).fill(7);
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime, feedId: otherFeedId });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user, tokenInOracle: oracle })
+            ).rejects.toThrow("Oracle price update is for another feed");
+        });
+
+        it("Should reject a stale oracle price", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await testHelper.advanceClockBy(61);
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user, tokenInOracle: oracle })
+            ).rejects.toThrow("Oracle price is stale");
+        });
+
+        it("Should reject an oracle price with a wide confidence interval", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            // 2% confidence against a 1% limit
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 2_500_000, exponent: -8, publishTime: currentTime });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user, tokenInOracle: oracle })
+            ).rejects.toThrow("Oracle price confidence interval too wide");
+        });
+
+        it("Should reject a partially verified price update", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            const oracle = createPriceUpdate({ price: 125_000_000, conf: 10_000, exponent: -8, publishTime: currentTime, fullyVerified: false });
+            await program.setOfferOracle({ tokenInMint, tokenOutMint, oracle, feedId: EUR_USD_FEED_ID, maxStaleness: 60, maxConfidenceBps: 100 });
+
+            await expect(
+                program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user, tokenInOracle: oracle })
+            ).rejects.toThrow("Oracle price is not fully verified");
+        });
+    });
+});
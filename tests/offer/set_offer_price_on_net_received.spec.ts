@@ -0,0 +1,151 @@
+import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import {
+    createAssociatedTokenAccountInstruction,
+    createMintToInstruction,
+    getAssociatedTokenAddressSync,
+    TOKEN_2022_PROGRAM_ID
+} from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Offer Price On Net Received", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenInAccount: PublicKey;
+    let vaultTokenInAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        // token_in withholds a 5% transfer fee
+        tokenInMint = await testHelper.createMint2022WithTransferFee(9, 500, BigInt(5_000_000_000));
+        tokenOutMint = testHelper.createMint(6);
+
+        await program.initialize({ onycMint: tokenOutMint });
+
+        user = testHelper.createUserAccount();
+        vaultTokenInAccount = await testHelper.createToken2022Account(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint));
+        userTokenInAccount = getAssociatedTokenAddressSync(tokenInMint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+        await testHelper.sendAndConfirmTransaction(
+            new Transaction().add(
+                createAssociatedTokenAccountInstruction(
+                    testHelper.payer.publicKey,
+                    userTokenInAccount,
+                    user.publicKey,
+                    tokenInMint,
+                    TOKEN_2022_PROGRAM_ID
+                ),
+                createMintToInstruction(
+                    tokenInMint,
+                    userTokenInAccount,
+                    testHelper.getBoss(),
+                    BigInt(10_000e9),
+                    [],
+                    TOKEN_2022_PROGRAM_ID
+                )
+            ),
+            [testHelper.payer]
+        );
+
+        await program.makeOffer({ tokenInMint, tokenOutMint, tokenInProgram: TOKEN_2022_PROGRAM_ID });
+
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e6));
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e6, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should enable and disable pricing on the net received amount", async () => {
+        // when
+        await program.setOfferPriceOnNetReceived({ tokenInMint, tokenOutMint, priceOnNetReceived: true });
+
+        // then
+        let view = await program.viewOffer({ tokenInMint, tokenOutMint });
+        expect(view.priceOnNetReceived).toBe(true);
+
+        // when
+        await program.setOfferPriceOnNetReceived({ tokenInMint, tokenOutMint, priceOnNetReceived: false });
+
+        // then
+        view = await program.viewOffer({ tokenInMint, tokenOutMint });
+        expect(view.priceOnNetReceived).toBe(false);
+    });
+
+    it("Should price a take on the token_in received after the transfer fee", async () => {
+        // given
+        await program.setOfferPriceOnNetReceived({ tokenInMint, tokenOutMint, priceOnNetReceived: true });
+
+        // when
+        await program.takeOffer({
+            tokenInAmount: 100e9,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            tokenInProgram: TOKEN_2022_PROGRAM_ID
+        });
+
+        // then the vault receives 95 token_in and the user is paid for exactly that
+        await testHelper.expectTokenAccountAmountToBe(userTokenInAccount, BigInt(9_900e9));
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenInAccount, BigInt(95e9));
+        const userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(95e6));
+    });
+
+    it("Should keep rejecting transfer-fee token_in when disabled", async () => {
+        await expect(
+            program.takeOffer({
+                tokenInAmount: 100e9,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                tokenInProgram: TOKEN_2022_PROGRAM_ID
+            })
+        ).rejects.toThrow("Token-2022 with transfer fees not supported");
+    });
+
+    it("Should reject transfer-fee token_in in the burn path", async () => {
+        // given
+        await program.setOfferPriceOnNetReceived({ tokenInMint, tokenOutMint, priceOnNetReceived: true });
+        await program.transferMintAuthorityToProgram({ mint: tokenInMint, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+        // when/then
+        await expect(
+            program.takeOffer({
+                tokenInAmount: 100e9,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                tokenInProgram: TOKEN_2022_PROGRAM_ID
+            })
+        ).rejects.toThrow("Token-2022 with transfer fees not supported");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+        await expect(
+            program.setOfferPriceOnNetReceived({
+                tokenInMint,
+                tokenOutMint,
+                priceOnNetReceived: true,
+                signer: notBoss
+            })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,106 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Offer Strict Accounts", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(100e6), true);
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+    });
+
+    async function take() {
+        await program.takeOffer({
+            tokenInAmount: 10e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+    }
+
+    it("Should toggle strict accounts", async () => {
+        await program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: true });
+        expect((await program.viewOffer({ tokenInMint, tokenOutMint })).strictAccounts).toBe(true);
+
+        await testHelper.advanceSlot();
+        await program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: false });
+        expect((await program.viewOffer({ tokenInMint, tokenOutMint })).strictAccounts).toBe(false);
+    });
+
+    it("Should keep creating accounts in takes of non-strict offers", async () => {
+        await take();
+
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(10e9));
+        expect((await program.getUserPosition(tokenOutMint, user.publicKey)).user).toEqual(user.publicKey);
+    });
+
+    it("Should reject takes of strict offers missing the token_out account", async () => {
+        await program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: true });
+
+        await expect(take()).rejects.toThrow("Account must exist before taking the offer");
+        expect(await testHelper.getAccountInfo(userTokenOutAccount)).toBeNull();
+    });
+
+    it("Should reject takes of strict offers missing the user position", async () => {
+        await program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: true });
+        testHelper.createTokenAccount(tokenOutMint, user.publicKey, BigInt(0));
+
+        await expect(take()).rejects.toThrow("Account must exist before taking the offer");
+    });
+
+    it("Should allow takes of strict offers after preparing the accounts", async () => {
+        await program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: true });
+
+        await program.prepareAccounts({ tokenInMint, tokenOutMint, user });
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(0));
+
+        await take();
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(10e9));
+    });
+
+    it("Should leave existing accounts untouched when preparing again", async () => {
+        await take();
+        await program.prepareAccounts({ tokenInMint, tokenOutMint, user });
+
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(10e9));
+        expect((await program.getUserPosition(tokenOutMint, user.publicKey)).totalPurchased.toNumber()).toBe(10e9);
+    });
+
+    it("Should reject non-boss callers", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(program.setOfferStrictAccounts({ tokenInMint, tokenOutMint, strictAccounts: true, signer: notBoss }))
+            .rejects.toThrow();
+    });
+});
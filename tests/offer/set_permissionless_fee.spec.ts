@@ -0,0 +1,140 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Permissionless Fee", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let bossTokenInAccount: PublicKey;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 100, allowPermissionless: true });
+        await program.initializePermissionlessAuthority({ accountName: "partner-1" });
+        await program.initializePermissionlessAuthority({ accountName: "partner-2", index: 2 });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        bossTokenInAccount = testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        for (const index of [1, 2]) {
+            const authority = program.getPermissionlessAuthorityPda(index);
+            testHelper.createTokenAccount(tokenInMint, authority, BigInt(0), true);
+            testHelper.createTokenAccount(tokenOutMint, authority, BigInt(0), true);
+        }
+
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        await program.offerVaultDeposit({ amount: 5_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    test("Authorities have no fee override by default", async () => {
+        const authority = await program.getPermissionlessAuthority(2);
+        expect(authority.feeOverrideBps).toBeNull();
+    });
+
+    test("Boss can set and clear the fee override", async () => {
+        // when
+        await program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 50 });
+
+        // then
+        expect((await program.getPermissionlessAuthority(2)).feeOverrideBps).toBe(50);
+
+        // when
+        await program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: null });
+
+        // then
+        expect((await program.getPermissionlessAuthority(2)).feeOverrideBps).toBeNull();
+    });
+
+    test("Takes through an authority with an override charge the override instead of the offer fee", async () => {
+        // given
+        await program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 50 });
+
+        // when
+        await program.takeOfferPermissionless({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            authorityIndex: 2
+        });
+
+        // then: 0.5% fee, so 995 token_out at a price of 1.0
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(995e9));
+        expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(1_000e6));
+    });
+
+    test("Takes through other authorities keep the offer fee", async () => {
+        // given
+        await program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 50 });
+
+        // when
+        await program.takeOfferPermissionless({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            authorityIndex: 1
+        });
+
+        // then: 1% offer fee, so 990 token_out at a price of 1.0
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(990e9));
+    });
+
+    test("A zero override waives the fee", async () => {
+        // given
+        await program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 0 });
+
+        // when
+        await program.takeOfferPermissionless({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            authorityIndex: 2
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(1_000e9));
+    });
+
+    test("Rejects an override above the configured fee cap", async () => {
+        await expect(
+            program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 1001 })
+        ).rejects.toThrow("Invalid fee: fee_override_bps exceeds the configured maximum");
+    });
+
+    test("Non-boss cannot set the fee override", async () => {
+        const nonBoss = testHelper.createUserAccount();
+        await expect(
+            program.setPermissionlessFee({ authorityIndex: 2, feeOverrideBps: 50, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,169 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Take Dual Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint1: PublicKey;
+    let tokenOutMint2: PublicKey;
+
+    let user: Keypair;
+    let userTokenOut1Account: PublicKey;
+    let userTokenOut2Account: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6); // USDC-like
+        tokenOutMint1 = testHelper.createMint(9); // ONyc-like principal
+        tokenOutMint2 = testHelper.createMint(6); // reward token
+
+        await program.initialize({ onycMint: tokenOutMint1 });
+        await program.makeOffer({ tokenInMint, tokenOutMint: tokenOutMint1, feeBasisPoints: 100 });
+
+        // 0.1 reward token per principal token
+        await program.makeDualOffer({ tokenInMint, tokenOutMint1, tokenOutMint2, tokenOut2Ratio: 1e8 });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint1, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenOutMint2, testHelper.getBoss(), BigInt(10_000e6));
+        userTokenOut1Account = getAssociatedTokenAddressSync(tokenOutMint1, user.publicKey);
+        userTokenOut2Account = getAssociatedTokenAddressSync(tokenOutMint2, user.publicKey);
+
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint1, offer: program.getOfferPda(tokenInMint, tokenOutMint1) });
+        await program.offerVaultDeposit({ amount: 10_000e6, tokenMint: tokenOutMint2, offer: program.getOfferPda(tokenInMint, tokenOutMint1) });
+
+        // Flat NAV of $1.0
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint: tokenOutMint1,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should pay out both legs from the vaults", async () => {
+        // when
+        await program.takeDualOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint1,
+            tokenOutMint2,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // then - 1% fee leaves 990 USDC, paying 990 principal and 99 reward tokens
+        expect(await testHelper.getTokenAccountBalance(userTokenOut1Account)).toBe(BigInt(990e9));
+        expect(await testHelper.getTokenAccountBalance(userTokenOut2Account)).toBe(BigInt(99e6));
+
+        const bossTokenInAccount = getAssociatedTokenAddressSync(tokenInMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(1_000e6));
+
+        const vaultTokenOut2Account = getAssociatedTokenAddressSync(tokenOutMint2, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint1), true);
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOut2Account)).toBe(BigInt(10_000e6 - 99e6));
+    });
+
+    it("Should mint the secondary leg when the program controls its mint", async () => {
+        // given
+        await program.transferMintAuthorityToProgram({ mint: tokenOutMint2 });
+        const vaultTokenOut2Account = getAssociatedTokenAddressSync(tokenOutMint2, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint1), true);
+
+        // when
+        await program.takeDualOffer({
+            tokenInAmount: 100e6,
+            tokenInMint,
+            tokenOutMint1,
+            tokenOutMint2,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userTokenOut2Account)).toBe(BigInt(9.9e6));
+        expect(await testHelper.getTokenAccountBalance(vaultTokenOut2Account)).toBe(BigInt(10_000e6));
+    });
+
+    it("Should price the principal leg with the base offer vectors", async () => {
+        // given - a new vector doubling the NAV
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint: tokenOutMint1,
+            baseTime: currentTime + 100,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await testHelper.advanceClockBy(100);
+
+        // when
+        await program.takeDualOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint1,
+            tokenOutMint2,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userTokenOut1Account)).toBe(BigInt(495e9));
+        expect(await testHelper.getTokenAccountBalance(userTokenOut2Account)).toBe(BigInt(49.5e6));
+    });
+
+    it("Should count the principal towards the base offer step volume", async () => {
+        await program.updateOfferMaxStepVolume({ tokenInMint, tokenOutMint: tokenOutMint1, maxStepVolume: 500e9 });
+
+        await expect(
+            program.takeDualOffer({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint1,
+                tokenOutMint2,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("Step volume cap exceeded");
+    });
+
+    it("Should reject when the kill switch is activated", async () => {
+        await program.setKillSwitch({ enable: true });
+
+        await expect(
+            program.takeDualOffer({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint1,
+                tokenOutMint2,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("Kill switch is activated");
+    });
+
+    it("Should reject when the secondary vault is underfunded", async () => {
+        await program.offerVaultWithdraw({ amount: 10_000e6, tokenMint: tokenOutMint2 });
+
+        await expect(
+            program.takeDualOffer({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint1,
+                tokenOutMint2,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("insufficient funds");
+    });
+});
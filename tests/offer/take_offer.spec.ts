@@ -48,13 +48,14 @@ describe("Take Offer", () => {
         bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
 
         // Create and fund vault
-        vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-        vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+        vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
         // Fund vault
         await program.offerVaultDeposit({
             amount: 10_000e9,
-            tokenMint: tokenOutMint
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
         });
     });
 
@@ -104,14 +105,8 @@ describe("Take Offer", () => {
             const bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
 
             // Create and fund vault
-            const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-            const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-
-            // Fund vault
-            await program.offerVaultDeposit({
-                amount: 10_000e9,
-                tokenMint: tokenOutMint
-            });
+            const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+            const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
             // Create a new offer
             await program.makeOffer({
@@ -120,6 +115,13 @@ describe("Take Offer", () => {
                 feeBasisPoints: 100 // 1% fee
             });
 
+            // Fund vault
+            await program.offerVaultDeposit({
+                amount: 10_000e9,
+                tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint)
+            });
+
             await program.addOfferVector({
                 tokenInMint,
                 tokenOutMint,
@@ -168,13 +170,8 @@ describe("Take Offer", () => {
             testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
 
             // Create and fund vault
-            testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-            testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-
-            await program.offerVaultDeposit({
-                amount: 10_000e9,
-                tokenMint: tokenOutMint
-            });
+            testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+            testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
             // Create offer with 0.5% fee (50 basis points)
             await program.makeOffer({
@@ -183,6 +180,12 @@ describe("Take Offer", () => {
                 feeBasisPoints: 50 // 0.5% fee
             });
 
+            await program.offerVaultDeposit({
+                amount: 10_000e9,
+                tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint)
+            });
+
             await program.addOfferVector({
                 tokenInMint,
                 tokenOutMint,
@@ -252,13 +255,8 @@ describe("Take Offer", () => {
             testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(200_000_000_000_000)); // 200k tokens
 
             // Create and fund vault
-            testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-            testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-
-            await program.offerVaultDeposit({
-                amount: 200_000_000_000_000, // 200k tokens with 9 decimals
-                tokenMint: tokenOutMint
-            });
+            testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+            testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
             // Create offer with 0.5% fee (50 basis points)
             await program.makeOffer({
@@ -267,6 +265,12 @@ describe("Take Offer", () => {
                 feeBasisPoints: 50 // 0.5% fee
             });
 
+            await program.offerVaultDeposit({
+                amount: 200_000_000_000_000, // 200k tokens with 9 decimals
+                tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint)
+            });
+
             await program.addOfferVector({
                 tokenInMint,
                 tokenOutMint,
@@ -390,6 +394,39 @@ describe("Take Offer", () => {
             // Should receive 1 token out
             expect(userBalanceAfter).toBe(BigInt(1e9));
         });
+
+        it("Should calculate per-second price for linear pricing mode", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 36_500,
+                priceFixDuration: 86400,
+                pricingMode: "linear"
+            });
+
+            // Advance half a day, linear pricing does not snap to the interval end
+            await testHelper.advanceClockBy(43_200);
+
+            // Price: 1.0 * (1 + 0.0365 * 43200 / (365*24*3600)) = 1.00005
+            const expectedTokenInAmount = 1_000_050; // 1.00005 USDC
+
+            await program.takeOffer({
+                tokenInAmount: expectedTokenInAmount,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const userBalanceAfter = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+
+            // Should receive 1 token out
+            expect(userBalanceAfter).toBe(BigInt(1e9));
+        });
     });
 
     describe("Multiple Vectors Tests", () => {
@@ -582,7 +619,8 @@ describe("Take Offer", () => {
             await program.makeOffer({
                 tokenInMint,
                 tokenOutMint,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
 
@@ -594,13 +632,14 @@ describe("Take Offer", () => {
             const bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9), false, TOKEN_2022_PROGRAM_ID);
 
             // Create and fund vault
-            const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true, TOKEN_2022_PROGRAM_ID);
-            const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true, TOKEN_2022_PROGRAM_ID);
+            const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, TOKEN_2022_PROGRAM_ID);
+            const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, TOKEN_2022_PROGRAM_ID);
 
             // Fund vault
             await program.offerVaultDeposit({
                 amount: 10_000e9,
                 tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint),
                 tokenProgram: TOKEN_2022_PROGRAM_ID
             });
 
@@ -661,7 +700,8 @@ describe("Take Offer", () => {
             await program.makeOffer({
                 tokenInMint,
                 tokenOutMint,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             // Create token accounts
@@ -672,13 +712,14 @@ describe("Take Offer", () => {
             testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9), false, TOKEN_2022_PROGRAM_ID);
 
             // Create and fund vault
-            testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true, TOKEN_2022_PROGRAM_ID);
-            testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true, TOKEN_2022_PROGRAM_ID);
+            testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, TOKEN_2022_PROGRAM_ID);
+            testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true, TOKEN_2022_PROGRAM_ID);
 
             // Fund vault
             await program.offerVaultDeposit({
                 amount: 10_000e9,
                 tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint),
                 tokenProgram: TOKEN_2022_PROGRAM_ID
             });
 
@@ -724,7 +765,8 @@ describe("Take Offer", () => {
             // Create offer where program lacks mint authority for token_out
             await program.makeOffer({
                 tokenInMint,
-                tokenOutMint
+                tokenOutMint,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             // Create token accounts
@@ -732,13 +774,13 @@ describe("Take Offer", () => {
             testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
             testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
             // Note: boss token account for tokenOutMint is already created by createMint2022WithTransferFee
-            // Create vault token account using proper Token-2022 instructions
-            await testHelper.createToken2022Account(tokenOutMint, program.pdas.offerVaultAuthorityPda);
+            // and the vault token account is created by make_offer
 
             // Fund vault
             await program.offerVaultDeposit({
                 amount: 10_000e9,
                 tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint),
                 tokenProgram: TOKEN_2022_PROGRAM_ID
             });
 
@@ -781,7 +823,7 @@ describe("Take Offer", () => {
             const user = testHelper.createUserAccount();
 
             // Create vault Token-2022 account (PDA owner - creates regular account)
-            await testHelper.createToken2022Account(tokenInMint, program.pdas.offerVaultAuthorityPda);
+            await testHelper.createToken2022Account(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint));
 
             // Create user Token-2022 ATA manually (helper's PDA detection doesn't work for regular users)
             const userTokenInAccount = getAssociatedTokenAddressSync(
@@ -834,12 +876,13 @@ describe("Take Offer", () => {
 
             // Create boss and vault accounts for token_out
             testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e6));
-            testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
             // Fund vault with token_out
             await program.offerVaultDeposit({
                 amount: 10_000e6,
-                tokenMint: tokenOutMint
+                tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint)
             });
 
             await program.addOfferVector({
@@ -981,6 +1024,64 @@ describe("Take Offer", () => {
             // Should receive 1 token out
             expect(userBalanceAfter).toEqual(BigInt(1_000_000_000));
         });
+
+        it("Should handle negative APR (declining price) correctly", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: -365_000, // -36.5% yearly APR
+                priceFixDuration: 86400
+            });
+
+            // Advance 1 year (365 days)
+            await testHelper.advanceClockBy(86400 * 365);
+
+            // After 1 year with -36.5% APR using the (366 * D) / S formula: price = 1.0 * (1 - 0.366) = 0.634
+            const expectedTokenInAmount = 634_000;
+
+            await program.takeOffer({
+                tokenInAmount: expectedTokenInAmount,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const userBalanceAfter = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+
+            // Should receive 1 token out
+            expect(userBalanceAfter).toEqual(BigInt(1_000_000_000));
+        });
+
+        it("Should reject take when negative APR drives the price to zero", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: -1_000_000, // -100% yearly APR
+                priceFixDuration: 86400
+            });
+
+            // After 1 year the price would be negative and is floored at zero
+            await testHelper.advanceClockBy(86400 * 365);
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1_000_000,
+                    tokenInMint,
+                    tokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Price cannot be zero");
+        });
     });
 
     describe("Mint/Burn Integration Tests", () => {
@@ -1222,13 +1323,14 @@ describe("Take Offer", () => {
                 const bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
 
                 // Create and fund vault
-                const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-                const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+                const vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+                const vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
                 // Fund vault
                 await program.offerVaultDeposit({
                     amount: 10_000e9,
-                    tokenMint: tokenOutMint
+                    tokenMint: tokenOutMint,
+                    offer: program.getOfferPda(tokenInMint, tokenOutMint)
                 });
 
                 const bossBefore = await testHelper.getTokenAccountBalance(bossTokenInAccount);
@@ -1292,13 +1394,14 @@ describe("Take Offer", () => {
                 testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
 
                 // Create and fund vault
-                testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-                testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+                testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+                testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
                 // Fund vault
                 await program.offerVaultDeposit({
                     amount: 10_000e9,
-                    tokenMint: tokenOutMint
+                    tokenMint: tokenOutMint,
+                    offer: program.getOfferPda(tokenInMint, tokenOutMint)
                 });
 
                 await program.takeOffer({
@@ -1326,6 +1429,91 @@ describe("Take Offer", () => {
         });
     });
 
+    describe("Fulfillment Mode Tests", () => {
+        let modeTokenOutMint: PublicKey;
+        let userModeTokenOutAccount: PublicKey;
+        let vaultModeTokenOutAccount: PublicKey;
+
+        beforeEach(async () => {
+            modeTokenOutMint = testHelper.createMint(9);
+            userModeTokenOutAccount = getAssociatedTokenAddressSync(modeTokenOutMint, user.publicKey);
+            testHelper.createTokenAccount(modeTokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+            vaultModeTokenOutAccount = testHelper.createTokenAccount(modeTokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, modeTokenOutMint), BigInt(0), true);
+        });
+
+        async function addVector() {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint: modeTokenOutMint,
+                baseTime: await testHelper.getCurrentClockTime(),
+                basePrice: 1e9,
+                apr: 36_500,
+                priceFixDuration: 86400
+            });
+        }
+
+        it("Should transfer token_out from the vault in Transfer mode even when the program holds the mint authority", async () => {
+            await program.makeOffer({ tokenInMint, tokenOutMint: modeTokenOutMint, fulfillmentMode: "transfer" });
+            await addVector();
+            await program.offerVaultDeposit({ amount: 10e9, tokenMint: modeTokenOutMint, offer: program.getOfferPda(tokenInMint, modeTokenOutMint) });
+            await program.transferMintAuthorityToProgram({ mint: modeTokenOutMint });
+
+            const supplyBefore = (await testHelper.getMintInfo(modeTokenOutMint)).supply;
+
+            await program.takeOffer({
+                tokenInAmount: 1.0001e6,
+                tokenInMint,
+                tokenOutMint: modeTokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const supplyAfter = (await testHelper.getMintInfo(modeTokenOutMint)).supply;
+            expect(supplyAfter).toBe(supplyBefore);
+            await testHelper.expectTokenAccountAmountToBe(userModeTokenOutAccount, BigInt(1e9));
+            await testHelper.expectTokenAccountAmountToBe(vaultModeTokenOutAccount, BigInt(9e9));
+        });
+
+        it("Should mint token_out in Mint mode", async () => {
+            await program.transferMintAuthorityToProgram({ mint: modeTokenOutMint });
+            await program.makeOffer({ tokenInMint, tokenOutMint: modeTokenOutMint, fulfillmentMode: "mint" });
+            await addVector();
+
+            const supplyBefore = (await testHelper.getMintInfo(modeTokenOutMint)).supply;
+
+            await program.takeOffer({
+                tokenInAmount: 1.0001e6,
+                tokenInMint,
+                tokenOutMint: modeTokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const supplyAfter = (await testHelper.getMintInfo(modeTokenOutMint)).supply;
+            expect(supplyAfter - supplyBefore).toBe(BigInt(1e9));
+            await testHelper.expectTokenAccountAmountToBe(userModeTokenOutAccount, BigInt(1e9));
+        });
+
+        it("Should fail in Mint mode once the program no longer holds the mint authority", async () => {
+            await program.transferMintAuthorityToProgram({ mint: modeTokenOutMint });
+            await program.makeOffer({ tokenInMint, tokenOutMint: modeTokenOutMint, fulfillmentMode: "mint" });
+            await addVector();
+            await program.offerVaultDeposit({ amount: 10e9, tokenMint: modeTokenOutMint, offer: program.getOfferPda(tokenInMint, modeTokenOutMint) });
+            await program.transferMintAuthorityToBoss({ mint: modeTokenOutMint });
+
+            // A funded vault must not be drained as a silent fallback
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1.0001e6,
+                    tokenInMint,
+                    tokenOutMint: modeTokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Fulfillment mode Mint requires the program to hold the token_out mint authority");
+        });
+    });
+
     describe("Kill Switch Tests", () => {
         it("Should reject take_offer when kill switch is enabled", async () => {
             // Add an admin (admin state is now part of main state)
@@ -1399,5 +1587,74 @@ describe("Take Offer", () => {
             const userTokenOutBalance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
             expect(userTokenOutBalance).toBe(BigInt(1e9));
         });
+
+        it("Should allow take_offer once a timed kill switch has expired", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            // Enable kill switch for one hour
+            await program.setKillSwitch({ enable: true, duration: 3600 });
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1_000_000,
+                    tokenInMint,
+                    tokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Kill switch is activated");
+
+            // Once expired the take succeeds without disabling the kill switch
+            await testHelper.advanceClockBy(3600);
+            await program.takeOffer({
+                tokenInAmount: 1_000_000,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const state = await program.getState();
+            expect(state.isKilled).toBe(true);
+            const userTokenOutBalance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(userTokenOutBalance).toBe(BigInt(1e9));
+        });
+    });
+
+    describe("Balance Invariant Tests", () => {
+        it("Should let the boss take its own offer as source and destination coincide", async () => {
+            // given
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+            testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(5e6));
+
+            // when
+            await program.takeOffer({
+                tokenInAmount: 1_000_000,
+                tokenInMint,
+                tokenOutMint,
+                user: testHelper.getBoss()
+            });
+
+            // then
+            await testHelper.expectTokenAccountAmountToBe(bossTokenInAccount, BigInt(5e6));
+            await testHelper.expectTokenAccountAmountToBe(bossTokenOutAccount, BigInt(1e9));
+        });
     });
-});
\ No newline at end of file
+});
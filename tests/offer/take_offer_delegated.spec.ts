@@ -0,0 +1,106 @@
+import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import { createApproveInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Take Offer Delegated", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let owner: Keypair;
+    let delegate: Keypair;
+    let ownerTokenInAccount: PublicKey;
+    let ownerTokenOutAccount: PublicKey;
+    let bossTokenInAccount: PublicKey;
+
+    async function approveDelegate(amount: number) {
+        const tx = new Transaction().add(
+            createApproveInstruction(ownerTokenInAccount, delegate.publicKey, owner.publicKey, BigInt(amount))
+        );
+        await testHelper.sendAndConfirmTransaction(tx, [owner]);
+    }
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        owner = testHelper.createUserAccount();
+        delegate = testHelper.createUserAccount();
+        ownerTokenInAccount = testHelper.createTokenAccount(tokenInMint, owner.publicKey, BigInt(10_000e6));
+        ownerTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, owner.publicKey);
+        bossTokenInAccount = testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should pull token_in from the owner and credit token_out to the owner", async () => {
+        // given
+        await approveDelegate(1_000e6);
+
+        // when
+        await program.takeOfferDelegated({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            owner: owner.publicKey,
+            delegate
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(ownerTokenInAccount, BigInt(9_000e6));
+        await testHelper.expectTokenAccountAmountToBe(ownerTokenOutAccount, BigInt(1_000e9));
+        await testHelper.expectTokenAccountAmountToBe(bossTokenInAccount, BigInt(1_000e6));
+        const delegateTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, delegate.publicKey);
+        expect(await testHelper.getAccountInfo(delegateTokenOutAccount)).toBeNull();
+    });
+
+    it("Should reject a signer that is not the delegate", async () => {
+        await expect(
+            program.takeOfferDelegated({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint,
+                owner: owner.publicKey,
+                delegate
+            })
+        ).rejects.toThrow("Signer is not the delegate of the owner's token account");
+    });
+
+    it("Should reject a take exceeding the delegated allowance", async () => {
+        // given
+        await approveDelegate(500e6);
+
+        // when / then
+        await expect(
+            program.takeOfferDelegated({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint,
+                owner: owner.publicKey,
+                delegate
+            })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,135 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Take Offer Exact Out", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenInAccount: PublicKey;
+    let userTokenOutAccount: PublicKey;
+    let bossTokenInAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6); // USDC-like (6 decimals)
+        tokenOutMint = testHelper.createMint(9); // ONyc-like (9 decimals)
+
+        await program.initialize({ onycMint: tokenOutMint });
+
+        // Offer with a 1% fee
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint,
+            feeBasisPoints: 100
+        });
+
+        user = testHelper.createUserAccount();
+        userTokenInAccount = testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        bossTokenInAccount = testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({
+            amount: 10_000e9,
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
+        });
+
+        // Flat price of 1.5 with daily steps
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1.5e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should deliver exactly the requested token_out", async () => {
+        await program.takeOfferExactOut({
+            tokenOutAmount: 10e9,
+            maxTokenInAmount: 20e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        });
+
+        const tokenOutBalance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+        expect(tokenOutBalance).toBe(BigInt(10e9));
+    });
+
+    it("Should charge the net amount grossed up by the fee", async () => {
+        await program.takeOfferExactOut({
+            tokenOutAmount: 10e9,
+            maxTokenInAmount: 20e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // net = 10 * 1.5 = 15 USDC, gross = ceil(15e6 * 10000 / 9900) = 15_151_516
+        const userTokenInBalance = await testHelper.getTokenAccountBalance(userTokenInAccount);
+        expect(userTokenInBalance).toBe(BigInt(10_000e6 - 15_151_516));
+
+        const bossTokenInBalance = await testHelper.getTokenAccountBalance(bossTokenInAccount);
+        expect(bossTokenInBalance).toBe(BigInt(15_151_516));
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.stepVolume.toNumber()).toBe(10e9);
+    });
+
+    it("Should round the token_in owed up", async () => {
+        // 1 base unit of token_out is worth 0.0000000015 USDC, rounded up to 1 base unit
+        await program.takeOfferExactOut({
+            tokenOutAmount: 1,
+            maxTokenInAmount: 2,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        });
+
+        const bossTokenInBalance = await testHelper.getTokenAccountBalance(bossTokenInAccount);
+        expect(bossTokenInBalance).toBe(BigInt(2));
+    });
+
+    it("Should reject when the token_in owed exceeds the maximum", async () => {
+        await expect(
+            program.takeOfferExactOut({
+                tokenOutAmount: 10e9,
+                maxTokenInAmount: 15_151_515,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("Required token_in exceeds the maximum");
+    });
+
+    it("Should reject a zero token_out amount", async () => {
+        await expect(
+            program.takeOfferExactOut({
+                tokenOutAmount: 0,
+                maxTokenInAmount: 20e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            })
+        ).rejects.toThrow("Invalid amount: token_out amount must be greater than zero");
+    });
+});
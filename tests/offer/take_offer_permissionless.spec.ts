@@ -57,8 +57,8 @@ describe("Take Offer Permissionless", () => {
         bossTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, testHelper.getBoss());
 
         // Create vault and permissionless intermediary accounts
-        vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-        vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+        vaultTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        vaultTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
         permissionlessTokenInAccount = testHelper.createTokenAccount(tokenInMint, program.pdas.permissionlessAuthorityPda, BigInt(0), true);
         permissionlessTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, program.pdas.permissionlessAuthorityPda, BigInt(0), true);
@@ -67,7 +67,8 @@ describe("Take Offer Permissionless", () => {
         testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
         await program.offerVaultDeposit({
             amount: 5_000e9,
-            tokenMint: tokenOutMint
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
         });
     });
 
@@ -201,7 +202,8 @@ describe("Take Offer Permissionless", () => {
             const bossTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(50_000e9));
             await program.offerVaultDeposit({
                 amount: 25_000e9,
-                tokenMint: tokenOutMint
+                tokenMint: tokenOutMint,
+                offer: program.getOfferPda(tokenInMint, tokenOutMint)
             });
 
             await program.addOfferVector({
@@ -892,7 +894,8 @@ describe("Take Offer Permissionless", () => {
             testHelper.createTokenAccount(restrictedTokenOut, testHelper.getBoss(), BigInt(10_000e9));
             await program.offerVaultDeposit({
                 amount: 5_000e9,
-                tokenMint: restrictedTokenOut
+                tokenMint: restrictedTokenOut,
+                offer: program.getOfferPda(restrictedTokenIn, restrictedTokenOut)
             });
 
             // Create permissionless token accounts for the restricted offer
@@ -962,4 +965,84 @@ describe("Take Offer Permissionless", () => {
         });
     });
 
+    describe("Multiple Authorities Tests", () => {
+        it("Should store the name of each authority", async () => {
+            await program.initializePermissionlessAuthority({ accountName: "partner-2", index: 2 });
+
+            expect((await program.getPermissionlessAuthority()).name).toBe("test-account");
+            expect((await program.getPermissionlessAuthority(2)).name).toBe("partner-2");
+        });
+
+        it("Should keep index 1 at the original permissionless-1 address", async () => {
+            expect(program.getPermissionlessAuthorityPda(1).equals(program.pdas.permissionlessAuthorityPda)).toBe(true);
+        });
+
+        it("Should route takes through the selected authority's intermediary accounts", async () => {
+            // given
+            await program.initializePermissionlessAuthority({ accountName: "partner-2", index: 2 });
+            const partnerAuthority = program.getPermissionlessAuthorityPda(2);
+            const partnerTokenInAccount = testHelper.createTokenAccount(tokenInMint, partnerAuthority, BigInt(0), true);
+            const partnerTokenOutAccount = testHelper.createTokenAccount(tokenOutMint, partnerAuthority, BigInt(0), true);
+
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            // when
+            await program.takeOfferPermissionless({
+                tokenInAmount: 1e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                authorityIndex: 2
+            });
+
+            // then
+            expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(1e9));
+            expect(await testHelper.getTokenAccountBalance(bossTokenInAccount)).toBe(BigInt(1e6));
+            expect(await testHelper.getTokenAccountBalance(partnerTokenInAccount)).toBe(BigInt(0));
+            expect(await testHelper.getTokenAccountBalance(partnerTokenOutAccount)).toBe(BigInt(0));
+        });
+
+        it("Should reject takes routed through an uninitialized authority", async () => {
+            // given
+            const unknownAuthority = program.getPermissionlessAuthorityPda(3);
+            testHelper.createTokenAccount(tokenInMint, unknownAuthority, BigInt(0), true);
+            testHelper.createTokenAccount(tokenOutMint, unknownAuthority, BigInt(0), true);
+
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            // when/then
+            await expect(program.takeOfferPermissionless({
+                tokenInAmount: 1e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                authorityIndex: 3
+            })).rejects.toThrow("Permissionless authority not initialized");
+        });
+
+        it("Should reject initializing the same index twice", async () => {
+            await expect(
+                program.initializePermissionlessAuthority({ accountName: "again", index: 1 })
+            ).rejects.toThrow();
+        });
+    });
+
 });
\ No newline at end of file
@@ -0,0 +1,130 @@
+import path from "path";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import callerIdl from "../../target/idl/onre_cpi_caller.json";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+// The CPI caller doubles as a mock screening program accepting amounts up to 1_000e6
+const SCREENING_PROGRAM_ID = new PublicKey((callerIdl as any).address);
+
+describe("Take offer screening", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        testHelper.svm.addProgramFromFile(
+            SCREENING_PROGRAM_ID,
+            path.join(process.cwd(), "target/deploy/onre_cpi_caller.so")
+        );
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        await program.setScreeningProgram({ screeningProgram: SCREENING_PROGRAM_ID });
+    });
+
+    it("Should complete takes accepted by the screening program", async () => {
+        // when
+        await program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            screeningProgram: SCREENING_PROGRAM_ID
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(1_000e9));
+    });
+
+    it("Should abort takes rejected by the screening program", async () => {
+        await expect(program.takeOffer({
+            tokenInAmount: 1_000e6 + 1,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            screeningProgram: SCREENING_PROGRAM_ID
+        })).rejects.toThrow("Screening check failed");
+    });
+
+    it("Should screen the derived token_in amount of exact-out takes", async () => {
+        await expect(program.takeOfferExactOut({
+            tokenOutAmount: 2_000e9,
+            maxTokenInAmount: 2_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            screeningProgram: SCREENING_PROGRAM_ID
+        })).rejects.toThrow("Screening check failed");
+    });
+
+    it("Should require the screening program account while screening is configured", async () => {
+        await expect(program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        })).rejects.toThrow("Screening program account required");
+    });
+
+    it("Should reject a screening program other than the configured one", async () => {
+        await expect(program.takeOffer({
+            tokenInAmount: 1_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            screeningProgram: PublicKey.unique()
+        })).rejects.toThrow("Invalid screening program account");
+    });
+
+    it("Should stop screening once the screening program is unset", async () => {
+        // given
+        await program.setScreeningProgram({ screeningProgram: PublicKey.default });
+
+        // when
+        await program.takeOffer({
+            tokenInAmount: 2_000e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(userTokenOutAccount, BigInt(2_000e9));
+    });
+});
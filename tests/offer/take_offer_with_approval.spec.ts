@@ -47,13 +47,14 @@ describe("Take Offer With Approval", () => {
         userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
 
         // Create and fund vault
-        testHelper.createTokenAccount(tokenInMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-        testHelper.createTokenAccount(tokenOutMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
 
         // Fund vault
         await program.offerVaultDeposit({
             amount: 10_000e9,
-            tokenMint: tokenOutMint
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
         });
 
         // Add vector to the offer
@@ -118,4 +119,146 @@ describe("Take Offer With Approval", () => {
             })
         ).rejects.toThrow();
     });
-});
\ No newline at end of file
+
+    it("Should fail with an approval valid for longer than the configured limit", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 600, secondsPerYear: 31_536_000 });
+
+        // when & then
+        await expect(
+            Ed25519Helper.executeApprovedTakeOffer({
+                program,
+                tokenInAmount: 1_000_100,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                userKeypair: user,
+                trustedAuthority,
+                boss: testHelper.getBoss(),
+                expiryTime: currentTime + 601
+            })
+        ).rejects.toThrow("The approval message is valid for too long.");
+    });
+
+    it("Should accept a marginally expired approval within the clock skew grace", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000, clockSkewGraceSecs: 30 });
+
+        // when
+        await Ed25519Helper.executeApprovedTakeOffer({
+            program,
+            tokenInAmount: 1_000_100,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            userKeypair: user,
+            trustedAuthority,
+            boss: testHelper.getBoss(),
+            expiryTime: currentTime - 20
+        });
+
+        // then
+        const userTokenOutBalance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+        expect(userTokenOutBalance).toBe(BigInt(1e9));
+    });
+
+    it("Should reject an approval expired for longer than the clock skew grace", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000, clockSkewGraceSecs: 30 });
+
+        // when & then
+        await expect(
+            Ed25519Helper.executeApprovedTakeOffer({
+                program,
+                tokenInAmount: 1_000_100,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                userKeypair: user,
+                trustedAuthority,
+                boss: testHelper.getBoss(),
+                expiryTime: currentTime - 31
+            })
+        ).rejects.toThrow("The approval message has expired.");
+    });
+
+    it("Should tolerate the clock skew grace on the validity limit", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 600, secondsPerYear: 31_536_000, clockSkewGraceSecs: 30 });
+
+        // when
+        await Ed25519Helper.executeApprovedTakeOffer({
+            program,
+            tokenInAmount: 1_000_100,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            userKeypair: user,
+            trustedAuthority,
+            boss: testHelper.getBoss(),
+            expiryTime: currentTime + 630
+        });
+
+        // then
+        const userTokenOutBalance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+        expect(userTokenOutBalance).toBe(BigInt(1e9));
+    });
+
+    it("Should reject an approval from a blocked jurisdiction", async () => {
+        // given
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true });
+
+        // when & then
+        await expect(
+            Ed25519Helper.executeApprovedTakeOffer({
+                program,
+                tokenInAmount: 1_000_100,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                userKeypair: user,
+                trustedAuthority,
+                boss: testHelper.getBoss(),
+                jurisdiction: 840
+            })
+        ).rejects.toThrow("Approval jurisdiction is blocked");
+    });
+
+    it("Should accept approvals from other jurisdictions and unblocked ones", async () => {
+        // given
+        const balanceBefore = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+
+        // when
+        await Ed25519Helper.executeApprovedTakeOffer({
+            program,
+            tokenInAmount: 1_000_100,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            userKeypair: user,
+            trustedAuthority,
+            boss: testHelper.getBoss(),
+            jurisdiction: 826
+        });
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: false });
+        await Ed25519Helper.executeApprovedTakeOffer({
+            program,
+            tokenInAmount: 1_000_100,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            userKeypair: user,
+            trustedAuthority,
+            boss: testHelper.getBoss(),
+            jurisdiction: 840
+        });
+
+        // then
+        const balanceAfter = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+        expect(balanceAfter).toBeGreaterThan(balanceBefore);
+    });
+});
@@ -0,0 +1,92 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Tick Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let offerPda: PublicKey;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+
+        currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime + 1000,
+            basePrice: 1.1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should record the active vector on the first tick", async () => {
+        // when
+        await program.tickOffer({ offer: offerPda });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lastActiveVectorStartTime.toNumber()).toBe(currentTime);
+    });
+
+    it("Should record the rollover to the next vector", async () => {
+        // given
+        await program.tickOffer({ offer: offerPda });
+
+        // when
+        await testHelper.advanceClockBy(1000);
+        await program.tickOffer({ offer: offerPda });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lastActiveVectorStartTime.toNumber()).toBe(currentTime + 1000);
+    });
+
+    it("Should leave the record untouched while the active vector is unchanged", async () => {
+        // given
+        await program.tickOffer({ offer: offerPda });
+
+        // when
+        await testHelper.advanceClockBy(500);
+        await program.tickOffer({ offer: offerPda });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lastActiveVectorStartTime.toNumber()).toBe(currentTime);
+    });
+
+    it("Should reset the record when the active vector is deleted", async () => {
+        // given
+        await program.tickOffer({ offer: offerPda });
+        await program.deleteAllOfferVectors(tokenInMint, tokenOutMint);
+
+        // when
+        await testHelper.advanceSlot();
+        await program.tickOffer({ offer: offerPda });
+
+        // then
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lastActiveVectorStartTime.toNumber()).toBe(0);
+    });
+});
@@ -0,0 +1,89 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Dust Threshold", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        // token_in has more decimals than token_out, so tiny takes round to zero
+        tokenInMint = testHelper.createMint(9);
+        tokenOutMint = testHelper.createMint(6);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e9), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e6));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e6, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    describe("Configuration", () => {
+        it("Should allow boss to update the dust threshold", async () => {
+            await program.updateOfferDustThreshold({ tokenInMint, tokenOutMint, minTokenOutAmount: 1_000 });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.minTokenOutAmount.toNumber()).toBe(1_000);
+        });
+
+        it("Should reject updates from non-boss", async () => {
+            await expect(
+                program.updateOfferDustThreshold({ tokenInMint, tokenOutMint, minTokenOutAmount: 1_000, signer: user })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Enforcement", () => {
+        it("Should reject takes paying out zero token_out", async () => {
+            // 999 base units of token_in are worth 0.999 base units of token_out
+            await expect(
+                program.takeOffer({ tokenInAmount: 999, tokenInMint, tokenOutMint, user: user.publicKey, signer: user })
+            ).rejects.toThrow("Take output below the offer's dust threshold");
+        });
+
+        it("Should accept takes paying out a single base unit by default", async () => {
+            await program.takeOffer({ tokenInAmount: 1_000, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+
+            expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(1));
+        });
+
+        it("Should reject takes paying out less than the configured threshold", async () => {
+            // given
+            await program.updateOfferDustThreshold({ tokenInMint, tokenOutMint, minTokenOutAmount: 1_000 });
+
+            // when / then
+            await expect(
+                program.takeOffer({ tokenInAmount: 999_999, tokenInMint, tokenOutMint, user: user.publicKey, signer: user })
+            ).rejects.toThrow("Take output below the offer's dust threshold");
+            await program.takeOffer({ tokenInAmount: 1_000_000, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+            expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(1_000));
+        });
+    });
+});
@@ -0,0 +1,94 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Flags", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, withApproval: true });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 10_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    it("Should allow boss to update both flags", async () => {
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: false, allowPermissionless: true });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.needsApproval).toBe(0);
+        expect(offer.allowPermissionless).toBe(1);
+    });
+
+    it("Should open a permissioned offer to takes without approval", async () => {
+        await expect(
+            program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user })
+        ).rejects.toThrow("Approval required for this offer");
+
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: false, allowPermissionless: false });
+
+        await program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+        expect(await testHelper.getTokenAccountBalance(userTokenOutAccount)).toBe(BigInt(1e9));
+    });
+
+    it("Should restrict a public offer to approved takes", async () => {
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: false, allowPermissionless: false });
+        await program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: true, allowPermissionless: false });
+
+        await expect(
+            program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user })
+        ).rejects.toThrow("Approval required for this offer");
+    });
+
+    it("Should keep the offer statistics and version", async () => {
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: false, allowPermissionless: false });
+        await program.takeOffer({ tokenInAmount: 1e6, tokenInMint, tokenOutMint, user: user.publicKey, signer: user });
+        const before = await program.getOffer(tokenInMint, tokenOutMint);
+
+        await program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: true, allowPermissionless: true });
+
+        const after = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(after.offerVersion).toBe(before.offerVersion);
+        expect(after.totalVolumeTokenIn).toEqual(before.totalVolumeTokenIn);
+        expect(after.vectors[0].startTime.toNumber()).toBe(before.vectors[0].startTime.toNumber());
+    });
+
+    it("Should reject updates from non-boss", async () => {
+        await expect(
+            program.updateOfferFlags({ tokenInMint, tokenOutMint, needsApproval: false, allowPermissionless: true, signer: user })
+        ).rejects.toThrow();
+    });
+});
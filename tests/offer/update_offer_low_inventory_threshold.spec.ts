@@ -0,0 +1,50 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Low Inventory Threshold", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+    });
+
+    it("Should start without an alert threshold", async () => {
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lowInventoryThreshold.toNumber()).toBe(0);
+    });
+
+    it("Should allow boss to update the threshold", async () => {
+        await program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 1_000e9 });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lowInventoryThreshold.toNumber()).toBe(1_000e9);
+    });
+
+    it("Should allow boss to disable alerts again", async () => {
+        await program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 1_000e9 });
+        await program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 0 });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.lowInventoryThreshold.toNumber()).toBe(0);
+    });
+
+    it("Should reject updates from non-boss", async () => {
+        const user: Keypair = testHelper.createUserAccount();
+
+        await expect(
+            program.updateOfferLowInventoryThreshold({ tokenInMint, tokenOutMint, lowInventoryThreshold: 1_000e9, signer: user })
+        ).rejects.toThrow();
+    });
+});
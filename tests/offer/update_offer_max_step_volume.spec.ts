@@ -0,0 +1,175 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Max Step Volume", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({
+            amount: 10_000e9,
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
+        });
+
+        // Flat price of 1.0 with daily steps
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    describe("Configuration", () => {
+        it("Should allow boss to update the max step volume", async () => {
+            await program.updateOfferMaxStepVolume({
+                tokenInMint,
+                tokenOutMint,
+                maxStepVolume: 5e9
+            });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.maxStepVolume.toNumber()).toBe(5e9);
+        });
+
+        it("Should reject update from non-boss", async () => {
+            const notBoss = testHelper.createUserAccount();
+
+            await expect(
+                program.updateOfferMaxStepVolume({
+                    tokenInMint,
+                    tokenOutMint,
+                    maxStepVolume: 5e9,
+                    signer: notBoss
+                })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Enforcement", () => {
+        beforeEach(async () => {
+            await program.updateOfferMaxStepVolume({
+                tokenInMint,
+                tokenOutMint,
+                maxStepVolume: 5e9
+            });
+        });
+
+        it("Should allow takes up to the cap within a step", async () => {
+            await program.takeOffer({
+                tokenInAmount: 3e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+            await program.takeOffer({
+                tokenInAmount: 2e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const balance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(balance).toBe(BigInt(5e9));
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.stepVolume.toNumber()).toBe(5e9);
+        });
+
+        it("Should reject a take exceeding the remaining step volume", async () => {
+            await program.takeOffer({
+                tokenInAmount: 3e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 3e6 + 1,
+                    tokenInMint,
+                    tokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Step volume cap exceeded");
+        });
+
+        it("Should reset the step volume at the next step boundary", async () => {
+            await program.takeOffer({
+                tokenInAmount: 5e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            await testHelper.advanceClockBy(86400);
+
+            await program.takeOffer({
+                tokenInAmount: 4e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.stepVolume.toNumber()).toBe(4e9);
+        });
+
+        it("Should not limit takes when the cap is zero", async () => {
+            await program.updateOfferMaxStepVolume({
+                tokenInMint,
+                tokenOutMint,
+                maxStepVolume: 0
+            });
+
+            await program.takeOffer({
+                tokenInAmount: 50e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const balance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(balance).toBe(BigInt(50e9));
+        });
+    });
+});
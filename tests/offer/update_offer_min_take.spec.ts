@@ -0,0 +1,170 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Min Take", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    let user: Keypair;
+    let userTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({
+            tokenInMint,
+            tokenOutMint
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        userTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, user.publicKey);
+
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({
+            amount: 10_000e9,
+            tokenMint: tokenOutMint,
+            offer: program.getOfferPda(tokenInMint, tokenOutMint)
+        });
+
+        // Flat price of 2.0 with daily steps
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+    });
+
+    describe("Configuration", () => {
+        it("Should allow boss to update the minimum take", async () => {
+            await program.updateOfferMinTake({
+                tokenInMint,
+                tokenOutMint,
+                minTakeAmount: 1_000e9,
+                denomination: "quote"
+            });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.minTakeAmount.toNumber()).toBe(1_000e9);
+        });
+
+        it("Should reject update from non-boss", async () => {
+            const notBoss = testHelper.createUserAccount();
+
+            await expect(
+                program.updateOfferMinTake({
+                    tokenInMint,
+                    tokenOutMint,
+                    minTakeAmount: 5e6,
+                    denomination: "token",
+                    signer: notBoss
+                })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Token denomination", () => {
+        beforeEach(async () => {
+            await program.updateOfferMinTake({
+                tokenInMint,
+                tokenOutMint,
+                minTakeAmount: 5e6,
+                denomination: "token"
+            });
+        });
+
+        it("Should allow a take at the minimum", async () => {
+            await program.takeOffer({
+                tokenInAmount: 5e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const balance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(balance).toBe(BigInt(2.5e9));
+        });
+
+        it("Should reject a take below the minimum", async () => {
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 5e6 - 1,
+                    tokenInMint,
+                    tokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Take below the offer's minimum");
+        });
+    });
+
+    describe("Quote denomination", () => {
+        beforeEach(async () => {
+            // $1,000 with scale=9
+            await program.updateOfferMinTake({
+                tokenInMint,
+                tokenOutMint,
+                minTakeAmount: 1_000e9,
+                denomination: "quote"
+            });
+        });
+
+        it("Should value token_in at $1.0 without an oracle", async () => {
+            await program.takeOffer({
+                tokenInAmount: 1_000e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1_000e6 - 1,
+                    tokenInMint,
+                    tokenOutMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Take below the offer's minimum");
+        });
+
+        it("Should not limit takes once the minimum is cleared", async () => {
+            await program.updateOfferMinTake({
+                tokenInMint,
+                tokenOutMint,
+                minTakeAmount: 0,
+                denomination: "quote"
+            });
+
+            await program.takeOffer({
+                tokenInAmount: 1e6,
+                tokenInMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user
+            });
+
+            const balance = await testHelper.getTokenAccountBalance(userTokenOutAccount);
+            expect(balance).toBe(BigInt(0.5e9));
+        });
+    });
+});
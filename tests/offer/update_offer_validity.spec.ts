@@ -0,0 +1,115 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update Offer Validity", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+    let currentTime: number;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        currentTime = await testHelper.getCurrentClockTime();
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400,
+            depositAmount: 1_000e9
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(1_000e6), true);
+    });
+
+    const take = () => program.takeOffer({
+        tokenInAmount: 10e6,
+        tokenInMint,
+        tokenOutMint,
+        user: user.publicKey,
+        signer: user,
+        bossTokenInAccount: null
+    });
+
+    it("Should start without a validity window", async () => {
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.validFrom.toNumber()).toBe(0);
+        expect(offer.validUntil.toNumber()).toBe(0);
+    });
+
+    it("Should allow boss to update the window", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: currentTime + 100, validUntil: currentTime + 200 });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.validFrom.toNumber()).toBe(currentTime + 100);
+        expect(offer.validUntil.toNumber()).toBe(currentTime + 200);
+    });
+
+    it("Should reject a window ending before it starts", async () => {
+        await expect(
+            program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: currentTime + 200, validUntil: currentTime + 200 })
+        ).rejects.toThrow("Invalid validity window: valid_until must be after valid_from");
+    });
+
+    it("Should reject takes before the window opens", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: currentTime + 100, validUntil: 0 });
+
+        await expect(take()).rejects.toThrow("Offer is not active yet");
+    });
+
+    it("Should accept takes once the window opens", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: currentTime + 100, validUntil: 0 });
+        await testHelper.advanceClockBy(100);
+
+        await take();
+
+        await testHelper.expectTokenAccountAmountToBe(
+            getAssociatedTokenAddressSync(tokenOutMint, user.publicKey),
+            BigInt(10e9)
+        );
+    });
+
+    it("Should reject takes once the offer expires", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: currentTime + 100 });
+        await take();
+
+        await testHelper.advanceClockBy(100);
+        await testHelper.advanceSlot();
+
+        await expect(take()).rejects.toThrow("Offer has expired");
+    });
+
+    it("Should reopen an expired offer when the window is cleared", async () => {
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: currentTime + 100 });
+        await testHelper.advanceClockBy(100);
+        await expect(take()).rejects.toThrow("Offer has expired");
+
+        await program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: 0 });
+        await testHelper.advanceSlot();
+
+        await take();
+    });
+
+    it("Should reject updates from non-boss", async () => {
+        const notBoss: Keypair = testHelper.createUserAccount();
+
+        await expect(
+            program.updateOfferValidity({ tokenInMint, tokenOutMint, validFrom: 0, validUntil: currentTime + 100, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -1,4 +1,4 @@
-import { Keypair, PublicKey } from "@solana/web3.js";
+import { AccountMeta, Keypair, PublicKey, Transaction } from "@solana/web3.js";
 import { AnchorProvider, BN, Program, Wallet } from "@coral-xyz/anchor";
 import { Onreapp } from "../target/types/onreapp";
 import { BPF_LOADER_PROGRAM_ID, BPF_UPGRADEABLE_LOADER_PROGRAM_ID, ONREAPP_PROGRAM_ID, TestHelper } from "./test_helper.ts";
@@ -7,6 +7,9 @@ import { getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID } from "@solana/spl-tok
 
 export { BPF_LOADER_PROGRAM_ID };
 
+// Byte offset of `own_vault` in offer account data, including the discriminator
+const OFFER_OWN_VAULT_OFFSET = 3301;
+
 /**
  * Helper to check view transaction errors and throw with logs
  */
@@ -28,12 +31,16 @@ export class OnreProgram {
         redemptionVaultAuthorityPda: PublicKey;
         permissionlessAuthorityPda: PublicKey;
         mintAuthorityPda: PublicKey;
+        keeperRewardConfigPda: PublicKey;
+        keeperRewardVaultAuthorityPda: PublicKey;
     } = {
         statePda: PublicKey.findProgramAddressSync([Buffer.from("state")], ONREAPP_PROGRAM_ID)[0],
         offerVaultAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("offer_vault_authority")], ONREAPP_PROGRAM_ID)[0],
         redemptionVaultAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("redemption_offer_vault_authority")], ONREAPP_PROGRAM_ID)[0],
         permissionlessAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("permissionless-1")], ONREAPP_PROGRAM_ID)[0],
-        mintAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("mint_authority")], ONREAPP_PROGRAM_ID)[0]
+        mintAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("mint_authority")], ONREAPP_PROGRAM_ID)[0],
+        keeperRewardConfigPda: PublicKey.findProgramAddressSync([Buffer.from("keeper_reward_config")], ONREAPP_PROGRAM_ID)[0],
+        keeperRewardVaultAuthorityPda: PublicKey.findProgramAddressSync([Buffer.from("keeper_reward_vault_authority")], ONREAPP_PROGRAM_ID)[0]
     };
 
     constructor(testHelper: TestHelper) {
@@ -65,6 +72,58 @@ export class OnreProgram {
                 )[0]
             })
             .rpc();
+
+        // Offer and redemption instructions require the program-wide config
+        if (!(await this.testHelper.getAccountInfo(this.getConfigPda()))) {
+            await this.initializeConfig();
+        }
+    }
+
+    async initializeConfig(params?: { signer?: Keypair }) {
+        const tx = this.program.methods
+            .initializeConfig()
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setConfig(params: {
+        maxFeeBps: number,
+        maxApprovalValiditySecs: number,
+        secondsPerYear: number,
+        clockSkewGraceSecs?: number,
+        signer?: Keypair
+    }) {
+        const tx = this.program.methods
+            .setConfig(
+                params.maxFeeBps,
+                new BN(params.maxApprovalValiditySecs),
+                new BN(params.secondsPerYear),
+                new BN(params.clockSkewGraceSecs ?? 0)
+            )
+            .accounts({});
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    getPermissionlessAuthorityPda(index: number) {
+        return PublicKey.findProgramAddressSync([Buffer.from(`permissionless-${index}`)], ONREAPP_PROGRAM_ID)[0];
+    }
+
+    getConfigPda() {
+        return PublicKey.findProgramAddressSync([Buffer.from("config")], ONREAPP_PROGRAM_ID)[0];
+    }
+
+    async getConfig() {
+        return await this.program.account.config.fetch(this.getConfigPda());
     }
 
     async makeOffer(params: {
@@ -73,16 +132,30 @@ export class OnreProgram {
         feeBasisPoints?: number;
         signer?: Keypair;
         tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
         withApproval?: boolean;
         allowPermissionless?: boolean;
+        proceedsToVault?: boolean;
+        fulfillmentMode?: "transfer" | "mint";
+        validFrom?: number;
+        validUntil?: number;
     }) {
         const feeBasisPoints = params.feeBasisPoints ?? 0;
+        const fulfillmentMode = params.fulfillmentMode ? { [params.fulfillmentMode]: {} } as any : null;
         const tx = this.program.methods
-            .makeOffer(feeBasisPoints, params.withApproval ?? false, params.allowPermissionless ?? false)
+            .makeOffer(
+                feeBasisPoints,
+                params.withApproval ?? false,
+                params.allowPermissionless ?? false,
+                fulfillmentMode,
+                new BN(params.validFrom ?? 0),
+                new BN(params.validUntil ?? 0)
+            )
             .accounts({
                 tokenInMint: params.tokenInMint,
                 tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutMint: params.tokenOutMint
+                tokenOutMint: params.tokenOutMint,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
             });
 
         if (params.signer) {
@@ -90,29 +163,67 @@ export class OnreProgram {
         }
 
         await tx.rpc();
+
+        // Most specs assert the boss receiving take proceeds, so offers made here pay
+        // the boss unless vault routing, the on-chain default, is requested
+        if (!params.proceedsToVault) {
+            await this.setOfferProceedsToVault({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                proceedsToVault: false,
+                signer: params.signer
+            });
+        }
     }
 
-    async addOfferVector(params: {
-        tokenInMint: PublicKey,
-        tokenOutMint: PublicKey,
-        startTime?: number,
-        baseTime: number,
-        basePrice: number,
-        apr: number,
-        priceFixDuration: number,
+    async makeOfferWithVector(params: {
+        tokenInMint: PublicKey;
+        tokenOutMint: PublicKey;
+        feeBasisPoints?: number;
+        withApproval?: boolean;
+        allowPermissionless?: boolean;
+        fulfillmentMode?: "transfer" | "mint";
+        startTime?: number;
+        baseTime: number;
+        basePrice: number;
+        apr: number;
+        priceFixDuration: number;
+        pricingMode?: "step" | "linear";
+        depositAmount?: number;
+        bossTokenOutAccount?: PublicKey | null;
         signer?: Keypair;
+        tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
     }) {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const fulfillmentMode = params.fulfillmentMode ? { [params.fulfillmentMode]: {} } as any : null;
+        const bossTokenOutAccount = params.bossTokenOutAccount !== undefined
+            ? params.bossTokenOutAccount
+            : params.depositAmount == null
+                ? null
+                : getAssociatedTokenAddressSync(params.tokenOutMint, this.testHelper.getBoss(), true, tokenOutProgram);
         const tx = this.program.methods
-            .addOfferVector(
-                params.startTime == null ? null : new BN(params.startTime),
-                new BN(params.baseTime),
-                new BN(params.basePrice),
-                new BN(params.apr),
-                new BN(params.priceFixDuration)
+            .makeOfferWithVector(
+                params.feeBasisPoints ?? 0,
+                params.withApproval ?? false,
+                params.allowPermissionless ?? false,
+                fulfillmentMode,
+                {
+                    startTime: params.startTime == null ? null : new BN(params.startTime),
+                    baseTime: new BN(params.baseTime),
+                    basePrice: new BN(params.basePrice),
+                    apr: new BN(params.apr),
+                    priceFixDuration: new BN(params.priceFixDuration),
+                    pricingMode: params.pricingMode === "linear" ? { linear: {} } : { step: {} }
+                } as any,
+                params.depositAmount == null ? null : new BN(params.depositAmount)
             )
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutMint: params.tokenOutMint,
+                tokenOutProgram,
+                bossTokenOutAccount
             });
 
         if (params.signer) {
@@ -122,14 +233,13 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async updateOfferFee(params: {
+    async migrateOffer(params: {
         tokenInMint: PublicKey,
         tokenOutMint: PublicKey,
-        newFee: number,
         signer?: Keypair
     }) {
         const tx = this.program.methods
-            .updateOfferFee(params.newFee)
+            .migrateOffer()
             .accounts({
                 tokenInMint: params.tokenInMint,
                 tokenOutMint: params.tokenOutMint
@@ -142,62 +252,69 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async deleteOfferVector(
+    async migrateRedemptionOffer(params: {
         tokenInMint: PublicKey,
         tokenOutMint: PublicKey,
-        vectorStartTime: number,
         signer?: Keypair
-    ) {
+    }) {
         const tx = this.program.methods
-            .deleteOfferVector(new BN(vectorStartTime))
+            .migrateRedemptionOffer()
             .accounts({
-                tokenInMint: tokenInMint,
-                tokenOutMint: tokenOutMint
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
-        if (signer) {
-            tx.signers([signer]);
+        if (params.signer) {
+            tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async deleteAllOfferVectors(
+    async addOfferVector(params: {
         tokenInMint: PublicKey,
         tokenOutMint: PublicKey,
-        signer?: Keypair
-    ) {
+        startTime?: number,
+        baseTime: number,
+        basePrice: number,
+        apr: number,
+        priceFixDuration: number,
+        pricingMode?: "step" | "linear",
+        signer?: Keypair;
+    }) {
+        const pricingMode = params.pricingMode === "linear" ? { linear: {} } : { step: {} };
         const tx = this.program.methods
-            .deleteAllOfferVectors()
+            .addOfferVector(
+                params.startTime == null ? null : new BN(params.startTime),
+                new BN(params.baseTime),
+                new BN(params.basePrice),
+                new BN(params.apr),
+                new BN(params.priceFixDuration),
+                pricingMode
+            )
             .accounts({
-                tokenInMint: tokenInMint,
-                tokenOutMint: tokenOutMint
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
-        if (signer) {
-            tx.signers([signer]);
+        if (params.signer) {
+            tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async takeOffer(params: {
-        tokenInAmount: number,
+    async updateOfferFee(params: {
         tokenInMint: PublicKey,
         tokenOutMint: PublicKey,
-        user: PublicKey,
-        signer?: Keypair,
-        tokenInProgram?: PublicKey,
-        tokenOutProgram?: PublicKey
+        newFee: number,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .takeOffer(new BN(params.tokenInAmount), null)
+            .updateOfferFee(params.newFee)
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint,
-                user: params.user,
-                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -207,27 +324,17 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async takeOfferPermissionless(params: {
-        tokenInAmount: number,
+    async updateOfferMaxStepVolume(params: {
         tokenInMint: PublicKey,
         tokenOutMint: PublicKey,
-        user: PublicKey,
-        signer?: Keypair,
-        tokenInProgram?: PublicKey,
-        tokenOutProgram?: PublicKey
+        maxStepVolume: number,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .takeOfferPermissionless(new BN(params.tokenInAmount), null)
+            .updateOfferMaxStepVolume(new BN(params.maxStepVolume))
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint,
-                user: params.user,
-                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
-                boss: this.testHelper.payer.publicKey,
-                vaultAuthority: this.pdas.offerVaultAuthorityPda,
-                permissionlessAuthority: this.pdas.permissionlessAuthorityPda,
-                mintAuthority: this.pdas.mintAuthorityPda
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -237,17 +344,17 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async offerVaultDeposit(params: {
-        amount: number,
-        tokenMint: PublicKey,
-        signer?: Keypair,
-        tokenProgram?: PublicKey
+    async updateOfferDustThreshold(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        minTokenOutAmount: number,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .offerVaultDeposit(new BN(params.amount))
+            .updateOfferDustThreshold(new BN(params.minTokenOutAmount))
             .accounts({
-                tokenMint: params.tokenMint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -257,17 +364,17 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async offerVaultWithdraw(params: {
-        amount: number,
-        tokenMint: PublicKey,
-        signer?: Keypair,
-        tokenProgram?: PublicKey
+    async updateOfferLowInventoryThreshold(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        lowInventoryThreshold: number,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .offerVaultWithdraw(new BN(params.amount))
+            .updateOfferLowInventoryThreshold(new BN(params.lowInventoryThreshold))
             .accounts({
-                tokenMint: params.tokenMint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -277,17 +384,18 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async redemptionVaultDeposit(params: {
-        amount: number,
-        tokenMint: PublicKey,
-        signer?: Keypair,
-        tokenProgram?: PublicKey
+    async updateOfferValidity(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        validFrom: number,
+        validUntil: number,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .redemptionVaultDeposit(new BN(params.amount))
+            .updateOfferValidity(new BN(params.validFrom), new BN(params.validUntil))
             .accounts({
-                tokenMint: params.tokenMint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -297,17 +405,18 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async redemptionVaultWithdraw(params: {
-        amount: number,
-        tokenMint: PublicKey,
-        signer?: Keypair,
-        tokenProgram?: PublicKey
+    async updateOfferFlags(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        needsApproval: boolean,
+        allowPermissionless: boolean,
+        signer?: Keypair
     }) {
         const tx = this.program.methods
-            .redemptionVaultWithdraw(new BN(params.amount))
+            .updateOfferFlags(params.needsApproval, params.allowPermissionless)
             .accounts({
-                tokenMint: params.tokenMint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -317,18 +426,18 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async initializePermissionlessAuthority(params: { accountName: string }) {
-        await this.program.methods
-            .initializePermissionlessAuthority(params.accountName)
-            .rpc();
-    }
-
-    async transferMintAuthorityToProgram(params: { mint: PublicKey, signer?: Keypair, tokenProgram?: PublicKey }) {
+    async updateOfferMinTake(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        minTakeAmount: number,
+        denomination: "token" | "quote",
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .transferMintAuthorityToProgram()
+            .updateOfferMinTake(new BN(params.minTakeAmount), { [params.denomination]: {} } as any)
             .accounts({
-                mint: params.mint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -338,12 +447,20 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async transferMintAuthorityToBoss(params: { mint: PublicKey, signer?: Keypair, tokenProgram?: PublicKey }) {
+    async setOfferOracle(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        oracle: PublicKey,
+        feedId?: number[],
+        maxStaleness: number,
+        maxConfidenceBps: number,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .transferMintAuthorityToBoss()
+            .setOfferOracle(params.oracle, params.feedId ?? Array(32).fill(0), new BN(params.maxStaleness), params.maxConfidenceBps)
             .accounts({
-                mint: params.mint,
-                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -353,9 +470,18 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async addAdmin(params: { admin: PublicKey, signer?: Keypair }) {
+    async setOfferClawback(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        clawbackEnabled: boolean,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .addAdmin(params.admin);
+            .setOfferClawback(params.clawbackEnabled)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            });
 
         if (params.signer) {
             tx.signers([params.signer]);
@@ -364,9 +490,18 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async removeAdmin(params: { admin: PublicKey, signer?: Keypair }) {
+    async setOfferProceedsToVault(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        proceedsToVault: boolean,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .removeAdmin(params.admin);
+            .setOfferProceedsToVault(params.proceedsToVault)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            });
 
         if (params.signer) {
             tx.signers([params.signer]);
@@ -375,11 +510,17 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async proposeBoss(params: { newBoss: PublicKey, signer?: Keypair }) {
+    async setOfferPriceOnNetReceived(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        priceOnNetReceived: boolean,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .proposeBoss(params.newBoss)
+            .setOfferPriceOnNetReceived(params.priceOnNetReceived)
             .accounts({
-                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             });
 
         if (params.signer) {
@@ -389,22 +530,63 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async acceptBoss(params: { newBoss: Keypair }) {
+    async setOfferStrictAccounts(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        strictAccounts: boolean,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .acceptBoss()
+            .setOfferStrictAccounts(params.strictAccounts)
             .accounts({
-                newBoss: params.newBoss.publicKey
-            })
-            .signers([params.newBoss]);
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            });
 
-        await tx.rpc();
-    }
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async prepareAccounts(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: Keypair,
+        tokenOutProgram?: PublicKey
+    }) {
+        await this.program.methods
+            .prepareAccounts()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: params.user.publicKey,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.user])
+            .rpc();
+    }
 
-    async setKillSwitch(params: { enable: boolean, signer?: Keypair }) {
+    async collectProceeds(params: {
+        amount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        destination?: PublicKey,
+        tokenInProgram?: PublicKey,
+        signer?: Keypair
+    }) {
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
         const tx = this.program.methods
-            .setKillSwitch(params.enable)
+            .collectProceeds(new BN(params.amount))
             .accounts({
-                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                destination: params.destination ?? this.testHelper.getBoss(),
+                tokenInProgram,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram)
             });
 
         if (params.signer) {
@@ -414,50 +596,159 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async setOnycMint(params: { onycMint: PublicKey, signer?: Keypair }) {
+    async clawbackTake(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: Keypair,
+        guardian: Keypair,
+        takeReceipt: PublicKey,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey
+    }) {
+        const offer = this.getOfferPda(params.tokenInMint, params.tokenOutMint);
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(offer);
         const tx = this.program.methods
-            .setOnycMint()
+            .clawbackTake()
+            .accountsPartial({
+                offer,
+                takeReceipt: params.takeReceipt,
+                vaultAuthority,
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                boss: this.testHelper.getBoss(),
+                guardian: params.guardian.publicKey,
+                user: params.user.publicKey,
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram
+            })
+            .signers([params.guardian, params.user]);
+
+        await tx.rpc();
+    }
+
+    async closeTakeReceipt(params: { takeReceipt: PublicKey, user: Keypair }) {
+        await this.program.methods
+            .closeTakeReceipt()
+            .accountsPartial({
+                takeReceipt: params.takeReceipt,
+                user: params.user.publicKey
+            })
+            .signers([params.user])
+            .rpc();
+    }
+
+    async checkpointNavs(offers: PublicKey[], params?: { signer?: Keypair, withRewards?: boolean }): Promise<number> {
+        const remainingAccounts = offers.map(pubkey => ({ pubkey, isSigner: false, isWritable: true }));
+        const keeper = params?.signer ? params.signer.publicKey : this.testHelper.payer.publicKey;
+        const accounts = {
+            keeper,
+            keeperRewardConfig: params?.withRewards ? this.pdas.keeperRewardConfigPda : null,
+            keeperRewards: params?.withRewards ? this.getKeeperRewardsPda(keeper) : null
+        };
+
+        // Read the processed count from a simulation, then persist the checkpoints
+        const simulation = await this.program.methods
+            .checkpointNavs()
+            .accounts(accounts)
+            .remainingAccounts(remainingAccounts)
+            .transaction();
+        const processed = Buffer.from(this.simulateView(simulation, "checkpointNavs")).readUInt32LE(0);
+
+        const tx = this.program.methods
+            .checkpointNavs()
+            .accounts(accounts)
+            .remainingAccounts(remainingAccounts);
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+
+        return processed;
+    }
+
+    async recordNavObservation(params: { offer: PublicKey, signer?: Keypair, withRewards?: boolean }) {
+        const keeper = params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey;
+        const tx = this.program.methods
+            .recordNavObservation()
             .accounts({
-                onycMint: params.onycMint
+                offer: params.offer,
+                keeper,
+                keeperRewardConfig: params.withRewards ? this.pdas.keeperRewardConfigPda : null,
+                keeperRewards: params.withRewards ? this.getKeeperRewardsPda(keeper) : null
             });
 
-        if (params?.signer) {
+        if (params.signer) {
             tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async setRedemptionAdmin(params: { redemptionAdmin: PublicKey, signer?: Keypair }) {
+    async tickOffer(params: { offer: PublicKey }) {
+        await this.program.methods
+            .tickOffer()
+            .accounts({ offer: params.offer })
+            .rpc();
+    }
+
+    async crankExpiredOffer(params: { offer: PublicKey }) {
+        await this.program.methods
+            .crankExpiredOffer()
+            .accounts({ offer: params.offer })
+            .rpc();
+    }
+
+    async configureKeeperReward(params: {
+        crank: "recordNavObservation" | "checkpointNavs",
+        rewardRate: number,
+        signer?: Keypair
+    }) {
         const tx = this.program.methods
-            .setRedemptionAdmin(params.redemptionAdmin)
+            .configureKeeperReward({ [params.crank]: {} } as any, new BN(params.rewardRate))
             .accounts({});
 
-        if (params?.signer) {
+        if (params.signer) {
             tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async makeRedemptionOffer(params: {
-        offer: PublicKey;
-        feeBasisPoints?: number;
-        signer?: Keypair;
-        tokenInProgram?: PublicKey;
-        tokenOutProgram?: PublicKey;
-    }) {
-        // Fetch the offer to get token mints
-        const offer = await this.program.account.offer.fetch(params.offer);
+    async openKeeperRewards(params: { signer: Keypair }) {
+        await this.program.methods
+            .openKeeperRewards()
+            .accounts({ keeper: params.signer.publicKey })
+            .signers([params.signer])
+            .rpc();
+    }
+
+    async claimKeeperRewards(params: { signer: Keypair, onycMint: PublicKey, tokenProgram?: PublicKey }) {
+        await this.program.methods
+            .claimKeeperRewards()
+            .accounts({
+                keeper: params.signer.publicKey,
+                rewardMint: params.onycMint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.signer])
+            .rpc();
+    }
 
+    async keeperRewardVaultDeposit(params: {
+        amount: number,
+        onycMint: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
         const tx = this.program.methods
-            .makeRedemptionOffer(params.feeBasisPoints ?? 0)
+            .keeperRewardVaultDeposit(new BN(params.amount))
             .accounts({
-                tokenInMint: offer.tokenOutMint,
-                tokenOutMint: offer.tokenInMint,
-                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
-                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
-                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+                tokenMint: params.onycMint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
             });
 
         if (params.signer) {
@@ -467,18 +758,137 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async updateRedemptionOfferFee(params: {
-        redemptionOffer: PublicKey;
-        newFeeBasisPoints: number;
-        signer?: Keypair;
-    }) {
+    getKeeperRewardsPda(keeper: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("keeper_rewards"), keeper.toBuffer()],
+            ONREAPP_PROGRAM_ID
+        )[0];
+    }
+
+    async getKeeperRewards(keeper: PublicKey) {
+        return await this.program.account.keeperRewards.fetch(this.getKeeperRewardsPda(keeper));
+    }
+
+    async getKeeperRewardConfig() {
+        return await this.program.account.keeperRewardConfig.fetch(this.pdas.keeperRewardConfigPda);
+    }
+
+    async deleteOfferVector(
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        vectorStartTime: number,
+        signer?: Keypair
+    ) {
         const tx = this.program.methods
-            .updateRedemptionOfferFee(params.newFeeBasisPoints)
-            .accountsPartial({
-                redemptionOffer: params.redemptionOffer,
-                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            .deleteOfferVector(new BN(vectorStartTime))
+            .accounts({
+                tokenInMint: tokenInMint,
+                tokenOutMint: tokenOutMint
+            });
+
+        if (signer) {
+            tx.signers([signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async deleteAllOfferVectors(
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        signer?: Keypair
+    ) {
+        const tx = this.program.methods
+            .deleteAllOfferVectors()
+            .accounts({
+                tokenInMint: tokenInMint,
+                tokenOutMint: tokenOutMint
             });
 
+        if (signer) {
+            tx.signers([signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async compactOfferVectors(
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey
+    ) {
+        await this.program.methods
+            .compactOfferVectors()
+            .accounts({
+                tokenInMint: tokenInMint,
+                tokenOutMint: tokenOutMint
+            })
+            .rpc();
+    }
+
+    /**
+     * Resolves the optional boss token_in account of a take: the boss ATA unless
+     * explicitly overridden, `null` omitting it for offers paying proceeds to the vault
+     */
+    private bossTokenInAccountFor(params: {
+        tokenInMint: PublicKey,
+        tokenInProgram?: PublicKey,
+        bossTokenInAccount?: PublicKey | null
+    }): PublicKey | null {
+        if (params.bossTokenInAccount !== undefined) {
+            return params.bossTokenInAccount;
+        }
+        return getAssociatedTokenAddressSync(
+            params.tokenInMint,
+            this.testHelper.getBoss(),
+            true,
+            params.tokenInProgram ?? TOKEN_PROGRAM_ID
+        );
+    }
+
+    async takeOffer(params: {
+        tokenInAmount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: PublicKey,
+        signer?: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey,
+        tokenInOracle?: PublicKey,
+        bossTokenInAccount?: PublicKey | null,
+        navHistory?: PublicKey,
+        redemptionOffer?: PublicKey,
+        redemptionVaultTokenInAccount?: PublicKey,
+        tokenInHookApproval?: PublicKey,
+        screeningProgram?: PublicKey,
+        takeReceipt?: PublicKey,
+        transferHookAccounts?: AccountMeta[]
+    }) {
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
+        const tx = this.program.methods
+            .takeOffer(new BN(params.tokenInAmount), null)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: params.user,
+                tokenInProgram,
+                tokenOutProgram,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                tokenInOracle: params.tokenInOracle ?? null,
+                bossTokenInAccount: this.bossTokenInAccountFor(params),
+                navHistory: params.navHistory ?? null,
+                redemptionOffer: params.redemptionOffer ?? null,
+                redemptionVaultTokenInAccount: params.redemptionVaultTokenInAccount ?? null,
+                tokenInHookApproval: params.tokenInHookApproval ?? null,
+                screeningProgram: params.screeningProgram ?? null,
+                blocklistBucket: this.getBlocklistBucketPda(params.user),
+                takeReceipt: params.takeReceipt ?? null
+            })
+            .remainingAccounts(params.transferHookAccounts ?? []);
+
         if (params.signer) {
             tx.signers([params.signer]);
         }
@@ -486,50 +896,1908 @@ export class OnreProgram {
         await tx.rpc();
     }
 
-    async mintTo(params: { amount: number, signer?: Keypair }) {
+    async takeOfferDelegated(params: {
+        tokenInAmount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        owner: PublicKey,
+        delegate: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey,
+        tokenInOracle?: PublicKey,
+        bossTokenInAccount?: PublicKey | null,
+        navHistory?: PublicKey,
+        redemptionOffer?: PublicKey,
+        redemptionVaultTokenInAccount?: PublicKey,
+        tokenInHookApproval?: PublicKey,
+        transferHookAccounts?: AccountMeta[]
+    }) {
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
+        await this.program.methods
+            .takeOfferDelegated(new BN(params.tokenInAmount), null)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                owner: params.owner,
+                delegate: params.delegate.publicKey,
+                tokenInProgram,
+                tokenOutProgram,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                tokenInOracle: params.tokenInOracle ?? null,
+                bossTokenInAccount: this.bossTokenInAccountFor(params),
+                navHistory: params.navHistory ?? null,
+                redemptionOffer: params.redemptionOffer ?? null,
+                redemptionVaultTokenInAccount: params.redemptionVaultTokenInAccount ?? null,
+                tokenInHookApproval: params.tokenInHookApproval ?? null
+            })
+            .remainingAccounts(params.transferHookAccounts ?? [])
+            .signers([params.delegate])
+            .rpc();
+    }
+
+    async takeOfferExactOut(params: {
+        tokenOutAmount: number,
+        maxTokenInAmount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: PublicKey,
+        signer?: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey,
+        tokenInOracle?: PublicKey,
+        bossTokenInAccount?: PublicKey | null,
+        screeningProgram?: PublicKey,
+        takeReceipt?: PublicKey
+    }) {
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
         const tx = this.program.methods
-            .mintTo(new BN(params.amount))
+            .takeOfferExactOut(new BN(params.tokenOutAmount), new BN(params.maxTokenInAmount), null)
             .accounts({
-                tokenProgram: TOKEN_PROGRAM_ID
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: params.user,
+                tokenInProgram,
+                tokenOutProgram,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                tokenInOracle: params.tokenInOracle ?? null,
+                bossTokenInAccount: this.bossTokenInAccountFor(params),
+                navHistory: null,
+                redemptionOffer: null,
+                redemptionVaultTokenInAccount: null,
+                tokenInHookApproval: null,
+                screeningProgram: params.screeningProgram ?? null,
+                blocklistBucket: this.getBlocklistBucketPda(params.user),
+                takeReceipt: params.takeReceipt ?? null
             });
 
-        if (params?.signer) {
+        if (params.signer) {
             tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async configureMaxSupply(params: { maxSupply: number, signer?: Keypair }) {
+    async takeOfferPermissionless(params: {
+        tokenInAmount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: PublicKey,
+        signer?: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey,
+        tokenInOracle?: PublicKey,
+        bossTokenInAccount?: PublicKey | null,
+        navHistory?: PublicKey,
+        redemptionOffer?: PublicKey,
+        redemptionVaultTokenInAccount?: PublicKey,
+        tokenInHookApproval?: PublicKey,
+        transferHookAccounts?: AccountMeta[],
+        authorityIndex?: number
+    }) {
+        const authorityIndex = params.authorityIndex ?? 1;
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
         const tx = this.program.methods
-            .configureMaxSupply(new BN(params.maxSupply));
+            .takeOfferPermissionless(new BN(params.tokenInAmount), null, authorityIndex)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: params.user,
+                tokenInProgram,
+                tokenOutProgram,
+                boss: this.testHelper.payer.publicKey,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram),
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(authorityIndex),
+                mintAuthority: this.pdas.mintAuthorityPda,
+                tokenInOracle: params.tokenInOracle ?? null,
+                bossTokenInAccount: this.bossTokenInAccountFor(params),
+                navHistory: params.navHistory ?? null,
+                redemptionOffer: params.redemptionOffer ?? null,
+                redemptionVaultTokenInAccount: params.redemptionVaultTokenInAccount ?? null,
+                tokenInHookApproval: params.tokenInHookApproval ?? null,
+                blocklistBucket: this.getBlocklistBucketPda(params.user)
+            })
+            .remainingAccounts(params.transferHookAccounts ?? []);
 
-        if (params?.signer) {
+        if (params.signer) {
             tx.signers([params.signer]);
         }
 
         await tx.rpc();
     }
 
-    async closeState(params?: { signer?: Keypair }) {
+
+    async makeDualOffer(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint1: PublicKey,
+        tokenOutMint2: PublicKey,
+        tokenOut2Ratio: number,
+        signer?: Keypair,
+        tokenOut1Program?: PublicKey,
+        tokenOut2Program?: PublicKey
+    }) {
+        // The dual offer draws on its base offer's vault
+        const offer = this.getOfferPda(params.tokenInMint, params.tokenOutMint1);
         const tx = this.program.methods
-            .closeState().accounts({
-                state: this.pdas.statePda
+            .makeDualOffer(new BN(params.tokenOut2Ratio))
+            .accountsPartial({
+                offer,
+                vaultAuthority: await this.getOfferVaultAuthority(offer),
+                tokenInMint: params.tokenInMint,
+                tokenOutMint1: params.tokenOutMint1,
+                tokenOut1Program: params.tokenOut1Program ?? TOKEN_PROGRAM_ID,
+                tokenOutMint2: params.tokenOutMint2,
+                tokenOut2Program: params.tokenOut2Program ?? TOKEN_PROGRAM_ID
             });
 
-        if (params?.signer) {
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async takeDualOffer(params: {
+        tokenInAmount: number,
+        tokenInMint: PublicKey,
+        tokenOutMint1: PublicKey,
+        tokenOutMint2: PublicKey,
+        user: PublicKey,
+        signer?: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOut1Program?: PublicKey,
+        tokenOut2Program?: PublicKey,
+        tokenInOracle?: PublicKey,
+        navHistory?: PublicKey,
+        redemptionOffer?: PublicKey,
+        redemptionVaultTokenInAccount?: PublicKey,
+        tokenInHookApproval?: PublicKey,
+        transferHookAccounts?: AccountMeta[]
+    }) {
+        const offer = this.getOfferPda(params.tokenInMint, params.tokenOutMint1);
+        const tokenInProgram = params.tokenInProgram ?? TOKEN_PROGRAM_ID;
+        const tokenOut1Program = params.tokenOut1Program ?? TOKEN_PROGRAM_ID;
+        const tokenOut2Program = params.tokenOut2Program ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(offer);
+        const tx = this.program.methods
+            .takeDualOffer(new BN(params.tokenInAmount), null)
+            .accountsPartial({
+                dualOffer: this.getDualOfferPda(params.tokenInMint, params.tokenOutMint1, params.tokenOutMint2),
+                offer,
+                boss: this.testHelper.getBoss(),
+                tokenInMint: params.tokenInMint,
+                tokenOutMint1: params.tokenOutMint1,
+                tokenOutMint2: params.tokenOutMint2,
+                user: params.user,
+                tokenInProgram,
+                tokenOut1Program,
+                tokenOut2Program,
+                vaultAuthority,
+                vaultTokenInAccount: getAssociatedTokenAddressSync(params.tokenInMint, vaultAuthority, true, tokenInProgram),
+                vaultTokenOut1Account: getAssociatedTokenAddressSync(params.tokenOutMint1, vaultAuthority, true, tokenOut1Program),
+                vaultTokenOut2Account: getAssociatedTokenAddressSync(params.tokenOutMint2, vaultAuthority, true, tokenOut2Program),
+                tokenInOracle: params.tokenInOracle ?? null,
+                navHistory: params.navHistory ?? null,
+                redemptionOffer: params.redemptionOffer ?? null,
+                redemptionVaultTokenInAccount: params.redemptionVaultTokenInAccount ?? null,
+                tokenInHookApproval: params.tokenInHookApproval ?? null
+            })
+            .remainingAccounts(params.transferHookAccounts ?? []);
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+    async offerVaultDeposit(params: {
+        amount: number,
+        tokenMint: PublicKey,
+        offer?: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = params.offer ? await this.getOfferVaultAuthority(params.offer) : this.pdas.offerVaultAuthorityPda;
+        const tx = this.program.methods
+            .offerVaultDeposit(new BN(params.amount))
+            .accounts({
+                offer: params.offer ?? null,
+                tokenMint: params.tokenMint,
+                tokenProgram,
+                vaultAuthority,
+                vaultTokenAccount: getAssociatedTokenAddressSync(params.tokenMint, vaultAuthority, true, tokenProgram)
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async offerVaultWithdraw(params: {
+        amount: number,
+        tokenMint: PublicKey,
+        offer?: PublicKey,
+        destination?: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = params.offer ? await this.getOfferVaultAuthority(params.offer) : this.pdas.offerVaultAuthorityPda;
+        const tx = this.program.methods
+            .offerVaultWithdraw(new BN(params.amount))
+            .accounts({
+                offer: params.offer ?? null,
+                tokenMint: params.tokenMint,
+                destination: params.destination ?? this.testHelper.getBoss(),
+                tokenProgram,
+                vaultAuthority,
+                vaultTokenAccount: getAssociatedTokenAddressSync(params.tokenMint, vaultAuthority, true, tokenProgram)
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async migrateOfferVault(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        tokenInAmount?: number,
+        tokenOutAmount?: number,
+        signer?: Keypair,
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey
+    }) {
+        const tx = this.program.methods
+            .migrateOfferVault(new BN(params.tokenInAmount ?? 0), new BN(params.tokenOutAmount ?? 0))
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async configureVaultWithdrawTimelock(params: {
+        tokenMint: PublicKey,
+        delaySecs: number,
+        immediateThreshold: number,
+        guardian?: Keypair,
+        signer?: Keypair
+    }) {
+        const tx = this.program.methods
+            .configureVaultWithdrawTimelock(new BN(params.delaySecs), new BN(params.immediateThreshold))
+            .accounts({
+                tokenMint: params.tokenMint,
+                guardian: params.guardian ? params.guardian.publicKey : null
+            });
+
+        const signers = [params.signer, params.guardian].filter((k): k is Keypair => !!k);
+        if (signers.length > 0) {
+            tx.signers(signers);
+        }
+
+        await tx.rpc();
+    }
+
+    async proposeVaultWithdraw(params: { amount: number, tokenMint: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .proposeVaultWithdraw(new BN(params.amount))
+            .accounts({
+                tokenMint: params.tokenMint
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async executeVaultWithdraw(params: {
+        tokenMint: PublicKey,
+        offer?: PublicKey,
+        destination?: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = params.offer ? await this.getOfferVaultAuthority(params.offer) : this.pdas.offerVaultAuthorityPda;
+        const tx = this.program.methods
+            .executeVaultWithdraw()
+            .accounts({
+                offer: params.offer ?? null,
+                tokenMint: params.tokenMint,
+                destination: params.destination ?? this.testHelper.getBoss(),
+                tokenProgram,
+                vaultAuthority,
+                vaultTokenAccount: getAssociatedTokenAddressSync(params.tokenMint, vaultAuthority, true, tokenProgram)
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async getVaultWithdrawProposal(tokenMint: PublicKey) {
+        const pda = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_withdraw_proposal"), tokenMint.toBuffer()],
+            ONREAPP_PROGRAM_ID
+        )[0];
+        return await this.program.account.vaultWithdrawProposal.fetch(pda);
+    }
+
+    async addWithdrawalDestination(params: { destination: PublicKey, guardian: Keypair, signer?: Keypair }) {
+        const tx = this.program.methods
+            .addWithdrawalDestination(params.destination)
+            .accounts({
+                guardian: params.guardian.publicKey
+            });
+
+        const signers = [params.signer, params.guardian].filter((k): k is Keypair => !!k);
+        tx.signers(signers);
+
+        await tx.rpc();
+    }
+
+    async removeWithdrawalDestination(params: { destination: PublicKey, guardian: Keypair, signer?: Keypair }) {
+        const tx = this.program.methods
+            .removeWithdrawalDestination(params.destination)
+            .accounts({
+                guardian: params.guardian.publicKey
+            });
+
+        const signers = [params.signer, params.guardian].filter((k): k is Keypair => !!k);
+        tx.signers(signers);
+
+        await tx.rpc();
+    }
+
+    async redemptionVaultDeposit(params: {
+        amount: number,
+        tokenMint: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tx = this.program.methods
+            .redemptionVaultDeposit(new BN(params.amount))
+            .accounts({
+                tokenMint: params.tokenMint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async executeRedemptionVaultWithdraw(params: {
+        tokenMint: PublicKey,
+        destination?: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tx = this.program.methods
+            .executeRedemptionVaultWithdraw()
+            .accounts({
+                tokenMint: params.tokenMint,
+                destination: params.destination ?? this.testHelper.getBoss(),
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async redemptionVaultWithdraw(params: {
+        amount: number,
+        tokenMint: PublicKey,
+        destination?: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const tx = this.program.methods
+            .redemptionVaultWithdraw(new BN(params.amount))
+            .accounts({
+                tokenMint: params.tokenMint,
+                destination: params.destination ?? this.testHelper.getBoss(),
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async sweepVaultDust(params: { vaultAccounts: PublicKey[], offer?: PublicKey, signer?: Keypair, tokenProgram?: PublicKey }) {
+        const tx = this.program.methods
+            .sweepVaultDust()
+            .accountsPartial({
+                offer: params.offer ?? null,
+                offerVaultAuthority: params.offer ? await this.getOfferVaultAuthority(params.offer) : this.pdas.offerVaultAuthorityPda,
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            })
+            .remainingAccounts(params.vaultAccounts.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })));
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async initializePermissionlessAuthority(params: { accountName: string, index?: number }) {
+        await this.program.methods
+            .initializePermissionlessAuthority(params.index ?? 1, params.accountName)
+            .accountsPartial({
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(params.index ?? 1)
+            })
+            .rpc();
+    }
+
+    async closeOffer(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        sweepMints?: PublicKey[],
+        tokenInProgram?: PublicKey,
+        tokenOutProgram?: PublicKey,
+        signer?: Keypair
+    }) {
+        const boss = params.signer?.publicKey ?? this.testHelper.payer.publicKey;
+        const offer = this.getOfferPda(params.tokenInMint, params.tokenOutMint);
+        const vaultAuthority = await this.getOfferVaultAuthority(offer);
+        const programOf = (mint: PublicKey) =>
+            (mint.equals(params.tokenInMint) ? params.tokenInProgram : params.tokenOutProgram) ?? TOKEN_PROGRAM_ID;
+        // An offer with its own vault has to sweep both of its mints unless told otherwise
+        const sweepMints = params.sweepMints
+            ?? (vaultAuthority.equals(this.pdas.offerVaultAuthorityPda) ? [] : [params.tokenInMint, params.tokenOutMint]);
+        const sweepAccounts = sweepMints.flatMap(mint => [
+            { pubkey: mint, isSigner: false, isWritable: false },
+            { pubkey: getAssociatedTokenAddressSync(mint, vaultAuthority, true, programOf(mint)), isSigner: false, isWritable: true },
+            { pubkey: getAssociatedTokenAddressSync(mint, boss, true, programOf(mint)), isSigner: false, isWritable: true },
+            {
+                pubkey: PublicKey.findProgramAddressSync([Buffer.from("vault_withdraw_timelock"), mint.toBuffer()], ONREAPP_PROGRAM_ID)[0],
+                isSigner: false,
+                isWritable: true
+            }
+        ]);
+
+        const tx = this.program.methods
+            .closeOffer()
+            .accountsPartial({
+                offer,
+                vaultAuthority,
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
+                boss
+            })
+            .remainingAccounts(sweepAccounts);
+
+        if (params.signer) {
             tx.signers([params.signer]);
         }
 
-        await tx.rpc();
+        await tx.rpc();
+    }
+
+    async closePermissionlessAuthority(params: { authorityIndex?: number, intermediaryAccounts: PublicKey[], signer?: Keypair }) {
+        const authorityIndex = params.authorityIndex ?? 1;
+        const tx = this.program.methods
+            .closePermissionlessAuthority(authorityIndex)
+            .accountsPartial({
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(authorityIndex),
+                boss: params.signer?.publicKey ?? this.testHelper.payer.publicKey
+            })
+            .remainingAccounts(params.intermediaryAccounts.map(pubkey => ({ pubkey, isSigner: false, isWritable: true })));
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async recoverIntermediaryFunds(params: {
+        authorityIndex?: number,
+        mint: PublicKey,
+        amount: number,
+        user: PublicKey,
+        destinationTokenAccount: PublicKey,
+        signer?: Keypair,
+        tokenProgram?: PublicKey
+    }) {
+        const authorityIndex = params.authorityIndex ?? 1;
+        const tx = this.program.methods
+            .recoverIntermediaryFunds(authorityIndex, new BN(params.amount))
+            .accountsPartial({
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(authorityIndex),
+                mint: params.mint,
+                user: params.user,
+                destinationTokenAccount: params.destinationTokenAccount,
+                signer: params.signer?.publicKey ?? this.testHelper.payer.publicKey,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setPermissionlessFee(params: { authorityIndex?: number, feeOverrideBps: number | null, signer?: Keypair }) {
+        const authorityIndex = params.authorityIndex ?? 1;
+        const tx = this.program.methods
+            .setPermissionlessFee(authorityIndex, params.feeOverrideBps)
+            .accountsPartial({
+                permissionlessAuthority: this.getPermissionlessAuthorityPda(authorityIndex),
+                boss: params.signer?.publicKey ?? this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async transferMintAuthorityToProgram(params: { mint: PublicKey, signer?: Keypair, tokenProgram?: PublicKey }) {
+        const tx = this.program.methods
+            .transferMintAuthorityToProgram()
+            .accounts({
+                mint: params.mint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async transferMintAuthorityToBoss(params: { mint: PublicKey, signer?: Keypair, tokenProgram?: PublicKey }) {
+        const tx = this.program.methods
+            .transferMintAuthorityToBoss()
+            .accounts({
+                mint: params.mint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async addAdmin(params: { admin: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .addAdmin(params.admin);
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async removeAdmin(params: { admin: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .removeAdmin(params.admin);
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async proposeBoss(params: { newBoss: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .proposeBoss(params.newBoss)
+            .accounts({
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async acceptBoss(params: { newBoss: Keypair }) {
+        const tx = this.program.methods
+            .acceptBoss()
+            .accounts({
+                newBoss: params.newBoss.publicKey
+            })
+            .signers([params.newBoss]);
+
+        await tx.rpc();
+    }
+
+    async setKillSwitch(params: { enable: boolean, duration?: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setKillSwitch(params.enable, params.duration !== undefined ? new BN(params.duration) : null)
+            .accounts({
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setOnycMint(params: { onycMint: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setOnycMint()
+            .accounts({
+                onycMint: params.onycMint
+            });
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setRedemptionAdmin(params: { redemptionAdmin: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setRedemptionAdmin(params.redemptionAdmin)
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setPrimaryOffer(params: { offer: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setPrimaryOffer()
+            .accounts({
+                offer: params.offer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setGuardian(params: { guardian: PublicKey, currentGuardian?: Keypair, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setGuardian(params.guardian)
+            .accounts({
+                currentGuardian: params.currentGuardian ? params.currentGuardian.publicKey : null
+            });
+
+        const signers = [params.signer, params.currentGuardian].filter((k): k is Keypair => !!k);
+        if (signers.length > 0) {
+            tx.signers(signers);
+        }
+
+        await tx.rpc();
+    }
+
+    async setJurisdictionBlocked(params: { jurisdiction: number, blocked: boolean, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setJurisdictionBlocked(params.jurisdiction, params.blocked)
+            .accounts({});
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async addBlockedAddress(params: { address: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .addBlockedAddress(params.address)
+            .accounts({
+                blocklistBucket: this.getBlocklistBucketPda(params.address),
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async removeBlockedAddress(params: { address: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .removeBlockedAddress(params.address)
+            .accounts({
+                blocklistBucket: this.getBlocklistBucketPda(params.address),
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setScreeningProgram(params: { screeningProgram: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .setScreeningProgram(params.screeningProgram)
+            .accounts({});
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async makeRedemptionOffer(params: {
+        offer: PublicKey;
+        feeBasisPoints?: number;
+        signer?: Keypair;
+        tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
+    }) {
+        // Fetch the offer to get token mints
+        const offer = await this.program.account.offer.fetch(params.offer);
+
+        const tx = this.program.methods
+            .makeRedemptionOffer(params.feeBasisPoints ?? 0)
+            .accounts({
+                tokenInMint: offer.tokenOutMint,
+                tokenOutMint: offer.tokenInMint,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async updateRedemptionOfferFee(params: {
+        redemptionOffer: PublicKey;
+        newFeeBasisPoints: number;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .updateRedemptionOfferFee(params.newFeeBasisPoints)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setRedemptionOfferFeeCurrency(params: {
+        redemptionOffer: PublicKey;
+        feeCurrency: "tokenIn" | "tokenOut";
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .setRedemptionOfferFeeCurrency({ [params.feeCurrency]: {} } as any)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async configureRedemptionBasket(params: {
+        redemptionOffer: PublicKey;
+        mints: PublicKey[];
+        weightsBps: number[];
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .configureRedemptionBasket(params.mints, params.weightsBps)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setRedemptionOfferInKind(params: {
+        redemptionOffer: PublicKey;
+        inKind: boolean;
+        withoutBasket?: boolean;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .setRedemptionOfferInKind(params.inKind)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                redemptionBasket: params.withoutBasket ? null : this.getRedemptionBasketPda(params.redemptionOffer),
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async setRedemptionOfferPause(params: {
+        redemptionOffer: PublicKey;
+        pauseNewRequests: boolean;
+        pauseFulfillment: boolean;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .setRedemptionOfferPause(params.pauseNewRequests, params.pauseFulfillment)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    getRedemptionBasketPda(redemptionOffer: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("redemption_basket"), redemptionOffer.toBuffer()],
+            this.program.programId
+        )[0];
+    }
+
+    async getRedemptionBasket(redemptionOffer: PublicKey) {
+        return await this.program.account.redemptionBasket.fetch(this.getRedemptionBasketPda(redemptionOffer));
+    }
+
+    async setRedemptionOfferPriceMode(params: {
+        redemptionOffer: PublicKey;
+        priceMode: "atFulfillment" | "lockedAtRequest";
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .setRedemptionOfferPriceMode({ [params.priceMode]: {} } as any)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async repriceRedemptionRequest(params: {
+        redemptionOffer: PublicKey;
+        requestId: number;
+        newNav: number;
+        guardian: Keypair;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .repriceRedemptionRequest(new BN(params.newNav))
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                redemptionRequest: this.getRedemptionRequestPda(params.redemptionOffer, params.requestId),
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey,
+                guardian: params.guardian.publicKey
+            });
+
+        const signers = [params.signer, params.guardian].filter((k): k is Keypair => !!k);
+        tx.signers(signers);
+
+        await tx.rpc();
+    }
+
+    async updateRedemptionOfferMinAmount(params: {
+        redemptionOffer: PublicKey;
+        minRedemptionAmount: number;
+        denomination: "token" | "quote";
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .updateRedemptionOfferMinAmount(new BN(params.minRedemptionAmount), { [params.denomination]: {} } as any)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async closeRedemptionOffer(params: {
+        redemptionOffer: PublicKey;
+        signer?: Keypair;
+        tokenOutProgram?: PublicKey;
+    }) {
+        const redemptionOffer = await this.program.account.redemptionOffer.fetch(params.redemptionOffer);
+
+        const tx = this.program.methods
+            .closeRedemptionOffer()
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: this.testHelper.getBoss(),
+                tokenOutMint: redemptionOffer.tokenOutMint,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async addRedemptionSettlementMint(params: {
+        redemptionOffer: PublicKey;
+        settlementMint: PublicKey;
+        signer?: Keypair;
+        tokenProgram?: PublicKey;
+    }) {
+        const tx = this.program.methods
+            .addRedemptionSettlementMint()
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                settlementMint: params.settlementMint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async removeRedemptionSettlementMint(params: {
+        redemptionOffer: PublicKey;
+        settlementMint: PublicKey;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .removeRedemptionSettlementMint(params.settlementMint)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async makeDualRedemptionOffer(params: {
+        tokenInMint: PublicKey;
+        tokenOutMint1: PublicKey;
+        tokenOutMint2: PublicKey;
+        tokenOut2BasisPoints: number;
+        signer?: Keypair;
+        tokenOut2Program?: PublicKey;
+    }) {
+        const tx = this.program.methods
+            .makeDualRedemptionOffer(params.tokenOut2BasisPoints)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint1: params.tokenOutMint1,
+                tokenOutMint2: params.tokenOutMint2,
+                tokenOut2Program: params.tokenOut2Program ?? TOKEN_PROGRAM_ID,
+                signer: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async takeDualRedemptionOffer(params: {
+        tokenInAmount: number;
+        tokenInMint: PublicKey;
+        tokenOutMint1: PublicKey;
+        tokenOutMint2: PublicKey;
+        user: Keypair;
+        tokenInProgram?: PublicKey;
+        tokenOut1Program?: PublicKey;
+        tokenOut2Program?: PublicKey;
+    }) {
+        const redemptionOfferPda = this.getRedemptionOfferPda(params.tokenInMint, params.tokenOutMint1);
+        const redemptionOffer = await this.program.account.redemptionOffer.fetch(redemptionOfferPda);
+
+        const tx = this.program.methods
+            .takeDualRedemptionOffer(new BN(params.tokenInAmount))
+            .accountsPartial({
+                dualRedemptionOffer: this.getDualRedemptionOfferPda(params.tokenInMint, params.tokenOutMint1, params.tokenOutMint2),
+                redemptionOffer: redemptionOfferPda,
+                offer: redemptionOffer.offer,
+                boss: this.testHelper.getBoss(),
+                tokenInMint: params.tokenInMint,
+                tokenOutMint1: params.tokenOutMint1,
+                tokenOutMint2: params.tokenOutMint2,
+                user: params.user.publicKey,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOut1Program: params.tokenOut1Program ?? TOKEN_PROGRAM_ID,
+                tokenOut2Program: params.tokenOut2Program ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.user]);
+
+        await tx.rpc();
+    }
+
+    async setRedemptionOfferInstant(params: {
+        redemptionOffer: PublicKey;
+        allowInstant: boolean;
+        instantFeeBasisPoints: number;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .setRedemptionOfferInstant(params.allowInstant, params.instantFeeBasisPoints)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async takeRedemptionInstant(params: {
+        tokenInAmount: number;
+        tokenInMint: PublicKey;
+        tokenOutMint: PublicKey;
+        user: Keypair;
+        redemptionOfferTokenOutMint?: PublicKey;
+        tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
+        bossTokenOutAccount?: PublicKey;
+    }) {
+        const redemptionOfferPda = this.getRedemptionOfferPda(
+            params.tokenInMint,
+            params.redemptionOfferTokenOutMint ?? params.tokenOutMint
+        );
+        const redemptionOffer = await this.program.account.redemptionOffer.fetch(redemptionOfferPda);
+
+        const tx = this.program.methods
+            .takeRedemptionInstant(new BN(params.tokenInAmount))
+            .accountsPartial({
+                redemptionOffer: redemptionOfferPda,
+                offer: redemptionOffer.offer,
+                boss: this.testHelper.getBoss(),
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                user: params.user.publicKey,
+                bossTokenOutAccount: params.bossTokenOutAccount ?? null,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.user]);
+
+        await tx.rpc();
+    }
+
+    async closeDualRedemptionOffer(params: {
+        dualRedemptionOffer: PublicKey;
+        signer?: Keypair;
+    }) {
+        const tx = this.program.methods
+            .closeDualRedemptionOffer()
+            .accountsPartial({
+                dualRedemptionOffer: params.dualRedemptionOffer,
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async mintTo(params: { amount: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .mintTo(new BN(params.amount))
+            .accounts({
+                tokenProgram: TOKEN_PROGRAM_ID
+            });
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async configureMintDelegate(params: { delegateProgram: PublicKey, mintCap: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .configureMintDelegate(params.delegateProgram, new BN(params.mintCap))
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async removeMintDelegate(params: { delegateProgram: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .removeMintDelegate()
+            .accounts({
+                mintDelegate: this.getMintDelegatePda(params.delegateProgram)
+            });
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async mintToViaDelegate(params: {
+        amount: number,
+        delegateProgram: PublicKey,
+        delegateAuthority: Keypair,
+        onycMint: PublicKey,
+        destination: PublicKey,
+        tokenProgram?: PublicKey
+    }) {
+        await this.program.methods
+            .mintToViaDelegate(new BN(params.amount))
+            .accounts({
+                mintDelegate: this.getMintDelegatePda(params.delegateProgram),
+                delegateAuthority: params.delegateAuthority.publicKey,
+                onycMint: params.onycMint,
+                destination: params.destination,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.delegateAuthority])
+            .rpc();
+    }
+
+    getMintDelegatePda(delegateProgram: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("mint_delegate"), delegateProgram.toBuffer()],
+            ONREAPP_PROGRAM_ID
+        )[0];
+    }
+
+    async getMintDelegate(delegateProgram: PublicKey) {
+        return await this.program.account.mintDelegate.fetch(this.getMintDelegatePda(delegateProgram));
+    }
+
+    async addManagedMint(params: { mint: PublicKey, maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .addManagedMint(new BN(params.maxSupply))
+            .accounts({
+                mint: params.mint
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async updateManagedMint(params: { mint: PublicKey, maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .updateManagedMint(new BN(params.maxSupply))
+            .accountsPartial({
+                managedMint: this.getManagedMintPda(params.mint),
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async removeManagedMint(params: { mint: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .removeManagedMint()
+            .accounts({
+                managedMint: this.getManagedMintPda(params.mint)
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async mintManagedTo(params: { mint: PublicKey, amount: number, tokenProgram?: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .mintManagedTo(new BN(params.amount))
+            .accounts({
+                mint: params.mint,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    getManagedMintPda(mint: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("managed_mint"), mint.toBuffer()],
+            ONREAPP_PROGRAM_ID
+        )[0];
+    }
+
+    async getManagedMint(mint: PublicKey) {
+        return await this.program.account.managedMint.fetch(this.getManagedMintPda(mint));
+    }
+
+    async addShareClass(params: { mint: PublicKey, conversionRate: number, maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .addShareClass(new BN(params.conversionRate), new BN(params.maxSupply))
+            .accounts({
+                mint: params.mint
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async updateShareClass(params: { mint: PublicKey, conversionRate: number, maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .updateShareClass(new BN(params.conversionRate), new BN(params.maxSupply))
+            .accountsPartial({
+                shareClass: this.getShareClassPda(params.mint),
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async convertShareClass(params: {
+        sourceMint: PublicKey,
+        destinationMint: PublicKey,
+        amount: number,
+        user: Keypair,
+        sourceTokenProgram?: PublicKey,
+        destinationTokenProgram?: PublicKey
+    }) {
+        await this.program.methods
+            .convertShareClass(new BN(params.amount))
+            .accounts({
+                sourceMint: params.sourceMint,
+                destinationMint: params.destinationMint,
+                user: params.user.publicKey,
+                sourceTokenProgram: params.sourceTokenProgram ?? TOKEN_PROGRAM_ID,
+                destinationTokenProgram: params.destinationTokenProgram ?? TOKEN_PROGRAM_ID
+            })
+            .signers([params.user])
+            .rpc();
+    }
+
+    async configureMaxSupply(params: { maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .configureMaxSupply(new BN(params.maxSupply));
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async proposeMaxSupply(params: { maxSupply: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .proposeMaxSupply(new BN(params.maxSupply))
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async acceptMaxSupply(params?: { signer?: Keypair }) {
+        const tx = this.program.methods
+            .acceptMaxSupply()
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    getCloseStateProposalPda() {
+        return PublicKey.findProgramAddressSync([Buffer.from("close_state_proposal")], ONREAPP_PROGRAM_ID)[0];
+    }
+
+    getMaxSupplyProposalPda() {
+        return PublicKey.findProgramAddressSync([Buffer.from("max_supply_proposal")], ONREAPP_PROGRAM_ID)[0];
+    }
+
+    async configureMintThrottle(params: {
+        maxMintPerWindow: number,
+        windowSecs: number,
+        guardian?: Keypair,
+        signer?: Keypair
+    }) {
+        const tx = this.program.methods
+            .configureMintThrottle(new BN(params.maxMintPerWindow), new BN(params.windowSecs))
+            .accounts({
+                guardian: params.guardian ? params.guardian.publicKey : null
+            });
+
+        const signers = [params.signer, params.guardian].filter((k): k is Keypair => !!k);
+        if (signers.length > 0) {
+            tx.signers(signers);
+        }
+
+        await tx.rpc();
+    }
+
+    async getMintThrottle() {
+        const pda = PublicKey.findProgramAddressSync([Buffer.from("mint_throttle")], ONREAPP_PROGRAM_ID)[0];
+        return await this.program.account.mintThrottle.fetch(pda);
+    }
+
+    async configureMaxNavJump(params: { maxNavJumpBps: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .configureMaxNavJump(params.maxNavJumpBps);
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async approveTransferHookMint(params: { mint: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .approveTransferHookMint()
+            .accounts({
+                mint: params.mint
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async revokeTransferHookMint(params: { mint: PublicKey, signer?: Keypair }) {
+        const tx = this.program.methods
+            .revokeTransferHookMint()
+            .accounts({
+                approval: this.getTransferHookMintApprovalPda(params.mint)
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    getTransferHookMintApprovalPda(mint: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("transfer_hook_mint"), mint.toBuffer()],
+            ONREAPP_PROGRAM_ID
+        )[0];
+    }
+
+    async getTransferHookMintApproval(mint: PublicKey) {
+        return await this.program.account.transferHookMintApproval.fetch(this.getTransferHookMintApprovalPda(mint));
+    }
+
+    async configureRedemptionStress(params: {
+        thresholdBps: number,
+        stressedTakeMaxTokenIn: number,
+        signer?: Keypair
+    }) {
+        const tx = this.program.methods
+            .configureRedemptionStress(params.thresholdBps, new BN(params.stressedTakeMaxTokenIn));
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async migrateState(params: { targetVersion: number, signer?: Keypair }) {
+        const tx = this.program.methods
+            .migrateState(params.targetVersion)
+            .accounts({
+                boss: params.signer ? params.signer.publicKey : this.testHelper.payer.publicKey
+            });
+
+        if (params.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async proposeCloseState(params?: { signer?: Keypair }) {
+        const tx = this.program.methods
+            .proposeCloseState()
+            .accounts({});
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async closeState(params?: { offers?: PublicKey[], signer?: Keypair }) {
+        const remainingAccounts = (params?.offers ?? []).map(pubkey => ({ pubkey, isSigner: false, isWritable: false }));
+        const tx = this.program.methods
+            .closeState().accounts({
+                state: this.pdas.statePda
+            })
+            .remainingAccounts(remainingAccounts);
+
+        if (params?.signer) {
+            tx.signers([params.signer]);
+        }
+
+        await tx.rpc();
+    }
+
+    async getNAV(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<number> {
+        const tx = await this.program.methods
+            .getNav()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const meta = result.meta();
+        const returnData = meta.returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getNAV`);
+        }
+
+        // Parse the return data as u64 (8 bytes, little-endian)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        const nav = Number(view.getBigUint64(0, true));
+
+        return nav;
+    }
+
+    async getNAVAt(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey, unixTs: number }): Promise<number> {
+        const tx = await this.program.methods
+            .getNavAt(new BN(params.unixTs))
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const returnData = result.meta().returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getNAVAt`);
+        }
+
+        // Parse the return data as u64 (8 bytes, little-endian)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        return Number(view.getBigUint64(0, true));
+    }
+
+    async getAPY(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        compoundingPeriodsPerYear?: number
+    }): Promise<number> {
+        const tx = await this.program.methods
+            .getApy(params.compoundingPeriodsPerYear ?? null)
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const meta = result.meta();
+        const returnData = meta.returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getAPY`);
+        }
+
+        // Parse the return data as i64 (8 bytes, little-endian)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        const apy = Number(view.getBigInt64(0, true));
+
+        return apy;
+    }
+
+    async getNavAdjustment(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<number> {
+        const tx = await this.program.methods
+            .getNavAdjustment()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const meta = result.meta();
+        const returnData = meta.returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getNavAdjustment`);
+        }
+
+        // Parse the return data as i64 (8 bytes, little-endian, signed)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        const adjustment = Number(view.getBigInt64(0, true));
+
+        return adjustment;
+    }
+
+    async getTVL(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        tokenOutProgram?: PublicKey
+    }): Promise<BN> {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
+        const tx = await this.program.methods
+            .getTvl()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint,
+                tokenOutProgram: tokenOutProgram,
+                vaultAuthority,
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram)
+            })
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const meta = result.meta();
+        const returnData = meta.returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getTVL`);
+        }
+
+        // Parse the return data as u64 (8 bytes, little-endian)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        const tvl = view.getBigUint64(0, true);
+
+        return new BN(tvl.toString());
+    }
+
+    // Remaining accounts excluding the ONyc of per-offer vaults: the offer and its own vault's ONyc account each
+    ownVaultOnycAccounts(onycMint: PublicKey, ownVaults: { offer: PublicKey, vaultAccount?: PublicKey }[], tokenProgram: PublicKey) {
+        return ownVaults.flatMap(({ offer, vaultAccount }) => [
+            { pubkey: offer, isSigner: false, isWritable: false },
+            {
+                pubkey: vaultAccount ?? getAssociatedTokenAddressSync(onycMint, this.getOwnOfferVaultAuthorityPda(offer), true, tokenProgram),
+                isSigner: false,
+                isWritable: false
+            }
+        ]);
+    }
+
+    async getCirculatingSupply(params: {
+        onycMint: PublicKey,
+        ownVaults?: { offer: PublicKey, vaultAccount?: PublicKey }[],
+        tokenOutProgram?: PublicKey
+    }): Promise<BN> {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+
+        const tx = await this.program.methods
+            .getCirculatingSupply()
+            .accounts({
+                tokenProgram: tokenOutProgram,
+                onycVaultAccount: getAssociatedTokenAddressSync(params.onycMint, this.pdas.offerVaultAuthorityPda, true, tokenOutProgram),
+                redemptionVaultOnycAccount: getAssociatedTokenAddressSync(params.onycMint, this.pdas.redemptionVaultAuthorityPda, true, tokenOutProgram)
+            })
+            .remainingAccounts(this.ownVaultOnycAccounts(params.onycMint, params.ownVaults ?? [], tokenOutProgram))
+            .transaction();
+
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const meta = result.meta();
+        const returnData = meta.returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from getCirculatingSupply`);
+        }
+
+        // Parse the return data as u64 (8 bytes, little-endian)
+        const data = returnData.data();
+        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+        const circulatingSupply = view.getBigUint64(0, true);
+
+        return new BN(circulatingSupply.toString());
+    }
+
+    async getGlobalTVL(params: {
+        onycMint: PublicKey,
+        primaryOffer: PublicKey,
+        ownVaults?: { offer: PublicKey, vaultAccount?: PublicKey }[],
+        tokenProgram?: PublicKey
+    }): Promise<bigint> {
+        const tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
+        const tx = await this.program.methods
+            .getGlobalTvl()
+            .accounts({
+                offer: params.primaryOffer,
+                tokenProgram,
+                offerVaultOnycAccount: getAssociatedTokenAddressSync(params.onycMint, this.pdas.offerVaultAuthorityPda, true, tokenProgram),
+                redemptionVaultOnycAccount: getAssociatedTokenAddressSync(params.onycMint, this.pdas.redemptionVaultAuthorityPda, true, tokenProgram)
+            })
+            .remainingAccounts(this.ownVaultOnycAccounts(params.onycMint, params.ownVaults ?? [], tokenProgram))
+            .transaction();
+
+        const data = Buffer.from(this.simulateView(tx, "getGlobalTvl"));
+
+        // Parse the return data as little-endian u128
+        return data.readBigUInt64LE(0) + (data.readBigUInt64LE(8) << BigInt(64));
+    }
+
+    async getOfferStats(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<{ totalFeesCollectedTokenIn: bigint, totalVolumeTokenIn: bigint }> {
+        const tx = await this.program.methods
+            .getOfferStats()
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        const data = Buffer.from(this.simulateView(tx, "getOfferStats"));
+
+        // Parse the return data as Borsh OfferStats (two little-endian u128)
+        const readU128 = (offset: number) =>
+            data.readBigUInt64LE(offset) + (data.readBigUInt64LE(offset + 8) << BigInt(64));
+
+        return {
+            totalFeesCollectedTokenIn: readU128(0),
+            totalVolumeTokenIn: readU128(16)
+        };
+    }
+
+    private simulateView(tx: Transaction, name: string): Uint8Array {
+        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
+        tx.feePayer = this.testHelper.payer.publicKey;
+        tx.sign(this.testHelper.payer);
+
+        const result = this.testHelper.svm.simulateTransaction(tx);
+
+        // Check for errors
+        parseViewError(result);
+
+        const returnData = result.meta().returnData();
+
+        if (!returnData || returnData.data().length === 0) {
+            throw new Error(`No return data from ${name}`);
+        }
+
+        return returnData.data();
+    }
+
+    async getAdmins(page: number = 0): Promise<{ page: number, totalAdmins: number, admins: PublicKey[], hasMore: boolean }> {
+        const tx = await this.program.methods
+            .getAdmins(page)
+            .accounts({})
+            .transaction();
+
+        const data = Buffer.from(this.simulateView(tx, "getAdmins"));
+
+        // Parse the return data as Borsh AdminsPage (page u8, total u8, Vec<Pubkey>, has_more bool)
+        const count = data.readUInt32LE(2);
+        const admins: PublicKey[] = [];
+        for (let i = 0; i < count; i++) {
+            admins.push(new PublicKey(data.subarray(6 + i * 32, 6 + (i + 1) * 32)));
+        }
+
+        return {
+            page: data.readUInt8(0),
+            totalAdmins: data.readUInt8(1),
+            admins,
+            hasMore: data.readUInt8(6 + count * 32) === 1
+        };
+    }
+
+    async getTWAP(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey, windowSecs: number }): Promise<number> {
+        const tx = await this.program.methods
+            .getTwap(new BN(params.windowSecs))
+            .accounts({
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
+            })
+            .transaction();
+
+        const data = Buffer.from(this.simulateView(tx, "getTwap"));
+
+        return Number(data.readBigUInt64LE(0));
     }
 
-    async getNAV(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<number> {
+    async snapshotMarketInfo(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        tokenOutProgram?: PublicKey
+    }) {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
         const tx = await this.program.methods
-            .getNav()
+            .snapshotMarketInfo()
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint
+                tokenOutMint: params.tokenOutMint,
+                tokenOutProgram: tokenOutProgram,
+                vaultAuthority,
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram)
             })
             .transaction();
 
@@ -537,32 +2805,38 @@ export class OnreProgram {
         tx.feePayer = this.testHelper.payer.publicKey;
         tx.sign(this.testHelper.payer);
 
-        const result = this.testHelper.svm.simulateTransaction(tx);
-
-        // Check for errors
+        const result = this.testHelper.svm.sendTransaction(tx);
         parseViewError(result);
 
-        const meta = result.meta();
-        const returnData = meta.returnData();
-
-        if (!returnData || returnData.data().length === 0) {
-            throw new Error(`No return data from getNAV`);
+        // Decode the MarketSnapshotEvent from the "Program data:" log line
+        for (const log of result.logs()) {
+            if (!log.startsWith("Program data: ")) continue;
+            const event = this.program.coder.events.decode(log.slice("Program data: ".length));
+            if (event?.name.toLowerCase() === "marketsnapshotevent") {
+                return event.data as any;
+            }
         }
 
-        // Parse the return data as u64 (8 bytes, little-endian)
-        const data = returnData.data();
-        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
-        const nav = Number(view.getBigUint64(0, true));
-
-        return nav;
+        throw new Error("No MarketSnapshotEvent emitted");
     }
 
-    async getAPY(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<number> {
+    async getVaultRunway(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        tokenOutProgram?: PublicKey,
+        tokenInOracle?: PublicKey
+    }) {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const vaultAuthority = await this.getOfferVaultAuthority(this.getOfferPda(params.tokenInMint, params.tokenOutMint));
         const tx = await this.program.methods
-            .getApy()
+            .getVaultRunway()
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint
+                tokenOutMint: params.tokenOutMint,
+                tokenOutProgram: tokenOutProgram,
+                vaultAuthority,
+                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, vaultAuthority, true, tokenOutProgram),
+                tokenInOracle: params.tokenInOracle ?? null
             })
             .transaction();
 
@@ -570,32 +2844,34 @@ export class OnreProgram {
         tx.feePayer = this.testHelper.payer.publicKey;
         tx.sign(this.testHelper.payer);
 
-        const result = this.testHelper.svm.simulateTransaction(tx);
-
-        // Check for errors
+        const result = this.testHelper.svm.sendTransaction(tx);
         parseViewError(result);
 
-        const meta = result.meta();
-        const returnData = meta.returnData();
-
-        if (!returnData || returnData.data().length === 0) {
-            throw new Error(`No return data from getAPY`);
+        // Decode the GetVaultRunwayEvent from the "Program data:" log line
+        for (const log of result.logs()) {
+            if (!log.startsWith("Program data: ")) continue;
+            const event = this.program.coder.events.decode(log.slice("Program data: ".length));
+            if (event?.name.toLowerCase() === "getvaultrunwayevent") {
+                return event.data as any;
+            }
         }
 
-        // Parse the return data as u64 (8 bytes, little-endian)
-        const data = returnData.data();
-        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
-        const apy = Number(view.getBigUint64(0, true));
-
-        return apy;
+        throw new Error("No GetVaultRunwayEvent emitted");
     }
 
-    async getNavAdjustment(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }): Promise<number> {
+    async getUserPositionView(params: {
+        tokenInMint: PublicKey,
+        tokenOutMint: PublicKey,
+        user: PublicKey,
+        tokenInOracle?: PublicKey
+    }) {
         const tx = await this.program.methods
-            .getNavAdjustment()
+            .getUserPosition()
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint
+                tokenOutMint: params.tokenOutMint,
+                user: params.user,
+                tokenInOracle: params.tokenInOracle ?? null
             })
             .transaction();
 
@@ -603,105 +2879,206 @@ export class OnreProgram {
         tx.feePayer = this.testHelper.payer.publicKey;
         tx.sign(this.testHelper.payer);
 
-        const result = this.testHelper.svm.simulateTransaction(tx);
-
-        // Check for errors
+        const result = this.testHelper.svm.sendTransaction(tx);
         parseViewError(result);
 
-        const meta = result.meta();
-        const returnData = meta.returnData();
-
-        if (!returnData || returnData.data().length === 0) {
-            throw new Error(`No return data from getNavAdjustment`);
+        // Decode the GetUserPositionEvent from the "Program data:" log line
+        for (const log of result.logs()) {
+            if (!log.startsWith("Program data: ")) continue;
+            const event = this.program.coder.events.decode(log.slice("Program data: ".length));
+            if (event?.name.toLowerCase() === "getuserpositionevent") {
+                return (event.data as any).position;
+            }
         }
 
-        // Parse the return data as i64 (8 bytes, little-endian, signed)
-        const data = returnData.data();
-        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
-        const adjustment = Number(view.getBigInt64(0, true));
+        throw new Error("No GetUserPositionEvent emitted");
+    }
 
-        return adjustment;
+    async getRegistry(page: number = 0): Promise<{ page: number, totalOffers: number, offers: PublicKey[], hasMore: boolean }> {
+        const tx = await this.program.methods
+            .getRegistry(page)
+            .accounts({})
+            .transaction();
+
+        const data = Buffer.from(this.simulateView(tx, "getRegistry"));
+
+        // Parse the return data as Borsh OfferRegistryPage (page u8, total u8, Vec<Pubkey>, has_more bool)
+        const count = data.readUInt32LE(2);
+        const offers: PublicKey[] = [];
+        for (let i = 0; i < count; i++) {
+            offers.push(new PublicKey(data.subarray(6 + i * 32, 6 + (i + 1) * 32)));
+        }
+
+        return {
+            page: data.readUInt8(0),
+            totalOffers: data.readUInt8(1),
+            offers,
+            hasMore: data.readUInt8(6 + count * 32) === 1
+        };
     }
 
-    async getTVL(params: {
-        tokenInMint: PublicKey,
-        tokenOutMint: PublicKey,
-        tokenOutProgram?: PublicKey
-    }): Promise<BN> {
-        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+    async getOfferVectors(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey, page?: number }): Promise<{
+        version: number,
+        page: number,
+        totalVectors: number,
+        vectors: { startTime: number, baseTime: number, basePrice: number, apr: number, priceFixDuration: number, pricingMode: number }[],
+        hasMore: boolean
+    }> {
         const tx = await this.program.methods
-            .getTvl()
+            .getOfferVectors(params.page ?? 0)
             .accounts({
                 tokenInMint: params.tokenInMint,
-                tokenOutMint: params.tokenOutMint,
-                tokenOutProgram: tokenOutProgram,
-                vaultTokenOutAccount: getAssociatedTokenAddressSync(params.tokenOutMint, this.pdas.offerVaultAuthorityPda, true, tokenOutProgram)
+                tokenOutMint: params.tokenOutMint
             })
             .transaction();
 
-        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
-        tx.feePayer = this.testHelper.payer.publicKey;
-        tx.sign(this.testHelper.payer);
+        const data = Buffer.from(this.simulateView(tx, "getOfferVectors"));
+
+        // Parse the return data as Borsh OfferVectorsPage (version u8, page u8, total u8, Vec<OfferVectorInfo>, has_more bool)
+        const VECTOR_INFO_SIZE = 41;
+        const count = data.readUInt32LE(3);
+        const vectors = [];
+        for (let i = 0; i < count; i++) {
+            const offset = 7 + i * VECTOR_INFO_SIZE;
+            vectors.push({
+                startTime: Number(data.readBigUInt64LE(offset)),
+                baseTime: Number(data.readBigUInt64LE(offset + 8)),
+                basePrice: Number(data.readBigUInt64LE(offset + 16)),
+                apr: Number(data.readBigInt64LE(offset + 24)),
+                priceFixDuration: Number(data.readBigUInt64LE(offset + 32)),
+                pricingMode: data.readUInt8(offset + 40)
+            });
+        }
 
-        const result = this.testHelper.svm.simulateTransaction(tx);
+        return {
+            version: data.readUInt8(0),
+            page: data.readUInt8(1),
+            totalVectors: data.readUInt8(2),
+            vectors,
+            hasMore: data.readUInt8(7 + count * VECTOR_INFO_SIZE) === 1
+        };
+    }
 
-        // Check for errors
-        parseViewError(result);
+    async getApprovers(): Promise<PublicKey[]> {
+        const tx = await this.program.methods
+            .getApprovers()
+            .accounts({})
+            .transaction();
 
-        const meta = result.meta();
-        const returnData = meta.returnData();
+        const data = Buffer.from(this.simulateView(tx, "getApprovers"));
 
-        if (!returnData || returnData.data().length === 0) {
-            throw new Error(`No return data from getTVL`);
+        // Parse the return data as Borsh Vec<Pubkey> (u32 length prefix followed by 32-byte keys)
+        const count = data.readUInt32LE(0);
+        const approvers: PublicKey[] = [];
+        for (let i = 0; i < count; i++) {
+            approvers.push(new PublicKey(data.subarray(4 + i * 32, 4 + (i + 1) * 32)));
         }
 
-        // Parse the return data as u64 (8 bytes, little-endian)
-        const data = returnData.data();
-        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
-        const tvl = view.getBigUint64(0, true);
-
-        return new BN(tvl.toString());
+        return approvers;
     }
 
-    async getCirculatingSupply(params: {
-        onycMint: PublicKey,
-        tokenOutProgram?: PublicKey
-    }): Promise<BN> {
-        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
-
+    async viewOffer(params: { tokenInMint: PublicKey, tokenOutMint: PublicKey }) {
         const tx = await this.program.methods
-            .getCirculatingSupply()
+            .viewOffer()
             .accounts({
-                tokenProgram: tokenOutProgram,
-                onycVaultAccount: getAssociatedTokenAddressSync(params.onycMint, this.pdas.offerVaultAuthorityPda, true, tokenOutProgram)
+                tokenInMint: params.tokenInMint,
+                tokenOutMint: params.tokenOutMint
             })
             .transaction();
 
-        tx.recentBlockhash = this.testHelper.svm.latestBlockhash();
-        tx.feePayer = this.testHelper.payer.publicKey;
-        tx.sign(this.testHelper.payer);
+        const data = Buffer.from(this.simulateView(tx, "viewOffer"));
 
-        const result = this.testHelper.svm.simulateTransaction(tx);
+        // OfferView is part of the IDL, so the coder decodes it directly
+        return this.program.coder.types.decode("offerView", data);
+    }
 
-        // Check for errors
-        parseViewError(result);
+    async viewState() {
+        const tx = await this.program.methods
+            .viewState()
+            .accounts({})
+            .transaction();
 
-        const meta = result.meta();
-        const returnData = meta.returnData();
+        const data = Buffer.from(this.simulateView(tx, "viewState"));
 
-        if (!returnData || returnData.data().length === 0) {
-            throw new Error(`No return data from getCirculatingSupply`);
-        }
+        return this.program.coder.types.decode("stateView", data);
+    }
 
-        // Parse the return data as u64 (8 bytes, little-endian)
-        const data = returnData.data();
-        const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
-        const circulatingSupply = view.getBigUint64(0, true);
+    // Accounts
+    async getDualOffer(tokenInMint: PublicKey, tokenOutMint1: PublicKey, tokenOutMint2: PublicKey) {
+        return await this.program.account.dualOffer.fetch(this.getDualOfferPda(tokenInMint, tokenOutMint1, tokenOutMint2));
+    }
 
-        return new BN(circulatingSupply.toString());
+    getDualOfferPda(tokenInMint: PublicKey, tokenOutMint1: PublicKey, tokenOutMint2: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("dual_offer"), tokenInMint.toBuffer(), tokenOutMint1.toBuffer(), tokenOutMint2.toBuffer()], this.program.programId)[0];
+    }
+
+    async getClawbackRecord(takeReceipt: PublicKey) {
+        return await this.program.account.clawbackRecord.fetch(this.getClawbackRecordPda(takeReceipt));
+    }
+
+    getClawbackRecordPda(takeReceipt: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("clawback_record"), takeReceipt.toBuffer()],
+            this.program.programId
+        )[0];
+    }
+
+    async getTakeReceipt(takeReceipt: PublicKey) {
+        return await this.program.account.takeReceipt.fetch(takeReceipt);
+    }
+
+    getTakeReceiptPda(offer: PublicKey, user: PublicKey, index: number) {
+        return PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("take_receipt"),
+                offer.toBuffer(),
+                user.toBuffer(),
+                new BN(index).toArrayLike(Buffer, "le", 8)
+            ],
+            this.program.programId
+        )[0];
+    }
+
+    // The next receipt is indexed by the receipt count of the user's token_out position
+    async getNextTakeReceiptPda(tokenInMint: PublicKey, tokenOutMint: PublicKey, user: PublicKey) {
+        const position = await this.program.account.userPosition.fetchNullable(this.getUserPositionPda(tokenOutMint, user));
+        const index = position ? position.takeReceiptCount.toNumber() : 0;
+        return this.getTakeReceiptPda(this.getOfferPda(tokenInMint, tokenOutMint), user, index);
+    }
+
+    async getNavHistory(offer: PublicKey) {
+        return await this.program.account.navHistory.fetch(this.getNavHistoryPda(offer));
+    }
+
+    getNavHistoryPda(offer: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("nav_history"), offer.toBuffer()],
+            this.program.programId
+        )[0];
+    }
+
+    async getUserPosition(mint: PublicKey, user: PublicKey) {
+        return await this.program.account.userPosition.fetch(this.getUserPositionPda(mint, user));
+    }
+
+    getBlocklistBucketPda(address: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("blocklist"), Buffer.from([address.toBytes()[0] % 32])],
+            this.program.programId
+        )[0];
+    }
+
+    async getBlocklistBucket(address: PublicKey) {
+        return await this.program.account.blocklistBucket.fetch(this.getBlocklistBucketPda(address));
+    }
+
+    getUserPositionPda(mint: PublicKey, user: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("user_position"), mint.toBuffer(), user.toBuffer()],
+            this.program.programId
+        )[0];
     }
 
-    // Accounts
     async getOffer(tokenInMint: PublicKey, tokenOutMint: PublicKey) {
         return await this.program.account.offer.fetch(this.getOfferPda(tokenInMint, tokenOutMint));
     }
@@ -710,6 +3087,37 @@ export class OnreProgram {
         return PublicKey.findProgramAddressSync([Buffer.from("offer"), tokenInMint.toBuffer(), tokenOutMint.toBuffer()], this.program.programId)[0];
     }
 
+    getOwnOfferVaultAuthorityPda(offerPda: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("offer_vault_authority"), offerPda.toBuffer()], this.program.programId)[0];
+    }
+
+    // The vault authority of a token pair's offer, which every offer made since per-offer vaults owns
+    getOfferVaultAuthorityPda(tokenInMint: PublicKey, tokenOutMint: PublicKey) {
+        return this.getOwnOfferVaultAuthorityPda(this.getOfferPda(tokenInMint, tokenOutMint));
+    }
+
+    // Puts an offer back on the shared offer vault, as if it was made before per-offer vaults
+    async moveOfferToSharedVault(offerPda: PublicKey) {
+        const account = await this.testHelper.getAccountInfo(offerPda);
+        const data = Buffer.from(account!.data);
+        data.writeUInt8(0, OFFER_OWN_VAULT_OFFSET);
+        this.testHelper.setAccount(offerPda, { ...account!, data });
+    }
+
+    // The vault authority serving an offer: its own once made or migrated so, the shared one otherwise
+    async getOfferVaultAuthority(offerPda: PublicKey) {
+        const offer = await this.program.account.offer.fetchNullable(offerPda);
+        return offer?.ownVault ? this.getOwnOfferVaultAuthorityPda(offerPda) : this.pdas.offerVaultAuthorityPda;
+    }
+
+    async getOfferVersionRecord(tokenInMint: PublicKey, tokenOutMint: PublicKey) {
+        return await this.program.account.offerVersionRecord.fetch(this.getOfferVersionRecordPda(tokenInMint, tokenOutMint));
+    }
+
+    getOfferVersionRecordPda(tokenInMint: PublicKey, tokenOutMint: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("offer_version"), tokenInMint.toBuffer(), tokenOutMint.toBuffer()], this.program.programId)[0];
+    }
+
     async getRedemptionOffer(tokenInMint: PublicKey, tokenOutMint: PublicKey) {
         return await this.program.account.redemptionOffer.fetch(this.getRedemptionOfferPda(tokenInMint, tokenOutMint));
     }
@@ -718,12 +3126,28 @@ export class OnreProgram {
         return PublicKey.findProgramAddressSync([Buffer.from("redemption_offer"), tokenInMint.toBuffer(), tokenOutMint.toBuffer()], this.program.programId)[0];
     }
 
+    async getDualRedemptionOffer(tokenInMint: PublicKey, tokenOutMint1: PublicKey, tokenOutMint2: PublicKey) {
+        return await this.program.account.dualRedemptionOffer.fetch(this.getDualRedemptionOfferPda(tokenInMint, tokenOutMint1, tokenOutMint2));
+    }
+
+    getDualRedemptionOfferPda(tokenInMint: PublicKey, tokenOutMint1: PublicKey, tokenOutMint2: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("dual_redemption_offer"), tokenInMint.toBuffer(), tokenOutMint1.toBuffer(), tokenOutMint2.toBuffer()], this.program.programId)[0];
+    }
+
+    async getShareClass(mint: PublicKey) {
+        return await this.program.account.shareClass.fetch(this.getShareClassPda(mint));
+    }
+
+    getShareClassPda(mint: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("share_class"), mint.toBuffer()], this.program.programId)[0];
+    }
+
     async getState() {
         return await this.program.account.state.fetch(this.pdas.statePda);
     }
 
-    async getPermissionlessAuthority() {
-        return await this.program.account.permissionlessAuthority.fetch(this.pdas.permissionlessAuthorityPda);
+    async getPermissionlessAuthority(index: number = 1) {
+        return await this.program.account.permissionlessAuthority.fetch(this.getPermissionlessAuthorityPda(index));
     }
 
     async addApprover(params: { trusted: PublicKey, signer?: Keypair }) {
@@ -749,6 +3173,7 @@ export class OnreProgram {
         redeemer: Keypair;
         amount: number;
         tokenProgram?: PublicKey;
+        offer?: PublicKey;
     }) {
         const redemptionOffer = await this.program.account.redemptionOffer.fetch(params.redemptionOffer);
         const tokenProgram = params.tokenProgram ?? TOKEN_PROGRAM_ID;
@@ -773,7 +3198,9 @@ export class OnreProgram {
             )
             .accounts({
                 redemptionOffer: params.redemptionOffer,
+                offer: params.offer ?? null,
                 redeemer: params.redeemer.publicKey,
+                blocklistBucket: this.getBlocklistBucketPda(params.redeemer.publicKey),
                 tokenInMint: redemptionOffer.tokenInMint,
                 tokenProgram,
             })
@@ -809,6 +3236,49 @@ export class OnreProgram {
         await tx.rpc();
     }
 
+    async cancelAllRedemptionRequests(params: {
+        redemptionOffer: PublicKey;
+        redemptionRequests: PublicKey[];
+        redeemer: Keypair;
+        redemptionAdmin: PublicKey;
+        tokenProgram?: PublicKey;
+    }) {
+        const redemptionOffer = await this.program.account.redemptionOffer.fetch(params.redemptionOffer);
+        const remainingAccounts = params.redemptionRequests.map(pubkey => ({ pubkey, isSigner: false, isWritable: true }));
+
+        const tx = this.program.methods
+            .cancelAllRedemptionRequests()
+            .accounts({
+                redemptionOffer: params.redemptionOffer,
+                redeemer: params.redeemer.publicKey,
+                tokenInMint: redemptionOffer.tokenInMint,
+                redemptionAdmin: params.redemptionAdmin,
+                tokenProgram: params.tokenProgram ?? TOKEN_PROGRAM_ID
+            })
+            .remainingAccounts(remainingAccounts)
+            .signers([params.redeemer]);
+
+        await tx.rpc();
+    }
+
+    async assignRedemptionRequest(params: {
+        redemptionOffer: PublicKey;
+        redemptionRequest: PublicKey;
+        redeemer: Keypair;
+        newRedeemer: PublicKey;
+    }) {
+        const tx = this.program.methods
+            .assignRedemptionRequest(params.newRedeemer)
+            .accountsPartial({
+                redemptionOffer: params.redemptionOffer,
+                redemptionRequest: params.redemptionRequest,
+                redeemer: params.redeemer.publicKey
+            })
+            .signers([params.redeemer]);
+
+        await tx.rpc();
+    }
+
     async fulfillRedemptionRequest(params: {
         offer: PublicKey;
         redemptionOffer: PublicKey;
@@ -819,6 +3289,9 @@ export class OnreProgram {
         tokenOutMint: PublicKey;
         tokenInProgram?: PublicKey;
         tokenOutProgram?: PublicKey;
+        navHistory?: PublicKey;
+        bossTokenOutAccount?: PublicKey;
+        userPosition?: PublicKey;
     }) {
         const tx = this.program.methods
             .fulfillRedemptionRequest()
@@ -831,8 +3304,60 @@ export class OnreProgram {
                 tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
                 tokenOutProgram: params.tokenOutProgram ?? TOKEN_PROGRAM_ID,
                 redeemer: params.redeemer,
-                redemptionAdmin: params.redemptionAdmin.publicKey
+                redemptionAdmin: params.redemptionAdmin.publicKey,
+                navHistory: params.navHistory ?? null,
+                bossTokenOutAccount: params.bossTokenOutAccount ?? null,
+                userPosition: params.userPosition ?? null
+            })
+            .signers([params.redemptionAdmin]);
+
+        await tx.rpc();
+    }
+
+    async fulfillRedemptionRequestBasket(params: {
+        offer: PublicKey;
+        redemptionOffer: PublicKey;
+        redemptionRequest: PublicKey;
+        redeemer: PublicKey;
+        redemptionAdmin: Keypair;
+        tokenInMint: PublicKey;
+        legMints: PublicKey[];
+        legTokenInAmounts?: number[];
+        tokenInProgram?: PublicKey;
+        tokenOutProgram?: PublicKey;
+        navHistory?: PublicKey;
+        redemptionBasket?: PublicKey;
+    }) {
+        const tokenOutProgram = params.tokenOutProgram ?? TOKEN_PROGRAM_ID;
+        const remainingAccounts = params.legMints.flatMap(mint => [
+            { pubkey: mint, isSigner: false, isWritable: true },
+            {
+                pubkey: getAssociatedTokenAddressSync(mint, this.pdas.redemptionVaultAuthorityPda, true, tokenOutProgram),
+                isSigner: false,
+                isWritable: true
+            },
+            {
+                pubkey: getAssociatedTokenAddressSync(mint, params.redeemer, true, tokenOutProgram),
+                isSigner: false,
+                isWritable: true
+            }
+        ]);
+
+        const tx = this.program.methods
+            .fulfillRedemptionRequestBasket((params.legTokenInAmounts ?? []).map(amount => new BN(amount)))
+            .accounts({
+                offer: params.offer,
+                redemptionOffer: params.redemptionOffer,
+                redemptionRequest: params.redemptionRequest,
+                tokenInMint: params.tokenInMint,
+                tokenInProgram: params.tokenInProgram ?? TOKEN_PROGRAM_ID,
+                tokenOutProgram,
+                redeemer: params.redeemer,
+                redemptionAdmin: params.redemptionAdmin.publicKey,
+                navHistory: params.navHistory ?? null,
+                redemptionBasket: params.redemptionBasket ?? null
             })
+            .remainingAccounts(remainingAccounts)
             .signers([params.redemptionAdmin]);
 
         await tx.rpc();
@@ -0,0 +1,83 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Add redemption settlement mint", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    it("Should allowlist a settlement mint and create its redemption vault", async () => {
+        // given
+        const usdtMint = testHelper.createMint(6);
+
+        // when
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+
+        // then
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.settlementMints[0].toString()).toBe(usdtMint.toString());
+
+        const vaultUsdtAccount = getAssociatedTokenAddressSync(usdtMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultUsdtAccount)).toBe(BigInt(0));
+    });
+
+    it("Should reject adding the same settlement mint twice", async () => {
+        const usdtMint = testHelper.createMint(6);
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint })
+        ).rejects.toThrow("Settlement mint already exists in the allowlist");
+    });
+
+    it("Should reject the offer's own token mints", async () => {
+        await expect(
+            program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdcMint })
+        ).rejects.toThrow("Invalid settlement mint");
+        await expect(
+            program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: onycMint })
+        ).rejects.toThrow("Invalid settlement mint");
+    });
+
+    it("Should reject when the allowlist is full", async () => {
+        for (let i = 0; i < 3; i++) {
+            await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: testHelper.createMint(6) });
+        }
+
+        await expect(
+            program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: testHelper.createMint(6) })
+        ).rejects.toThrow("Maximum number of settlement mints (3) reached");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.addRedemptionSettlementMint({
+                redemptionOffer: redemptionOfferPda,
+                settlementMint: testHelper.createMint(6),
+                signer: notBoss
+            })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,120 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+
+describe("Assign redemption request", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionRequestPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+    let newRedeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+
+        redeemer = testHelper.createUserAccount();
+        newRedeemer = testHelper.createUserAccount();
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(REDEMPTION_AMOUNT));
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000_000_000));
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.redemptionVaultDeposit({ amount: 1_000_000_000, tokenMint: onycMint });
+
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+        redemptionRequestPda = program.getRedemptionRequestPda(redemptionOfferPda, 0);
+    });
+
+    test("Should move the request to the new redeemer", async () => {
+        // when
+        await program.assignRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: redemptionRequestPda,
+            redeemer,
+            newRedeemer: newRedeemer.publicKey
+        });
+
+        // then
+        const request = await program.getRedemptionRequest(redemptionOfferPda, 0);
+        expect(request.redeemer).toEqual(newRedeemer.publicKey);
+        expect(request.requestId.toNumber()).toBe(0);
+        expect(request.amount.toNumber()).toBe(REDEMPTION_AMOUNT);
+    });
+
+    test("Should refund the new redeemer on cancellation", async () => {
+        // given
+        await program.assignRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: redemptionRequestPda,
+            redeemer,
+            newRedeemer: newRedeemer.publicKey
+        });
+
+        // when
+        await program.cancelRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: redemptionRequestPda,
+            signer: newRedeemer,
+            redemptionAdmin: redemptionAdmin.publicKey
+        });
+
+        // then
+        const newRedeemerTokenAccount = getAssociatedTokenAddressSync(onycMint, newRedeemer.publicKey);
+        await testHelper.expectTokenAccountAmountToBe(newRedeemerTokenAccount, BigInt(REDEMPTION_AMOUNT));
+        const redeemerTokenAccount = getAssociatedTokenAddressSync(onycMint, redeemer.publicKey);
+        await testHelper.expectTokenAccountAmountToBe(redeemerTokenAccount, BigInt(0));
+    });
+
+    test("Should reject assignment by the previous redeemer", async () => {
+        // given
+        await program.assignRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: redemptionRequestPda,
+            redeemer,
+            newRedeemer: newRedeemer.publicKey
+        });
+
+        // when/then
+        await expect(
+            program.assignRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: redemptionRequestPda,
+                redeemer,
+                newRedeemer: redeemer.publicKey
+            })
+        ).rejects.toThrow("Unauthorized: only the redeemer can assign the redemption request");
+    });
+
+    test("Should reject assigning the request to its current redeemer", async () => {
+        await expect(
+            program.assignRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: redemptionRequestPda,
+                redeemer,
+                newRedeemer: redeemer.publicKey
+            })
+        ).rejects.toThrow("Invalid new redeemer");
+    });
+});
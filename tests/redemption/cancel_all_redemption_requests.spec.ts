@@ -0,0 +1,119 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+
+describe("Cancel all redemption requests", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+
+        redeemer = testHelper.createUserAccount();
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000_000_000)); // 10 ONyc
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000_000_000));
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.redemptionVaultDeposit({ amount: 1_000_000_000, tokenMint: onycMint });
+
+        for (let i = 0; i < 3; i++) {
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            });
+        }
+    });
+
+    test("Should cancel all passed requests and refund the locked tokens", async () => {
+        // given
+        const requests = [0, 1, 2].map(i => program.getRedemptionRequestPda(redemptionOfferPda, i));
+
+        // when
+        await program.cancelAllRedemptionRequests({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequests: requests,
+            redeemer,
+            redemptionAdmin: redemptionAdmin.publicKey
+        });
+
+        // then
+        for (let i = 0; i < 3; i++) {
+            await expect(program.getRedemptionRequest(redemptionOfferPda, i)).rejects.toThrow();
+        }
+
+        const redeemerTokenAccount = getAssociatedTokenAddressSync(onycMint, redeemer.publicKey);
+        await testHelper.expectTokenAccountAmountToBe(redeemerTokenAccount, BigInt(10_000_000_000));
+
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.requestedRedemptions.toString()).toBe("0");
+    });
+
+    test("Should reject requests of another redeemer", async () => {
+        // given
+        const otherRedeemer = testHelper.createUserAccount();
+        testHelper.createTokenAccount(onycMint, otherRedeemer.publicKey, BigInt(REDEMPTION_AMOUNT));
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer: otherRedeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+
+        // when/then
+        await expect(
+            program.cancelAllRedemptionRequests({
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequests: [
+                    program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                    program.getRedemptionRequestPda(redemptionOfferPda, 3)
+                ],
+                redeemer,
+                redemptionAdmin: redemptionAdmin.publicKey
+            })
+        ).rejects.toThrow("Invalid redemption request: must be a request of the redemption offer and the signer");
+    });
+
+    test("Should reject the same request passed twice", async () => {
+        const request = program.getRedemptionRequestPda(redemptionOfferPda, 0);
+
+        await expect(
+            program.cancelAllRedemptionRequests({
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequests: [request, request],
+                redeemer,
+                redemptionAdmin: redemptionAdmin.publicKey
+            })
+        ).rejects.toThrow();
+    });
+
+    test("Should reject an empty batch", async () => {
+        await expect(
+            program.cancelAllRedemptionRequests({
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequests: [],
+                redeemer,
+                redemptionAdmin: redemptionAdmin.publicKey
+            })
+        ).rejects.toThrow("No redemption requests to cancel");
+    });
+});
@@ -0,0 +1,81 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Close Dual Redemption Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let rewardMint: PublicKey;
+    let dualRedemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+        rewardMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 2500
+        });
+
+        dualRedemptionOfferPda = program.getDualRedemptionOfferPda(onycMint, usdcMint, rewardMint);
+    });
+
+    it("Should close the dual redemption offer and return rent to boss", async () => {
+        // given
+        const initialBossBalance = testHelper.svm.getBalance(testHelper.getBoss());
+
+        // when
+        await program.closeDualRedemptionOffer({ dualRedemptionOffer: dualRedemptionOfferPda });
+
+        // then
+        await expect(program.getDualRedemptionOffer(onycMint, usdcMint, rewardMint)).rejects.toThrow();
+        const finalBossBalance = testHelper.svm.getBalance(testHelper.getBoss());
+        expect(finalBossBalance).toBeGreaterThan(initialBossBalance);
+    });
+
+    it("Should keep the base redemption offer", async () => {
+        // when
+        await program.closeDualRedemptionOffer({ dualRedemptionOffer: dualRedemptionOfferPda });
+
+        // then
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.tokenOutMint.toString()).toBe(usdcMint.toString());
+    });
+
+    it("Should allow recreating the dual redemption offer after closing", async () => {
+        // given
+        await program.closeDualRedemptionOffer({ dualRedemptionOffer: dualRedemptionOfferPda });
+
+        // when
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 5000
+        });
+
+        // then
+        const dualRedemptionOffer = await program.getDualRedemptionOffer(onycMint, usdcMint, rewardMint);
+        expect(dualRedemptionOffer.tokenOut2BasisPoints).toBe(5000);
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.closeDualRedemptionOffer({ dualRedemptionOffer: dualRedemptionOfferPda, signer: notBoss })
+        ).rejects.toThrow("Unauthorized");
+    });
+});
@@ -0,0 +1,133 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Close redemption offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        redeemer = testHelper.createUserAccount();
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+    });
+
+    it("Should close the redemption offer", async () => {
+        // when
+        await program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda });
+
+        // then
+        await expect(program.getRedemptionOffer(onycMint, usdcMint)).rejects.toThrow();
+    });
+
+    it("Should return the remaining token_out vault balance to boss", async () => {
+        // given
+        const boss = testHelper.getBoss();
+        const bossUsdcAccount = testHelper.createTokenAccount(usdcMint, boss, BigInt(500e6), true);
+        await program.redemptionVaultDeposit({ amount: 500e6, tokenMint: usdcMint });
+
+        // when
+        await program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda });
+
+        // then
+        const vaultUsdcAccount = getAssociatedTokenAddressSync(usdcMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultUsdcAccount)).toBe(BigInt(0));
+        expect(await testHelper.getTokenAccountBalance(bossUsdcAccount)).toBe(BigInt(500e6));
+    });
+
+    it("Should reject sweeping a balance above the withdrawal timelock threshold", async () => {
+        // given
+        testHelper.createTokenAccount(usdcMint, testHelper.getBoss(), BigInt(500e6), true);
+        await program.redemptionVaultDeposit({ amount: 500e6, tokenMint: usdcMint });
+        await program.configureVaultWithdrawTimelock({ tokenMint: usdcMint, delaySecs: 86_400, immediateThreshold: 100e6 });
+
+        // when / then
+        await expect(
+            program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+    });
+
+    it("Should allow redemption_admin to close and receive the rent", async () => {
+        // given
+        const initialAdminBalance = testHelper.svm.getBalance(redemptionAdmin.publicKey);
+
+        // when
+        await program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda, signer: redemptionAdmin });
+
+        // then
+        await expect(program.getRedemptionOffer(onycMint, usdcMint)).rejects.toThrow();
+        expect(testHelper.svm.getBalance(redemptionAdmin.publicKey)).toBeGreaterThan(initialAdminBalance);
+    });
+
+    it("Should reject closing while redemption requests are pending", async () => {
+        // given
+        await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer, amount: 1e9 });
+
+        // when/then
+        await expect(
+            program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda })
+        ).rejects.toThrow("Redemption offer has pending redemption requests");
+    });
+
+    it("Should allow closing once pending requests are cancelled", async () => {
+        // given
+        await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer, amount: 1e9 });
+        await program.cancelRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+            signer: redeemer,
+            redemptionAdmin: redemptionAdmin.publicKey
+        });
+
+        // when
+        await program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda });
+
+        // then
+        await expect(program.getRedemptionOffer(onycMint, usdcMint)).rejects.toThrow();
+    });
+
+    it("Should allow recreating the redemption offer after closing", async () => {
+        // given
+        await program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda });
+
+        // when
+        await program.makeRedemptionOffer({ offer: offerPda, feeBasisPoints: 50 });
+
+        // then
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.feeBasisPoints).toBe(50);
+        expect(redemptionOffer.requestCounter.toNumber()).toBe(0);
+    });
+
+    it("Should reject when called by unauthorized user", async () => {
+        const unauthorized = testHelper.createUserAccount();
+
+        await expect(
+            program.closeRedemptionOffer({ redemptionOffer: redemptionOfferPda, signer: unauthorized })
+        ).rejects.toThrow("Unauthorized");
+    });
+});
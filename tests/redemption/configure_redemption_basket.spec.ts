@@ -0,0 +1,105 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Configure redemption basket", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    it("Should store the basket mints and weights", async () => {
+        // given
+        const tBillMint = testHelper.createMint(6);
+
+        // when
+        await program.configureRedemptionBasket({
+            redemptionOffer: redemptionOfferPda,
+            mints: [tBillMint, usdcMint],
+            weightsBps: [7_000, 3_000]
+        });
+
+        // then
+        const basket = await program.getRedemptionBasket(redemptionOfferPda);
+        expect(basket.redemptionOffer.toString()).toBe(redemptionOfferPda.toString());
+        expect(basket.mints[0].toString()).toBe(tBillMint.toString());
+        expect(basket.mints[1].toString()).toBe(usdcMint.toString());
+        expect(basket.mints[2].toString()).toBe(PublicKey.default.toString());
+        expect(basket.weightsBps.slice(0, 2)).toEqual([7_000, 3_000]);
+    });
+
+    it("Should replace a previously configured basket", async () => {
+        await program.configureRedemptionBasket({
+            redemptionOffer: redemptionOfferPda,
+            mints: [testHelper.createMint(6), usdcMint],
+            weightsBps: [5_000, 5_000]
+        });
+
+        await program.configureRedemptionBasket({
+            redemptionOffer: redemptionOfferPda,
+            mints: [usdcMint],
+            weightsBps: [10_000]
+        });
+
+        const basket = await program.getRedemptionBasket(redemptionOfferPda);
+        expect(basket.mints[0].toString()).toBe(usdcMint.toString());
+        expect(basket.mints[1].toString()).toBe(PublicKey.default.toString());
+        expect(basket.weightsBps[1]).toBe(0);
+    });
+
+    it("Should reject weights that don't sum to 10000 basis points", async () => {
+        await expect(
+            program.configureRedemptionBasket({
+                redemptionOffer: redemptionOfferPda,
+                mints: [testHelper.createMint(6), usdcMint],
+                weightsBps: [5_000, 4_000]
+            })
+        ).rejects.toThrow("Basket weights must be non-zero and sum to 10000 basis points");
+    });
+
+    it("Should reject duplicate mints and the token_in mint", async () => {
+        await expect(
+            program.configureRedemptionBasket({
+                redemptionOffer: redemptionOfferPda,
+                mints: [usdcMint, usdcMint],
+                weightsBps: [5_000, 5_000]
+            })
+        ).rejects.toThrow("Duplicate basket mint");
+        await expect(
+            program.configureRedemptionBasket({
+                redemptionOffer: redemptionOfferPda,
+                mints: [onycMint],
+                weightsBps: [10_000]
+            })
+        ).rejects.toThrow("Invalid basket mint");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.configureRedemptionBasket({
+                redemptionOffer: redemptionOfferPda,
+                mints: [usdcMint],
+                weightsBps: [10_000],
+                signer: notBoss
+            })
+        ).rejects.toThrow();
+    });
+});
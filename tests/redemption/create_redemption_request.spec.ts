@@ -315,4 +315,37 @@ describe("Create redemption request", () => {
         const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
         expect(redemptionOffer.requestCounter.toString()).toBe("2");
     });
+
+    describe("Per-slot ordering", () => {
+        test("Should index requests created in the same slot in arrival order", async () => {
+            const redeemer2 = testHelper.createUserAccount();
+            testHelper.createTokenAccount(onycMint, redeemer2.publicKey, BigInt(10_000_000_000));
+
+            await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer, amount: REDEMPTION_AMOUNT });
+            await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer: redeemer2, amount: REDEMPTION_AMOUNT });
+
+            const request1 = await program.getRedemptionRequest(redemptionOfferPda, 0);
+            const request2 = await program.getRedemptionRequest(redemptionOfferPda, 1);
+
+            expect(request2.slot.toString()).toBe(request1.slot.toString());
+            expect(request1.slotIndex).toBe(0);
+            expect(request2.slotIndex).toBe(1);
+
+            const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+            expect(redemptionOffer.lastRequestSlot.toString()).toBe(request2.slot.toString());
+            expect(redemptionOffer.lastRequestSlotIndex).toBe(1);
+        });
+
+        test("Should restart the intra-slot index in a new slot", async () => {
+            await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer, amount: REDEMPTION_AMOUNT });
+            await testHelper.advanceSlot();
+            await program.createRedemptionRequest({ redemptionOffer: redemptionOfferPda, redeemer, amount: REDEMPTION_AMOUNT });
+
+            const request1 = await program.getRedemptionRequest(redemptionOfferPda, 0);
+            const request2 = await program.getRedemptionRequest(redemptionOfferPda, 1);
+
+            expect(request2.slot.gt(request1.slot)).toBe(true);
+            expect(request2.slotIndex).toBe(0);
+        });
+    });
 });
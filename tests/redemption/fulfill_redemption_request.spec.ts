@@ -292,6 +292,49 @@ describe("Fulfill redemption request", () => {
             );
         });
 
+        test("Should record the redemption in the redeemer's position when passed", async () => {
+            // given - the redeemer bought ONyc through the offer first
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+            await program.transferMintAuthorityToProgram({ mint: usdcMint });
+
+            const boss = testHelper.getBoss();
+            testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, boss, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, redeemer.publicKey, BigInt(10e6), true);
+
+            await program.takeOffer({
+                tokenInAmount: 10e6,
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                user: redeemer.publicKey,
+                signer: redeemer
+            });
+
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            });
+
+            // when
+            await program.fulfillRedemptionRequest({
+                offer: offerPda,
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                redeemer: redeemer.publicKey,
+                redemptionAdmin,
+                tokenInMint: onycMint,
+                tokenOutMint: usdcMint,
+                userPosition: program.getUserPositionPda(onycMint, redeemer.publicKey)
+            });
+
+            // then
+            const position = await program.getUserPosition(onycMint, redeemer.publicKey);
+            expect(position.totalPurchased.toNumber()).toBe(10e9);
+            expect(position.totalRedeemed.toNumber()).toBe(REDEMPTION_AMOUNT);
+        });
+
         test("Should decrement requested_redemptions in RedemptionOffer", async () => {
             // given
             await program.transferMintAuthorityToProgram({ mint: onycMint });
@@ -879,6 +922,136 @@ describe("Fulfill redemption request", () => {
             const userUsdcBalance = await testHelper.getTokenAccountBalance(userUsdcAccount);
             expect(userUsdcBalance).toBe(BigInt(100_020_000)); // 100.02 USDC (6 decimals)
         });
+
+        test("Should use per-second price for linear pricing mode", async () => {
+            // given - Base price 1.0 with 3.65% APR and linear pricing
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+            await program.transferMintAuthorityToProgram({ mint: usdcMint });
+
+            const boss = testHelper.getBoss();
+            testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, boss, BigInt(0), true);
+
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.addOfferVector({
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                baseTime: currentTime + 700,
+                basePrice: 1_000_000_000,
+                apr: 36_500,
+                priceFixDuration: 86400,
+                pricingMode: "linear"
+            });
+
+            // Half a day after base time: Price = 1.0 * (1 + 0.0365 * 0.5/365) = 1.00005
+            await testHelper.advanceClockBy(700 + 43_200);
+
+            const amountIn = 100_000_000_000; // 100 ONyc (9 decimals)
+
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: amountIn
+            });
+
+            const redemptionRequestPda = program.getRedemptionRequestPda(
+                redemptionOfferPda,
+                0
+            );
+
+            // when
+            await program.fulfillRedemptionRequest({
+                offer: offerPda,
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: redemptionRequestPda,
+                redeemer: redeemer.publicKey,
+                redemptionAdmin,
+                tokenInMint: onycMint,
+                tokenOutMint: usdcMint
+            });
+
+            // then - Should receive 100.005 USDC
+            const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+            const userUsdcBalance = await testHelper.getTokenAccountBalance(userUsdcAccount);
+            expect(userUsdcBalance).toBe(BigInt(100_005_000));
+        });
+    });
+
+    describe("Settlement mints", () => {
+        let usdtMint: PublicKey;
+
+        beforeEach(async () => {
+            usdtMint = testHelper.createMint(8);
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+
+            const boss = testHelper.getBoss();
+            testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+            testHelper.createTokenAccount(usdtMint, boss, BigInt(10_000e8), true);
+
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            });
+        });
+
+        test("Should settle in an allowlisted mint using its decimals", async () => {
+            // given
+            await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+            await program.redemptionVaultDeposit({ amount: 10_000e8, tokenMint: usdtMint });
+
+            // when
+            await program.fulfillRedemptionRequest({
+                offer: offerPda,
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                redeemer: redeemer.publicKey,
+                redemptionAdmin,
+                tokenInMint: onycMint,
+                tokenOutMint: usdtMint
+            });
+
+            // then - 1 ONyc at 1.0 pays 1 USDT with 8 decimals
+            const userUsdtAccount = getAssociatedTokenAddressSync(usdtMint, redeemer.publicKey);
+            expect(await testHelper.getTokenAccountBalance(userUsdtAccount)).toBe(BigInt(1e8));
+
+            const vaultUsdcAccount = getAssociatedTokenAddressSync(usdcMint, program.pdas.redemptionVaultAuthorityPda, true);
+            expect(await testHelper.getTokenAccountBalance(vaultUsdcAccount)).toBe(BigInt(0));
+        });
+
+        test("Should reject settlement in a mint that is not allowlisted", async () => {
+            await expect(
+                program.fulfillRedemptionRequest({
+                    offer: offerPda,
+                    redemptionOffer: redemptionOfferPda,
+                    redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                    redeemer: redeemer.publicKey,
+                    redemptionAdmin,
+                    tokenInMint: onycMint,
+                    tokenOutMint: usdtMint
+                })
+            ).rejects.toThrow();
+        });
+
+        test("Should reject settlement in a mint removed from the allowlist", async () => {
+            // given
+            await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+            await program.redemptionVaultDeposit({ amount: 10_000e8, tokenMint: usdtMint });
+            await program.removeRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+
+            // when/then
+            await expect(
+                program.fulfillRedemptionRequest({
+                    offer: offerPda,
+                    redemptionOffer: redemptionOfferPda,
+                    redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                    redeemer: redeemer.publicKey,
+                    redemptionAdmin,
+                    tokenInMint: onycMint,
+                    tokenOutMint: usdtMint
+                })
+            ).rejects.toThrow("Invalid token_out mint");
+        });
     });
 
     describe("Fee handling", () => {
@@ -958,6 +1131,10 @@ describe("Fulfill redemption request", () => {
             const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint2, redeemer.publicKey);
             const userUsdcBalance = await testHelper.getTokenAccountBalance(userUsdcAccount);
             expect(userUsdcBalance).toBe(BigInt(9_500_000)); // 9.5 USDC (6 decimals)
+
+            // Fee should be accounted on the redemption offer
+            const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint2);
+            expect(redemptionOffer.totalFeesCollectedTokenIn.toNumber()).toBe(500_000_000);
         });
 
         test("Should transfer full amount (net + fee) to boss when program lacks mint authority", async () => {
@@ -1278,6 +1455,94 @@ describe("Fulfill redemption request", () => {
             const userUsdcBalance = await testHelper.getTokenAccountBalance(userUsdcAccount);
             expect(userUsdcBalance).toBe(BigInt(28_140_410)); // Actual value with discrete step pricing
         });
+
+        test("Should deduct fee from token_out and pay it to boss when fee currency is token_out", async () => {
+            // given - 5% fee charged in the payout currency
+            await program.updateRedemptionOfferFee({
+                redemptionOffer: redemptionOfferPda,
+                newFeeBasisPoints: 500
+            });
+            await program.setRedemptionOfferFeeCurrency({
+                redemptionOffer: redemptionOfferPda,
+                feeCurrency: "tokenOut"
+            });
+
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+            await program.transferMintAuthorityToProgram({ mint: usdcMint });
+
+            const boss = testHelper.getBoss();
+            const bossOnycAccount = testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+            const bossUsdcAccount = testHelper.createTokenAccount(usdcMint, boss, BigInt(0), true);
+
+            const amount = 10_000_000_000; // 10 ONyc
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount
+            });
+            const onycSupplyBefore = (await testHelper.getMintInfo(onycMint)).supply;
+
+            // when
+            await program.fulfillRedemptionRequest({
+                offer: offerPda,
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                redeemer: redeemer.publicKey,
+                redemptionAdmin,
+                tokenInMint: onycMint,
+                tokenOutMint: usdcMint,
+                bossTokenOutAccount: bossUsdcAccount
+            });
+
+            // then
+            // Gross: 10 USDC, fee: 0.5 USDC to boss, user receives 9.5 USDC
+            const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+            expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(9_500_000));
+            expect(await testHelper.getTokenAccountBalance(bossUsdcAccount)).toBe(BigInt(500_000));
+
+            // The full token_in amount is burned and no token_in fee is taken
+            expect(await testHelper.getTokenAccountBalance(bossOnycAccount)).toBe(BigInt(0));
+            const onycSupplyAfter = (await testHelper.getMintInfo(onycMint)).supply;
+            expect(onycSupplyAfter).toBe(onycSupplyBefore - BigInt(amount));
+
+            const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+            expect(redemptionOffer.totalFeesCollectedTokenIn.toNumber()).toBe(0);
+        });
+
+        test("Should reject token_out fee without boss token_out account", async () => {
+            // given
+            await program.updateRedemptionOfferFee({
+                redemptionOffer: redemptionOfferPda,
+                newFeeBasisPoints: 500
+            });
+            await program.setRedemptionOfferFeeCurrency({
+                redemptionOffer: redemptionOfferPda,
+                feeCurrency: "tokenOut"
+            });
+
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+            await program.transferMintAuthorityToProgram({ mint: usdcMint });
+            testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(0), true);
+
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            });
+
+            // when / then
+            await expect(
+                program.fulfillRedemptionRequest({
+                    offer: offerPda,
+                    redemptionOffer: redemptionOfferPda,
+                    redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                    redeemer: redeemer.publicKey,
+                    redemptionAdmin,
+                    tokenInMint: onycMint,
+                    tokenOutMint: usdcMint
+                })
+            ).rejects.toThrow("Boss token_out account required for fees charged in token_out");
+        });
     });
 
     describe("Token2022 with random APR and fees", () => {
@@ -1289,7 +1554,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1373,7 +1639,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1455,7 +1722,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1538,6 +1806,7 @@ describe("Fulfill redemption request", () => {
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
                 tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1619,7 +1888,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1701,7 +1971,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1783,7 +2054,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1865,7 +2137,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -1947,7 +2220,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -2029,7 +2303,8 @@ describe("Fulfill redemption request", () => {
             await program.makeOffer({
                 tokenInMint: usdc2022,
                 tokenOutMint: onyc2022,
-                tokenInProgram: TOKEN_2022_PROGRAM_ID
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenOutProgram: TOKEN_2022_PROGRAM_ID
             });
 
             const offerPda = program.getOfferPda(usdc2022, onyc2022);
@@ -0,0 +1,122 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Fulfill redemption request basket", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let usdtMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        usdtMint = testHelper.createMint(8);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+        redeemer = testHelper.createUserAccount();
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        const boss = testHelper.getBoss();
+        testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+        testHelper.createTokenAccount(usdcMint, boss, BigInt(3_000e6), true);
+        testHelper.createTokenAccount(usdtMint, boss, BigInt(1_000e8), true);
+        await program.redemptionVaultDeposit({ amount: 3_000e6, tokenMint: usdcMint });
+        await program.redemptionVaultDeposit({ amount: 1_000e8, tokenMint: usdtMint });
+
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+        testHelper.createTokenAccount(usdcMint, redeemer.publicKey, BigInt(0), true);
+        testHelper.createTokenAccount(usdtMint, redeemer.publicKey, BigInt(0), true);
+
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+    });
+
+    function fulfillBasket(legMints: PublicKey[], legTokenInAmounts?: number[]) {
+        return program.fulfillRedemptionRequestBasket({
+            offer: offerPda,
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+            redeemer: redeemer.publicKey,
+            redemptionAdmin,
+            tokenInMint: onycMint,
+            legMints,
+            legTokenInAmounts
+        });
+    }
+
+    test("Should split the payout pro-rata to the vault balances", async () => {
+        // when - vaults hold 3,000 USDC and 1,000 USDT
+        await fulfillBasket([usdcMint, usdtMint]);
+
+        // then - 1 ONyc at 1.0 pays 0.75 USDC and 0.25 USDT
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+        const userUsdtAccount = getAssociatedTokenAddressSync(usdtMint, redeemer.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(750_000));
+        expect(await testHelper.getTokenAccountBalance(userUsdtAccount)).toBe(BigInt(25_000_000));
+
+        await expect(program.getRedemptionRequest(redemptionOfferPda, 0)).rejects.toThrow();
+    });
+
+    test("Should split the payout by explicit per-leg amounts", async () => {
+        // when
+        await fulfillBasket([usdcMint, usdtMint], [400_000_000, 600_000_000]);
+
+        // then
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+        const userUsdtAccount = getAssociatedTokenAddressSync(usdtMint, redeemer.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(400_000));
+        expect(await testHelper.getTokenAccountBalance(userUsdtAccount)).toBe(BigInt(60_000_000));
+    });
+
+    test("Should reject explicit amounts that don't sum to the net token_in amount", async () => {
+        await expect(
+            fulfillBasket([usdcMint, usdtMint], [400_000_000, 500_000_000])
+        ).rejects.toThrow("Leg amounts must match the legs and sum to the net token_in amount");
+    });
+
+    test("Should reject a leg in a mint that is not allowlisted", async () => {
+        const otherMint = testHelper.createMint(6);
+        testHelper.createTokenAccount(otherMint, program.pdas.redemptionVaultAuthorityPda, BigInt(0), true);
+        testHelper.createTokenAccount(otherMint, redeemer.publicKey, BigInt(0), true);
+
+        await expect(fulfillBasket([usdcMint, otherMint])).rejects.toThrow("Invalid token_out mint");
+    });
+
+    test("Should reject the same mint in two legs", async () => {
+        await expect(fulfillBasket([usdcMint, usdcMint])).rejects.toThrow("Duplicate payout mint in basket");
+    });
+});
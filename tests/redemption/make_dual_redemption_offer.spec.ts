@@ -0,0 +1,148 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Make Dual Redemption Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let rewardMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+        rewardMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+    });
+
+    it("Should create a dual redemption offer with correct configuration", async () => {
+        // when
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 2500
+        });
+
+        // then
+        const dualRedemptionOffer = await program.getDualRedemptionOffer(onycMint, usdcMint, rewardMint);
+        expect(dualRedemptionOffer.redemptionOffer.toString()).toBe(program.getRedemptionOfferPda(onycMint, usdcMint).toString());
+        expect(dualRedemptionOffer.tokenInMint.toString()).toBe(onycMint.toString());
+        expect(dualRedemptionOffer.tokenOutMint1.toString()).toBe(usdcMint.toString());
+        expect(dualRedemptionOffer.tokenOutMint2.toString()).toBe(rewardMint.toString());
+        expect(dualRedemptionOffer.tokenOut2BasisPoints).toBe(2500);
+        expect(dualRedemptionOffer.executedRedemptions.toNumber()).toBe(0);
+    });
+
+    it("Should create the secondary redemption vault account", async () => {
+        // when
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 2500
+        });
+
+        // then
+        const vaultRewardAccount = getAssociatedTokenAddressSync(rewardMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultRewardAccount)).toBe(BigInt(0));
+    });
+
+    it("Should allow redemption_admin to create a dual redemption offer", async () => {
+        // given
+        const redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+
+        // when
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 10000,
+            signer: redemptionAdmin
+        });
+
+        // then
+        const dualRedemptionOffer = await program.getDualRedemptionOffer(onycMint, usdcMint, rewardMint);
+        expect(dualRedemptionOffer.tokenOut2BasisPoints).toBe(10000);
+    });
+
+    it("Should reject when called by unauthorized user", async () => {
+        const unauthorized = testHelper.createUserAccount();
+
+        await expect(
+            program.makeDualRedemptionOffer({
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                tokenOut2BasisPoints: 2500,
+                signer: unauthorized
+            })
+        ).rejects.toThrow("Unauthorized");
+    });
+
+    it("Should reject a zero or excessive token_out_2 share", async () => {
+        for (const tokenOut2BasisPoints of [0, 10001]) {
+            await expect(
+                program.makeDualRedemptionOffer({
+                    tokenInMint: onycMint,
+                    tokenOutMint1: usdcMint,
+                    tokenOutMint2: rewardMint,
+                    tokenOut2BasisPoints
+                })
+            ).rejects.toThrow("Invalid ratio");
+        }
+    });
+
+    it("Should reject a secondary mint equal to the principal mint", async () => {
+        await expect(
+            program.makeDualRedemptionOffer({
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: usdcMint,
+                tokenOut2BasisPoints: 2500
+            })
+        ).rejects.toThrow("Invalid secondary token mint");
+    });
+
+    it("Should reject when the base redemption offer does not exist", async () => {
+        const otherMint = testHelper.createMint(6);
+
+        await expect(
+            program.makeDualRedemptionOffer({
+                tokenInMint: onycMint,
+                tokenOutMint1: otherMint,
+                tokenOutMint2: rewardMint,
+                tokenOut2BasisPoints: 2500
+            })
+        ).rejects.toThrow();
+    });
+
+    it("Should reject creating the same dual redemption offer twice", async () => {
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 2500
+        });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.makeDualRedemptionOffer({
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                tokenOut2BasisPoints: 2500
+            })
+        ).rejects.toThrow();
+    });
+});
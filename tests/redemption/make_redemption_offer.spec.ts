@@ -44,6 +44,9 @@ describe("Make redemption offer", () => {
         expect(redemptionOffer.tokenOutMint.toString()).toBe(usdcMint.toString());
         expect(redemptionOffer.executedRedemptions.toString()).toBe("0");
         expect(redemptionOffer.requestedRedemptions.toString()).toBe("0");
+        expect(redemptionOffer.tokenInDecimals).toBe(9);
+        expect(redemptionOffer.tokenOutDecimals).toBe(6);
+        expect(redemptionOffer.decimalsRecorded).toBe(true);
     });
 
     test("Make redemption offer by redemption_admin should succeed", async () => {
@@ -146,7 +149,8 @@ describe("Make redemption offer", () => {
 
         await program.makeOffer({
             tokenInMint: usdcMint,
-            tokenOutMint: onyc2022
+            tokenOutMint: onyc2022,
+            tokenOutProgram: TOKEN_2022_PROGRAM_ID
         });
         const offer2022Pda = program.getOfferPda(usdcMint, onyc2022);
 
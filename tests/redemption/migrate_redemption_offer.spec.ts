@@ -0,0 +1,70 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+const ORDERING_LAYOUT_GROWTH = 40; // last_request_slot (8) + last_request_slot_index (4) + reserved growth (28)
+
+describe("Migrate Redemption Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({
+            offer: program.getOfferPda(usdcMint, onycMint),
+            feeBasisPoints: 30
+        });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    async function truncateToLegacyLayout() {
+        const accountInfo = await testHelper.getAccountInfo(redemptionOfferPda);
+        testHelper.setAccount(redemptionOfferPda, {
+            executable: false,
+            data: Buffer.from(accountInfo.data).subarray(0, accountInfo.data.length - ORDERING_LAYOUT_GROWTH),
+            lamports: accountInfo.lamports,
+            owner: program.program.programId
+        });
+    }
+
+    it("Should migrate a legacy redemption offer preserving its configuration", async () => {
+        await truncateToLegacyLayout();
+
+        await program.migrateRedemptionOffer({ tokenInMint: onycMint, tokenOutMint: usdcMint });
+
+        const accountInfo = await testHelper.getAccountInfo(redemptionOfferPda);
+        expect(accountInfo.data.length).toBe(program.program.account.redemptionOffer.size);
+
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.feeBasisPoints).toBe(30);
+        expect(redemptionOffer.tokenInMint.toBase58()).toBe(onycMint.toBase58());
+        expect(redemptionOffer.lastRequestSlot.toNumber()).toBe(0);
+        expect(redemptionOffer.lastRequestSlotIndex).toBe(0);
+    });
+
+    it("Should reject redemption offers already on the current layout", async () => {
+        await expect(
+            program.migrateRedemptionOffer({ tokenInMint: onycMint, tokenOutMint: usdcMint })
+        ).rejects.toThrow("Redemption offer is already migrated");
+    });
+
+    it("Should reject migration by non-boss", async () => {
+        await truncateToLegacyLayout();
+        const nonBoss = Keypair.generate();
+
+        await expect(
+            program.migrateRedemptionOffer({ tokenInMint: onycMint, tokenOutMint: usdcMint, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Remove redemption settlement mint", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    it("Should clear the settlement mint slot, allowing it to be reused", async () => {
+        // given
+        const usdtMint = testHelper.createMint(6);
+        const pyusdMint = testHelper.createMint(6);
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+
+        // when
+        await program.removeRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: pyusdMint });
+
+        // then
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.settlementMints[0].toString()).toBe(pyusdMint.toString());
+    });
+
+    it("Should reject removing a mint that is not allowlisted", async () => {
+        await expect(
+            program.removeRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: testHelper.createMint(6) })
+        ).rejects.toThrow("Settlement mint not found in the allowlist");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const usdtMint = testHelper.createMint(6);
+        await program.addRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint });
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.removeRedemptionSettlementMint({ redemptionOffer: redemptionOfferPda, settlementMint: usdtMint, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,199 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Reprice redemption request", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+    let guardian: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+    const RESTATED_NAV = 900_000_000; // 0.9 (scale 9)
+    const REPRICE_WINDOW_SECONDS = 7 * 86400;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+        redeemer = testHelper.createUserAccount();
+        guardian = testHelper.createUserAccount();
+        await program.setGuardian({ guardian: guardian.publicKey });
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500_000, // 36.5% APR
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        await program.transferMintAuthorityToProgram({ mint: usdcMint });
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(0), true);
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+
+        await program.setRedemptionOfferPriceMode({ redemptionOffer: redemptionOfferPda, priceMode: "lockedAtRequest" });
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT,
+            offer: offerPda
+        });
+    });
+
+    it("Should restate the locked NAV and keep the lock timestamp", async () => {
+        // given
+        const before = await program.getRedemptionRequest(redemptionOfferPda, 0);
+        await testHelper.advanceClockBy(86400);
+
+        // when
+        await program.repriceRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            requestId: 0,
+            newNav: RESTATED_NAV,
+            guardian
+        });
+
+        // then
+        const request = await program.getRedemptionRequest(redemptionOfferPda, 0);
+        expect(request.navUsed.toNumber()).toBe(RESTATED_NAV);
+        expect(request.priceTimestamp.toNumber()).toBe(before.priceTimestamp.toNumber());
+    });
+
+    it("Should fulfill the request at the restated NAV", async () => {
+        // given
+        await program.repriceRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            requestId: 0,
+            newNav: RESTATED_NAV,
+            guardian
+        });
+
+        // when
+        await program.fulfillRedemptionRequest({
+            offer: offerPda,
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+            redeemer: redeemer.publicKey,
+            redemptionAdmin,
+            tokenInMint: onycMint,
+            tokenOutMint: usdcMint
+        });
+
+        // then - 1 ONyc pays out at 0.9 (scale 9 to 6 decimals)
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(900_000));
+    });
+
+    it("Should reject repricing without a guardian set", async () => {
+        await program.setGuardian({ guardian: PublicKey.default, currentGuardian: guardian });
+
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: RESTATED_NAV,
+                guardian
+            })
+        ).rejects.toThrow("A guardian must be set to reprice a redemption request");
+    });
+
+    it("Should reject a co-signer that is not the guardian", async () => {
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: RESTATED_NAV,
+                guardian: testHelper.createUserAccount()
+            })
+        ).rejects.toThrow("Invalid guardian account");
+    });
+
+    it("Should reject repricing by non-boss", async () => {
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: RESTATED_NAV,
+                guardian,
+                signer: testHelper.createUserAccount()
+            })
+        ).rejects.toThrow();
+    });
+
+    it("Should reject repricing a request priced at fulfillment", async () => {
+        // given
+        await program.setRedemptionOfferPriceMode({ redemptionOffer: redemptionOfferPda, priceMode: "atFulfillment" });
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+
+        // when / then
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 1,
+                newNav: RESTATED_NAV,
+                guardian
+            })
+        ).rejects.toThrow("Redemption request has no locked NAV");
+    });
+
+    it("Should reject an unchanged or zero NAV", async () => {
+        const request = await program.getRedemptionRequest(redemptionOfferPda, 0);
+
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: request.navUsed.toNumber(),
+                guardian
+            })
+        ).rejects.toThrow("Invalid NAV: must be non-zero and differ from the locked NAV");
+
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: 0,
+                guardian
+            })
+        ).rejects.toThrow("Invalid NAV: must be non-zero and differ from the locked NAV");
+    });
+
+    it("Should reject repricing after the restatement window", async () => {
+        await testHelper.advanceClockBy(REPRICE_WINDOW_SECONDS);
+
+        await expect(
+            program.repriceRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                requestId: 0,
+                newNav: RESTATED_NAV,
+                guardian
+            })
+        ).rejects.toThrow("Restatement window has elapsed");
+    });
+});
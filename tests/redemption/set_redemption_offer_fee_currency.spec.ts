@@ -0,0 +1,83 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set redemption offer fee currency", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint
+        });
+
+        await program.makeRedemptionOffer({
+            offer: program.getOfferPda(usdcMint, onycMint),
+            feeBasisPoints: 100
+        });
+
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    test("Should default to charging the fee in token_in", async () => {
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+
+        expect(redemptionOffer.feeCurrency).toEqual({ tokenIn: {} });
+    });
+
+    test("Should allow boss to switch the fee currency back and forth", async () => {
+        // when
+        await program.setRedemptionOfferFeeCurrency({
+            redemptionOffer: redemptionOfferPda,
+            feeCurrency: "tokenOut"
+        });
+
+        // then
+        let redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.feeCurrency).toEqual({ tokenOut: {} });
+        expect(redemptionOffer.feeBasisPoints).toBe(100);
+
+        // when
+        await program.setRedemptionOfferFeeCurrency({
+            redemptionOffer: redemptionOfferPda,
+            feeCurrency: "tokenIn"
+        });
+
+        // then
+        redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.feeCurrency).toEqual({ tokenIn: {} });
+    });
+
+    test("Should reject setting the current fee currency", async () => {
+        await expect(
+            program.setRedemptionOfferFeeCurrency({
+                redemptionOffer: redemptionOfferPda,
+                feeCurrency: "tokenIn"
+            })
+        ).rejects.toThrow("No change: fee currency is already set");
+    });
+
+    test("Should reject when called by non-boss", async () => {
+        const nonBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setRedemptionOfferFeeCurrency({
+                redemptionOffer: redemptionOfferPda,
+                feeCurrency: "tokenOut",
+                signer: nonBoss
+            })
+        ).rejects.toThrow();
+    });
+});
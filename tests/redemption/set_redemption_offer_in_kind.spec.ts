@@ -0,0 +1,134 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set redemption offer in kind", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let tBillMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+        tBillMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+        redeemer = testHelper.createUserAccount();
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+    });
+
+    it("Should reject enabling in-kind payouts without a basket", async () => {
+        await expect(
+            program.setRedemptionOfferInKind({ redemptionOffer: redemptionOfferPda, inKind: true, withoutBasket: true })
+        ).rejects.toThrow("Redemption basket not configured");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        await program.configureRedemptionBasket({ redemptionOffer: redemptionOfferPda, mints: [usdcMint], weightsBps: [10_000] });
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setRedemptionOfferInKind({ redemptionOffer: redemptionOfferPda, inKind: true, signer: notBoss })
+        ).rejects.toThrow();
+    });
+
+    describe("In-kind fulfillment", () => {
+        beforeEach(async () => {
+            await program.configureRedemptionBasket({
+                redemptionOffer: redemptionOfferPda,
+                mints: [tBillMint, usdcMint],
+                weightsBps: [6_000, 4_000]
+            });
+            await program.setRedemptionOfferInKind({ redemptionOffer: redemptionOfferPda, inKind: true });
+
+            await program.transferMintAuthorityToProgram({ mint: onycMint });
+            const boss = testHelper.getBoss();
+            testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+            testHelper.createTokenAccount(tBillMint, boss, BigInt(1_000e9), true);
+            testHelper.createTokenAccount(usdcMint, boss, BigInt(1_000e6), true);
+            await program.redemptionVaultDeposit({ amount: 1_000e9, tokenMint: tBillMint });
+            await program.redemptionVaultDeposit({ amount: 1_000e6, tokenMint: usdcMint });
+
+            testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+            testHelper.createTokenAccount(tBillMint, redeemer.publicKey, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, redeemer.publicKey, BigInt(0), true);
+
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            });
+        });
+
+        function fulfillInKind(legMints: PublicKey[]) {
+            return program.fulfillRedemptionRequestBasket({
+                offer: offerPda,
+                redemptionOffer: redemptionOfferPda,
+                redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                redeemer: redeemer.publicKey,
+                redemptionAdmin,
+                tokenInMint: onycMint,
+                legMints,
+                redemptionBasket: program.getRedemptionBasketPda(redemptionOfferPda)
+            });
+        }
+
+        it("Should pay out the basket by its weights", async () => {
+            // when
+            await fulfillInKind([tBillMint, usdcMint]);
+
+            // then - 1 ONyc at 1.0 pays 0.6 T-bill tokens and 0.4 USDC
+            const userTBillAccount = getAssociatedTokenAddressSync(tBillMint, redeemer.publicKey);
+            const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+            expect(await testHelper.getTokenAccountBalance(userTBillAccount)).toBe(BigInt(600_000_000));
+            expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(400_000));
+        });
+
+        it("Should reject fulfillment that omits a basket mint", async () => {
+            await expect(fulfillInKind([tBillMint])).rejects.toThrow("In-kind fulfillment must pay out every basket mint");
+        });
+
+        it("Should reject single-mint fulfillment", async () => {
+            await expect(
+                program.fulfillRedemptionRequest({
+                    offer: offerPda,
+                    redemptionOffer: redemptionOfferPda,
+                    redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+                    redeemer: redeemer.publicKey,
+                    redemptionAdmin,
+                    tokenInMint: onycMint,
+                    tokenOutMint: usdcMint
+                })
+            ).rejects.toThrow("In-kind redemption offers must be fulfilled with the redemption basket");
+        });
+    });
+});
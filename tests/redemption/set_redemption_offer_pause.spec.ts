@@ -0,0 +1,157 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set redemption offer pause", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+        redeemer = testHelper.createUserAccount();
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        await program.transferMintAuthorityToProgram({ mint: usdcMint });
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(0), true);
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+    });
+
+    function fulfill() {
+        return program.fulfillRedemptionRequest({
+            offer: offerPda,
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+            redeemer: redeemer.publicKey,
+            redemptionAdmin,
+            tokenInMint: onycMint,
+            tokenOutMint: usdcMint
+        });
+    }
+
+    test("Should reject new requests but keep fulfilling the backlog", async () => {
+        // when
+        await program.setRedemptionOfferPause({
+            redemptionOffer: redemptionOfferPda,
+            pauseNewRequests: true,
+            pauseFulfillment: false,
+            signer: redemptionAdmin
+        });
+
+        // then
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.pauseNewRequests).toBe(true);
+        expect(redemptionOffer.pauseFulfillment).toBe(false);
+
+        await expect(
+            program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            })
+        ).rejects.toThrow("New redemption requests are paused");
+
+        await fulfill();
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(1_000_000));
+    });
+
+    test("Should reject fulfillment but keep accepting new requests", async () => {
+        // when
+        await program.setRedemptionOfferPause({
+            redemptionOffer: redemptionOfferPda,
+            pauseNewRequests: false,
+            pauseFulfillment: true
+        });
+
+        // then
+        await expect(fulfill()).rejects.toThrow("Redemption fulfillment is paused");
+
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.requestCounter.toNumber()).toBe(2);
+    });
+
+    test("Should resume fulfillment once unpaused", async () => {
+        // given
+        await program.setRedemptionOfferPause({
+            redemptionOffer: redemptionOfferPda,
+            pauseNewRequests: true,
+            pauseFulfillment: true
+        });
+
+        // when
+        await program.setRedemptionOfferPause({
+            redemptionOffer: redemptionOfferPda,
+            pauseNewRequests: true,
+            pauseFulfillment: false
+        });
+
+        // then
+        await fulfill();
+        await expect(program.getRedemptionRequest(redemptionOfferPda, 0)).rejects.toThrow();
+    });
+
+    test("Should reject unchanged flags", async () => {
+        await expect(
+            program.setRedemptionOfferPause({
+                redemptionOffer: redemptionOfferPda,
+                pauseNewRequests: false,
+                pauseFulfillment: false
+            })
+        ).rejects.toThrow("No change: pause flags are already set");
+    });
+
+    test("Should reject signers other than boss and redemption admin", async () => {
+        await expect(
+            program.setRedemptionOfferPause({
+                redemptionOffer: redemptionOfferPda,
+                pauseNewRequests: true,
+                pauseFulfillment: false,
+                signer: redeemer
+            })
+        ).rejects.toThrow("Unauthorized: only boss or redemption_admin can pause the redemption offer");
+    });
+});
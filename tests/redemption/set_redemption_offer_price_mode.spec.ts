@@ -0,0 +1,135 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set redemption offer price mode", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redemptionAdmin: Keypair;
+    let redeemer: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        redemptionAdmin = testHelper.createUserAccount();
+        await program.setRedemptionAdmin({ redemptionAdmin: redemptionAdmin.publicKey });
+        redeemer = testHelper.createUserAccount();
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 36_500_000, // 36.5% APR
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        await program.transferMintAuthorityToProgram({ mint: usdcMint });
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(0), true);
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10_000e9), true);
+    });
+
+    async function fulfill() {
+        await program.fulfillRedemptionRequest({
+            offer: offerPda,
+            redemptionOffer: redemptionOfferPda,
+            redemptionRequest: program.getRedemptionRequestPda(redemptionOfferPda, 0),
+            redeemer: redeemer.publicKey,
+            redemptionAdmin,
+            tokenInMint: onycMint,
+            tokenOutMint: usdcMint
+        });
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, redeemer.publicKey);
+        return await testHelper.getTokenAccountBalance(userUsdcAccount);
+    }
+
+    it("Should lock the NAV on requests created in locked mode", async () => {
+        // given
+        await program.setRedemptionOfferPriceMode({ redemptionOffer: redemptionOfferPda, priceMode: "lockedAtRequest" });
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT,
+            offer: offerPda
+        });
+
+        const request = await program.getRedemptionRequest(redemptionOfferPda, 0);
+        expect(request.navUsed.toNumber()).toBeGreaterThan(0);
+        expect(request.priceTimestamp.toNumber()).toBe(await testHelper.getCurrentClockTime());
+
+        // when - NAV accrues for 10 days before fulfillment
+        await testHelper.advanceClockBy(10 * 86400);
+        const balance = await fulfill();
+
+        // then - 1 ONyc pays out at the locked NAV (scale 9 to 6 decimals)
+        expect(balance).toBe(BigInt(Math.floor(request.navUsed.toNumber() / 1_000)));
+    });
+
+    it("Should price requests at fulfillment by default", async () => {
+        // given
+        await program.createRedemptionRequest({
+            redemptionOffer: redemptionOfferPda,
+            redeemer,
+            amount: REDEMPTION_AMOUNT
+        });
+        const request = await program.getRedemptionRequest(redemptionOfferPda, 0);
+        expect(request.priceTimestamp.toNumber()).toBe(0);
+
+        // when
+        await testHelper.advanceClockBy(10 * 86400);
+        const balance = await fulfill();
+
+        // then - the accrued NAV is above 1.0
+        expect(balance > BigInt(1_000_000)).toBe(true);
+    });
+
+    it("Should require the underlying offer to create requests in locked mode", async () => {
+        await program.setRedemptionOfferPriceMode({ redemptionOffer: redemptionOfferPda, priceMode: "lockedAtRequest" });
+
+        await expect(
+            program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: REDEMPTION_AMOUNT
+            })
+        ).rejects.toThrow("Underlying offer account required to value the redemption");
+    });
+
+    it("Should reject setting the current price mode", async () => {
+        await expect(
+            program.setRedemptionOfferPriceMode({ redemptionOffer: redemptionOfferPda, priceMode: "atFulfillment" })
+        ).rejects.toThrow("No change: price mode is already set");
+    });
+
+    it("Should reject when called by non-boss", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setRedemptionOfferPriceMode({
+                redemptionOffer: redemptionOfferPda,
+                priceMode: "lockedAtRequest",
+                signer: notBoss
+            })
+        ).rejects.toThrow();
+    });
+});
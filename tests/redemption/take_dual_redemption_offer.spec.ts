@@ -0,0 +1,204 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Take Dual Redemption Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let rewardMint: PublicKey;
+
+    let user: Keypair;
+    let userOnycAccount: PublicKey;
+    let userUsdcAccount: PublicKey;
+    let userRewardAccount: PublicKey;
+
+    const REDEMPTION_AMOUNT = 1_000e9; // 1000 ONyc
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+        rewardMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+
+        // Base offer (USDC -> ONyc) with a flat NAV of $1.0
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        // Redemption offer (ONyc -> USDC) with a 1% fee, paying 25% of it in the reward token
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint), feeBasisPoints: 100 });
+        await program.makeDualRedemptionOffer({
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            tokenOut2BasisPoints: 2500
+        });
+
+        user = testHelper.createUserAccount();
+        userOnycAccount = testHelper.createTokenAccount(onycMint, user.publicKey, BigInt(10_000e9), true);
+        userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, user.publicKey);
+        userRewardAccount = getAssociatedTokenAddressSync(rewardMint, user.publicKey);
+
+        testHelper.createTokenAccount(usdcMint, testHelper.getBoss(), BigInt(10_000e6), true);
+        testHelper.createTokenAccount(rewardMint, testHelper.getBoss(), BigInt(10_000e9), true);
+        await program.redemptionVaultDeposit({ amount: 10_000e6, tokenMint: usdcMint });
+        await program.redemptionVaultDeposit({ amount: 10_000e9, tokenMint: rewardMint });
+    });
+
+    it("Should pay out both legs from the redemption vaults and burn token_in", async () => {
+        // given
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+
+        // when
+        await program.takeDualRedemptionOffer({
+            tokenInAmount: REDEMPTION_AMOUNT,
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            user
+        });
+
+        // then - 1% fee leaves 990 ONyc, split 742.5 / 247.5 between the legs
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(742_500_000));
+        expect(await testHelper.getTokenAccountBalance(userRewardAccount)).toBe(BigInt(247_500_000_000));
+        expect(await testHelper.getTokenAccountBalance(userOnycAccount)).toBe(BigInt(10_000e9 - REDEMPTION_AMOUNT));
+
+        const bossOnycAccount = getAssociatedTokenAddressSync(onycMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossOnycAccount)).toBe(BigInt(10e9));
+
+        const vaultOnycAccount = getAssociatedTokenAddressSync(onycMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultOnycAccount)).toBe(BigInt(0));
+
+        const vaultRewardAccount = getAssociatedTokenAddressSync(rewardMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultRewardAccount)).toBe(BigInt(10_000e9 - 247_500_000_000));
+    });
+
+    it("Should mint output tokens and forward token_in to boss without ONyc mint authority", async () => {
+        // given
+        await program.transferMintAuthorityToProgram({ mint: usdcMint });
+        await program.transferMintAuthorityToProgram({ mint: rewardMint });
+
+        // when
+        await program.takeDualRedemptionOffer({
+            tokenInAmount: REDEMPTION_AMOUNT,
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            user
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(742_500_000));
+        expect(await testHelper.getTokenAccountBalance(userRewardAccount)).toBe(BigInt(247_500_000_000));
+
+        const bossOnycAccount = getAssociatedTokenAddressSync(onycMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossOnycAccount)).toBe(BigInt(REDEMPTION_AMOUNT));
+
+        const vaultUsdcAccount = getAssociatedTokenAddressSync(usdcMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultUsdcAccount)).toBe(BigInt(10_000e6));
+    });
+
+    it("Should value both legs at the current NAV", async () => {
+        // given - NAV of $2.0
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime + 1,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+        await testHelper.advanceClockBy(1);
+
+        // when
+        await program.takeDualRedemptionOffer({
+            tokenInAmount: REDEMPTION_AMOUNT,
+            tokenInMint: onycMint,
+            tokenOutMint1: usdcMint,
+            tokenOutMint2: rewardMint,
+            user
+        });
+
+        // then
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(1_485_000_000));
+        expect(await testHelper.getTokenAccountBalance(userRewardAccount)).toBe(BigInt(495_000_000_000));
+    });
+
+    it("Should accumulate executed_redemptions", async () => {
+        // when
+        for (let i = 0; i < 2; i++) {
+            await program.takeDualRedemptionOffer({
+                tokenInAmount: REDEMPTION_AMOUNT,
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                user
+            });
+            await testHelper.advanceSlot();
+        }
+
+        // then
+        const dualRedemptionOffer = await program.getDualRedemptionOffer(onycMint, usdcMint, rewardMint);
+        expect(dualRedemptionOffer.executedRedemptions.toString()).toBe((2 * REDEMPTION_AMOUNT).toString());
+    });
+
+    it("Should reject a zero amount", async () => {
+        await expect(
+            program.takeDualRedemptionOffer({
+                tokenInAmount: 0,
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                user
+            })
+        ).rejects.toThrow("Invalid amount: must be greater than zero");
+    });
+
+    it("Should reject when kill switch is activated", async () => {
+        // given
+        await program.setKillSwitch({ enable: true });
+
+        // when/then
+        await expect(
+            program.takeDualRedemptionOffer({
+                tokenInAmount: REDEMPTION_AMOUNT,
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                user
+            })
+        ).rejects.toThrow("Kill switch is activated");
+    });
+
+    it("Should reject when the secondary vault lacks funds", async () => {
+        // given
+        await program.redemptionVaultWithdraw({ amount: 10_000e9, tokenMint: rewardMint });
+
+        // when/then
+        await expect(
+            program.takeDualRedemptionOffer({
+                tokenInAmount: REDEMPTION_AMOUNT,
+                tokenInMint: onycMint,
+                tokenOutMint1: usdcMint,
+                tokenOutMint2: rewardMint,
+                user
+            })
+        ).rejects.toThrow();
+    });
+});
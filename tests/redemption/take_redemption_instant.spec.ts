@@ -0,0 +1,224 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Take redemption instant", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let user: Keypair;
+
+    const REDEMPTION_AMOUNT = 1_000_000_000; // 1 ONyc (9 decimals)
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        user = testHelper.createUserAccount();
+
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        const offerPda = program.getOfferPda(usdcMint, onycMint);
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({ offer: offerPda });
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        const boss = testHelper.getBoss();
+        testHelper.createTokenAccount(onycMint, boss, BigInt(0), true);
+        testHelper.createTokenAccount(usdcMint, boss, BigInt(1_000e6), true);
+        await program.redemptionVaultDeposit({ amount: 1_000e6, tokenMint: usdcMint });
+
+        testHelper.createTokenAccount(onycMint, user.publicKey, BigInt(10_000e9), true);
+    });
+
+    function takeInstant(tokenInAmount: number) {
+        return program.takeRedemptionInstant({
+            tokenInAmount,
+            tokenInMint: onycMint,
+            tokenOutMint: usdcMint,
+            user
+        });
+    }
+
+    test("Should pay out of the redemption vault net of the instant fee", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 100 // 1%
+        });
+
+        // when
+        await takeInstant(REDEMPTION_AMOUNT);
+
+        // then - 0.99 ONyc at 1.0 pays 0.99 USDC
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, user.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(990_000));
+
+        const vaultUsdcAccount = getAssociatedTokenAddressSync(usdcMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultUsdcAccount)).toBe(BigInt(1_000e6 - 990_000));
+
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.executedRedemptions.toString()).toBe(REDEMPTION_AMOUNT.toString());
+        expect(redemptionOffer.totalFeesCollectedTokenIn.toNumber()).toBe(10_000_000);
+        expect(redemptionOffer.requestCounter.toNumber()).toBe(0);
+    });
+
+    test("Should charge the instant fee in token_out when the fee currency is token_out", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 100 // 1%
+        });
+        await program.setRedemptionOfferFeeCurrency({
+            redemptionOffer: redemptionOfferPda,
+            feeCurrency: "tokenOut"
+        });
+        const bossUsdcAccount = getAssociatedTokenAddressSync(usdcMint, testHelper.getBoss());
+        const bossUsdcBefore = await testHelper.getTokenAccountBalance(bossUsdcAccount);
+
+        // when
+        await program.takeRedemptionInstant({
+            tokenInAmount: REDEMPTION_AMOUNT,
+            tokenInMint: onycMint,
+            tokenOutMint: usdcMint,
+            user,
+            bossTokenOutAccount: bossUsdcAccount
+        });
+
+        // then - 1 ONyc at 1.0 is worth 1 USDC, of which 1% goes to the boss
+        const userUsdcAccount = getAssociatedTokenAddressSync(usdcMint, user.publicKey);
+        expect(await testHelper.getTokenAccountBalance(userUsdcAccount)).toBe(BigInt(990_000));
+        expect(await testHelper.getTokenAccountBalance(bossUsdcAccount)).toBe(bossUsdcBefore + BigInt(10_000));
+
+        const vaultUsdcAccount = getAssociatedTokenAddressSync(usdcMint, program.pdas.redemptionVaultAuthorityPda, true);
+        expect(await testHelper.getTokenAccountBalance(vaultUsdcAccount)).toBe(BigInt(1_000e6 - 1_000_000));
+
+        const redemptionOffer = await program.getRedemptionOffer(onycMint, usdcMint);
+        expect(redemptionOffer.totalFeesCollectedTokenIn.toNumber()).toBe(0);
+    });
+
+    test("Should require the boss token_out account for a token_out instant fee", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 100
+        });
+        await program.setRedemptionOfferFeeCurrency({
+            redemptionOffer: redemptionOfferPda,
+            feeCurrency: "tokenOut"
+        });
+
+        // when/then
+        await expect(takeInstant(REDEMPTION_AMOUNT)).rejects.toThrow(
+            "Boss token_out account required for fees charged in token_out"
+        );
+    });
+
+    test("Should record the redemption in the user's position", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 100
+        });
+
+        // when
+        await takeInstant(REDEMPTION_AMOUNT);
+        await takeInstant(REDEMPTION_AMOUNT);
+
+        // then - the fee is part of the redeemed amount
+        const position = await program.getUserPosition(onycMint, user.publicKey);
+        expect(position.user).toEqual(user.publicKey);
+        expect(position.mint).toEqual(onycMint);
+        expect(position.totalRedeemed.toNumber()).toBe(2 * REDEMPTION_AMOUNT);
+        expect(position.totalPurchased.toNumber()).toBe(0);
+    });
+
+    test("Should reject redemption offers that don't allow instant redemptions", async () => {
+        await expect(takeInstant(REDEMPTION_AMOUNT)).rejects.toThrow(
+            "Instant redemptions are not allowed on this redemption offer"
+        );
+    });
+
+    test("Should reject redemptions the vault can't pay out", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 0
+        });
+
+        // when/then - 2,000 ONyc at 1.0 exceeds the 1,000 USDC in the vault
+        await expect(takeInstant(2_000e9)).rejects.toThrow("Insufficient redemption vault balance for instant redemption");
+    });
+
+    test("Should reject redemptions paying out zero tokens", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 0
+        });
+
+        // when/then - 999 base units of ONyc at 1.0 are worth less than one base unit of USDC
+        await expect(takeInstant(999)).rejects.toThrow("Redemption pays out zero tokens");
+    });
+
+    test("Should reject instant redemptions while new requests are paused", async () => {
+        // given
+        await program.setRedemptionOfferInstant({
+            redemptionOffer: redemptionOfferPda,
+            allowInstant: true,
+            instantFeeBasisPoints: 0
+        });
+        await program.setRedemptionOfferPause({
+            redemptionOffer: redemptionOfferPda,
+            pauseNewRequests: true,
+            pauseFulfillment: false
+        });
+
+        // when/then
+        await expect(takeInstant(REDEMPTION_AMOUNT)).rejects.toThrow("Redemptions are paused");
+    });
+
+    test("Should reject an instant fee above the maximum", async () => {
+        await expect(
+            program.setRedemptionOfferInstant({
+                redemptionOffer: redemptionOfferPda,
+                allowInstant: true,
+                instantFeeBasisPoints: 1001
+            })
+        ).rejects.toThrow("Invalid fee: instant_fee_basis_points exceeds the configured maximum");
+    });
+
+    test("Should reject instant configuration by non-boss", async () => {
+        await expect(
+            program.setRedemptionOfferInstant({
+                redemptionOffer: redemptionOfferPda,
+                allowInstant: true,
+                instantFeeBasisPoints: 0,
+                signer: user
+            })
+        ).rejects.toThrow("Unauthorized: only boss can configure instant redemptions");
+    });
+});
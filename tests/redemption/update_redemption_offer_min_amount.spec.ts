@@ -0,0 +1,182 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Update redemption offer min amount", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+    let redemptionOfferPda: PublicKey;
+    let redeemer: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        // Create mints
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        // Initialize program
+        await program.initialize({ onycMint });
+
+        redeemer = testHelper.createUserAccount();
+        testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(100e9)); // 100 ONyc
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(100e9));
+
+        // Create offer priced at 2.0 USDC per ONyc
+        await program.makeOffer({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint
+        });
+
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint: usdcMint,
+            tokenOutMint: onycMint,
+            baseTime: currentTime,
+            basePrice: 2e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        await program.makeRedemptionOffer({
+            offer: offerPda
+        });
+
+        redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+
+        // Deposit into redemption vault to create the vault token account
+        await program.redemptionVaultDeposit({
+            amount: 1e9,
+            tokenMint: onycMint
+        });
+    });
+
+    describe("Configuration", () => {
+        test("Should allow boss to update the minimum", async () => {
+            await program.updateRedemptionOfferMinAmount({
+                redemptionOffer: redemptionOfferPda,
+                minRedemptionAmount: 10e9,
+                denomination: "quote"
+            });
+
+            const redemptionOffer = await program.program.account.redemptionOffer.fetch(redemptionOfferPda);
+            expect(redemptionOffer.minRedemptionAmount.toNumber()).toBe(10e9);
+            expect(redemptionOffer.minRedemptionDenomination).toEqual({ quote: {} });
+        });
+
+        test("Should reject update from non-boss", async () => {
+            const notBoss = testHelper.createUserAccount();
+
+            await expect(
+                program.updateRedemptionOfferMinAmount({
+                    redemptionOffer: redemptionOfferPda,
+                    minRedemptionAmount: 1e9,
+                    denomination: "token",
+                    signer: notBoss
+                })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Token denomination", () => {
+        beforeEach(async () => {
+            await program.updateRedemptionOfferMinAmount({
+                redemptionOffer: redemptionOfferPda,
+                minRedemptionAmount: 5e9,
+                denomination: "token"
+            });
+        });
+
+        test("Should accept a request at the minimum without the offer account", async () => {
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: 5e9
+            });
+
+            const redemptionRequest = await program.getRedemptionRequest(redemptionOfferPda, 0);
+            expect(redemptionRequest.amount.toNumber()).toBe(5e9);
+        });
+
+        test("Should reject a request below the minimum", async () => {
+            await expect(
+                program.createRedemptionRequest({
+                    redemptionOffer: redemptionOfferPda,
+                    redeemer,
+                    amount: 5e9 - 1
+                })
+            ).rejects.toThrow("Redemption below the offer's minimum");
+        });
+    });
+
+    describe("Quote denomination", () => {
+        beforeEach(async () => {
+            // $10 with scale=9
+            await program.updateRedemptionOfferMinAmount({
+                redemptionOffer: redemptionOfferPda,
+                minRedemptionAmount: 10e9,
+                denomination: "quote"
+            });
+        });
+
+        test("Should value the request at the current NAV", async () => {
+            // 5 ONyc at 2.0 = $10
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: 5e9,
+                offer: offerPda
+            });
+
+            await expect(
+                program.createRedemptionRequest({
+                    redemptionOffer: redemptionOfferPda,
+                    redeemer,
+                    amount: 5e9 - 1,
+                    offer: offerPda
+                })
+            ).rejects.toThrow("Redemption below the offer's minimum");
+        });
+
+        test("Should follow the NAV as it drifts", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.addOfferVector({
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                baseTime: currentTime + 86400,
+                basePrice: 4e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+            await testHelper.advanceClockBy(86400);
+
+            // 2.5 ONyc at 4.0 = $10
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: 2.5e9,
+                offer: offerPda
+            });
+
+            const redemptionRequest = await program.getRedemptionRequest(redemptionOfferPda, 0);
+            expect(redemptionRequest.amount.toNumber()).toBe(2.5e9);
+        });
+
+        test("Should require the underlying offer account", async () => {
+            await expect(
+                program.createRedemptionRequest({
+                    redemptionOffer: redemptionOfferPda,
+                    redeemer,
+                    amount: 5e9
+                })
+            ).rejects.toThrow("Underlying offer account required to value the redemption");
+        });
+    });
+});
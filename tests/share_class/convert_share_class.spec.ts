@@ -0,0 +1,199 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Share Classes", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let distributingMint: PublicKey;
+    let accumulatingMint: PublicKey;
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        distributingMint = testHelper.createMint(9);
+        accumulatingMint = testHelper.createMint(9, null, BigInt(0));
+
+        await program.initialize({ onycMint: distributingMint });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(distributingMint, user.publicKey, BigInt(1_000e9));
+
+        await program.transferMintAuthorityToProgram({ mint: distributingMint });
+        await program.transferMintAuthorityToProgram({ mint: accumulatingMint });
+    });
+
+    describe("Registry", () => {
+        test("Boss can register a share class", async () => {
+            // when
+            await program.addShareClass({ mint: accumulatingMint, conversionRate: 1.05e9, maxSupply: 0 });
+
+            // then
+            const shareClass = await program.getShareClass(accumulatingMint);
+            expect(shareClass.mint).toEqual(accumulatingMint);
+            expect(shareClass.conversionRate.toNumber()).toBe(1.05e9);
+            expect(shareClass.maxSupply.toNumber()).toBe(0);
+        });
+
+        test("Cannot register the same mint twice", async () => {
+            // given
+            await program.addShareClass({ mint: accumulatingMint, conversionRate: 1e9, maxSupply: 0 });
+
+            // when & then
+            await expect(
+                program.addShareClass({ mint: accumulatingMint, conversionRate: 1e9, maxSupply: 0 })
+            ).rejects.toThrow();
+        });
+
+        test("Cannot register a share class with zero conversion rate", async () => {
+            await expect(
+                program.addShareClass({ mint: accumulatingMint, conversionRate: 0, maxSupply: 0 })
+            ).rejects.toThrow("Invalid conversion rate");
+        });
+
+        test("Non-boss cannot register or update a share class", async () => {
+            const nonBoss = testHelper.createUserAccount();
+
+            await expect(
+                program.addShareClass({ mint: accumulatingMint, conversionRate: 1e9, maxSupply: 0, signer: nonBoss })
+            ).rejects.toThrow();
+
+            await program.addShareClass({ mint: accumulatingMint, conversionRate: 1e9, maxSupply: 0 });
+
+            await expect(
+                program.updateShareClass({ mint: accumulatingMint, conversionRate: 2e9, maxSupply: 0, signer: nonBoss })
+            ).rejects.toThrow();
+        });
+
+        test("Boss can update conversion rate and supply cap", async () => {
+            // given
+            await program.addShareClass({ mint: accumulatingMint, conversionRate: 1e9, maxSupply: 0 });
+
+            // when
+            await program.updateShareClass({ mint: accumulatingMint, conversionRate: 1.1e9, maxSupply: 500e9 });
+
+            // then
+            const shareClass = await program.getShareClass(accumulatingMint);
+            expect(shareClass.conversionRate.toNumber()).toBe(1.1e9);
+            expect(shareClass.maxSupply.toNumber()).toBe(500e9);
+        });
+    });
+
+    describe("Conversion", () => {
+        beforeEach(async () => {
+            await program.addShareClass({ mint: distributingMint, conversionRate: 1e9, maxSupply: 0 });
+            await program.addShareClass({ mint: accumulatingMint, conversionRate: 1.25e9, maxSupply: 0 });
+        });
+
+        test("Converts at the ratio of the registered conversion rates", async () => {
+            // when
+            await program.convertShareClass({
+                sourceMint: distributingMint,
+                destinationMint: accumulatingMint,
+                amount: 100e9,
+                user
+            });
+
+            // then - 100 * 1.0 / 1.25 = 80
+            const sourceBalance = await testHelper.getTokenAccountBalance(
+                getAssociatedTokenAddressSync(distributingMint, user.publicKey)
+            );
+            const destinationBalance = await testHelper.getTokenAccountBalance(
+                getAssociatedTokenAddressSync(accumulatingMint, user.publicKey)
+            );
+            expect(sourceBalance).toBe(BigInt(900e9));
+            expect(destinationBalance).toBe(BigInt(80e9));
+        });
+
+        test("Adjusts for different mint decimals", async () => {
+            // given
+            const sixDecimalsMint = testHelper.createMint(6);
+            await program.transferMintAuthorityToProgram({ mint: sixDecimalsMint });
+            await program.addShareClass({ mint: sixDecimalsMint, conversionRate: 1e9, maxSupply: 0 });
+
+            // when
+            await program.convertShareClass({
+                sourceMint: distributingMint,
+                destinationMint: sixDecimalsMint,
+                amount: 10e9,
+                user
+            });
+
+            // then
+            const destinationBalance = await testHelper.getTokenAccountBalance(
+                getAssociatedTokenAddressSync(sixDecimalsMint, user.publicKey)
+            );
+            expect(destinationBalance).toBe(BigInt(10e6));
+        });
+
+        test("Rejects conversion exceeding the destination supply cap", async () => {
+            // given
+            await program.updateShareClass({ mint: accumulatingMint, conversionRate: 1.25e9, maxSupply: 50e9 });
+
+            // when & then
+            await expect(
+                program.convertShareClass({
+                    sourceMint: distributingMint,
+                    destinationMint: accumulatingMint,
+                    amount: 100e9,
+                    user
+                })
+            ).rejects.toThrow("Minting would exceed maximum supply cap");
+        });
+
+        test("Rejects conversion into an unregistered class", async () => {
+            const unregisteredMint = testHelper.createMint(9);
+            await program.transferMintAuthorityToProgram({ mint: unregisteredMint });
+
+            await expect(
+                program.convertShareClass({
+                    sourceMint: distributingMint,
+                    destinationMint: unregisteredMint,
+                    amount: 1e9,
+                    user
+                })
+            ).rejects.toThrow();
+        });
+
+        test("Rejects conversion when the program lacks destination mint authority", async () => {
+            await program.transferMintAuthorityToBoss({ mint: accumulatingMint });
+
+            await expect(
+                program.convertShareClass({
+                    sourceMint: distributingMint,
+                    destinationMint: accumulatingMint,
+                    amount: 1e9,
+                    user
+                })
+            ).rejects.toThrow("Program does not have mint authority for the destination share class");
+        });
+
+        test("Rejects conversion into the same class", async () => {
+            await expect(
+                program.convertShareClass({
+                    sourceMint: distributingMint,
+                    destinationMint: distributingMint,
+                    amount: 1e9,
+                    user
+                })
+            ).rejects.toThrow();
+        });
+
+        test("Rejects conversion while the kill switch is active", async () => {
+            await program.setKillSwitch({ enable: true });
+
+            await expect(
+                program.convertShareClass({
+                    sourceMint: distributingMint,
+                    destinationMint: accumulatingMint,
+                    amount: 1e9,
+                    user
+                })
+            ).rejects.toThrow("Kill switch is activated");
+        });
+    });
+});
@@ -0,0 +1,139 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Add Blocked Address", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint, allowPermissionless: true });
+        await program.initializePermissionlessAuthority({ accountName: "test-account" });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenOutMint, user.publicKey, BigInt(10e9), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenInMint, program.pdas.permissionlessAuthorityPda, BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.pdas.permissionlessAuthorityPda, BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 5_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+    });
+
+    test("Boss can block an address", async () => {
+        // when
+        await program.addBlockedAddress({ address: user.publicKey });
+
+        // then
+        const bucket = await program.getBlocklistBucket(user.publicKey);
+        expect(bucket.addresses.map((address: PublicKey) => address.toBase58())).toContain(user.publicKey.toBase58());
+        expect(bucket.index).toBe(user.publicKey.toBytes()[0] % 32);
+    });
+
+    test("Admin can block an address", async () => {
+        // given
+        const admin = testHelper.createUserAccount();
+        await program.addAdmin({ admin: admin.publicKey });
+
+        // when
+        await program.addBlockedAddress({ address: user.publicKey, signer: admin });
+
+        // then
+        const bucket = await program.getBlocklistBucket(user.publicKey);
+        expect(bucket.addresses.map((address: PublicKey) => address.toBase58())).toContain(user.publicKey.toBase58());
+    });
+
+    test("Blocking an already blocked address should fail", async () => {
+        await program.addBlockedAddress({ address: user.publicKey });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.addBlockedAddress({ address: user.publicKey })
+        ).rejects.toThrow("Address is already blocked");
+    });
+
+    test("Blocking the default address should fail", async () => {
+        await expect(
+            program.addBlockedAddress({ address: PublicKey.default })
+        ).rejects.toThrow("Invalid address");
+    });
+
+    test("Non-admin cannot block an address", async () => {
+        const notAdmin = testHelper.createUserAccount();
+
+        await expect(
+            program.addBlockedAddress({ address: user.publicKey, signer: notAdmin })
+        ).rejects.toThrow("Unauthorized: boss or admin required");
+    });
+
+    test("Blocked users cannot take offers", async () => {
+        // given
+        await program.addBlockedAddress({ address: user.publicKey });
+
+        // when & then
+        await expect(program.takeOffer({
+            tokenInAmount: 1e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        })).rejects.toThrow("Address is blocklisted");
+        await expect(program.takeOfferExactOut({
+            tokenOutAmount: 1e9,
+            maxTokenInAmount: 1e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        })).rejects.toThrow("Address is blocklisted");
+    });
+
+    test("Blocked users cannot take offers permissionlessly", async () => {
+        // given
+        await program.addBlockedAddress({ address: user.publicKey });
+
+        // when & then
+        await expect(program.takeOfferPermissionless({
+            tokenInAmount: 1e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        })).rejects.toThrow("Address is blocklisted");
+    });
+
+    test("Blocked users cannot request redemptions", async () => {
+        // given
+        await program.makeRedemptionOffer({ offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+        await program.redemptionVaultDeposit({ amount: 1e9, tokenMint: tokenOutMint });
+        await program.addBlockedAddress({ address: user.publicKey });
+
+        // when & then
+        await expect(program.createRedemptionRequest({
+            redemptionOffer: program.getRedemptionOfferPda(tokenOutMint, tokenInMint),
+            redeemer: user,
+            amount: 1e9
+        })).rejects.toThrow("Address is blocklisted");
+    });
+});
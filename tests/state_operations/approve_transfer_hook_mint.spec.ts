@@ -0,0 +1,136 @@
+import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import { createUpdateTransferHookInstruction, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Transfer Hook Mint Approval", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let nonBoss: Keypair;
+    let hookProgram: PublicKey;
+    let hookMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        nonBoss = testHelper.createUserAccount();
+        hookProgram = Keypair.generate().publicKey;
+        hookMint = await testHelper.createMint2022WithTransferHook(6, hookProgram);
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    describe("Approve Transfer Hook Mint Instruction", () => {
+        test("Boss can approve a transfer-hook mint", async () => {
+            await program.approveTransferHookMint({ mint: hookMint });
+
+            const approval = await program.getTransferHookMintApproval(hookMint);
+            expect(approval.mint.toBase58()).toBe(hookMint.toBase58());
+            expect(approval.hookProgram.toBase58()).toBe(hookProgram.toBase58());
+        });
+
+        test("Non-boss cannot approve a transfer-hook mint", async () => {
+            await expect(
+                program.approveTransferHookMint({ mint: hookMint, signer: nonBoss })
+            ).rejects.toThrow();
+        });
+
+        test("Should reject mints without a transfer hook", async () => {
+            const plainMint = testHelper.createMint(6);
+
+            await expect(
+                program.approveTransferHookMint({ mint: plainMint })
+            ).rejects.toThrow("Mint has no transfer hook");
+        });
+
+        test("Should reject approving the same mint twice", async () => {
+            await program.approveTransferHookMint({ mint: hookMint });
+            await testHelper.advanceSlot();
+
+            await expect(program.approveTransferHookMint({ mint: hookMint })).rejects.toThrow();
+        });
+    });
+
+    describe("Revoke Transfer Hook Mint Instruction", () => {
+        beforeEach(async () => {
+            await program.approveTransferHookMint({ mint: hookMint });
+        });
+
+        test("Boss can revoke an approval", async () => {
+            await program.revokeTransferHookMint({ mint: hookMint });
+
+            const account = testHelper.svm.getAccount(program.getTransferHookMintApprovalPda(hookMint));
+            expect(account === null || account.lamports === 0).toBe(true);
+        });
+
+        test("Non-boss cannot revoke an approval", async () => {
+            await expect(
+                program.revokeTransferHookMint({ mint: hookMint, signer: nonBoss })
+            ).rejects.toThrow();
+        });
+    });
+
+    describe("Take Offer Enforcement", () => {
+        let user: Keypair;
+        let tokenOutMint: PublicKey;
+
+        beforeEach(async () => {
+            tokenOutMint = testHelper.createMint(9);
+
+            await program.makeOffer({
+                tokenInMint: hookMint,
+                tokenOutMint,
+                tokenInProgram: TOKEN_2022_PROGRAM_ID
+            });
+            await program.addOfferVector({
+                tokenInMint: hookMint,
+                tokenOutMint,
+                baseTime: await testHelper.getCurrentClockTime(),
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            user = testHelper.createUserAccount();
+            testHelper.createTokenAccount(hookMint, user.publicKey, BigInt(1_000e6), false, TOKEN_2022_PROGRAM_ID);
+            testHelper.createTokenAccount(hookMint, testHelper.getBoss(), BigInt(0), false, TOKEN_2022_PROGRAM_ID);
+            testHelper.createTokenAccount(hookMint, program.getOfferVaultAuthorityPda(hookMint, tokenOutMint), BigInt(0), true, TOKEN_2022_PROGRAM_ID);
+            testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(1_000e9));
+            testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(hookMint, tokenOutMint), BigInt(0), true);
+            await program.offerVaultDeposit({ amount: 1_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(hookMint, tokenOutMint) });
+        });
+
+        const take = (tokenInHookApproval?: PublicKey) =>
+            program.takeOffer({
+                tokenInAmount: 1e6,
+                tokenInMint: hookMint,
+                tokenOutMint,
+                user: user.publicKey,
+                signer: user,
+                tokenInProgram: TOKEN_2022_PROGRAM_ID,
+                tokenInHookApproval
+            });
+
+        test("Should reject takes paying with an unapproved transfer-hook mint", async () => {
+            await expect(take()).rejects.toThrow("Token-2022 transfer-hook mint not approved");
+        });
+
+        test("Should reject takes once the hook program changed since approval", async () => {
+            await program.approveTransferHookMint({ mint: hookMint });
+
+            const updateIx = createUpdateTransferHookInstruction(
+                hookMint,
+                testHelper.getBoss(),
+                Keypair.generate().publicKey,
+                [],
+                TOKEN_2022_PROGRAM_ID
+            );
+            await testHelper.sendAndConfirmTransaction(new Transaction().add(updateIx), [testHelper.payer]);
+
+            await expect(
+                take(program.getTransferHookMintApprovalPda(hookMint))
+            ).rejects.toThrow("Transfer hook program differs from the approved one");
+        });
+    });
+});
@@ -19,12 +19,19 @@ describe("Close State", () => {
         await program.initialize({ onycMint });
     });
 
+    async function proposeAndWait() {
+        await program.setKillSwitch({ enable: true });
+        await program.proposeCloseState();
+        await testHelper.advanceClockBy(259_200);
+    }
+
     test("Boss can close state successfully", async () => {
         // given - state exists
         const initialState = await program.getState();
         expect(initialState.boss).toEqual(testHelper.getBoss());
 
         // when
+        await proposeAndWait();
         await program.closeState();
 
         // then - state account should no longer exist
@@ -32,6 +39,9 @@ describe("Close State", () => {
     });
 
     test("Non-boss cannot close state - should fail", async () => {
+        // given
+        await proposeAndWait();
+
         // when & then
         await expect(
             program.closeState({ signer: nonBoss })
@@ -44,6 +54,7 @@ describe("Close State", () => {
 
     test("After closing state, it can be re-initialized", async () => {
         // given - close the state
+        await proposeAndWait();
         await program.closeState();
         await expect(program.getState()).rejects.toThrow();
 
@@ -59,6 +70,7 @@ describe("Close State", () => {
 
     test("After closing and re-initializing, state has correct default values", async () => {
         // given - close the state
+        await proposeAndWait();
         await program.closeState();
 
         // when - re-initialize with new mint
@@ -81,4 +93,71 @@ describe("Close State", () => {
         expect(state.approver1).toEqual(PublicKey.default);
         expect(state.approver2).toEqual(PublicKey.default);
     });
+
+    test("Cannot propose closing the state while the kill switch is disabled - should fail", async () => {
+        // when & then
+        await expect(program.proposeCloseState()).rejects.toThrow("Kill switch must be enabled before closing the state");
+    });
+
+    test("Non-boss cannot propose closing the state - should fail", async () => {
+        // given
+        await program.setKillSwitch({ enable: true });
+
+        // when & then
+        await expect(program.proposeCloseState({ signer: nonBoss })).rejects.toThrow();
+    });
+
+    test("Cannot close state without a proposal - should fail", async () => {
+        // given
+        await program.setKillSwitch({ enable: true });
+
+        // when & then
+        await expect(program.closeState()).rejects.toThrow();
+        const state = await program.getState();
+        expect(state.boss).toEqual(testHelper.getBoss());
+    });
+
+    test("Cannot close state before the timelock elapses - should fail", async () => {
+        // given
+        await program.setKillSwitch({ enable: true });
+        await program.proposeCloseState();
+        await testHelper.advanceClockBy(259_199);
+
+        // when & then
+        await expect(program.closeState()).rejects.toThrow("State closure proposal timelock has not elapsed");
+    });
+
+    test("Cannot close state after the kill switch is disabled again - should fail", async () => {
+        // given
+        await proposeAndWait();
+        await program.setKillSwitch({ enable: false });
+
+        // when & then
+        await expect(program.closeState()).rejects.toThrow("Kill switch must be enabled before closing the state");
+    });
+
+    test("Cannot close state while an offer still exists - should fail", async () => {
+        // given
+        const tokenInMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint, tokenOutMint: onycMint });
+        await proposeAndWait();
+
+        // when & then
+        await expect(
+            program.closeState({ offers: [program.getOfferPda(tokenInMint, onycMint)] })
+        ).rejects.toThrow("Cannot close state while offers still exist");
+    });
+
+    test("Closed offer PDAs do not block closing the state", async () => {
+        // given
+        const tokenInMint = testHelper.createMint(6);
+        await proposeAndWait();
+
+        // when
+        await program.closeState({ offers: [program.getOfferPda(tokenInMint, onycMint)] });
+
+        // then
+        await expect(program.getState()).rejects.toThrow();
+        expect(await testHelper.getAccountInfo(program.getCloseStateProposalPda())).toBeNull();
+    });
 });
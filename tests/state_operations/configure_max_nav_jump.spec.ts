@@ -0,0 +1,149 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Configure Max NAV Jump", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let nonBoss: Keypair;
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        nonBoss = testHelper.createUserAccount();
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+    });
+
+    describe("Configure Max NAV Jump Instruction", () => {
+        test("Boss can configure max NAV jump", async () => {
+            await program.configureMaxNavJump({ maxNavJumpBps: 50 });
+
+            const state = await program.getState();
+            expect(state.maxNavJumpBps).toBe(50);
+        });
+
+        test("Boss can disable the guardrail by setting zero", async () => {
+            await program.configureMaxNavJump({ maxNavJumpBps: 50 });
+            await program.configureMaxNavJump({ maxNavJumpBps: 0 });
+
+            const state = await program.getState();
+            expect(state.maxNavJumpBps).toBe(0);
+        });
+
+        test("Non-boss cannot configure max NAV jump", async () => {
+            await expect(
+                program.configureMaxNavJump({ maxNavJumpBps: 50, signer: nonBoss })
+            ).rejects.toThrow();
+        });
+
+        test("Should reject values above 10000 basis points", async () => {
+            await expect(
+                program.configureMaxNavJump({ maxNavJumpBps: 10001 })
+            ).rejects.toThrow("Invalid max NAV jump");
+        });
+    });
+
+    describe("Add Offer Vector Enforcement", () => {
+        let currentTime: number;
+
+        beforeEach(async () => {
+            currentTime = await testHelper.getCurrentClockTime();
+
+            // Flat vector at 1.0 so the expected price at any future time is known
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 1_000_000_000,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+        });
+
+        test("Should allow large jumps when the guardrail is disabled", async () => {
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + 1000,
+                basePrice: 2_000_000_000,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.vectors[1].basePrice.toNumber()).toBe(2_000_000_000);
+        });
+
+        test("Should allow a vector within the configured deviation", async () => {
+            await program.configureMaxNavJump({ maxNavJumpBps: 100 });
+
+            // 0.5% above the previous vector at activation
+            await program.addOfferVector({
+                tokenInMint,
+                tokenOutMint,
+                baseTime: currentTime + 1000,
+                basePrice: 1_005_000_000,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            const offer = await program.getOffer(tokenInMint, tokenOutMint);
+            expect(offer.vectors[1].basePrice.toNumber()).toBe(1_005_000_000);
+        });
+
+        test("Should reject a vector jumping above the configured deviation", async () => {
+            await program.configureMaxNavJump({ maxNavJumpBps: 100 });
+
+            await expect(
+                program.addOfferVector({
+                    tokenInMint,
+                    tokenOutMint,
+                    baseTime: currentTime + 1000,
+                    basePrice: 1_020_000_000,
+                    apr: 0,
+                    priceFixDuration: 86400
+                })
+            ).rejects.toThrow("NAV jump exceeds the configured maximum");
+        });
+
+        test("Should reject a vector dropping below the configured deviation", async () => {
+            await program.configureMaxNavJump({ maxNavJumpBps: 100 });
+
+            await expect(
+                program.addOfferVector({
+                    tokenInMint,
+                    tokenOutMint,
+                    baseTime: currentTime + 1000,
+                    basePrice: 980_000_000,
+                    apr: 0,
+                    priceFixDuration: 86400
+                })
+            ).rejects.toThrow("NAV jump exceeds the configured maximum");
+        });
+
+        test("Should not apply the guardrail to the first vector of an offer", async () => {
+            const otherTokenInMint = testHelper.createMint(6);
+            await program.makeOffer({ tokenInMint: otherTokenInMint, tokenOutMint });
+            await program.configureMaxNavJump({ maxNavJumpBps: 1 });
+
+            await program.addOfferVector({
+                tokenInMint: otherTokenInMint,
+                tokenOutMint,
+                baseTime: currentTime,
+                basePrice: 5_000_000_000,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            const offer = await program.getOffer(otherTokenInMint, tokenOutMint);
+            expect(offer.vectors[0].basePrice.toNumber()).toBe(5_000_000_000);
+        });
+    });
+});
@@ -33,15 +33,12 @@ describe("Configure Max Supply and Enforcement", () => {
             expect(state.maxSupply.toNumber()).toBe(maxSupply);
         });
 
-        test("Boss can set max supply to zero (no cap)", async () => {
-            // First set a cap
+        test("Boss cannot remove the cap without a proposal", async () => {
             await program.configureMaxSupply({ maxSupply: 1_000_000 });
 
-            // Then remove it
-            await program.configureMaxSupply({ maxSupply: 0 });
-
-            const state = await program.program.account.state.fetch(program.pdas.statePda);
-            expect(state.maxSupply.toNumber()).toBe(0);
+            await expect(
+                program.configureMaxSupply({ maxSupply: 0 })
+            ).rejects.toThrow("Raising or removing the max supply requires propose_max_supply");
         });
 
         test("Non-boss cannot configure max supply", async () => {
@@ -50,14 +47,73 @@ describe("Configure Max Supply and Enforcement", () => {
             ).rejects.toThrow();
         });
 
-        test("Boss can update max supply multiple times", async () => {
+        test("Boss can lower max supply immediately", async () => {
+            await program.configureMaxSupply({ maxSupply: 2_000_000 });
             await program.configureMaxSupply({ maxSupply: 1_000_000 });
-            let state = await program.program.account.state.fetch(program.pdas.statePda);
+
+            const state = await program.program.account.state.fetch(program.pdas.statePda);
             expect(state.maxSupply.toNumber()).toBe(1_000_000);
+        });
 
-            await program.configureMaxSupply({ maxSupply: 2_000_000 });
-            state = await program.program.account.state.fetch(program.pdas.statePda);
+        test("Boss cannot raise max supply without a proposal", async () => {
+            await program.configureMaxSupply({ maxSupply: 1_000_000 });
+
+            await expect(
+                program.configureMaxSupply({ maxSupply: 2_000_000 })
+            ).rejects.toThrow("Raising or removing the max supply requires propose_max_supply");
+        });
+    });
+
+    describe("Propose and Accept Max Supply", () => {
+        let testHelper: TestHelper;
+        let program: OnreProgram;
+        let nonBoss: Keypair;
+
+        beforeEach(async () => {
+            testHelper = await TestHelper.create();
+            program = new OnreProgram(testHelper);
+
+            nonBoss = testHelper.createUserAccount();
+            await program.initialize({ onycMint: testHelper.createMint(9) });
+            await program.configureMaxSupply({ maxSupply: 1_000_000 });
+        });
+
+        test("Boss can raise max supply after the timelock", async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+            await program.proposeMaxSupply({ maxSupply: 2_000_000 });
+
+            const proposal = await program.program.account.maxSupplyProposal.fetch(program.getMaxSupplyProposalPda());
+            expect(proposal.maxSupply.toNumber()).toBe(2_000_000);
+            expect(proposal.effectiveAt.toNumber()).toBe(currentTime + 86_400);
+
+            await testHelper.advanceClockBy(86_400);
+            await program.acceptMaxSupply();
+
+            const state = await program.program.account.state.fetch(program.pdas.statePda);
             expect(state.maxSupply.toNumber()).toBe(2_000_000);
+            expect(await testHelper.getAccountInfo(program.getMaxSupplyProposalPda())).toBeNull();
+        });
+
+        test("Boss can remove the cap after the timelock", async () => {
+            await program.proposeMaxSupply({ maxSupply: 0 });
+            await testHelper.advanceClockBy(86_400);
+            await program.acceptMaxSupply();
+
+            const state = await program.program.account.state.fetch(program.pdas.statePda);
+            expect(state.maxSupply.toNumber()).toBe(0);
+        });
+
+        test("Cannot accept before the timelock has elapsed", async () => {
+            await program.proposeMaxSupply({ maxSupply: 2_000_000 });
+            await testHelper.advanceClockBy(86_399);
+
+            await expect(program.acceptMaxSupply()).rejects.toThrow("Max supply proposal timelock has not elapsed");
+        });
+
+        test("Non-boss cannot propose max supply", async () => {
+            await expect(
+                program.proposeMaxSupply({ maxSupply: 2_000_000, signer: nonBoss })
+            ).rejects.toThrow();
         });
     });
 
@@ -164,8 +220,8 @@ describe("Configure Max Supply and Enforcement", () => {
             testHelper.createTokenAccount(usdcMint, user.publicKey, BigInt(1_000_000_000)); // 1000 USDC
 
             // Create vault accounts
-            testHelper.createTokenAccount(usdcMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-            testHelper.createTokenAccount(onycMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
+            testHelper.createTokenAccount(onycMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
 
             // Initialize state with ONyc mint
             await program.initialize({ onycMint: onycMint });
@@ -297,8 +353,8 @@ describe("Configure Max Supply and Enforcement", () => {
             testHelper.createTokenAccount(onycMint, program.pdas.permissionlessAuthorityPda, BigInt(0), true);
 
             // Create vault accounts
-            testHelper.createTokenAccount(usdcMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
-            testHelper.createTokenAccount(onycMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            testHelper.createTokenAccount(usdcMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
+            testHelper.createTokenAccount(onycMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
 
             // Initialize state with ONyc mint
             await program.initialize({ onycMint: onycMint });
@@ -0,0 +1,94 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Configure Mint Throttle", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let onycMint: PublicKey;
+    let guardian: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        onycMint = testHelper.createMint(9);
+        guardian = testHelper.createUserAccount();
+
+        await program.initialize({ onycMint });
+        await program.transferMintAuthorityToProgram({ mint: onycMint });
+        testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(0));
+    });
+
+    test("Boss can configure the mint throttle", async () => {
+        // when
+        await program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 86400 });
+
+        // then
+        const throttle = await program.getMintThrottle();
+        expect(throttle.maxMintPerWindow.toNumber()).toBe(1_000e9);
+        expect(throttle.windowSecs.toNumber()).toBe(86400);
+    });
+
+    test("Non-boss cannot configure the mint throttle - should fail", async () => {
+        const nonBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 86400, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+
+    test("Rejects a limit with a zero window", async () => {
+        await expect(
+            program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 0 })
+        ).rejects.toThrow("Invalid window: must be greater than zero");
+    });
+
+    test("mint_to is limited by the rolling window budget", async () => {
+        // given
+        await program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 86400 });
+        await program.mintTo({ amount: 800e9 });
+
+        // when / then - the remaining budget is 200 ONyc
+        await expect(program.mintTo({ amount: 300e9 })).rejects.toThrow("Mint exceeds the rolling window budget");
+
+        // half a window later half of the consumption has decayed
+        await testHelper.advanceClockBy(43200);
+        await program.mintTo({ amount: 500e9 });
+
+        const bossOnycAccount = getAssociatedTokenAddressSync(onycMint, testHelper.getBoss());
+        expect(await testHelper.getTokenAccountBalance(bossOnycAccount)).toBe(BigInt(1_300e9));
+    });
+
+    test("Loosening the throttle requires the guardian once set", async () => {
+        // given
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 86400 });
+
+        // when / then
+        await expect(
+            program.configureMintThrottle({ maxMintPerWindow: 2_000e9, windowSecs: 86400 })
+        ).rejects.toThrow("Guardian signature required to loosen the mint throttle");
+        await expect(
+            program.configureMintThrottle({ maxMintPerWindow: 0, windowSecs: 0 })
+        ).rejects.toThrow("Guardian signature required to loosen the mint throttle");
+
+        await program.configureMintThrottle({ maxMintPerWindow: 2_000e9, windowSecs: 86400, guardian });
+        expect((await program.getMintThrottle()).maxMintPerWindow.toNumber()).toBe(2_000e9);
+    });
+
+    test("Tightening the throttle does not require the guardian", async () => {
+        // given
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await program.configureMintThrottle({ maxMintPerWindow: 1_000e9, windowSecs: 86400 });
+
+        // when
+        await program.configureMintThrottle({ maxMintPerWindow: 500e9, windowSecs: 172800 });
+
+        // then
+        const throttle = await program.getMintThrottle();
+        expect(throttle.maxMintPerWindow.toNumber()).toBe(500e9);
+        expect(throttle.windowSecs.toNumber()).toBe(172800);
+    });
+});
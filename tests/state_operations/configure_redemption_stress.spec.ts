@@ -0,0 +1,181 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Configure Redemption Stress", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let nonBoss: Keypair;
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        nonBoss = testHelper.createUserAccount();
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+    });
+
+    describe("Configure Redemption Stress Instruction", () => {
+        test("Boss can configure the redemption stress policy", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 8000, stressedTakeMaxTokenIn: 5e6 });
+
+            const state = await program.getState();
+            expect(state.redemptionStressThresholdBps).toBe(8000);
+            expect(state.stressedTakeMaxTokenIn.toNumber()).toBe(5e6);
+        });
+
+        test("Boss can disable the policy by setting a zero threshold", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 8000, stressedTakeMaxTokenIn: 0 });
+            await program.configureRedemptionStress({ thresholdBps: 0, stressedTakeMaxTokenIn: 0 });
+
+            const state = await program.getState();
+            expect(state.redemptionStressThresholdBps).toBe(0);
+        });
+
+        test("Non-boss cannot configure the redemption stress policy", async () => {
+            await expect(
+                program.configureRedemptionStress({ thresholdBps: 8000, stressedTakeMaxTokenIn: 0, signer: nonBoss })
+            ).rejects.toThrow();
+        });
+
+        test("Should reject thresholds above 10000 basis points", async () => {
+            await expect(
+                program.configureRedemptionStress({ thresholdBps: 10001, stressedTakeMaxTokenIn: 0 })
+            ).rejects.toThrow("Invalid threshold");
+        });
+    });
+
+    describe("Take Offer Enforcement", () => {
+        let user: Keypair;
+        let redeemer: Keypair;
+        let redemptionOfferPda: PublicKey;
+        let redemptionVaultUsdcAccount: PublicKey;
+
+        beforeEach(async () => {
+            const currentTime = await testHelper.getCurrentClockTime();
+
+            // Flat price of 1 USDC per ONyc
+            await program.addOfferVector({
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                baseTime: currentTime,
+                basePrice: 1e9,
+                apr: 0,
+                priceFixDuration: 86400
+            });
+
+            user = testHelper.createUserAccount();
+            testHelper.createTokenAccount(usdcMint, user.publicKey, BigInt(1_000e6), true);
+            testHelper.createTokenAccount(usdcMint, testHelper.getBoss(), BigInt(1_000e6));
+            testHelper.createTokenAccount(onycMint, testHelper.getBoss(), BigInt(1_000e9));
+            testHelper.createTokenAccount(usdcMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
+            testHelper.createTokenAccount(onycMint, program.getOfferVaultAuthorityPda(usdcMint, onycMint), BigInt(0), true);
+            await program.offerVaultDeposit({ amount: 500e9, tokenMint: onycMint, offer: program.getOfferPda(usdcMint, onycMint) });
+
+            await program.makeRedemptionOffer({ offer: program.getOfferPda(usdcMint, onycMint) });
+            redemptionOfferPda = program.getRedemptionOfferPda(onycMint, usdcMint);
+            redemptionVaultUsdcAccount = getAssociatedTokenAddressSync(
+                usdcMint,
+                program.pdas.redemptionVaultAuthorityPda,
+                true
+            );
+
+            // 10 USDC of coverage against 10 ONyc (10 USDC) of outstanding redemptions
+            await program.redemptionVaultDeposit({ amount: 10e6, tokenMint: usdcMint });
+            redeemer = testHelper.createUserAccount();
+            testHelper.createTokenAccount(onycMint, redeemer.publicKey, BigInt(10e9));
+            await program.createRedemptionRequest({
+                redemptionOffer: redemptionOfferPda,
+                redeemer,
+                amount: 10e9
+            });
+        });
+
+        const takeWithStressAccounts = (tokenInAmount: number) =>
+            program.takeOffer({
+                tokenInAmount,
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                user: user.publicKey,
+                signer: user,
+                redemptionOffer: redemptionOfferPda,
+                redemptionVaultTokenInAccount: redemptionVaultUsdcAccount
+            });
+
+        test("Takes are not throttled while the policy is disabled", async () => {
+            await program.takeOffer({
+                tokenInAmount: 100e6,
+                tokenInMint: usdcMint,
+                tokenOutMint: onycMint,
+                user: user.publicKey,
+                signer: user
+            });
+        });
+
+        test("Should require the redemption accounts while the policy is enabled", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 5000, stressedTakeMaxTokenIn: 0 });
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1e6,
+                    tokenInMint: usdcMint,
+                    tokenOutMint: onycMint,
+                    user: user.publicKey,
+                    signer: user
+                })
+            ).rejects.toThrow("Redemption offer and vault accounts required");
+        });
+
+        test("Should allow takes while outstanding redemptions are within the threshold", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 10000, stressedTakeMaxTokenIn: 0 });
+
+            await takeWithStressAccounts(100e6);
+        });
+
+        test("Should pause takes under stress when no stressed limit is set", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 5000, stressedTakeMaxTokenIn: 0 });
+
+            await expect(takeWithStressAccounts(1e6)).rejects.toThrow("Takes paused during redemption stress");
+        });
+
+        test("Should cap takes under stress at the stressed limit", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 5000, stressedTakeMaxTokenIn: 5e6 });
+
+            await takeWithStressAccounts(5e6);
+            await testHelper.advanceSlot();
+            await expect(takeWithStressAccounts(5e6 + 1)).rejects.toThrow("Take exceeds the redemption stress limit");
+        });
+
+        test("Should lift the throttle once the vault covers outstanding redemptions", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 5000, stressedTakeMaxTokenIn: 0 });
+            await expect(takeWithStressAccounts(1e6)).rejects.toThrow("Takes paused during redemption stress");
+
+            await program.redemptionVaultDeposit({ amount: 10e6, tokenMint: usdcMint });
+
+            await takeWithStressAccounts(100e6);
+        });
+
+        test("Should reject a vault account other than the redemption vault ATA", async () => {
+            await program.configureRedemptionStress({ thresholdBps: 5000, stressedTakeMaxTokenIn: 0 });
+
+            await expect(
+                program.takeOffer({
+                    tokenInAmount: 1e6,
+                    tokenInMint: usdcMint,
+                    tokenOutMint: onycMint,
+                    user: user.publicKey,
+                    signer: user,
+                    redemptionOffer: redemptionOfferPda,
+                    redemptionVaultTokenInAccount: getAssociatedTokenAddressSync(usdcMint, testHelper.getBoss())
+                })
+            ).rejects.toThrow("Invalid redemption vault account");
+        });
+    });
+});
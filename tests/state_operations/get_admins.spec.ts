@@ -0,0 +1,83 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+const ADMINS_PAGE_SIZE = 10;
+
+describe("Get Admins", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    test("Returns an empty page when no admins are configured", async () => {
+        // when
+        const result = await program.getAdmins(0);
+
+        // then
+        expect(result.page).toBe(0);
+        expect(result.totalAdmins).toBe(0);
+        expect(result.admins).toHaveLength(0);
+        expect(result.hasMore).toBe(false);
+    });
+
+    test("Returns configured admins in storage order", async () => {
+        // given
+        const admins = [testHelper.createUserAccount().publicKey, testHelper.createUserAccount().publicKey];
+        for (const admin of admins) {
+            await program.addAdmin({ admin });
+        }
+
+        // when
+        const result = await program.getAdmins(0);
+
+        // then
+        expect(result.totalAdmins).toBe(2);
+        expect(result.admins).toEqual(admins);
+        expect(result.hasMore).toBe(false);
+    });
+
+    test("Paginates admins across pages", async () => {
+        // given
+        const admins: PublicKey[] = [];
+        for (let i = 0; i < 15; i++) {
+            const admin = testHelper.createUserAccount().publicKey;
+            admins.push(admin);
+            await program.addAdmin({ admin });
+        }
+
+        // when
+        const firstPage = await program.getAdmins(0);
+        const secondPage = await program.getAdmins(1);
+        const thirdPage = await program.getAdmins(2);
+
+        // then
+        expect(firstPage.admins).toEqual(admins.slice(0, ADMINS_PAGE_SIZE));
+        expect(firstPage.hasMore).toBe(true);
+        expect(secondPage.admins).toEqual(admins.slice(ADMINS_PAGE_SIZE));
+        expect(secondPage.hasMore).toBe(false);
+        expect(secondPage.totalAdmins).toBe(15);
+        expect(thirdPage.admins).toHaveLength(0);
+    });
+
+    test("Skips slots freed by removed admins", async () => {
+        // given
+        const first = testHelper.createUserAccount().publicKey;
+        const second = testHelper.createUserAccount().publicKey;
+        await program.addAdmin({ admin: first });
+        await program.addAdmin({ admin: second });
+        await program.removeAdmin({ admin: first });
+
+        // when
+        const result = await program.getAdmins(0);
+
+        // then
+        expect(result.totalAdmins).toBe(1);
+        expect(result.admins).toEqual([second]);
+    });
+});
@@ -0,0 +1,51 @@
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Get Approvers", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    test("Returns an empty list when no approvers are configured", async () => {
+        // when
+        const approvers = await program.getApprovers();
+
+        // then
+        expect(approvers).toHaveLength(0);
+    });
+
+    test("Returns both configured approvers in slot order", async () => {
+        // given
+        const approver1 = testHelper.createUserAccount().publicKey;
+        const approver2 = testHelper.createUserAccount().publicKey;
+        await program.addApprover({ trusted: approver1 });
+        await program.addApprover({ trusted: approver2 });
+
+        // when
+        const approvers = await program.getApprovers();
+
+        // then
+        expect(approvers).toEqual([approver1, approver2]);
+    });
+
+    test("Omits an emptied approver slot", async () => {
+        // given
+        const approver1 = testHelper.createUserAccount().publicKey;
+        const approver2 = testHelper.createUserAccount().publicKey;
+        await program.addApprover({ trusted: approver1 });
+        await program.addApprover({ trusted: approver2 });
+        await program.removeApprover({ approver: approver1 });
+
+        // when
+        const approvers = await program.getApprovers();
+
+        // then
+        expect(approvers).toEqual([approver2]);
+    });
+});
@@ -0,0 +1,40 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Initialize", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let onycMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        onycMint = testHelper.createMint(9);
+        await program.initialize({ onycMint });
+    });
+
+    it("Should be a no-op when repeated with the same boss and mint", async () => {
+        // given
+        const admin = testHelper.createUserAccount();
+        await program.addAdmin({ admin: admin.publicKey });
+        await testHelper.advanceSlot();
+
+        // when
+        await program.initialize({ onycMint });
+
+        // then
+        const state = await program.getState();
+        expect(state.onycMint.toBase58()).toBe(onycMint.toBase58());
+        expect(state.admins.map(a => a.toBase58())).toContain(admin.publicKey.toBase58());
+    });
+
+    it("Should reject re-initialization with a different mint", async () => {
+        const otherMint = testHelper.createMint(9);
+
+        await expect(program.initialize({ onycMint: otherMint })).rejects.toThrow(
+            "Boss is already set, state has been initialized"
+        );
+    });
+});
@@ -40,6 +40,37 @@ describe("Kill Switch Enable", () => {
         expect(state.isKilled).toBe(true);
     });
 
+    test("Enabling with a duration sets the kill switch expiry", async () => {
+        // given
+        const currentTime = await testHelper.getCurrentClockTime();
+
+        // when
+        await program.setKillSwitch({ enable: true, duration: 3600 });
+
+        // then
+        const state = await program.getState();
+        expect(state.isKilled).toBe(true);
+        expect(state.killSwitchExpiry.toNumber()).toBe(currentTime + 3600);
+    });
+
+    test("Enabling without a duration clears a previous expiry", async () => {
+        // given
+        await program.setKillSwitch({ enable: true, duration: 3600 });
+
+        // when
+        await program.setKillSwitch({ enable: true, signer: admin });
+
+        // then
+        const state = await program.getState();
+        expect(state.killSwitchExpiry.toNumber()).toBe(0);
+    });
+
+    test("Enabling with a zero duration should fail", async () => {
+        await expect(
+            program.setKillSwitch({ enable: true, duration: 0 })
+        ).rejects.toThrow("Invalid kill switch duration");
+    });
+
     test("Non-boss and non-admin cannot enable kill switch - should fail", async () => {
         // when & then
         await expect(
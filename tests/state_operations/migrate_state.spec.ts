@@ -0,0 +1,107 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+// Offset of schema_version from the end of the state account:
+// u16 + blocked_jurisdictions [u8; 128] + screening_program [u8; 32] + reserved [u8; 2]
+const SCHEMA_VERSION_OFFSET_FROM_END = 164;
+// Size of the fields added after schema version 1: the jurisdiction bitmap and
+// the screening program
+const FIELDS_ADDED_SINCE_V1_BYTES = 160;
+
+describe("Migrate state", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let onycMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        onycMint = testHelper.createMint(9);
+        await program.initialize({ onycMint });
+    });
+
+    async function resetToUnversionedState() {
+        const accountInfo = await testHelper.getAccountInfo(program.pdas.statePda);
+        const data = Buffer.from(accountInfo.data);
+        data.writeUInt16LE(0, data.length - SCHEMA_VERSION_OFFSET_FROM_END);
+        testHelper.setAccount(program.pdas.statePda, {
+            executable: false,
+            data,
+            lamports: accountInfo.lamports,
+            owner: program.program.programId
+        });
+    }
+
+    async function resetToV1State() {
+        const accountInfo = await testHelper.getAccountInfo(program.pdas.statePda);
+        const data = Buffer.from(accountInfo.data.subarray(0, accountInfo.data.length - FIELDS_ADDED_SINCE_V1_BYTES));
+        data.writeUInt16LE(1, data.length - 4);
+        testHelper.setAccount(program.pdas.statePda, {
+            executable: false,
+            data,
+            lamports: accountInfo.lamports,
+            owner: program.program.programId
+        });
+    }
+
+    it("Should initialize new states at the current schema version", async () => {
+        const state = await program.getState();
+        expect(state.schemaVersion).toBe(3);
+    });
+
+    it("Should reject instructions on an outdated state until it is migrated", async () => {
+        // given
+        await resetToUnversionedState();
+        const admin = Keypair.generate();
+
+        // when/then
+        await expect(program.addAdmin({ admin: admin.publicKey })).rejects.toThrow(
+            "State schema is outdated: run migrate_state first"
+        );
+
+        await program.migrateState({ targetVersion: 3 });
+
+        const state = await program.getState();
+        expect(state.schemaVersion).toBe(3);
+        expect(state.boss.toBase58()).toBe(testHelper.getBoss().toBase58());
+        expect(state.onycMint.toBase58()).toBe(onycMint.toBase58());
+
+        await program.addAdmin({ admin: admin.publicKey });
+    });
+
+    it("Should grow a version 1 state with an empty jurisdiction bitmap and no screening", async () => {
+        // given
+        await resetToV1State();
+
+        // when
+        await program.migrateState({ targetVersion: 3 });
+
+        // then
+        const state = await program.getState();
+        expect(state.schemaVersion).toBe(3);
+        expect(state.blockedJurisdictions.every((byte: number) => byte === 0)).toBe(true);
+        expect(state.screeningProgram).toEqual(PublicKey.default);
+        expect(state.boss.toBase58()).toBe(testHelper.getBoss().toBase58());
+    });
+
+    it("Should reject a target version that is not newer than the stored one", async () => {
+        await expect(program.migrateState({ targetVersion: 3 })).rejects.toThrow("Invalid target schema version");
+    });
+
+    it("Should reject a target version newer than the program supports", async () => {
+        await resetToUnversionedState();
+
+        await expect(program.migrateState({ targetVersion: 4 })).rejects.toThrow("Invalid target schema version");
+    });
+
+    it("Should reject migration by non-boss", async () => {
+        await resetToUnversionedState();
+        const nonBoss = testHelper.createUserAccount();
+
+        await expect(program.migrateState({ targetVersion: 3, signer: nonBoss })).rejects.toThrow(
+            "Unauthorized signer: must be the boss"
+        );
+    });
+});
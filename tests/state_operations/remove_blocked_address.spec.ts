@@ -0,0 +1,101 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Remove Blocked Address", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let user: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: await testHelper.getCurrentClockTime(),
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(10_000e6), true);
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+        testHelper.createTokenAccount(tokenInMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        testHelper.createTokenAccount(tokenOutMint, program.getOfferVaultAuthorityPda(tokenInMint, tokenOutMint), BigInt(0), true);
+        await program.offerVaultDeposit({ amount: 5_000e9, tokenMint: tokenOutMint, offer: program.getOfferPda(tokenInMint, tokenOutMint) });
+
+        await program.addBlockedAddress({ address: user.publicKey });
+    });
+
+    test("Boss can unblock an address", async () => {
+        // when
+        await program.removeBlockedAddress({ address: user.publicKey });
+
+        // then
+        const bucket = await program.getBlocklistBucket(user.publicKey);
+        expect(bucket.addresses.map((address: PublicKey) => address.toBase58())).not.toContain(user.publicKey.toBase58());
+    });
+
+    test("Admin can unblock an address", async () => {
+        // given
+        const admin = testHelper.createUserAccount();
+        await program.addAdmin({ admin: admin.publicKey });
+
+        // when
+        await program.removeBlockedAddress({ address: user.publicKey, signer: admin });
+
+        // then
+        const bucket = await program.getBlocklistBucket(user.publicKey);
+        expect(bucket.addresses.map((address: PublicKey) => address.toBase58())).not.toContain(user.publicKey.toBase58());
+    });
+
+    test("Unblocked users can take offers again", async () => {
+        // given
+        await program.removeBlockedAddress({ address: user.publicKey });
+
+        // when
+        await program.takeOffer({
+            tokenInAmount: 1e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user
+        });
+
+        // then
+        await testHelper.expectTokenAccountAmountToBe(
+            getAssociatedTokenAddressSync(tokenOutMint, user.publicKey),
+            BigInt(1e9)
+        );
+    });
+
+    test("Unblocking an address that is not blocked should fail", async () => {
+        await program.removeBlockedAddress({ address: user.publicKey });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.removeBlockedAddress({ address: user.publicKey })
+        ).rejects.toThrow("Address is not blocked");
+    });
+
+    test("Non-admin cannot unblock an address", async () => {
+        const notAdmin = testHelper.createUserAccount();
+
+        await expect(
+            program.removeBlockedAddress({ address: user.publicKey, signer: notAdmin })
+        ).rejects.toThrow("Unauthorized: boss or admin required");
+    });
+});
@@ -0,0 +1,118 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Config", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let nonBoss: Keypair;
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        nonBoss = testHelper.createUserAccount();
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        // Also creates the config account
+        await program.initialize({ onycMint: tokenOutMint });
+    });
+
+    test("Config is created with the default tunables", async () => {
+        const config = await program.getConfig();
+        expect(config.maxFeeBps).toBe(1000);
+        expect(config.maxApprovalValiditySecs.toNumber()).toBe(0);
+        expect(config.secondsPerYear.toNumber()).toBe(31_536_000);
+        expect(config.clockSkewGraceSecs.toNumber()).toBe(0);
+    });
+
+    test("Config cannot be initialized twice", async () => {
+        await expect(program.initializeConfig()).rejects.toThrow();
+    });
+
+    test("Boss can update the config", async () => {
+        // when
+        await program.setConfig({ maxFeeBps: 500, maxApprovalValiditySecs: 3600, secondsPerYear: 31_622_400, clockSkewGraceSecs: 30 });
+
+        // then
+        const config = await program.getConfig();
+        expect(config.maxFeeBps).toBe(500);
+        expect(config.maxApprovalValiditySecs.toNumber()).toBe(3600);
+        expect(config.secondsPerYear.toNumber()).toBe(31_622_400);
+        expect(config.clockSkewGraceSecs.toNumber()).toBe(30);
+    });
+
+    test("Non-boss cannot update the config", async () => {
+        await expect(
+            program.setConfig({ maxFeeBps: 500, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+
+    test("Rejects a fee cap above 10000 basis points", async () => {
+        await expect(
+            program.setConfig({ maxFeeBps: 10_001, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000 })
+        ).rejects.toThrow("Invalid max fee");
+    });
+
+    test("Rejects an approval validity above 30 days", async () => {
+        await expect(
+            program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 30 * 86_400 + 1, secondsPerYear: 31_536_000 })
+        ).rejects.toThrow("Invalid max approval validity");
+    });
+
+    test("Rejects a clock skew grace above 5 minutes", async () => {
+        await expect(
+            program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000, clockSkewGraceSecs: 301 })
+        ).rejects.toThrow("Invalid clock skew grace");
+    });
+
+    test("Rejects a year length outside 360 to 366 days", async () => {
+        await expect(
+            program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 360 * 86_400 - 1 })
+        ).rejects.toThrow("Invalid seconds per year");
+        await expect(
+            program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 366 * 86_400 + 1 })
+        ).rejects.toThrow("Invalid seconds per year");
+    });
+
+    test("Offer fees are capped by the configured maximum", async () => {
+        // given
+        await program.setConfig({ maxFeeBps: 2000, maxApprovalValiditySecs: 0, secondsPerYear: 31_536_000 });
+
+        // when
+        await program.makeOffer({ tokenInMint, tokenOutMint, feeBasisPoints: 2000 });
+
+        // then
+        const otherMint = testHelper.createMint(6);
+        await expect(
+            program.makeOffer({ tokenInMint: otherMint, tokenOutMint, feeBasisPoints: 2001 })
+        ).rejects.toThrow("Invalid fee: fee_basis_points exceeds the configured maximum");
+    });
+
+    test("NAV accrues over the configured year length", async () => {
+        // given
+        await program.makeOffer({ tokenInMint, tokenOutMint });
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.addOfferVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 1_000_000, // 100% APR
+            priceFixDuration: 86_400
+        });
+        const navWith365Days = await program.getNAV({ tokenInMint, tokenOutMint });
+
+        // when
+        await program.setConfig({ maxFeeBps: 1000, maxApprovalValiditySecs: 0, secondsPerYear: 31_622_400 });
+        await testHelper.advanceSlot();
+
+        // then
+        const navWith366Days = await program.getNAV({ tokenInMint, tokenOutMint });
+        expect(navWith365Days).toBe(1_002_739_726);
+        expect(navWith366Days).toBe(1_002_732_240);
+    });
+});
@@ -0,0 +1,106 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Guardian", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let guardian: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        guardian = testHelper.createUserAccount();
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    test("Guardian can be set", async () => {
+        // given
+        const initialState = await program.getState();
+        expect(initialState.guardian).toEqual(PublicKey.default);
+
+        // when
+        await program.setGuardian({ guardian: guardian.publicKey });
+
+        // then
+        const state = await program.getState();
+        expect(state.guardian).toEqual(guardian.publicKey);
+    });
+
+    test("Guardian can be unset (set to default Pubkey) with its signature", async () => {
+        // given
+        await program.setGuardian({ guardian: guardian.publicKey });
+
+        // when
+        await program.setGuardian({ guardian: PublicKey.default, currentGuardian: guardian });
+
+        // then
+        const state = await program.getState();
+        expect(state.guardian).toEqual(PublicKey.default);
+    });
+
+    test("Setting the same guardian should fail", async () => {
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.setGuardian({ guardian: guardian.publicKey })
+        ).rejects.toThrow("No change: new guardian is the same as current");
+    });
+
+    test("Guardian can be replaced with the current guardian's signature", async () => {
+        // given
+        await program.setGuardian({ guardian: guardian.publicKey });
+        const newGuardian = testHelper.createUserAccount();
+
+        // when
+        await program.setGuardian({ guardian: newGuardian.publicKey, currentGuardian: guardian });
+
+        // then
+        const state = await program.getState();
+        expect(state.guardian).toEqual(newGuardian.publicKey);
+    });
+
+    test("Boss alone cannot replace a set guardian", async () => {
+        // given
+        await program.setGuardian({ guardian: guardian.publicKey });
+        const bossControlledKey = testHelper.createUserAccount();
+
+        // when / then
+        await expect(
+            program.setGuardian({ guardian: bossControlledKey.publicKey })
+        ).rejects.toThrow("Current guardian signature required to replace the guardian");
+        await expect(
+            program.setGuardian({ guardian: PublicKey.default })
+        ).rejects.toThrow("Current guardian signature required to replace the guardian");
+
+        const state = await program.getState();
+        expect(state.guardian).toEqual(guardian.publicKey);
+    });
+
+    test("Replacing the guardian with another co-signer should fail", async () => {
+        await program.setGuardian({ guardian: guardian.publicKey });
+        const impostor = testHelper.createUserAccount();
+
+        await expect(
+            program.setGuardian({ guardian: impostor.publicKey, currentGuardian: impostor })
+        ).rejects.toThrow("Invalid guardian account");
+    });
+
+    test("Setting the boss as guardian should fail", async () => {
+        await expect(
+            program.setGuardian({ guardian: testHelper.getBoss() })
+        ).rejects.toThrow("Guardian cannot be the boss");
+    });
+
+    test("Non-boss cannot set the guardian", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setGuardian({ guardian: guardian.publicKey, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
@@ -0,0 +1,67 @@
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Jurisdiction Blocked", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    function isBlocked(bitmap: number[], jurisdiction: number): boolean {
+        return (bitmap[Math.floor(jurisdiction / 8)] & (1 << (jurisdiction % 8))) !== 0;
+    }
+
+    test("Jurisdiction can be blocked", async () => {
+        // given
+        const initialState = await program.getState();
+        expect(initialState.blockedJurisdictions.every((byte: number) => byte === 0)).toBe(true);
+
+        // when
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true });
+
+        // then
+        const state = await program.getState();
+        expect(isBlocked(state.blockedJurisdictions, 840)).toBe(true);
+        expect(isBlocked(state.blockedJurisdictions, 841)).toBe(false);
+    });
+
+    test("Jurisdiction can be unblocked", async () => {
+        // given
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true });
+
+        // when
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: false });
+
+        // then
+        const state = await program.getState();
+        expect(isBlocked(state.blockedJurisdictions, 840)).toBe(false);
+    });
+
+    test("Blocking an already blocked jurisdiction should fail", async () => {
+        await program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true })
+        ).rejects.toThrow("No change: jurisdiction already in the requested state");
+    });
+
+    test("Jurisdiction codes beyond the bitmap should fail", async () => {
+        await expect(
+            program.setJurisdictionBlocked({ jurisdiction: 1024, blocked: true })
+        ).rejects.toThrow("Invalid jurisdiction code");
+    });
+
+    test("Non-boss cannot block a jurisdiction", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setJurisdictionBlocked({ jurisdiction: 840, blocked: true, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
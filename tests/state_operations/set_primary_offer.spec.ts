@@ -0,0 +1,78 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Primary Offer", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let usdcMint: PublicKey;
+    let onycMint: PublicKey;
+    let offerPda: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        usdcMint = testHelper.createMint(6);
+        onycMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint });
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: onycMint });
+        offerPda = program.getOfferPda(usdcMint, onycMint);
+    });
+
+    test("Primary offer can be set", async () => {
+        // given
+        const initialState = await program.getState();
+        expect(initialState.primaryOffer).toEqual(PublicKey.default);
+
+        // when
+        await program.setPrimaryOffer({ offer: offerPda });
+
+        // then
+        const state = await program.getState();
+        expect(state.primaryOffer).toEqual(offerPda);
+    });
+
+    test("Primary offer can be switched to another ONyc offer", async () => {
+        // given
+        const usdtMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: usdtMint, tokenOutMint: onycMint });
+        await program.setPrimaryOffer({ offer: offerPda });
+
+        // when
+        const otherOfferPda = program.getOfferPda(usdtMint, onycMint);
+        await program.setPrimaryOffer({ offer: otherOfferPda });
+
+        // then
+        const state = await program.getState();
+        expect(state.primaryOffer).toEqual(otherOfferPda);
+    });
+
+    test("Setting an offer not selling ONyc should fail", async () => {
+        const otherMint = testHelper.createMint(9);
+        await program.makeOffer({ tokenInMint: usdcMint, tokenOutMint: otherMint });
+
+        await expect(
+            program.setPrimaryOffer({ offer: program.getOfferPda(usdcMint, otherMint) })
+        ).rejects.toThrow("Invalid primary offer: token_out mint must be the ONyc mint");
+    });
+
+    test("Setting the same primary offer should fail", async () => {
+        await program.setPrimaryOffer({ offer: offerPda });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.setPrimaryOffer({ offer: offerPda })
+        ).rejects.toThrow("No change: offer is already the primary offer");
+    });
+
+    test("Non-boss cannot set the primary offer", async () => {
+        const nonBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setPrimaryOffer({ offer: offerPda, signer: nonBoss })
+        ).rejects.toThrow();
+    });
+});
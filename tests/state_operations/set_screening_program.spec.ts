@@ -0,0 +1,67 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Set Screening Program", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let screeningProgram: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        screeningProgram = PublicKey.unique();
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+    });
+
+    test("Screening program can be set", async () => {
+        // given
+        const initialState = await program.getState();
+        expect(initialState.screeningProgram).toEqual(PublicKey.default);
+
+        // when
+        await program.setScreeningProgram({ screeningProgram });
+
+        // then
+        const state = await program.getState();
+        expect(state.screeningProgram).toEqual(screeningProgram);
+    });
+
+    test("Screening program can be unset (set to default Pubkey)", async () => {
+        // given
+        await program.setScreeningProgram({ screeningProgram });
+
+        // when
+        await program.setScreeningProgram({ screeningProgram: PublicKey.default });
+
+        // then
+        const state = await program.getState();
+        expect(state.screeningProgram).toEqual(PublicKey.default);
+    });
+
+    test("Setting the same screening program should fail", async () => {
+        await program.setScreeningProgram({ screeningProgram });
+        await testHelper.advanceSlot();
+
+        await expect(
+            program.setScreeningProgram({ screeningProgram })
+        ).rejects.toThrow("No change: new screening program is the same as current");
+    });
+
+    test("Setting the program itself should fail", async () => {
+        await expect(
+            program.setScreeningProgram({ screeningProgram: program.program.programId })
+        ).rejects.toThrow("Invalid screening program");
+    });
+
+    test("Non-boss cannot set the screening program", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.setScreeningProgram({ screeningProgram, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
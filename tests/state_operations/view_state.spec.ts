@@ -0,0 +1,45 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper.ts";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("View State", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+    let onycMint: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        onycMint = testHelper.createMint(9);
+        await program.initialize({ onycMint });
+    });
+
+    test("Returns the settings of a freshly initialized state", async () => {
+        // when
+        const view = await program.viewState();
+
+        // then
+        expect(view.boss).toEqual(testHelper.getBoss());
+        expect(view.onycMint).toEqual(onycMint);
+        expect(view.killSwitchActive).toBe(false);
+        expect(view.adminCount).toBe(0);
+        expect(view.approver1).toEqual(PublicKey.default);
+        expect(view.maxSupply.toNumber()).toBe(0);
+    });
+
+    test("Reflects governance changes", async () => {
+        // given
+        const approver = testHelper.createUserAccount().publicKey;
+        const redemptionAdmin = testHelper.createUserAccount().publicKey;
+        await program.addApprover({ trusted: approver });
+        await program.setRedemptionAdmin({ redemptionAdmin });
+
+        // when
+        const view = await program.viewState();
+
+        // then
+        expect(view.approver1).toEqual(approver);
+        expect(view.redemptionAdmin).toEqual(redemptionAdmin);
+    });
+});
@@ -1,4 +1,4 @@
-import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
 import {
     ACCOUNT_SIZE,
     AccountLayout,
@@ -11,6 +11,9 @@ import {
     getMintLen,
     createInitializeMint2Instruction,
     createInitializeTransferFeeConfigInstruction,
+    createInitializeTransferHookInstruction,
+    createInitializePermanentDelegateInstruction,
+    createInitializeMintCloseAuthorityInstruction,
     createAssociatedTokenAccountInstruction,
     createMintToInstruction,
 } from "@solana/spl-token";
@@ -190,6 +193,108 @@ export class TestHelper {
         return mint.publicKey;
     }
 
+    async createMint2022WithTransferHook(
+        decimals: number,
+        hookProgramId: PublicKey
+    ): Promise<PublicKey> {
+        const mint = Keypair.generate();
+        const mintAuth = this.getBoss();
+
+        const mintLen = getMintLen([ExtensionType.TransferHook]);
+
+        const createAccountIx = SystemProgram.createAccount({
+            fromPubkey: this.payer.publicKey,
+            newAccountPubkey: mint.publicKey,
+            space: mintLen,
+            lamports: INITIAL_LAMPORTS,
+            programId: TOKEN_2022_PROGRAM_ID,
+        });
+
+        const initTransferHookIx = createInitializeTransferHookInstruction(
+            mint.publicKey,
+            mintAuth,
+            hookProgramId,
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        const initMintIx = createInitializeMint2Instruction(
+            mint.publicKey,
+            decimals,
+            mintAuth,
+            mintAuth,
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        const tx = new Transaction().add(createAccountIx, initTransferHookIx, initMintIx);
+        await this.sendAndConfirmTransaction(tx, [this.payer, mint]);
+
+        return mint.publicKey;
+    }
+
+    async createMint2022WithExtension(
+        decimals: number,
+        extension: "permanentDelegate" | "confidentialTransfer" | "mintCloseAuthority"
+    ): Promise<PublicKey> {
+        const mint = Keypair.generate();
+        const mintAuth = this.getBoss();
+
+        let extensionType: ExtensionType;
+        let initExtensionIx: TransactionInstruction;
+        switch (extension) {
+            case "permanentDelegate":
+                extensionType = ExtensionType.PermanentDelegate;
+                initExtensionIx = createInitializePermanentDelegateInstruction(
+                    mint.publicKey,
+                    mintAuth,
+                    TOKEN_2022_PROGRAM_ID
+                );
+                break;
+            case "confidentialTransfer":
+                // ConfidentialTransferExtension::InitializeMint with no auditor
+                extensionType = ExtensionType.ConfidentialTransferMint;
+                initExtensionIx = new TransactionInstruction({
+                    keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+                    programId: TOKEN_2022_PROGRAM_ID,
+                    data: Buffer.concat([
+                        Buffer.from([27, 0]),
+                        mintAuth.toBuffer(),
+                        Buffer.from([1]),
+                        Buffer.alloc(32)
+                    ])
+                });
+                break;
+            case "mintCloseAuthority":
+                extensionType = ExtensionType.MintCloseAuthority;
+                initExtensionIx = createInitializeMintCloseAuthorityInstruction(
+                    mint.publicKey,
+                    mintAuth,
+                    TOKEN_2022_PROGRAM_ID
+                );
+                break;
+        }
+
+        const createAccountIx = SystemProgram.createAccount({
+            fromPubkey: this.payer.publicKey,
+            newAccountPubkey: mint.publicKey,
+            space: getMintLen([extensionType]),
+            lamports: INITIAL_LAMPORTS,
+            programId: TOKEN_2022_PROGRAM_ID,
+        });
+
+        const initMintIx = createInitializeMint2Instruction(
+            mint.publicKey,
+            decimals,
+            mintAuth,
+            mintAuth,
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        const tx = new Transaction().add(createAccountIx, initExtensionIx, initMintIx);
+        await this.sendAndConfirmTransaction(tx, [this.payer, mint]);
+
+        return mint.publicKey;
+    }
+
     createMint(
         decimals: number,
         mintAuthority: PublicKey | null = null,
@@ -332,6 +437,21 @@ export class TestHelper {
         return AccountLayout.decode(account.data);
     }
 
+    // Simulates a transaction and returns the compute units it consumed
+    simulateComputeUnits(tx: Transaction, signers: Keypair[] = []): number {
+        tx.recentBlockhash = this.svm.latestBlockhash();
+        tx.feePayer = this.payer.publicKey;
+        tx.sign(this.payer, ...signers);
+
+        const result = this.svm.simulateTransaction(tx);
+
+        if (typeof (result as any).err === 'function') {
+            throw new Error(result.meta().logs().join('\n'));
+        }
+
+        return Number(result.meta().computeUnitsConsumed());
+    }
+
     // Helper to send transactions
     async sendAndConfirmTransaction(tx: Transaction, signers: Keypair[]) {
         tx.recentBlockhash = this.svm.latestBlockhash();
@@ -0,0 +1,133 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+
+describe("Migrate offer vault", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let tokenInMint: PublicKey;
+    let tokenOutMint: PublicKey;
+    let offerPda: PublicKey;
+    let ownVaultAuthority: PublicKey;
+    let sharedVaultTokenOutAccount: PublicKey;
+    let ownVaultTokenInAccount: PublicKey;
+    let ownVaultTokenOutAccount: PublicKey;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+
+        tokenInMint = testHelper.createMint(6);
+        tokenOutMint = testHelper.createMint(9);
+
+        await program.initialize({ onycMint: tokenOutMint });
+        testHelper.createTokenAccount(tokenInMint, testHelper.getBoss(), BigInt(0));
+        testHelper.createTokenAccount(tokenOutMint, testHelper.getBoss(), BigInt(10_000e9));
+
+        const currentTime = await testHelper.getCurrentClockTime();
+        await program.makeOfferWithVector({
+            tokenInMint,
+            tokenOutMint,
+            baseTime: currentTime,
+            basePrice: 1e9,
+            apr: 0,
+            priceFixDuration: 86400
+        });
+
+        // Offers are created on their own vault, so serve this one from the shared vault like an offer made before per-offer vaults
+        offerPda = program.getOfferPda(tokenInMint, tokenOutMint);
+        await program.moveOfferToSharedVault(offerPda);
+        await program.offerVaultDeposit({ amount: 1_000e9, tokenMint: tokenOutMint });
+        ownVaultAuthority = program.getOwnOfferVaultAuthorityPda(offerPda);
+        sharedVaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, program.pdas.offerVaultAuthorityPda, true);
+        ownVaultTokenInAccount = getAssociatedTokenAddressSync(tokenInMint, ownVaultAuthority, true);
+        ownVaultTokenOutAccount = getAssociatedTokenAddressSync(tokenOutMint, ownVaultAuthority, true);
+    });
+
+    it("Should move the inventory to the offer's own vault", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint, tokenOutAmount: 600e9 });
+
+        const offer = await program.getOffer(tokenInMint, tokenOutMint);
+        expect(offer.ownVault).toBe(1);
+        await testHelper.expectTokenAccountAmountToBe(sharedVaultTokenOutAccount, BigInt(400e9));
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(600e9));
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenInAccount, BigInt(0));
+    });
+
+    it("Should pay takes from the offer's own vault", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint, tokenOutAmount: 600e9 });
+        const user = testHelper.createUserAccount();
+        testHelper.createTokenAccount(tokenInMint, user.publicKey, BigInt(100e6), true);
+
+        await program.takeOffer({
+            tokenInAmount: 100e6,
+            tokenInMint,
+            tokenOutMint,
+            user: user.publicKey,
+            signer: user,
+            bossTokenInAccount: null
+        });
+
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(500e9));
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenInAccount, BigInt(100e6));
+        await testHelper.expectTokenAccountAmountToBe(sharedVaultTokenOutAccount, BigInt(400e9));
+    });
+
+    it("Should target the offer's own vault for deposits and withdrawals naming the offer", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint });
+
+        await program.offerVaultDeposit({ amount: 200e9, tokenMint: tokenOutMint, offer: offerPda });
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(200e9));
+
+        await program.offerVaultWithdraw({ amount: 50e9, tokenMint: tokenOutMint, offer: offerPda });
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(150e9));
+        await testHelper.expectTokenAccountAmountToBe(sharedVaultTokenOutAccount, BigInt(1_000e9));
+    });
+
+    it("Should sweep the offer's own vault when closing", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint, tokenOutAmount: 600e9 });
+
+        await program.closeOffer({ tokenInMint, tokenOutMint, sweepMints: [tokenInMint, tokenOutMint] });
+
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(0));
+        await testHelper.expectTokenAccountAmountToBe(sharedVaultTokenOutAccount, BigInt(400e9));
+    });
+
+    it("Should sweep dust of the offer's own vault when naming the offer", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint, tokenOutAmount: 600e9 });
+
+        await expect(
+            program.sweepVaultDust({ vaultAccounts: [ownVaultTokenInAccount] })
+        ).rejects.toThrow("Invalid vault token account");
+
+        await program.sweepVaultDust({ vaultAccounts: [ownVaultTokenInAccount, ownVaultTokenOutAccount], offer: offerPda });
+
+        expect(await testHelper.getAccountInfo(ownVaultTokenInAccount)).toBeNull();
+        await testHelper.expectTokenAccountAmountToBe(ownVaultTokenOutAccount, BigInt(600e9));
+    });
+
+    it("Should reject migrating an offer twice", async () => {
+        await program.migrateOfferVault({ tokenInMint, tokenOutMint });
+        await testHelper.advanceSlot();
+
+        await expect(program.migrateOfferVault({ tokenInMint, tokenOutMint }))
+            .rejects.toThrow("Offer already has its own vault");
+    });
+
+    it("Should reject migrating a new offer", async () => {
+        const otherMint = testHelper.createMint(6);
+        await program.makeOffer({ tokenInMint: otherMint, tokenOutMint });
+
+        await expect(program.migrateOfferVault({ tokenInMint: otherMint, tokenOutMint }))
+            .rejects.toThrow("Offer already has its own vault");
+    });
+
+    it("Should reject non-boss callers", async () => {
+        const notBoss: Keypair = testHelper.createUserAccount();
+
+        await expect(program.migrateOfferVault({ tokenInMint, tokenOutMint, signer: notBoss }))
+            .rejects.toThrow();
+    });
+});
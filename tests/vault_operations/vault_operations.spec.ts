@@ -45,6 +45,21 @@ describe("Vault Operations", () => {
             await testHelper.expectTokenAccountAmountToBe(testBossTokenAccount, expectedBossBalance);
         });
 
+        test("Vault balance changes advance the vault event sequence", async () => {
+            // given
+            const testTokenMint = testHelper.createMint(9);
+            testHelper.createTokenAccount(testTokenMint, boss, BigInt(1_000_000e9));
+
+            // when
+            await program.offerVaultDeposit({ amount: 100_000e9, tokenMint: testTokenMint });
+            await program.redemptionVaultDeposit({ amount: 50_000e9, tokenMint: testTokenMint });
+            await program.offerVaultWithdraw({ amount: 10_000e9, tokenMint: testTokenMint });
+
+            // then
+            const state = await program.getState();
+            expect(state.vaultEventSequence.toNumber()).toBe(3);
+        });
+
         test("Non-boss cannot deposit to offer vault", async () => {
             // given
             const testTokenMint = testHelper.createMint(9);
@@ -284,4 +299,63 @@ describe("Vault Operations", () => {
             })).rejects.toThrow();
         });
     });
-});
\ No newline at end of file
+
+    describe("Vault Dust Sweep", () => {
+        test("Empty offer and redemption vault accounts are closed and rent goes to boss", async () => {
+            // given
+            const mint = testHelper.createMint(9);
+            const offerVault = testHelper.createTokenAccount(mint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            const redemptionVault = testHelper.createTokenAccount(mint, program.pdas.redemptionVaultAuthorityPda, BigInt(0), true);
+            const rent = testHelper.svm.getBalance(offerVault) + testHelper.svm.getBalance(redemptionVault);
+            const bossBalanceBefore = testHelper.svm.getBalance(boss);
+
+            // when
+            const admin = testHelper.createUserAccount();
+            await program.addAdmin({ admin: admin.publicKey });
+            await program.sweepVaultDust({ vaultAccounts: [offerVault, redemptionVault], signer: admin });
+
+            // then
+            expect(await testHelper.getAccountInfo(offerVault)).toBeNull();
+            expect(await testHelper.getAccountInfo(redemptionVault)).toBeNull();
+            expect(testHelper.svm.getBalance(boss)).toBeGreaterThanOrEqual(bossBalanceBefore + rent - BigInt(50_000));
+        });
+
+        test("Vault accounts holding tokens are skipped", async () => {
+            // given
+            const emptyMint = testHelper.createMint(9);
+            const fundedMint = testHelper.createMint(9);
+            const emptyVault = testHelper.createTokenAccount(emptyMint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            const fundedVault = testHelper.createTokenAccount(fundedMint, program.pdas.offerVaultAuthorityPda, BigInt(5), true);
+
+            // when
+            await program.sweepVaultDust({ vaultAccounts: [emptyVault, fundedVault] });
+
+            // then
+            expect(await testHelper.getAccountInfo(emptyVault)).toBeNull();
+            await testHelper.expectTokenAccountAmountToBe(fundedVault, BigInt(5));
+        });
+
+        test("Token accounts not controlled by a vault authority are rejected", async () => {
+            // given
+            const mint = testHelper.createMint(9);
+            const userAccount = testHelper.createTokenAccount(mint, testHelper.createUserAccount().publicKey, BigInt(0));
+
+            // when & then
+            await expect(
+                program.sweepVaultDust({ vaultAccounts: [userAccount] })
+            ).rejects.toThrow("Invalid vault token account");
+        });
+
+        test("Non-boss non-admin cannot sweep vault dust", async () => {
+            // given
+            const mint = testHelper.createMint(9);
+            const offerVault = testHelper.createTokenAccount(mint, program.pdas.offerVaultAuthorityPda, BigInt(0), true);
+            const notAdmin = testHelper.createUserAccount();
+
+            // when & then
+            await expect(
+                program.sweepVaultDust({ vaultAccounts: [offerVault], signer: notAdmin })
+            ).rejects.toThrow("Unauthorized: signer must be boss or admin");
+        });
+    });
+});
@@ -0,0 +1,163 @@
+import { PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+
+describe("Vault Withdraw Timelock", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let boss: PublicKey;
+    let tokenMint: PublicKey;
+    let vaultTokenAccount: PublicKey;
+
+    const DELAY = 86_400;
+    const THRESHOLD = 1_000e9;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+        boss = testHelper.getBoss();
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+
+        tokenMint = testHelper.createMint(9);
+        testHelper.createTokenAccount(tokenMint, boss, BigInt(1_000_000e9));
+        vaultTokenAccount = getAssociatedTokenAddressSync(tokenMint, program.pdas.offerVaultAuthorityPda, true);
+        await program.offerVaultDeposit({ amount: 100_000e9, tokenMint });
+    });
+
+    test("Withdrawals stay immediate until a delay is configured", async () => {
+        await program.offerVaultWithdraw({ amount: 50_000e9, tokenMint });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(50_000e9));
+    });
+
+    test("Withdrawals up to the threshold stay immediate", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+
+        await program.offerVaultWithdraw({ amount: THRESHOLD, tokenMint });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(100_000e9 - THRESHOLD));
+    });
+
+    test("Withdrawals above the threshold must be proposed", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+
+        await expect(
+            program.offerVaultWithdraw({ amount: THRESHOLD + 1, tokenMint })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+    });
+
+    test("Splitting a large withdrawal into smaller ones is rejected", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+        await program.offerVaultWithdraw({ amount: THRESHOLD / 2, tokenMint });
+        await program.offerVaultWithdraw({ amount: THRESHOLD / 2, tokenMint });
+
+        await expect(
+            program.offerVaultWithdraw({ amount: THRESHOLD / 2, tokenMint })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(100_000e9 - THRESHOLD));
+    });
+
+    test("Immediate withdrawals of the offer and redemption vaults share one window", async () => {
+        await program.redemptionVaultDeposit({ amount: 100_000e9, tokenMint });
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+        await program.offerVaultWithdraw({ amount: THRESHOLD, tokenMint });
+
+        await expect(
+            program.redemptionVaultWithdraw({ amount: 1, tokenMint })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+    });
+
+    test("The immediate allowance is restored once the window has passed", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+        await program.offerVaultWithdraw({ amount: THRESHOLD, tokenMint });
+        await testHelper.advanceClockBy(DELAY);
+
+        await program.offerVaultWithdraw({ amount: THRESHOLD, tokenMint });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(100_000e9 - 2 * THRESHOLD));
+    });
+
+    test("Proposed withdrawal executes after the delay and closes the proposal", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+        await program.proposeVaultWithdraw({ amount: 40_000e9, tokenMint });
+
+        const proposal = await program.getVaultWithdrawProposal(tokenMint);
+        expect(proposal.amount.toNumber()).toBe(40_000e9);
+
+        await testHelper.advanceClockBy(DELAY);
+        await program.executeVaultWithdraw({ tokenMint });
+
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(60_000e9));
+        await expect(program.getVaultWithdrawProposal(tokenMint)).rejects.toThrow();
+    });
+
+    test("Cannot execute before the delay has elapsed", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+        await program.proposeVaultWithdraw({ amount: 40_000e9, tokenMint });
+        await testHelper.advanceClockBy(DELAY - 1);
+
+        await expect(
+            program.executeVaultWithdraw({ tokenMint })
+        ).rejects.toThrow("Vault withdraw proposal timelock has not elapsed");
+    });
+
+    test("Redemption vault withdrawals above the threshold must be proposed", async () => {
+        const redemptionVaultTokenAccount = getAssociatedTokenAddressSync(tokenMint, program.pdas.redemptionVaultAuthorityPda, true);
+        await program.redemptionVaultDeposit({ amount: 100_000e9, tokenMint });
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+
+        await program.redemptionVaultWithdraw({ amount: THRESHOLD, tokenMint });
+        await expect(
+            program.redemptionVaultWithdraw({ amount: THRESHOLD + 1, tokenMint })
+        ).rejects.toThrow("Withdrawal exceeds the immediate threshold and must be proposed");
+
+        await program.proposeVaultWithdraw({ amount: 40_000e9, tokenMint });
+        await testHelper.advanceClockBy(DELAY);
+        await program.executeRedemptionVaultWithdraw({ tokenMint });
+
+        await testHelper.expectTokenAccountAmountToBe(redemptionVaultTokenAccount, BigInt(60_000e9 - THRESHOLD));
+        await testHelper.expectTokenAccountAmountToBe(vaultTokenAccount, BigInt(100_000e9));
+    });
+
+    test("Loosening the timelock fails while no guardian is set", async () => {
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+
+        await expect(
+            program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: 0, immediateThreshold: THRESHOLD })
+        ).rejects.toThrow("A guardian must be set to loosen the vault withdraw timelock");
+        await expect(
+            program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD + 1 })
+        ).rejects.toThrow("A guardian must be set to loosen the vault withdraw timelock");
+
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY * 2, immediateThreshold: THRESHOLD });
+    });
+
+    test("Loosening the timelock requires the guardian", async () => {
+        const guardian = testHelper.createUserAccount();
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: DELAY, immediateThreshold: THRESHOLD });
+
+        await expect(
+            program.configureVaultWithdrawTimelock({ tokenMint, delaySecs: 0, immediateThreshold: THRESHOLD })
+        ).rejects.toThrow("Guardian signature required to loosen the vault withdraw timelock");
+
+        await program.configureVaultWithdrawTimelock({
+            tokenMint,
+            delaySecs: 0,
+            immediateThreshold: THRESHOLD,
+            guardian
+        });
+    });
+
+    test("Non-boss cannot propose a withdrawal", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.proposeVaultWithdraw({ amount: 40_000e9, tokenMint, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});
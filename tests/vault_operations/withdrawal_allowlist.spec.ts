@@ -0,0 +1,104 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TestHelper } from "../test_helper";
+import { OnreProgram } from "../onre_program.ts";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+
+describe("Withdrawal Allowlist", () => {
+    let testHelper: TestHelper;
+    let program: OnreProgram;
+
+    let boss: PublicKey;
+    let tokenMint: PublicKey;
+    let treasury: PublicKey;
+    let guardian: Keypair;
+
+    beforeEach(async () => {
+        testHelper = await TestHelper.create();
+        program = new OnreProgram(testHelper);
+        boss = testHelper.getBoss();
+
+        await program.initialize({ onycMint: testHelper.createMint(9) });
+
+        tokenMint = testHelper.createMint(9);
+        testHelper.createTokenAccount(tokenMint, boss, BigInt(1_000_000e9));
+        treasury = testHelper.createUserAccount().publicKey;
+        guardian = testHelper.createUserAccount();
+        await program.setGuardian({ guardian: guardian.publicKey });
+        await program.offerVaultDeposit({ amount: 100_000e9, tokenMint });
+        await program.redemptionVaultDeposit({ amount: 100_000e9, tokenMint });
+    });
+
+    test("Withdrawals go only to the boss while the allowlist is empty", async () => {
+        await program.offerVaultWithdraw({ amount: 1_000e9, tokenMint });
+
+        await expect(
+            program.offerVaultWithdraw({ amount: 1_000e9, tokenMint, destination: treasury })
+        ).rejects.toThrow("Withdrawal destination not allowed");
+    });
+
+    test("Listed destinations receive withdrawals and the boss no longer does", async () => {
+        await program.addWithdrawalDestination({ destination: treasury, guardian });
+
+        await program.offerVaultWithdraw({ amount: 1_000e9, tokenMint, destination: treasury });
+        await program.redemptionVaultWithdraw({ amount: 2_000e9, tokenMint, destination: treasury });
+
+        const treasuryTokenAccount = getAssociatedTokenAddressSync(tokenMint, treasury, true);
+        await testHelper.expectTokenAccountAmountToBe(treasuryTokenAccount, BigInt(3_000e9));
+        await expect(
+            program.offerVaultWithdraw({ amount: 1_000e9, tokenMint })
+        ).rejects.toThrow("Withdrawal destination not allowed");
+    });
+
+    test("Removing the last destination does not restore withdrawals to the boss", async () => {
+        await program.addWithdrawalDestination({ destination: treasury, guardian });
+        await program.removeWithdrawalDestination({ destination: treasury, guardian });
+
+        await expect(
+            program.offerVaultWithdraw({ amount: 1_000e9, tokenMint })
+        ).rejects.toThrow("Withdrawal destination not allowed");
+        await expect(
+            program.offerVaultWithdraw({ amount: 1_000e9, tokenMint, destination: treasury })
+        ).rejects.toThrow("Withdrawal destination not allowed");
+    });
+
+    test("Removing a destination requires the guardian's signature", async () => {
+        const notGuardian = testHelper.createUserAccount();
+        await program.addWithdrawalDestination({ destination: treasury, guardian });
+
+        await expect(
+            program.removeWithdrawalDestination({ destination: treasury, guardian: notGuardian })
+        ).rejects.toThrow("Invalid guardian account");
+    });
+
+    test("Adding a destination requires the guardian's signature", async () => {
+        const notGuardian = testHelper.createUserAccount();
+
+        await expect(
+            program.addWithdrawalDestination({ destination: treasury, guardian: notGuardian })
+        ).rejects.toThrow("Invalid guardian account");
+    });
+
+    test("Adding a destination fails while no guardian is set", async () => {
+        await program.setGuardian({ guardian: PublicKey.default, currentGuardian: guardian });
+
+        await expect(
+            program.addWithdrawalDestination({ destination: treasury, guardian })
+        ).rejects.toThrow("A guardian must be set to add a withdrawal destination");
+    });
+
+    test("Cannot add the same destination twice", async () => {
+        await program.addWithdrawalDestination({ destination: treasury, guardian });
+
+        await expect(
+            program.addWithdrawalDestination({ destination: treasury, guardian })
+        ).rejects.toThrow("Destination already exists in the withdrawal allowlist");
+    });
+
+    test("Non-boss cannot add a destination", async () => {
+        const notBoss = testHelper.createUserAccount();
+
+        await expect(
+            program.addWithdrawalDestination({ destination: notBoss.publicKey, guardian, signer: notBoss })
+        ).rejects.toThrow();
+    });
+});